@@ -0,0 +1,101 @@
+use sea_orm_migration::prelude::*;
+
+/// 审计列的默认操作人，与实体保存钩子在没有请求用户时写入的值一致
+const SYSTEM_ACTOR: &str = "system";
+
+/// 审计列，保存时由实体的 `audit_stamped!` 钩子填充
+#[derive(DeriveIden, Clone, Copy)]
+enum AuditColumn {
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
+
+impl AuditColumn {
+    fn def(self) -> ColumnDef {
+        let mut column = ColumnDef::new(self);
+        match self {
+            Self::CreatedAt => column
+                .timestamp()
+                .not_null()
+                .default(Expr::current_timestamp()),
+            Self::CreatedBy => column.string().not_null().default(SYSTEM_ACTOR),
+            Self::UpdatedAt => column.timestamp().null(),
+            Self::UpdatedBy => column.string().null(),
+        };
+        column
+    }
+
+    fn columns(with_updates: bool) -> &'static [AuditColumn] {
+        if with_updates {
+            &[
+                Self::CreatedAt,
+                Self::CreatedBy,
+                Self::UpdatedAt,
+                Self::UpdatedBy,
+            ]
+        } else {
+            &[Self::CreatedAt, Self::CreatedBy]
+        }
+    }
+}
+
+/// 建表时使用的审计列，`with_updates` 为 `false` 时只有创建时间和创建人，用于只追加的日志表
+///
+/// ```rust,ignore
+/// let mut table = Table::create().table(SysExample::Table).col(...).to_owned();
+/// for column in audit_columns(true) {
+///     table.col(column);
+/// }
+/// ```
+pub fn audit_columns(with_updates: bool) -> Vec<ColumnDef> {
+    AuditColumn::columns(with_updates)
+        .iter()
+        .map(|column| column.def())
+        .collect()
+}
+
+/// 为已有表补齐缺少的审计列，已存在的列跳过，已有数据的创建人为 `system`
+pub async fn add_audit_columns(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    with_updates: bool,
+) -> Result<(), DbErr> {
+    for column in AuditColumn::columns(with_updates) {
+        if manager.has_column(table, &column.to_string()).await? {
+            continue;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new(table))
+                    .add_column(column.def())
+                    .to_owned(),
+            )
+            .await?;
+    }
+    Ok(())
+}
+
+/// 删除 `add_audit_columns` 添加的审计列，用于迁移回滚
+pub async fn drop_audit_columns(
+    manager: &SchemaManager<'_>,
+    table: &str,
+    with_updates: bool,
+) -> Result<(), DbErr> {
+    for column in AuditColumn::columns(with_updates) {
+        if !manager.has_column(table, &column.to_string()).await? {
+            continue;
+        }
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Alias::new(table))
+                    .drop_column(*column)
+                    .to_owned(),
+            )
+            .await?;
+    }
+    Ok(())
+}
@@ -1,5 +1,6 @@
 pub use sea_orm_migration::prelude::*;
 
+pub mod audit_columns;
 mod datas;
 mod schemas;
 pub struct Migrator;
@@ -22,6 +23,25 @@ impl MigratorTrait for Migrator {
             Box::new(schemas::m20241023_091204_create_sys_tokens::Migration),
             Box::new(schemas::m20241023_091210_create_sys_user_role::Migration),
             Box::new(schemas::m20241023_091159_create_sys_role_menu::Migration),
+            Box::new(schemas::m20261015_100000_create_sys_file_blob::Migration),
+            Box::new(schemas::m20261015_100100_create_sys_file::Migration),
+            Box::new(schemas::m20261015_110000_alter_sys_file_add_scan_status::Migration),
+            Box::new(schemas::m20261015_120000_create_sys_alert_rule::Migration),
+            Box::new(schemas::m20261015_130000_create_sys_user_passkey::Migration),
+            Box::new(schemas::m20261015_140000_add_data_scope::Migration),
+            Box::new(schemas::m20261015_150000_create_sys_access_review::Migration),
+            Box::new(schemas::m20261015_160000_create_sys_policy::Migration),
+            Box::new(schemas::m20261015_170000_create_sys_notification::Migration),
+            Box::new(schemas::m20261015_180000_create_sys_maintenance_window::Migration),
+            Box::new(schemas::m20261015_190000_create_sys_api_usage::Migration),
+            Box::new(schemas::m20261015_200000_create_sys_metering::Migration),
+            Box::new(schemas::m20261015_210000_create_sys_tenant::Migration),
+            Box::new(schemas::m20261015_220000_create_sys_region::Migration),
+            Box::new(schemas::m20261015_230000_create_sys_file_share::Migration),
+            Box::new(schemas::m20261015_233000_alter_sys_operation_log_add_hash_chain::Migration),
+            Box::new(schemas::m20261015_234000_create_sys_operation_log_archive::Migration),
+            Box::new(schemas::m20261015_235000_create_sys_param::Migration),
+            Box::new(schemas::m20261016_000000_create_sys_personal_token::Migration),
             // 数据迁移
             Box::new(datas::m20241023_102950_insert_sys_domain::Migration),
             Box::new(datas::m20241024_033005_insert_sys_user::Migration),
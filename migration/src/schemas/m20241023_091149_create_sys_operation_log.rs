@@ -84,7 +84,7 @@ impl MigrationTrait for Migration {
 }
 
 #[derive(DeriveIden)]
-enum SysOperationLog {
+pub enum SysOperationLog {
     Table,
     Id,
     UserId,
@@ -0,0 +1,67 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysFileBlob::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysFileBlob::Hash)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysFileBlob::Size).big_integer().not_null())
+                    .col(ColumnDef::new(SysFileBlob::StorageKey).string().not_null())
+                    .col(
+                        ColumnDef::new(SysFileBlob::RefCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(SysFileBlob::ZeroRefAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysFileBlob::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_file_blob_ref_count_zero_ref_at")
+                    .table(SysFileBlob::Table)
+                    .col(SysFileBlob::RefCount)
+                    .col(SysFileBlob::ZeroRefAt)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysFileBlob::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysFileBlob {
+    Table,
+    Hash,
+    Size,
+    StorageKey,
+    RefCount,
+    ZeroRefAt,
+    CreatedAt,
+}
@@ -0,0 +1,74 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261015_100000_create_sys_file_blob::SysFileBlob;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysFile::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysFile::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysFile::Domain).string().not_null())
+                    .col(ColumnDef::new(SysFile::BlobHash).string().not_null())
+                    .col(ColumnDef::new(SysFile::FileName).string().not_null())
+                    .col(ColumnDef::new(SysFile::ContentType).string().not_null())
+                    .col(ColumnDef::new(SysFile::Size).big_integer().not_null())
+                    .col(
+                        ColumnDef::new(SysFile::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysFile::CreatedBy).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sys_file_blob_hash")
+                            .from(SysFile::Table, SysFile::BlobHash)
+                            .to(SysFileBlob::Table, SysFileBlob::Hash)
+                            .on_delete(ForeignKeyAction::Restrict),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_file_blob_hash")
+                    .table(SysFile::Table)
+                    .col(SysFile::BlobHash)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysFile::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysFile {
+    Table,
+    Id,
+    Domain,
+    BlobHash,
+    FileName,
+    ContentType,
+    Size,
+    CreatedAt,
+    CreatedBy,
+}
@@ -0,0 +1,60 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20261015_100100_create_sys_file::SysFile;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysFile::Table)
+                    .add_column(
+                        ColumnDef::new(SysFileScan::ScanStatus)
+                            .string()
+                            .not_null()
+                            .default("clean"),
+                    )
+                    .add_column(ColumnDef::new(SysFileScan::ScanResult).string().null())
+                    .add_column(ColumnDef::new(SysFileScan::ReviewedBy).string().null())
+                    .add_column(ColumnDef::new(SysFileScan::ReviewedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_file_scan_status")
+                    .table(SysFile::Table)
+                    .col(SysFileScan::ScanStatus)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysFile::Table)
+                    .drop_column(SysFileScan::ScanStatus)
+                    .drop_column(SysFileScan::ScanResult)
+                    .drop_column(SysFileScan::ReviewedBy)
+                    .drop_column(SysFileScan::ReviewedAt)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysFileScan {
+    ScanStatus,
+    ScanResult,
+    ReviewedBy,
+    ReviewedAt,
+}
@@ -0,0 +1,99 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAlertRule::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAlertRule::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAlertRule::Name)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(SysAlertRule::SignalKind).string().not_null())
+                    .col(ColumnDef::new(SysAlertRule::Threshold).integer().not_null())
+                    .col(
+                        ColumnDef::new(SysAlertRule::WindowSeconds)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAlertRule::CooldownSeconds)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysAlertRule::Channel).string().not_null())
+                    .col(ColumnDef::new(SysAlertRule::Target).string().null())
+                    .col(
+                        ColumnDef::new(SysAlertRule::Enabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysAlertRule::LastTriggeredAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAlertRule::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysAlertRule::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysAlertRule::UpdatedAt).timestamp().null())
+                    .col(ColumnDef::new(SysAlertRule::UpdatedBy).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_alert_rule_signal_kind")
+                    .table(SysAlertRule::Table)
+                    .col(SysAlertRule::SignalKind)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAlertRule::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAlertRule {
+    Table,
+    Id,
+    Name,
+    SignalKind,
+    Threshold,
+    WindowSeconds,
+    CooldownSeconds,
+    Channel,
+    Target,
+    Enabled,
+    LastTriggeredAt,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
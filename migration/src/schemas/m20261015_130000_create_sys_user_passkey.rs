@@ -0,0 +1,76 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysUserPasskey::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysUserPasskey::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysUserPasskey::UserId).string().not_null())
+                    .col(
+                        ColumnDef::new(SysUserPasskey::CredentialId)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserPasskey::DeviceName)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysUserPasskey::Credential).text().not_null())
+                    .col(
+                        ColumnDef::new(SysUserPasskey::LastUsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysUserPasskey::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_user_passkey_user_id")
+                    .table(SysUserPasskey::Table)
+                    .col(SysUserPasskey::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysUserPasskey::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysUserPasskey {
+    Table,
+    Id,
+    UserId,
+    CredentialId,
+    DeviceName,
+    Credential,
+    LastUsedAt,
+    CreatedAt,
+}
@@ -0,0 +1,78 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysUser::Table)
+                    .add_column(ColumnDef::new(SysUser::OrganizationId).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_user_organization_id")
+                    .table(SysUser::Table)
+                    .col(SysUser::OrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        // 已有角色默认不限制数据范围，保持升级前的行为
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysRole::Table)
+                    .add_column(
+                        ColumnDef::new(SysRole::DataScope)
+                            .string()
+                            .not_null()
+                            .default("all"),
+                    )
+                    .add_column(ColumnDef::new(SysRole::ScopeOrganizationId).string().null())
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysRole::Table)
+                    .drop_column(SysRole::DataScope)
+                    .drop_column(SysRole::ScopeOrganizationId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysUser::Table)
+                    .drop_column(SysUser::OrganizationId)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysUser {
+    Table,
+    OrganizationId,
+}
+
+#[derive(DeriveIden)]
+enum SysRole {
+    Table,
+    DataScope,
+    ScopeOrganizationId,
+}
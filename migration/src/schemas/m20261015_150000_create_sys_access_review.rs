@@ -0,0 +1,167 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAccessReview::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAccessReview::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysAccessReview::Name).string().not_null())
+                    .col(ColumnDef::new(SysAccessReview::Status).string().not_null())
+                    .col(
+                        ColumnDef::new(SysAccessReview::DueAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReview::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReview::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysAccessReview::ClosedAt).timestamp().null())
+                    .col(ColumnDef::new(SysAccessReview::ClosedBy).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysAccessReviewItem::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::ReviewId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::Username)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::OrganizationId)
+                            .string()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(SysAccessReviewItem::Roles).text().not_null())
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::LastLoginAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::ReviewerId)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::Decision)
+                            .string()
+                            .not_null()
+                            .default("pending"),
+                    )
+                    .col(ColumnDef::new(SysAccessReviewItem::Comment).string().null())
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::DecidedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysAccessReviewItem::DecidedBy)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_access_review_item_review_id")
+                    .table(SysAccessReviewItem::Table)
+                    .col(SysAccessReviewItem::ReviewId)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_access_review_item_reviewer_id")
+                    .table(SysAccessReviewItem::Table)
+                    .col(SysAccessReviewItem::ReviewerId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysAccessReviewItem::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SysAccessReview::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysAccessReview {
+    Table,
+    Id,
+    Name,
+    Status,
+    DueAt,
+    CreatedAt,
+    CreatedBy,
+    ClosedAt,
+    ClosedBy,
+}
+
+#[derive(DeriveIden)]
+pub enum SysAccessReviewItem {
+    Table,
+    Id,
+    ReviewId,
+    UserId,
+    Username,
+    OrganizationId,
+    Roles,
+    LastLoginAt,
+    ReviewerId,
+    Decision,
+    Comment,
+    DecidedAt,
+    DecidedBy,
+}
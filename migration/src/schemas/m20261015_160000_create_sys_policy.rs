@@ -0,0 +1,165 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPolicyDocument::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPolicyDocument::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysPolicyDocument::Code).string().not_null())
+                    .col(
+                        ColumnDef::new(SysPolicyDocument::Version)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysPolicyDocument::Title).string().not_null())
+                    .col(ColumnDef::new(SysPolicyDocument::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(SysPolicyDocument::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyDocument::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_policy_document_code_version")
+                    .table(SysPolicyDocument::Table)
+                    .col(SysPolicyDocument::Code)
+                    .col(SysPolicyDocument::Version)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPolicyAcceptance::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::DocumentId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::Code)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::Version)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::UserId)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::Username)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::AcceptedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysPolicyAcceptance::Ip).string().not_null())
+                    .col(
+                        ColumnDef::new(SysPolicyAcceptance::UserAgent)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_policy_acceptance_user_document")
+                    .table(SysPolicyAcceptance::Table)
+                    .col(SysPolicyAcceptance::UserId)
+                    .col(SysPolicyAcceptance::DocumentId)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_policy_acceptance_document_id")
+                    .table(SysPolicyAcceptance::Table)
+                    .col(SysPolicyAcceptance::DocumentId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPolicyAcceptance::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SysPolicyDocument::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysPolicyDocument {
+    Table,
+    Id,
+    Code,
+    Version,
+    Title,
+    Content,
+    CreatedAt,
+    CreatedBy,
+}
+
+#[derive(DeriveIden)]
+pub enum SysPolicyAcceptance {
+    Table,
+    Id,
+    DocumentId,
+    Code,
+    Version,
+    UserId,
+    Username,
+    AcceptedAt,
+    Ip,
+    UserAgent,
+}
@@ -0,0 +1,180 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysNotificationPreference::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::UserId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::EmailEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::InAppEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::MutedCategories)
+                            .text()
+                            .not_null()
+                            .default(""),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::QuietStart)
+                            .time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::QuietEnd)
+                            .time()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::DigestEnabled)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotificationPreference::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysNotification::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysNotification::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysNotification::UserId).string().not_null())
+                    .col(
+                        ColumnDef::new(SysNotification::Category)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotification::Priority)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysNotification::Title).string().not_null())
+                    .col(ColumnDef::new(SysNotification::Content).text().not_null())
+                    .col(
+                        ColumnDef::new(SysNotification::InApp)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotification::EmailStatus)
+                            .string()
+                            .not_null()
+                            .default("skipped"),
+                    )
+                    .col(
+                        ColumnDef::new(SysNotification::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysNotification::ReadAt).timestamp().null())
+                    .col(
+                        ColumnDef::new(SysNotification::EmailedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_notification_user_id_created_at")
+                    .table(SysNotification::Table)
+                    .col(SysNotification::UserId)
+                    .col(SysNotification::CreatedAt)
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_notification_email_status")
+                    .table(SysNotification::Table)
+                    .col(SysNotification::EmailStatus)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysNotification::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SysNotificationPreference::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysNotificationPreference {
+    Table,
+    UserId,
+    EmailEnabled,
+    InAppEnabled,
+    MutedCategories,
+    QuietStart,
+    QuietEnd,
+    DigestEnabled,
+    UpdatedAt,
+}
+
+#[derive(DeriveIden)]
+pub enum SysNotification {
+    Table,
+    Id,
+    UserId,
+    Category,
+    Priority,
+    Title,
+    Content,
+    InApp,
+    EmailStatus,
+    CreatedAt,
+    ReadAt,
+    EmailedAt,
+}
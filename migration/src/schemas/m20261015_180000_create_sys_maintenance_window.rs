@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysMaintenanceWindow::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::Name)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(SysMaintenanceWindow::Message).text().null())
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::ScheduleKind)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysMaintenanceWindow::Cron).string().null())
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::DurationMinutes)
+                            .integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::StartAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::EndAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::SuppressAlerts)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::PauseJobs)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::ShowBanner)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::Enabled)
+                            .boolean()
+                            .not_null()
+                            .default(true),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::UpdatedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysMaintenanceWindow::UpdatedBy)
+                            .string()
+                            .null(),
+                    )
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysMaintenanceWindow::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysMaintenanceWindow {
+    Table,
+    Id,
+    Name,
+    Message,
+    ScheduleKind,
+    Cron,
+    DurationMinutes,
+    StartAt,
+    EndAt,
+    SuppressAlerts,
+    PauseJobs,
+    ShowBanner,
+    Enabled,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
@@ -0,0 +1,93 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysApiUsage::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysApiUsage::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysApiUsage::BucketStart)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysApiUsage::SubjectKind).string().not_null())
+                    .col(ColumnDef::new(SysApiUsage::Subject).string().not_null())
+                    .col(ColumnDef::new(SysApiUsage::Label).string().not_null())
+                    .col(
+                        ColumnDef::new(SysApiUsage::Requests)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SysApiUsage::Errors)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SysApiUsage::UpdatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_sys_api_usage_bucket_subject")
+                    .table(SysApiUsage::Table)
+                    .col(SysApiUsage::BucketStart)
+                    .col(SysApiUsage::SubjectKind)
+                    .col(SysApiUsage::Subject)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_api_usage_subject")
+                    .table(SysApiUsage::Table)
+                    .col(SysApiUsage::SubjectKind)
+                    .col(SysApiUsage::Subject)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysApiUsage::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysApiUsage {
+    Table,
+    Id,
+    BucketStart,
+    SubjectKind,
+    Subject,
+    Label,
+    Requests,
+    Errors,
+    UpdatedAt,
+}
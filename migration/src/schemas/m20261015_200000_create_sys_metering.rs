@@ -0,0 +1,92 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysMetering::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysMetering::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysMetering::Domain).string().not_null())
+                    .col(ColumnDef::new(SysMetering::MeteringDate).date().not_null())
+                    .col(
+                        ColumnDef::new(SysMetering::ActiveUsers)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SysMetering::StorageBytes)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SysMetering::ApiCalls)
+                            .big_integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(
+                        ColumnDef::new(SysMetering::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysMetering::PushedAt).timestamp().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("uk_sys_metering_domain_date")
+                    .table(SysMetering::Table)
+                    .col(SysMetering::Domain)
+                    .col(SysMetering::MeteringDate)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_metering_date")
+                    .table(SysMetering::Table)
+                    .col(SysMetering::MeteringDate)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysMetering::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysMetering {
+    Table,
+    Id,
+    Domain,
+    MeteringDate,
+    ActiveUsers,
+    StorageBytes,
+    ApiCalls,
+    CreatedAt,
+    PushedAt,
+}
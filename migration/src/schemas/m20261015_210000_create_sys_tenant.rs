@@ -0,0 +1,86 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysTenant::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysTenant::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysTenant::Domain)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(SysTenant::DomainId).string().not_null())
+                    .col(ColumnDef::new(SysTenant::Isolation).string().not_null())
+                    .col(ColumnDef::new(SysTenant::DbInstance).string().null())
+                    .col(ColumnDef::new(SysTenant::AdminRoleId).string().not_null())
+                    .col(ColumnDef::new(SysTenant::Status).string().not_null())
+                    .col(ColumnDef::new(SysTenant::SuspendReason).string().null())
+                    .col(
+                        ColumnDef::new(SysTenant::PurgeScheduledAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(ColumnDef::new(SysTenant::PurgeRequestedBy).string().null())
+                    .col(
+                        ColumnDef::new(SysTenant::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysTenant::CreatedBy).string().not_null())
+                    .col(ColumnDef::new(SysTenant::UpdatedAt).timestamp().null())
+                    .col(ColumnDef::new(SysTenant::UpdatedBy).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_tenant_status")
+                    .table(SysTenant::Table)
+                    .col(SysTenant::Status)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysTenant::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysTenant {
+    Table,
+    Id,
+    Domain,
+    DomainId,
+    Isolation,
+    DbInstance,
+    AdminRoleId,
+    Status,
+    SuspendReason,
+    PurgeScheduledAt,
+    PurgeRequestedBy,
+    CreatedAt,
+    CreatedBy,
+    UpdatedAt,
+    UpdatedBy,
+}
@@ -0,0 +1,129 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysRegionVersion::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysRegionVersion::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysRegionVersion::Version)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(SysRegionVersion::Source).string().null())
+                    .col(
+                        ColumnDef::new(SysRegionVersion::RegionCount)
+                            .integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysRegionVersion::Active)
+                            .boolean()
+                            .not_null()
+                            .default(false),
+                    )
+                    .col(
+                        ColumnDef::new(SysRegionVersion::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(
+                        ColumnDef::new(SysRegionVersion::CreatedBy)
+                            .string()
+                            .not_null(),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysRegion::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysRegion::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysRegion::Version).string().not_null())
+                    .col(ColumnDef::new(SysRegion::Code).string().not_null())
+                    .col(ColumnDef::new(SysRegion::Name).string().not_null())
+                    .col(ColumnDef::new(SysRegion::Pid).string().not_null())
+                    .col(ColumnDef::new(SysRegion::Level).small_integer().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_region_version_code")
+                    .table(SysRegion::Table)
+                    .col(SysRegion::Version)
+                    .col(SysRegion::Code)
+                    .unique()
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_region_version_pid")
+                    .table(SysRegion::Table)
+                    .col(SysRegion::Version)
+                    .col(SysRegion::Pid)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysRegion::Table).to_owned())
+            .await?;
+
+        manager
+            .drop_table(Table::drop().table(SysRegionVersion::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysRegionVersion {
+    Table,
+    Id,
+    Version,
+    Source,
+    RegionCount,
+    Active,
+    CreatedAt,
+    CreatedBy,
+}
+
+#[derive(DeriveIden)]
+pub enum SysRegion {
+    Table,
+    Id,
+    Version,
+    Code,
+    Name,
+    Pid,
+    Level,
+}
@@ -0,0 +1,98 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysFileShare::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysFileShare::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysFileShare::FileId).string().not_null())
+                    .col(
+                        ColumnDef::new(SysFileShare::TokenHash)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(ColumnDef::new(SysFileShare::PasswordHash).string().null())
+                    .col(
+                        ColumnDef::new(SysFileShare::ExpiresAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysFileShare::MaxDownloads).integer().null())
+                    .col(
+                        ColumnDef::new(SysFileShare::DownloadCount)
+                            .integer()
+                            .not_null()
+                            .default(0),
+                    )
+                    .col(ColumnDef::new(SysFileShare::RevokedAt).timestamp().null())
+                    .col(ColumnDef::new(SysFileShare::RevokedBy).string().null())
+                    .col(
+                        ColumnDef::new(SysFileShare::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .col(ColumnDef::new(SysFileShare::CreatedBy).string().not_null())
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk_sys_file_share_file_id")
+                            .from(SysFileShare::Table, SysFileShare::FileId)
+                            .to(SysFile::Table, SysFile::Id)
+                            .on_delete(ForeignKeyAction::Cascade),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_file_share_file_id")
+                    .table(SysFileShare::Table)
+                    .col(SysFileShare::FileId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysFileShare::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysFileShare {
+    Table,
+    Id,
+    FileId,
+    TokenHash,
+    PasswordHash,
+    ExpiresAt,
+    MaxDownloads,
+    DownloadCount,
+    RevokedAt,
+    RevokedBy,
+    CreatedAt,
+    CreatedBy,
+}
+
+#[derive(DeriveIden)]
+enum SysFile {
+    Table,
+    Id,
+}
@@ -0,0 +1,63 @@
+use sea_orm_migration::prelude::*;
+
+use super::m20241023_091149_create_sys_operation_log::SysOperationLog;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysOperationLog::Table)
+                    .add_column(
+                        ColumnDef::new(SysOperationLogChain::ChainSeq)
+                            .big_integer()
+                            .null(),
+                    )
+                    .add_column(
+                        ColumnDef::new(SysOperationLogChain::PrevHash)
+                            .string()
+                            .null(),
+                    )
+                    .add_column(ColumnDef::new(SysOperationLogChain::Hash).string().null())
+                    .to_owned(),
+            )
+            .await?;
+
+        // 同一分区的序号唯一，多个实例并发写入同一分区时后写入的一方失败后重试
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_operation_log_chain")
+                    .table(SysOperationLog::Table)
+                    .col(SysOperationLog::Domain)
+                    .col(SysOperationLogChain::ChainSeq)
+                    .unique()
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(SysOperationLog::Table)
+                    .drop_column(SysOperationLogChain::ChainSeq)
+                    .drop_column(SysOperationLogChain::PrevHash)
+                    .drop_column(SysOperationLogChain::Hash)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+enum SysOperationLogChain {
+    ChainSeq,
+    PrevHash,
+    Hash,
+}
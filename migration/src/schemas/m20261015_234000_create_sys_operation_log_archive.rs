@@ -0,0 +1,124 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysOperationLogArchive::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::Domain)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::PeriodStart)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::PeriodEnd)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::Bucket)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::ObjectKey)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::RecordCount)
+                            .big_integer()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::FirstSeq)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::LastSeq)
+                            .big_integer()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::LastHash)
+                            .string()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::Sha256)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::RetainUntil)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysOperationLogArchive::ArchivedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_operation_log_archive_period")
+                    .table(SysOperationLogArchive::Table)
+                    .col(SysOperationLogArchive::Domain)
+                    .col(SysOperationLogArchive::PeriodStart)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(
+                Table::drop()
+                    .table(SysOperationLogArchive::Table)
+                    .to_owned(),
+            )
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysOperationLogArchive {
+    Table,
+    Id,
+    Domain,
+    PeriodStart,
+    PeriodEnd,
+    Bucket,
+    ObjectKey,
+    RecordCount,
+    FirstSeq,
+    LastSeq,
+    LastHash,
+    Sha256,
+    RetainUntil,
+    ArchivedAt,
+}
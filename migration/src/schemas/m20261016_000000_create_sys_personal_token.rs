@@ -0,0 +1,84 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(SysPersonalToken::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(SysPersonalToken::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(SysPersonalToken::UserId).string().not_null())
+                    .col(ColumnDef::new(SysPersonalToken::Name).string().not_null())
+                    .col(
+                        ColumnDef::new(SysPersonalToken::TokenHash)
+                            .string()
+                            .not_null()
+                            .unique_key(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPersonalToken::TokenHint)
+                            .string()
+                            .not_null(),
+                    )
+                    .col(ColumnDef::new(SysPersonalToken::Scopes).string().not_null())
+                    .col(
+                        ColumnDef::new(SysPersonalToken::ExpiresAt)
+                            .timestamp()
+                            .not_null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPersonalToken::LastUsedAt)
+                            .timestamp()
+                            .null(),
+                    )
+                    .col(
+                        ColumnDef::new(SysPersonalToken::CreatedAt)
+                            .timestamp()
+                            .not_null()
+                            .default(Expr::current_timestamp()),
+                    )
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx_sys_personal_token_user_id")
+                    .table(SysPersonalToken::Table)
+                    .col(SysPersonalToken::UserId)
+                    .to_owned(),
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(SysPersonalToken::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(DeriveIden)]
+pub enum SysPersonalToken {
+    Table,
+    Id,
+    UserId,
+    Name,
+    TokenHash,
+    TokenHint,
+    Scopes,
+    ExpiresAt,
+    LastUsedAt,
+    CreatedAt,
+}
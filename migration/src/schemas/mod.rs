@@ -11,3 +11,22 @@ pub mod m20241023_091155_create_sys_organization;
 pub mod m20241023_091159_create_sys_role_menu;
 pub mod m20241023_091204_create_sys_tokens;
 pub mod m20241023_091210_create_sys_user_role;
+pub mod m20261015_100000_create_sys_file_blob;
+pub mod m20261015_100100_create_sys_file;
+pub mod m20261015_110000_alter_sys_file_add_scan_status;
+pub mod m20261015_120000_create_sys_alert_rule;
+pub mod m20261015_130000_create_sys_user_passkey;
+pub mod m20261015_140000_add_data_scope;
+pub mod m20261015_150000_create_sys_access_review;
+pub mod m20261015_160000_create_sys_policy;
+pub mod m20261015_170000_create_sys_notification;
+pub mod m20261015_180000_create_sys_maintenance_window;
+pub mod m20261015_190000_create_sys_api_usage;
+pub mod m20261015_200000_create_sys_metering;
+pub mod m20261015_210000_create_sys_tenant;
+pub mod m20261015_220000_create_sys_region;
+pub mod m20261015_230000_create_sys_file_share;
+pub mod m20261015_233000_alter_sys_operation_log_add_hash_chain;
+pub mod m20261015_234000_create_sys_operation_log_archive;
+pub mod m20261015_235000_create_sys_param;
+pub mod m20261016_000000_create_sys_personal_token;
@@ -1,23 +1,83 @@
 pub use sys_access_key_api::SysAccessKeyApi;
+pub use sys_access_review_api::SysAccessReviewApi;
+pub use sys_alert_rule_api::SysAlertRuleApi;
+pub use sys_api_usage_api::SysApiUsageApi;
 pub use sys_authentication_api::SysAuthenticationApi;
+pub use sys_break_glass_api::SysBreakGlassApi;
+#[cfg(feature = "chaos")]
+pub use sys_chaos_api::SysChaosApi;
+pub use sys_cluster_api::SysClusterApi;
+pub use sys_config_api::SysConfigApi;
+pub use sys_consistency_api::SysConsistencyApi;
+pub use sys_db_pool_api::SysDbPoolApi;
+pub use sys_deletion_api::SysDeletionApi;
+pub use sys_diagnostics_api::SysDiagnosticsApi;
 pub use sys_domain_api::SysDomainApi;
 pub use sys_endpoint_api::SysEndpointApi;
+pub use sys_file_api::SysFileApi;
+pub use sys_instance_api::SysInstanceApi;
 pub use sys_login_log_api::SysLoginLogApi;
+pub use sys_maintenance_window_api::SysMaintenanceWindowApi;
 pub use sys_menu_api::SysMenuApi;
+pub use sys_metering_api::SysMeteringApi;
+pub use sys_migration_api::SysMigrationApi;
+pub use sys_module_api::SysModuleApi;
+pub use sys_notification_api::SysNotificationApi;
 pub use sys_operation_log_api::SysOperationLogApi;
 pub use sys_organization_api::SysOrganizationApi;
+pub use sys_passkey_api::SysPasskeyApi;
+pub use sys_personal_token_api::SysPersonalTokenApi;
+pub use sys_policy_api::SysPolicyApi;
+#[cfg(feature = "profiling")]
+pub use sys_profiling_api::SysProfilingApi;
+pub use sys_recorder_api::SysRecorderApi;
+pub use sys_reference_api::SysReferenceApi;
+pub use sys_region_api::SysRegionApi;
 pub use sys_role_api::SysRoleApi;
 pub use sys_sandbox_api::SysSandboxApi;
+pub use sys_sensitive_operation_api::SysSensitiveOperationApi;
+pub use sys_telemetry_api::SysTelemetryApi;
+pub use sys_tenant_api::SysTenantApi;
 pub use sys_user_api::SysUserApi;
 
 mod sys_access_key_api;
+mod sys_access_review_api;
+mod sys_alert_rule_api;
+mod sys_api_usage_api;
 mod sys_authentication_api;
+mod sys_break_glass_api;
+#[cfg(feature = "chaos")]
+mod sys_chaos_api;
+mod sys_cluster_api;
+mod sys_config_api;
+mod sys_consistency_api;
+mod sys_db_pool_api;
+mod sys_deletion_api;
+mod sys_diagnostics_api;
 mod sys_domain_api;
 mod sys_endpoint_api;
+mod sys_file_api;
+mod sys_instance_api;
 mod sys_login_log_api;
+mod sys_maintenance_window_api;
 mod sys_menu_api;
+mod sys_metering_api;
+mod sys_migration_api;
+mod sys_module_api;
+mod sys_notification_api;
 mod sys_operation_log_api;
 mod sys_organization_api;
+mod sys_passkey_api;
+mod sys_personal_token_api;
+mod sys_policy_api;
+#[cfg(feature = "profiling")]
+mod sys_profiling_api;
+mod sys_recorder_api;
+mod sys_reference_api;
+mod sys_region_api;
 mod sys_role_api;
 mod sys_sandbox_api;
+mod sys_sensitive_operation_api;
+mod sys_telemetry_api;
+mod sys_tenant_api;
 mod sys_user_api;
@@ -4,7 +4,7 @@ use axum::{
     extract::{Path, Query},
     Extension,
 };
-use server_core::web::{error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm};
+use server_core::web::{error::AppError, page::PageResult, res::Res, validator::ValidatedForm};
 use server_service::admin::{
     AccessKeyPageRequest, CreateAccessKeyInput, SysAccessKeyModel, SysAccessKeyService,
     TAccessKeyService,
@@ -16,7 +16,7 @@ impl SysAccessKeyApi {
     pub async fn get_paginated_access_keys(
         Query(params): Query<AccessKeyPageRequest>,
         Extension(service): Extension<Arc<SysAccessKeyService>>,
-    ) -> Result<Res<PaginatedData<SysAccessKeyModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysAccessKeyModel>>, AppError> {
         service
             .find_paginated_access_keys(params)
             .await
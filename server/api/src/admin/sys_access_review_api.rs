@@ -0,0 +1,76 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    AccessReviewItemPageRequest, AccessReviewPageRequest, AccessReviewReport,
+    DecideAccessReviewInput, StartAccessReviewInput, SysAccessReviewItemModel,
+    SysAccessReviewModel, SysAccessReviewService, TAccessReviewService,
+};
+
+pub struct SysAccessReviewApi;
+
+impl SysAccessReviewApi {
+    pub async fn get_paginated_reviews(
+        Query(params): Query<AccessReviewPageRequest>,
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+    ) -> Result<Res<PageResult<SysAccessReviewModel>>, AppError> {
+        service
+            .find_paginated_reviews(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn start_review(
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<StartAccessReviewInput>,
+    ) -> Result<Res<SysAccessReviewModel>, AppError> {
+        service.start_review(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_report(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<AccessReviewReport>, AppError> {
+        service.get_report(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_paginated_items(
+        Path(id): Path<String>,
+        Query(params): Query<AccessReviewItemPageRequest>,
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<PageResult<SysAccessReviewItemModel>>, AppError> {
+        service
+            .find_paginated_items(&id, params, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn decide_item(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<DecideAccessReviewInput>,
+    ) -> Result<Res<SysAccessReviewItemModel>, AppError> {
+        service
+            .decide_item(&id, input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn close_review(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAccessReviewService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SysAccessReviewModel>, AppError> {
+        service.close_review(&id, &user).await.map(Res::new_data)
+    }
+}
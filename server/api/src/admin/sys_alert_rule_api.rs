@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    AlertRulePageRequest, CreateAlertRuleInput, SysAlertRuleModel, SysAlertRuleService,
+    TAlertRuleService, UpdateAlertRuleInput,
+};
+
+pub struct SysAlertRuleApi;
+
+impl SysAlertRuleApi {
+    pub async fn get_paginated_rules(
+        Query(params): Query<AlertRulePageRequest>,
+        Extension(service): Extension<Arc<SysAlertRuleService>>,
+    ) -> Result<Res<PageResult<SysAlertRuleModel>>, AppError> {
+        service
+            .find_paginated_rules(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_rule(
+        Extension(service): Extension<Arc<SysAlertRuleService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateAlertRuleInput>,
+    ) -> Result<Res<SysAlertRuleModel>, AppError> {
+        service.create_rule(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_rule(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAlertRuleService>>,
+    ) -> Result<Res<SysAlertRuleModel>, AppError> {
+        service.get_rule(&id).await.map(Res::new_data)
+    }
+
+    pub async fn update_rule(
+        Extension(service): Extension<Arc<SysAlertRuleService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateAlertRuleInput>,
+    ) -> Result<Res<SysAlertRuleModel>, AppError> {
+        service.update_rule(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn delete_rule(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysAlertRuleService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.delete_rule(&id, &user).await.map(Res::new_data)
+    }
+}
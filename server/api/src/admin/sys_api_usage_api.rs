@@ -0,0 +1,35 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, Extension};
+use server_core::web::{error::AppError, page::PageResult, res::Res};
+use server_service::admin::{
+    ApiUsagePageRequest, ApiUsageSummary, ApiUsageTrendRequest, SysApiUsageModel,
+    SysApiUsageService, TApiUsageService,
+};
+
+pub struct SysApiUsageApi;
+
+impl SysApiUsageApi {
+    pub async fn get_paginated_usage(
+        Query(params): Query<ApiUsagePageRequest>,
+        Extension(service): Extension<Arc<SysApiUsageService>>,
+    ) -> Result<Res<PageResult<ApiUsageSummary>>, AppError> {
+        service
+            .find_paginated_usage(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_trend(
+        Query(params): Query<ApiUsageTrendRequest>,
+        Extension(service): Extension<Arc<SysApiUsageService>>,
+    ) -> Result<Res<Vec<SysApiUsageModel>>, AppError> {
+        service.get_trend(params).await.map(Res::new_data)
+    }
+
+    pub async fn get_current_usage(
+        Extension(service): Extension<Arc<SysApiUsageService>>,
+    ) -> Result<Res<Vec<ApiUsageSummary>>, AppError> {
+        service.get_current_usage().await.map(Res::new_data)
+    }
+}
@@ -9,14 +9,49 @@ use server_core::web::{
 use server_service::{
     admin::{
         dto::sys_auth_dto::LoginContext, AssignPermissionDto, AssignRouteDto, AuthOutput,
-        LoginInput, SysAuthService, SysAuthorizationService, TAuthService, TAuthorizationService,
-        UserInfoOutput, UserRoute,
+        FinishPasskeyInput, LoginInput, PasskeyChallengeOutput, ReauthInput, RefreshTokenInput,
+        StartPasskeyLoginInput, SysAuthService, SysAuthorizationService, TAuthService,
+        TAuthorizationService, UserInfoOutput, UserRoute,
     },
     Audience,
 };
 
 pub struct SysAuthenticationApi;
 
+/// 登录接口固定使用内置域
+const LOGIN_DOMAIN: &str = "built-in";
+
+pub(crate) fn login_context(
+    addr: SocketAddr,
+    headers: &HeaderMap,
+    user_agent: &UserAgent,
+    request_id: &RequestId,
+    login_type: &str,
+) -> LoginContext {
+    let client_ip = {
+        let header_ip = ClientIp::get_real_ip(headers);
+        if header_ip == "unknown" {
+            addr.ip().to_string()
+        } else {
+            header_ip
+        }
+    };
+
+    let address = xdb::searcher::search_by_ip(client_ip.as_str())
+        .unwrap_or_else(|_| "Unknown Location".to_string());
+
+    LoginContext {
+        client_ip,
+        client_port: Some(addr.port() as i32),
+        address,
+        user_agent: user_agent.as_str().to_string(),
+        request_id: request_id.to_string(),
+        audience: Audience::ManagementPlatform,
+        login_type: login_type.to_string(),
+        domain: LOGIN_DOMAIN.to_string(),
+    }
+}
+
 impl SysAuthenticationApi {
     pub async fn login_handler(
         ConnectInfo(addr): ConnectInfo<SocketAddr>,
@@ -26,28 +61,7 @@ impl SysAuthenticationApi {
         Extension(service): Extension<Arc<SysAuthService>>,
         ValidatedForm(input): ValidatedForm<LoginInput>,
     ) -> Result<Res<AuthOutput>, AppError> {
-        let client_ip = {
-            let header_ip = ClientIp::get_real_ip(&headers);
-            if header_ip == "unknown" {
-                addr.ip().to_string()
-            } else {
-                header_ip
-            }
-        };
-
-        let address = xdb::searcher::search_by_ip(client_ip.as_str())
-            .unwrap_or_else(|_| "Unknown Location".to_string());
-
-        let login_context = LoginContext {
-            client_ip,
-            client_port: Some(addr.port() as i32),
-            address,
-            user_agent: user_agent.as_str().to_string(),
-            request_id: request_id.to_string(),
-            audience: Audience::ManagementPlatform,
-            login_type: "PC".to_string(),
-            domain: "built-in".to_string(),
-        };
+        let login_context = login_context(addr, &headers, &user_agent, &request_id, "PC");
 
         service
             .pwd_login(input, login_context)
@@ -55,6 +69,76 @@ impl SysAuthenticationApi {
             .map(Res::new_data)
     }
 
+    /// 重新认证
+    ///
+    /// 敏感接口返回 `stepUpRequired` 时，前端弹窗让用户输入密码后调用，
+    /// 用返回的新令牌替换当前令牌再重试原请求。要求通行密钥时改走通行密钥登录流程。
+    pub async fn reauth_handler(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(user): Extension<User>,
+        Extension(service): Extension<Arc<SysAuthService>>,
+        ValidatedForm(input): ValidatedForm<ReauthInput>,
+    ) -> Result<Res<AuthOutput>, AppError> {
+        let login_context = login_context(addr, &headers, &user_agent, &request_id, "Reauth");
+
+        service
+            .reauthenticate(&user, input, login_context)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 刷新令牌
+    ///
+    /// 访问令牌过期后用登录时返回的 `refreshToken` 换发新的令牌对，每个刷新令牌只能使用一次。
+    pub async fn refresh_token_handler(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(service): Extension<Arc<SysAuthService>>,
+        ValidatedForm(input): ValidatedForm<RefreshTokenInput>,
+    ) -> Result<Res<AuthOutput>, AppError> {
+        let login_context = login_context(addr, &headers, &user_agent, &request_id, "Refresh");
+
+        service
+            .refresh_token(input, login_context)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 开始通行密钥登录
+    ///
+    /// 密码登录返回 `passkeyTicket` 时提交该票据完成二次验证；启用免密登录时也可只提交用户名。
+    pub async fn start_passkey_login(
+        Extension(service): Extension<Arc<SysAuthService>>,
+        ValidatedForm(input): ValidatedForm<StartPasskeyLoginInput>,
+    ) -> Result<Res<PasskeyChallengeOutput>, AppError> {
+        service
+            .start_passkey_login(input, LOGIN_DOMAIN)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 提交浏览器返回的断言，验证通过后签发令牌
+    pub async fn finish_passkey_login(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(service): Extension<Arc<SysAuthService>>,
+        ValidatedForm(input): ValidatedForm<FinishPasskeyInput>,
+    ) -> Result<Res<AuthOutput>, AppError> {
+        let login_context = login_context(addr, &headers, &user_agent, &request_id, "Passkey");
+
+        service
+            .finish_passkey_login(input, login_context)
+            .await
+            .map(Res::new_data)
+    }
+
     pub async fn get_user_info(
         Extension(user): Extension<User>,
     ) -> Result<Res<UserInfoOutput>, AppError> {
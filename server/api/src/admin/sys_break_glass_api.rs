@@ -0,0 +1,49 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, Path},
+    http::HeaderMap,
+    Extension,
+};
+use axum_extra::{headers::UserAgent, TypedHeader};
+use server_core::web::{
+    auth::User, error::AppError, res::Res, validator::ValidatedForm, RequestId,
+};
+use server_service::admin::{
+    BreakGlassInput, BreakGlassOutput, BreakGlassSession, SysBreakGlassService, TBreakGlassService,
+};
+
+use super::sys_authentication_api::login_context;
+
+pub struct SysBreakGlassApi;
+
+impl SysBreakGlassApi {
+    /// 使用紧急凭证，返回的令牌在会话到期前有效且不能续期
+    pub async fn activate(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        TypedHeader(user_agent): TypedHeader<UserAgent>,
+        Extension(request_id): Extension<RequestId>,
+        Extension(service): Extension<Arc<SysBreakGlassService>>,
+        ValidatedForm(input): ValidatedForm<BreakGlassInput>,
+    ) -> Result<Res<BreakGlassOutput>, AppError> {
+        let context = login_context(addr, &headers, &user_agent, &request_id, "BreakGlass");
+
+        service.activate(input, context).await.map(Res::new_data)
+    }
+
+    pub async fn get_session(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysBreakGlassService>>,
+    ) -> Result<Res<BreakGlassSession>, AppError> {
+        service.get_session(&id).await.map(Res::new_data)
+    }
+
+    pub async fn end_session(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysBreakGlassService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.end_session(&id, &user).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,44 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{
+    auth::User,
+    chaos::{FaultRule, FaultSpec},
+    error::AppError,
+    res::Res,
+    validator::ValidatedForm,
+};
+use server_service::admin::{SysChaosService, TChaosService};
+
+pub struct SysChaosApi;
+
+impl SysChaosApi {
+    pub async fn get_faults(
+        Extension(service): Extension<Arc<SysChaosService>>,
+    ) -> Result<Res<Vec<FaultRule>>, AppError> {
+        service.list_faults().await.map(Res::new_data)
+    }
+
+    pub async fn inject_fault(
+        Extension(service): Extension<Arc<SysChaosService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(spec): ValidatedForm<FaultSpec>,
+    ) -> Result<Res<FaultRule>, AppError> {
+        service.inject(spec, &user).await.map(Res::new_data)
+    }
+
+    pub async fn remove_fault(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysChaosService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.remove(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn clear_faults(
+        Extension(service): Extension<Arc<SysChaosService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<usize>, AppError> {
+        service.clear(&user).await.map(Res::new_data)
+    }
+}
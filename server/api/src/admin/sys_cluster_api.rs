@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{ClusterMember, SysClusterService, TClusterService};
+
+pub struct SysClusterApi;
+
+impl SysClusterApi {
+    pub async fn get_members(
+        Extension(service): Extension<Arc<SysClusterService>>,
+    ) -> Result<Res<Vec<ClusterMember>>, AppError> {
+        service.list_members().await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,47 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    CanaryConfigInput, StageConfigInput, StagedConfigStatus, SysConfigService, TConfigService,
+};
+
+pub struct SysConfigApi;
+
+impl SysConfigApi {
+    pub async fn get_staged_config(
+        Extension(service): Extension<Arc<SysConfigService>>,
+    ) -> Result<Res<Option<StagedConfigStatus>>, AppError> {
+        service.get_staged_config().await.map(Res::new_data)
+    }
+
+    pub async fn stage_config(
+        Extension(service): Extension<Arc<SysConfigService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<StageConfigInput>,
+    ) -> Result<Res<StagedConfigStatus>, AppError> {
+        service.stage_config(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn apply_canary(
+        Extension(service): Extension<Arc<SysConfigService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CanaryConfigInput>,
+    ) -> Result<Res<StagedConfigStatus>, AppError> {
+        service.apply_canary(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn promote_config(
+        Extension(service): Extension<Arc<SysConfigService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<StagedConfigStatus>, AppError> {
+        service.promote_config(&user).await.map(Res::new_data)
+    }
+
+    pub async fn rollback_config(
+        Extension(service): Extension<Arc<SysConfigService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<StagedConfigStatus>, AppError> {
+        service.rollback_config(&user).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use axum::{extract::Query, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res};
+use server_service::admin::{
+    ConsistencyCheckInput, ConsistencyReport, SysConsistencyService, TConsistencyService,
+};
+
+pub struct SysConsistencyApi;
+
+impl SysConsistencyApi {
+    pub async fn run_check(
+        Extension(service): Extension<Arc<SysConsistencyService>>,
+        Extension(user): Extension<User>,
+        Query(input): Query<ConsistencyCheckInput>,
+    ) -> Result<Res<ConsistencyReport>, AppError> {
+        service.run_check(input, &user).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{DbPoolInfo, ResizeDbPoolInput, SysDbPoolService, TDbPoolService};
+
+pub struct SysDbPoolApi;
+
+impl SysDbPoolApi {
+    pub async fn get_pools(
+        Extension(service): Extension<Arc<SysDbPoolService>>,
+    ) -> Result<Res<Vec<DbPoolInfo>>, AppError> {
+        service.list_pools().await.map(Res::new_data)
+    }
+
+    pub async fn resize_pool(
+        Path(name): Path<String>,
+        Extension(service): Extension<Arc<SysDbPoolService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ResizeDbPoolInput>,
+    ) -> Result<Res<DbPoolInfo>, AppError> {
+        service
+            .resize_pool(&name, input, &user)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{
+    DeleteWithCascadeInput, DeletionPreview, DeletionTarget, SysDeletionService, TDeletionService,
+};
+
+pub struct SysDeletionApi;
+
+impl SysDeletionApi {
+    pub async fn preview_deletion(
+        Path((target, id)): Path<(DeletionTarget, String)>,
+        Query(input): Query<DeleteWithCascadeInput>,
+        Extension(service): Extension<Arc<SysDeletionService>>,
+    ) -> Result<Res<DeletionPreview>, AppError> {
+        service.preview(target, &id, input).await.map(Res::new_data)
+    }
+
+    pub async fn delete_with_cascade(
+        Path((target, id)): Path<(DeletionTarget, String)>,
+        Query(input): Query<DeleteWithCascadeInput>,
+        Extension(service): Extension<Arc<SysDeletionService>>,
+    ) -> Result<Res<DeletionPreview>, AppError> {
+        service.delete(target, &id, input).await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,16 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{auth::User, error::AppError, res::Res};
+use server_service::admin::{DiagnosticsBundle, SysDiagnosticsService, TDiagnosticsService};
+
+pub struct SysDiagnosticsApi;
+
+impl SysDiagnosticsApi {
+    pub async fn generate_bundle(
+        Extension(service): Extension<Arc<SysDiagnosticsService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<DiagnosticsBundle>, AppError> {
+        service.generate_bundle(&user).await.map(Res::new_data)
+    }
+}
@@ -4,7 +4,7 @@ use axum::{
     extract::{Path, Query},
     Extension,
 };
-use server_core::web::{error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm};
+use server_core::web::{error::AppError, page::PageResult, res::Res, validator::ValidatedForm};
 use server_service::admin::{
     CreateDomainInput, DomainPageRequest, SysDomainModel, SysDomainService, TDomainService,
     UpdateDomainInput,
@@ -16,7 +16,7 @@ impl SysDomainApi {
     pub async fn get_paginated_domains(
         Query(params): Query<DomainPageRequest>,
         Extension(service): Extension<Arc<SysDomainService>>,
-    ) -> Result<Res<PaginatedData<SysDomainModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysDomainModel>>, AppError> {
         service
             .find_paginated_domains(params)
             .await
@@ -5,7 +5,7 @@ use axum::{
     Extension,
 };
 use axum_casbin::{casbin::MgmtApi, CasbinAxumLayer};
-use server_core::web::{auth::User, error::AppError, page::PaginatedData, res::Res};
+use server_core::web::{auth::User, error::AppError, page::PageResult, res::Res};
 use server_service::admin::{
     EndpointPageRequest, EndpointTree, SysEndpointModel, SysEndpointService, TEndpointService,
 };
@@ -16,7 +16,7 @@ impl SysEndpointApi {
     pub async fn get_paginated_endpoints(
         Query(params): Query<EndpointPageRequest>,
         Extension(service): Extension<Arc<SysEndpointService>>,
-    ) -> Result<Res<PaginatedData<SysEndpointModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysEndpointModel>>, AppError> {
         service
             .find_paginated_endpoints(params)
             .await
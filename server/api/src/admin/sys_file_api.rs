@@ -0,0 +1,208 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, Multipart, Path, Query},
+    http::{header, HeaderMap},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use server_core::web::{
+    auth::User,
+    error::{AppError, ErrorCategory},
+    page::PageResult,
+    res::Res,
+    util::ClientIp,
+    validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateFileShareInput, DownloadSharedFileInput, FileDownload, FilePageRequest, FileShareLink,
+    FileUrl, ReviewFileInput, SharedFileInfo, SysFileModel, SysFileService, SysFileShareModel,
+    TFileService, UploadFileInput,
+};
+
+pub struct SysFileApi;
+
+/// 以附件形式返回文件内容
+fn attachment_response(download: FileDownload) -> Response {
+    let disposition = format!(
+        "attachment; filename*=UTF-8''{}",
+        urlencoding::encode(&download.file.file_name)
+    );
+
+    (
+        [
+            (header::CONTENT_TYPE, download.file.content_type),
+            (header::CONTENT_DISPOSITION, disposition),
+        ],
+        download.data,
+    )
+        .into_response()
+}
+
+impl SysFileApi {
+    pub async fn get_paginated_files(
+        Query(params): Query<FilePageRequest>,
+        Extension(service): Extension<Arc<SysFileService>>,
+    ) -> Result<Res<PageResult<SysFileModel>>, AppError> {
+        service
+            .find_paginated_files(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn upload_file(
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+        mut multipart: Multipart,
+    ) -> Result<Res<SysFileModel>, AppError> {
+        while let Some(field) = multipart.next_field().await.map_err(|e| {
+            AppError::new(
+                ErrorCategory::Validation,
+                e.status().as_u16(),
+                e.body_text(),
+            )
+        })? {
+            if field.name() != Some("file") {
+                continue;
+            }
+
+            let file_name = field.file_name().unwrap_or("unnamed").to_string();
+            let content_type = field
+                .content_type()
+                .unwrap_or("application/octet-stream")
+                .to_string();
+            let data = field.bytes().await.map_err(|e| {
+                AppError::new(
+                    ErrorCategory::Validation,
+                    e.status().as_u16(),
+                    e.body_text(),
+                )
+            })?;
+
+            let input = UploadFileInput {
+                domain: user.domain(),
+                file_name,
+                content_type,
+                data: data.to_vec(),
+                created_by: user.username(),
+            };
+            return service.upload_file(input).await.map(Res::new_data);
+        }
+
+        Err(AppError::validation("Missing multipart field 'file'"))
+    }
+
+    pub async fn download_file(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Response, AppError> {
+        let download = service.download_file(&id, &user).await?;
+        Ok(attachment_response(download))
+    }
+
+    pub async fn get_file_url(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<FileUrl>, AppError> {
+        service.get_file_url(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn delete_file(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+    ) -> Result<Res<()>, AppError> {
+        service.delete_file(&id).await.map(Res::new_data)
+    }
+
+    pub async fn get_paginated_quarantined_files(
+        Query(params): Query<FilePageRequest>,
+        Extension(service): Extension<Arc<SysFileService>>,
+    ) -> Result<Res<PageResult<SysFileModel>>, AppError> {
+        service
+            .find_paginated_quarantined_files(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn release_file(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ReviewFileInput>,
+    ) -> Result<Res<SysFileModel>, AppError> {
+        service
+            .release_file(&id, &user, input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn purge_file(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ReviewFileInput>,
+    ) -> Result<Res<SysFileModel>, AppError> {
+        service
+            .purge_file(&id, &user, input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_share_links(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+    ) -> Result<Res<Vec<SysFileShareModel>>, AppError> {
+        service.find_share_links(&id).await.map(Res::new_data)
+    }
+
+    pub async fn create_share_link(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateFileShareInput>,
+    ) -> Result<Res<FileShareLink>, AppError> {
+        service
+            .create_share_link(&id, &user, input)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn revoke_share_link(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SysFileShareModel>, AppError> {
+        service
+            .revoke_share_link(&id, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 分享链接的文件信息，免登录访问
+    pub async fn get_shared_file(
+        Path(token): Path<String>,
+        Extension(service): Extension<Arc<SysFileService>>,
+    ) -> Result<Res<SharedFileInfo>, AppError> {
+        service.get_shared_file(&token).await.map(Res::new_data)
+    }
+
+    /// 通过分享链接下载文件，免登录访问，密码放在请求体中避免出现在访问日志
+    pub async fn download_shared_file(
+        Path(token): Path<String>,
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        Extension(service): Extension<Arc<SysFileService>>,
+        ValidatedForm(input): ValidatedForm<DownloadSharedFileInput>,
+    ) -> Result<Response, AppError> {
+        let client_ip = match ClientIp::get_real_ip(&headers) {
+            ip if ip == "unknown" => addr.ip().to_string(),
+            ip => ip,
+        };
+        let download = service
+            .download_shared_file(&token, input, &client_ip)
+            .await?;
+        Ok(attachment_response(download))
+    }
+}
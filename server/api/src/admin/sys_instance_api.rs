@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+    Extension, Json,
+};
+use server_core::web::{admission::AdmissionSnapshot, auth::User, error::AppError, res::Res};
+use server_service::admin::{
+    DrainStatus, RouteMiddlewareStack, SysInstanceService, TInstanceService,
+};
+
+pub struct SysInstanceApi;
+
+impl SysInstanceApi {
+    pub async fn get_admission_status(
+        Extension(service): Extension<Arc<SysInstanceService>>,
+    ) -> Result<Res<Option<AdmissionSnapshot>>, AppError> {
+        service.admission_status().await.map(Res::new_data)
+    }
+
+    pub async fn drain(
+        Extension(service): Extension<Arc<SysInstanceService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<DrainStatus>, AppError> {
+        service.drain(&user).await.map(Res::new_data)
+    }
+
+    pub async fn get_middleware_stack(
+        Extension(service): Extension<Arc<SysInstanceService>>,
+    ) -> Result<Res<Vec<RouteMiddlewareStack>>, AppError> {
+        service.middleware_stack().await.map(Res::new_data)
+    }
+
+    pub async fn get_openapi_document(
+        Extension(service): Extension<Arc<SysInstanceService>>,
+    ) -> Result<Json<serde_json::Value>, AppError> {
+        service.openapi_document().await.map(Json)
+    }
+
+    /// 存活检查，进程能响应即返回 200
+    pub async fn liveness() -> StatusCode {
+        StatusCode::OK
+    }
+
+    /// 就绪检查，排空期间返回 503 以便负载均衡摘除实例
+    pub async fn readiness(
+        Extension(service): Extension<Arc<SysInstanceService>>,
+    ) -> Result<Response, AppError> {
+        let status = service.drain_status().await?;
+        let code = if status.draining {
+            StatusCode::SERVICE_UNAVAILABLE
+        } else {
+            StatusCode::OK
+        };
+        Ok((code, Res::new_data(status)).into_response())
+    }
+}
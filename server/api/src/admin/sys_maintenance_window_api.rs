@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    CreateMaintenanceWindowInput, MaintenanceBanner, MaintenanceWindowPageRequest,
+    SysMaintenanceWindowModel, SysMaintenanceWindowService, TMaintenanceWindowService,
+    UpdateMaintenanceWindowInput,
+};
+
+pub struct SysMaintenanceWindowApi;
+
+impl SysMaintenanceWindowApi {
+    pub async fn get_paginated_windows(
+        Query(params): Query<MaintenanceWindowPageRequest>,
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+    ) -> Result<Res<PageResult<SysMaintenanceWindowModel>>, AppError> {
+        service
+            .find_paginated_windows(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn create_window(
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreateMaintenanceWindowInput>,
+    ) -> Result<Res<SysMaintenanceWindowModel>, AppError> {
+        service.create_window(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_window(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+    ) -> Result<Res<SysMaintenanceWindowModel>, AppError> {
+        service.get_window(&id).await.map(Res::new_data)
+    }
+
+    pub async fn update_window(
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateMaintenanceWindowInput>,
+    ) -> Result<Res<SysMaintenanceWindowModel>, AppError> {
+        service.update_window(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn delete_window(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.delete_window(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_banners(
+        Extension(service): Extension<Arc<SysMaintenanceWindowService>>,
+    ) -> Result<Res<Vec<MaintenanceBanner>>, AppError> {
+        service.get_banners().await.map(Res::new_data)
+    }
+}
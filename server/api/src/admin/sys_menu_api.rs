@@ -1,10 +1,13 @@
 use std::sync::Arc;
 
-use axum::{extract::Path, Extension};
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
 use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
 use server_service::admin::{
-    CreateMenuInput, MenuRoute, MenuTree, SysMenuModel, SysMenuService, TMenuService,
-    UpdateMenuInput,
+    CreateMenuInput, MenuRoute, MenuTree, MoveTreeNodeInput, SubtreeRequest, SysMenuModel,
+    SysMenuService, TMenuService, TreeNode, UpdateMenuInput,
 };
 
 pub struct SysMenuApi;
@@ -70,4 +73,30 @@ impl SysMenuApi {
             .await
             .map(Res::new_data)
     }
+
+    pub async fn get_menu_children(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysMenuService>>,
+    ) -> Result<Res<Vec<TreeNode<SysMenuModel>>>, AppError> {
+        service.get_menu_children(&id).await.map(Res::new_data)
+    }
+
+    pub async fn get_menu_subtree(
+        Path(id): Path<String>,
+        Query(params): Query<SubtreeRequest>,
+        Extension(service): Extension<Arc<SysMenuService>>,
+    ) -> Result<Res<TreeNode<SysMenuModel>>, AppError> {
+        service
+            .get_menu_subtree(&id, params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn move_menu(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysMenuService>>,
+        ValidatedForm(input): ValidatedForm<MoveTreeNodeInput>,
+    ) -> Result<Res<()>, AppError> {
+        service.move_menu(&id, input).await.map(Res::new_data)
+    }
 }
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Query,
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError, page::PageResult, res::Res};
+use server_service::admin::{
+    MeteringExportRequest, MeteringPageRequest, SysMeteringModel, SysMeteringService,
+    TMeteringService, WATERMARK_HEADER,
+};
+
+pub struct SysMeteringApi;
+
+impl SysMeteringApi {
+    pub async fn get_paginated_metering(
+        Query(params): Query<MeteringPageRequest>,
+        Extension(service): Extension<Arc<SysMeteringService>>,
+    ) -> Result<Res<PageResult<SysMeteringModel>>, AppError> {
+        service
+            .find_paginated_metering(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn export_metering(
+        Query(params): Query<MeteringExportRequest>,
+        Extension(service): Extension<Arc<SysMeteringService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Response, AppError> {
+        let export = service.export_csv(params, &user).await?;
+        let disposition = "attachment; filename=\"metering.csv\"".to_string();
+
+        let mut response = (
+            [
+                (header::CONTENT_TYPE, "text/csv; charset=utf-8".to_string()),
+                (header::CONTENT_DISPOSITION, disposition),
+            ],
+            export.csv,
+        )
+            .into_response();
+        if let Some(value) = export
+            .watermark_id
+            .and_then(|id| HeaderValue::from_str(&id).ok())
+        {
+            response.headers_mut().insert(WATERMARK_HEADER, value);
+        }
+        Ok(response)
+    }
+}
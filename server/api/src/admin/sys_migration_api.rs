@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{MigrationPlan, SysMigrationService, TMigrationService};
+
+pub struct SysMigrationApi;
+
+impl SysMigrationApi {
+    pub async fn get_migration_plan(
+        Extension(service): Extension<Arc<SysMigrationService>>,
+    ) -> Result<Res<MigrationPlan>, AppError> {
+        service.plan_migrations().await.map(Res::new_data)
+    }
+}
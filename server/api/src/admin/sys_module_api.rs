@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{ModuleStatus, SysModuleService, TModuleService, UpdateModuleInput};
+
+pub struct SysModuleApi;
+
+impl SysModuleApi {
+    pub async fn get_modules(
+        Extension(service): Extension<Arc<SysModuleService>>,
+    ) -> Result<Res<Vec<ModuleStatus>>, AppError> {
+        service.list_modules().await.map(Res::new_data)
+    }
+
+    pub async fn update_module(
+        Path(code): Path<String>,
+        Extension(service): Extension<Arc<SysModuleService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateModuleInput>,
+    ) -> Result<Res<ModuleStatus>, AppError> {
+        service
+            .update_module(&code, input, &user)
+            .await
+            .map(Res::new_data)
+    }
+}
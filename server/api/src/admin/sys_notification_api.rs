@@ -0,0 +1,61 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    NotificationPageRequest, NotificationPreferenceOutput, SysNotificationModel,
+    SysNotificationService, TNotificationService, UpdateNotificationPreferenceInput,
+};
+
+pub struct SysNotificationApi;
+
+impl SysNotificationApi {
+    pub async fn get_paginated_notifications(
+        Query(params): Query<NotificationPageRequest>,
+        Extension(service): Extension<Arc<SysNotificationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<PageResult<SysNotificationModel>>, AppError> {
+        service
+            .find_paginated_notifications(params, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn mark_read(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysNotificationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.mark_read(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn mark_all_read(
+        Extension(service): Extension<Arc<SysNotificationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.mark_all_read(&user).await.map(Res::new_data)
+    }
+
+    pub async fn get_preference(
+        Extension(service): Extension<Arc<SysNotificationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<NotificationPreferenceOutput>, AppError> {
+        service.get_preference(&user).await.map(Res::new_data)
+    }
+
+    pub async fn update_preference(
+        Extension(service): Extension<Arc<SysNotificationService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<UpdateNotificationPreferenceInput>,
+    ) -> Result<Res<NotificationPreferenceOutput>, AppError> {
+        service
+            .update_preference(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+}
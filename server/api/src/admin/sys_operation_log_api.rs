@@ -1,9 +1,11 @@
 use std::sync::Arc;
 
 use axum::extract::{Extension, Query};
-use server_core::web::{error::AppError, page::PaginatedData, res::Res};
+use server_core::web::{error::AppError, page::PageResult, res::Res};
 use server_service::admin::{
-    OperationLogPageRequest, SysOperationLogModel, SysOperationLogService, TOperationLogService,
+    AuditChainReport, AuditChainVerifyRequest, OperationLogHistoryRequest, OperationLogPageRequest,
+    SysOperationLogArchiveModel, SysOperationLogModel, SysOperationLogService,
+    TOperationLogService,
 };
 
 pub struct SysOperationLogApi;
@@ -12,10 +14,36 @@ impl SysOperationLogApi {
     pub async fn get_paginated_operation_logs(
         Query(params): Query<OperationLogPageRequest>,
         Extension(service): Extension<Arc<SysOperationLogService>>,
-    ) -> Result<Res<PaginatedData<SysOperationLogModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysOperationLogModel>>, AppError> {
         service
             .find_paginated_operation_logs(params)
             .await
             .map(Res::new_data)
     }
+
+    pub async fn get_operation_log_history(
+        Query(params): Query<OperationLogHistoryRequest>,
+        Extension(service): Extension<Arc<SysOperationLogService>>,
+    ) -> Result<Res<PageResult<SysOperationLogModel>>, AppError> {
+        service
+            .find_operation_log_history(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_operation_log_archives(
+        Extension(service): Extension<Arc<SysOperationLogService>>,
+    ) -> Result<Res<Vec<SysOperationLogArchiveModel>>, AppError> {
+        service
+            .find_operation_log_archives()
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn verify_audit_chain(
+        Query(params): Query<AuditChainVerifyRequest>,
+        Extension(service): Extension<Arc<SysOperationLogService>>,
+    ) -> Result<Res<AuditChainReport>, AppError> {
+        service.verify_audit_chain(params).await.map(Res::new_data)
+    }
 }
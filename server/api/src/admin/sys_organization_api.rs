@@ -1,9 +1,10 @@
 use std::sync::Arc;
 
-use axum::extract::{Extension, Query};
-use server_core::web::{error::AppError, page::PaginatedData, res::Res};
+use axum::extract::{Extension, Path, Query};
+use server_core::web::{error::AppError, page::PageResult, res::Res, validator::ValidatedForm};
 use server_service::admin::{
-    OrganizationPageRequest, SysOrganizationModel, SysOrganizationService, TOrganizationService,
+    MoveTreeNodeInput, OrganizationPageRequest, SubtreeRequest, SysOrganizationModel,
+    SysOrganizationService, TOrganizationService, TreeNode,
 };
 
 pub struct SysOrganizationApi;
@@ -12,10 +13,42 @@ impl SysOrganizationApi {
     pub async fn get_paginated_organizations(
         Query(params): Query<OrganizationPageRequest>,
         Extension(service): Extension<Arc<SysOrganizationService>>,
-    ) -> Result<Res<PaginatedData<SysOrganizationModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysOrganizationModel>>, AppError> {
         service
             .find_paginated_organizations(params)
             .await
             .map(Res::new_data)
     }
+
+    pub async fn get_organization_children(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+    ) -> Result<Res<Vec<TreeNode<SysOrganizationModel>>>, AppError> {
+        service
+            .get_organization_children(&id)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_organization_subtree(
+        Path(id): Path<String>,
+        Query(params): Query<SubtreeRequest>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+    ) -> Result<Res<TreeNode<SysOrganizationModel>>, AppError> {
+        service
+            .get_organization_subtree(&id, params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn move_organization(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysOrganizationService>>,
+        ValidatedForm(input): ValidatedForm<MoveTreeNodeInput>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .move_organization(&id, input)
+            .await
+            .map(Res::new_data)
+    }
 }
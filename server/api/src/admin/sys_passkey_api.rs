@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    FinishPasskeyInput, PasskeyChallengeOutput, StartPasskeyRegistrationInput, SysPasskeyService,
+    SysUserPasskeyModel, TPasskeyService,
+};
+
+pub struct SysPasskeyApi;
+
+impl SysPasskeyApi {
+    /// 当前用户已注册的通行密钥
+    pub async fn list_passkeys(
+        Extension(service): Extension<Arc<SysPasskeyService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<SysUserPasskeyModel>>, AppError> {
+        service.list_passkeys(&user).await.map(Res::new_data)
+    }
+
+    pub async fn start_registration(
+        Extension(service): Extension<Arc<SysPasskeyService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<StartPasskeyRegistrationInput>,
+    ) -> Result<Res<PasskeyChallengeOutput>, AppError> {
+        service
+            .start_registration(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn finish_registration(
+        Extension(service): Extension<Arc<SysPasskeyService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<FinishPasskeyInput>,
+    ) -> Result<Res<SysUserPasskeyModel>, AppError> {
+        service
+            .finish_registration(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn delete_passkey(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysPasskeyService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.delete_passkey(&id, &user).await.map(Res::new_data)
+    }
+}
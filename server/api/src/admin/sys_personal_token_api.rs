@@ -0,0 +1,36 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    CreatePersonalTokenInput, CreatedPersonalToken, PersonalTokenInfo, SysPersonalTokenService,
+    TPersonalTokenService,
+};
+
+pub struct SysPersonalTokenApi;
+
+impl SysPersonalTokenApi {
+    /// 当前用户的个人访问令牌
+    pub async fn list_tokens(
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<PersonalTokenInfo>>, AppError> {
+        service.list_tokens(&user).await.map(Res::new_data)
+    }
+
+    pub async fn create_token(
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<CreatePersonalTokenInput>,
+    ) -> Result<Res<CreatedPersonalToken>, AppError> {
+        service.create_token(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn revoke_token(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysPersonalTokenService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service.revoke_token(&id, &user).await.map(Res::new_data)
+    }
+}
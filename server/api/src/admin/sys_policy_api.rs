@@ -0,0 +1,83 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::{ConnectInfo, Path, Query},
+    http::{header, HeaderMap},
+    Extension,
+};
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, util::ClientIp,
+    validator::ValidatedForm,
+};
+use server_service::admin::{
+    AcceptPolicyInput, PolicyAcceptancePageRequest, PolicyDocumentPageRequest, PublishPolicyInput,
+    SysPolicyAcceptanceModel, SysPolicyDocumentModel, SysPolicyService, TPolicyService,
+};
+
+pub struct SysPolicyApi;
+
+impl SysPolicyApi {
+    pub async fn get_paginated_documents(
+        Query(params): Query<PolicyDocumentPageRequest>,
+        Extension(service): Extension<Arc<SysPolicyService>>,
+    ) -> Result<Res<PageResult<SysPolicyDocumentModel>>, AppError> {
+        service
+            .find_paginated_documents(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn publish_document(
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<PublishPolicyInput>,
+    ) -> Result<Res<SysPolicyDocumentModel>, AppError> {
+        service
+            .publish_document(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_paginated_acceptances(
+        Path(id): Path<String>,
+        Query(params): Query<PolicyAcceptancePageRequest>,
+        Extension(service): Extension<Arc<SysPolicyService>>,
+    ) -> Result<Res<PageResult<SysPolicyAcceptanceModel>>, AppError> {
+        service
+            .find_paginated_acceptances(&id, params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_pending_documents(
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<Vec<SysPolicyDocumentModel>>, AppError> {
+        service
+            .get_pending_documents(&user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn accept_documents(
+        ConnectInfo(addr): ConnectInfo<SocketAddr>,
+        headers: HeaderMap,
+        Extension(service): Extension<Arc<SysPolicyService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<AcceptPolicyInput>,
+    ) -> Result<Res<Vec<SysPolicyAcceptanceModel>>, AppError> {
+        let ip = match ClientIp::get_real_ip(&headers) {
+            ip if ip == "unknown" => addr.ip().to_string(),
+            ip => ip,
+        };
+        let user_agent = headers
+            .get(header::USER_AGENT)
+            .and_then(|value| value.to_str().ok())
+            .map(ToString::to_string);
+
+        service
+            .accept_documents(input, &user, ip, user_agent)
+            .await
+            .map(Res::new_data)
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Query,
+    http::header,
+    response::{IntoResponse, Response},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError, res::Res};
+use server_service::admin::{
+    CpuProfileInput, RuntimeStats, SysProfilingService, TProfilingService,
+};
+
+pub struct SysProfilingApi;
+
+impl SysProfilingApi {
+    pub async fn capture_cpu_profile(
+        Query(input): Query<CpuProfileInput>,
+        Extension(service): Extension<Arc<SysProfilingService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Response, AppError> {
+        let report = service.capture_cpu_profile(input, &user).await?;
+        let disposition = format!("attachment; filename=\"{}\"", report.file_name);
+
+        Ok((
+            [
+                (header::CONTENT_TYPE, report.content_type),
+                (header::CONTENT_DISPOSITION, disposition),
+            ],
+            report.data,
+        )
+            .into_response())
+    }
+
+    pub async fn get_runtime_stats(
+        Extension(service): Extension<Arc<SysProfilingService>>,
+    ) -> Result<Res<RuntimeStats>, AppError> {
+        service.runtime_stats().await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{
+    auth::User,
+    error::AppError,
+    recorder::{RecordedTrace, RecorderStatus, RecordingSession},
+    res::Res,
+    validator::ValidatedForm,
+};
+use server_service::admin::{
+    ReplayRecordingInput, ReplayResult, StartRecordingInput, SysRecorderService, TRecorderService,
+};
+
+pub struct SysRecorderApi;
+
+impl SysRecorderApi {
+    pub async fn get_status(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+    ) -> Result<Res<RecorderStatus>, AppError> {
+        service.status().await.map(Res::new_data)
+    }
+
+    pub async fn start(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<StartRecordingInput>,
+    ) -> Result<Res<RecordingSession>, AppError> {
+        service.start(input, &user).await.map(Res::new_data)
+    }
+
+    pub async fn stop(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<RecorderStatus>, AppError> {
+        service.stop(&user).await.map(Res::new_data)
+    }
+
+    pub async fn get_traces(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+    ) -> Result<Res<Vec<RecordedTrace>>, AppError> {
+        service.list_traces().await.map(Res::new_data)
+    }
+
+    pub async fn clear_traces(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<usize>, AppError> {
+        service.clear_traces(&user).await.map(Res::new_data)
+    }
+
+    pub async fn replay(
+        Extension(service): Extension<Arc<SysRecorderService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ReplayRecordingInput>,
+    ) -> Result<Res<Vec<ReplayResult>>, AppError> {
+        service.replay(input, &user).await.map(Res::new_data)
+    }
+}
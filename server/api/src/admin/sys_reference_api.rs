@@ -0,0 +1,19 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{EntityReferences, SysReferenceService, TReferenceService};
+
+pub struct SysReferenceApi;
+
+impl SysReferenceApi {
+    pub async fn get_references(
+        Path((entity, id)): Path<(String, String)>,
+        Extension(service): Extension<Arc<SysReferenceService>>,
+    ) -> Result<Res<EntityReferences>, AppError> {
+        service
+            .find_references(&entity, &id)
+            .await
+            .map(Res::new_data)
+    }
+}
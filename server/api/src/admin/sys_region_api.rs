@@ -0,0 +1,78 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    Extension,
+};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    ImportRegionInput, Region, SubtreeRequest, SysRegionService, SysRegionVersionModel,
+    TRegionService, TreeNode,
+};
+
+pub struct SysRegionApi;
+
+impl SysRegionApi {
+    pub async fn get_region_tree(
+        Query(params): Query<SubtreeRequest>,
+        Extension(service): Extension<Arc<SysRegionService>>,
+    ) -> Result<Res<Vec<TreeNode<Region>>>, AppError> {
+        service
+            .get_region_tree(params.depth)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_children(
+        Path(code): Path<String>,
+        Extension(service): Extension<Arc<SysRegionService>>,
+    ) -> Result<Res<Vec<TreeNode<Region>>>, AppError> {
+        service.get_children(&code).await.map(Res::new_data)
+    }
+
+    pub async fn get_path(
+        Path(code): Path<String>,
+        Extension(service): Extension<Arc<SysRegionService>>,
+    ) -> Result<Res<Vec<Region>>, AppError> {
+        service.get_path(&code).await.map(Res::new_data)
+    }
+
+    pub async fn get_versions(
+        Extension(service): Extension<Arc<SysRegionService>>,
+    ) -> Result<Res<Vec<SysRegionVersionModel>>, AppError> {
+        service.find_versions().await.map(Res::new_data)
+    }
+
+    pub async fn import_regions(
+        Extension(service): Extension<Arc<SysRegionService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ImportRegionInput>,
+    ) -> Result<Res<SysRegionVersionModel>, AppError> {
+        service
+            .import_regions(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn activate_version(
+        Path(version): Path<String>,
+        Extension(service): Extension<Arc<SysRegionService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SysRegionVersionModel>, AppError> {
+        service
+            .activate_version(&version, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn delete_version(
+        Path(version): Path<String>,
+        Extension(service): Extension<Arc<SysRegionService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<()>, AppError> {
+        service
+            .delete_version(&version, &user)
+            .await
+            .map(Res::new_data)
+    }
+}
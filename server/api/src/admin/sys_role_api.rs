@@ -4,7 +4,7 @@ use axum::{
     extract::{Path, Query},
     Extension,
 };
-use server_core::web::{error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm};
+use server_core::web::{error::AppError, page::PageResult, res::Res, validator::ValidatedForm};
 use server_service::admin::{
     CreateRoleInput, RolePageRequest, SysRoleModel, SysRoleService, TRoleService, UpdateRoleInput,
 };
@@ -15,7 +15,7 @@ impl SysRoleApi {
     pub async fn get_paginated_roles(
         Query(params): Query<RolePageRequest>,
         Extension(service): Extension<Arc<SysRoleService>>,
-    ) -> Result<Res<PaginatedData<SysRoleModel>>, AppError> {
+    ) -> Result<Res<PageResult<SysRoleModel>>, AppError> {
         service
             .find_paginated_roles(params)
             .await
@@ -0,0 +1,39 @@
+use std::sync::Arc;
+
+use axum::{extract::Path, Extension};
+use server_core::web::{auth::User, error::AppError, res::Res, validator::ValidatedForm};
+use server_service::admin::{
+    RequestSensitiveOperationInput, SensitiveOperationApproval, SysSensitiveOperationService,
+    TSensitiveOperationService,
+};
+
+pub struct SysSensitiveOperationApi;
+
+impl SysSensitiveOperationApi {
+    /// 申请紧急授权，获批后在请求头携带审批 ID 调用被拦截的接口
+    pub async fn request_approval(
+        Extension(service): Extension<Arc<SysSensitiveOperationService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<RequestSensitiveOperationInput>,
+    ) -> Result<Res<SensitiveOperationApproval>, AppError> {
+        service
+            .request_approval(input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_approval(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysSensitiveOperationService>>,
+    ) -> Result<Res<SensitiveOperationApproval>, AppError> {
+        service.get_approval(&id).await.map(Res::new_data)
+    }
+
+    pub async fn approve(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysSensitiveOperationService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SensitiveOperationApproval>, AppError> {
+        service.approve(&id, &user).await.map(Res::new_data)
+    }
+}
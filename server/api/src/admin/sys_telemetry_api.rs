@@ -0,0 +1,15 @@
+use std::sync::Arc;
+
+use axum::Extension;
+use server_core::web::{error::AppError, res::Res};
+use server_service::admin::{SysTelemetryService, TTelemetryService, TelemetryReport};
+
+pub struct SysTelemetryApi;
+
+impl SysTelemetryApi {
+    pub async fn preview_report(
+        Extension(service): Extension<Arc<SysTelemetryService>>,
+    ) -> Result<Res<TelemetryReport>, AppError> {
+        service.preview_report().await.map(Res::new_data)
+    }
+}
@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::{Path, Query},
+    http::{header, HeaderValue},
+    response::{IntoResponse, Response},
+    Extension,
+};
+use axum_casbin::CasbinAxumLayer;
+use server_core::web::{
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
+};
+use server_service::admin::{
+    ConfirmTenantPurgeInput, ImportTenantInput, ProvisionTenantInput, SuspendTenantInput,
+    SysTenantModel, SysTenantService, TTenantService, TenantImportResult, TenantPageRequest,
+    TenantPurgeConfirmation, WATERMARK_HEADER,
+};
+
+pub struct SysTenantApi;
+
+impl SysTenantApi {
+    pub async fn get_paginated_tenants(
+        Query(params): Query<TenantPageRequest>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+    ) -> Result<Res<PageResult<SysTenantModel>>, AppError> {
+        service
+            .find_paginated_tenants(params)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn get_tenant(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        service.get_tenant(&id).await.map(Res::new_data)
+    }
+
+    /// 开通租户
+    ///
+    /// 创建域、管理员角色和管理员账号，并为管理员角色授予模板角色的接口权限。
+    pub async fn provision_tenant(
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ProvisionTenantInput>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+
+        service
+            .provision_tenant(input, &user, enforcer)
+            .await
+            .map(Res::new_data)
+    }
+
+    /// 导出租户档案，以 JSON 附件返回
+    pub async fn export_tenant(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Response, AppError> {
+        let export = service.export_tenant(&id, &user).await?;
+        let disposition = format!(
+            "attachment; filename*=UTF-8''{}",
+            urlencoding::encode(&export.file_name)
+        );
+
+        let mut response = (
+            [
+                (header::CONTENT_TYPE, "application/json".to_string()),
+                (header::CONTENT_DISPOSITION, disposition),
+            ],
+            export.data,
+        )
+            .into_response();
+        if let Some(value) = export
+            .watermark_id
+            .and_then(|id| HeaderValue::from_str(&id).ok())
+        {
+            response.headers_mut().insert(WATERMARK_HEADER, value);
+        }
+        Ok(response)
+    }
+
+    /// 从导出档案导入租户
+    pub async fn import_tenant(
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ImportTenantInput>,
+    ) -> Result<Res<TenantImportResult>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+
+        service
+            .import_tenant(input, &user, enforcer)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn suspend_tenant(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<SuspendTenantInput>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        service
+            .suspend_tenant(&id, input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn resume_tenant(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        service.resume_tenant(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn request_purge(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<TenantPurgeConfirmation>, AppError> {
+        service.request_purge(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn confirm_purge(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+        ValidatedForm(input): ValidatedForm<ConfirmTenantPurgeInput>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        service
+            .confirm_purge(&id, input, &user)
+            .await
+            .map(Res::new_data)
+    }
+
+    pub async fn cancel_purge(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysTenantService>>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<SysTenantModel>, AppError> {
+        service.cancel_purge(&id, &user).await.map(Res::new_data)
+    }
+}
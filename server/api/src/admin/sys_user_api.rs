@@ -6,11 +6,11 @@ use axum::{
 };
 use axum_casbin::{casbin::MgmtApi, CasbinAxumLayer};
 use server_core::web::{
-    auth::User, error::AppError, page::PaginatedData, res::Res, validator::ValidatedForm,
+    auth::User, error::AppError, page::PageResult, res::Res, validator::ValidatedForm,
 };
 use server_service::admin::{
-    CreateUserInput, SysUserService, TUserService, UpdateUserInput, UserPageRequest,
-    UserWithoutPassword,
+    CreateUserInput, EffectivePermissionsOutput, SysUserService, TUserService, UpdateUserInput,
+    UserPageRequest, UserWithoutPassword,
 };
 
 pub struct SysUserApi;
@@ -18,18 +18,19 @@ pub struct SysUserApi;
 impl SysUserApi {
     pub async fn get_all_users(
         Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
     ) -> Result<Res<Vec<UserWithoutPassword>>, AppError> {
-        service.find_all().await.map(Res::new_data)
+        service.find_all(&user).await.map(Res::new_data)
     }
 
     pub async fn get_paginated_users(
         Query(params): Query<UserPageRequest>,
         Extension(service): Extension<Arc<SysUserService>>,
-        user: User,
-    ) -> Result<Res<PaginatedData<UserWithoutPassword>>, AppError> {
+        Extension(user): Extension<User>,
+    ) -> Result<Res<PageResult<UserWithoutPassword>>, AppError> {
         print!("user is {:#?}", user);
         service
-            .find_paginated_users(params)
+            .find_paginated_users(params, &user)
             .await
             .map(Res::new_data)
     }
@@ -66,29 +67,46 @@ impl SysUserApi {
 
     pub async fn create_user(
         Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
         ValidatedForm(input): ValidatedForm<CreateUserInput>,
     ) -> Result<Res<UserWithoutPassword>, AppError> {
-        service.create_user(input).await.map(Res::new_data)
+        service.create_user(input, &user).await.map(Res::new_data)
     }
 
     pub async fn get_user(
         Path(id): Path<String>,
         Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
     ) -> Result<Res<UserWithoutPassword>, AppError> {
-        service.get_user(&id).await.map(Res::new_data)
+        service.get_user(&id, &user).await.map(Res::new_data)
     }
 
     pub async fn update_user(
         Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
         ValidatedForm(input): ValidatedForm<UpdateUserInput>,
     ) -> Result<Res<UserWithoutPassword>, AppError> {
-        service.update_user(input).await.map(Res::new_data)
+        service.update_user(input, &user).await.map(Res::new_data)
     }
 
     pub async fn delete_user(
         Path(id): Path<String>,
         Extension(service): Extension<Arc<SysUserService>>,
+        Extension(user): Extension<User>,
     ) -> Result<Res<()>, AppError> {
-        service.delete_user(&id).await.map(Res::new_data)
+        service.delete_user(&id, &user).await.map(Res::new_data)
+    }
+
+    pub async fn get_effective_permissions(
+        Path(id): Path<String>,
+        Extension(service): Extension<Arc<SysUserService>>,
+        Extension(mut cache_enforcer): Extension<CasbinAxumLayer>,
+        Extension(user): Extension<User>,
+    ) -> Result<Res<EffectivePermissionsOutput>, AppError> {
+        let enforcer = cache_enforcer.get_enforcer();
+        service
+            .get_effective_permissions(&id, &user, enforcer)
+            .await
+            .map(Res::new_data)
     }
 }
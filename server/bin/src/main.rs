@@ -1,30 +1,74 @@
-use std::net::SocketAddr;
-
 use tokio::net::TcpListener;
 
-#[tokio::main]
-async fn main() {
+fn main() {
     let config_path = if cfg!(debug_assertions) {
         "server/resources/application-test.yaml"
     } else {
         "server/resources/application.yaml"
     };
 
+    // 运行时参数需在构建运行时前确定，修改后重启生效
+    let runtime_config = server_initialize::load_runtime_config(config_path);
+    let runtime = match server_initialize::build_runtime(&runtime_config) {
+        Ok(runtime) => runtime,
+        Err(e) => {
+            eprintln!("Failed to build tokio runtime: {}", e);
+            return;
+        },
+    };
+
+    // --preflight 只执行启动前检查，打印报告后退出，不启动服务
+    if std::env::args().any(|arg| arg == "--preflight") {
+        let passed = runtime.block_on(async {
+            server_initialize::initialize_log_tracing().await;
+            server_initialize::run_preflight(config_path).await
+        });
+        std::process::exit(if passed { 0 } else { 1 });
+    }
+
+    runtime.block_on(run(config_path));
+}
+
+async fn run(config_path: &str) {
     server_initialize::initialize_log_tracing().await;
 
-    // 使用多实例环境变量优先的配置加载方式
-    // 支持单个配置项和多实例配置的环境变量覆盖
-    server_initialize::initialize_config_with_multi_instance_env(config_path, None).await;
+    // 设置了 ETCD_ENDPOINTS、CONSUL_HTTP_ADDR、NACOS_SERVER_ADDR 或 APOLLO_CONFIG_SERVICE 时从配置中心加载配置，
+    // 否则使用多实例环境变量优先的配置加载方式，支持单个配置项和多实例配置的环境变量覆盖
+    if !server_initialize::initialize_config_from_etcd(None).await
+        && !server_initialize::initialize_config_from_consul().await
+        && !server_initialize::initialize_config_from_nacos().await
+        && !server_initialize::initialize_config_from_apollo().await
+    {
+        server_initialize::initialize_config_with_multi_instance_env(config_path, None).await;
+    }
     let _ = server_initialize::init_xdb().await;
     server_initialize::init_primary_connection().await;
+    // 数据库结构或配置文件与程序版本不兼容时给出提示，严格模式下拒绝启动
+    server_initialize::check_compatibility().await;
     server_initialize::init_db_pools().await;
+    server_initialize::initialize_tenant_pools().await;
     server_initialize::initialize_keys_and_validation().await;
     server_initialize::initialize_event_channel().await;
+    server_initialize::initialize_siem_exporter().await;
 
     server_initialize::init_primary_redis().await;
     server_initialize::init_redis_pools().await;
     server_initialize::init_primary_mongo().await;
     server_initialize::init_mongo_pools().await;
+    server_initialize::init_primary_s3().await;
+    server_initialize::init_s3_pools().await;
+
+    // 按 server.role 决定是否运行后台任务和监听端口
+    let role = server_initialize::get_server_role().await;
+    server_initialize::initialize_drain_signal().await;
+    server_initialize::initialize_cluster_membership().await;
+    if role.runs_jobs() {
+        server_initialize::initialize_background_jobs().await;
+    }
+    if !role.serves_http() {
+        server_initialize::run_worker().await;
+        return;
+    }
 
     // build our application with a route
     let app = server_initialize::initialize_admin_router().await;
@@ -43,10 +87,7 @@ async fn main() {
     // run it
     let listener = TcpListener::bind(&addr).await.unwrap();
     // tracing::debug!("listening on {}", listener.local_addr().unwrap());
-    axum::serve(
-        listener,
-        app.into_make_service_with_connect_info::<SocketAddr>(),
-    )
-    .await
-    .unwrap();
+    server_initialize::serve(listener, app).await;
+
+    server_initialize::finalize_shutdown().await;
 }
@@ -0,0 +1,450 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use config::{Map, Source, Value};
+use reqwest::{StatusCode, Url};
+use ring::hmac;
+use tokio::task::JoinHandle;
+
+use crate::{
+    config_init::{apply_remote_config, load_layered_config, ConfigError},
+    env_config::to_value_kind,
+    model::Config,
+    project_error, project_info,
+};
+
+/// 未指定集群时使用的 Apollo 默认集群
+pub const APOLLO_DEFAULT_CLUSTER: &str = "default";
+
+/// 未指定命名空间时使用的 Apollo 默认命名空间
+pub const APOLLO_DEFAULT_NAMESPACE: &str = "application";
+
+/// 长轮询的请求超时，需大于服务端 60 秒的挂起时间
+const LONG_POLLING_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// 长轮询失败后重试的间隔
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Apollo 连接设置
+///
+/// 通过环境变量启用：
+/// - APOLLO_CONFIG_SERVICE: Config Service 地址，如 `http://apollo-config:8080`
+/// - APOLLO_APP_ID: 应用 AppId
+/// - APOLLO_CLUSTER: 集群名称（可选，默认为 `default`）
+/// - APOLLO_NAMESPACES: 逗号分隔的命名空间（可选，默认为 `application`），靠后的优先
+/// - APOLLO_ACCESS_KEY_SECRET: 开启访问密钥时的密钥（可选）
+#[derive(Debug, Clone)]
+pub struct ApolloSettings {
+    pub config_service: String,
+    pub app_id: String,
+    pub cluster: String,
+    pub namespaces: Vec<String>,
+    pub secret: Option<String>,
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl ApolloSettings {
+    /// 集群和访问密钥取自环境变量，命名空间为空时使用 `application`
+    pub fn new(config_service: &str, app_id: &str, namespaces: &[&str]) -> Self {
+        let namespaces: Vec<String> = namespaces
+            .iter()
+            .map(|namespace| namespace.trim().to_string())
+            .filter(|namespace| !namespace.is_empty())
+            .collect();
+
+        Self {
+            config_service: config_service.trim_end_matches('/').to_string(),
+            app_id: app_id.to_string(),
+            cluster: env_var("APOLLO_CLUSTER")
+                .unwrap_or_else(|| APOLLO_DEFAULT_CLUSTER.to_string()),
+            namespaces: if namespaces.is_empty() {
+                vec![APOLLO_DEFAULT_NAMESPACE.to_string()]
+            } else {
+                namespaces
+            },
+            secret: env_var("APOLLO_ACCESS_KEY_SECRET"),
+        }
+    }
+
+    /// 从环境变量读取，未设置 `APOLLO_CONFIG_SERVICE` 或 `APOLLO_APP_ID` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        let namespaces = env_var("APOLLO_NAMESPACES").unwrap_or_default();
+        Some(Self::new(
+            &env_var("APOLLO_CONFIG_SERVICE")?,
+            &env_var("APOLLO_APP_ID")?,
+            &namespaces.split(',').collect::<Vec<_>>(),
+        ))
+    }
+
+    fn source(&self) -> String {
+        format!("apollo:{}/{}", self.app_id, self.cluster)
+    }
+}
+
+/// 命名空间的格式，由名称的扩展名决定
+fn is_document_namespace(namespace: &str) -> bool {
+    matches!(
+        namespace.rsplit_once('.').map(|(_, ext)| ext),
+        Some("yaml" | "yml" | "json")
+    )
+}
+
+/// 单个命名空间的配置项
+#[derive(Debug, Clone)]
+struct NamespaceSource {
+    values: Map<String, Value>,
+}
+
+impl Source for NamespaceSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        Ok(self.values.clone())
+    }
+}
+
+/// Apollo 命名空间配置源
+///
+/// properties 命名空间的键按路径设置单个配置项，如 `server.port`，值按 YAML 解析；
+/// 名称以 `.yaml`、`.yml` 或 `.json` 结尾的命名空间保存完整的配置文档。
+/// 多个命名空间按顺序合并，靠后的命名空间覆盖靠前的
+#[derive(Debug, Clone)]
+pub struct ApolloConfigSource {
+    namespaces: Vec<NamespaceSource>,
+}
+
+impl ApolloConfigSource {
+    /// 由各命名空间的 `configurations` 构造配置源
+    pub fn from_namespaces(
+        namespaces: Vec<(String, HashMap<String, String>)>,
+    ) -> Result<Self, ConfigError> {
+        let mut sources = Vec::with_capacity(namespaces.len());
+        for (namespace, configurations) in namespaces {
+            let origin = format!("apollo:{}", namespace);
+            let mut values = Map::new();
+            if is_document_namespace(&namespace) {
+                let content = configurations.get("content").map(String::as_str);
+                let document: serde_json::Value = serde_yaml::from_str(content.unwrap_or(""))
+                    .map_err(|e| ConfigError::ApolloError(format!("{}: {}", namespace, e)))?;
+                match document {
+                    serde_json::Value::Object(document) => {
+                        for (field, value) in document {
+                            values.insert(field, Value::new(Some(&origin), to_value_kind(value)));
+                        }
+                    },
+                    // 空文档
+                    serde_json::Value::Null => {},
+                    _ => {
+                        return Err(ConfigError::ApolloError(format!(
+                            "{}: config document must be a mapping",
+                            namespace
+                        )))
+                    },
+                }
+            } else {
+                for (key, value) in configurations {
+                    let value: serde_json::Value = serde_yaml::from_str(&value)
+                        .map_err(|e| ConfigError::ApolloError(format!("{}: {}", key, e)))?;
+                    values.insert(key, Value::new(Some(&origin), to_value_kind(value)));
+                }
+            }
+            sources.push(NamespaceSource { values });
+        }
+        Ok(Self {
+            namespaces: sources,
+        })
+    }
+}
+
+impl Source for ApolloConfigSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    /// 按顺序逐个合并命名空间，同一路径以靠后的为准
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        self.namespaces.collect()
+    }
+}
+
+/// 从 Apollo 配置中心加载并订阅配置
+///
+/// 读取各命名空间的配置并合并加载，结果仍可被 Vault 和环境变量覆盖。订阅使用 Apollo 的通知长轮询，
+/// 任一命名空间发布后重新加载，发布版本有变化且校验通过才替换全局配置
+///
+/// # 示例
+/// ```rust,no_run
+/// use server_config::{ApolloConfigClient, ApolloSettings};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let settings = ApolloSettings::new("http://127.0.0.1:8080", "soybean-admin", &["application"]);
+///     let mut client = ApolloConfigClient::new(settings, "APP")?;
+///     let config = client.load().await?;
+///     client.subscribe();
+///     Ok(())
+/// }
+/// ```
+pub struct ApolloConfigClient {
+    settings: ApolloSettings,
+    env_prefix: String,
+    client: reqwest::Client,
+    /// 各命名空间最近一次收到的通知 ID，初始为 -1
+    notifications: HashMap<String, i64>,
+    /// 各命名空间最近一次加载的发布版本
+    release_keys: HashMap<String, String>,
+}
+
+impl ApolloConfigClient {
+    pub fn new(settings: ApolloSettings, env_prefix: &str) -> Result<Self, ConfigError> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| ConfigError::ApolloError(e.to_string()))?;
+        let notifications = settings
+            .namespaces
+            .iter()
+            .map(|namespace| (namespace.clone(), -1))
+            .collect();
+
+        Ok(Self {
+            settings,
+            env_prefix: env_prefix.to_string(),
+            client,
+            notifications,
+            release_keys: HashMap::new(),
+        })
+    }
+
+    /// 读取全部命名空间并加载配置
+    pub async fn load(&mut self) -> Result<Config, ConfigError> {
+        let mut namespaces = Vec::with_capacity(self.settings.namespaces.len());
+        for namespace in self.settings.namespaces.clone() {
+            let (configurations, release_key) = self.fetch(&namespace).await.map_err(|e| {
+                project_error!("Failed to load config from apollo: {}", e);
+                e
+            })?;
+            self.release_keys.insert(namespace.clone(), release_key);
+            namespaces.push((namespace, configurations));
+        }
+        project_info!(
+            "Loaded {} namespace(s) from {}",
+            namespaces.len(),
+            self.settings.source()
+        );
+
+        let source = ApolloConfigSource::from_namespaces(namespaces)?;
+        load_layered_config(None, None, Some(&self.env_prefix), Some(Box::new(source))).await
+    }
+
+    /// 在后台订阅命名空间的发布通知，有新版本时重新加载并替换全局配置
+    pub fn subscribe(mut self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.poll_notifications().await {
+                    Ok(false) => {},
+                    Ok(true) => {
+                        let previous = self.release_keys.clone();
+                        match self.load().await {
+                            // 首次通知只同步通知 ID，发布版本未变化时无需替换
+                            Ok(_) if self.release_keys == previous => {},
+                            Ok(config) => {
+                                apply_remote_config(config, &self.settings.source()).await;
+                            },
+                            Err(e) => {
+                                project_error!("Failed to reload config from apollo: {}", e);
+                                tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                            },
+                        }
+                    },
+                    Err(e) => {
+                        project_error!("Apollo config subscription interrupted: {}", e);
+                        tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                    },
+                }
+            }
+        })
+    }
+
+    /// 读取命名空间的配置和发布版本
+    async fn fetch(
+        &self,
+        namespace: &str,
+    ) -> Result<(HashMap<String, String>, String), ConfigError> {
+        let url = self.url(
+            &format!(
+                "configs/{}/{}/{}",
+                self.settings.app_id, self.settings.cluster, namespace
+            ),
+            &[],
+        )?;
+        let response = self
+            .signed(url)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| ConfigError::ApolloError(format!("request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ConfigError::ApolloError(format!(
+                "reading namespace {} returned {}",
+                namespace, status
+            )));
+        }
+
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| ConfigError::ApolloError(format!("invalid response: {}", e)))?;
+        let configurations = body
+            .get("configurations")
+            .cloned()
+            .map(serde_json::from_value)
+            .transpose()
+            .map_err(|e| ConfigError::ApolloError(format!("invalid configurations: {}", e)))?
+            .unwrap_or_default();
+        let release_key = body
+            .get("releaseKey")
+            .and_then(|key| key.as_str())
+            .unwrap_or_default()
+            .to_string();
+        Ok((configurations, release_key))
+    }
+
+    /// 长轮询等待发布通知，有命名空间发布时返回 `true`
+    async fn poll_notifications(&mut self) -> Result<bool, ConfigError> {
+        let notifications: Vec<serde_json::Value> = self
+            .notifications
+            .iter()
+            .map(|(namespace, id)| {
+                serde_json::json!({ "namespaceName": namespace, "notificationId": id })
+            })
+            .collect();
+        let url = self.url(
+            "notifications/v2",
+            &[
+                ("appId", self.settings.app_id.clone()),
+                ("cluster", self.settings.cluster.clone()),
+                (
+                    "notifications",
+                    serde_json::Value::Array(notifications).to_string(),
+                ),
+            ],
+        )?;
+        let response = self
+            .signed(url)
+            .timeout(LONG_POLLING_TIMEOUT)
+            .send()
+            .await
+            .map_err(|e| ConfigError::ApolloError(format!("notification request failed: {}", e)))?;
+        let status = response.status();
+        // 挂起时间内没有发布
+        if status == StatusCode::NOT_MODIFIED {
+            return Ok(false);
+        }
+        if !status.is_success() {
+            return Err(ConfigError::ApolloError(format!(
+                "polling notifications for {} returned {}",
+                self.settings.source(),
+                status
+            )));
+        }
+
+        let changes: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| ConfigError::ApolloError(format!("invalid response: {}", e)))?;
+        let mut changed = false;
+        for change in changes {
+            let namespace = change.get("namespaceName").and_then(|name| name.as_str());
+            let id = change.get("notificationId").and_then(|id| id.as_i64());
+            if let (Some(namespace), Some(id)) = (namespace, id) {
+                if let Some(current) = self.notifications.get_mut(namespace) {
+                    changed |= *current != id;
+                    *current = id;
+                }
+            }
+        }
+        Ok(changed)
+    }
+
+    fn url(&self, path: &str, query: &[(&str, String)]) -> Result<Url, ConfigError> {
+        let base = format!("{}/{}", self.settings.config_service, path);
+        Url::parse_with_params(&base, query)
+            .map_err(|e| ConfigError::ApolloError(format!("invalid url {}: {}", base, e)))
+    }
+
+    /// 开启访问密钥时按 Apollo 的规则签名：HMAC-SHA1(时间戳 + "\n" + 路径和查询)
+    fn signed(&self, url: Url) -> reqwest::RequestBuilder {
+        let Some(secret) = &self.settings.secret else {
+            return self.client.get(url);
+        };
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis()
+            .to_string();
+        let path_with_query = match url.query() {
+            Some(query) => format!("{}?{}", url.path(), query),
+            None => url.path().to_string(),
+        };
+        let key = hmac::Key::new(hmac::HMAC_SHA1_FOR_LEGACY_USE_ONLY, secret.as_bytes());
+        let signature = hmac::sign(
+            &key,
+            format!("{}\n{}", timestamp, path_with_query).as_bytes(),
+        );
+
+        self.client
+            .get(url)
+            .header(
+                "Authorization",
+                format!(
+                    "Apollo {}:{}",
+                    self.settings.app_id,
+                    STANDARD.encode(signature.as_ref())
+                ),
+            )
+            .header("Timestamp", timestamp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_apollo_namespaces_merge_in_order() {
+        let source = ApolloConfigSource::from_namespaces(vec![
+            (
+                "application.yaml".to_string(),
+                HashMap::from([(
+                    "content".to_string(),
+                    "server:\n  host: 0.0.0.0\n  port: 10001\n".to_string(),
+                )]),
+            ),
+            (
+                "application".to_string(),
+                HashMap::from([("server.port".to_string(), "10002".to_string())]),
+            ),
+        ])
+        .unwrap();
+
+        let config = config::Config::builder()
+            .add_source(source)
+            .build()
+            .unwrap();
+        assert_eq!(config.get_string("server.host").unwrap(), "0.0.0.0");
+        assert_eq!(config.get_int("server.port").unwrap(), 10002);
+    }
+}
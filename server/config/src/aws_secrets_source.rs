@@ -0,0 +1,270 @@
+use config::{File, Map, Source, Value, ValueKind};
+
+use crate::env_config::EnvConfigError;
+
+/// Secrets Manager 引用前缀，`aws-secrets://{secret_id}` 或 `aws-secrets://{secret_id}#{json_key}`
+pub const SECRETS_MANAGER_SCHEME: &str = "aws-secrets://";
+
+/// SSM Parameter Store 引用前缀，`aws-ssm://{parameter_name}`，SecureString 自动解密
+pub const PARAMETER_STORE_SCHEME: &str = "aws-ssm://";
+
+/// 配置文件中的 AWS 密钥引用
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SecretReference {
+    /// 配置路径，如 `database.url` 或 `database_instances[0].database.url`
+    pub path: String,
+    pub target: SecretTarget,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SecretTarget {
+    /// Secrets Manager 密钥，指定 `key` 时按 JSON 解析后取该字段
+    SecretsManager {
+        secret_id: String,
+        key: Option<String>,
+    },
+    /// SSM 参数
+    Parameter { name: String },
+}
+
+impl SecretTarget {
+    /// 解析引用，不是 AWS 引用时返回 `None`
+    pub fn parse(value: &str) -> Option<Self> {
+        if let Some(rest) = value.strip_prefix(SECRETS_MANAGER_SCHEME) {
+            let (secret_id, key) = match rest.split_once('#') {
+                Some((secret_id, key)) => (secret_id, Some(key.to_string())),
+                None => (rest, None),
+            };
+            return Some(SecretTarget::SecretsManager {
+                secret_id: secret_id.to_string(),
+                key,
+            });
+        }
+        value
+            .strip_prefix(PARAMETER_STORE_SCHEME)
+            .map(|name| SecretTarget::Parameter {
+                name: name.to_string(),
+            })
+    }
+}
+
+/// AWS Secrets Manager / SSM Parameter Store 配置源
+///
+/// 配置文件中形如 `aws-secrets://prod/soybean#jwt_secret` 或 `aws-ssm:///soybean/prod/db_url`
+/// 的字符串在加载时替换为密钥的值，优先级紧随配置文件，Vault 和环境变量仍可覆盖。
+/// 凭证和区域使用 AWS 默认凭证链（ECS 任务角色、EKS IRSA、`AWS_REGION` 等）。
+///
+/// 需要启用 `aws-secrets` 特性；未启用时配置文件中存在引用会导致启动失败，
+/// 避免引用字符串被当作真实的密钥使用
+#[derive(Debug, Clone)]
+pub struct AwsSecretsSource {
+    values: Map<String, Value>,
+}
+
+impl AwsSecretsSource {
+    /// 查找配置文件中的全部 AWS 密钥引用
+    pub fn find_references(file_path: &str) -> Result<Vec<SecretReference>, EnvConfigError> {
+        Self::find_layered_references(&[file_path])
+    }
+
+    /// 查找依次叠加的多个配置文件中的 AWS 密钥引用，被后面的文件覆盖的引用不会读取
+    pub fn find_layered_references(
+        file_paths: &[&str],
+    ) -> Result<Vec<SecretReference>, EnvConfigError> {
+        let files: Vec<_> = file_paths
+            .iter()
+            .map(|file_path| File::with_name(file_path))
+            .collect();
+        let values = files.collect()?;
+        let mut references = Vec::new();
+        for (key, value) in values {
+            collect_references(key, &value, &mut references);
+        }
+        Ok(references)
+    }
+
+    /// 读取引用的密钥，同一个密钥只读取一次
+    #[cfg(feature = "aws-secrets")]
+    pub async fn resolve(references: &[SecretReference]) -> Result<Self, EnvConfigError> {
+        use std::collections::HashMap;
+
+        let sdk_config = aws_config::load_defaults(aws_config::BehaviorVersion::latest()).await;
+        let secrets = aws_sdk_secretsmanager::Client::new(&sdk_config);
+        let parameters = aws_sdk_ssm::Client::new(&sdk_config);
+        let mut fetched: HashMap<String, String> = HashMap::new();
+        let mut values = Map::new();
+
+        for reference in references {
+            let value = match &reference.target {
+                SecretTarget::SecretsManager { secret_id, key } => {
+                    if !fetched.contains_key(secret_id) {
+                        let secret = secrets
+                            .get_secret_value()
+                            .secret_id(secret_id)
+                            .send()
+                            .await
+                            .map_err(|e| aws_error(&reference.path, e))?
+                            .secret_string()
+                            .map(str::to_string)
+                            .ok_or_else(|| {
+                                EnvConfigError::AwsSecrets(format!(
+                                    "{}: secret '{}' has no string value",
+                                    reference.path, secret_id
+                                ))
+                            })?;
+                        fetched.insert(secret_id.clone(), secret);
+                    }
+                    let secret = &fetched[secret_id];
+                    match key {
+                        Some(key) => json_field(secret, key).ok_or_else(|| {
+                            EnvConfigError::AwsSecrets(format!(
+                                "{}: secret '{}' has no field '{}'",
+                                reference.path, secret_id, key
+                            ))
+                        })?,
+                        None => secret.clone(),
+                    }
+                },
+                SecretTarget::Parameter { name } => parameters
+                    .get_parameter()
+                    .name(name)
+                    .with_decryption(true)
+                    .send()
+                    .await
+                    .map_err(|e| aws_error(&reference.path, e))?
+                    .parameter()
+                    .and_then(|parameter| parameter.value())
+                    .map(str::to_string)
+                    .ok_or_else(|| {
+                        EnvConfigError::AwsSecrets(format!(
+                            "{}: parameter '{}' has no value",
+                            reference.path, name
+                        ))
+                    })?,
+            };
+            values.insert(
+                reference.path.clone(),
+                Value::new(Some(&"aws-secrets".to_string()), ValueKind::String(value)),
+            );
+        }
+
+        Ok(Self { values })
+    }
+
+    /// 未启用 `aws-secrets` 特性时无法读取引用
+    #[cfg(not(feature = "aws-secrets"))]
+    pub async fn resolve(references: &[SecretReference]) -> Result<Self, EnvConfigError> {
+        let paths: Vec<&str> = references
+            .iter()
+            .map(|reference| reference.path.as_str())
+            .collect();
+        Err(EnvConfigError::AwsSecrets(format!(
+            "config references AWS secrets at [{}] but the server was built without the \
+             `aws-secrets` feature",
+            paths.join(", ")
+        )))
+    }
+
+    /// 替换的配置项数量
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+#[cfg(feature = "aws-secrets")]
+fn aws_error(path: &str, err: impl std::error::Error) -> EnvConfigError {
+    EnvConfigError::AwsSecrets(format!("{}: {}", path, err))
+}
+
+/// 从 JSON 格式的密钥中取字段，非字符串字段按 JSON 文本返回
+#[cfg_attr(not(feature = "aws-secrets"), allow(dead_code))]
+fn json_field(secret: &str, key: &str) -> Option<String> {
+    let value = serde_json::from_str::<serde_json::Value>(secret).ok()?;
+    match value.get(key)? {
+        serde_json::Value::String(value) => Some(value.clone()),
+        other => Some(other.to_string()),
+    }
+}
+
+fn collect_references(path: String, value: &Value, references: &mut Vec<SecretReference>) {
+    match &value.kind {
+        ValueKind::String(value) => {
+            if let Some(target) = SecretTarget::parse(value) {
+                references.push(SecretReference { path, target });
+            }
+        },
+        ValueKind::Table(table) => {
+            for (key, value) in table {
+                collect_references(format!("{}.{}", path, key), value, references);
+            }
+        },
+        ValueKind::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                collect_references(format!("{}[{}]", path, index), value, references);
+            }
+        },
+        _ => {},
+    }
+}
+
+impl Source for AwsSecretsSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        Ok(self.values.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_secret_reference() {
+        assert_eq!(
+            SecretTarget::parse("aws-secrets://prod/soybean#jwt_secret"),
+            Some(SecretTarget::SecretsManager {
+                secret_id: "prod/soybean".to_string(),
+                key: Some("jwt_secret".to_string()),
+            })
+        );
+        assert_eq!(
+            SecretTarget::parse("aws-ssm:///soybean/prod/db_url"),
+            Some(SecretTarget::Parameter {
+                name: "/soybean/prod/db_url".to_string(),
+            })
+        );
+        assert_eq!(SecretTarget::parse("postgres://localhost/db"), None);
+    }
+
+    #[test]
+    fn test_collect_references_with_paths() {
+        let values = config::Config::builder()
+            .add_source(File::from_str(
+                "jwt:\n  jwt_secret: aws-secrets://prod/soybean#jwt\n  issuer: soybean\n\
+                 database_instances:\n  - name: report\n    database:\n      url: aws-ssm:///db\n",
+                config::FileFormat::Yaml,
+            ))
+            .build()
+            .unwrap()
+            .collect()
+            .unwrap();
+        let mut references = Vec::new();
+        for (key, value) in values {
+            collect_references(key, &value, &mut references);
+        }
+        references.sort_by(|a, b| a.path.cmp(&b.path));
+
+        let paths: Vec<&str> = references.iter().map(|r| r.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["database_instances[0].database.url", "jwt.jwt_secret"]
+        );
+    }
+}
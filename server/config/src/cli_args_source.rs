@@ -0,0 +1,103 @@
+use config::{ConfigError as ConfigBuilderError, Map, Source, Value, ValueKind};
+
+/// 命令行参数配置源
+///
+/// `--server.port=9090` 或 `--server.port 9090` 按配置路径覆盖配置项，优先级高于环境变量。
+/// 键中的 `-` 视为 `_`，`--database.max-connections=50` 即 `database.max_connections`。
+/// 只识别带 `.` 的键，`--preflight` 等程序自身的开关不受影响；
+/// 值按字符串读取，由反序列化转换为数字、布尔值等目标类型
+#[derive(Debug, Clone, Default)]
+pub struct CliArgsSource {
+    values: Map<String, Value>,
+}
+
+impl CliArgsSource {
+    /// 从当前进程的命令行参数构造
+    pub fn from_env() -> Self {
+        Self::from_args(std::env::args().skip(1))
+    }
+
+    /// 从参数列表构造，不含程序名
+    pub fn from_args<I, S>(args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        let mut values = Map::new();
+        let mut args = args.into_iter().map(Into::into).peekable();
+        while let Some(arg) = args.next() {
+            let Some(option) = arg.strip_prefix("--") else {
+                continue;
+            };
+            let (key, value) = match option.split_once('=') {
+                Some((key, value)) => (key, Some(value.to_string())),
+                None => (option, None),
+            };
+            if !key.contains('.') {
+                continue;
+            }
+            let value = match value {
+                Some(value) => value,
+                None => match args.next_if(|next| !next.starts_with("--")) {
+                    Some(value) => value,
+                    None => continue,
+                },
+            };
+            values.insert(
+                key.replace('-', "_"),
+                Value::new(Some(&format!("--{}", key)), ValueKind::String(value)),
+            );
+        }
+        Self { values }
+    }
+
+    /// 命令行中的配置项数量
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Source for CliArgsSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigBuilderError> {
+        Ok(self.values.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cli_args() {
+        let source = CliArgsSource::from_args([
+            "--preflight",
+            "--server.port=9090",
+            "--database.max-connections",
+            "50",
+            "--redis.url",
+            "--jwt.issuer=a=b",
+            "positional",
+        ]);
+        let values = source.collect().unwrap();
+
+        assert_eq!(source.len(), 3);
+        assert_eq!(values["server.port"].clone().into_string().unwrap(), "9090");
+        assert_eq!(
+            values["database.max_connections"]
+                .clone()
+                .into_int()
+                .unwrap(),
+            50
+        );
+        assert_eq!(values["jwt.issuer"].clone().into_string().unwrap(), "a=b");
+        assert!(!values.contains_key("redis.url"));
+    }
+}
@@ -0,0 +1,253 @@
+use base64::{engine::general_purpose::STANDARD, Engine};
+use config::{Source, Value, ValueKind};
+use ring::{
+    aead::{Aad, LessSafeKey, Nonce, UnboundKey, AES_256_GCM, NONCE_LEN},
+    digest,
+    rand::{SecureRandom, SystemRandom},
+};
+
+use crate::config_init::ConfigError;
+
+/// 主密钥所在的环境变量
+pub const CONFIG_KEY_ENV: &str = "APP_CONFIG_KEY";
+
+/// 主密钥文件路径所在的环境变量，未设置 `APP_CONFIG_KEY` 时读取
+pub const CONFIG_KEY_FILE_ENV: &str = "APP_CONFIG_KEY_FILE";
+
+const ENC_PREFIX: &str = "ENC(";
+const ENC_SUFFIX: &str = ")";
+
+/// 是否为 `ENC(...)` 形式的加密值
+pub fn is_encrypted(value: &str) -> bool {
+    let value = value.trim();
+    value.starts_with(ENC_PREFIX) && value.ends_with(ENC_SUFFIX)
+}
+
+/// 读取主密钥，`APP_CONFIG_KEY` 优先于 `APP_CONFIG_KEY_FILE`，都未设置时返回 `None`
+pub fn master_key() -> Result<Option<String>, ConfigError> {
+    if let Some(key) = std::env::var(CONFIG_KEY_ENV)
+        .ok()
+        .filter(|key| !key.trim().is_empty())
+    {
+        return Ok(Some(key.trim().to_string()));
+    }
+    let Some(path) = std::env::var(CONFIG_KEY_FILE_ENV)
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+    else {
+        return Ok(None);
+    };
+    let key = std::fs::read_to_string(path.trim()).map_err(|e| {
+        ConfigError::EncryptionError(format!("failed to read key file {}: {}", path, e))
+    })?;
+    let key = key.trim();
+    if key.is_empty() {
+        return Err(ConfigError::EncryptionError(format!(
+            "key file {} is empty",
+            path
+        )));
+    }
+    Ok(Some(key.to_string()))
+}
+
+/// 主密钥经 SHA-256 派生为 AES-256-GCM 密钥
+fn cipher_key(master_key: &str) -> LessSafeKey {
+    let hash = digest::digest(&digest::SHA256, master_key.as_bytes());
+    let key = UnboundKey::new(&AES_256_GCM, hash.as_ref()).expect("SHA-256 output is 32 bytes");
+    LessSafeKey::new(key)
+}
+
+/// 加密配置值，返回可直接写入配置文件的 `ENC(base64)`
+///
+/// 密文为随机 nonce 与 AES-256-GCM 密文（含认证标签）拼接后的 Base64，同一明文每次加密结果不同
+pub fn encrypt_value(plaintext: &str, master_key: &str) -> Result<String, ConfigError> {
+    let mut nonce = [0u8; NONCE_LEN];
+    SystemRandom::new()
+        .fill(&mut nonce)
+        .map_err(|_| ConfigError::EncryptionError("failed to generate nonce".to_string()))?;
+
+    let mut sealed = plaintext.as_bytes().to_vec();
+    cipher_key(master_key)
+        .seal_in_place_append_tag(
+            Nonce::assume_unique_for_key(nonce),
+            Aad::empty(),
+            &mut sealed,
+        )
+        .map_err(|_| ConfigError::EncryptionError("failed to encrypt value".to_string()))?;
+
+    let mut payload = nonce.to_vec();
+    payload.extend_from_slice(&sealed);
+    Ok(format!(
+        "{}{}{}",
+        ENC_PREFIX,
+        STANDARD.encode(payload),
+        ENC_SUFFIX
+    ))
+}
+
+/// 解密 `ENC(base64)`，密钥错误或密文被篡改时报错
+pub fn decrypt_value(value: &str, master_key: &str) -> Result<String, ConfigError> {
+    let encoded = value
+        .trim()
+        .strip_prefix(ENC_PREFIX)
+        .and_then(|value| value.strip_suffix(ENC_SUFFIX))
+        .ok_or_else(|| ConfigError::EncryptionError("value is not wrapped in ENC()".to_string()))?;
+    let payload = STANDARD
+        .decode(encoded.trim())
+        .map_err(|e| ConfigError::EncryptionError(format!("invalid base64: {}", e)))?;
+    if payload.len() < NONCE_LEN + AES_256_GCM.tag_len() {
+        return Err(ConfigError::EncryptionError(
+            "ciphertext is too short".to_string(),
+        ));
+    }
+
+    let (nonce, sealed) = payload.split_at(NONCE_LEN);
+    let nonce = Nonce::try_assume_unique_for_key(nonce)
+        .map_err(|_| ConfigError::EncryptionError("invalid nonce".to_string()))?;
+    let mut sealed = sealed.to_vec();
+    let plaintext = cipher_key(master_key)
+        .open_in_place(nonce, Aad::empty(), &mut sealed)
+        .map_err(|_| {
+            ConfigError::EncryptionError(
+                "decryption failed, check the master key or the ciphertext".to_string(),
+            )
+        })?;
+    String::from_utf8(plaintext.to_vec())
+        .map_err(|e| ConfigError::EncryptionError(format!("invalid utf-8: {}", e)))
+}
+
+/// 解密配置树中全部 `ENC(...)` 字符串，存在加密值但未配置主密钥时报错
+pub(crate) fn decrypt_json_values(value: &mut serde_json::Value) -> Result<(), ConfigError> {
+    let mut key = None;
+    decrypt_json(value, "", &mut key)
+}
+
+fn decrypt_json(
+    value: &mut serde_json::Value,
+    path: &str,
+    key: &mut Option<String>,
+) -> Result<(), ConfigError> {
+    match value {
+        serde_json::Value::String(text) if is_encrypted(text) => {
+            *text = decrypt_at(path, text, key)?;
+        },
+        serde_json::Value::Array(items) => {
+            for (index, item) in items.iter_mut().enumerate() {
+                decrypt_json(item, &format!("{}[{}]", path, index), key)?;
+            }
+        },
+        serde_json::Value::Object(object) => {
+            for (field, item) in object.iter_mut() {
+                decrypt_json(item, &join_path(path, field), key)?;
+            }
+        },
+        _ => {},
+    }
+    Ok(())
+}
+
+/// 解密合并后配置中的全部 `ENC(...)` 字符串，以明文覆盖原值
+pub(crate) fn decrypt_config(merged: config::Config) -> Result<config::Config, ConfigError> {
+    let values = merged
+        .collect()
+        .map_err(|e| ConfigError::ParseError(e.to_string()))?;
+    let mut key = None;
+    let mut decrypted = Vec::new();
+    for (field, value) in &values {
+        collect_decrypted(field.clone(), value, &mut key, &mut decrypted)?;
+    }
+    if decrypted.is_empty() {
+        return Ok(merged);
+    }
+
+    let mut builder = config::Config::builder().add_source(merged);
+    for (path, plaintext) in decrypted {
+        builder = builder
+            .set_override(path, plaintext)
+            .map_err(|e| ConfigError::ParseError(e.to_string()))?;
+    }
+    builder
+        .build()
+        .map_err(|e| ConfigError::ParseError(e.to_string()))
+}
+
+fn collect_decrypted(
+    path: String,
+    value: &Value,
+    key: &mut Option<String>,
+    decrypted: &mut Vec<(String, String)>,
+) -> Result<(), ConfigError> {
+    match &value.kind {
+        ValueKind::String(text) if is_encrypted(text) => {
+            let plaintext = decrypt_at(&path, text, key)?;
+            decrypted.push((path, plaintext));
+        },
+        ValueKind::Table(table) => {
+            for (field, value) in table {
+                collect_decrypted(join_path(&path, field), value, key, decrypted)?;
+            }
+        },
+        ValueKind::Array(values) => {
+            for (index, value) in values.iter().enumerate() {
+                collect_decrypted(format!("{}[{}]", path, index), value, key, decrypted)?;
+            }
+        },
+        _ => {},
+    }
+    Ok(())
+}
+
+/// 首次遇到加密值时才读取主密钥，错误信息带上配置路径
+fn decrypt_at(path: &str, text: &str, key: &mut Option<String>) -> Result<String, ConfigError> {
+    if key.is_none() {
+        *key = Some(master_key()?.ok_or_else(|| {
+            ConfigError::EncryptionError(format!(
+                "{} is encrypted but neither {} nor {} is set",
+                path, CONFIG_KEY_ENV, CONFIG_KEY_FILE_ENV
+            ))
+        })?);
+    }
+    let master_key = key.as_deref().unwrap_or_default();
+    decrypt_value(text, master_key).map_err(|e| match e {
+        ConfigError::EncryptionError(message) => {
+            ConfigError::EncryptionError(format!("{}: {}", path, message))
+        },
+        e => e,
+    })
+}
+
+pub(crate) fn join_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_string()
+    } else {
+        format!("{}.{}", path, field)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip() {
+        let encrypted = encrypt_value("postgres://admin:secret@db/soybean", "master").unwrap();
+        assert!(is_encrypted(&encrypted));
+        assert_ne!(
+            encrypted,
+            encrypt_value("postgres://admin:secret@db/soybean", "master").unwrap()
+        );
+        assert_eq!(
+            decrypt_value(&encrypted, "master").unwrap(),
+            "postgres://admin:secret@db/soybean"
+        );
+        assert!(decrypt_value(&encrypted, "other").is_err());
+        assert!(decrypt_value("ENC(bm90LWVub3VnaA==)", "master").is_err());
+    }
+
+    #[test]
+    fn test_decrypt_json_values_without_key() {
+        let mut value = serde_json::json!({ "jwt": { "jwt_secret": "plain" } });
+        decrypt_json_values(&mut value).unwrap();
+        assert_eq!(value["jwt"]["jwt_secret"], "plain");
+    }
+}
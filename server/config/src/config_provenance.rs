@@ -0,0 +1,196 @@
+use std::{
+    collections::BTreeMap,
+    fmt,
+    sync::{Arc, Mutex, MutexGuard},
+};
+
+use config::{Map, Source, Value, ValueKind};
+use server_global::global;
+
+use crate::project_info;
+
+/// 已加载但尚未生效的配置来源，配置校验通过并注入全局状态时发布
+static PENDING: Mutex<Option<ConfigProvenance>> = Mutex::new(None);
+
+/// 配置项的来源
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigOrigin {
+    /// 没有出现在任何配置源中，使用默认值
+    Default,
+    /// 配置文件，包括 `include` 引用的文件和 profile 配置文件
+    File(String),
+    /// 配置文件中的 AWS Secrets Manager / SSM 引用
+    AwsSecrets,
+    /// 远程配置中心（etcd、Consul、Nacos、Apollo）
+    Remote,
+    /// Vault
+    Vault,
+    /// 环境变量，记录变量名
+    Env(String),
+    /// 多实例环境变量覆盖，记录变量名前缀
+    MultiInstanceEnv(String),
+    /// 命令行参数
+    CommandLine,
+}
+
+impl fmt::Display for ConfigOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Default => f.write_str("default"),
+            Self::File(path) => write!(f, "file {}", path),
+            Self::AwsSecrets => f.write_str("aws secrets"),
+            Self::Remote => f.write_str("remote config"),
+            Self::Vault => f.write_str("vault"),
+            Self::Env(name) => write!(f, "env {}", name),
+            Self::MultiInstanceEnv(prefix) => write!(f, "multi-instance env {}*", prefix),
+            Self::CommandLine => f.write_str("command line"),
+        }
+    }
+}
+
+/// 每个配置项最终生效值的来源
+///
+/// 按配置源的加载顺序记录，后加载的覆盖先加载的。环境变量以 `_` 分隔层级，
+/// 查询时 `_` 和 `.` 视为相同，`server.port` 与 `APP_SERVER_PORT` 对应同一项。
+/// 数组整体记录在数组所在的键上，如 `database_instances`
+#[derive(Debug, Clone, Default)]
+pub struct ConfigProvenance {
+    /// 归一化的键到（原始键, 来源）
+    entries: BTreeMap<String, (String, ConfigOrigin)>,
+}
+
+impl ConfigProvenance {
+    /// 配置项的来源，如 `source("server.port")`，没有记录的配置项来自默认值
+    pub fn source(&self, key: &str) -> ConfigOrigin {
+        self.entries
+            .get(&normalize(key))
+            .map(|(_, origin)| origin.clone())
+            .unwrap_or(ConfigOrigin::Default)
+    }
+
+    /// 全部记录的配置项及来源，按键排序
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &ConfigOrigin)> {
+        self.entries
+            .values()
+            .map(|(key, origin)| (key.as_str(), origin))
+    }
+
+    /// 逐项记录来源，只输出键名，不输出配置值
+    pub fn log(&self) {
+        for (key, origin) in self.entries() {
+            project_info!("Config key {} <- {}", key, origin);
+        }
+    }
+
+    pub(crate) fn record(&mut self, key: &str, origin: ConfigOrigin) {
+        self.entries
+            .insert(normalize(key), (key.to_string(), origin));
+    }
+
+    /// 记录配置源中的全部配置项，读取失败时跳过，错误在构建配置时报告
+    pub(crate) fn record_source(&mut self, source: &dyn Source, origin: ConfigOrigin) {
+        if let Ok(values) = source.collect() {
+            for key in leaf_keys(&values) {
+                self.record(&key, origin.clone());
+            }
+        }
+    }
+
+    /// 记录环境变量配置源，按前缀和分隔符还原变量名
+    pub(crate) fn record_env(&mut self, source: &dyn Source, prefix: &str, separator: &str) {
+        if let Ok(values) = source.collect() {
+            for key in leaf_keys(&values) {
+                let name = format!(
+                    "{}{}{}",
+                    prefix,
+                    separator,
+                    key.to_uppercase().replace('.', separator)
+                );
+                self.record(&key, ConfigOrigin::Env(name));
+            }
+        }
+    }
+}
+
+/// 当前生效配置的来源，配置尚未初始化时返回 `None`
+pub async fn config_provenance() -> Option<Arc<ConfigProvenance>> {
+    global::get_config::<ConfigProvenance>().await
+}
+
+/// 暂存刚加载的配置来源
+pub(crate) fn stage(provenance: ConfigProvenance) {
+    *pending() = Some(provenance);
+}
+
+/// 在暂存的配置来源上追加记录，如多实例环境变量覆盖
+pub(crate) fn update_staged(update: impl FnOnce(&mut ConfigProvenance)) {
+    if let Some(provenance) = pending().as_mut() {
+        update(provenance);
+    }
+}
+
+/// 取出暂存的配置来源，配置注入全局状态时调用
+pub(crate) fn take_staged() -> Option<ConfigProvenance> {
+    pending().take()
+}
+
+fn pending() -> MutexGuard<'static, Option<ConfigProvenance>> {
+    PENDING.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn normalize(key: &str) -> String {
+    key.to_ascii_lowercase().replace('_', ".")
+}
+
+fn leaf_keys(values: &Map<String, Value>) -> Vec<String> {
+    let mut keys = Vec::new();
+    for (key, value) in values {
+        collect_leaf_keys(key.clone(), value, &mut keys);
+    }
+    keys
+}
+
+fn collect_leaf_keys(path: String, value: &Value, keys: &mut Vec<String>) {
+    match &value.kind {
+        ValueKind::Table(table) if !table.is_empty() => {
+            for (field, value) in table {
+                collect_leaf_keys(format!("{}.{}", path, field), value, keys);
+            }
+        },
+        _ => keys.push(path),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use config::{File, FileFormat};
+
+    use super::*;
+
+    #[test]
+    fn test_later_sources_override_earlier() {
+        let mut provenance = ConfigProvenance::default();
+        provenance.record_source(
+            &File::from_str(
+                "server:\n  host: 0.0.0.0\n  port: 8080\ndatabase:\n  max_connections: 10\n",
+                FileFormat::Yaml,
+            ),
+            ConfigOrigin::File("application.yaml".to_string()),
+        );
+        provenance.record(
+            "database.max.connections",
+            ConfigOrigin::Env("APP_DATABASE_MAX_CONNECTIONS".to_string()),
+        );
+
+        assert_eq!(
+            provenance.source("server.port"),
+            ConfigOrigin::File("application.yaml".to_string())
+        );
+        assert_eq!(
+            provenance.source("database.max_connections"),
+            ConfigOrigin::Env("APP_DATABASE_MAX_CONNECTIONS".to_string())
+        );
+        assert_eq!(provenance.source("server.role"), ConfigOrigin::Default);
+        assert_eq!(provenance.entries().count(), 3);
+    }
+}
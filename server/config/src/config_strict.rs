@@ -0,0 +1,126 @@
+use serde_json::{Map, Value};
+
+use crate::{config_init::ConfigError, model::Config};
+
+/// 设为 `true` 时开启严格模式，配置文件中存在无法识别的键时启动失败
+const CONFIG_STRICT_ENV: &str = "APP_CONFIG_STRICT";
+
+pub(crate) fn strict_mode_enabled() -> bool {
+    std::env::var(CONFIG_STRICT_ENV)
+        .is_ok_and(|value| matches!(value.trim().to_ascii_lowercase().as_str(), "true" | "1"))
+}
+
+/// 检查配置文件中的键，存在无法识别的键时返回 `ConfigError::UnknownKey`
+///
+/// 以反序列化后的配置为准：配置重新序列化后不存在的键即为未知键，
+/// 效果等同于为全部配置结构启用 `deny_unknown_fields`，但一次列出所有拼错的键，
+/// 并给出同一层级中最接近的键名。环境变量和命令行参数不参与检查
+pub(crate) fn check_unknown_keys(raw: &Value, config: &Config) -> Result<(), ConfigError> {
+    let known = serde_json::to_value(config)?;
+    let unknown = unknown_keys(raw, &known);
+    if unknown.is_empty() {
+        return Ok(());
+    }
+    Err(ConfigError::UnknownKey(unknown.join(", ")))
+}
+
+/// 列出 `raw` 中不存在于 `known` 的键路径，如 `database.max_connection (did you mean max_connections?)`
+pub(crate) fn unknown_keys(raw: &Value, known: &Value) -> Vec<String> {
+    let mut unknown = Vec::new();
+    collect_unknown(String::new(), raw, known, &mut unknown);
+    unknown
+}
+
+fn collect_unknown(path: String, raw: &Value, known: &Value, unknown: &mut Vec<String>) {
+    match (raw, known) {
+        (Value::Object(raw), Value::Object(known)) => {
+            for (key, value) in raw {
+                let child = join_path(&path, key);
+                match known.get(key) {
+                    Some(known) => collect_unknown(child, value, known, unknown),
+                    None => unknown.push(match closest_key(key, known) {
+                        Some(suggestion) => {
+                            format!("{} (did you mean {}?)", child, suggestion)
+                        },
+                        None => child,
+                    }),
+                }
+            }
+        },
+        // 多实例覆盖可能改变数组长度，超出部分按第一个元素的结构检查
+        (Value::Array(raw), Value::Array(known)) => {
+            for (index, value) in raw.iter().enumerate() {
+                if let Some(known) = known.get(index).or_else(|| known.first()) {
+                    collect_unknown(format!("{}[{}]", path, index), value, known, unknown);
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+fn join_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// 同一层级中编辑距离最小且不超过键长三分之一（至少为 2）的键
+fn closest_key<'a>(key: &str, known: &'a Map<String, Value>) -> Option<&'a str> {
+    let max_distance = (key.chars().count() / 3).max(2);
+    known
+        .keys()
+        .map(|candidate| (edit_distance(key, candidate), candidate))
+        .filter(|(distance, _)| *distance <= max_distance)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate.as_str())
+}
+
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_unknown_keys() {
+        let known = json!({
+            "database": { "url": "postgres://", "max_connections": 10, "min_connections": 1 },
+            "redis_instances": [{ "name": "cache", "redis": { "url": "redis://" } }],
+        });
+        let raw = json!({
+            "database": { "url": "postgres://", "max_connection": 10 },
+            "redis_instances": [
+                { "name": "cache" },
+                { "name": "queue", "redis": { "ur": "redis://" } },
+            ],
+            "featuers": { "enabled": true },
+        });
+
+        assert_eq!(
+            unknown_keys(&raw, &known),
+            vec![
+                "database.max_connection (did you mean max_connections?)".to_string(),
+                "redis_instances[1].redis.ur (did you mean url?)".to_string(),
+                "featuers".to_string(),
+            ]
+        );
+        assert!(unknown_keys(&known, &known).is_empty());
+    }
+}
@@ -0,0 +1,311 @@
+use std::{future::Future, time::Duration};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use config::{Map, Source, Value};
+use reqwest::StatusCode;
+use tokio::task::JoinHandle;
+
+use crate::{
+    config_init::{apply_remote_config, load_layered_config, ConfigError},
+    env_config::{kv_config_values, EnvConfigError},
+    model::Config,
+    project_error, project_info,
+};
+
+/// 阻塞查询的最长等待时间
+const BLOCKING_WAIT: &str = "5m";
+
+/// 阻塞查询的请求超时，需大于等待时间
+const BLOCKING_TIMEOUT: Duration = Duration::from_secs(330);
+
+/// 查询失败后重试的间隔
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Consul 连接设置
+///
+/// 通过环境变量启用：
+/// - CONSUL_HTTP_ADDR: Consul 地址，如 `http://consul.service:8500`
+/// - CONSUL_CONFIG_PREFIX: 配置所在的 KV 前缀，如 `soybean-admin/production`
+/// - CONSUL_HTTP_TOKEN: ACL 令牌（可选）
+/// - CONSUL_WATCH: 是否监听变更，默认开启
+#[derive(Debug, Clone)]
+pub struct ConsulSettings {
+    pub addr: String,
+    pub prefix: String,
+    pub token: Option<String>,
+    pub watch: bool,
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl ConsulSettings {
+    /// 令牌取自 `CONSUL_HTTP_TOKEN`，默认监听变更
+    pub fn new(addr: &str, prefix: &str) -> Self {
+        Self {
+            addr: addr.trim_end_matches('/').to_string(),
+            prefix: prefix.trim_matches('/').to_string(),
+            token: env_var("CONSUL_HTTP_TOKEN"),
+            watch: true,
+        }
+    }
+
+    /// 从环境变量读取，未设置 `CONSUL_HTTP_ADDR` 或 `CONSUL_CONFIG_PREFIX` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        let mut settings = Self::new(
+            &env_var("CONSUL_HTTP_ADDR")?,
+            &env_var("CONSUL_CONFIG_PREFIX")?,
+        );
+        settings.watch = env_var("CONSUL_WATCH").is_none_or(|value| value != "false");
+        Some(settings)
+    }
+}
+
+/// Consul KV 配置源
+///
+/// 键布局与 etcd 相同：前缀本身保存完整的配置文档，前缀下的键按路径覆盖单个配置项，
+/// 如 `{prefix}/redis_instances` 可以单独保存 Redis 实例列表
+#[derive(Debug, Clone)]
+pub struct ConsulConfigSource {
+    values: Map<String, Value>,
+}
+
+impl ConsulConfigSource {
+    /// 由前缀下的全部键值构造配置源，文档先于单个配置项生效
+    pub fn from_kvs(prefix: &str, kvs: Vec<(String, String)>) -> Result<Self, EnvConfigError> {
+        let values = kv_config_values(&format!("consul:{}", prefix), prefix, kvs)
+            .map_err(EnvConfigError::Consul)?;
+        Ok(Self { values })
+    }
+
+    /// 配置项数量
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Source for ConsulConfigSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        Ok(self.values.clone())
+    }
+}
+
+/// 从 Consul KV 加载并监听完整配置
+///
+/// 加载结果仍可被 Vault 和环境变量覆盖。监听使用 Consul 阻塞查询，前缀下的键变化后重新加载，
+/// 校验通过才替换全局配置，并调用 `on_reload` 按新的实例列表刷新连接池
+///
+/// # 示例
+/// ```rust,no_run
+/// use server_config::{ConsulConfigLoader, ConsulSettings};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let settings = ConsulSettings::new("http://127.0.0.1:8500", "soybean-admin/production");
+///     let mut loader = ConsulConfigLoader::new(settings, "APP")?;
+///     let config = loader.load().await?;
+///     loader.watch(|| async {});
+///     Ok(())
+/// }
+/// ```
+pub struct ConsulConfigLoader {
+    settings: ConsulSettings,
+    env_prefix: String,
+    client: reqwest::Client,
+    /// 最近一次读取的 `X-Consul-Index`
+    index: u64,
+}
+
+impl ConsulConfigLoader {
+    pub fn new(settings: ConsulSettings, env_prefix: &str) -> Result<Self, ConfigError> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .timeout(BLOCKING_TIMEOUT)
+            .build()
+            .map_err(|e| ConfigError::ConsulError(e.to_string()))?;
+
+        Ok(Self {
+            settings,
+            env_prefix: env_prefix.to_string(),
+            client,
+            index: 0,
+        })
+    }
+
+    pub fn settings(&self) -> &ConsulSettings {
+        &self.settings
+    }
+
+    /// 读取前缀下的全部键并加载配置
+    pub async fn load(&mut self) -> Result<Config, ConfigError> {
+        let (source, index) = self.fetch(None).await.map_err(|e| {
+            project_error!("Failed to load config from consul: {}", e);
+            ConfigError::ConsulError(e.to_string())
+        })?;
+        let source = source.ok_or_else(|| {
+            ConfigError::ConsulError(format!(
+                "no config found under prefix '{}'",
+                self.settings.prefix
+            ))
+        })?;
+        project_info!(
+            "Loaded {} config keys from consul prefix {} at index {}",
+            source.len(),
+            self.settings.prefix,
+            index
+        );
+        self.index = index;
+
+        load_layered_config(None, None, Some(&self.env_prefix), Some(Box::new(source))).await
+    }
+
+    /// 在后台用阻塞查询监听前缀下的键变化，新配置生效后调用 `on_reload`
+    pub fn watch<F, Fut>(mut self, on_reload: F) -> JoinHandle<()>
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = ()> + Send,
+    {
+        tokio::spawn(async move {
+            loop {
+                let index = self.index;
+                match self.fetch(Some(index)).await {
+                    // 索引未变化说明等待超时，继续下一次查询
+                    Ok((_, latest)) if latest == index => {},
+                    Ok((_, latest)) => {
+                        // 索引回退时（如 Consul 重建）从头开始查询
+                        self.index = if latest < index { 0 } else { latest };
+                        match self.load().await {
+                            Ok(config) => {
+                                let source = format!("consul:{}", self.settings.prefix);
+                                if apply_remote_config(config, &source).await {
+                                    on_reload().await;
+                                }
+                            },
+                            Err(e) => project_error!("Failed to reload config from consul: {}", e),
+                        }
+                    },
+                    Err(e) => {
+                        project_error!("Consul config watch interrupted: {}", e);
+                        tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                    },
+                }
+            }
+        })
+    }
+
+    /// 读取前缀下的全部键，指定 `index` 时为阻塞查询；前缀不存在时配置源为 `None`
+    async fn fetch(
+        &self,
+        index: Option<u64>,
+    ) -> Result<(Option<ConsulConfigSource>, u64), EnvConfigError> {
+        let mut query = vec![("recurse", "true".to_string())];
+        if let Some(index) = index {
+            query.push(("index", index.to_string()));
+            query.push(("wait", BLOCKING_WAIT.to_string()));
+        }
+        let mut request = self
+            .client
+            .get(format!(
+                "{}/v1/kv/{}",
+                self.settings.addr, self.settings.prefix
+            ))
+            .query(&query);
+        if let Some(token) = &self.settings.token {
+            request = request.header("X-Consul-Token", token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| EnvConfigError::Consul(format!("request failed: {}", e)))?;
+        let latest = response
+            .headers()
+            .get("X-Consul-Index")
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.parse().ok())
+            .unwrap_or_default();
+        let status = response.status();
+        if status == StatusCode::NOT_FOUND {
+            return Ok((None, latest));
+        }
+        if !status.is_success() {
+            return Err(EnvConfigError::Consul(format!(
+                "reading '{}' returned {}",
+                self.settings.prefix, status
+            )));
+        }
+
+        let entries: Vec<serde_json::Value> = response
+            .json()
+            .await
+            .map_err(|e| EnvConfigError::Consul(format!("invalid response: {}", e)))?;
+        let mut kvs = Vec::new();
+        for entry in entries {
+            let Some(key) = entry.get("Key").and_then(|key| key.as_str()) else {
+                continue;
+            };
+            // 目录键的值为 null
+            let Some(value) = entry.get("Value").and_then(|value| value.as_str()) else {
+                continue;
+            };
+            let value = STANDARD
+                .decode(value)
+                .map_err(|e| EnvConfigError::Consul(format!("{}: invalid base64: {}", key, e)))?;
+            let value = String::from_utf8(value)
+                .map_err(|e| EnvConfigError::Consul(format!("{}: invalid utf-8: {}", key, e)))?;
+            kvs.push((key.to_string(), value));
+        }
+
+        Ok((
+            Some(ConsulConfigSource::from_kvs(&self.settings.prefix, kvs)?),
+            latest,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consul_instance_list_key() {
+        let source = ConsulConfigSource::from_kvs(
+            "soybean-admin/production",
+            vec![
+                (
+                    "soybean-admin/production".to_string(),
+                    "server:\n  host: 0.0.0.0\n  port: 10001\n".to_string(),
+                ),
+                ("soybean-admin/production/".to_string(), String::new()),
+                (
+                    "soybean-admin/production/redis_instances".to_string(),
+                    "- name: cache\n  redis:\n    mode: single\n    url: redis://cache:6379\n"
+                        .to_string(),
+                ),
+            ],
+        )
+        .unwrap();
+
+        let config = config::Config::builder()
+            .add_source(source)
+            .build()
+            .unwrap();
+        assert_eq!(config.get_int("server.port").unwrap(), 10001);
+        assert_eq!(
+            config.get_string("redis_instances[0].redis.url").unwrap(),
+            "redis://cache:6379"
+        );
+    }
+}
@@ -1,9 +1,22 @@
-use config::{Config as ConfigBuilder, ConfigError as ConfigBuilderError, Environment, File};
+use config::{
+    Config as ConfigBuilder, ConfigError as ConfigBuilderError, Environment, File, Map, Source,
+    Value, ValueKind,
+};
 use serde::de::DeserializeOwned;
-use std::path::Path;
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 use thiserror::Error;
 
-use crate::{project_error, project_info};
+use crate::{
+    aws_secrets_source::AwsSecretsSource,
+    cli_args_source::CliArgsSource,
+    config_crypto::decrypt_config,
+    config_init::expand_config,
+    config_provenance::{ConfigOrigin, ConfigProvenance},
+    project_error, project_info,
+};
 
 #[derive(Error, Debug)]
 pub enum EnvConfigError {
@@ -13,11 +26,234 @@ pub enum EnvConfigError {
     UnsupportedFormat(String),
     #[error("IO error: {0}")]
     IoError(#[from] std::io::Error),
+    #[error("Vault error: {0}")]
+    Vault(String),
+    #[error("AWS secrets error: {0}")]
+    AwsSecrets(String),
+    #[error("Etcd error: {0}")]
+    Etcd(String),
+    #[error("Consul error: {0}")]
+    Consul(String),
+    #[error("Dotenv error: {0}")]
+    Dotenv(String),
+    #[error("Encryption error: {0}")]
+    Encryption(String),
+    #[error("Placeholder error: {0}")]
+    Placeholder(String),
+}
+
+/// Vault KV v2 配置源
+///
+/// 启动时读取一条 KV v2 密钥，密钥中的每个字段按配置路径覆盖配置文件，
+/// 如 `jwt.jwt_secret`、`database.url`、`s3.secret_access_key`，环境变量仍然优先。
+/// 字段值可以是字符串、数字、布尔值或 JSON 对象。
+///
+/// 通过 Vault 标准环境变量启用：
+/// - VAULT_ADDR: Vault 地址，如 `https://vault.example.com:8200`
+/// - VAULT_TOKEN: 访问令牌
+/// - VAULT_NAMESPACE: 命名空间（可选，Vault 企业版）
+/// - VAULT_KV_MOUNT: KV v2 引擎挂载路径（可选，默认为 `secret`）
+/// - VAULT_CONFIG_PATH: 密钥路径，如 `soybean-admin/production`
+#[derive(Debug, Clone)]
+pub struct VaultConfigSource {
+    path: String,
+    values: Map<String, Value>,
+}
+
+/// Vault 连接设置
+#[derive(Debug, Clone)]
+pub struct VaultSettings {
+    pub addr: String,
+    pub token: String,
+    pub namespace: Option<String>,
+    pub mount: String,
+    pub path: String,
+}
+
+impl VaultSettings {
+    /// 从 Vault 标准环境变量读取，未设置 `VAULT_ADDR` 或 `VAULT_CONFIG_PATH` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        Some(Self {
+            addr: var("VAULT_ADDR")?,
+            token: var("VAULT_TOKEN").unwrap_or_default(),
+            namespace: var("VAULT_NAMESPACE"),
+            mount: var("VAULT_KV_MOUNT").unwrap_or_else(|| "secret".to_string()),
+            path: var("VAULT_CONFIG_PATH")?,
+        })
+    }
+
+    fn url(&self) -> String {
+        format!(
+            "{}/v1/{}/data/{}",
+            self.addr.trim_end_matches('/'),
+            self.mount.trim_matches('/'),
+            self.path.trim_matches('/')
+        )
+    }
+}
+
+impl VaultConfigSource {
+    /// 读取 KV v2 密钥的最新版本，Vault 不可用或密钥不存在时返回错误
+    pub async fn fetch(settings: &VaultSettings) -> Result<Self, EnvConfigError> {
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| EnvConfigError::Vault(e.to_string()))?;
+        let mut request = client
+            .get(settings.url())
+            .header("X-Vault-Token", &settings.token);
+        if let Some(namespace) = &settings.namespace {
+            request = request.header("X-Vault-Namespace", namespace);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| EnvConfigError::Vault(format!("request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(EnvConfigError::Vault(format!(
+                "reading '{}' returned {}",
+                settings.path, status
+            )));
+        }
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| EnvConfigError::Vault(format!("invalid response: {}", e)))?;
+        let Some(serde_json::Value::Object(data)) = body.pointer("/data/data").cloned() else {
+            return Err(EnvConfigError::Vault(format!(
+                "secret '{}' has no data",
+                settings.path
+            )));
+        };
+
+        Ok(Self::from_data(&settings.path, data))
+    }
+
+    /// 由密钥字段构造配置源
+    pub fn from_data(path: &str, data: serde_json::Map<String, serde_json::Value>) -> Self {
+        let origin = format!("vault:{}", path);
+        let values = data
+            .into_iter()
+            .map(|(key, value)| (key, Value::new(Some(&origin), to_value_kind(value))))
+            .collect();
+
+        Self {
+            path: path.to_string(),
+            values,
+        }
+    }
+}
+
+pub(crate) fn to_value_kind(value: serde_json::Value) -> ValueKind {
+    match value {
+        serde_json::Value::Null => ValueKind::Nil,
+        serde_json::Value::Bool(value) => ValueKind::Boolean(value),
+        serde_json::Value::Number(value) => match value.as_i64() {
+            Some(value) => ValueKind::I64(value),
+            None => ValueKind::Float(value.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(value) => ValueKind::String(value),
+        serde_json::Value::Array(values) => ValueKind::Array(
+            values
+                .into_iter()
+                .map(|value| Value::new(None, to_value_kind(value)))
+                .collect(),
+        ),
+        serde_json::Value::Object(values) => ValueKind::Table(
+            values
+                .into_iter()
+                .map(|(key, value)| (key, Value::new(None, to_value_kind(value))))
+                .collect(),
+        ),
+    }
+}
+
+/// 把配置中心前缀下的键值转换为配置项
+///
+/// 键等于前缀时值为完整的 YAML（或 JSON）配置文档，`{prefix}/a/b` 按路径覆盖 `a.b`，
+/// 值按 YAML 解析，可以是标量或对象。单个配置项总是覆盖文档中的值
+pub(crate) fn kv_config_values(
+    origin: &str,
+    prefix: &str,
+    kvs: Vec<(String, String)>,
+) -> Result<Map<String, Value>, String> {
+    let origin = origin.to_string();
+    let mut document = serde_json::Map::new();
+    let mut overrides = Vec::new();
+
+    for (key, value) in kvs {
+        if key == prefix {
+            let parsed: serde_json::Value =
+                serde_yaml::from_str(&value).map_err(|e| format!("{}: {}", key, e))?;
+            let serde_json::Value::Object(parsed) = parsed else {
+                return Err(format!("{}: config document must be a mapping", key));
+            };
+            document = parsed;
+        } else if let Some(path) = key.strip_prefix(&format!("{}/", prefix)) {
+            // 目录键（如 Consul 中以 / 结尾的键）没有值
+            if path.is_empty() || path.ends_with('/') {
+                continue;
+            }
+            let value: serde_json::Value =
+                serde_yaml::from_str(&value).map_err(|e| format!("{}: {}", key, e))?;
+            overrides.push((path.to_string(), value));
+        }
+    }
+    // 覆盖项合并进文档树，而不是与文档的顶层表并列，否则两者的先后取决于哈希顺序
+    for (path, value) in overrides {
+        merge_at_path(&mut document, &path.split('/').collect::<Vec<_>>(), value);
+    }
+
+    Ok(document
+        .into_iter()
+        .map(|(field, value)| (field, Value::new(Some(&origin), to_value_kind(value))))
+        .collect())
+}
+
+/// 把值写入文档树的嵌套路径，路径上缺失或不是对象的节点替换为对象
+fn merge_at_path(
+    document: &mut serde_json::Map<String, serde_json::Value>,
+    path: &[&str],
+    value: serde_json::Value,
+) {
+    let Some((last, parents)) = path.split_last() else {
+        return;
+    };
+    let mut node = document;
+    for segment in parents {
+        let entry = node
+            .entry(segment.to_string())
+            .or_insert_with(|| serde_json::Value::Object(serde_json::Map::new()));
+        if !entry.is_object() {
+            *entry = serde_json::Value::Object(serde_json::Map::new());
+        }
+        node = entry.as_object_mut().expect("node is an object");
+    }
+    node.insert(last.to_string(), value);
+}
+
+impl Source for VaultConfigSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, ConfigBuilderError> {
+        Ok(self.values.clone())
+    }
 }
 
 /// 环境变量优先的配置加载器
 ///
-/// 加载优先级：环境变量 > 配置文件 > 默认值
+/// 加载优先级：命令行参数 > 环境变量 > Vault > 远程配置中心 > 配置文件（含 AWS 密钥引用） > 默认值
 ///
 /// 环境变量命名规范：
 /// - 使用 APP_ 前缀
@@ -36,17 +272,31 @@ pub enum EnvConfigError {
 ///     .expect("Failed to load config");
 /// ```
 pub struct EnvConfigLoader {
+    included_files: Vec<String>,
     file_path: Option<String>,
+    profile_path: Option<String>,
+    dotenv_path: Option<PathBuf>,
     env_prefix: String,
     env_separator: String,
+    aws_secrets: Option<AwsSecretsSource>,
+    remote: Option<Box<dyn Source + Send + Sync>>,
+    vault: Option<VaultConfigSource>,
+    cli_args: Option<CliArgsSource>,
 }
 
 impl Default for EnvConfigLoader {
     fn default() -> Self {
         Self {
+            included_files: Vec::new(),
             file_path: None,
+            profile_path: None,
+            dotenv_path: None,
             env_prefix: "APP".to_string(),
             env_separator: "_".to_string(),
+            aws_secrets: None,
+            remote: None,
+            vault: None,
+            cli_args: None,
         }
     }
 }
@@ -63,6 +313,26 @@ impl EnvConfigLoader {
         self
     }
 
+    /// 设置配置文件通过 `include` 引用的文件，按顺序先于配置文件加载
+    pub fn with_included_files(mut self, files: Vec<String>) -> Self {
+        self.included_files = files;
+        self
+    }
+
+    /// 设置叠加在配置文件之上的 profile 配置文件，如 `application-prod.yaml`
+    pub fn with_profile_file<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.profile_path = Some(path.as_ref().to_string_lossy().to_string());
+        self
+    }
+
+    /// 设置 `.env` 文件路径，加载配置前将其中的变量写入进程环境
+    ///
+    /// 已存在的环境变量不会被覆盖，文件不存在时忽略
+    pub fn with_dotenv<P: AsRef<Path>>(mut self, path: P) -> Self {
+        self.dotenv_path = Some(path.as_ref().to_path_buf());
+        self
+    }
+
     /// 设置环境变量前缀（默认为 "APP"）
     pub fn with_env_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
         self.env_prefix = prefix.into();
@@ -75,47 +345,164 @@ impl EnvConfigLoader {
         self
     }
 
+    /// 设置配置文件中 AWS 密钥引用的解析结果，覆盖配置文件中的引用字符串
+    pub fn with_aws_secrets(mut self, source: AwsSecretsSource) -> Self {
+        self.aws_secrets = Some(source);
+        self
+    }
+
+    /// 设置远程配置中心的配置源（如 etcd、Consul），覆盖配置文件，Vault 和环境变量仍可覆盖
+    pub fn with_remote(mut self, source: Box<dyn Source + Send + Sync>) -> Self {
+        self.remote = Some(source);
+        self
+    }
+
+    /// 设置 Vault 配置源，优先级介于配置文件和环境变量之间
+    pub fn with_vault(mut self, source: VaultConfigSource) -> Self {
+        self.vault = Some(source);
+        self
+    }
+
+    /// 设置命令行参数，如 `--server.port=9090`，覆盖包括环境变量在内的全部配置源
+    ///
+    /// 传入不含程序名的参数列表，通常为 `std::env::args().skip(1)`
+    pub fn with_cli_args<I, S>(mut self, args: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.cli_args = Some(CliArgsSource::from_args(args));
+        self
+    }
+
     /// 加载配置
     ///
     /// 按照以下优先级加载配置：
-    /// 1. 环境变量（最高优先级）
-    /// 2. 配置文件
-    /// 3. 默认值（最低优先级）
+    /// 1. 命令行参数（最高优先级）
+    /// 2. 环境变量
+    /// 3. Vault
+    /// 4. 远程配置中心
+    /// 5. profile 配置文件
+    /// 6. 配置文件
+    /// 7. 默认值（最低优先级）
     pub fn load<T>(&self) -> Result<T, EnvConfigError>
+    where
+        T: DeserializeOwned,
+    {
+        self.load_with_provenance().map(|(config, _)| config)
+    }
+
+    /// 加载配置，同时返回每个配置项最终生效值的来源
+    pub fn load_with_provenance<T>(&self) -> Result<(T, ConfigProvenance), EnvConfigError>
     where
         T: DeserializeOwned,
     {
         let mut builder = ConfigBuilder::builder();
+        let mut provenance = ConfigProvenance::default();
+
+        // 配置文件引用的文件优先级最低
+        for included in &self.included_files {
+            project_info!("Loading included config from file: {}", included);
+
+            let file_format = self.detect_file_format(included)?;
+            let source = File::with_name(included).format(file_format);
+            provenance.record_source(&source, ConfigOrigin::File(included.clone()));
+            builder = builder.add_source(source);
+        }
 
         // 1. 如果指定了配置文件，先加载文件配置
         if let Some(file_path) = &self.file_path {
             project_info!("Loading config from file: {}", file_path);
 
             let file_format = self.detect_file_format(file_path)?;
-            builder = builder.add_source(File::with_name(file_path).format(file_format));
+            let source = File::with_name(file_path).format(file_format);
+            provenance.record_source(&source, ConfigOrigin::File(file_path.clone()));
+            builder = builder.add_source(source);
+        }
+
+        // profile 配置文件覆盖基础配置文件
+        if let Some(profile_path) = &self.profile_path {
+            project_info!("Loading profile config from file: {}", profile_path);
+
+            let file_format = self.detect_file_format(profile_path)?;
+            let source = File::with_name(profile_path).format(file_format);
+            provenance.record_source(&source, ConfigOrigin::File(profile_path.clone()));
+            builder = builder.add_source(source);
         }
 
-        // 2. 加载环境变量配置（会覆盖文件配置）
+        // 替换配置文件中的 AWS 密钥引用
+        if let Some(aws_secrets) = &self.aws_secrets {
+            project_info!("Resolved {} AWS secret references", aws_secrets.len());
+            provenance.record_source(aws_secrets, ConfigOrigin::AwsSecrets);
+            builder = builder.add_source(aws_secrets.clone());
+        }
+
+        // 加载远程配置中心的配置（会覆盖文件配置）
+        if let Some(remote) = &self.remote {
+            provenance.record_source(remote.as_ref(), ConfigOrigin::Remote);
+            builder = builder.add_source(vec![remote.clone_into_box()]);
+        }
+
+        // 2. 加载 Vault 中的密钥（会覆盖文件配置）
+        if let Some(vault) = &self.vault {
+            project_info!(
+                "Loading {} config keys from vault: {}",
+                vault.values.len(),
+                vault.path
+            );
+            provenance.record_source(vault, ConfigOrigin::Vault);
+            builder = builder.add_source(vault.clone());
+        }
+
+        // 3. 加载环境变量配置（会覆盖文件和 Vault 配置）
+        if let Some(dotenv_path) = &self.dotenv_path {
+            self.load_dotenv(dotenv_path)?;
+        }
         project_info!(
             "Loading config from environment variables with prefix: {}",
             self.env_prefix
         );
-        builder = builder.add_source(
-            Environment::with_prefix(&self.env_prefix)
-                .separator(&self.env_separator)
-                .try_parsing(true),
-        );
+        let environment = Environment::with_prefix(&self.env_prefix)
+            .separator(&self.env_separator)
+            .try_parsing(true);
+        provenance.record_env(&environment, &self.env_prefix, &self.env_separator);
+        builder = builder.add_source(environment);
+
+        // 加载命令行参数（会覆盖环境变量）
+        if let Some(cli_args) = self.cli_args.as_ref().filter(|args| !args.is_empty()) {
+            project_info!("Loading {} config keys from command line", cli_args.len());
+            provenance.record_source(cli_args, ConfigOrigin::CommandLine);
+            builder = builder.add_source(cli_args.clone());
+        }
 
-        // 3. 构建最终配置
-        let config = builder.build()?;
+        // 4. 构建最终配置，展开各层中的 `${VAR}` 占位符，再解密 `ENC(...)` 值
+        let config = expand_config(builder.build()?)
+            .map_err(|e| EnvConfigError::Placeholder(e.to_string()))?;
+        let config =
+            decrypt_config(config).map_err(|e| EnvConfigError::Encryption(e.to_string()))?;
 
-        // 4. 反序列化为目标类型
+        // 5. 反序列化为目标类型
         let result: T = config.try_deserialize()?;
 
         project_info!(
             "Configuration loaded successfully with environment variable override support"
         );
-        Ok(result)
+        Ok((result, provenance))
+    }
+
+    /// 将 `.env` 文件中的变量写入进程环境，供环境变量配置源和多实例处理器读取
+    fn load_dotenv(&self, path: &Path) -> Result<(), EnvConfigError> {
+        match dotenvy::from_path(path) {
+            Ok(()) => {
+                project_info!("Loaded environment variables from {}", path.display());
+                Ok(())
+            },
+            Err(e) if e.not_found() => Ok(()),
+            Err(e) => {
+                project_error!("Failed to load {}: {}", path.display(), e);
+                Err(EnvConfigError::Dotenv(format!("{}: {}", path.display(), e)))
+            },
+        }
     }
 
     /// 检测文件格式
@@ -229,4 +616,53 @@ mod tests {
         let loader = EnvConfigLoader::new().with_env_separator("__");
         assert_eq!(loader.env_separator, "__");
     }
+
+    #[test]
+    fn test_vault_source_overrides_file() {
+        let data = serde_json::json!({
+            "jwt.jwt_secret": "from-vault",
+            "database": { "url": "postgres://vault/db" },
+        });
+        let serde_json::Value::Object(data) = data else {
+            unreachable!()
+        };
+        let vault = VaultConfigSource::from_data("soybean-admin/test", data);
+
+        let config = ConfigBuilder::builder()
+            .add_source(File::from_str(
+                "jwt:\n  jwt_secret: from-file\n  issuer: soybean\ndatabase:\n  url: postgres://file/db\n",
+                config::FileFormat::Yaml,
+            ))
+            .add_source(vault)
+            .build()
+            .unwrap();
+
+        assert_eq!(config.get_string("jwt.jwt_secret").unwrap(), "from-vault");
+        assert_eq!(config.get_string("jwt.issuer").unwrap(), "soybean");
+        assert_eq!(
+            config.get_string("database.url").unwrap(),
+            "postgres://vault/db"
+        );
+    }
+
+    #[test]
+    fn test_dotenv_does_not_override_environment() {
+        let path = std::env::temp_dir().join(format!("soybean-dotenv-{}.env", std::process::id()));
+        std::fs::write(
+            &path,
+            "DOTENV_TEST_SERVER_HOST=127.0.0.1\nDOTENV_TEST_SERVER_PORT=9528\n",
+        )
+        .unwrap();
+        std::env::set_var("DOTENV_TEST_SERVER_PORT", "10001");
+
+        let config: serde_json::Value = EnvConfigLoader::new()
+            .with_dotenv(&path)
+            .with_env_prefix("DOTENV_TEST")
+            .load()
+            .unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(config["server"]["host"], "127.0.0.1");
+        assert_eq!(config["server"]["port"], 10001);
+    }
 }
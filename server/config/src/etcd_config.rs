@@ -0,0 +1,380 @@
+use std::time::Duration;
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use config::{Map, Source, Value};
+use futures::StreamExt;
+use serde_json::json;
+use tokio::task::JoinHandle;
+
+use crate::{
+    config_init::{apply_remote_config, load_layered_config, ConfigError},
+    env_config::{kv_config_values, EnvConfigError},
+    model::Config,
+    project_error, project_info,
+};
+
+/// 监听断开后重连的间隔
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// etcd 连接设置
+///
+/// 通过环境变量启用：
+/// - ETCD_ENDPOINTS: 逗号分隔的 etcd 地址，如 `http://etcd-0:2379,http://etcd-1:2379`
+/// - ETCD_CONFIG_PREFIX: 配置所在的键前缀，如 `/soybean-admin/production`
+/// - ETCD_USERNAME / ETCD_PASSWORD: 启用认证时的用户名和密码（可选）
+/// - ETCD_WATCH: 是否监听变更，默认开启
+#[derive(Debug, Clone)]
+pub struct EtcdSettings {
+    pub endpoints: Vec<String>,
+    pub prefix: String,
+    pub username: Option<String>,
+    pub password: Option<String>,
+    pub watch: bool,
+}
+
+impl EtcdSettings {
+    /// 从环境变量读取，未设置 `ETCD_ENDPOINTS` 或 `ETCD_CONFIG_PREFIX` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        let var = |name: &str| {
+            std::env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| !value.is_empty())
+        };
+
+        let endpoints: Vec<String> = var("ETCD_ENDPOINTS")?
+            .split(',')
+            .map(|endpoint| endpoint.trim().trim_end_matches('/').to_string())
+            .filter(|endpoint| !endpoint.is_empty())
+            .collect();
+        if endpoints.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            endpoints,
+            prefix: var("ETCD_CONFIG_PREFIX")?.trim_end_matches('/').to_string(),
+            username: var("ETCD_USERNAME"),
+            password: var("ETCD_PASSWORD"),
+            watch: var("ETCD_WATCH").is_none_or(|value| value != "false"),
+        })
+    }
+}
+
+/// etcd 配置源
+///
+/// 键前缀本身保存完整的 YAML（或 JSON）配置文档，前缀下的键按路径覆盖单个配置项，
+/// 如 `{prefix}/database/url` 对应 `database.url`，值按 YAML 解析，可以是标量或对象
+#[derive(Debug, Clone)]
+pub struct EtcdConfigSource {
+    values: Map<String, Value>,
+}
+
+impl EtcdConfigSource {
+    /// 由前缀下的全部键值构造配置源，文档先于单个配置项生效
+    pub fn from_kvs(prefix: &str, kvs: Vec<(String, String)>) -> Result<Self, EnvConfigError> {
+        let values = kv_config_values(&format!("etcd:{}", prefix), prefix, kvs)
+            .map_err(EnvConfigError::Etcd)?;
+        Ok(Self { values })
+    }
+
+    /// 配置项数量
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+impl Source for EtcdConfigSource {
+    fn clone_into_box(&self) -> Box<dyn Source + Send + Sync> {
+        Box::new(self.clone())
+    }
+
+    fn collect(&self) -> Result<Map<String, Value>, config::ConfigError> {
+        Ok(self.values.clone())
+    }
+}
+
+/// 从 etcd 加载并监听完整配置
+///
+/// 通过 etcd v3 的 HTTP 网关访问，依次尝试各个地址。加载结果仍可被 Vault 和环境变量覆盖；
+/// 监听到前缀下的键变化后重新加载，校验通过才替换全局配置，
+/// 已建立的连接池等启动期资源不会重建
+///
+/// # 示例
+/// ```rust,no_run
+/// use server_config::{EtcdConfigLoader, EtcdSettings};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let settings = EtcdSettings::from_env().expect("ETCD_ENDPOINTS is not set");
+///     let loader = EtcdConfigLoader::new(settings, "APP")?;
+///     let (config, revision) = loader.load().await?;
+///     loader.watch(revision);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct EtcdConfigLoader {
+    settings: EtcdSettings,
+    env_prefix: String,
+    client: reqwest::Client,
+}
+
+impl EtcdConfigLoader {
+    pub fn new(settings: EtcdSettings, env_prefix: &str) -> Result<Self, ConfigError> {
+        // 监听请求长期保持连接，只限制建立连接的时间
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| ConfigError::EtcdError(e.to_string()))?;
+
+        Ok(Self {
+            settings,
+            env_prefix: env_prefix.to_string(),
+            client,
+        })
+    }
+
+    /// 读取前缀下的全部键并加载配置，返回配置和读取时的修订版本
+    pub async fn load(&self) -> Result<(Config, i64), ConfigError> {
+        let (source, revision) = self.fetch().await.map_err(|e| {
+            project_error!("Failed to load config from etcd: {}", e);
+            ConfigError::EtcdError(e.to_string())
+        })?;
+        project_info!(
+            "Loaded {} config keys from etcd prefix {} at revision {}",
+            source.len(),
+            self.settings.prefix,
+            revision
+        );
+
+        let config =
+            load_layered_config(None, None, Some(&self.env_prefix), Some(Box::new(source))).await?;
+        Ok((config, revision))
+    }
+
+    /// 在后台监听前缀下的键变化
+    pub fn watch(self, revision: i64) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let mut revision = revision;
+            loop {
+                if let Err(e) = self.watch_once(&mut revision).await {
+                    project_error!("etcd config watch interrupted: {}", e);
+                }
+                tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                // 断开期间可能错过变更，重连前重新加载一次
+                revision = self.reload(revision).await;
+            }
+        })
+    }
+
+    async fn reload(&self, revision: i64) -> i64 {
+        match self.load().await {
+            Ok((config, latest)) => {
+                if latest != revision {
+                    apply_remote_config(config, &format!("etcd:{}", self.settings.prefix)).await;
+                }
+                latest
+            },
+            Err(e) => {
+                project_error!("Failed to reload config from etcd: {}", e);
+                revision
+            },
+        }
+    }
+
+    async fn watch_once(&self, revision: &mut i64) -> Result<(), EnvConfigError> {
+        let (key, range_end) = self.range();
+        let body = json!({
+            "create_request": {
+                "key": key,
+                "range_end": range_end,
+                "start_revision": (*revision + 1).to_string(),
+            }
+        });
+        let response = self.post("/v3/watch", &body).await?;
+        let mut stream = response.bytes_stream();
+        let mut buffer = Vec::new();
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| EnvConfigError::Etcd(e.to_string()))?;
+            buffer.extend_from_slice(&chunk);
+            // 网关按行输出监听结果
+            while let Some(end) = buffer.iter().position(|byte| *byte == b'\n') {
+                let line: Vec<u8> = buffer.drain(..=end).collect();
+                let Ok(message) = serde_json::from_slice::<serde_json::Value>(&line) else {
+                    continue;
+                };
+                let has_events = message
+                    .pointer("/result/events")
+                    .and_then(|events| events.as_array())
+                    .is_some_and(|events| !events.is_empty());
+                if has_events {
+                    *revision = self.reload(*revision).await;
+                }
+            }
+        }
+
+        Err(EnvConfigError::Etcd("watch stream closed".to_string()))
+    }
+
+    async fn fetch(&self) -> Result<(EtcdConfigSource, i64), EnvConfigError> {
+        let (key, range_end) = self.range();
+        let response = self
+            .post(
+                "/v3/kv/range",
+                &json!({ "key": key, "range_end": range_end }),
+            )
+            .await?;
+        let body: serde_json::Value = response
+            .json()
+            .await
+            .map_err(|e| EnvConfigError::Etcd(format!("invalid response: {}", e)))?;
+
+        let revision = body
+            .pointer("/header/revision")
+            .and_then(json_i64)
+            .unwrap_or_default();
+        let mut kvs = Vec::new();
+        for kv in body
+            .get("kvs")
+            .and_then(|kvs| kvs.as_array())
+            .into_iter()
+            .flatten()
+        {
+            kvs.push((decode(kv.get("key"))?, decode(kv.get("value"))?));
+        }
+        if kvs.is_empty() {
+            return Err(EnvConfigError::Etcd(format!(
+                "no config found under prefix '{}'",
+                self.settings.prefix
+            )));
+        }
+
+        Ok((
+            EtcdConfigSource::from_kvs(&self.settings.prefix, kvs)?,
+            revision,
+        ))
+    }
+
+    /// 前缀的键范围，base64 编码
+    fn range(&self) -> (String, String) {
+        let key = self.settings.prefix.as_bytes().to_vec();
+        let mut range_end = key.clone();
+        // 前缀范围的结束键为最后一个字节加一
+        while let Some(last) = range_end.pop() {
+            if last < u8::MAX {
+                range_end.push(last + 1);
+                break;
+            }
+        }
+        (STANDARD.encode(key), STANDARD.encode(range_end))
+    }
+
+    /// 依次尝试各个地址，返回第一个成功的响应
+    async fn post(
+        &self,
+        path: &str,
+        body: &serde_json::Value,
+    ) -> Result<reqwest::Response, EnvConfigError> {
+        let mut last_error = None;
+        for endpoint in &self.settings.endpoints {
+            let result = async {
+                let mut request = self.client.post(format!("{}{}", endpoint, path)).json(body);
+                if let Some(token) = self.authenticate(endpoint).await? {
+                    request = request.header("Authorization", token);
+                }
+                let response = request
+                    .send()
+                    .await
+                    .map_err(|e| EnvConfigError::Etcd(format!("{}: {}", endpoint, e)))?;
+                if !response.status().is_success() {
+                    return Err(EnvConfigError::Etcd(format!(
+                        "{}{} returned {}",
+                        endpoint,
+                        path,
+                        response.status()
+                    )));
+                }
+                Ok(response)
+            }
+            .await;
+            match result {
+                Ok(response) => return Ok(response),
+                Err(e) => last_error = Some(e),
+            }
+        }
+        Err(last_error.unwrap_or_else(|| EnvConfigError::Etcd("no endpoints".to_string())))
+    }
+
+    /// 启用认证时获取令牌
+    async fn authenticate(&self, endpoint: &str) -> Result<Option<String>, EnvConfigError> {
+        let (Some(username), Some(password)) = (&self.settings.username, &self.settings.password)
+        else {
+            return Ok(None);
+        };
+        let body: serde_json::Value = self
+            .client
+            .post(format!("{}/v3/auth/authenticate", endpoint))
+            .timeout(Duration::from_secs(10))
+            .json(&json!({ "name": username, "password": password }))
+            .send()
+            .await
+            .map_err(|e| EnvConfigError::Etcd(format!("{}: {}", endpoint, e)))?
+            .json()
+            .await
+            .map_err(|e| EnvConfigError::Etcd(format!("invalid auth response: {}", e)))?;
+        body.get("token")
+            .and_then(|token| token.as_str())
+            .map(|token| Some(token.to_string()))
+            .ok_or_else(|| EnvConfigError::Etcd(format!("{}: authentication failed", endpoint)))
+    }
+}
+
+/// 网关以字符串输出 64 位整数
+fn json_i64(value: &serde_json::Value) -> Option<i64> {
+    value
+        .as_i64()
+        .or_else(|| value.as_str().and_then(|value| value.parse().ok()))
+}
+
+fn decode(value: Option<&serde_json::Value>) -> Result<String, EnvConfigError> {
+    let encoded = value.and_then(|value| value.as_str()).unwrap_or_default();
+    let bytes = STANDARD
+        .decode(encoded)
+        .map_err(|e| EnvConfigError::Etcd(format!("invalid base64: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| EnvConfigError::Etcd(format!("invalid utf-8: {}", e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_etcd_keys_override_document() {
+        let source = EtcdConfigSource::from_kvs(
+            "/soybean",
+            vec![
+                (
+                    "/soybean".to_string(),
+                    "server:\n  host: 0.0.0.0\n  port: 10001\n".to_string(),
+                ),
+                ("/soybean/server/port".to_string(), "9528".to_string()),
+                ("/soybean-other/ignored".to_string(), "1".to_string()),
+            ],
+        )
+        .unwrap();
+
+        let config = config::Config::builder()
+            .add_source(source)
+            .build()
+            .unwrap();
+        assert_eq!(config.get_string("server.host").unwrap(), "0.0.0.0");
+        assert_eq!(config.get_int("server.port").unwrap(), 9528);
+        assert!(config.get_string("ignored").is_err());
+    }
+}
@@ -1,16 +1,64 @@
+pub use apollo_config::{
+    ApolloConfigClient, ApolloConfigSource, ApolloSettings, APOLLO_DEFAULT_CLUSTER,
+    APOLLO_DEFAULT_NAMESPACE,
+};
+pub use aws_secrets_source::{AwsSecretsSource, SecretReference, SecretTarget};
+pub use cli_args_source::CliArgsSource;
+pub use config_crypto::{
+    decrypt_value, encrypt_value, is_encrypted, master_key, CONFIG_KEY_ENV, CONFIG_KEY_FILE_ENV,
+};
 pub use config_init::{
-    init_from_env_only, init_from_file, init_from_file_with_env,
-    init_from_file_with_multi_instance_env,
+    init_from_apollo, init_from_consul, init_from_dir, init_from_env_only, init_from_etcd,
+    init_from_file, init_from_file_with_env, init_from_file_with_multi_instance_env,
+    init_from_file_with_profile, init_from_nacos, init_from_url, init_from_url_with_settings,
+    parse_config_str, ConfigError,
+};
+pub use config_provenance::{config_provenance, ConfigOrigin, ConfigProvenance};
+pub use config_staging::{
+    apply_canary, promote_staged_config, rollback_staged_config, stage_config,
+    staged_config_status, validate_config, ConfigSubsystem, StageStatus, StagedConfigStatus,
+};
+pub use consul_config::{ConsulConfigLoader, ConsulConfigSource, ConsulSettings};
+pub use env_config::{
+    load_config_from_env, load_config_with_env, EnvConfigLoader, VaultConfigSource, VaultSettings,
 };
-pub use env_config::{load_config_from_env, load_config_with_env, EnvConfigLoader};
+pub use etcd_config::{EtcdConfigLoader, EtcdConfigSource, EtcdSettings};
 pub use model::{
-    Config, DatabaseConfig, DatabasesInstancesConfig, JwtConfig, MongoConfig, MongoInstancesConfig,
-    OptionalConfigs, RedisConfig, RedisInstancesConfig, RedisMode, S3Config, S3InstancesConfig,
-    ServerConfig,
+    append_pg_option, parse_byte_size, parse_duration_secs, redact_text, AccessLogConfig,
+    AccessLogFormat, AccessLogRotation, AccessReviewConfig, AlertConfig, ApiUsageConfig,
+    AuditArchiveConfig, AuditChainConfig, BotDetectionConfig, BotRouteGroup, BreakGlassConfig,
+    CacheConfig, ClusterConfig, ComplianceConfig, ConcurrencyConfig, ConcurrencyMode, Config,
+    ConsistencyCategory, ConsistencyConfig, DatabaseConfig, DatabaseDriver,
+    DatabasesInstancesConfig, Environment, ExportWatermarkConfig, FieldAccessConfig,
+    FieldAccessRule, InactiveAccountConfig, JwtAlgorithm, JwtConfig, LoginThrottleConfig,
+    MeteringConfig, MongoConfig, MongoInstancesConfig, MysqlOptions, NotificationConfig,
+    ObjectLockMode, OptionalConfigs, PasskeyConfig, PolicyGateConfig, PostgresOptions,
+    RecorderConfig, RedisConfig, RedisInstancesConfig, RedisMode, RedisRetryConfig, RedisTlsConfig,
+    ReplicaStrategy, RowLevelSecurityConfig, RuntimeConfig, S3Config, S3CredentialSource,
+    S3CredentialsConfig, S3InstancesConfig, ScannerConfig, SecretString, SecurityConfig,
+    SensitiveOperationConfig, SensitiveOperationRule, ServerConfig, ServerRole, ShareLinkConfig,
+    SiemConfig, SiemFormat, SiemTransport, SqliteOptions, SslMode, StepUpConfig, StepUpRule,
+    StorageConfig, TelemetryConfig, TenantConfig, TrailerPolicy, WatermarkPolicy,
+    CONFIG_SCHEMA_VERSION, MAX_HEADER_COUNT_LIMIT,
 };
+pub use nacos_config::{NacosConfigProvider, NacosSettings, NACOS_DEFAULT_GROUP};
 pub use server_global::{project_error, project_info};
+pub use url_config::{UrlConfigLoader, UrlSettings};
+pub use validator::ConfigValidator;
 
+mod apollo_config;
+mod aws_secrets_source;
+mod cli_args_source;
+mod config_crypto;
 mod config_init;
+mod config_provenance;
+mod config_staging;
+mod config_strict;
+mod consul_config;
 pub mod env_config;
+mod etcd_config;
 mod model;
 pub mod multi_instance_env;
+mod nacos_config;
+mod url_config;
+mod validator;
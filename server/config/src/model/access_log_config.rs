@@ -0,0 +1,116 @@
+use serde::{Deserialize, Serialize};
+
+use super::byte_size;
+
+/// 访问日志配置
+///
+/// 默认关闭。开启后每个请求写一行访问日志，与应用日志分开输出，便于单独接入分析管道。
+/// 输出到文件时按时间或大小轮转，轮转后的文件可以压缩为 gzip。
+///
+/// 支持的环境变量：
+/// - APP_ACCESS_LOG_ENABLED: 是否开启访问日志
+/// - APP_ACCESS_LOG_FORMAT: 日志格式（combined/json）
+/// - APP_ACCESS_LOG_OUTPUT: 输出位置，`stdout` 或文件路径
+/// - APP_ACCESS_LOG_ROTATION: 按时间轮转的周期（hourly/daily/never）
+/// - APP_ACCESS_LOG_MAX_SIZE: 单个文件的大小上限（字节，或 `100MiB` 形式的容量）
+/// - APP_ACCESS_LOG_MAX_FILES: 保留的轮转文件数
+/// - APP_ACCESS_LOG_COMPRESS: 是否压缩轮转后的文件
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct AccessLogConfig {
+    /// 是否开启
+    /// 环境变量: APP_ACCESS_LOG_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 日志格式，默认 Apache/Nginx 的 combined 格式
+    /// 环境变量: APP_ACCESS_LOG_FORMAT
+    #[serde(default)]
+    pub format: AccessLogFormat,
+
+    /// 输出位置，`stdout` 输出到标准输出，其他值视为文件路径，默认 `stdout`
+    /// 环境变量: APP_ACCESS_LOG_OUTPUT
+    #[serde(default = "default_output")]
+    pub output: String,
+
+    /// 按时间轮转的周期，只用于文件输出，默认每天
+    /// 环境变量: APP_ACCESS_LOG_ROTATION
+    #[serde(default)]
+    pub rotation: AccessLogRotation,
+
+    /// 单个文件的大小上限，超过后立即轮转，0 表示不按大小轮转，默认 100MiB
+    /// 环境变量: APP_ACCESS_LOG_MAX_SIZE
+    #[serde(
+        default = "default_max_size",
+        deserialize_with = "byte_size::deserialize"
+    )]
+    pub max_size: u64,
+
+    /// 保留的轮转文件数，超出时删除最旧的文件，0 表示全部保留，默认 7
+    /// 环境变量: APP_ACCESS_LOG_MAX_FILES
+    #[serde(default = "default_max_files")]
+    pub max_files: usize,
+
+    /// 是否将轮转后的文件压缩为 `.gz`，默认开启
+    /// 环境变量: APP_ACCESS_LOG_COMPRESS
+    #[serde(default = "default_compress")]
+    pub compress: bool,
+}
+
+impl Default for AccessLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            format: AccessLogFormat::default(),
+            output: default_output(),
+            rotation: AccessLogRotation::default(),
+            max_size: default_max_size(),
+            max_files: default_max_files(),
+            compress: default_compress(),
+        }
+    }
+}
+
+impl AccessLogConfig {
+    /// 是否输出到标准输出
+    pub fn is_stdout(&self) -> bool {
+        self.output.trim().eq_ignore_ascii_case("stdout")
+    }
+}
+
+/// 访问日志格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessLogFormat {
+    /// `%h %l %u %t "%r" %>s %b "%{Referer}i" "%{User-agent}i"`
+    #[default]
+    Combined,
+    /// 每行一个 JSON 对象
+    Json,
+}
+
+/// 访问日志按时间轮转的周期
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AccessLogRotation {
+    Hourly,
+    #[default]
+    Daily,
+    /// 只按大小轮转
+    Never,
+}
+
+fn default_output() -> String {
+    "stdout".to_string()
+}
+
+fn default_max_size() -> u64 {
+    100 * 1024 * 1024
+}
+
+fn default_max_files() -> usize {
+    7
+}
+
+fn default_compress() -> bool {
+    true
+}
@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+
+/// 安全告警配置
+///
+/// 告警规则本身保存在 `sys_alert_rule` 表中，由管理接口维护；
+/// 这里只配置通知渠道的公共参数。项目不直接对接 SMTP，邮件渠道通过 HTTP 邮件网关发送。
+///
+/// 支持的环境变量：
+/// - APP_ALERT_MAIL_GATEWAY: 邮件网关地址
+/// - APP_ALERT_MAIL_AUTHORIZATION: 邮件网关 Authorization 请求头
+/// - APP_ALERT_TIMEOUT: 通知请求超时（秒）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlertConfig {
+    /// 邮件网关地址，邮件渠道的告警以 `{"to", "subject", "content"}` POST 到该地址，
+    /// 未配置时邮件渠道的告警只写入日志
+    /// 环境变量: APP_ALERT_MAIL_GATEWAY
+    #[serde(default)]
+    pub mail_gateway: Option<String>,
+
+    /// 请求邮件网关时附带的 Authorization 请求头
+    /// 环境变量: APP_ALERT_MAIL_AUTHORIZATION
+    #[serde(default)]
+    pub mail_authorization: Option<String>,
+
+    /// webhook 和邮件网关请求的超时（秒）
+    /// 环境变量: APP_ALERT_TIMEOUT
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            mail_gateway: None,
+            mail_authorization: None,
+            timeout: default_timeout(),
+        }
+    }
+}
+
+fn default_timeout() -> u64 {
+    5
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// API 调用统计配置
+///
+/// 开启后按用户和 API Key 统计每小时的请求数和错误数，实时计数保存在 Redis，
+/// 由主节点每小时汇总到数据库，供“API 调用统计”页面查询。
+///
+/// 支持的环境变量：
+/// - APP_API_USAGE_ENABLED: 是否统计 API 调用
+/// - APP_API_USAGE_RETENTION_DAYS: 数据库中小时统计的保留天数
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApiUsageConfig {
+    /// 是否启用，需要配置 Redis
+    /// 环境变量: APP_API_USAGE_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 小时统计的保留天数，汇总时清理更早的记录
+    /// 环境变量: APP_API_USAGE_RETENTION_DAYS
+    #[serde(default = "default_retention_days")]
+    pub retention_days: u32,
+}
+
+impl Default for ApiUsageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_days: default_retention_days(),
+        }
+    }
+}
+
+fn default_retention_days() -> u32 {
+    90
+}
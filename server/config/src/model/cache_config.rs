@@ -0,0 +1,66 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+/// 缓存配置
+///
+/// 目前用于“未找到”结果的负缓存：按 ID 查询不存在的记录时，在 Redis 中记下短期标记，
+/// 有效期内的重复查询直接返回未找到，避免针对不存在 ID 的枚举请求反复访问数据库。
+/// 未配置 Redis 或 Redis 不可用时不缓存。
+///
+/// 支持的环境变量：
+/// - APP_CACHE_KEY_PREFIX: Redis 键前缀
+/// - APP_CACHE_REDIS_INSTANCE: 使用的命名 Redis 实例，默认使用主 Redis
+/// - APP_CACHE_NEGATIVE_TTL: 负缓存默认有效期（秒）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CacheConfig {
+    /// Redis 键前缀
+    /// 环境变量: APP_CACHE_KEY_PREFIX
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: String,
+
+    /// 使用的命名 Redis 实例，未设置时使用主 Redis
+    /// 环境变量: APP_CACHE_REDIS_INSTANCE
+    #[serde(default)]
+    pub redis_instance: Option<String>,
+
+    /// 负缓存默认有效期（秒），0 表示关闭
+    /// 环境变量: APP_CACHE_NEGATIVE_TTL
+    #[serde(default = "default_negative_ttl")]
+    pub negative_ttl: u64,
+
+    /// 按命名空间覆盖负缓存有效期（秒），0 表示该命名空间不缓存
+    ///
+    /// 命名空间与实体对应，如 `user`、`role`、`domain`、`menu`、`file`
+    #[serde(default)]
+    pub negative_namespaces: HashMap<String, u64>,
+}
+
+impl CacheConfig {
+    /// 指定命名空间的负缓存有效期（秒）
+    pub fn negative_ttl_for(&self, namespace: &str) -> u64 {
+        self.negative_namespaces
+            .get(namespace)
+            .copied()
+            .unwrap_or(self.negative_ttl)
+    }
+}
+
+impl Default for CacheConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: default_key_prefix(),
+            redis_instance: None,
+            negative_ttl: default_negative_ttl(),
+            negative_namespaces: HashMap::new(),
+        }
+    }
+}
+
+fn default_key_prefix() -> String {
+    "soybean:cache".to_string()
+}
+
+fn default_negative_ttl() -> u64 {
+    30
+}
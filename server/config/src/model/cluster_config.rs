@@ -0,0 +1,61 @@
+use serde::{Deserialize, Serialize};
+
+/// 集群配置
+///
+/// 各实例通过 Redis 上报心跳完成成员注册，并基于 Redis 锁选举主节点，
+/// 保留数据清理、聚合等单例任务只在主节点运行。未配置 Redis 时按单节点处理。
+///
+/// 支持的环境变量：
+/// - APP_CLUSTER_KEY_PREFIX: Redis 键前缀
+/// - APP_CLUSTER_HEARTBEAT_INTERVAL: 心跳间隔（秒）
+/// - APP_CLUSTER_MEMBER_TTL: 成员心跳超时（秒）
+/// - APP_CLUSTER_LEADER_TTL: 主节点租约时长（秒）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ClusterConfig {
+    /// Redis 键前缀
+    /// 环境变量: APP_CLUSTER_KEY_PREFIX
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: String,
+
+    /// 心跳间隔（秒）
+    /// 环境变量: APP_CLUSTER_HEARTBEAT_INTERVAL
+    #[serde(default = "default_heartbeat_interval")]
+    pub heartbeat_interval: u64,
+
+    /// 超过该时长未上报心跳的成员视为下线（秒）
+    /// 环境变量: APP_CLUSTER_MEMBER_TTL
+    #[serde(default = "default_member_ttl")]
+    pub member_ttl: u64,
+
+    /// 主节点租约时长（秒），主节点在每次心跳时续约
+    /// 环境变量: APP_CLUSTER_LEADER_TTL
+    #[serde(default = "default_leader_ttl")]
+    pub leader_ttl: u64,
+}
+
+impl Default for ClusterConfig {
+    fn default() -> Self {
+        Self {
+            key_prefix: default_key_prefix(),
+            heartbeat_interval: default_heartbeat_interval(),
+            member_ttl: default_member_ttl(),
+            leader_ttl: default_leader_ttl(),
+        }
+    }
+}
+
+fn default_key_prefix() -> String {
+    "soybean:cluster".to_string()
+}
+
+fn default_heartbeat_interval() -> u64 {
+    10
+}
+
+fn default_member_ttl() -> u64 {
+    30
+}
+
+fn default_leader_ttl() -> u64 {
+    30
+}
@@ -0,0 +1,409 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use super::duration_secs;
+
+/// 合规配置
+///
+/// 支持的环境变量：
+/// - APP_COMPLIANCE_ACCESS_REVIEW_ENABLED: 是否定期发起权限复核
+/// - APP_COMPLIANCE_ACCESS_REVIEW_INTERVAL_DAYS: 复核周期（天）
+/// - APP_COMPLIANCE_ACCESS_REVIEW_DUE_DAYS: 复核期限（天）
+/// - APP_COMPLIANCE_ACCESS_REVIEW_REVIEWER_ROLE: 复核人角色编码
+/// - APP_COMPLIANCE_ACCESS_REVIEW_FALLBACK_REVIEWER: 兜底复核人用户名
+/// - APP_COMPLIANCE_INACTIVE_ACCOUNT_ENABLED: 是否自动禁用长期未登录的账号
+/// - APP_COMPLIANCE_INACTIVE_ACCOUNT_INACTIVE_DAYS: 默认的未登录天数上限
+/// - APP_COMPLIANCE_INACTIVE_ACCOUNT_WARNING_DAYS: 禁用前提前通知的天数
+/// - APP_COMPLIANCE_INACTIVE_ACCOUNT_MANAGER_ROLE: 上级的角色编码
+/// - APP_COMPLIANCE_POLICY_GATE_ENABLED: 是否要求用户确认最新的政策文档
+/// - APP_COMPLIANCE_POLICY_GATE_CACHE_TTL: 最新版本的缓存时间（秒）
+/// - APP_COMPLIANCE_EXPORT_WATERMARK_DEFAULT_ENABLED: 导出文件是否默认添加水印
+/// - APP_COMPLIANCE_AUDIT_CHAIN_VERIFY_INTERVAL: 审计日志哈希链的校验间隔（秒，或时长）
+/// - APP_COMPLIANCE_AUDIT_ARCHIVE_ENABLED: 是否归档已关闭的审计日志分区
+/// - APP_COMPLIANCE_AUDIT_ARCHIVE_BUCKET: 归档存储桶
+/// - APP_COMPLIANCE_AUDIT_ARCHIVE_S3_INSTANCE: 归档使用的 S3 实例名称
+/// - APP_COMPLIANCE_AUDIT_ARCHIVE_HOT_MONTHS: 保留在数据库中的月数
+/// - APP_COMPLIANCE_AUDIT_ARCHIVE_RETENTION_DAYS: 归档对象的锁定天数
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ComplianceConfig {
+    /// 权限复核配置
+    #[serde(default)]
+    pub access_review: AccessReviewConfig,
+
+    /// 长期未登录账号禁用策略
+    #[serde(default)]
+    pub inactive_account: InactiveAccountConfig,
+
+    /// 政策文档确认
+    #[serde(default)]
+    pub policy_gate: PolicyGateConfig,
+
+    /// 导出文件水印
+    #[serde(default)]
+    pub export_watermark: ExportWatermarkConfig,
+
+    /// 审计日志哈希链校验
+    #[serde(default)]
+    pub audit_chain: AuditChainConfig,
+
+    /// 审计日志归档
+    #[serde(default)]
+    pub audit_archive: AuditArchiveConfig,
+}
+
+/// 权限复核配置
+///
+/// 每个复核活动按组织生成用户、角色和最近登录时间的清单，分派给组织内持有
+/// `reviewer_role` 的用户复核；本组织没有复核人时逐级向上级组织查找，
+/// 仍找不到时分派给 `fallback_reviewer`。复核人确认保留或撤销用户的角色，
+/// 活动的发起、每条决定和关闭都会写入审计日志。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AccessReviewConfig {
+    /// 是否按周期自动发起复核，关闭时仍可通过接口手动发起
+    /// 环境变量: APP_COMPLIANCE_ACCESS_REVIEW_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 复核周期（天），距上次发起超过该天数时自动发起新一轮
+    /// 环境变量: APP_COMPLIANCE_ACCESS_REVIEW_INTERVAL_DAYS
+    #[serde(default = "default_interval_days")]
+    pub interval_days: u32,
+
+    /// 复核期限（天）
+    /// 环境变量: APP_COMPLIANCE_ACCESS_REVIEW_DUE_DAYS
+    #[serde(default = "default_due_days")]
+    pub due_days: u32,
+
+    /// 复核人角色编码
+    /// 环境变量: APP_COMPLIANCE_ACCESS_REVIEW_REVIEWER_ROLE
+    #[serde(default = "default_reviewer_role")]
+    pub reviewer_role: String,
+
+    /// 找不到组织复核人时的兜底复核人用户名，未配置时条目不分派，
+    /// 只能由不受数据范围限制的管理员处理
+    /// 环境变量: APP_COMPLIANCE_ACCESS_REVIEW_FALLBACK_REVIEWER
+    #[serde(default)]
+    pub fallback_reviewer: Option<String>,
+}
+
+impl Default for AccessReviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval_days: default_interval_days(),
+            due_days: default_due_days(),
+            reviewer_role: default_reviewer_role(),
+            fallback_reviewer: None,
+        }
+    }
+}
+
+/// 长期未登录账号禁用策略
+///
+/// 以最近一次登录时间（从未登录时为创建时间）计算未登录天数，超过上限的账号被禁用。
+/// 禁用前 `warning_days` 天通过站内信和邮件通知本人和上级，上级为所属组织或最近上级组织中
+/// 持有 `manager_role` 的用户。内置账号不受该策略影响。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct InactiveAccountConfig {
+    /// 是否启用
+    /// 环境变量: APP_COMPLIANCE_INACTIVE_ACCOUNT_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 默认的未登录天数上限
+    /// 环境变量: APP_COMPLIANCE_INACTIVE_ACCOUNT_INACTIVE_DAYS
+    #[serde(default = "default_inactive_days")]
+    pub inactive_days: u32,
+
+    /// 按角色编码单独设置的未登录天数上限，用户拥有多个角色时取最短的
+    #[serde(default)]
+    pub role_inactive_days: HashMap<String, u32>,
+
+    /// 禁用前提前通知的天数，为 0 时不通知
+    /// 环境变量: APP_COMPLIANCE_INACTIVE_ACCOUNT_WARNING_DAYS
+    #[serde(default = "default_warning_days")]
+    pub warning_days: u32,
+
+    /// 上级的角色编码
+    /// 环境变量: APP_COMPLIANCE_INACTIVE_ACCOUNT_MANAGER_ROLE
+    #[serde(default = "default_reviewer_role")]
+    pub manager_role: String,
+
+    /// 豁免的用户名，如服务账号
+    #[serde(default)]
+    pub exempt_users: Vec<String>,
+
+    /// 豁免的角色编码
+    #[serde(default)]
+    pub exempt_roles: Vec<String>,
+}
+
+impl InactiveAccountConfig {
+    /// 用户适用的未登录天数上限，豁免的用户返回 `None`
+    pub fn inactive_days_for(&self, username: &str, roles: &[String]) -> Option<u32> {
+        let exempt = self.exempt_users.iter().any(|user| user == username)
+            || roles.iter().any(|role| self.exempt_roles.contains(role));
+        if exempt {
+            return None;
+        }
+        roles
+            .iter()
+            .filter_map(|role| self.role_inactive_days.get(role).copied())
+            .min()
+            .or(Some(self.inactive_days))
+    }
+}
+
+impl Default for InactiveAccountConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            inactive_days: default_inactive_days(),
+            role_inactive_days: HashMap::new(),
+            warning_days: default_warning_days(),
+            manager_role: default_reviewer_role(),
+            exempt_users: Vec::new(),
+            exempt_roles: Vec::new(),
+        }
+    }
+}
+
+/// 政策文档确认配置
+///
+/// 开启后，用户须确认每个政策编码（如服务条款、隐私政策）的最新版本才能继续调用需要登录的接口，
+/// 否则返回 403 和 `policyAcceptanceRequired` 结构。查询和确认待确认文档的接口始终放行。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PolicyGateConfig {
+    /// 是否启用
+    /// 环境变量: APP_COMPLIANCE_POLICY_GATE_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 额外放行的路由前缀，如前端渲染确认页面需要的 `/auth/getUserInfo`
+    #[serde(default)]
+    pub exempt_paths: Vec<String>,
+
+    /// 各编码最新版本的进程内缓存时间（秒），发布新版本后最迟在该时间后生效
+    /// 环境变量: APP_COMPLIANCE_POLICY_GATE_CACHE_TTL
+    #[serde(default = "default_policy_cache_ttl")]
+    pub cache_ttl: u64,
+}
+
+impl PolicyGateConfig {
+    /// 路径是否在配置的放行前缀内
+    pub fn is_exempt(&self, path: &str) -> bool {
+        self.exempt_paths
+            .iter()
+            .any(|prefix| path.starts_with(prefix.as_str()))
+    }
+}
+
+impl Default for PolicyGateConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            exempt_paths: Vec::new(),
+            cache_ttl: default_policy_cache_ttl(),
+        }
+    }
+}
+
+/// 导出文件水印配置
+///
+/// 导出敏感数据时生成带导出人和导出时间的水印，每次导出分配一个水印编号并写入审计日志，
+/// 外泄的文件可以按编号追溯到导出请求。水印编号总是通过 `X-Export-Watermark` 响应头返回，
+/// `embed` 开启时水印文字同时写入文件内容：CSV 为首行注释，JSON 档案为 `watermark` 字段
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct ExportWatermarkConfig {
+    /// 未单独配置的导出类型使用的策略
+    #[serde(default)]
+    pub default: WatermarkPolicy,
+
+    /// 按导出类型覆盖，键为导出类型，如 `metering`、`tenant`
+    #[serde(default)]
+    pub exports: HashMap<String, WatermarkPolicy>,
+}
+
+impl ExportWatermarkConfig {
+    /// 导出类型生效的策略
+    pub fn policy(&self, export_type: &str) -> &WatermarkPolicy {
+        self.exports.get(export_type).unwrap_or(&self.default)
+    }
+}
+
+/// 单个导出类型的水印策略
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WatermarkPolicy {
+    /// 是否添加水印
+    /// 环境变量: APP_COMPLIANCE_EXPORT_WATERMARK_DEFAULT_ENABLED
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+
+    /// 是否把水印文字写入文件内容，关闭时只返回水印编号响应头
+    #[serde(default = "default_true")]
+    pub embed: bool,
+
+    /// 水印文字模板，可用占位符 `{export_id}`、`{user_id}`、`{username}`、`{domain}`、`{timestamp}`
+    #[serde(default = "default_watermark_template")]
+    pub template: String,
+}
+
+impl Default for WatermarkPolicy {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            embed: true,
+            template: default_watermark_template(),
+        }
+    }
+}
+
+/// 审计日志哈希链校验配置
+///
+/// 操作日志按域分区写入哈希链，每条记录保存前一条记录的哈希和本条记录的哈希，
+/// 修改或删除链中的记录都会使校验失败。定期校验任务发现断链时记录错误日志并写入审计日志，
+/// 也可以通过接口随时校验
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditChainConfig {
+    /// 定期校验间隔（秒），默认 1 天，为 0 时不启动定期校验
+    /// 环境变量: APP_COMPLIANCE_AUDIT_CHAIN_VERIFY_INTERVAL
+    #[serde(
+        default = "default_audit_chain_verify_interval",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub verify_interval: u64,
+}
+
+impl Default for AuditChainConfig {
+    fn default() -> Self {
+        Self {
+            verify_interval: default_audit_chain_verify_interval(),
+        }
+    }
+}
+
+fn default_audit_chain_verify_interval() -> u64 {
+    24 * 60 * 60
+}
+
+/// 审计日志归档配置
+///
+/// 操作日志按域和自然月分区，早于 `hot_months` 个月的分区视为已关闭，由主节点定期写入启用了
+/// Object Lock 的存储桶（WORM），写入成功后从数据库删除。归档对象按 `object_lock_mode` 和
+/// `retention_days` 设置保留期，保留期内不能删除或覆盖。按时间范围查询历史日志的接口
+/// 自动读取覆盖该范围的归档对象
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AuditArchiveConfig {
+    /// 是否启用，默认关闭
+    /// 环境变量: APP_COMPLIANCE_AUDIT_ARCHIVE_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 归档存储桶，须在创建时开启 Object Lock
+    /// 环境变量: APP_COMPLIANCE_AUDIT_ARCHIVE_BUCKET
+    #[serde(default)]
+    pub bucket: String,
+
+    /// 使用的 S3 实例名称，未配置时使用主 S3 客户端
+    /// 环境变量: APP_COMPLIANCE_AUDIT_ARCHIVE_S3_INSTANCE
+    #[serde(default)]
+    pub s3_instance: Option<String>,
+
+    /// 对象键前缀，默认 `audit-log`
+    #[serde(default = "default_archive_prefix")]
+    pub prefix: String,
+
+    /// 保留在数据库中的月数（含当月），默认 6
+    /// 环境变量: APP_COMPLIANCE_AUDIT_ARCHIVE_HOT_MONTHS
+    #[serde(default = "default_hot_months")]
+    pub hot_months: u32,
+
+    /// Object Lock 模式，默认 `compliance`，保留期内任何账号都不能删除；
+    /// `governance` 模式下有特殊权限的账号可以提前删除
+    #[serde(default)]
+    pub object_lock_mode: ObjectLockMode,
+
+    /// 归档对象的锁定天数，从归档时起算，默认 2557（7 年）
+    /// 环境变量: APP_COMPLIANCE_AUDIT_ARCHIVE_RETENTION_DAYS
+    #[serde(default = "default_archive_retention_days")]
+    pub retention_days: u32,
+
+    /// 归档任务的执行间隔（秒），默认 1 天
+    #[serde(
+        default = "default_archive_interval",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub interval: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ObjectLockMode {
+    #[default]
+    Compliance,
+    Governance,
+}
+
+impl Default for AuditArchiveConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            bucket: String::new(),
+            s3_instance: None,
+            prefix: default_archive_prefix(),
+            hot_months: default_hot_months(),
+            object_lock_mode: ObjectLockMode::default(),
+            retention_days: default_archive_retention_days(),
+            interval: default_archive_interval(),
+        }
+    }
+}
+
+fn default_archive_prefix() -> String {
+    "audit-log".to_string()
+}
+
+fn default_hot_months() -> u32 {
+    6
+}
+
+fn default_archive_retention_days() -> u32 {
+    2557
+}
+
+fn default_archive_interval() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_watermark_template() -> String {
+    "Exported by {username} ({user_id}@{domain}) at {timestamp}, ref {export_id}".to_string()
+}
+
+fn default_interval_days() -> u32 {
+    90
+}
+
+fn default_due_days() -> u32 {
+    14
+}
+
+fn default_reviewer_role() -> String {
+    "ROLE_ADMIN".to_string()
+}
+
+fn default_inactive_days() -> u32 {
+    90
+}
+
+fn default_warning_days() -> u32 {
+    7
+}
+
+fn default_policy_cache_ttl() -> u64 {
+    60
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+
+/// 并发准入控制配置
+///
+/// 限制同时处理的 HTTP 请求数，超出上限的请求进入等待队列，
+/// 队列已满或等待超时时直接返回 503。
+///
+/// 支持的环境变量：
+/// - APP_CONCURRENCY_MODE: 限流模式（disabled/fixed/adaptive）
+/// - APP_CONCURRENCY_LIMIT: 固定上限，自适应模式下作为初始上限
+/// - APP_CONCURRENCY_MIN_LIMIT: 自适应模式的下限
+/// - APP_CONCURRENCY_MAX_LIMIT: 自适应模式的上限
+/// - APP_CONCURRENCY_QUEUE_DEPTH: 等待队列长度
+/// - APP_CONCURRENCY_QUEUE_TIMEOUT_MS: 排队超时（毫秒）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ConcurrencyConfig {
+    /// 限流模式
+    /// 环境变量: APP_CONCURRENCY_MODE
+    #[serde(default)]
+    pub mode: ConcurrencyMode,
+
+    /// 固定上限，自适应模式下作为初始上限
+    /// 环境变量: APP_CONCURRENCY_LIMIT
+    #[serde(default = "default_limit")]
+    pub limit: usize,
+
+    /// 自适应模式的下限
+    /// 环境变量: APP_CONCURRENCY_MIN_LIMIT
+    #[serde(default = "default_min_limit")]
+    pub min_limit: usize,
+
+    /// 自适应模式的上限
+    /// 环境变量: APP_CONCURRENCY_MAX_LIMIT
+    #[serde(default = "default_max_limit")]
+    pub max_limit: usize,
+
+    /// 等待队列长度，为 0 时超出上限立即拒绝
+    /// 环境变量: APP_CONCURRENCY_QUEUE_DEPTH
+    #[serde(default = "default_queue_depth")]
+    pub queue_depth: usize,
+
+    /// 排队超时（毫秒）
+    /// 环境变量: APP_CONCURRENCY_QUEUE_TIMEOUT_MS
+    #[serde(default = "default_queue_timeout_ms")]
+    pub queue_timeout_ms: u64,
+}
+
+/// 限流模式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ConcurrencyMode {
+    /// 不限制并发
+    #[default]
+    Disabled,
+    /// 固定并发上限
+    Fixed,
+    /// 根据请求延迟自动调整上限（梯度算法），延迟升高时收缩，恢复后逐步放开
+    Adaptive,
+}
+
+impl Default for ConcurrencyConfig {
+    fn default() -> Self {
+        Self {
+            mode: ConcurrencyMode::default(),
+            limit: default_limit(),
+            min_limit: default_min_limit(),
+            max_limit: default_max_limit(),
+            queue_depth: default_queue_depth(),
+            queue_timeout_ms: default_queue_timeout_ms(),
+        }
+    }
+}
+
+fn default_limit() -> usize {
+    256
+}
+
+fn default_min_limit() -> usize {
+    8
+}
+
+fn default_max_limit() -> usize {
+    1024
+}
+
+fn default_queue_depth() -> usize {
+    64
+}
+
+fn default_queue_timeout_ms() -> u64 {
+    1000
+}
@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+
+use super::duration_secs;
+
+/// 数据一致性检查的类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConsistencyCategory {
+    /// 用户或角色已删除的用户角色关联
+    OrphanedUserRole,
+    /// 角色或菜单已删除的角色菜单授权
+    OrphanedRoleMenu,
+    /// 元数据表中存在、对象存储中缺失的文件内容
+    MissingFileObject,
+    /// 对象存储中存在、元数据表中没有记录的文件内容
+    UnreferencedFileObject,
+}
+
+impl ConsistencyCategory {
+    pub const ALL: [ConsistencyCategory; 4] = [
+        ConsistencyCategory::OrphanedUserRole,
+        ConsistencyCategory::OrphanedRoleMenu,
+        ConsistencyCategory::MissingFileObject,
+        ConsistencyCategory::UnreferencedFileObject,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ConsistencyCategory::OrphanedUserRole => "orphaned_user_role",
+            ConsistencyCategory::OrphanedRoleMenu => "orphaned_role_menu",
+            ConsistencyCategory::MissingFileObject => "missing_file_object",
+            ConsistencyCategory::UnreferencedFileObject => "unreferenced_file_object",
+        }
+    }
+
+    /// 可以安全自动修复的类别：只删除指向已删除记录的关联，不影响任何有效数据
+    ///
+    /// 缺失的文件内容无法修复；未登记的对象可能属于进行中的上传，只报告不删除
+    pub fn is_repairable(&self) -> bool {
+        matches!(
+            self,
+            ConsistencyCategory::OrphanedUserRole | ConsistencyCategory::OrphanedRoleMenu
+        )
+    }
+}
+
+/// 数据一致性检查配置
+///
+/// 开启后由主节点定期检查孤立的用户角色关联、角色菜单授权，以及文件元数据与对象存储
+/// 是否一致，发现问题时通知 `notify_roles` 的全部用户。列在 `auto_repair` 中的类别
+/// 在检查时自动修复，只允许可以安全修复的类别
+///
+/// 支持的环境变量：
+/// - APP_CONSISTENCY_ENABLED: 是否开启定期检查
+/// - APP_CONSISTENCY_INTERVAL: 检查间隔（秒，或时长）
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ConsistencyConfig {
+    /// 是否开启定期检查，`POST /consistency/check` 不受此开关影响
+    /// 环境变量: APP_CONSISTENCY_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 检查间隔
+    /// 环境变量: APP_CONSISTENCY_INTERVAL
+    #[serde(
+        default = "default_interval",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub interval: u64,
+
+    /// 接收检查报告的角色
+    #[serde(default = "default_notify_roles")]
+    pub notify_roles: Vec<String>,
+
+    /// 定期检查时自动修复的类别
+    #[serde(default)]
+    pub auto_repair: Vec<ConsistencyCategory>,
+}
+
+impl Default for ConsistencyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            interval: default_interval(),
+            notify_roles: default_notify_roles(),
+            auto_repair: Vec::new(),
+        }
+    }
+}
+
+fn default_interval() -> u64 {
+    24 * 3600
+}
+
+fn default_notify_roles() -> Vec<String> {
+    vec!["ROLE_SUPER".to_string()]
+}
@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use super::{duration_secs, SecretString};
 
 /// 数据库配置
 ///
@@ -6,29 +8,451 @@ use serde::Deserialize;
 /// - APP_DATABASE_URL: 数据库连接URL
 /// - APP_DATABASE_MAX_CONNECTIONS: 最大连接数
 /// - APP_DATABASE_MIN_CONNECTIONS: 最小连接数
-/// - APP_DATABASE_CONNECT_TIMEOUT: 连接超时时间（秒）
-/// - APP_DATABASE_IDLE_TIMEOUT: 空闲超时时间（秒）
-#[derive(Deserialize, Debug, Clone)]
+/// - APP_DATABASE_CONNECT_TIMEOUT: 连接超时时间（秒，或 `30s`、`10m` 形式的时长）
+/// - APP_DATABASE_IDLE_TIMEOUT: 空闲超时时间（秒，或时长）
+/// - APP_DATABASE_STATEMENT_CACHE_CAPACITY: 每个连接缓存的预编译语句数
+/// - APP_DATABASE_SSL_MODE: TLS 模式（disable/allow/prefer/require/verify-ca/verify-full）
+/// - APP_DATABASE_SSL_ROOT_CERT: 校验服务端证书的 CA 证书路径
+/// - APP_DATABASE_SSL_CLIENT_CERT: 客户端证书路径
+/// - APP_DATABASE_SSL_CLIENT_KEY: 客户端私钥路径
+/// - APP_DATABASE_INSTANCES_0_DATABASE_READ_ONLY: 实例是否只读（仅用于 `database_instances`）
+/// - APP_DATABASE_INSTANCES_0_DATABASE_REPLICAS: 实例的只读副本连接URL，逗号分隔（仅用于 `database_instances`）
+/// - APP_DATABASE_INSTANCES_0_DATABASE_REPLICA_STRATEGY: 副本负载均衡策略（仅用于 `database_instances`）
+/// - APP_DATABASE_INSTANCES_0_DATABASE_DRIVER: 实例的数据库驱动（仅用于 `database_instances`）
+/// - APP_DATABASE_INSTANCES_0_DATABASE_SSL_MODE 等：实例的 TLS 选项，字段同上（仅用于 `database_instances`）
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct DatabaseConfig {
     /// 数据库连接URL
     /// 环境变量: APP_DATABASE_URL
-    pub url: String,
+    pub url: SecretString,
 
-    /// 最大连接数
+    /// 最大连接数，默认 10
     /// 环境变量: APP_DATABASE_MAX_CONNECTIONS
+    #[serde(default = "default_max_connections")]
     pub max_connections: u32,
 
-    /// 最小连接数
+    /// 最小连接数，默认 1
     /// 环境变量: APP_DATABASE_MIN_CONNECTIONS
+    #[serde(default = "default_min_connections")]
     pub min_connections: u32,
 
-    /// 连接超时时间（秒）
+    /// 连接超时时间（秒），默认 30
     /// 环境变量: APP_DATABASE_CONNECT_TIMEOUT
+    #[serde(
+        default = "default_connect_timeout",
+        deserialize_with = "duration_secs::deserialize"
+    )]
     pub connect_timeout: u64,
 
-    /// 空闲超时时间（秒）
+    /// 空闲超时时间（秒），默认 600
     /// 环境变量: APP_DATABASE_IDLE_TIMEOUT
+    #[serde(
+        default = "default_idle_timeout",
+        deserialize_with = "duration_secs::deserialize"
+    )]
     pub idle_timeout: u64,
+
+    /// 每个连接缓存的预编译语句数，未设置时使用驱动默认值 100；
+    /// 动态筛选条件多的场景语句种类多，容量过小会反复淘汰和重新预编译
+    /// 环境变量: APP_DATABASE_STATEMENT_CACHE_CAPACITY
+    #[serde(default)]
+    pub statement_cache_capacity: Option<usize>,
+
+    /// 只读实例，用于报表和只读副本：PostgreSQL 会话默认以只读事务执行，
+    /// 通过 `get_read_only_connection` 获取的连接在执行前拒绝写语句。主数据库不能设为只读
+    /// 环境变量: APP_DATABASE_INSTANCES_0_DATABASE_READ_ONLY
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// 只读副本连接URL，连接池参数与主库相同。配置后通过 `get_read_connection`
+    /// 获取的连接分摊到各副本，未配置时使用主库
+    #[serde(default)]
+    pub replicas: Vec<SecretString>,
+
+    /// 副本负载均衡策略，默认轮询
+    #[serde(default)]
+    pub replica_strategy: ReplicaStrategy,
+
+    /// 数据库驱动，未设置时按连接串的协议识别。主数据库只支持 PostgreSQL
+    /// 环境变量: APP_DATABASE_INSTANCES_0_DATABASE_DRIVER
+    #[serde(default)]
+    pub driver: Option<DatabaseDriver>,
+
+    /// PostgreSQL 专用连接选项
+    #[serde(default)]
+    pub postgres: Option<PostgresOptions>,
+
+    /// MySQL 专用连接选项
+    #[serde(default)]
+    pub mysql: Option<MysqlOptions>,
+
+    /// SQLite 专用连接选项
+    #[serde(default)]
+    pub sqlite: Option<SqliteOptions>,
+
+    /// TLS 模式，未设置时使用连接串中的参数或驱动默认值。只用于 PostgreSQL 和 MySQL
+    /// 环境变量: APP_DATABASE_SSL_MODE
+    #[serde(default)]
+    pub ssl_mode: Option<SslMode>,
+
+    /// 校验服务端证书的 CA 证书（PEM）路径，如 RDS 的 `global-bundle.pem`
+    /// 环境变量: APP_DATABASE_SSL_ROOT_CERT
+    #[serde(default)]
+    pub ssl_root_cert: Option<String>,
+
+    /// 双向认证的客户端证书（PEM）路径，须与 `ssl_client_key` 同时配置
+    /// 环境变量: APP_DATABASE_SSL_CLIENT_CERT
+    #[serde(default)]
+    pub ssl_client_cert: Option<String>,
+
+    /// 双向认证的客户端私钥（PEM）路径
+    /// 环境变量: APP_DATABASE_SSL_CLIENT_KEY
+    #[serde(default)]
+    pub ssl_client_key: Option<String>,
+}
+
+/// 数据库连接的 TLS 模式，取值与 PostgreSQL 的 `sslmode` 一致
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum SslMode {
+    /// 不使用 TLS
+    Disable,
+    /// 先尝试明文连接，失败时使用 TLS
+    Allow,
+    /// 优先使用 TLS，服务端不支持时使用明文连接
+    Prefer,
+    /// 必须使用 TLS，不校验证书
+    Require,
+    /// 必须使用 TLS，校验证书由受信任的 CA 签发
+    VerifyCa,
+    /// 必须使用 TLS，校验证书并核对主机名
+    VerifyFull,
+}
+
+impl SslMode {
+    /// 解析环境变量中的取值，同时接受 `verify-full` 和 `verify_full`
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_lowercase().replace('_', "-").as_str() {
+            "disable" => Some(Self::Disable),
+            "allow" => Some(Self::Allow),
+            "prefer" => Some(Self::Prefer),
+            "require" => Some(Self::Require),
+            "verify-ca" => Some(Self::VerifyCa),
+            "verify-full" => Some(Self::VerifyFull),
+            _ => None,
+        }
+    }
+
+    /// PostgreSQL 连接串中的 `sslmode` 取值
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Disable => "disable",
+            Self::Allow => "allow",
+            Self::Prefer => "prefer",
+            Self::Require => "require",
+            Self::VerifyCa => "verify-ca",
+            Self::VerifyFull => "verify-full",
+        }
+    }
+
+    /// MySQL 连接串中的 `ssl-mode` 取值，MySQL 没有 `allow`，按 `PREFERRED` 处理
+    pub fn mysql_value(&self) -> &'static str {
+        match self {
+            Self::Disable => "DISABLED",
+            Self::Allow | Self::Prefer => "PREFERRED",
+            Self::Require => "REQUIRED",
+            Self::VerifyCa => "VERIFY_CA",
+            Self::VerifyFull => "VERIFY_IDENTITY",
+        }
+    }
+}
+
+/// 数据库驱动
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DatabaseDriver {
+    Postgres,
+    Mysql,
+    Sqlite,
+}
+
+impl DatabaseDriver {
+    /// 按连接串的协议识别驱动
+    pub fn from_url(url: &str) -> Option<Self> {
+        let (scheme, _) = url.split_once(':')?;
+        match scheme {
+            "postgres" | "postgresql" => Some(Self::Postgres),
+            "mysql" | "mariadb" => Some(Self::Mysql),
+            "sqlite" => Some(Self::Sqlite),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Postgres => "postgres",
+            Self::Mysql => "mysql",
+            Self::Sqlite => "sqlite",
+        }
+    }
+}
+
+/// PostgreSQL 连接选项
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct PostgresOptions {
+    /// 连接后设置的 `search_path`
+    #[serde(default)]
+    pub schema_search_path: Option<String>,
+
+    /// 上报给服务端的 `application_name`，便于在 `pg_stat_activity` 中区分连接来源
+    #[serde(default)]
+    pub application_name: Option<String>,
+}
+
+/// MySQL 连接选项
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct MysqlOptions {
+    /// 连接字符集，如 `utf8mb4`
+    #[serde(default)]
+    pub charset: Option<String>,
+
+    /// 会话时区，如 `+08:00`
+    #[serde(default)]
+    pub timezone: Option<String>,
+}
+
+/// SQLite 连接选项
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq)]
+pub struct SqliteOptions {
+    /// 数据库文件不存在时创建
+    #[serde(default)]
+    pub create_if_missing: bool,
+
+    /// SQLCipher 加密密钥
+    #[serde(default)]
+    pub sqlcipher_key: Option<SecretString>,
+}
+
+/// 只读副本的负载均衡策略
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ReplicaStrategy {
+    /// 按顺序轮流使用各副本
+    #[default]
+    RoundRobin,
+    /// 每次随机选择副本
+    Random,
+}
+
+pub(crate) fn default_max_connections() -> u32 {
+    10
+}
+
+pub(crate) fn default_min_connections() -> u32 {
+    1
+}
+
+pub(crate) fn default_connect_timeout() -> u64 {
+    30
+}
+
+pub(crate) fn default_idle_timeout() -> u64 {
+    600
+}
+
+/// sqlx 预编译语句缓存的默认容量
+const DEFAULT_STATEMENT_CACHE_CAPACITY: usize = 100;
+
+/// 连接串中设置语句缓存容量的参数，PostgreSQL 和 MySQL 驱动支持
+const STATEMENT_CACHE_PARAM: &str = "statement-cache-capacity";
+
+/// PostgreSQL 会话默认使用只读事务的启动参数
+const READ_ONLY_OPTION: &str = "default_transaction_read_only=on";
+
+impl DatabaseConfig {
+    /// 生效的语句缓存容量，连接串中已指定时以连接串为准
+    pub fn effective_statement_cache_capacity(&self) -> usize {
+        url_statement_cache_capacity(self.url.expose_secret())
+            .or(self.statement_cache_capacity)
+            .unwrap_or(DEFAULT_STATEMENT_CACHE_CAPACITY)
+    }
+
+    /// 建立连接使用的连接串原值
+    ///
+    /// 配置了语句缓存容量且连接串未指定时追加 `statement-cache-capacity`；
+    /// PostgreSQL 只读实例追加 `default_transaction_read_only` 启动参数
+    pub fn connect_url(&self) -> String {
+        self.build_url(self.url.expose_secret(), self.read_only)
+    }
+
+    /// 各只读副本建立连接使用的连接串，PostgreSQL 副本始终以只读事务执行
+    pub fn replica_connect_urls(&self) -> Vec<String> {
+        self.replicas
+            .iter()
+            .map(|replica| self.build_url(replica.expose_secret(), true))
+            .collect()
+    }
+
+    /// 生效的驱动：显式配置优先，否则按连接串识别
+    pub fn effective_driver(&self) -> Option<DatabaseDriver> {
+        self.driver
+            .or_else(|| DatabaseDriver::from_url(self.url.expose_secret()))
+    }
+
+    /// 生效驱动对应的 `search_path`，只用于 PostgreSQL
+    pub fn schema_search_path(&self) -> Option<&str> {
+        self.postgres
+            .as_ref()
+            .filter(|_| self.effective_driver() == Some(DatabaseDriver::Postgres))
+            .and_then(|options| options.schema_search_path.as_deref())
+    }
+
+    /// 生效驱动对应的 SQLCipher 密钥，只用于 SQLite
+    pub fn sqlcipher_key(&self) -> Option<&SecretString> {
+        self.sqlite
+            .as_ref()
+            .filter(|_| self.effective_driver() == Some(DatabaseDriver::Sqlite))
+            .and_then(|options| options.sqlcipher_key.as_ref())
+    }
+
+    /// 是否配置了任一 TLS 选项
+    pub fn has_ssl_options(&self) -> bool {
+        self.ssl_mode.is_some()
+            || self.ssl_root_cert.is_some()
+            || self.ssl_client_cert.is_some()
+            || self.ssl_client_key.is_some()
+    }
+
+    /// 追加 TLS 参数，连接串中已有的同名参数优先
+    fn append_ssl_params(&self, url: &mut String, driver: DatabaseDriver) {
+        let (mode, root_cert, client_cert, client_key) = match driver {
+            DatabaseDriver::Postgres => ("sslmode", "sslrootcert", "sslcert", "sslkey"),
+            DatabaseDriver::Mysql => ("ssl-mode", "ssl-ca", "ssl-cert", "ssl-key"),
+            DatabaseDriver::Sqlite => return,
+        };
+        let mode_value = self.ssl_mode.map(|ssl_mode| match driver {
+            DatabaseDriver::Mysql => ssl_mode.mysql_value(),
+            _ => ssl_mode.as_str(),
+        });
+        let params = [
+            (mode, mode_value),
+            (root_cert, self.ssl_root_cert.as_deref()),
+            (client_cert, self.ssl_client_cert.as_deref()),
+            (client_key, self.ssl_client_key.as_deref()),
+        ];
+        for (key, value) in params {
+            if let Some(value) = value {
+                if url_param(url, key).is_none() {
+                    append_param(url, &format!("{}={}", key, encode_param(value)));
+                }
+            }
+        }
+    }
+
+    fn build_url(&self, url: &str, read_only: bool) -> String {
+        let mut url = url.to_string();
+        let driver = self.effective_driver();
+        if let Some(capacity) = self.statement_cache_capacity {
+            let supported = matches!(
+                driver,
+                Some(DatabaseDriver::Postgres | DatabaseDriver::Mysql)
+            );
+            if supported && url_statement_cache_capacity(&url).is_none() {
+                append_param(&mut url, &format!("{}={}", STATEMENT_CACHE_PARAM, capacity));
+            }
+        }
+        match driver {
+            Some(DatabaseDriver::Postgres) => {
+                if let Some(name) = self
+                    .postgres
+                    .as_ref()
+                    .and_then(|options| options.application_name.as_deref())
+                {
+                    append_param(
+                        &mut url,
+                        &format!("application_name={}", encode_param(name)),
+                    );
+                }
+                if read_only {
+                    append_pg_option(&mut url, READ_ONLY_OPTION);
+                }
+            },
+            Some(DatabaseDriver::Mysql) => {
+                if let Some(options) = &self.mysql {
+                    if let Some(charset) = &options.charset {
+                        append_param(&mut url, &format!("charset={}", encode_param(charset)));
+                    }
+                    if let Some(timezone) = &options.timezone {
+                        append_param(&mut url, &format!("timezone={}", encode_param(timezone)));
+                    }
+                }
+            },
+            Some(DatabaseDriver::Sqlite)
+                if self
+                    .sqlite
+                    .as_ref()
+                    .is_some_and(|options| options.create_if_missing) =>
+            {
+                append_param(&mut url, "mode=rwc");
+            },
+            Some(DatabaseDriver::Sqlite) | None => {},
+        }
+        if let Some(driver) = driver {
+            self.append_ssl_params(&mut url, driver);
+        }
+        url
+    }
+}
+
+/// 连接串中参数的原始值，MySQL 的 `ssl-mode` 与 `sslmode` 等别名视为同一参数
+fn url_param<'a>(url: &'a str, name: &str) -> Option<&'a str> {
+    let (_, query) = url.split_once('?')?;
+    let normalize = |key: &str| key.replace('-', "");
+    query.split('&').find_map(|pair| {
+        let (key, value) = pair.split_once('=')?;
+        (normalize(key) == normalize(name)).then_some(value)
+    })
+}
+
+fn url_statement_cache_capacity(url: &str) -> Option<usize> {
+    url_param(url, STATEMENT_CACHE_PARAM).and_then(|value| value.parse().ok())
+}
+
+/// 对连接串参数值做百分号编码，避免 `+`、`&` 等字符被解析为分隔符
+fn encode_param(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                (byte as char).to_string()
+            },
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn append_param(url: &mut String, param: &str) {
+    url.push(if url.contains('?') { '&' } else { '?' });
+    url.push_str(param);
+}
+
+/// 以 `-c name=value` 形式追加 PostgreSQL 启动参数
+///
+/// 驱动只读取一个 `options` 参数，连接串中已有 `options` 时合并到其中，而不是再追加一个
+pub fn append_pg_option(url: &mut String, option: &str) {
+    let option = format!("-c%20{}", encode_param(option));
+    let existing = url.split_once('?').and_then(|(base, query)| {
+        let mut offset = base.len() + 1;
+        query.split('&').find_map(|pair| {
+            let end = offset + pair.len();
+            offset = end + 1;
+            pair.starts_with("options=").then_some(end)
+        })
+    });
+    match existing {
+        Some(end) => url.insert_str(end, &format!("%20{}", option)),
+        None => append_param(url, &format!("options={}", option)),
+    }
 }
 
 /// 数据库实例配置
@@ -38,8 +462,9 @@ pub struct DatabaseConfig {
 /// - APP_DATABASE_INSTANCES_0_DATABASE_URL: 第一个实例数据库URL
 /// - APP_DATABASE_INSTANCES_1_NAME: 第二个实例名称
 /// - APP_DATABASE_INSTANCES_1_DATABASE_URL: 第二个实例数据库URL
+///
 /// 以此类推...
-#[derive(Deserialize, Debug, Clone)]
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct DatabasesInstancesConfig {
     /// 实例名称
     pub name: String,
@@ -47,3 +472,189 @@ pub struct DatabasesInstancesConfig {
     /// 数据库配置
     pub database: DatabaseConfig,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn database(url: &str, statement_cache_capacity: Option<usize>) -> DatabaseConfig {
+        DatabaseConfig {
+            url: url.into(),
+            max_connections: 10,
+            min_connections: 1,
+            connect_timeout: 30,
+            idle_timeout: 600,
+            statement_cache_capacity,
+            read_only: false,
+            replicas: Vec::new(),
+            replica_strategy: ReplicaStrategy::RoundRobin,
+            driver: None,
+            postgres: None,
+            mysql: None,
+            sqlite: None,
+            ssl_mode: None,
+            ssl_root_cert: None,
+            ssl_client_cert: None,
+            ssl_client_key: None,
+        }
+    }
+
+    #[test]
+    fn test_statement_cache_capacity_in_connect_url() {
+        let config = database("postgres://localhost/db?sslmode=prefer", Some(500));
+        assert_eq!(
+            config.connect_url(),
+            "postgres://localhost/db?sslmode=prefer&statement-cache-capacity=500"
+        );
+        assert_eq!(config.effective_statement_cache_capacity(), 500);
+
+        let config = database(
+            "postgres://localhost/db?statement-cache-capacity=50",
+            Some(500),
+        );
+        assert_eq!(config.connect_url(), config.url.expose_secret());
+        assert_eq!(config.effective_statement_cache_capacity(), 50);
+
+        let config = database("postgres://localhost/db", None);
+        assert_eq!(config.connect_url(), config.url.expose_secret());
+        assert_eq!(config.effective_statement_cache_capacity(), 100);
+
+        let mut config = database("postgres://localhost/db", Some(500));
+        config.read_only = true;
+        assert_eq!(
+            config.connect_url(),
+            "postgres://localhost/db?statement-cache-capacity=500&\
+             options=-c%20default_transaction_read_only%3Don"
+        );
+    }
+
+    #[test]
+    fn test_append_pg_option() {
+        let mut url = "postgres://db/app?sslmode=require".to_string();
+        append_pg_option(&mut url, "search_path=tenant_acme");
+        assert_eq!(
+            url,
+            "postgres://db/app?sslmode=require&options=-c%20search_path%3Dtenant_acme"
+        );
+
+        append_pg_option(&mut url, READ_ONLY_OPTION);
+        assert_eq!(
+            url,
+            "postgres://db/app?sslmode=require&options=-c%20search_path%3Dtenant_acme\
+             %20-c%20default_transaction_read_only%3Don"
+        );
+
+        let mut url = "postgres://db/app?options=-c%20work_mem%3D64MB&sslmode=require".to_string();
+        append_pg_option(&mut url, READ_ONLY_OPTION);
+        assert_eq!(
+            url,
+            "postgres://db/app?options=-c%20work_mem%3D64MB\
+             %20-c%20default_transaction_read_only%3Don&sslmode=require"
+        );
+    }
+
+    #[test]
+    fn test_replica_connect_urls() {
+        let mut config = database("postgres://primary/db", Some(500));
+        config.replicas = vec![
+            "postgres://replica1/db".into(),
+            "postgres://replica2/db?statement-cache-capacity=50".into(),
+        ];
+        assert_eq!(
+            config.replica_connect_urls(),
+            vec![
+                "postgres://replica1/db?statement-cache-capacity=500&\
+                 options=-c%20default_transaction_read_only%3Don",
+                "postgres://replica2/db?statement-cache-capacity=50&\
+                 options=-c%20default_transaction_read_only%3Don",
+            ]
+        );
+        assert_eq!(
+            config.connect_url(),
+            "postgres://primary/db?statement-cache-capacity=500"
+        );
+    }
+
+    #[test]
+    fn test_driver_options_in_connect_url() {
+        assert_eq!(
+            DatabaseDriver::from_url("postgresql://localhost/db"),
+            Some(DatabaseDriver::Postgres)
+        );
+        assert_eq!(
+            DatabaseDriver::from_url("sqlite://data.db"),
+            Some(DatabaseDriver::Sqlite)
+        );
+        assert_eq!(DatabaseDriver::from_url("mssql://localhost/db"), None);
+
+        let mut config = database("mysql://localhost/report", None);
+        config.mysql = Some(MysqlOptions {
+            charset: Some("utf8mb4".to_string()),
+            timezone: Some("+08:00".to_string()),
+        });
+        config.postgres = Some(PostgresOptions {
+            schema_search_path: Some("report".to_string()),
+            application_name: Some("soybean".to_string()),
+        });
+        assert_eq!(config.effective_driver(), Some(DatabaseDriver::Mysql));
+        assert_eq!(
+            config.connect_url(),
+            "mysql://localhost/report?charset=utf8mb4&timezone=%2B08%3A00"
+        );
+        assert_eq!(config.schema_search_path(), None);
+
+        let mut config = database("sqlite://data.db", Some(500));
+        config.sqlite = Some(SqliteOptions {
+            create_if_missing: true,
+            sqlcipher_key: None,
+        });
+        assert_eq!(config.connect_url(), "sqlite://data.db?mode=rwc");
+    }
+
+    #[test]
+    fn test_ssl_options_in_connect_url() {
+        let mut config = database("postgres://db.rds.amazonaws.com/app", None);
+        config.ssl_mode = Some(SslMode::VerifyFull);
+        config.ssl_root_cert = Some("/etc/ssl/rds/global-bundle.pem".to_string());
+        assert_eq!(
+            config.connect_url(),
+            "postgres://db.rds.amazonaws.com/app?sslmode=verify-full&\
+             sslrootcert=%2Fetc%2Fssl%2Frds%2Fglobal-bundle.pem"
+        );
+
+        // 连接串中已有的参数优先
+        let mut config = database("postgres://localhost/db?sslmode=disable", None);
+        config.ssl_mode = Some(SslMode::Require);
+        assert_eq!(config.connect_url(), config.url.expose_secret());
+
+        let mut config = database("mysql://localhost/report", None);
+        config.ssl_mode = Some(SslMode::VerifyFull);
+        config.ssl_client_cert = Some("client.pem".to_string());
+        config.ssl_client_key = Some("client.key".to_string());
+        assert_eq!(
+            config.connect_url(),
+            "mysql://localhost/report?ssl-mode=VERIFY_IDENTITY&\
+             ssl-cert=client.pem&ssl-key=client.key"
+        );
+
+        assert_eq!(SslMode::parse("VERIFY_FULL"), Some(SslMode::VerifyFull));
+        assert_eq!(SslMode::parse("strict"), None);
+    }
+
+    #[test]
+    fn test_database_defaults() {
+        let config: DatabaseConfig =
+            serde_json::from_str(r#"{ "url": "postgres://localhost/db" }"#).unwrap();
+        assert_eq!(config, database("postgres://localhost/db", None));
+    }
+
+    #[test]
+    fn test_database_duration_strings() {
+        let config: DatabaseConfig = serde_json::from_str(
+            r#"{ "url": "postgres://localhost/db", "connect_timeout": "30s", "idle_timeout": "10m" }"#,
+        )
+        .unwrap();
+        assert_eq!(config.connect_timeout, 30);
+        assert_eq!(config.idle_timeout, 600);
+    }
+}
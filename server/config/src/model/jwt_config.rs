@@ -1,16 +1,59 @@
-use serde::Deserialize;
+use std::{fs, io};
+
+use serde::{Deserialize, Serialize};
+
+use super::SecretString;
 
 /// JWT 配置
 ///
+/// 默认使用 HS256 和共享密钥 `jwt_secret` 签名；RS256/ES256 使用私钥签名、公钥验证，
+/// 其他服务只需公钥即可验证令牌，不必共享密钥。密钥可以是 PEM 文件路径或内联的 PEM 文本，
+/// 两者只能设置其一。
+///
 /// 支持的环境变量：
-/// - APP_JWT_JWT_SECRET: JWT 密钥
+/// - APP_JWT_ALGORITHM: 签名算法（HS256/RS256/ES256）
+/// - APP_JWT_JWT_SECRET: JWT 密钥，HS256 时必填
+/// - APP_JWT_PRIVATE_KEY_PATH / APP_JWT_PRIVATE_KEY: 签名私钥文件路径或 PEM 文本
+/// - APP_JWT_PUBLIC_KEY_PATH / APP_JWT_PUBLIC_KEY: 验证公钥文件路径或 PEM 文本
 /// - APP_JWT_ISSUER: JWT 签发者
 /// - APP_JWT_EXPIRE: JWT 过期时间（秒）
-#[derive(Deserialize, Debug, Clone)]
+/// - APP_JWT_REFRESH_EXPIRE: 刷新令牌过期时间（秒）
+/// - APP_JWT_REFRESH_SECRET: 刷新令牌的签名密钥
+/// - APP_JWT_MAX_REFRESH_COUNT: 一次登录最多刷新的次数
+/// - APP_JWT_AUDIENCE: 额外接受的受众
+/// - APP_JWT_LEEWAY_SECONDS: 校验时间类声明时容忍的时钟偏差（秒）
+/// - APP_JWT_NBF_OFFSET: 令牌签发后延迟生效的秒数
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct JwtConfig {
-    /// JWT 密钥
+    /// 签名算法，默认 HS256
+    /// 环境变量: APP_JWT_ALGORITHM
+    #[serde(default)]
+    pub algorithm: JwtAlgorithm,
+
+    /// JWT 密钥，只用于 HS256
     /// 环境变量: APP_JWT_JWT_SECRET
-    pub jwt_secret: String,
+    #[serde(default)]
+    pub jwt_secret: SecretString,
+
+    /// PEM 格式的签名私钥文件路径，RSA 私钥用于 RS256，P-256 私钥（PKCS#8）用于 ES256
+    /// 环境变量: APP_JWT_PRIVATE_KEY_PATH
+    #[serde(default)]
+    pub private_key_path: Option<String>,
+
+    /// 内联的 PEM 格式签名私钥，与 `private_key_path` 只能设置一个
+    /// 环境变量: APP_JWT_PRIVATE_KEY
+    #[serde(default)]
+    pub private_key: Option<SecretString>,
+
+    /// PEM 格式的验证公钥文件路径
+    /// 环境变量: APP_JWT_PUBLIC_KEY_PATH
+    #[serde(default)]
+    pub public_key_path: Option<String>,
+
+    /// 内联的 PEM 格式验证公钥，与 `public_key_path` 只能设置一个
+    /// 环境变量: APP_JWT_PUBLIC_KEY
+    #[serde(default)]
+    pub public_key: Option<String>,
 
     /// JWT 签发者
     /// 环境变量: APP_JWT_ISSUER
@@ -19,4 +62,92 @@ pub struct JwtConfig {
     /// JWT 过期时间（秒）
     /// 环境变量: APP_JWT_EXPIRE
     pub expire: i64,
+
+    /// 刷新令牌过期时间（秒），默认 7 天，每次刷新重新计算
+    /// 环境变量: APP_JWT_REFRESH_EXPIRE
+    #[serde(default = "default_refresh_expire")]
+    pub refresh_expire: i64,
+
+    /// 刷新令牌的 HS256 签名密钥，未设置时与访问令牌使用同一套密钥
+    /// 环境变量: APP_JWT_REFRESH_SECRET
+    #[serde(default)]
+    pub refresh_secret: Option<SecretString>,
+
+    /// 一次登录最多刷新的次数，超过后需要重新登录，0 表示不限制
+    /// 环境变量: APP_JWT_MAX_REFRESH_COUNT
+    #[serde(default)]
+    pub max_refresh_count: u32,
+
+    /// 除路由分组自身的受众外额外接受的受众，多个服务共用令牌时配置
+    /// 环境变量: APP_JWT_AUDIENCE
+    #[serde(default)]
+    pub audience: Vec<String>,
+
+    /// 校验 `exp`、`nbf` 时容忍的时钟偏差（秒），默认 60
+    /// 环境变量: APP_JWT_LEEWAY_SECONDS
+    #[serde(default = "default_leeway_seconds")]
+    pub leeway_seconds: u64,
+
+    /// 令牌签发后延迟生效的秒数，`nbf` 为签发时间加上该值，默认 0 即立即生效；须小于 `expire`
+    /// 环境变量: APP_JWT_NBF_OFFSET
+    #[serde(default)]
+    pub nbf_offset: u64,
+}
+
+fn default_refresh_expire() -> i64 {
+    7 * 24 * 3600
+}
+
+fn default_leeway_seconds() -> u64 {
+    60
+}
+
+/// JWT 签名算法
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum JwtAlgorithm {
+    /// HMAC-SHA256，签发和验证共用 `jwt_secret`
+    #[default]
+    #[serde(alias = "hs256")]
+    HS256,
+    /// RSA PKCS#1 v1.5 + SHA-256
+    #[serde(alias = "rs256")]
+    RS256,
+    /// ECDSA P-256 + SHA-256
+    #[serde(alias = "es256")]
+    ES256,
+}
+
+impl JwtAlgorithm {
+    /// 是否为非对称算法，非对称算法使用密钥对而不是 `jwt_secret`
+    pub fn is_asymmetric(&self) -> bool {
+        !matches!(self, JwtAlgorithm::HS256)
+    }
+}
+
+/// 读取 PEM 文本，内联值和文件路径都未设置时返回 `None`，同时设置时返回错误
+fn load_pem(inline: Option<&str>, path: Option<&str>) -> io::Result<Option<Vec<u8>>> {
+    match (inline, path) {
+        (Some(_), Some(_)) => Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "inline key and key path must not both be set",
+        )),
+        (Some(pem), None) => Ok(Some(pem.as_bytes().to_vec())),
+        (None, Some(path)) => fs::read(path).map(Some),
+        (None, None) => Ok(None),
+    }
+}
+
+impl JwtConfig {
+    /// 读取签名私钥
+    pub fn private_key_pem(&self) -> io::Result<Option<Vec<u8>>> {
+        load_pem(
+            self.private_key.as_ref().map(SecretString::expose_secret),
+            self.private_key_path.as_deref(),
+        )
+    }
+
+    /// 读取验证公钥
+    pub fn public_key_pem(&self) -> io::Result<Option<Vec<u8>>> {
+        load_pem(self.public_key.as_deref(), self.public_key_path.as_deref())
+    }
 }
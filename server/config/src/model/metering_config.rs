@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// 计量配置
+///
+/// 面向多租户托管部署，由主节点每天按租户（域）汇总活跃用户数、存储字节数和 API 调用次数，
+/// 写入计量表供导出 CSV，并可推送到计费系统的 webhook。
+///
+/// 支持的环境变量：
+/// - APP_METERING_ENABLED: 是否启用每日计量
+/// - APP_METERING_WEBHOOK_URL: 计量数据推送地址
+/// - APP_METERING_WEBHOOK_AUTHORIZATION: 推送时附带的 Authorization 请求头
+/// - APP_METERING_TIMEOUT: 推送请求超时（秒）
+/// - APP_METERING_REPORT_DB_INSTANCE: 查询和导出计量报表使用的数据库实例
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct MeteringConfig {
+    /// 是否启用，API 调用次数来自 API 调用统计，需同时开启 `api_usage`
+    /// 环境变量: APP_METERING_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 每日计量生成后以 `{"date", "tenants": [...]}` POST 到该地址，失败时下次执行重试，
+    /// 未配置时只写入计量表
+    /// 环境变量: APP_METERING_WEBHOOK_URL
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+
+    /// 推送时附带的 Authorization 请求头
+    /// 环境变量: APP_METERING_WEBHOOK_AUTHORIZATION
+    #[serde(default)]
+    pub webhook_authorization: Option<String>,
+
+    /// 推送请求超时（秒）
+    /// 环境变量: APP_METERING_TIMEOUT
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+
+    /// 查询和导出计量报表使用的 `database_instances` 实例名，通常配置为 `read_only` 的报表库；
+    /// 未配置时读取主库（有只读副本时使用副本）
+    /// 环境变量: APP_METERING_REPORT_DB_INSTANCE
+    #[serde(default)]
+    pub report_db_instance: Option<String>,
+}
+
+impl Default for MeteringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            webhook_url: None,
+            webhook_authorization: None,
+            timeout: default_timeout(),
+            report_db_instance: None,
+        }
+    }
+}
+
+fn default_timeout() -> u64 {
+    10
+}
@@ -1,10 +1,49 @@
-pub use config::Config;
-pub use database_config::{DatabaseConfig, DatabasesInstancesConfig};
-pub use jwt_config::JwtConfig;
+pub use access_log_config::{AccessLogConfig, AccessLogFormat, AccessLogRotation};
+pub use alert_config::AlertConfig;
+pub use api_usage_config::ApiUsageConfig;
+pub use cache_config::CacheConfig;
+pub use cluster_config::ClusterConfig;
+pub use compliance_config::{
+    AccessReviewConfig, AuditArchiveConfig, AuditChainConfig, ComplianceConfig,
+    ExportWatermarkConfig, InactiveAccountConfig, ObjectLockMode, PolicyGateConfig,
+    WatermarkPolicy,
+};
+pub use concurrency_config::{ConcurrencyConfig, ConcurrencyMode};
+pub use config::{redact_text, Config, CONFIG_SCHEMA_VERSION};
+pub use consistency_config::{ConsistencyCategory, ConsistencyConfig};
+pub use database_config::{
+    append_pg_option, DatabaseConfig, DatabaseDriver, DatabasesInstancesConfig, MysqlOptions,
+    PostgresOptions, ReplicaStrategy, SqliteOptions, SslMode,
+};
+pub(crate) use database_config::{
+    default_connect_timeout, default_idle_timeout, default_max_connections, default_min_connections,
+};
+pub use jwt_config::{JwtAlgorithm, JwtConfig};
+pub use metering_config::MeteringConfig;
 pub use mongo_config::{MongoConfig, MongoInstancesConfig};
-pub use redis_config::{RedisConfig, RedisInstancesConfig, RedisMode};
-pub use s3_config::{S3Config, S3InstancesConfig};
-pub use server_config::ServerConfig;
+pub use notification_config::NotificationConfig;
+pub use recorder_config::RecorderConfig;
+pub(crate) use redis_config::{default_pool_size, default_redis_connection_timeout};
+pub use redis_config::{
+    RedisConfig, RedisInstancesConfig, RedisMode, RedisRetryConfig, RedisTlsConfig,
+};
+pub use runtime_config::RuntimeConfig;
+pub use s3_config::{S3Config, S3CredentialSource, S3CredentialsConfig, S3InstancesConfig};
+pub use secret_string::SecretString;
+pub use security_config::{
+    BotDetectionConfig, BotRouteGroup, BreakGlassConfig, FieldAccessConfig, FieldAccessRule,
+    LoginThrottleConfig, PasskeyConfig, RowLevelSecurityConfig, SecurityConfig,
+    SensitiveOperationConfig, SensitiveOperationRule, StepUpConfig, StepUpRule,
+};
+pub use server_config::{
+    Environment, ServerConfig, ServerRole, TrailerPolicy, MAX_HEADER_COUNT_LIMIT,
+};
+pub use siem_config::{SiemConfig, SiemFormat, SiemTransport};
+pub use storage_config::{ScannerConfig, ShareLinkConfig, StorageConfig};
+pub use telemetry_config::TelemetryConfig;
+pub use tenant_config::TenantConfig;
+pub(crate) use units::{byte_size, duration_secs};
+pub use units::{parse_byte_size, parse_duration_secs};
 
 /// 可选配置集合的包装类
 #[allow(dead_code)]
@@ -19,10 +58,29 @@ impl<T> From<Option<Vec<T>>> for OptionalConfigs<T> {
     }
 }
 
+mod access_log_config;
+mod alert_config;
+mod api_usage_config;
+mod cache_config;
+mod cluster_config;
+mod compliance_config;
+mod concurrency_config;
 mod config;
+mod consistency_config;
 mod database_config;
 mod jwt_config;
+mod metering_config;
 mod mongo_config;
+mod notification_config;
+mod recorder_config;
 mod redis_config;
+mod runtime_config;
 mod s3_config;
+mod secret_string;
+mod security_config;
 mod server_config;
+mod siem_config;
+mod storage_config;
+mod telemetry_config;
+mod tenant_config;
+mod units;
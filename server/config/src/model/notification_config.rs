@@ -0,0 +1,40 @@
+use serde::{Deserialize, Serialize};
+
+/// 通知配置
+///
+/// 通知按用户偏好投递到站内信和邮件，邮件通过告警配置的邮件网关发送。
+/// 开启摘要的用户的低优先级通知、以及免打扰时段内的非紧急通知不立即发邮件，
+/// 由主节点按 `digest_interval` 汇总成一封摘要邮件发送。
+///
+/// 支持的环境变量：
+/// - APP_NOTIFICATION_DIGEST_INTERVAL: 摘要邮件的发送间隔（秒）
+/// - APP_NOTIFICATION_DIGEST_MAX_ITEMS: 每封摘要邮件最多包含的通知数
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct NotificationConfig {
+    /// 摘要邮件的发送间隔（秒）
+    /// 环境变量: APP_NOTIFICATION_DIGEST_INTERVAL
+    #[serde(default = "default_digest_interval")]
+    pub digest_interval: u64,
+
+    /// 每封摘要邮件最多包含的通知数，超出的部分留到下一封
+    /// 环境变量: APP_NOTIFICATION_DIGEST_MAX_ITEMS
+    #[serde(default = "default_digest_max_items")]
+    pub digest_max_items: u64,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            digest_interval: default_digest_interval(),
+            digest_max_items: default_digest_max_items(),
+        }
+    }
+}
+
+fn default_digest_interval() -> u64 {
+    3600
+}
+
+fn default_digest_max_items() -> u64 {
+    50
+}
@@ -0,0 +1,58 @@
+use serde::{Deserialize, Serialize};
+
+/// 请求录制配置
+///
+/// 录制用于复现难以触发的问题：管理员按用户或请求 ID 开启录制会话后，
+/// 命中的请求（脱敏后的参数、响应和数据库交互摘要）写入有界的内存存储，可回放到预发环境。
+///
+/// 支持的环境变量：
+/// - APP_RECORDER_ENABLED: 是否允许开启录制
+/// - APP_RECORDER_MAX_CAPACITY: 单次会话最多保留的请求数
+/// - APP_RECORDER_MAX_BODY_BYTES: 单个请求/响应体最多记录的字节数
+/// - APP_RECORDER_N_PLUS_ONE_THRESHOLD: 调试构建中同一语句在单个请求内的执行次数告警阈值
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RecorderConfig {
+    /// 是否允许开启录制，关闭时录制接口返回错误
+    /// 环境变量: APP_RECORDER_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 单次会话最多保留的请求数，超出后丢弃最早的记录
+    /// 环境变量: APP_RECORDER_MAX_CAPACITY
+    #[serde(default = "default_max_capacity")]
+    pub max_capacity: usize,
+
+    /// 单个请求/响应体最多记录的字节数，超出部分截断
+    /// 环境变量: APP_RECORDER_MAX_BODY_BYTES
+    #[serde(default = "default_max_body_bytes")]
+    pub max_body_bytes: usize,
+
+    /// 调试构建中同一语句在单个请求内执行超过该次数时记录 N+1 查询告警，0 表示关闭；
+    /// 发布构建不统计
+    /// 环境变量: APP_RECORDER_N_PLUS_ONE_THRESHOLD
+    #[serde(default = "default_n_plus_one_threshold")]
+    pub n_plus_one_threshold: usize,
+}
+
+impl Default for RecorderConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_capacity: default_max_capacity(),
+            max_body_bytes: default_max_body_bytes(),
+            n_plus_one_threshold: default_n_plus_one_threshold(),
+        }
+    }
+}
+
+fn default_max_capacity() -> usize {
+    500
+}
+
+fn default_max_body_bytes() -> usize {
+    16 * 1024
+}
+
+fn default_n_plus_one_threshold() -> usize {
+    10
+}
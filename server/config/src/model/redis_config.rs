@@ -1,4 +1,6 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use super::{duration_secs, SecretString};
 
 /// Redis 配置
 ///
@@ -6,7 +8,17 @@ use serde::Deserialize;
 /// - APP_REDIS_MODE: Redis 模式 (single/cluster)
 /// - APP_REDIS_URL: Redis 连接 URL (单机模式)
 /// - APP_REDIS_URLS: Redis 集群节点地址列表 (逗号分隔)
-#[derive(Debug, Clone, Deserialize)]
+/// - APP_REDIS_POOL_SIZE: 连接池大小
+/// - APP_REDIS_CONNECTION_TIMEOUT: 建立连接超时时间（秒，或 `5s` 形式的时长）
+/// - APP_REDIS_RESPONSE_TIMEOUT: 命令响应超时时间（秒，或时长），0 表示不限制
+/// - APP_REDIS_RETRY_MAX_RETRIES: 连接失败重试次数
+/// - APP_REDIS_RETRY_MAX_DELAY_MS: 重试间隔上限（毫秒）
+/// - APP_REDIS_TLS_ENABLED: 是否使用 TLS 连接
+/// - APP_REDIS_TLS_CA_CERT: 自定义 CA 证书
+/// - APP_REDIS_TLS_CLIENT_CERT: 双向认证的客户端证书
+/// - APP_REDIS_TLS_CLIENT_KEY: 双向认证的客户端私钥
+/// - APP_REDIS_TLS_INSECURE_SKIP_VERIFY: 跳过服务端证书校验
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RedisConfig {
     /// Redis 模式
     /// 环境变量: APP_REDIS_MODE
@@ -27,7 +39,7 @@ pub struct RedisConfig {
     ///    redis+unix:///<path>[?db=<db>[&pass=<password>][&user=<username>][&protocol=<protocol>]]
     ///    或
     ///    unix:///<path>[?db=<db>][&pass=<password>][&user=<username>][&protocol=<protocol>]]
-    pub url: Option<String>,
+    pub url: Option<SecretString>,
 
     /// Redis 集群节点地址列表
     /// 环境变量: APP_REDIS_URLS (逗号分隔的URL列表)
@@ -36,10 +48,120 @@ pub struct RedisConfig {
     /// 注意：
     /// - 集群模式下，db 参数将被忽略，因为 Redis 集群不支持多数据库
     /// - 所有节点应使用相同的认证信息（用户名/密码）
-    pub urls: Option<Vec<String>>,
+    pub urls: Option<Vec<SecretString>>,
+
+    /// 连接池大小，默认 4
+    /// 环境变量: APP_REDIS_POOL_SIZE
+    ///
+    /// 每个连接都是多路复用连接，可以并发执行命令，连接数按实例的并发量和
+    /// 大 value 读写情况调整，不需要与数据库连接数一样多
+    #[serde(default = "default_pool_size")]
+    pub pool_size: usize,
+
+    /// 建立连接超时时间（秒），默认 5
+    /// 环境变量: APP_REDIS_CONNECTION_TIMEOUT
+    #[serde(
+        default = "default_redis_connection_timeout",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub connection_timeout: u64,
+
+    /// 命令响应超时时间（秒），默认 0 表示不限制
+    /// 环境变量: APP_REDIS_RESPONSE_TIMEOUT
+    #[serde(default, deserialize_with = "duration_secs::deserialize")]
+    pub response_timeout: u64,
+
+    /// 连接失败重试策略
+    #[serde(default)]
+    pub retry: RedisRetryConfig,
+
+    /// TLS 配置
+    #[serde(default)]
+    pub tls: RedisTlsConfig,
+}
+
+/// Redis TLS 配置
+///
+/// URL 使用 `rediss://` 时总是启用 TLS；托管 Redis（ElastiCache、Azure Cache 等）
+/// 给出 `redis://` 地址时开启 `enabled` 即可。证书和私钥可以是 PEM 文件路径，
+/// 也可以直接是 PEM 内容，便于通过环境变量或密钥管理注入
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct RedisTlsConfig {
+    /// 是否使用 TLS 连接，默认关闭
+    /// 环境变量: APP_REDIS_TLS_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 校验服务端证书的 CA 证书，未设置时使用系统信任的根证书
+    /// 环境变量: APP_REDIS_TLS_CA_CERT
+    #[serde(default)]
+    pub ca_cert: Option<String>,
+
+    /// 双向认证的客户端证书，须与 `client_key` 同时设置
+    /// 环境变量: APP_REDIS_TLS_CLIENT_CERT
+    #[serde(default)]
+    pub client_cert: Option<String>,
+
+    /// 双向认证的客户端私钥
+    /// 环境变量: APP_REDIS_TLS_CLIENT_KEY
+    #[serde(default)]
+    pub client_key: Option<SecretString>,
+
+    /// 跳过服务端证书校验，仅用于测试环境
+    /// 环境变量: APP_REDIS_TLS_INSECURE_SKIP_VERIFY
+    #[serde(default)]
+    pub insecure_skip_verify: bool,
+}
+
+impl RedisTlsConfig {
+    /// 是否配置了自定义证书
+    pub fn has_certificates(&self) -> bool {
+        self.ca_cert.is_some() || self.client_cert.is_some() || self.client_key.is_some()
+    }
+}
+
+/// Redis 连接重试配置
+///
+/// 单机模式下连接断开后按指数退避重连，集群模式下用于请求失败和节点切换时的重试
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct RedisRetryConfig {
+    /// 重试次数，默认 6
+    /// 环境变量: APP_REDIS_RETRY_MAX_RETRIES
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+
+    /// 重试间隔上限（毫秒），默认 2000
+    /// 环境变量: APP_REDIS_RETRY_MAX_DELAY_MS
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+}
+
+impl Default for RedisRetryConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: default_max_retries(),
+            max_delay_ms: default_max_delay_ms(),
+        }
+    }
+}
+
+pub(crate) fn default_pool_size() -> usize {
+    4
+}
+
+pub(crate) fn default_redis_connection_timeout() -> u64 {
+    5
 }
 
-#[derive(Debug, Clone, Deserialize, PartialEq)]
+fn default_max_retries() -> u32 {
+    6
+}
+
+fn default_max_delay_ms() -> u64 {
+    2000
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq)]
 pub enum RedisMode {
     #[serde(rename = "single")]
     Single,
@@ -53,17 +175,34 @@ pub enum RedisMode {
 /// - APP_REDIS_INSTANCES_0_NAME: 第一个实例名称
 /// - APP_REDIS_INSTANCES_0_REDIS_MODE: 第一个实例模式
 /// - APP_REDIS_INSTANCES_0_REDIS_URL: 第一个实例URL
+/// - APP_REDIS_INSTANCES_0_REDIS_POOL_SIZE: 第一个实例连接池大小
+/// - APP_REDIS_INSTANCES_0_REDIS_CONNECTION_TIMEOUT: 第一个实例建立连接超时时间
+/// - APP_REDIS_INSTANCES_0_REDIS_RESPONSE_TIMEOUT: 第一个实例命令响应超时时间
+/// - APP_REDIS_INSTANCES_0_REDIS_TLS_ENABLED: 第一个实例是否使用 TLS，同样支持 `TLS_CA_CERT`、
+///   `TLS_CLIENT_CERT`、`TLS_CLIENT_KEY`、`TLS_INSECURE_SKIP_VERIFY`
 /// - APP_REDIS_INSTANCES_1_NAME: 第二个实例名称
 /// - APP_REDIS_INSTANCES_1_REDIS_MODE: 第二个实例模式
 /// - APP_REDIS_INSTANCES_1_REDIS_URL: 第二个实例URL
+/// - APP_REDIS_INSTANCES_0_FALLBACK: 第一个实例的备用实例名称
+///
 /// 以此类推...
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct RedisInstancesConfig {
     /// 实例名称
     pub name: String,
 
     /// Redis 配置
     pub redis: RedisConfig,
+
+    /// 备用实例名称，须为另一个已配置的实例
+    /// 环境变量: APP_REDIS_INSTANCES_0_FALLBACK
+    ///
+    /// 本实例熔断期间，经 redis_helper 执行的命令自动转到备用实例。
+    /// 备用实例是独立的缓存，切换后的读取通常未命中，由调用方回源后写入备用实例；
+    /// 主实例恢复后不会同步备用实例的数据，切换期间更新过的键在主实例上可能是旧值，
+    /// 对一致性敏感的缓存应设置较短的过期时间
+    #[serde(default)]
+    pub fallback: Option<String>,
 }
 
 impl RedisConfig {
@@ -71,17 +210,78 @@ impl RedisConfig {
         self.mode == RedisMode::Cluster
     }
 
+    /// 是否使用 TLS 连接
+    pub fn use_tls(&self) -> bool {
+        let url_tls = |url: &SecretString| url.expose_secret().starts_with("rediss://");
+        self.tls.enabled
+            || match self.mode {
+                RedisMode::Single => self.url.as_ref().is_some_and(url_tls),
+                RedisMode::Cluster => self.urls.iter().flatten().any(url_tls),
+            }
+    }
+
+    /// 单机模式的连接 URL 原值
     pub fn get_url(&self) -> Option<String> {
         match self.mode {
-            RedisMode::Single => self.url.clone(),
+            RedisMode::Single => self.url.as_ref().map(|url| url.expose_secret().to_string()),
             RedisMode::Cluster => None,
         }
     }
 
+    /// 集群模式的节点 URL 原值
     pub fn get_urls(&self) -> Option<Vec<String>> {
         match self.mode {
             RedisMode::Single => None,
-            RedisMode::Cluster => self.urls.clone(),
+            RedisMode::Cluster => self.urls.as_ref().map(|urls| {
+                urls.iter()
+                    .map(|url| url.expose_secret().to_string())
+                    .collect()
+            }),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redis_pool_defaults() {
+        let config: RedisConfig =
+            serde_json::from_str(r#"{ "mode": "single", "url": "redis://localhost:6379/0" }"#)
+                .unwrap();
+        assert_eq!(config.pool_size, 4);
+        assert_eq!(config.connection_timeout, 5);
+        assert_eq!(config.response_timeout, 0);
+        assert_eq!(config.retry, RedisRetryConfig::default());
+        assert!(!config.use_tls());
+
+        let config: RedisConfig = serde_json::from_str(
+            r#"{ "mode": "single", "url": "redis://localhost:6379/0", "pool_size": 8,
+                "connection_timeout": "2s", "response_timeout": "1m", "retry": { "max_retries": 2 } }"#,
+        )
+        .unwrap();
+        assert_eq!(config.pool_size, 8);
+        assert_eq!(config.connection_timeout, 2);
+        assert_eq!(config.response_timeout, 60);
+        assert_eq!(config.retry.max_retries, 2);
+        assert_eq!(config.retry.max_delay_ms, 2000);
+    }
+
+    #[test]
+    fn test_redis_use_tls() {
+        let config: RedisConfig =
+            serde_json::from_str(r#"{ "mode": "single", "url": "rediss://cache:6380/0" }"#)
+                .unwrap();
+        assert!(config.use_tls());
+
+        let config: RedisConfig = serde_json::from_str(
+            r#"{ "mode": "cluster", "urls": ["redis://node-1:6379"],
+                "tls": { "enabled": true, "ca_cert": "/etc/redis/ca.pem" } }"#,
+        )
+        .unwrap();
+        assert!(config.use_tls());
+        assert!(config.tls.has_certificates());
+        assert!(!config.tls.insecure_skip_verify);
+    }
+}
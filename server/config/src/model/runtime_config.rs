@@ -0,0 +1,25 @@
+use serde::{Deserialize, Serialize};
+
+/// Tokio 运行时配置
+///
+/// 在构建运行时前读取，修改后需重启进程生效。未配置的项使用 tokio 默认值：
+/// 工作线程数等于 CPU 核数，阻塞线程池上限 512，线程栈 2MiB。
+///
+/// 支持的环境变量：
+/// - APP_RUNTIME_WORKER_THREADS: 工作线程数
+/// - APP_RUNTIME_BLOCKING_THREADS: 阻塞线程池上限
+/// - APP_RUNTIME_THREAD_STACK_SIZE: 线程栈大小（字节）
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RuntimeConfig {
+    /// 工作线程数
+    /// 环境变量: APP_RUNTIME_WORKER_THREADS
+    pub worker_threads: Option<usize>,
+
+    /// 阻塞线程池上限，文件读写、CPU 剖析等阻塞任务在此执行
+    /// 环境变量: APP_RUNTIME_BLOCKING_THREADS
+    pub blocking_threads: Option<usize>,
+
+    /// 线程栈大小（字节）
+    /// 环境变量: APP_RUNTIME_THREAD_STACK_SIZE
+    pub thread_stack_size: Option<usize>,
+}
@@ -1,29 +1,184 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use super::{duration_secs, SecretString};
 
 /// S3 配置
 ///
 /// 支持的环境变量：
 /// - APP_S3_REGION: S3 区域
-/// - APP_S3_ACCESS_KEY_ID: S3 访问密钥ID
-/// - APP_S3_SECRET_ACCESS_KEY: S3 秘密访问密钥
+/// - APP_S3_ACCESS_KEY_ID: S3 访问密钥ID (可选)
+/// - APP_S3_SECRET_ACCESS_KEY: S3 秘密访问密钥 (可选)
+/// - APP_S3_CREDENTIALS_SOURCE: 凭证来源 static/env/imds/web_identity/profile (可选)
+/// - APP_S3_CREDENTIALS_PROFILE: profile 来源使用的配置名 (可选)
 /// - APP_S3_ENDPOINT: S3 端点URL (可选)
-#[derive(Debug, Clone, Deserialize)]
+/// - APP_S3_MAX_ATTEMPTS: 请求最大尝试次数 (可选)
+/// - APP_S3_CONNECT_TIMEOUT: 连接超时（秒）(可选)
+/// - APP_S3_OPERATION_TIMEOUT: 单次操作总超时（秒）(可选)
+/// - APP_S3_OPERATION_ATTEMPT_TIMEOUT: 单次尝试超时（秒）(可选)
+/// - APP_S3_ACCELERATE: 是否启用传输加速 (可选)
+/// - APP_S3_MULTIPART_CHUNK_SIZE: 分片上传的分片大小（字节）(可选)
+/// - APP_S3_MULTIPART_CONCURRENCY: 分片并发上传数 (可选)
+/// - APP_S3_BUCKET: 默认存储桶 (可选)
+/// - APP_S3_FORCE_PATH_STYLE: 是否使用路径风格访问 (可选)
+/// - APP_S3_PRESIGN_EXPIRY: 预签名地址有效期（秒，或时长）(可选)
+/// - APP_S3_PUBLIC_BASE_URL: 对象的公开访问地址前缀 (可选)
+///
+/// MinIO 等 S3 兼容服务与 AWS 的行为差异较大（如不支持传输加速、对并发分片更敏感），
+/// 因此以上选项均按实例配置
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
 pub struct S3Config {
     /// S3 区域
     /// 环境变量: APP_S3_REGION
     pub region: String,
 
-    /// S3 访问密钥ID
+    /// S3 访问密钥ID，使用 IAM 角色等非静态凭证时省略
     /// 环境变量: APP_S3_ACCESS_KEY_ID
+    #[serde(default)]
     pub access_key_id: String,
 
-    /// S3 秘密访问密钥
+    /// S3 秘密访问密钥，使用 IAM 角色等非静态凭证时省略
     /// 环境变量: APP_S3_SECRET_ACCESS_KEY
-    pub secret_access_key: String,
+    #[serde(default)]
+    pub secret_access_key: SecretString,
+
+    /// 凭证来源，未配置时有密钥则使用密钥，否则使用 SDK 默认凭证链
+    pub credentials: Option<S3CredentialsConfig>,
 
     /// S3 端点URL (可选，用于自定义S3兼容服务)
     /// 环境变量: APP_S3_ENDPOINT
     pub endpoint: Option<String>,
+
+    /// 请求最大尝试次数（含首次请求），未配置时使用 SDK 默认值
+    /// 环境变量: APP_S3_MAX_ATTEMPTS
+    pub max_attempts: Option<u32>,
+
+    /// 连接超时（秒）
+    /// 环境变量: APP_S3_CONNECT_TIMEOUT
+    pub connect_timeout: Option<u64>,
+
+    /// 单次操作总超时（秒，包含所有重试）
+    /// 环境变量: APP_S3_OPERATION_TIMEOUT
+    pub operation_timeout: Option<u64>,
+
+    /// 单次尝试超时（秒）
+    /// 环境变量: APP_S3_OPERATION_ATTEMPT_TIMEOUT
+    pub operation_attempt_timeout: Option<u64>,
+
+    /// 是否启用 S3 传输加速，仅 AWS 支持，自定义端点时请勿开启
+    /// 环境变量: APP_S3_ACCELERATE
+    #[serde(default)]
+    pub accelerate: bool,
+
+    /// 分片上传的分片大小（字节），超过该大小的对象使用分片上传
+    /// 环境变量: APP_S3_MULTIPART_CHUNK_SIZE
+    pub multipart_chunk_size: Option<usize>,
+
+    /// 分片并发上传数
+    /// 环境变量: APP_S3_MULTIPART_CONCURRENCY
+    pub multipart_concurrency: Option<usize>,
+
+    /// 默认存储桶，文件存储、审计归档等未单独配置存储桶时使用
+    /// 环境变量: APP_S3_BUCKET
+    pub bucket: Option<String>,
+
+    /// 是否使用路径风格（`{endpoint}/{bucket}/{key}`）访问，MinIO 等未配置泛域名的服务需要开启
+    /// 环境变量: APP_S3_FORCE_PATH_STYLE
+    #[serde(default)]
+    pub force_path_style: bool,
+
+    /// 预签名下载地址的有效期（秒），未配置时为 1 小时，S3 限制最长 7 天
+    /// 环境变量: APP_S3_PRESIGN_EXPIRY
+    #[serde(default, deserialize_with = "duration_secs::deserialize")]
+    pub presign_expiry: Option<u64>,
+
+    /// 对象的公开访问地址前缀，指向存储桶根目录，如 CDN 域名或 `https://minio.example.com/bucket`；
+    /// 配置后文件地址直接拼接对象键，不再生成预签名地址
+    /// 环境变量: APP_S3_PUBLIC_BASE_URL
+    pub public_base_url: Option<String>,
+}
+
+/// S3 凭证来源
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum S3CredentialSource {
+    /// 配置中的 `access_key_id` 和 `secret_access_key`
+    #[default]
+    Static,
+    /// 环境变量 `AWS_ACCESS_KEY_ID`、`AWS_SECRET_ACCESS_KEY` 和 `AWS_SESSION_TOKEN`
+    Env,
+    /// EC2 实例角色，从实例元数据服务获取临时凭证
+    Imds,
+    /// Web Identity 令牌换取角色凭证，EKS 的 IRSA 通过 `AWS_ROLE_ARN` 和
+    /// `AWS_WEB_IDENTITY_TOKEN_FILE` 环境变量注入
+    WebIdentity,
+    /// `~/.aws/credentials` 和 `~/.aws/config` 中的配置
+    Profile,
+}
+
+impl S3CredentialSource {
+    /// 除静态密钥外，其余来源均获取可自动刷新的临时凭证
+    pub fn is_static(self) -> bool {
+        self == Self::Static
+    }
+}
+
+/// S3 凭证配置
+#[derive(Debug, Clone, Default, PartialEq, Deserialize, Serialize)]
+pub struct S3CredentialsConfig {
+    /// 环境变量: APP_S3_CREDENTIALS_SOURCE
+    #[serde(default)]
+    pub source: S3CredentialSource,
+
+    /// profile 来源使用的配置名，未配置时使用 `AWS_PROFILE` 或 `default`
+    /// 环境变量: APP_S3_CREDENTIALS_PROFILE
+    pub profile: Option<String>,
+}
+
+impl S3Config {
+    /// 默认预签名有效期 1 小时
+    pub const DEFAULT_PRESIGN_EXPIRY: u64 = 3600;
+
+    /// S3 预签名地址的最长有效期 7 天
+    pub const MAX_PRESIGN_EXPIRY: u64 = 7 * 24 * 3600;
+
+    /// S3 要求除最后一片外每个分片不小于 5MiB
+    pub const MIN_MULTIPART_CHUNK_SIZE: usize = 5 * 1024 * 1024;
+
+    /// 默认分片大小 8MiB
+    pub const DEFAULT_MULTIPART_CHUNK_SIZE: usize = 8 * 1024 * 1024;
+
+    /// 默认分片并发数
+    pub const DEFAULT_MULTIPART_CONCURRENCY: usize = 4;
+
+    /// 获取分片大小，小于 S3 下限时按下限处理
+    pub fn multipart_chunk_size(&self) -> usize {
+        self.multipart_chunk_size
+            .unwrap_or(Self::DEFAULT_MULTIPART_CHUNK_SIZE)
+            .max(Self::MIN_MULTIPART_CHUNK_SIZE)
+    }
+
+    /// 获取分片并发数，至少为 1
+    pub fn multipart_concurrency(&self) -> usize {
+        self.multipart_concurrency
+            .unwrap_or(Self::DEFAULT_MULTIPART_CONCURRENCY)
+            .max(1)
+    }
+
+    /// 获取预签名有效期
+    pub fn presign_expiry(&self) -> u64 {
+        self.presign_expiry.unwrap_or(Self::DEFAULT_PRESIGN_EXPIRY)
+    }
+
+    /// 对象的公开访问地址，未配置 `public_base_url` 时返回 `None`
+    pub fn public_url(&self, key: &str) -> Option<String> {
+        self.public_base_url.as_deref().map(|base| {
+            format!(
+                "{}/{}",
+                base.trim_end_matches('/'),
+                key.trim_start_matches('/')
+            )
+        })
+    }
 }
 
 /// S3 实例配置
@@ -33,9 +188,20 @@ pub struct S3Config {
 /// - APP_S3_INSTANCES_0_S3_REGION: 第一个实例区域
 /// - APP_S3_INSTANCES_0_S3_ACCESS_KEY_ID: 第一个实例访问密钥ID
 /// - APP_S3_INSTANCES_0_S3_SECRET_ACCESS_KEY: 第一个实例秘密访问密钥
+/// - APP_S3_INSTANCES_0_S3_CREDENTIALS_SOURCE: 第一个实例凭证来源
+/// - APP_S3_INSTANCES_0_S3_CREDENTIALS_PROFILE: 第一个实例 profile 配置名
 /// - APP_S3_INSTANCES_0_S3_ENDPOINT: 第一个实例端点URL
+/// - APP_S3_INSTANCES_0_S3_MAX_ATTEMPTS: 第一个实例请求最大尝试次数
+/// - APP_S3_INSTANCES_0_S3_ACCELERATE: 第一个实例是否启用传输加速
+/// - APP_S3_INSTANCES_0_S3_MULTIPART_CHUNK_SIZE: 第一个实例分片大小
+/// - APP_S3_INSTANCES_0_S3_MULTIPART_CONCURRENCY: 第一个实例分片并发数
+/// - APP_S3_INSTANCES_0_S3_BUCKET: 第一个实例默认存储桶
+/// - APP_S3_INSTANCES_0_S3_FORCE_PATH_STYLE: 第一个实例是否使用路径风格访问
+/// - APP_S3_INSTANCES_0_S3_PRESIGN_EXPIRY: 第一个实例预签名有效期
+/// - APP_S3_INSTANCES_0_S3_PUBLIC_BASE_URL: 第一个实例公开访问地址前缀
+///
 /// 以此类推...
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
 pub struct S3InstancesConfig {
     /// 实例名称
     pub name: String,
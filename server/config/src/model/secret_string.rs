@@ -0,0 +1,80 @@
+use std::fmt;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// 敏感配置值，如 JWT 密钥、S3 秘密访问密钥和带凭证的连接串
+///
+/// `Debug`、`Display` 和序列化只输出 `***`，整份配置打印到日志时不会泄露密钥；
+/// 需要原值时调用 `expose_secret()`，调用处即为密钥的使用点
+pub(crate) const REDACTED: &str = "***";
+
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(String);
+
+impl SecretString {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// 读取原值，不要写入日志或响应
+    pub fn expose_secret(&self) -> &str {
+        &self.0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretString({})", REDACTED)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(secret: String) -> Self {
+        Self(secret)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(secret: &str) -> Self {
+        Self(secret.to_string())
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for SecretString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(REDACTED)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_string_redacts_formatting() {
+        let secret = SecretString::from("postgres://admin:secret@db/soybean");
+        assert_eq!(format!("{:?}", secret), "SecretString(***)");
+        assert_eq!(secret.to_string(), "***");
+        assert_eq!(serde_json::to_string(&secret).unwrap(), "\"***\"");
+        assert_eq!(secret.expose_secret(), "postgres://admin:secret@db/soybean");
+
+        let secret: SecretString = serde_json::from_str("\"jwt-secret\"").unwrap();
+        assert_eq!(secret.expose_secret(), "jwt-secret");
+    }
+}
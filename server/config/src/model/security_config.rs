@@ -0,0 +1,671 @@
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+/// 安全配置
+///
+/// 支持的环境变量：
+/// - APP_SECURITY_LOGIN_THROTTLE_ENABLED: 是否启用登录节流
+/// - APP_SECURITY_LOGIN_THROTTLE_REDIS_INSTANCE: 使用的命名 Redis 实例
+/// - APP_SECURITY_LOGIN_THROTTLE_FREE_ATTEMPTS: 不延迟的失败次数
+/// - APP_SECURITY_LOGIN_THROTTLE_BASE_DELAY_MS: 首次延迟（毫秒）
+/// - APP_SECURITY_LOGIN_THROTTLE_MAX_DELAY_MS: 延迟上限（毫秒）
+/// - APP_SECURITY_LOGIN_THROTTLE_WINDOW: 失败计数有效期（秒）
+/// - APP_SECURITY_BOT_DETECTION_ENABLED: 是否启用机器人检测
+/// - APP_SECURITY_PASSKEY_ENABLED: 是否启用通行密钥
+/// - APP_SECURITY_PASSKEY_RP_ID: 依赖方 ID（站点域名）
+/// - APP_SECURITY_PASSKEY_RP_ORIGIN: 前端访问地址
+/// - APP_SECURITY_PASSKEY_SECOND_FACTOR: 已注册通行密钥的用户密码登录后是否需要二次验证
+/// - APP_SECURITY_PASSKEY_PASSWORDLESS: 是否允许仅使用通行密钥登录
+/// - APP_SECURITY_STEP_UP_ENABLED: 是否启用敏感接口二次认证
+/// - APP_SECURITY_STEP_UP_MAX_AGE: 默认的认证有效期（秒）
+/// - APP_SECURITY_SENSITIVE_OPERATION_APPROVAL_TTL: 敏感操作审批的有效期（秒）
+/// - APP_SECURITY_BREAK_GLASS_ENABLED: 是否启用紧急访问
+/// - APP_SECURITY_BREAK_GLASS_CREDENTIAL_HASH: 紧急凭证的哈希
+/// - APP_SECURITY_BREAK_GLASS_TTL: 紧急访问会话的有效期（秒）
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SecurityConfig {
+    /// 登录节流配置
+    #[serde(default)]
+    pub login_throttle: LoginThrottleConfig,
+
+    /// 机器人检测配置
+    #[serde(default)]
+    pub bot_detection: BotDetectionConfig,
+
+    /// 通行密钥（WebAuthn）配置
+    #[serde(default)]
+    pub passkey: PasskeyConfig,
+
+    /// 敏感接口二次认证配置
+    #[serde(default)]
+    pub step_up: StepUpConfig,
+
+    /// 生产环境敏感操作防护配置
+    #[serde(default)]
+    pub sensitive_operation: SensitiveOperationConfig,
+
+    /// 紧急访问配置
+    #[serde(default)]
+    pub break_glass: BreakGlassConfig,
+
+    /// 响应字段权限配置
+    #[serde(default)]
+    pub field_access: FieldAccessConfig,
+
+    /// PostgreSQL 行级安全配置
+    #[serde(default)]
+    pub row_level_security: RowLevelSecurityConfig,
+}
+
+/// PostgreSQL 行级安全配置
+///
+/// 启用后鉴权通过的请求通过 `db_helper::get_rls_connection` 执行的语句会带上
+/// `app.current_user_id` 和 `app.tenant_id` 会话变量，RLS 策略据此在数据库层隔离租户，
+/// 作为应用层过滤之外的兜底
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct RowLevelSecurityConfig {
+    /// 是否启用
+    #[serde(default)]
+    pub enabled: bool,
+}
+
+/// 响应字段权限配置
+///
+/// 规则中的字段只返回给拥有 `roles` 中任一角色的用户，其他用户的响应中移除这些字段，
+/// 即使通过 `fields` 参数显式请求也不会返回
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct FieldAccessConfig {
+    #[serde(default)]
+    pub rules: Vec<FieldAccessRule>,
+}
+
+impl FieldAccessConfig {
+    /// 请求路径下当前角色不能查看的字段
+    pub fn hidden_fields(&self, path: &str, roles: &[String]) -> Vec<&str> {
+        self.rules
+            .iter()
+            .filter(|rule| path.starts_with(rule.path.as_str()))
+            .filter(|rule| !rule.roles.iter().any(|role| roles.contains(role)))
+            .flat_map(|rule| rule.fields.iter().map(String::as_str))
+            .collect()
+    }
+}
+
+/// 字段权限规则
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FieldAccessRule {
+    /// 路由前缀，如 `/user`
+    pub path: String,
+
+    /// 受限的字段，使用响应中的字段名，如 `phoneNumber`
+    pub fields: Vec<String>,
+
+    /// 可以查看这些字段的角色
+    #[serde(default)]
+    pub roles: Vec<String>,
+}
+
+/// 敏感接口二次认证配置
+///
+/// 命中规则的接口要求令牌中的 `auth_time` 在有效期内，即用户最近重新验证过身份；
+/// `require_passkey` 的规则还要求本次验证使用了通行密钥。
+/// 不满足时返回 401 和 `stepUpRequired` 结构，前端据此弹出重新认证窗口。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StepUpConfig {
+    /// 是否启用
+    /// 环境变量: APP_SECURITY_STEP_UP_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 默认的认证有效期（秒），规则未单独设置时使用
+    /// 环境变量: APP_SECURITY_STEP_UP_MAX_AGE
+    #[serde(default = "default_step_up_max_age")]
+    pub max_age: u64,
+
+    /// 需要二次认证的接口
+    #[serde(default)]
+    pub rules: Vec<StepUpRule>,
+}
+
+impl StepUpConfig {
+    /// 匹配请求的规则，多个规则匹配时取路径前缀最长的
+    pub fn rule(&self, method: &str, path: &str) -> Option<&StepUpRule> {
+        self.rules
+            .iter()
+            .filter(|rule| {
+                rule.method
+                    .as_deref()
+                    .is_none_or(|rule_method| rule_method.eq_ignore_ascii_case(method))
+                    && path.starts_with(rule.path.as_str())
+            })
+            .max_by_key(|rule| rule.path.len())
+    }
+}
+
+impl Default for StepUpConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_age: default_step_up_max_age(),
+            rules: Vec::new(),
+        }
+    }
+}
+
+/// 二次认证规则
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct StepUpRule {
+    /// 路由前缀，如 `/authorization/assign-permission`
+    pub path: String,
+
+    /// 请求方法，未设置时匹配所有方法
+    #[serde(default)]
+    pub method: Option<String>,
+
+    /// 认证有效期（秒），未设置时使用 `max_age`
+    #[serde(default)]
+    pub max_age: Option<u64>,
+
+    /// 是否要求使用通行密钥完成认证
+    #[serde(default)]
+    pub require_passkey: bool,
+}
+
+/// 生产环境敏感操作防护配置
+///
+/// `server.environment` 为 `production` 时，命中规则的接口须携带紧急授权标记和一份
+/// 由其他管理员批准的审批，审批只能使用一次
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SensitiveOperationConfig {
+    /// 审批的有效期（秒），从申请时开始计算
+    /// 环境变量: APP_SECURITY_SENSITIVE_OPERATION_APPROVAL_TTL
+    #[serde(default = "default_approval_ttl")]
+    pub approval_ttl: u64,
+
+    /// 受保护的接口，未配置时保护删除租户和彻底删除隔离文件
+    #[serde(default = "default_sensitive_operation_rules")]
+    pub rules: Vec<SensitiveOperationRule>,
+}
+
+impl SensitiveOperationConfig {
+    /// 匹配请求的规则
+    pub fn rule(&self, method: &str, path: &str) -> Option<&SensitiveOperationRule> {
+        self.rules.iter().find(|rule| rule.matches(method, path))
+    }
+}
+
+impl Default for SensitiveOperationConfig {
+    fn default() -> Self {
+        Self {
+            approval_ttl: default_approval_ttl(),
+            rules: default_sensitive_operation_rules(),
+        }
+    }
+}
+
+/// 敏感操作规则
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SensitiveOperationRule {
+    /// 路由，`:` 开头的段匹配任意值，如 `/tenant/:id/purge`
+    pub path: String,
+
+    /// 请求方法
+    pub method: String,
+}
+
+impl SensitiveOperationRule {
+    fn new(method: &str, path: &str) -> Self {
+        Self {
+            path: path.to_string(),
+            method: method.to_string(),
+        }
+    }
+
+    /// 按段比较路径，段数必须相同
+    pub fn matches(&self, method: &str, path: &str) -> bool {
+        if !self.method.eq_ignore_ascii_case(method) {
+            return false;
+        }
+        let pattern: Vec<&str> = self.path.trim_end_matches('/').split('/').collect();
+        let segments: Vec<&str> = path.trim_end_matches('/').split('/').collect();
+        pattern.len() == segments.len()
+            && pattern.iter().zip(&segments).all(|(expected, actual)| {
+                expected == actual || (expected.starts_with(':') && !actual.is_empty())
+            })
+    }
+}
+
+/// 紧急访问配置
+///
+/// 紧急凭证只以哈希形式保存在配置中，平时封存在保险柜。使用凭证并填写原因后获得
+/// `ttl` 内有效的 `role` 角色会话，同时立即通知 `notify_roles` 的全部用户。
+/// 凭证使用一次后即失效，更换 `credential_hash` 后重新封存。
+/// 会话内的每个请求和响应都完整记录到操作日志
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BreakGlassConfig {
+    /// 是否启用
+    /// 环境变量: APP_SECURITY_BREAK_GLASS_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 紧急凭证的哈希，与用户密码使用相同的算法
+    /// 环境变量: APP_SECURITY_BREAK_GLASS_CREDENTIAL_HASH
+    #[serde(default)]
+    pub credential_hash: String,
+
+    /// 会话获得的角色
+    #[serde(default = "default_break_glass_role")]
+    pub role: String,
+
+    /// 会话所在的域
+    #[serde(default = "default_break_glass_domain")]
+    pub domain: String,
+
+    /// 会话有效期（秒），到期后令牌失效，不能续期
+    /// 环境变量: APP_SECURITY_BREAK_GLASS_TTL
+    #[serde(default = "default_break_glass_ttl")]
+    pub ttl: u64,
+
+    /// 使用凭证时通知的角色
+    #[serde(default = "default_break_glass_notify_roles")]
+    pub notify_roles: Vec<String>,
+}
+
+impl Default for BreakGlassConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            credential_hash: String::new(),
+            role: default_break_glass_role(),
+            domain: default_break_glass_domain(),
+            ttl: default_break_glass_ttl(),
+            notify_roles: default_break_glass_notify_roles(),
+        }
+    }
+}
+
+/// 通行密钥（WebAuthn）配置
+///
+/// 用户登录后可在个人中心注册多个通行密钥（按设备命名）。`second_factor` 开启时，
+/// 已注册通行密钥的用户密码登录后还需完成通行密钥验证才能拿到令牌；
+/// `passwordless` 开启时允许输入用户名后直接使用通行密钥登录。
+/// 注册和验证过程中的挑战保存在主 Redis 中，有效期为 `challenge_ttl`。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PasskeyConfig {
+    /// 是否启用通行密钥
+    /// 环境变量: APP_SECURITY_PASSKEY_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 依赖方 ID，即前端站点的域名，如 `admin.example.com`
+    /// 环境变量: APP_SECURITY_PASSKEY_RP_ID
+    #[serde(default = "default_rp_id")]
+    pub rp_id: String,
+
+    /// 前端访问地址，如 `https://admin.example.com`，须与 `rp_id` 属于同一域名
+    /// 环境变量: APP_SECURITY_PASSKEY_RP_ORIGIN
+    #[serde(default = "default_rp_origin")]
+    pub rp_origin: String,
+
+    /// 依赖方名称，注册时展示给用户
+    #[serde(default = "default_rp_name")]
+    pub rp_name: String,
+
+    /// 已注册通行密钥的用户密码登录后是否需要二次验证
+    /// 环境变量: APP_SECURITY_PASSKEY_SECOND_FACTOR
+    #[serde(default)]
+    pub second_factor: bool,
+
+    /// 是否允许仅使用通行密钥登录
+    /// 环境变量: APP_SECURITY_PASSKEY_PASSWORDLESS
+    #[serde(default)]
+    pub passwordless: bool,
+
+    /// 注册和验证挑战的有效期（秒）
+    #[serde(default = "default_challenge_ttl")]
+    pub challenge_ttl: u64,
+}
+
+impl Default for PasskeyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            rp_id: default_rp_id(),
+            rp_origin: default_rp_origin(),
+            rp_name: default_rp_name(),
+            second_factor: false,
+            passwordless: false,
+            challenge_ttl: default_challenge_ttl(),
+        }
+    }
+}
+
+/// 机器人检测配置
+///
+/// 对配置的路由组按请求特征打分：蜜罐字段被填写、缺少或可疑的 User-Agent、
+/// 命中可疑 TLS 指纹（JA3，由前置代理通过请求头传入）等。
+/// 分数达到路由组的 `captcha_score` 时要求验证码，达到 `block_score` 时直接拒绝。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BotDetectionConfig {
+    /// 是否启用机器人检测
+    /// 环境变量: APP_SECURITY_BOT_DETECTION_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 蜜罐字段名，前端表单中隐藏该字段，正常用户不会填写
+    #[serde(default = "default_honeypot_field")]
+    pub honeypot_field: String,
+
+    /// 前置代理传入 JA3 指纹的请求头，未设置时不检查 TLS 指纹
+    #[serde(default)]
+    pub ja3_header: Option<String>,
+
+    /// 可疑的 JA3 指纹，如常见脚本库和无头浏览器的指纹
+    #[serde(default)]
+    pub suspicious_ja3: Vec<String>,
+
+    /// 客户端完成验证码后提交令牌的请求头
+    #[serde(default = "default_captcha_header")]
+    pub captcha_header: String,
+
+    /// 启用检测的路由组，默认只包含登录接口
+    #[serde(default = "default_route_groups")]
+    pub route_groups: Vec<BotRouteGroup>,
+}
+
+impl BotDetectionConfig {
+    /// 匹配请求路径的路由组，多个路由组匹配时取前缀最长的
+    pub fn route_group(&self, path: &str) -> Option<&BotRouteGroup> {
+        self.route_groups
+            .iter()
+            .filter(|group| path.starts_with(group.prefix.as_str()))
+            .max_by_key(|group| group.prefix.len())
+    }
+}
+
+impl Default for BotDetectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            honeypot_field: default_honeypot_field(),
+            ja3_header: None,
+            suspicious_ja3: Vec::new(),
+            captcha_header: default_captcha_header(),
+            route_groups: default_route_groups(),
+        }
+    }
+}
+
+/// 机器人检测路由组
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct BotRouteGroup {
+    /// 路由前缀，如 `/auth/login`
+    pub prefix: String,
+
+    /// 是否检查蜜罐字段，只对 JSON 和表单请求生效
+    #[serde(default = "default_true")]
+    pub honeypot: bool,
+
+    /// 要求验证码的分数（0-100）
+    #[serde(default = "default_captcha_score")]
+    pub captcha_score: u32,
+
+    /// 直接拒绝的分数（0-100）
+    #[serde(default = "default_block_score")]
+    pub block_score: u32,
+}
+
+/// 登录节流配置
+///
+/// 按账号和来源 IP 分别在 Redis 中累计登录失败次数，超过 `free_attempts` 后
+/// 每次登录前按失败次数指数增加响应延迟，登录成功后清除账号计数。
+/// 白名单内的 IP（如办公网出口）不计数也不延迟。未配置 Redis 或 Redis 不可用时不节流。
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LoginThrottleConfig {
+    /// 是否启用登录节流
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 使用的命名 Redis 实例，未设置时使用主 Redis
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_REDIS_INSTANCE
+    #[serde(default)]
+    pub redis_instance: Option<String>,
+
+    /// Redis 键前缀
+    #[serde(default = "default_key_prefix")]
+    pub key_prefix: String,
+
+    /// 不延迟的失败次数
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_FREE_ATTEMPTS
+    #[serde(default = "default_free_attempts")]
+    pub free_attempts: u32,
+
+    /// 超过免延迟次数后的首次延迟（毫秒），之后每多失败一次翻倍
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_BASE_DELAY_MS
+    #[serde(default = "default_base_delay_ms")]
+    pub base_delay_ms: u64,
+
+    /// 延迟上限（毫秒）
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_MAX_DELAY_MS
+    #[serde(default = "default_max_delay_ms")]
+    pub max_delay_ms: u64,
+
+    /// 失败计数有效期（秒），从最近一次失败开始计算
+    /// 环境变量: APP_SECURITY_LOGIN_THROTTLE_WINDOW
+    #[serde(default = "default_window")]
+    pub window: u64,
+
+    /// 不节流的 IP 或网段，如 `203.0.113.10`、`10.0.0.0/8`
+    #[serde(default)]
+    pub allowlist: Vec<String>,
+}
+
+impl LoginThrottleConfig {
+    /// 按失败次数计算登录延迟（毫秒）
+    pub fn delay_ms(&self, failures: u32) -> u64 {
+        if failures <= self.free_attempts {
+            return 0;
+        }
+        let exponent = (failures - self.free_attempts - 1).min(32);
+        self.base_delay_ms
+            .saturating_mul(1 << exponent)
+            .min(self.max_delay_ms)
+    }
+
+    /// IP 是否在白名单内，无法解析的 IP 视为不在白名单内
+    pub fn is_allowlisted(&self, ip: &str) -> bool {
+        let Ok(ip) = ip.parse::<IpAddr>() else {
+            return false;
+        };
+        self.allowlist
+            .iter()
+            .filter_map(|entry| parse_network(entry))
+            .any(|(network, prefix)| contains(network, prefix, ip))
+    }
+
+    /// 无法解析的白名单条目
+    pub fn invalid_allowlist_entries(&self) -> Vec<&str> {
+        self.allowlist
+            .iter()
+            .filter(|entry| parse_network(entry).is_none())
+            .map(String::as_str)
+            .collect()
+    }
+}
+
+impl Default for LoginThrottleConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            redis_instance: None,
+            key_prefix: default_key_prefix(),
+            free_attempts: default_free_attempts(),
+            base_delay_ms: default_base_delay_ms(),
+            max_delay_ms: default_max_delay_ms(),
+            window: default_window(),
+            allowlist: Vec::new(),
+        }
+    }
+}
+
+/// 解析 IP 或 CIDR 网段，返回网络地址和前缀长度
+fn parse_network(entry: &str) -> Option<(IpAddr, u32)> {
+    let (address, prefix) = match entry.trim().split_once('/') {
+        Some((address, prefix)) => (address, Some(prefix.parse::<u32>().ok()?)),
+        None => (entry.trim(), None),
+    };
+    let address = address.parse::<IpAddr>().ok()?;
+    let max_prefix = if address.is_ipv4() { 32 } else { 128 };
+    let prefix = prefix.unwrap_or(max_prefix);
+    (prefix <= max_prefix).then_some((address, prefix))
+}
+
+fn contains(network: IpAddr, prefix: u32, ip: IpAddr) -> bool {
+    match (network, ip) {
+        (IpAddr::V4(network), IpAddr::V4(ip)) => {
+            let mask = u32::MAX.checked_shl(32 - prefix).unwrap_or(0);
+            u32::from(network) & mask == u32::from(ip) & mask
+        },
+        (IpAddr::V6(network), IpAddr::V6(ip)) => {
+            let mask = u128::MAX.checked_shl(128 - prefix).unwrap_or(0);
+            u128::from(network) & mask == u128::from(ip) & mask
+        },
+        _ => false,
+    }
+}
+
+fn default_honeypot_field() -> String {
+    "website".to_string()
+}
+
+fn default_captcha_header() -> String {
+    "X-Captcha-Token".to_string()
+}
+
+fn default_route_groups() -> Vec<BotRouteGroup> {
+    vec![BotRouteGroup {
+        prefix: "/auth/login".to_string(),
+        honeypot: true,
+        captcha_score: default_captcha_score(),
+        block_score: default_block_score(),
+    }]
+}
+
+fn default_true() -> bool {
+    true
+}
+
+fn default_captcha_score() -> u32 {
+    50
+}
+
+fn default_block_score() -> u32 {
+    80
+}
+
+fn default_rp_id() -> String {
+    "localhost".to_string()
+}
+
+fn default_rp_origin() -> String {
+    "http://localhost:9527".to_string()
+}
+
+fn default_rp_name() -> String {
+    "Soybean Admin".to_string()
+}
+
+fn default_challenge_ttl() -> u64 {
+    300
+}
+
+fn default_approval_ttl() -> u64 {
+    3600
+}
+
+fn default_break_glass_role() -> String {
+    "ROLE_SUPER".to_string()
+}
+
+fn default_break_glass_domain() -> String {
+    "built-in".to_string()
+}
+
+fn default_break_glass_ttl() -> u64 {
+    3600
+}
+
+fn default_break_glass_notify_roles() -> Vec<String> {
+    vec!["ROLE_SUPER".to_string()]
+}
+
+fn default_sensitive_operation_rules() -> Vec<SensitiveOperationRule> {
+    vec![
+        SensitiveOperationRule::new("POST", "/tenant/:id/purge"),
+        SensitiveOperationRule::new("POST", "/tenant/:id/purge/confirm"),
+        SensitiveOperationRule::new("DELETE", "/file/quarantine/:id"),
+    ]
+}
+
+fn default_step_up_max_age() -> u64 {
+    300
+}
+
+fn default_key_prefix() -> String {
+    "soybean:login_throttle".to_string()
+}
+
+fn default_free_attempts() -> u32 {
+    3
+}
+
+fn default_base_delay_ms() -> u64 {
+    500
+}
+
+fn default_max_delay_ms() -> u64 {
+    10_000
+}
+
+fn default_window() -> u64 {
+    900
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_access_hidden_fields() {
+        let config = FieldAccessConfig {
+            rules: vec![FieldAccessRule {
+                path: "/user".to_string(),
+                fields: vec!["phoneNumber".to_string(), "email".to_string()],
+                roles: vec!["ROLE_SUPER".to_string()],
+            }],
+        };
+
+        assert_eq!(
+            config.hidden_fields("/user/01HX", &["ROLE_USER".to_string()]),
+            vec!["phoneNumber", "email"]
+        );
+        assert!(config
+            .hidden_fields("/user", &["ROLE_SUPER".to_string()])
+            .is_empty());
+        assert!(config.hidden_fields("/role", &[]).is_empty());
+    }
+
+    #[test]
+    fn test_sensitive_operation_rule_matches_segments() {
+        let rule = SensitiveOperationRule::new("POST", "/tenant/:id/purge");
+
+        assert!(rule.matches("POST", "/tenant/01HX/purge"));
+        assert!(rule.matches("post", "/tenant/01HX/purge/"));
+        assert!(!rule.matches("DELETE", "/tenant/01HX/purge"));
+        assert!(!rule.matches("POST", "/tenant//purge"));
+        assert!(!rule.matches("POST", "/tenant/01HX/purge/confirm"));
+        assert!(!rule.matches("POST", "/tenant/01HX"));
+    }
+}
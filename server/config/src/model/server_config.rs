@@ -1,17 +1,214 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+
+use super::{byte_size, duration_secs};
 
 /// 服务器配置
 ///
 /// 支持的环境变量：
 /// - APP_SERVER_HOST: 服务器监听地址
 /// - APP_SERVER_PORT: 服务器监听端口
-#[derive(Deserialize, Debug, Clone)]
+/// - APP_SERVER_ROLE: 进程角色（api/worker/all）
+/// - APP_SERVER_DRAIN_DELAY: 排空时等待负载均衡摘除实例的时长（秒，或 `5s`、`1m` 形式的时长）
+/// - APP_SERVER_DRAIN_TIMEOUT: 排空时等待进行中工作完成的最长时间（秒，或时长）
+/// - APP_SERVER_ENVIRONMENT: 部署环境（dev/staging/production）
+/// - APP_SERVER_STRICT_COMPATIBILITY: 启动时发现版本不兼容则拒绝启动
+/// - APP_SERVER_MAX_HEADER_SIZE: 请求头总大小上限（字节，或 `32KiB` 形式的容量）
+/// - APP_SERVER_MAX_HEADER_COUNT: 请求头数量上限
+/// - APP_SERVER_MAX_URI_LENGTH: URI 长度上限（字节，或容量）
+/// - APP_SERVER_TRAILERS: 请求尾部字段的处理方式（limit/discard）
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
 pub struct ServerConfig {
-    /// 服务器监听地址
+    /// 服务器监听地址，默认 `0.0.0.0`
     /// 环境变量: APP_SERVER_HOST
+    #[serde(default = "default_host")]
     pub host: String,
 
-    /// 服务器监听端口
+    /// 服务器监听端口，默认 10001
     /// 环境变量: APP_SERVER_PORT
+    #[serde(default = "default_port")]
     pub port: u32,
+
+    /// 进程角色，默认同时提供 HTTP 服务和运行后台任务
+    /// 环境变量: APP_SERVER_ROLE
+    #[serde(default)]
+    pub role: ServerRole,
+
+    /// 排空开始后就绪检查立即失败，等待该时长让负载均衡摘除实例（秒）
+    /// 环境变量: APP_SERVER_DRAIN_DELAY
+    #[serde(
+        default = "default_drain_delay",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub drain_delay: u64,
+
+    /// 等待进行中的请求和后台任务完成的最长时间，超时后强制退出（秒）
+    /// 环境变量: APP_SERVER_DRAIN_TIMEOUT
+    #[serde(
+        default = "default_drain_timeout",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub drain_timeout: u64,
+
+    /// 部署环境，生产环境下破坏性接口需要紧急授权和审批才能调用
+    /// 环境变量: APP_SERVER_ENVIRONMENT
+    #[serde(default)]
+    pub environment: Environment,
+
+    /// 启动时发现未受权限保护的写接口时拒绝启动，默认只记录警告
+    /// 环境变量: APP_SERVER_STRICT_ROUTE_PERMISSIONS
+    #[serde(default)]
+    pub strict_route_permissions: bool,
+
+    /// 启动时发现数据库结构、配置文件与程序版本不兼容时拒绝启动，默认只记录警告
+    /// 环境变量: APP_SERVER_STRICT_COMPATIBILITY
+    #[serde(default)]
+    pub strict_compatibility: bool,
+
+    /// 请求头名称和值的总字节数上限，超出时在进入业务处理前返回 431，默认 32KiB
+    /// 环境变量: APP_SERVER_MAX_HEADER_SIZE
+    #[serde(
+        default = "default_max_header_size",
+        deserialize_with = "byte_size::deserialize"
+    )]
+    pub max_header_size: usize,
+
+    /// 请求头数量上限，超出时由 HTTP 解析器返回 431，默认 100；解析器最多接受 100 个请求头，不能调大
+    /// 环境变量: APP_SERVER_MAX_HEADER_COUNT
+    #[serde(default = "default_max_header_count")]
+    pub max_header_count: usize,
+
+    /// 路径和查询参数的总长度上限，超出时返回 414，默认 8KiB
+    /// 环境变量: APP_SERVER_MAX_URI_LENGTH
+    #[serde(
+        default = "default_max_uri_length",
+        deserialize_with = "byte_size::deserialize"
+    )]
+    pub max_uri_length: usize,
+
+    /// 分块请求体末尾的尾部字段（trailers）的处理方式
+    /// 环境变量: APP_SERVER_TRAILERS
+    #[serde(default)]
+    pub trailers: TrailerPolicy,
+}
+
+impl Default for ServerConfig {
+    fn default() -> Self {
+        Self {
+            host: default_host(),
+            port: default_port(),
+            role: ServerRole::default(),
+            drain_delay: default_drain_delay(),
+            drain_timeout: default_drain_timeout(),
+            environment: Environment::default(),
+            strict_route_permissions: false,
+            strict_compatibility: false,
+            max_header_size: default_max_header_size(),
+            max_header_count: default_max_header_count(),
+            max_uri_length: default_max_uri_length(),
+            trailers: TrailerPolicy::default(),
+        }
+    }
+}
+
+fn default_host() -> String {
+    "0.0.0.0".to_string()
+}
+
+fn default_port() -> u32 {
+    10001
+}
+
+fn default_drain_delay() -> u64 {
+    5
+}
+
+fn default_drain_timeout() -> u64 {
+    30
+}
+
+fn default_max_header_size() -> usize {
+    32 * 1024
+}
+
+/// HTTP/1 解析器接受的请求头数量上限
+pub const MAX_HEADER_COUNT_LIMIT: usize = 100;
+
+fn default_max_header_count() -> usize {
+    MAX_HEADER_COUNT_LIMIT
+}
+
+fn default_max_uri_length() -> usize {
+    8 * 1024
+}
+
+/// 请求尾部字段（trailers）的处理方式
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TrailerPolicy {
+    /// 保留尾部字段，与请求头共用大小和数量上限，超出时读取请求体失败
+    #[default]
+    Limit,
+    /// 丢弃尾部字段，业务处理看不到
+    Discard,
+}
+
+/// 进程角色
+///
+/// 同一个二进制可以按角色启动，以便独立扩缩容 HTTP 节点和后台任务节点
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ServerRole {
+    /// 只提供 HTTP 服务，不运行后台任务
+    Api,
+    /// 只运行后台任务（队列消费、定时任务），不监听端口
+    Worker,
+    /// 同时提供 HTTP 服务和运行后台任务
+    #[default]
+    All,
+}
+
+impl ServerRole {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ServerRole::Api => "api",
+            ServerRole::Worker => "worker",
+            ServerRole::All => "all",
+        }
+    }
+
+    /// 是否监听端口提供 HTTP 服务
+    pub fn serves_http(&self) -> bool {
+        matches!(self, ServerRole::Api | ServerRole::All)
+    }
+
+    /// 是否运行后台任务
+    pub fn runs_jobs(&self) -> bool {
+        matches!(self, ServerRole::Worker | ServerRole::All)
+    }
+}
+
+/// 部署环境
+///
+/// 未配置时为 `dev`，保持原有行为；`production` 启用敏感操作防护
+#[derive(Deserialize, Serialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Environment {
+    #[default]
+    Dev,
+    Staging,
+    Production,
+}
+
+impl Environment {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Environment::Dev => "dev",
+            Environment::Staging => "staging",
+            Environment::Production => "production",
+        }
+    }
+
+    pub fn is_production(&self) -> bool {
+        matches!(self, Environment::Production)
+    }
 }
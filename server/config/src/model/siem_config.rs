@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+
+/// SIEM 导出配置
+///
+/// 将审计事件（操作日志、业务审计）和认证事件（登录成功/失败）实时推送到 SIEM，
+/// 事件先写入内存缓冲区，由后台任务按批次发送，发送失败时重试并保留在缓冲区中。
+///
+/// 支持的环境变量：
+/// - APP_SIEM_ENABLED: 是否启用导出
+/// - APP_SIEM_FORMAT: 事件格式（json/cef）
+/// - APP_SIEM_TRANSPORT: 传输方式（http/syslog_udp/syslog_tcp）
+/// - APP_SIEM_ENDPOINT: HTTP 地址或 syslog 的 host:port
+/// - APP_SIEM_AUTHORIZATION: HTTP Authorization 请求头
+/// - APP_SIEM_BATCH_SIZE: 单批最多发送的事件数
+/// - APP_SIEM_FLUSH_INTERVAL: 发送间隔（秒）
+/// - APP_SIEM_BUFFER_CAPACITY: 缓冲区容量
+/// - APP_SIEM_MAX_RETRIES: 单批发送失败后的重试次数
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SiemConfig {
+    /// 是否启用导出
+    /// 环境变量: APP_SIEM_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 事件格式
+    /// 环境变量: APP_SIEM_FORMAT
+    #[serde(default)]
+    pub format: SiemFormat,
+
+    /// 传输方式
+    /// 环境变量: APP_SIEM_TRANSPORT
+    #[serde(default)]
+    pub transport: SiemTransport,
+
+    /// HTTP 传输时为完整 URL（如 Splunk HEC 的 raw 端点），syslog 传输时为 host:port
+    /// 环境变量: APP_SIEM_ENDPOINT
+    #[serde(default)]
+    pub endpoint: String,
+
+    /// HTTP 传输时附带的 Authorization 请求头，如 `Splunk <token>`
+    /// 环境变量: APP_SIEM_AUTHORIZATION
+    #[serde(default)]
+    pub authorization: Option<String>,
+
+    /// 单批最多发送的事件数
+    /// 环境变量: APP_SIEM_BATCH_SIZE
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+
+    /// 发送间隔（秒），决定事件到达 SIEM 的最大延迟
+    /// 环境变量: APP_SIEM_FLUSH_INTERVAL
+    #[serde(default = "default_flush_interval")]
+    pub flush_interval: u64,
+
+    /// 缓冲区容量，SIEM 长时间不可用导致缓冲区写满时丢弃最早的事件
+    /// 环境变量: APP_SIEM_BUFFER_CAPACITY
+    #[serde(default = "default_buffer_capacity")]
+    pub buffer_capacity: usize,
+
+    /// 单批发送失败后的重试次数，重试间隔从 1 秒开始指数增长
+    /// 环境变量: APP_SIEM_MAX_RETRIES
+    #[serde(default = "default_max_retries")]
+    pub max_retries: u32,
+}
+
+/// SIEM 事件格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SiemFormat {
+    /// 每行一个 JSON 对象（JSON Lines）
+    #[default]
+    Json,
+    /// ArcSight Common Event Format
+    Cef,
+}
+
+/// SIEM 传输方式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SiemTransport {
+    /// HTTP POST，每批事件按行拼接为一个请求体
+    #[default]
+    Http,
+    /// RFC 5424 syslog over UDP，每个事件一个报文
+    SyslogUdp,
+    /// RFC 5424 syslog over TCP，按换行分隔
+    SyslogTcp,
+}
+
+impl Default for SiemConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            format: SiemFormat::default(),
+            transport: SiemTransport::default(),
+            endpoint: String::new(),
+            authorization: None,
+            batch_size: default_batch_size(),
+            flush_interval: default_flush_interval(),
+            buffer_capacity: default_buffer_capacity(),
+            max_retries: default_max_retries(),
+        }
+    }
+}
+
+fn default_batch_size() -> usize {
+    200
+}
+
+fn default_flush_interval() -> u64 {
+    5
+}
+
+fn default_buffer_capacity() -> usize {
+    10_000
+}
+
+fn default_max_retries() -> u32 {
+    3
+}
@@ -0,0 +1,195 @@
+use serde::{Deserialize, Serialize};
+
+use super::{byte_size, S3Config};
+
+/// 文件存储配置
+///
+/// 上传的文件按内容哈希（SHA-256）寻址存储在 S3 中，相同内容只保存一份，
+/// 元数据表中维护引用计数，引用计数归零并超过宽限期的对象由 GC 任务清理。
+///
+/// 支持的环境变量：
+/// - APP_STORAGE_BUCKET: 存储桶名称
+/// - APP_STORAGE_S3_INSTANCE: 使用的 S3 实例名称（可选）
+/// - APP_STORAGE_BLOB_PREFIX: 对象键前缀
+/// - APP_STORAGE_GC_GRACE_PERIOD: 引用归零后的宽限期（秒）
+/// - APP_STORAGE_GC_INTERVAL: GC 任务执行间隔（秒）
+/// - APP_STORAGE_MAX_UPLOAD_SIZE: 单个文件上传大小上限（字节，或 `100MiB` 形式的容量）
+/// - APP_STORAGE_SCANNER_CLAMD_ADDRESS: ClamAV 守护进程地址（可选）
+/// - APP_STORAGE_SHARE_DEFAULT_TTL: 分享链接默认有效期（秒）
+/// - APP_STORAGE_SHARE_MAX_PASSWORD_ATTEMPTS: 分享密码连续输错的次数上限
+/// - APP_STORAGE_SHARE_PASSWORD_LOCKOUT: 分享密码输错达到上限后的锁定时长（秒）
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct StorageConfig {
+    /// 存储桶名称，未配置时使用 S3 实例的 `bucket`
+    /// 环境变量: APP_STORAGE_BUCKET
+    #[serde(default)]
+    pub bucket: String,
+
+    /// 使用的 S3 实例名称，未配置时使用主 S3 客户端
+    /// 环境变量: APP_STORAGE_S3_INSTANCE
+    pub s3_instance: Option<String>,
+
+    /// 对象键前缀
+    /// 环境变量: APP_STORAGE_BLOB_PREFIX
+    #[serde(default = "default_blob_prefix")]
+    pub blob_prefix: String,
+
+    /// 引用计数归零后的宽限期（秒），宽限期内再次上传相同内容可直接复用
+    /// 环境变量: APP_STORAGE_GC_GRACE_PERIOD
+    #[serde(default = "default_gc_grace_period")]
+    pub gc_grace_period: u64,
+
+    /// GC 任务执行间隔（秒）
+    /// 环境变量: APP_STORAGE_GC_INTERVAL
+    #[serde(default = "default_gc_interval")]
+    pub gc_interval: u64,
+
+    /// 单个文件上传大小上限（字节）
+    /// 环境变量: APP_STORAGE_MAX_UPLOAD_SIZE
+    #[serde(
+        default = "default_max_upload_size",
+        deserialize_with = "byte_size::deserialize"
+    )]
+    pub max_upload_size: usize,
+
+    /// 病毒扫描配置，未配置时上传文件不做扫描
+    pub scanner: Option<ScannerConfig>,
+
+    /// 公开分享链接配置
+    #[serde(default)]
+    pub share: ShareLinkConfig,
+}
+
+/// 病毒扫描配置
+///
+/// 通过 clamd 的 INSTREAM 协议扫描上传内容，命中的文件进入隔离状态，
+/// 只能由管理员在复核队列中放行或清除
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ScannerConfig {
+    /// clamd 监听地址，如 `127.0.0.1:3310`
+    /// 环境变量: APP_STORAGE_SCANNER_CLAMD_ADDRESS
+    pub clamd_address: String,
+
+    /// 扫描超时时间（秒）
+    /// 环境变量: APP_STORAGE_SCANNER_TIMEOUT
+    #[serde(default = "default_scan_timeout")]
+    pub timeout: u64,
+
+    /// 扫描失败时是否直接放行，默认为否（文件进入待复核状态）
+    /// 环境变量: APP_STORAGE_SCANNER_FAIL_OPEN
+    #[serde(default)]
+    pub fail_open: bool,
+}
+
+/// 公开分享链接配置
+///
+/// 分享链接凭令牌免登录下载，只能分享扫描通过或已放行的文件
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct ShareLinkConfig {
+    /// 未指定有效期时的默认有效期（秒）
+    /// 环境变量: APP_STORAGE_SHARE_DEFAULT_TTL
+    #[serde(default = "default_share_ttl")]
+    pub default_ttl: u64,
+
+    /// 有效期上限（秒），超过上限的请求按上限处理
+    /// 环境变量: APP_STORAGE_SHARE_MAX_TTL
+    #[serde(default = "default_share_max_ttl")]
+    pub max_ttl: u64,
+
+    /// 生成链接时使用的公开地址前缀，如 `https://admin.example.com/api`，
+    /// 未配置时返回相对路径
+    /// 环境变量: APP_STORAGE_SHARE_BASE_URL
+    pub base_url: Option<String>,
+
+    /// 同一来源对同一分享链接连续输错密码的次数上限，达到后锁定
+    /// 环境变量: APP_STORAGE_SHARE_MAX_PASSWORD_ATTEMPTS
+    #[serde(default = "default_share_max_password_attempts")]
+    pub max_password_attempts: u32,
+
+    /// 输错密码达到上限后的锁定时长（秒）
+    /// 环境变量: APP_STORAGE_SHARE_PASSWORD_LOCKOUT
+    #[serde(default = "default_share_password_lockout")]
+    pub password_lockout: u64,
+}
+
+impl Default for ShareLinkConfig {
+    fn default() -> Self {
+        Self {
+            default_ttl: default_share_ttl(),
+            max_ttl: default_share_max_ttl(),
+            base_url: None,
+            max_password_attempts: default_share_max_password_attempts(),
+            password_lockout: default_share_password_lockout(),
+        }
+    }
+}
+
+fn default_blob_prefix() -> String {
+    "blobs".to_string()
+}
+
+fn default_gc_grace_period() -> u64 {
+    24 * 60 * 60
+}
+
+fn default_gc_interval() -> u64 {
+    60 * 60
+}
+
+fn default_max_upload_size() -> usize {
+    100 * 1024 * 1024
+}
+
+fn default_scan_timeout() -> u64 {
+    30
+}
+
+fn default_share_ttl() -> u64 {
+    7 * 24 * 60 * 60
+}
+
+fn default_share_max_ttl() -> u64 {
+    30 * 24 * 60 * 60
+}
+
+fn default_share_max_password_attempts() -> u32 {
+    5
+}
+
+fn default_share_password_lockout() -> u64 {
+    15 * 60
+}
+
+impl StorageConfig {
+    /// 根据内容哈希生成对象键，按哈希前两位分目录避免单目录对象过多
+    pub fn blob_key(&self, hash: &str) -> String {
+        let prefix = self.blob_prefix.trim_end_matches('/');
+        let shard = &hash[..hash.len().min(2)];
+        if prefix.is_empty() {
+            format!("{}/{}", shard, hash)
+        } else {
+            format!("{}/{}/{}", prefix, shard, hash)
+        }
+    }
+
+    /// 实际使用的存储桶：单独配置的存储桶优先，未配置时使用 S3 实例的默认存储桶
+    pub fn resolve_bucket(&self, instance: &S3Config) -> Option<String> {
+        Some(self.bucket.trim())
+            .filter(|bucket| !bucket.is_empty())
+            .map(str::to_string)
+            .or_else(|| instance.bucket.clone())
+    }
+}
+
+impl ShareLinkConfig {
+    /// 实际使用的有效期（秒）：未指定时取默认值，不超过上限
+    pub fn effective_ttl(&self, requested: Option<u64>) -> u64 {
+        requested.unwrap_or(self.default_ttl).min(self.max_ttl)
+    }
+
+    /// 根据令牌生成分享链接
+    pub fn share_url(&self, token: &str) -> String {
+        let base = self.base_url.as_deref().unwrap_or("").trim_end_matches('/');
+        format!("{}/share/{}", base, token)
+    }
+}
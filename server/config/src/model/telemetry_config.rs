@@ -0,0 +1,60 @@
+use serde::{Deserialize, Serialize};
+
+use super::duration_secs;
+
+/// 匿名遥测配置
+///
+/// 默认关闭。开启后由主节点定期向 `endpoint` 上报匿名的汇总数据：版本号、启用的模块、
+/// 各类实例数量和按路由分组的 P95 延迟，帮助维护者判断功能的使用情况。
+/// 上报内容不包含主机名、连接串、用户、租户、请求路径参数等任何可识别部署的信息，
+/// 可通过 `GET /telemetry/preview` 查看将要上报的完整内容。
+///
+/// 支持的环境变量：
+/// - APP_TELEMETRY_ENABLED: 是否开启匿名遥测
+/// - APP_TELEMETRY_ENDPOINT: 上报地址
+/// - APP_TELEMETRY_INTERVAL: 上报间隔（秒，或时长）
+/// - APP_TELEMETRY_TIMEOUT: 上报请求超时（秒）
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct TelemetryConfig {
+    /// 是否开启，需要显式开启
+    /// 环境变量: APP_TELEMETRY_ENABLED
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// 上报地址，开启时必须配置 https 地址
+    /// 环境变量: APP_TELEMETRY_ENDPOINT
+    #[serde(default)]
+    pub endpoint: Option<String>,
+
+    /// 上报间隔，每次上报后重新统计延迟
+    /// 环境变量: APP_TELEMETRY_INTERVAL
+    #[serde(
+        default = "default_interval",
+        deserialize_with = "duration_secs::deserialize"
+    )]
+    pub interval: u64,
+
+    /// 上报请求超时（秒）
+    /// 环境变量: APP_TELEMETRY_TIMEOUT
+    #[serde(default = "default_timeout")]
+    pub timeout: u64,
+}
+
+impl Default for TelemetryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            endpoint: None,
+            interval: default_interval(),
+            timeout: default_timeout(),
+        }
+    }
+}
+
+fn default_interval() -> u64 {
+    24 * 3600
+}
+
+fn default_timeout() -> u64 {
+    10
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+/// 租户管理配置
+///
+/// 租户对应系统中的域，开通时创建域、管理员角色和管理员账号，可选为租户创建独立 schema
+/// 或使用 `database_instances` 中配置的独立数据库；停用后拒绝该租户的所有请求；
+/// 删除需两步确认，确认后在等待期结束时由主节点执行。
+///
+/// 支持的环境变量：
+/// - APP_TENANT_ADMIN_TEMPLATE_ROLE: 租户管理员角色复制权限和菜单的模板角色
+/// - APP_TENANT_PURGE_CONFIRM_TTL: 删除确认码的有效期（秒）
+/// - APP_TENANT_PURGE_DELAY_DAYS: 确认删除后到实际删除的等待天数
+/// - APP_TENANT_MAX_ARCHIVE_SIZE: 导入租户档案的大小上限（字节）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TenantConfig {
+    /// 模板角色在 `built-in` 域下的接口权限和菜单会复制给新租户的管理员角色
+    /// 环境变量: APP_TENANT_ADMIN_TEMPLATE_ROLE
+    #[serde(default = "default_admin_template_role")]
+    pub admin_template_role: String,
+
+    /// 删除确认码的有效期（秒）
+    /// 环境变量: APP_TENANT_PURGE_CONFIRM_TTL
+    #[serde(default = "default_purge_confirm_ttl")]
+    pub purge_confirm_ttl: u64,
+
+    /// 确认删除后到实际删除的等待天数，期间可以撤销
+    /// 环境变量: APP_TENANT_PURGE_DELAY_DAYS
+    #[serde(default = "default_purge_delay_days")]
+    pub purge_delay_days: u32,
+
+    /// 导入租户档案的请求体大小上限（字节），档案包含全部文件内容
+    /// 环境变量: APP_TENANT_MAX_ARCHIVE_SIZE
+    #[serde(default = "default_max_archive_size")]
+    pub max_archive_size: usize,
+}
+
+impl Default for TenantConfig {
+    fn default() -> Self {
+        Self {
+            admin_template_role: default_admin_template_role(),
+            purge_confirm_ttl: default_purge_confirm_ttl(),
+            purge_delay_days: default_purge_delay_days(),
+            max_archive_size: default_max_archive_size(),
+        }
+    }
+}
+
+fn default_admin_template_role() -> String {
+    "ROLE_ADMIN".to_string()
+}
+
+fn default_purge_confirm_ttl() -> u64 {
+    900
+}
+
+fn default_purge_delay_days() -> u32 {
+    7
+}
+
+fn default_max_archive_size() -> usize {
+    512 * 1024 * 1024
+}
@@ -0,0 +1,180 @@
+use serde::{de::Error, Deserialize, Deserializer};
+
+/// 整数或带单位的字符串
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum NumberOrString {
+    Number(u64),
+    Text(String),
+}
+
+/// 拆分 `<数字><单位>` 序列，如 `1h30m` 拆为 `[(1, "h"), (30, "m")]`
+fn split_segments(text: &str) -> Option<Vec<(u64, &str)>> {
+    let mut segments = Vec::new();
+    let mut rest = text.trim();
+    if rest.is_empty() {
+        return None;
+    }
+    while !rest.is_empty() {
+        let digits = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        if digits == 0 {
+            return None;
+        }
+        let value = rest[..digits].parse().ok()?;
+        rest = rest[digits..].trim_start();
+        let unit = rest
+            .find(|c: char| c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        segments.push((value, rest[..unit].trim()));
+        rest = &rest[unit..];
+    }
+    Some(segments)
+}
+
+/// 解析时长，返回秒数
+///
+/// 支持的单位：`s`、`m`、`h`、`d`，可以组合使用；不带单位的数字按秒处理
+pub fn parse_duration_secs(text: &str) -> Result<u64, String> {
+    let invalid = || {
+        format!(
+            "invalid duration '{}', expected seconds or a value like 30s, 10m, 1h30m",
+            text
+        )
+    };
+    let segments = split_segments(text).ok_or_else(invalid)?;
+    segments.into_iter().try_fold(0u64, |total, (value, unit)| {
+        let factor = match unit {
+            "" | "s" => 1,
+            "m" => 60,
+            "h" => 60 * 60,
+            "d" => 24 * 60 * 60,
+            _ => return Err(invalid()),
+        };
+        value
+            .checked_mul(factor)
+            .and_then(|secs| total.checked_add(secs))
+            .ok_or_else(invalid)
+    })
+}
+
+/// 解析容量，返回字节数
+///
+/// `KB`、`MB`、`GB`、`TB` 按 1000 进位，`KiB`、`MiB`、`GiB`、`TiB` 及简写 `K`、`M`、`G`、`T`
+/// 按 1024 进位，单位不区分大小写；不带单位的数字按字节处理
+pub fn parse_byte_size(text: &str) -> Result<u64, String> {
+    let invalid = || {
+        format!(
+            "invalid byte size '{}', expected bytes or a value like 512KB, 16MiB",
+            text
+        )
+    };
+    let segments = split_segments(text).ok_or_else(invalid)?;
+    let [(value, unit)] = segments.as_slice() else {
+        return Err(invalid());
+    };
+    let factor: u64 = match unit.to_ascii_lowercase().as_str() {
+        "" | "b" => 1,
+        "kb" => 1000,
+        "mb" => 1000_u64.pow(2),
+        "gb" => 1000_u64.pow(3),
+        "tb" => 1000_u64.pow(4),
+        "k" | "kib" => 1 << 10,
+        "m" | "mib" => 1 << 20,
+        "g" | "gib" => 1 << 30,
+        "t" | "tib" => 1 << 40,
+        _ => return Err(invalid()),
+    };
+    value.checked_mul(factor).ok_or_else(invalid)
+}
+
+fn deserialize_with<'de, D, T>(
+    deserializer: D,
+    parse: fn(&str) -> Result<u64, String>,
+) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: TryFrom<u64>,
+{
+    let value = match NumberOrString::deserialize(deserializer)? {
+        NumberOrString::Number(value) => value,
+        NumberOrString::Text(text) => parse(&text).map_err(D::Error::custom)?,
+    };
+    T::try_from(value).map_err(|_| D::Error::custom(format!("value {} is out of range", value)))
+}
+
+/// 以秒为单位的时长字段，用于 `#[serde(deserialize_with = "duration_secs::deserialize")]`
+pub(crate) mod duration_secs {
+    use serde::Deserializer;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<u64>,
+    {
+        super::deserialize_with(deserializer, super::parse_duration_secs)
+    }
+}
+
+/// 以字节为单位的容量字段，用于 `#[serde(deserialize_with = "byte_size::deserialize")]`
+pub(crate) mod byte_size {
+    use serde::Deserializer;
+
+    pub fn deserialize<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+        T: TryFrom<u64>,
+    {
+        super::deserialize_with(deserializer, super::parse_byte_size)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_duration_secs() {
+        assert_eq!(parse_duration_secs("30").unwrap(), 30);
+        assert_eq!(parse_duration_secs("30s").unwrap(), 30);
+        assert_eq!(parse_duration_secs("10m").unwrap(), 600);
+        assert_eq!(parse_duration_secs("1h30m").unwrap(), 5400);
+        assert_eq!(parse_duration_secs("1d").unwrap(), 86400);
+        assert!(parse_duration_secs("").is_err());
+        assert!(parse_duration_secs("10x").is_err());
+        assert!(parse_duration_secs("m").is_err());
+    }
+
+    #[test]
+    fn test_parse_byte_size() {
+        assert_eq!(parse_byte_size("1024").unwrap(), 1024);
+        assert_eq!(parse_byte_size("16MiB").unwrap(), 16 * 1024 * 1024);
+        assert_eq!(parse_byte_size("16m").unwrap(), 16 * 1024 * 1024);
+        assert_eq!(parse_byte_size("512KB").unwrap(), 512_000);
+        assert_eq!(parse_byte_size("1 GiB").unwrap(), 1 << 30);
+        assert!(parse_byte_size("1MiB512KiB").is_err());
+        assert!(parse_byte_size("16MiBs").is_err());
+    }
+
+    #[test]
+    fn test_deserialize_number_or_string() {
+        #[derive(Deserialize)]
+        struct Sample {
+            #[serde(deserialize_with = "duration_secs::deserialize")]
+            timeout: u64,
+            #[serde(deserialize_with = "byte_size::deserialize")]
+            limit: usize,
+        }
+
+        let sample: Sample =
+            serde_json::from_value(serde_json::json!({ "timeout": "10m", "limit": 1024 })).unwrap();
+        assert_eq!(sample.timeout, 600);
+        assert_eq!(sample.limit, 1024);
+
+        let sample: Sample =
+            serde_json::from_value(serde_json::json!({ "timeout": 30, "limit": "16MiB" })).unwrap();
+        assert_eq!(sample.timeout, 30);
+        assert_eq!(sample.limit, 16 * 1024 * 1024);
+    }
+}
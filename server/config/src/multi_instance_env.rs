@@ -1,6 +1,13 @@
 use crate::{
-    DatabaseConfig, DatabasesInstancesConfig, MongoConfig, MongoInstancesConfig, RedisConfig,
-    RedisInstancesConfig, RedisMode, S3Config, S3InstancesConfig,
+    model::{
+        default_connect_timeout, default_idle_timeout, default_max_connections,
+        default_min_connections, default_pool_size, default_redis_connection_timeout,
+        parse_duration_secs,
+    },
+    DatabaseConfig, DatabaseDriver, DatabasesInstancesConfig, MongoConfig, MongoInstancesConfig,
+    RedisConfig, RedisInstancesConfig, RedisMode, RedisRetryConfig, RedisTlsConfig,
+    ReplicaStrategy, S3Config, S3CredentialSource, S3CredentialsConfig, S3InstancesConfig,
+    SecretString, SslMode,
 };
 use std::env;
 
@@ -45,35 +52,115 @@ impl MultiInstanceEnvProcessor {
                     "{}_DATABASE_INSTANCES_{}_DATABASE_IDLE_TIMEOUT",
                     self.prefix, index
                 );
+                let statement_cache_capacity_key = format!(
+                    "{}_DATABASE_INSTANCES_{}_DATABASE_STATEMENT_CACHE_CAPACITY",
+                    self.prefix, index
+                );
 
                 let max_connections = env::var(&max_connections_key)
-                    .unwrap_or_else(|_| "10".to_string())
-                    .parse::<u32>()
-                    .unwrap_or(10);
+                    .ok()
+                    .and_then(|value| value.parse::<u32>().ok())
+                    .unwrap_or_else(default_max_connections);
 
                 let min_connections = env::var(&min_connections_key)
-                    .unwrap_or_else(|_| "1".to_string())
-                    .parse::<u32>()
-                    .unwrap_or(1);
+                    .ok()
+                    .and_then(|value| value.parse::<u32>().ok())
+                    .unwrap_or_else(default_min_connections);
 
                 let connect_timeout = env::var(&connect_timeout_key)
-                    .unwrap_or_else(|_| "30".to_string())
-                    .parse::<u64>()
-                    .unwrap_or(30);
+                    .ok()
+                    .and_then(|value| parse_duration_secs(&value).ok())
+                    .unwrap_or_else(default_connect_timeout);
 
                 let idle_timeout = env::var(&idle_timeout_key)
-                    .unwrap_or_else(|_| "600".to_string())
-                    .parse::<u64>()
-                    .unwrap_or(600);
+                    .ok()
+                    .and_then(|value| parse_duration_secs(&value).ok())
+                    .unwrap_or_else(default_idle_timeout);
+
+                let statement_cache_capacity = env::var(&statement_cache_capacity_key)
+                    .ok()
+                    .and_then(|capacity| capacity.parse::<usize>().ok());
+
+                let read_only_key = format!(
+                    "{}_DATABASE_INSTANCES_{}_DATABASE_READ_ONLY",
+                    self.prefix, index
+                );
+                let read_only = env::var(&read_only_key)
+                    .ok()
+                    .and_then(|read_only| read_only.parse::<bool>().ok())
+                    .unwrap_or(false);
+
+                let replicas_key = format!(
+                    "{}_DATABASE_INSTANCES_{}_DATABASE_REPLICAS",
+                    self.prefix, index
+                );
+                let replicas = env::var(&replicas_key)
+                    .ok()
+                    .map(|s| {
+                        s.split(',')
+                            .map(str::trim)
+                            .filter(|s| !s.is_empty())
+                            .map(SecretString::from)
+                            .collect()
+                    })
+                    .unwrap_or_default();
+
+                let strategy_key = format!(
+                    "{}_DATABASE_INSTANCES_{}_DATABASE_REPLICA_STRATEGY",
+                    self.prefix, index
+                );
+                let replica_strategy = match env::var(&strategy_key)
+                    .unwrap_or_default()
+                    .to_lowercase()
+                    .as_str()
+                {
+                    "random" => ReplicaStrategy::Random,
+                    _ => ReplicaStrategy::RoundRobin,
+                };
+
+                let driver_key = format!(
+                    "{}_DATABASE_INSTANCES_{}_DATABASE_DRIVER",
+                    self.prefix, index
+                );
+                let driver = env::var(&driver_key).ok().and_then(|driver| {
+                    match driver.to_lowercase().as_str() {
+                        "postgres" | "postgresql" => Some(DatabaseDriver::Postgres),
+                        "mysql" => Some(DatabaseDriver::Mysql),
+                        "sqlite" => Some(DatabaseDriver::Sqlite),
+                        _ => None,
+                    }
+                });
+
+                let ssl_key = |field: &str| {
+                    format!(
+                        "{}_DATABASE_INSTANCES_{}_DATABASE_SSL_{}",
+                        self.prefix, index, field
+                    )
+                };
+                let ssl_mode = env::var(ssl_key("MODE"))
+                    .ok()
+                    .and_then(|mode| SslMode::parse(&mode));
 
                 instances.push(DatabasesInstancesConfig {
                     name,
                     database: DatabaseConfig {
-                        url,
+                        url: url.into(),
                         max_connections,
                         min_connections,
                         connect_timeout,
                         idle_timeout,
+                        statement_cache_capacity,
+                        read_only,
+                        replicas,
+                        replica_strategy,
+                        driver,
+                        postgres: None,
+                        mysql: None,
+                        sqlite: None,
+                        ssl_mode,
+                        ssl_root_cert: env::var(ssl_key("ROOT_CERT")).ok(),
+                        ssl_client_cert: env::var(ssl_key("CLIENT_CERT")).ok(),
+                        ssl_client_key: env::var(ssl_key("CLIENT_KEY")).ok(),
                     },
                 });
 
@@ -105,16 +192,72 @@ impl MultiInstanceEnvProcessor {
                 let url_key = format!("{}_REDIS_INSTANCES_{}_REDIS_URL", self.prefix, index);
                 let urls_key = format!("{}_REDIS_INSTANCES_{}_REDIS_URLS", self.prefix, index);
 
-                let url = env::var(&url_key).ok();
+                let fallback_key = format!("{}_REDIS_INSTANCES_{}_FALLBACK", self.prefix, index);
+
+                let url = env::var(&url_key).ok().map(SecretString::from);
                 let urls = env::var(&urls_key).ok().map(|s| {
                     s.split(',')
-                        .map(|s| s.trim().to_string())
-                        .collect::<Vec<String>>()
+                        .map(|s| SecretString::from(s.trim()))
+                        .collect::<Vec<SecretString>>()
                 });
 
+                let pool_size_key =
+                    format!("{}_REDIS_INSTANCES_{}_REDIS_POOL_SIZE", self.prefix, index);
+                let connection_timeout_key = format!(
+                    "{}_REDIS_INSTANCES_{}_REDIS_CONNECTION_TIMEOUT",
+                    self.prefix, index
+                );
+                let response_timeout_key = format!(
+                    "{}_REDIS_INSTANCES_{}_REDIS_RESPONSE_TIMEOUT",
+                    self.prefix, index
+                );
+
+                let pool_size = env::var(&pool_size_key)
+                    .ok()
+                    .and_then(|value| value.parse::<usize>().ok())
+                    .unwrap_or_else(default_pool_size);
+                let connection_timeout = env::var(&connection_timeout_key)
+                    .ok()
+                    .and_then(|value| parse_duration_secs(&value).ok())
+                    .unwrap_or_else(default_redis_connection_timeout);
+                let response_timeout = env::var(&response_timeout_key)
+                    .ok()
+                    .and_then(|value| parse_duration_secs(&value).ok())
+                    .unwrap_or_default();
+
+                let tls_key = |field: &str| {
+                    format!(
+                        "{}_REDIS_INSTANCES_{}_REDIS_TLS_{}",
+                        self.prefix, index, field
+                    )
+                };
+                let tls_flag = |field: &str| {
+                    env::var(tls_key(field))
+                        .ok()
+                        .and_then(|value| value.parse::<bool>().ok())
+                        .unwrap_or(false)
+                };
+                let tls = RedisTlsConfig {
+                    enabled: tls_flag("ENABLED"),
+                    ca_cert: env::var(tls_key("CA_CERT")).ok(),
+                    client_cert: env::var(tls_key("CLIENT_CERT")).ok(),
+                    client_key: env::var(tls_key("CLIENT_KEY")).ok().map(SecretString::from),
+                    insecure_skip_verify: tls_flag("INSECURE_SKIP_VERIFY"),
+                };
+
                 instances.push(RedisInstancesConfig {
                     name,
-                    redis: RedisConfig { mode, url, urls },
+                    redis: RedisConfig {
+                        mode,
+                        url,
+                        urls,
+                        pool_size,
+                        connection_timeout,
+                        response_timeout,
+                        retry: RedisRetryConfig::default(),
+                        tls,
+                    },
+                    fallback: env::var(&fallback_key).ok(),
                 });
 
                 index += 1;
@@ -136,9 +279,25 @@ impl MultiInstanceEnvProcessor {
             let uri_key = format!("{}_MONGO_INSTANCES_{}_MONGO_URI", self.prefix, index);
 
             if let (Ok(name), Ok(uri)) = (env::var(&name_key), env::var(&uri_key)) {
+                let mongo_key = |field: &str| {
+                    format!("{}_MONGO_INSTANCES_{}_MONGO_{}", self.prefix, index, field)
+                };
+                let duration = |field: &str| {
+                    env::var(mongo_key(field))
+                        .ok()
+                        .and_then(|value| parse_duration_secs(&value).ok())
+                };
+
                 instances.push(MongoInstancesConfig {
                     name,
-                    mongo: MongoConfig { uri },
+                    mongo: MongoConfig {
+                        max_pool_size: parse_env(&mongo_key("MAX_POOL_SIZE")),
+                        min_pool_size: parse_env(&mongo_key("MIN_POOL_SIZE")),
+                        connect_timeout: duration("CONNECT_TIMEOUT"),
+                        server_selection_timeout: duration("SERVER_SELECTION_TIMEOUT"),
+                        default_database: env::var(mongo_key("DEFAULT_DATABASE")).ok(),
+                        ..MongoConfig::from_uri(uri)
+                    },
                 });
 
                 index += 1;
@@ -165,22 +324,49 @@ impl MultiInstanceEnvProcessor {
                 self.prefix, index
             );
 
-            if let (Ok(name), Ok(region), Ok(access_key_id), Ok(secret_access_key)) = (
-                env::var(&name_key),
-                env::var(&region_key),
-                env::var(&access_key_id_key),
-                env::var(&secret_access_key_key),
-            ) {
+            // 使用 IAM 角色等非静态凭证时可以不配置密钥
+            if let (Ok(name), Ok(region)) = (env::var(&name_key), env::var(&region_key)) {
+                let access_key_id = env::var(&access_key_id_key).unwrap_or_default();
+                let secret_access_key = env::var(&secret_access_key_key).unwrap_or_default();
                 let endpoint_key = format!("{}_S3_INSTANCES_{}_S3_ENDPOINT", self.prefix, index);
                 let endpoint = env::var(&endpoint_key).ok();
+                let s3_key =
+                    |field: &str| format!("{}_S3_INSTANCES_{}_S3_{}", self.prefix, index, field);
+                let credentials =
+                    env::var(s3_key("CREDENTIALS_SOURCE"))
+                        .ok()
+                        .map(|source| S3CredentialsConfig {
+                            source: match source.to_lowercase().as_str() {
+                                "env" => S3CredentialSource::Env,
+                                "imds" => S3CredentialSource::Imds,
+                                "web_identity" => S3CredentialSource::WebIdentity,
+                                "profile" => S3CredentialSource::Profile,
+                                _ => S3CredentialSource::Static,
+                            },
+                            profile: env::var(s3_key("CREDENTIALS_PROFILE")).ok(),
+                        });
 
                 instances.push(S3InstancesConfig {
                     name,
                     s3: S3Config {
                         region,
                         access_key_id,
-                        secret_access_key,
+                        secret_access_key: secret_access_key.into(),
+                        credentials,
                         endpoint,
+                        max_attempts: parse_env(&s3_key("MAX_ATTEMPTS")),
+                        connect_timeout: parse_env(&s3_key("CONNECT_TIMEOUT")),
+                        operation_timeout: parse_env(&s3_key("OPERATION_TIMEOUT")),
+                        operation_attempt_timeout: parse_env(&s3_key("OPERATION_ATTEMPT_TIMEOUT")),
+                        accelerate: parse_env(&s3_key("ACCELERATE")).unwrap_or(false),
+                        multipart_chunk_size: parse_env(&s3_key("MULTIPART_CHUNK_SIZE")),
+                        multipart_concurrency: parse_env(&s3_key("MULTIPART_CONCURRENCY")),
+                        bucket: env::var(s3_key("BUCKET")).ok(),
+                        force_path_style: parse_env(&s3_key("FORCE_PATH_STYLE")).unwrap_or(false),
+                        presign_expiry: env::var(s3_key("PRESIGN_EXPIRY"))
+                            .ok()
+                            .and_then(|value| parse_duration_secs(&value).ok()),
+                        public_base_url: env::var(s3_key("PUBLIC_BASE_URL")).ok(),
                     },
                 });
 
@@ -254,6 +440,11 @@ impl MultiInstanceEnvProcessor {
     }
 }
 
+/// 读取并解析可选的环境变量，不存在或解析失败时返回 None
+fn parse_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+    env::var(key).ok().and_then(|value| value.parse().ok())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,14 +473,14 @@ mod tests {
         assert_eq!(instances.len(), 2);
         assert_eq!(instances[0].name, "test_db");
         assert_eq!(
-            instances[0].database.url,
+            instances[0].database.url.expose_secret(),
             "postgres://test@localhost:5432/test"
         );
         assert_eq!(instances[0].database.max_connections, 5);
 
         assert_eq!(instances[1].name, "analytics_db");
         assert_eq!(
-            instances[1].database.url,
+            instances[1].database.url.expose_secret(),
             "postgres://analytics@localhost:5432/analytics"
         );
         assert_eq!(instances[1].database.max_connections, 10);
@@ -327,14 +518,14 @@ mod tests {
         assert_eq!(instances[0].name, "cache");
         assert_eq!(instances[0].redis.mode, RedisMode::Single);
         assert_eq!(
-            instances[0].redis.url,
+            instances[0].redis.get_url(),
             Some("redis://localhost:6379/0".to_string())
         );
 
         assert_eq!(instances[1].name, "cluster_cache");
         assert_eq!(instances[1].redis.mode, RedisMode::Cluster);
         assert_eq!(
-            instances[1].redis.urls,
+            instances[1].redis.get_urls(),
             Some(vec![
                 "redis://host1:7001".to_string(),
                 "redis://host2:7002".to_string()
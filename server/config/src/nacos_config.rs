@@ -0,0 +1,315 @@
+use std::time::{Duration, Instant};
+
+use config::{File, FileFormat};
+use md5::{Digest, Md5};
+use tokio::task::JoinHandle;
+
+use crate::{
+    config_init::{apply_remote_config, load_layered_config, ConfigError},
+    model::Config,
+    project_error, project_info,
+};
+
+/// 未指定分组时使用的 Nacos 默认分组
+pub const NACOS_DEFAULT_GROUP: &str = "DEFAULT_GROUP";
+
+/// 长轮询的挂起时间（毫秒），与 Nacos 客户端默认值一致
+const LONG_POLLING_TIMEOUT_MS: u64 = 30_000;
+
+/// 长轮询失败后重试的间隔
+const WATCH_RETRY_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Nacos 连接设置
+///
+/// 通过环境变量启用：
+/// - NACOS_SERVER_ADDR: Nacos 地址，如 `http://nacos:8848`，未带协议时使用 http
+/// - NACOS_DATA_ID: 配置的 Data ID，如 `soybean-admin.yaml`
+/// - NACOS_GROUP: 配置分组（可选，默认为 `DEFAULT_GROUP`）
+/// - NACOS_NAMESPACE: 命名空间 ID（可选）
+/// - NACOS_USERNAME / NACOS_PASSWORD: 开启鉴权时的用户名和密码（可选）
+#[derive(Debug, Clone)]
+pub struct NacosSettings {
+    pub server_addr: String,
+    pub data_id: String,
+    pub group: String,
+    pub namespace: Option<String>,
+    pub username: Option<String>,
+    pub password: Option<String>,
+}
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+impl NacosSettings {
+    /// 命名空间和鉴权信息取自环境变量
+    pub fn new(server_addr: &str, data_id: &str, group: &str) -> Self {
+        let server_addr = server_addr.trim_end_matches('/');
+        let server_addr = if server_addr.contains("://") {
+            server_addr.to_string()
+        } else {
+            format!("http://{}", server_addr)
+        };
+        let group = if group.is_empty() {
+            NACOS_DEFAULT_GROUP
+        } else {
+            group
+        };
+
+        Self {
+            server_addr,
+            data_id: data_id.to_string(),
+            group: group.to_string(),
+            namespace: env_var("NACOS_NAMESPACE"),
+            username: env_var("NACOS_USERNAME"),
+            password: env_var("NACOS_PASSWORD"),
+        }
+    }
+
+    /// 从环境变量读取，未设置 `NACOS_SERVER_ADDR` 或 `NACOS_DATA_ID` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(
+            &env_var("NACOS_SERVER_ADDR")?,
+            &env_var("NACOS_DATA_ID")?,
+            &env_var("NACOS_GROUP").unwrap_or_default(),
+        ))
+    }
+
+    /// 按 Data ID 的扩展名确定格式，未知扩展名按 YAML 解析
+    fn format(&self) -> FileFormat {
+        match self.data_id.rsplit_once('.').map(|(_, ext)| ext) {
+            Some("json") => FileFormat::Json,
+            Some("toml") => FileFormat::Toml,
+            _ => FileFormat::Yaml,
+        }
+    }
+
+    fn source(&self) -> String {
+        format!("nacos:{}@{}", self.data_id, self.group)
+    }
+}
+
+/// 从 Nacos 配置中心加载并订阅配置
+///
+/// 通过 Nacos Open API 读取配置内容，作为完整的配置文档加载，结果仍可被 Vault 和环境变量覆盖。
+/// 订阅使用与 Nacos 客户端相同的长轮询，服务端推送变更后重新加载，校验通过才替换全局配置
+///
+/// # 示例
+/// ```rust,no_run
+/// use server_config::{NacosConfigProvider, NacosSettings};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let settings = NacosSettings::new("127.0.0.1:8848", "soybean-admin.yaml", "DEFAULT_GROUP");
+///     let mut provider = NacosConfigProvider::new(settings, "APP")?;
+///     let config = provider.load().await?;
+///     provider.subscribe();
+///     Ok(())
+/// }
+/// ```
+pub struct NacosConfigProvider {
+    settings: NacosSettings,
+    env_prefix: String,
+    client: reqwest::Client,
+    /// 访问令牌及其过期时间
+    token: Option<(String, Instant)>,
+    /// 最近一次读取的配置内容的 MD5，长轮询时用于比对
+    content_md5: String,
+}
+
+impl NacosConfigProvider {
+    pub fn new(settings: NacosSettings, env_prefix: &str) -> Result<Self, ConfigError> {
+        let client = reqwest::Client::builder()
+            .connect_timeout(Duration::from_secs(10))
+            .build()
+            .map_err(|e| ConfigError::NacosError(e.to_string()))?;
+
+        Ok(Self {
+            settings,
+            env_prefix: env_prefix.to_string(),
+            client,
+            token: None,
+            content_md5: String::new(),
+        })
+    }
+
+    /// 读取配置内容并加载配置
+    pub async fn load(&mut self) -> Result<Config, ConfigError> {
+        let content = self.fetch().await.map_err(|e| {
+            project_error!("Failed to load config from nacos: {}", e);
+            e
+        })?;
+        self.content_md5 = hex::encode(Md5::digest(content.as_bytes()));
+        project_info!(
+            "Loaded config {} from nacos (md5 {})",
+            self.settings.source(),
+            self.content_md5
+        );
+
+        let source = File::from_str(&content, self.settings.format());
+        load_layered_config(None, None, Some(&self.env_prefix), Some(Box::new(source))).await
+    }
+
+    /// 在后台订阅配置变更，推送后重新加载并替换全局配置
+    pub fn subscribe(mut self) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            loop {
+                match self.poll_changes().await {
+                    Ok(false) => {},
+                    Ok(true) => match self.load().await {
+                        Ok(config) => {
+                            apply_remote_config(config, &self.settings.source()).await;
+                        },
+                        Err(e) => {
+                            project_error!("Failed to reload config from nacos: {}", e);
+                            tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                        },
+                    },
+                    Err(e) => {
+                        project_error!("Nacos config subscription interrupted: {}", e);
+                        tokio::time::sleep(WATCH_RETRY_INTERVAL).await;
+                    },
+                }
+            }
+        })
+    }
+
+    async fn fetch(&mut self) -> Result<String, ConfigError> {
+        let mut query = self.query().await?;
+        query.push(("dataId", self.settings.data_id.clone()));
+        query.push(("group", self.settings.group.clone()));
+
+        let response = self
+            .client
+            .get(format!("{}/nacos/v1/cs/configs", self.settings.server_addr))
+            .query(&query)
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ConfigError::NacosError(format!(
+                "reading {} returned {}",
+                self.settings.source(),
+                status
+            )));
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("invalid response: {}", e)))
+    }
+
+    /// 长轮询监听配置，服务端在挂起时间内发现内容变化时返回 `true`
+    async fn poll_changes(&mut self) -> Result<bool, ConfigError> {
+        // 格式：dataId^2group^2md5^2tenant^1，未使用命名空间时省略 tenant
+        let mut listening = format!(
+            "{}\u{2}{}\u{2}{}",
+            self.settings.data_id, self.settings.group, self.content_md5
+        );
+        if let Some(namespace) = &self.settings.namespace {
+            listening.push('\u{2}');
+            listening.push_str(namespace);
+        }
+        listening.push('\u{1}');
+
+        let query = self.query().await?;
+        let response = self
+            .client
+            .post(format!(
+                "{}/nacos/v1/cs/configs/listener",
+                self.settings.server_addr
+            ))
+            .query(&query)
+            .header("Long-Pulling-Timeout", LONG_POLLING_TIMEOUT_MS.to_string())
+            .form(&[("Listening-Configs", listening)])
+            .timeout(Duration::from_millis(LONG_POLLING_TIMEOUT_MS + 10_000))
+            .send()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("listener request failed: {}", e)))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ConfigError::NacosError(format!(
+                "listening {} returned {}",
+                self.settings.source(),
+                status
+            )));
+        }
+        let changed = response
+            .text()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("invalid response: {}", e)))?;
+        Ok(!changed.trim().is_empty())
+    }
+
+    /// 命名空间和访问令牌查询参数
+    async fn query(&mut self) -> Result<Vec<(&'static str, String)>, ConfigError> {
+        let mut query = Vec::new();
+        if let Some(namespace) = &self.settings.namespace {
+            query.push(("tenant", namespace.clone()));
+        }
+        if let Some(token) = self.access_token().await? {
+            query.push(("accessToken", token));
+        }
+        Ok(query)
+    }
+
+    /// 开启鉴权时登录获取令牌，令牌在过期前复用
+    async fn access_token(&mut self) -> Result<Option<String>, ConfigError> {
+        let (Some(username), Some(password)) = (&self.settings.username, &self.settings.password)
+        else {
+            return Ok(None);
+        };
+        if let Some((token, expires_at)) = &self.token {
+            if Instant::now() < *expires_at {
+                return Ok(Some(token.clone()));
+            }
+        }
+
+        let body: serde_json::Value = self
+            .client
+            .post(format!("{}/nacos/v1/auth/login", self.settings.server_addr))
+            .form(&[("username", username), ("password", password)])
+            .timeout(Duration::from_secs(10))
+            .send()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("login failed: {}", e)))?
+            .json()
+            .await
+            .map_err(|e| ConfigError::NacosError(format!("invalid login response: {}", e)))?;
+        let token = body
+            .get("accessToken")
+            .and_then(|token| token.as_str())
+            .ok_or_else(|| ConfigError::NacosError("login returned no access token".to_string()))?
+            .to_string();
+        // 提前一分钟刷新
+        let ttl = body
+            .get("tokenTtl")
+            .and_then(|ttl| ttl.as_u64())
+            .unwrap_or(18_000)
+            .saturating_sub(60);
+        self.token = Some((token.clone(), Instant::now() + Duration::from_secs(ttl)));
+        Ok(Some(token))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nacos_settings_defaults() {
+        let settings = NacosSettings::new("nacos:8848/", "soybean-admin.json", "");
+        assert_eq!(settings.server_addr, "http://nacos:8848");
+        assert_eq!(settings.group, NACOS_DEFAULT_GROUP);
+        assert_eq!(settings.format(), FileFormat::Json);
+
+        let settings = NacosSettings::new("https://nacos", "soybean-admin", "SOYBEAN");
+        assert_eq!(settings.server_addr, "https://nacos");
+        assert_eq!(settings.format(), FileFormat::Yaml);
+    }
+}
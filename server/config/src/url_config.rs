@@ -0,0 +1,333 @@
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use config::{File, FileFormat};
+use md5::{Digest, Md5};
+use reqwest::{header, StatusCode};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config_init::{load_layered_config, ConfigError},
+    model::Config,
+    project_error, project_info,
+};
+
+/// 默认重试次数，不含首次请求
+const DEFAULT_RETRIES: u32 = 3;
+
+/// 默认重试间隔，每次重试翻倍
+const DEFAULT_RETRY_INTERVAL: Duration = Duration::from_secs(2);
+
+/// 单次请求的超时
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(15);
+
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty())
+}
+
+/// 远程配置文件的访问设置
+///
+/// `new` 从环境变量读取可选项：
+/// - CONFIG_URL_TOKEN: Bearer 令牌（可选）
+/// - CONFIG_URL_RETRIES: 请求失败后的重试次数（可选，默认为 3）
+/// - CONFIG_URL_RETRY_INTERVAL_MS: 首次重试的间隔毫秒数，之后每次翻倍（可选，默认为 2000）
+/// - CONFIG_URL_CACHE_DIR: 缓存目录（可选，默认为系统临时目录下的 `soybean-admin-config`）
+#[derive(Debug, Clone)]
+pub struct UrlSettings {
+    pub url: String,
+    pub bearer_token: Option<String>,
+    pub retries: u32,
+    pub retry_interval: Duration,
+    pub cache_dir: PathBuf,
+}
+
+impl UrlSettings {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            bearer_token: env_var("CONFIG_URL_TOKEN"),
+            retries: env_var("CONFIG_URL_RETRIES")
+                .and_then(|value| value.parse().ok())
+                .unwrap_or(DEFAULT_RETRIES),
+            retry_interval: env_var("CONFIG_URL_RETRY_INTERVAL_MS")
+                .and_then(|value| value.parse().ok())
+                .map(Duration::from_millis)
+                .unwrap_or(DEFAULT_RETRY_INTERVAL),
+            cache_dir: env_var("CONFIG_URL_CACHE_DIR")
+                .map(PathBuf::from)
+                .unwrap_or_else(|| std::env::temp_dir().join("soybean-admin-config")),
+        }
+    }
+
+    /// 从环境变量读取，未设置 `CONFIG_URL` 时返回 `None`
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(&env_var("CONFIG_URL")?))
+    }
+
+    pub fn with_bearer_token(mut self, token: impl Into<String>) -> Self {
+        self.bearer_token = Some(token.into());
+        self
+    }
+
+    pub fn with_retry(mut self, retries: u32, interval: Duration) -> Self {
+        self.retries = retries;
+        self.retry_interval = interval;
+        self
+    }
+
+    pub fn with_cache_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = dir.into();
+        self
+    }
+
+    /// 缓存文件路径，按 URL 的 MD5 命名，避免令牌等信息出现在文件名中
+    fn cache_path(&self) -> PathBuf {
+        let digest = hex::encode(Md5::digest(self.url.as_bytes()));
+        self.cache_dir.join(format!("{}.json", digest))
+    }
+}
+
+/// 按 URL 路径的扩展名确定格式，没有扩展名时参考 Content-Type，都无法判断时按 YAML 解析
+fn config_format(url: &str, content_type: Option<&str>) -> FileFormat {
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let extension = path
+        .rsplit('/')
+        .next()
+        .and_then(|name| name.rsplit_once('.'))
+        .map(|(_, ext)| ext.to_ascii_lowercase());
+    match extension.as_deref() {
+        Some("json") => return FileFormat::Json,
+        Some("toml") => return FileFormat::Toml,
+        Some("yaml" | "yml") => return FileFormat::Yaml,
+        _ => {},
+    }
+    match content_type {
+        Some(content_type) if content_type.contains("json") => FileFormat::Json,
+        Some(content_type) if content_type.contains("toml") => FileFormat::Toml,
+        _ => FileFormat::Yaml,
+    }
+}
+
+/// 上次成功读取的配置内容，用于条件请求
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct CachedConfig {
+    etag: Option<String>,
+    content_type: Option<String>,
+    content: String,
+}
+
+impl CachedConfig {
+    async fn read(path: &Path) -> Option<Self> {
+        let content = tokio::fs::read(path).await.ok()?;
+        serde_json::from_slice(&content).ok()
+    }
+
+    async fn write(&self, path: &Path) {
+        let result = async {
+            if let Some(dir) = path.parent() {
+                tokio::fs::create_dir_all(dir).await?;
+            }
+            tokio::fs::write(path, serde_json::to_vec(self)?).await?;
+            // 配置中可能有数据库密码等密钥，只允许当前用户读取
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+                tokio::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).await?;
+            }
+            Ok::<_, std::io::Error>(())
+        }
+        .await;
+        if let Err(e) = result {
+            tracing::warn!("Failed to cache config at {}: {}", path.display(), e);
+        }
+    }
+}
+
+/// 从 HTTP(S) 地址加载配置文件
+///
+/// 响应作为完整的配置文档加载，结果仍可被 Vault、环境变量和命令行参数覆盖。
+/// 成功读取的内容连同 `ETag` 缓存到本地，下次启动时发送 `If-None-Match`，服务端返回 304 时使用缓存。
+/// 网络错误、超时和 5xx 按设置重试，重试耗尽后有缓存时使用缓存并记录警告，4xx 直接失败
+///
+/// # 示例
+/// ```rust,no_run
+/// use server_config::{UrlConfigLoader, UrlSettings};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let settings = UrlSettings::new("https://config.internal/app.yaml").with_bearer_token("token");
+///     let config = UrlConfigLoader::new(settings, "APP")?.load().await?;
+///     Ok(())
+/// }
+/// ```
+pub struct UrlConfigLoader {
+    settings: UrlSettings,
+    env_prefix: String,
+    client: reqwest::Client,
+}
+
+impl UrlConfigLoader {
+    pub fn new(settings: UrlSettings, env_prefix: &str) -> Result<Self, ConfigError> {
+        let client = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .build()
+            .map_err(|e| ConfigError::UrlError(e.to_string()))?;
+
+        Ok(Self {
+            settings,
+            env_prefix: env_prefix.to_string(),
+            client,
+        })
+    }
+
+    /// 读取配置内容并加载配置
+    pub async fn load(&self) -> Result<Config, ConfigError> {
+        let cache_path = self.settings.cache_path();
+        let cached = CachedConfig::read(&cache_path).await;
+
+        let document = match (self.fetch(cached.as_ref()).await, cached) {
+            (Ok(Some(document)), _) => {
+                document.write(&cache_path).await;
+                document
+            },
+            (Ok(None), Some(cached)) => {
+                project_info!("Config at {} not modified, using cache", self.settings.url);
+                cached
+            },
+            (Err(FetchError::Retryable(e)), Some(cached)) => {
+                tracing::warn!(
+                    "Failed to load config from {}, using cached copy: {}",
+                    self.settings.url,
+                    e
+                );
+                cached
+            },
+            (Ok(None), None) => {
+                return Err(ConfigError::UrlError(
+                    "server returned 304 without a cached copy".to_string(),
+                ));
+            },
+            (Err(FetchError::Retryable(e) | FetchError::Fatal(e)), _) => {
+                project_error!("Failed to load config from {}: {}", self.settings.url, e);
+                return Err(ConfigError::UrlError(e));
+            },
+        };
+
+        let format = config_format(&self.settings.url, document.content_type.as_deref());
+        let source = File::from_str(&document.content, format);
+        load_layered_config(None, None, Some(&self.env_prefix), Some(Box::new(source))).await
+    }
+
+    /// 按重试设置请求配置，返回 `None` 表示服务端确认缓存仍然有效
+    async fn fetch(
+        &self,
+        cached: Option<&CachedConfig>,
+    ) -> Result<Option<CachedConfig>, FetchError> {
+        let etag = cached.and_then(|cached| cached.etag.as_deref());
+        let mut interval = self.settings.retry_interval;
+        let mut attempt = 0;
+        loop {
+            match self.fetch_once(etag).await {
+                Err(FetchError::Retryable(e)) if attempt < self.settings.retries => {
+                    attempt += 1;
+                    tracing::warn!(
+                        "Loading config from {} failed ({}), retry {}/{} in {:?}",
+                        self.settings.url,
+                        e,
+                        attempt,
+                        self.settings.retries,
+                        interval
+                    );
+                    tokio::time::sleep(interval).await;
+                    interval *= 2;
+                },
+                result => return result,
+            }
+        }
+    }
+
+    async fn fetch_once(&self, etag: Option<&str>) -> Result<Option<CachedConfig>, FetchError> {
+        let mut request = self.client.get(&self.settings.url);
+        if let Some(token) = &self.settings.bearer_token {
+            request = request.bearer_auth(token);
+        }
+        if let Some(etag) = etag {
+            request = request.header(header::IF_NONE_MATCH, etag);
+        }
+
+        let response = request
+            .send()
+            .await
+            .map_err(|e| FetchError::Retryable(format!("request failed: {}", e)))?;
+        let status = response.status();
+        if status == StatusCode::NOT_MODIFIED && etag.is_some() {
+            return Ok(None);
+        }
+        if status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS {
+            return Err(FetchError::Retryable(format!("server returned {}", status)));
+        }
+        if !status.is_success() {
+            return Err(FetchError::Fatal(format!("server returned {}", status)));
+        }
+
+        let header_value = |name: header::HeaderName| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+        let etag = header_value(header::ETAG);
+        let content_type = header_value(header::CONTENT_TYPE);
+        let content = response
+            .text()
+            .await
+            .map_err(|e| FetchError::Retryable(format!("reading body failed: {}", e)))?;
+
+        Ok(Some(CachedConfig {
+            etag,
+            content_type,
+            content,
+        }))
+    }
+}
+
+enum FetchError {
+    /// 网络错误、超时、5xx 和 429，可以重试
+    Retryable(String),
+    /// 其他非成功状态，如 401、404
+    Fatal(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_format() {
+        assert_eq!(
+            config_format("https://config.internal/app.json?v=2", None),
+            FileFormat::Json
+        );
+        assert_eq!(
+            config_format("https://config.internal/app.yml", Some("application/json")),
+            FileFormat::Yaml
+        );
+        assert_eq!(
+            config_format(
+                "https://config.internal/v1/config",
+                Some("application/toml")
+            ),
+            FileFormat::Toml
+        );
+        assert_eq!(
+            config_format("https://config.internal/v1/config", None),
+            FileFormat::Yaml
+        );
+    }
+}
@@ -32,4 +32,8 @@ pub enum SystemEvent {
     AuditOperationLoggedEvent,
     /// API密钥验证事件
     AuthApiKeyValidatedEvent,
+    /// 安全信号事件（登录失败、权限提升、批量导出）
+    SecuritySignalEvent,
+    /// API 调用统计事件
+    ApiUsageEvent,
 }
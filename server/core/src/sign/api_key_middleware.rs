@@ -87,6 +87,14 @@ pub enum ApiKeyValidation {
     Complex(ComplexApiKeyValidator, ComplexApiKeyConfig),
 }
 
+/// Identity of the API key caller.
+///
+/// Inserted into request extensions after successful validation. Holds the
+/// AccessKeyId for signed requests and a masked key for simple keys, so it can
+/// be used in statistics without exposing the secret.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ApiKeyIdentity(pub String);
+
 /// Add a path to protected routes requiring API key validation.
 ///
 /// This function adds a path to the set of protected paths.
@@ -116,7 +124,7 @@ fn is_protected_path(uri: &Uri) -> bool {
 #[inline]
 pub async fn api_key_middleware(
     validator: ApiKeyValidation,
-    req: Request<Body>,
+    mut req: Request<Body>,
     next: Next,
 ) -> impl IntoResponse {
     if !is_protected_path(req.uri()) {
@@ -124,8 +132,11 @@ pub async fn api_key_middleware(
     }
 
     match validate_request(&validator, &req) {
-        Ok(true) => next.run(req).await.into_response(),
-        Ok(false) => Res::<()>::new_error(
+        Ok(Some(identity)) => {
+            req.extensions_mut().insert(identity);
+            next.run(req).await.into_response()
+        },
+        Ok(None) => Res::<()>::new_error(
             StatusCode::UNAUTHORIZED.as_u16(),
             "Invalid API key or signature",
         )
@@ -153,14 +164,27 @@ fn get_query_value<'a>(params: &'a [(String, String)], name: &str) -> Option<&'a
         .map(|(_, v)| v.as_str())
 }
 
+/// Mask a simple API key, keeping only the first and last four characters.
+#[inline]
+fn mask_key(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    if chars.len() <= 8 {
+        return "****".to_string();
+    }
+    let head: String = chars[..4].iter().collect();
+    let tail: String = chars[chars.len() - 4..].iter().collect();
+    format!("{}****{}", head, tail)
+}
+
 /// Validate API key in request.
 ///
-/// This function validates the API key in the given request.
+/// This function validates the API key in the given request and returns the
+/// caller identity when it is valid.
 #[inline]
 fn validate_request(
     validator: &ApiKeyValidation,
     req: &Request<Body>,
-) -> Result<bool, &'static str> {
+) -> Result<Option<ApiKeyIdentity>, &'static str> {
     let headers = req.headers();
     let query = req.uri().query().unwrap_or("");
     let params = if !query.is_empty() {
@@ -183,7 +207,9 @@ fn validate_request(
                     api_key: api_key.to_owned(),
                 }),
             );
-            Ok(validator.validate_key(api_key))
+            Ok(validator
+                .validate_key(api_key)
+                .then(|| ApiKeyIdentity(mask_key(api_key))))
         },
         ApiKeyValidation::Complex(validator, config) => {
             let api_key =
@@ -211,13 +237,9 @@ fn validate_request(
                     api_key: api_key.to_owned(),
                 }),
             );
-            Ok(validator.validate_signature(
-                api_key,
-                &params_for_signing,
-                signature,
-                timestamp,
-                nonce,
-            ))
+            Ok(validator
+                .validate_signature(api_key, &params_for_signing, signature, timestamp, nonce)
+                .then(|| ApiKeyIdentity(api_key.to_owned())))
         },
     }
 }
@@ -257,7 +279,7 @@ mod tests {
             .as_millis() as i64;
         let nonce = format!("nonce_{}", timestamp);
 
-        let mut params = vec![
+        let mut params = [
             ("AccessKeyId".to_string(), "test-access-key".to_string()),
             ("param1".to_string(), "value1".to_string()),
             ("param2".to_string(), "value2".to_string()),
@@ -280,4 +302,10 @@ mod tests {
             signing_string, signature
         );
     }
+
+    #[test]
+    fn test_mask_key() {
+        assert_eq!(mask_key("test-api-key"), "test****-key");
+        assert_eq!(mask_key("short"), "****");
+    }
 }
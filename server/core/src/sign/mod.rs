@@ -8,8 +8,8 @@ pub use api_key::{
     ApiKeyConfig, ComplexApiKeyValidator, SignatureAlgorithm, SimpleApiKeyValidator,
 };
 pub use api_key_middleware::{
-    api_key_middleware, protect_route, ApiKeySource, ApiKeyValidation, ComplexApiKeyConfig,
-    SimpleApiKeyConfig,
+    api_key_middleware, protect_route, ApiKeyIdentity, ApiKeySource, ApiKeyValidation,
+    ComplexApiKeyConfig, SimpleApiKeyConfig,
 };
 pub use memory_nonce_store::{create_memory_nonce_store_factory, MemoryNonceStore};
 pub use nonce_store::{NonceStore, NonceStoreFactory};
@@ -24,10 +24,12 @@ pub enum ValidatorType {
     Complex,
 }
 
-static API_KEY_VALIDATORS: Lazy<(
+type ApiKeyValidators = (
     Arc<RwLock<SimpleApiKeyValidator>>,
     Arc<RwLock<ComplexApiKeyValidator>>,
-)> = Lazy::new(|| {
+);
+
+static API_KEY_VALIDATORS: Lazy<ApiKeyValidators> = Lazy::new(|| {
     (
         Arc::new(RwLock::new(SimpleApiKeyValidator::new())),
         Arc::new(RwLock::new(ComplexApiKeyValidator::new(None))),
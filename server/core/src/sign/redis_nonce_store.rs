@@ -46,7 +46,7 @@ impl RedisNonceStore {
 
         match redis_connection {
             RedisConnection::Single(client) => {
-                if let Ok(mut conn) = client.get_multiplexed_async_connection().await {
+                if let Ok(mut conn) = client.get_connection().await {
                     let result: Option<bool> = redis::cmd("SET")
                         .arg(&key)
                         .arg("1")
@@ -63,7 +63,7 @@ impl RedisNonceStore {
                 }
             },
             RedisConnection::Cluster(client) => {
-                if let Ok(mut conn) = client.get_async_connection().await {
+                if let Ok(mut conn) = client.get_connection().await {
                     let result: Option<bool> = redis::cmd("SET")
                         .arg(&key)
                         .arg("1")
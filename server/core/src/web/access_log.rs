@@ -0,0 +1,429 @@
+use std::{
+    fs::{self, File, OpenOptions},
+    io::{self, BufReader, BufWriter, Stdout, Write},
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        mpsc::{self, Receiver, RecvTimeoutError, SyncSender, TrySendError},
+        Arc,
+    },
+    thread,
+    time::{Duration, Instant},
+};
+
+use axum::{body::Body, extract::ConnectInfo, middleware::Next, response::Response};
+use chrono::{DateTime, Local};
+use http::{header, HeaderMap, Request};
+use serde_json::json;
+use server_config::{AccessLogConfig, AccessLogFormat, AccessLogRotation};
+use server_utils::GzipUtil;
+
+use crate::web::util::ClientIp;
+
+/// 待写入行数的上限，写入线程跟不上时丢弃新的访问日志而不是阻塞请求
+const QUEUE_CAPACITY: usize = 8192;
+
+/// 没有新日志时刷新缓冲区的间隔
+const FLUSH_INTERVAL: Duration = Duration::from_secs(1);
+
+/// 一次请求的访问记录
+struct AccessLogEntry {
+    time: DateTime<Local>,
+    remote_addr: String,
+    method: String,
+    uri: String,
+    protocol: String,
+    status: u16,
+    bytes: Option<u64>,
+    referer: Option<String>,
+    user_agent: Option<String>,
+    duration: Duration,
+}
+
+/// combined 格式中引号内的字段需要转义引号和反斜杠
+fn quoted(value: Option<&str>) -> String {
+    match value {
+        Some(value) => value.replace('\\', "\\\\").replace('"', "\\\""),
+        None => "-".to_string(),
+    }
+}
+
+impl AccessLogEntry {
+    fn format(&self, format: AccessLogFormat) -> String {
+        match format {
+            AccessLogFormat::Combined => format!(
+                "{} - - [{}] \"{} {} {}\" {} {} \"{}\" \"{}\"",
+                self.remote_addr,
+                self.time.format("%d/%b/%Y:%H:%M:%S %z"),
+                self.method,
+                quoted(Some(&self.uri)),
+                self.protocol,
+                self.status,
+                self.bytes
+                    .map(|bytes| bytes.to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+                quoted(self.referer.as_deref()),
+                quoted(self.user_agent.as_deref()),
+            ),
+            AccessLogFormat::Json => json!({
+                "time": self.time.to_rfc3339(),
+                "remoteAddr": self.remote_addr,
+                "method": self.method,
+                "uri": self.uri,
+                "protocol": self.protocol,
+                "status": self.status,
+                "bytes": self.bytes,
+                "referer": self.referer,
+                "userAgent": self.user_agent,
+                "durationMs": self.duration.as_secs_f64() * 1000.0,
+            })
+            .to_string(),
+        }
+    }
+}
+
+/// 访问日志写入器，格式化后的日志交给独立线程写入，不阻塞请求处理
+pub struct AccessLogger {
+    format: AccessLogFormat,
+    sender: SyncSender<String>,
+    dropped: AtomicU64,
+}
+
+impl AccessLogger {
+    /// 打开输出并启动写入线程，文件无法打开时返回错误
+    pub fn start(config: AccessLogConfig) -> io::Result<Self> {
+        let sink = if config.is_stdout() {
+            Sink::Stdout(BufWriter::new(io::stdout()))
+        } else {
+            Sink::File(RotatingFile::open(&config)?)
+        };
+        let (sender, receiver) = mpsc::sync_channel(QUEUE_CAPACITY);
+        thread::Builder::new()
+            .name("access-log".to_string())
+            .spawn(move || write_loop(sink, receiver))?;
+
+        Ok(Self {
+            format: config.format,
+            sender,
+            dropped: AtomicU64::new(0),
+        })
+    }
+
+    fn log(&self, entry: &AccessLogEntry) {
+        match self.sender.try_send(entry.format(self.format)) {
+            Ok(()) => {},
+            Err(TrySendError::Full(_)) => {
+                let dropped = self.dropped.fetch_add(1, Ordering::Relaxed) + 1;
+                // 只在丢弃数达到 2 的幂时记录，避免积压时刷屏
+                if dropped.is_power_of_two() {
+                    tracing::warn!(dropped, "Access log queue full, dropping entries");
+                }
+                tracing::info!(target: "metrics", event = "access_log_dropped");
+            },
+            Err(TrySendError::Disconnected(_)) => {
+                tracing::error!("Access log writer stopped, dropping entry");
+            },
+        }
+    }
+}
+
+/// 访问日志中间件，记录每个请求的客户端地址、请求行、状态码、响应大小和耗时
+///
+/// 位于全部中间件的最外层，被限流或拒绝的请求同样会记录
+pub async fn access_log_middleware(
+    logger: Arc<AccessLogger>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let started = Instant::now();
+    let time = Local::now();
+    let headers = req.headers();
+    let mut remote_addr = ClientIp::get_real_ip(headers);
+    if remote_addr == "unknown" {
+        if let Some(ConnectInfo(addr)) = req.extensions().get::<ConnectInfo<SocketAddr>>() {
+            remote_addr = addr.ip().to_string();
+        }
+    }
+    let header_value = |headers: &HeaderMap, name| {
+        headers
+            .get(name)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string)
+    };
+    let referer = header_value(headers, header::REFERER);
+    let user_agent = header_value(headers, header::USER_AGENT);
+    let method = req.method().to_string();
+    let uri = req
+        .uri()
+        .path_and_query()
+        .map(|path_and_query| path_and_query.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let protocol = format!("{:?}", req.version());
+
+    let response = next.run(req).await;
+
+    logger.log(&AccessLogEntry {
+        time,
+        remote_addr,
+        method,
+        uri,
+        protocol,
+        status: response.status().as_u16(),
+        bytes: header_value(response.headers(), header::CONTENT_LENGTH)
+            .and_then(|length| length.parse().ok()),
+        referer,
+        user_agent,
+        duration: started.elapsed(),
+    });
+    response
+}
+
+enum Sink {
+    Stdout(BufWriter<Stdout>),
+    File(RotatingFile),
+}
+
+impl Sink {
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        match self {
+            Sink::Stdout(writer) => writeln!(writer, "{}", line),
+            Sink::File(file) => file.write_line(line),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Sink::Stdout(writer) => writer.flush(),
+            Sink::File(file) => file.writer.flush(),
+        }
+    }
+}
+
+fn write_loop(mut sink: Sink, receiver: Receiver<String>) {
+    loop {
+        let result = match receiver.recv_timeout(FLUSH_INTERVAL) {
+            Ok(line) => sink.write_line(&line),
+            Err(RecvTimeoutError::Timeout) => sink.flush(),
+            Err(RecvTimeoutError::Disconnected) => {
+                let _ = sink.flush();
+                return;
+            },
+        };
+        if let Err(e) = result {
+            tracing::error!(error = %e, "Failed to write access log");
+        }
+    }
+}
+
+/// 轮转周期的标识，周期内写入的文件标识相同
+fn period_key(time: DateTime<Local>, rotation: AccessLogRotation) -> String {
+    match rotation {
+        AccessLogRotation::Hourly => time.format("%Y%m%d%H").to_string(),
+        AccessLogRotation::Daily => time.format("%Y%m%d").to_string(),
+        AccessLogRotation::Never => String::new(),
+    }
+}
+
+/// 按时间或大小轮转的日志文件
+///
+/// 轮转时将当前文件重命名为 `<output>.<时间戳>`，需要压缩时在后台线程压缩为 `.gz`
+struct RotatingFile {
+    path: PathBuf,
+    writer: BufWriter<File>,
+    size: u64,
+    period: String,
+    rotation: AccessLogRotation,
+    max_size: u64,
+    max_files: usize,
+    compress: bool,
+}
+
+impl RotatingFile {
+    fn open(config: &AccessLogConfig) -> io::Result<Self> {
+        let path = PathBuf::from(config.output.trim());
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)?;
+        }
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let metadata = file.metadata()?;
+        // 沿用已有文件时按其修改时间确定所属周期，重启后跨周期的文件会在第一次写入时轮转
+        let modified = metadata
+            .modified()
+            .map(DateTime::<Local>::from)
+            .unwrap_or_else(|_| Local::now());
+
+        Ok(Self {
+            writer: BufWriter::new(file),
+            size: metadata.len(),
+            period: period_key(modified, config.rotation),
+            path,
+            rotation: config.rotation,
+            max_size: config.max_size,
+            max_files: config.max_files,
+            compress: config.compress,
+        })
+    }
+
+    fn write_line(&mut self, line: &str) -> io::Result<()> {
+        let now = Local::now();
+        let period = period_key(now, self.rotation);
+        let length = line.len() as u64 + 1;
+        let oversized = self.max_size > 0 && self.size > 0 && self.size + length > self.max_size;
+        if self.size > 0 && (period != self.period || oversized) {
+            self.rotate(now)?;
+        }
+        self.period = period;
+
+        writeln!(self.writer, "{}", line)?;
+        self.size += length;
+        Ok(())
+    }
+
+    fn rotate(&mut self, now: DateTime<Local>) -> io::Result<()> {
+        self.writer.flush()?;
+        let base = self.path.as_os_str().to_string_lossy().into_owned();
+        let stamp = now.format("%Y%m%d%H%M%S");
+        let mut rotated = PathBuf::from(format!("{}.{}", base, stamp));
+        let mut suffix = 1;
+        while rotated.exists() || Path::new(&format!("{}.gz", rotated.display())).exists() {
+            rotated = PathBuf::from(format!("{}.{}-{}", base, stamp, suffix));
+            suffix += 1;
+        }
+        fs::rename(&self.path, &rotated)?;
+
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)?;
+        self.writer = BufWriter::new(file);
+        self.size = 0;
+
+        let path = self.path.clone();
+        let (compress, max_files) = (self.compress, self.max_files);
+        // 压缩较大的文件耗时较长，放到单独的线程中，不影响后续日志写入
+        thread::spawn(move || {
+            if compress {
+                if let Err(e) = compress_file(&rotated) {
+                    tracing::error!(error = %e, file = %rotated.display(), "Failed to compress access log");
+                }
+            }
+            if let Err(e) = prune(&path, max_files) {
+                tracing::error!(error = %e, "Failed to remove old access logs");
+            }
+        });
+        Ok(())
+    }
+}
+
+fn compress_file(path: &Path) -> io::Result<()> {
+    let input = BufReader::new(File::open(path)?);
+    let output = BufWriter::new(File::create(format!("{}.gz", path.display()))?);
+    GzipUtil::compress(input, output)?;
+    fs::remove_file(path)
+}
+
+/// 只保留最近的 `max_files` 个轮转文件，时间戳后缀按字典序即为时间顺序
+fn prune(path: &Path, max_files: usize) -> io::Result<()> {
+    if max_files == 0 {
+        return Ok(());
+    }
+    let Some(name) = path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+    else {
+        return Ok(());
+    };
+    let prefix = format!("{}.", name);
+    let dir = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or_else(|| Path::new("."));
+
+    let mut rotated: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .map(|entry| entry.path())
+        .collect();
+    rotated.sort();
+    let excess = rotated.len().saturating_sub(max_files);
+    for old in &rotated[..excess] {
+        match fs::remove_file(old) {
+            // 多次轮转的清理线程可能同时删除同一个文件
+            Err(e) if e.kind() != io::ErrorKind::NotFound => return Err(e),
+            _ => {},
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    fn entry() -> AccessLogEntry {
+        AccessLogEntry {
+            time: Local.with_ymd_and_hms(2024, 5, 17, 13, 45, 30).unwrap(),
+            remote_addr: "10.0.0.1".to_string(),
+            method: "GET".to_string(),
+            uri: "/user?current=1".to_string(),
+            protocol: "HTTP/1.1".to_string(),
+            status: 200,
+            bytes: None,
+            referer: None,
+            user_agent: Some("curl/8.0 \"test\"".to_string()),
+            duration: Duration::from_millis(12),
+        }
+    }
+
+    #[test]
+    fn test_format() {
+        let combined = entry().format(AccessLogFormat::Combined);
+        assert!(combined.starts_with("10.0.0.1 - - [17/May/2024:13:45:30 "));
+        assert!(combined
+            .ends_with("] \"GET /user?current=1 HTTP/1.1\" 200 - \"-\" \"curl/8.0 \\\"test\\\"\""));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&entry().format(AccessLogFormat::Json)).unwrap();
+        assert_eq!(json["status"], 200);
+        assert_eq!(json["uri"], "/user?current=1");
+        assert_eq!(json["bytes"], serde_json::Value::Null);
+        assert_eq!(json["durationMs"], 12.0);
+    }
+
+    #[test]
+    fn test_rotate_by_size() {
+        let dir = std::env::temp_dir().join(format!("access-log-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let config = AccessLogConfig {
+            enabled: true,
+            output: dir.join("access.log").display().to_string(),
+            rotation: AccessLogRotation::Never,
+            max_size: 16,
+            max_files: 2,
+            compress: false,
+            ..Default::default()
+        };
+
+        let mut file = RotatingFile::open(&config).unwrap();
+        for line in ["first line", "second line", "third line", "fourth line"] {
+            file.write_line(line).unwrap();
+        }
+        file.writer.flush().unwrap();
+        // 清理在后台线程中执行
+        thread::sleep(Duration::from_millis(200));
+
+        assert_eq!(
+            fs::read_to_string(dir.join("access.log")).unwrap(),
+            "fourth line\n"
+        );
+        let rotated = fs::read_dir(&dir).unwrap().count() - 1;
+        assert_eq!(rotated, 2);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+use axum::{
+    body::Body,
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use once_cell::sync::OnceCell;
+use parking_lot::Mutex;
+use serde::Serialize;
+use server_config::{ConcurrencyConfig, ConcurrencyMode};
+use tokio::sync::Notify;
+
+use crate::web::res::Res;
+
+/// 长期 RTT 的指数平均窗口（样本数）
+const LONG_RTT_WINDOW: f64 = 100.0;
+/// 允许的延迟放大倍数，短期 RTT 在此范围内不收缩上限
+const RTT_TOLERANCE: f64 = 1.5;
+/// 上限调整的平滑系数
+const SMOOTHING: f64 = 0.2;
+
+static ADMISSION: OnceCell<Arc<AdmissionController>> = OnceCell::new();
+
+/// 准入控制状态快照
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AdmissionSnapshot {
+    pub mode: ConcurrencyMode,
+    /// 当前并发上限
+    pub limit: usize,
+    pub in_flight: usize,
+    pub queued: usize,
+    /// 累计拒绝的请求数
+    pub rejected: u64,
+    /// 长期平均延迟（毫秒），自适应模式下作为无负载基线
+    pub long_rtt_ms: Option<f64>,
+}
+
+struct LimitState {
+    limit: f64,
+    long_rtt_ms: Option<f64>,
+}
+
+/// 并发准入控制器
+///
+/// 固定模式下上限不变；自适应模式按梯度算法调整：
+/// 短期延迟相对长期平均明显升高时按比例收缩上限，否则以 `sqrt(limit)` 的步长放开
+pub struct AdmissionController {
+    config: ConcurrencyConfig,
+    state: Mutex<LimitState>,
+    in_flight: AtomicUsize,
+    queued: AtomicUsize,
+    rejected: AtomicU64,
+    notify: Notify,
+}
+
+/// 已获得的处理许可，释放时归还并发额度
+struct AdmissionPermit {
+    controller: Arc<AdmissionController>,
+    started_at: Instant,
+}
+
+impl Drop for AdmissionPermit {
+    fn drop(&mut self) {
+        self.controller.release(self.started_at.elapsed());
+    }
+}
+
+impl AdmissionController {
+    pub fn new(config: ConcurrencyConfig) -> Self {
+        let limit = config
+            .limit
+            .clamp(config.min_limit.max(1), config.max_limit.max(1));
+        Self {
+            config,
+            state: Mutex::new(LimitState {
+                limit: limit as f64,
+                long_rtt_ms: None,
+            }),
+            in_flight: AtomicUsize::new(0),
+            queued: AtomicUsize::new(0),
+            rejected: AtomicU64::new(0),
+            notify: Notify::new(),
+        }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.state.lock().limit as usize
+    }
+
+    pub fn snapshot(&self) -> AdmissionSnapshot {
+        let state = self.state.lock();
+        AdmissionSnapshot {
+            mode: self.config.mode,
+            limit: state.limit as usize,
+            in_flight: self.in_flight.load(Ordering::Relaxed),
+            queued: self.queued.load(Ordering::Relaxed),
+            rejected: self.rejected.load(Ordering::Relaxed),
+            long_rtt_ms: state.long_rtt_ms,
+        }
+    }
+
+    fn try_acquire(&self) -> bool {
+        let limit = self.limit();
+        self.in_flight
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |current| {
+                (current < limit).then_some(current + 1)
+            })
+            .is_ok()
+    }
+
+    /// 获取处理许可，超出上限时排队等待，队列已满或超时返回 `None`
+    async fn acquire(self: &Arc<Self>) -> Option<AdmissionPermit> {
+        if !self.try_acquire() {
+            if self.queued.fetch_add(1, Ordering::AcqRel) >= self.config.queue_depth {
+                self.queued.fetch_sub(1, Ordering::AcqRel);
+                return None;
+            }
+
+            let timeout = Duration::from_millis(self.config.queue_timeout_ms);
+            let acquired = tokio::time::timeout(timeout, async {
+                loop {
+                    let notified = self.notify.notified();
+                    if self.try_acquire() {
+                        return;
+                    }
+                    notified.await;
+                }
+            })
+            .await
+            .is_ok();
+
+            self.queued.fetch_sub(1, Ordering::AcqRel);
+            if !acquired {
+                return None;
+            }
+        }
+
+        Some(AdmissionPermit {
+            controller: Arc::clone(self),
+            started_at: Instant::now(),
+        })
+    }
+
+    fn release(&self, latency: Duration) {
+        let in_flight = self.in_flight.fetch_sub(1, Ordering::AcqRel);
+        if self.config.mode == ConcurrencyMode::Adaptive {
+            self.update_limit(latency.as_secs_f64() * 1000.0, in_flight);
+        }
+        self.notify.notify_one();
+    }
+
+    fn update_limit(&self, rtt_ms: f64, in_flight: usize) {
+        let mut state = self.state.lock();
+        let long_rtt = match state.long_rtt_ms {
+            Some(long) => long + (rtt_ms - long) / LONG_RTT_WINDOW,
+            None => rtt_ms,
+        };
+        state.long_rtt_ms = Some(long_rtt);
+
+        let previous = state.limit;
+        state.limit = next_limit(
+            previous,
+            long_rtt,
+            rtt_ms,
+            in_flight,
+            self.config.min_limit.max(1) as f64,
+            self.config.max_limit.max(1) as f64,
+        );
+
+        if previous as usize != state.limit as usize {
+            tracing::debug!(
+                target: "metrics",
+                event = "concurrency_limit_changed",
+                limit = state.limit as usize,
+                in_flight,
+                long_rtt_ms = long_rtt,
+                rtt_ms,
+            );
+        }
+    }
+}
+
+/// 计算下一次的并发上限
+///
+/// 负载不足上限一半时不再放开，避免空闲期间上限无限增长
+fn next_limit(
+    limit: f64,
+    long_rtt_ms: f64,
+    rtt_ms: f64,
+    in_flight: usize,
+    min_limit: f64,
+    max_limit: f64,
+) -> f64 {
+    let gradient = if rtt_ms > 0.0 {
+        (RTT_TOLERANCE * long_rtt_ms / rtt_ms).clamp(0.5, 1.0)
+    } else {
+        1.0
+    };
+    if gradient >= 1.0 && (in_flight as f64) < limit / 2.0 {
+        return limit;
+    }
+
+    let target = limit * gradient + limit.sqrt();
+    (limit * (1.0 - SMOOTHING) + target * SMOOTHING).clamp(min_limit, max_limit)
+}
+
+/// 按配置创建全局准入控制器，未启用时返回 `None`
+pub fn init_admission_controller(config: ConcurrencyConfig) -> Option<Arc<AdmissionController>> {
+    if config.mode == ConcurrencyMode::Disabled {
+        return None;
+    }
+    Some(Arc::clone(
+        ADMISSION.get_or_init(|| Arc::new(AdmissionController::new(config))),
+    ))
+}
+
+/// 获取当前准入控制状态，未启用时返回 `None`
+pub fn admission_snapshot() -> Option<AdmissionSnapshot> {
+    ADMISSION.get().map(|controller| controller.snapshot())
+}
+
+/// 并发准入中间件，超出上限且排队失败时返回 503
+pub async fn admission_middleware(
+    controller: Arc<AdmissionController>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(_permit) = controller.acquire().await else {
+        controller.rejected.fetch_add(1, Ordering::Relaxed);
+        return (
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, "1")],
+            Res::<()>::new_error(
+                StatusCode::SERVICE_UNAVAILABLE.as_u16(),
+                "Server is overloaded, please retry later",
+            ),
+        )
+            .into_response();
+    };
+
+    next.run(req).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(mode: ConcurrencyMode, limit: usize, queue_depth: usize) -> ConcurrencyConfig {
+        ConcurrencyConfig {
+            mode,
+            limit,
+            queue_depth,
+            queue_timeout_ms: 10,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_next_limit_shrinks_when_latency_rises() {
+        let limit = next_limit(100.0, 10.0, 100.0, 100, 8.0, 1024.0);
+        assert!(limit < 100.0);
+    }
+
+    #[test]
+    fn test_next_limit_grows_under_load() {
+        let limit = next_limit(100.0, 10.0, 10.0, 100, 8.0, 1024.0);
+        assert!(limit > 100.0);
+    }
+
+    #[test]
+    fn test_next_limit_holds_when_idle() {
+        assert_eq!(next_limit(100.0, 10.0, 10.0, 10, 8.0, 1024.0), 100.0);
+    }
+
+    #[test]
+    fn test_next_limit_respects_bounds() {
+        assert_eq!(next_limit(8.0, 10.0, 1000.0, 8, 8.0, 1024.0), 8.0);
+        assert_eq!(next_limit(1024.0, 10.0, 10.0, 1024, 8.0, 1024.0), 1024.0);
+    }
+
+    #[tokio::test]
+    async fn test_acquire_rejects_when_queue_full() {
+        let controller = Arc::new(AdmissionController::new(config(
+            ConcurrencyMode::Fixed,
+            8,
+            0,
+        )));
+        let permits: Vec<_> = futures::future::join_all((0..8).map(|_| controller.acquire()))
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+        assert_eq!(permits.len(), 8);
+        assert!(controller.acquire().await.is_none());
+
+        drop(permits);
+        assert_eq!(controller.snapshot().in_flight, 0);
+        assert!(controller.acquire().await.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_acquire_waits_in_queue() {
+        let controller = Arc::new(AdmissionController::new(config(
+            ConcurrencyMode::Fixed,
+            8,
+            1,
+        )));
+        let mut permits: Vec<_> = futures::future::join_all((0..8).map(|_| controller.acquire()))
+            .await
+            .into_iter()
+            .flatten()
+            .collect();
+
+        let waiter = {
+            let controller = Arc::clone(&controller);
+            tokio::spawn(async move { controller.acquire().await.is_some() })
+        };
+        tokio::task::yield_now().await;
+        permits.pop();
+        assert!(waiter.await.unwrap());
+    }
+}
@@ -1,11 +1,28 @@
-use async_trait::async_trait;
+use std::future::Future;
+
 use axum::{
     extract::{FromRequest, Request},
     http::StatusCode,
 };
 use serde::{Deserialize, Serialize};
 
-use crate::web::res::Res;
+use crate::web::{jwt::TokenType, res::Res};
+
+tokio::task_local! {
+    static CURRENT_USER: User;
+}
+
+/// 在当前用户的作用域内执行请求，由 JWT 鉴权中间件调用
+pub async fn scope_user<F: Future>(user: User, f: F) -> F::Output {
+    CURRENT_USER.scope(user, f).await
+}
+
+/// 当前请求的用户，供拿不到请求的代码（如实体的保存钩子）使用
+///
+/// 未鉴权的请求和后台任务返回 `None`，`tokio::spawn` 出的任务不会继承
+pub fn current_user() -> Option<User> {
+    CURRENT_USER.try_with(Clone::clone).ok()
+}
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
@@ -17,11 +34,20 @@ pub struct Claims {
     iat: Option<usize>,
     nbf: Option<usize>,
     jti: Option<String>,
+    /// 令牌类型，刷新令牌不能作为访问令牌使用
+    typ: TokenType,
 
     username: String,
     role: Vec<String>,
     domain: String,
     org: Option<String>,
+
+    /// 用户完成身份验证的时间（秒级时间戳），换发令牌时保持不变
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    auth_time: Option<usize>,
+    /// 本次身份验证使用的方式，如 `pwd`、`passkey`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    amr: Vec<String>,
 }
 
 impl Claims {
@@ -41,13 +67,32 @@ impl Claims {
             iat: None,
             nbf: None,
             jti: None,
+            typ: TokenType::Access,
             username,
             role,
             domain,
             org,
+            auth_time: None,
+            amr: Vec::new(),
         }
     }
 
+    pub fn typ(&self) -> TokenType {
+        self.typ
+    }
+
+    pub fn auth_time(&self) -> Option<usize> {
+        self.auth_time
+    }
+
+    pub fn set_auth_time(&mut self, auth_time: usize) {
+        self.auth_time = Some(auth_time);
+    }
+
+    pub fn set_amr(&mut self, amr: Vec<String>) {
+        self.amr = amr;
+    }
+
     pub fn set_exp(&mut self, exp: usize) {
         self.exp = Some(exp);
     }
@@ -76,6 +121,10 @@ pub struct User {
     role: Vec<String>,
     domain: String,
     org: Option<String>,
+    #[serde(default)]
+    auth_time: Option<usize>,
+    #[serde(default)]
+    amr: Vec<String>,
 }
 
 impl User {
@@ -94,6 +143,16 @@ impl User {
     pub fn domain(&self) -> String {
         self.domain.to_string()
     }
+
+    /// 完成身份验证的时间（秒级时间戳），旧令牌中没有该字段
+    pub fn auth_time(&self) -> Option<usize> {
+        self.auth_time
+    }
+
+    /// 是否使用指定方式完成了身份验证
+    pub fn authenticated_with(&self, method: &str) -> bool {
+        self.amr.iter().any(|amr| amr == method)
+    }
 }
 
 impl From<Claims> for User {
@@ -104,26 +163,22 @@ impl From<Claims> for User {
             role: claims.role,
             domain: claims.domain,
             org: claims.org,
+            auth_time: claims.auth_time,
+            amr: claims.amr,
         }
     }
 }
 
-#[async_trait]
 impl<S> FromRequest<S> for User
 where
     S: Send + Sync + 'static,
 {
     type Rejection = Res<String>;
 
-    fn from_request(
-        req: Request,
-        _state: &S,
-    ) -> impl std::future::Future<Output = Result<Self, Self::Rejection>> + Send {
-        async move {
-            req.extensions()
-                .get::<User>()
-                .cloned()
-                .ok_or_else(|| Res::new_error(StatusCode::UNAUTHORIZED.as_u16(), "Unauthorized"))
-        }
+    async fn from_request(req: Request, _state: &S) -> Result<Self, Self::Rejection> {
+        req.extensions()
+            .get::<User>()
+            .cloned()
+            .ok_or_else(|| Res::new_error(StatusCode::UNAUTHORIZED.as_u16(), "Unauthorized"))
     }
 }
@@ -0,0 +1,319 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::{to_bytes, Body},
+    http::{header, HeaderMap, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use once_cell::sync::{Lazy, OnceCell};
+use parking_lot::RwLock;
+use serde_json::Value;
+use server_config::{BotDetectionConfig, BotRouteGroup};
+
+use crate::web::res::Res;
+
+/// 要求验证码时返回的业务码，前端据此弹出验证码并携带令牌重试
+pub const CAPTCHA_REQUIRED_CODE: u16 = 9601;
+/// 被判定为机器人时返回的业务码
+pub const BOT_BLOCKED_CODE: u16 = 9602;
+
+/// 蜜罐检查时最多读取的请求体字节数，公开表单的请求体都很小
+const MAX_INSPECTED_BODY: usize = 64 * 1024;
+
+/// 常见脚本库和自动化工具的 User-Agent 片段
+const AUTOMATION_AGENTS: &[&str] = &[
+    "curl",
+    "wget",
+    "python-requests",
+    "python-urllib",
+    "aiohttp",
+    "go-http-client",
+    "java/",
+    "okhttp",
+    "libwww-perl",
+    "scrapy",
+    "httpclient",
+    "headlesschrome",
+    "phantomjs",
+    "selenium",
+    "puppeteer",
+    "playwright",
+];
+
+static SCORERS: Lazy<RwLock<Vec<BotScorer>>> = Lazy::new(|| RwLock::new(Vec::new()));
+static CAPTCHA_VERIFIER: OnceCell<Arc<dyn CaptchaVerifier>> = OnceCell::new();
+
+/// 参与打分的请求特征
+pub struct BotSignals<'a> {
+    pub path: &'a str,
+    pub headers: &'a HeaderMap,
+    /// 蜜罐字段是否被填写
+    pub honeypot_filled: bool,
+}
+
+/// 额外的打分规则，返回值累加到内置规则的分数上
+pub type BotScorer = Box<dyn Fn(&BotSignals<'_>) -> u32 + Send + Sync>;
+
+/// 验证码校验，由接入的验证码服务实现
+#[async_trait]
+pub trait CaptchaVerifier: Send + Sync {
+    async fn verify(&self, token: &str) -> bool;
+}
+
+/// 检测结论
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BotVerdict {
+    Allow,
+    /// 需要完成验证码后重试
+    Captcha,
+    Block,
+}
+
+/// 注册额外的打分规则
+pub fn register_bot_scorer(scorer: BotScorer) {
+    SCORERS.write().push(scorer);
+}
+
+/// 设置验证码校验实现，未设置时要求验证码的请求一律拒绝
+pub fn set_captcha_verifier(verifier: Arc<dyn CaptchaVerifier>) -> bool {
+    CAPTCHA_VERIFIER.set(verifier).is_ok()
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &str) -> Option<&'a str> {
+    headers
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// 按内置规则和注册的打分规则计算分数（0-100）
+pub fn score(config: &BotDetectionConfig, signals: &BotSignals<'_>) -> u32 {
+    if signals.honeypot_filled {
+        return 100;
+    }
+
+    let mut score = 0;
+    match header_str(signals.headers, header::USER_AGENT.as_str()) {
+        None => score += 40,
+        Some(agent) => {
+            let agent = agent.to_ascii_lowercase();
+            // 脚本库通常不带浏览器请求头，叠加后达到默认的拦截分数
+            if AUTOMATION_AGENTS.iter().any(|token| agent.contains(token)) {
+                score += 60;
+            } else if !agent.starts_with("mozilla/") {
+                score += 20;
+            }
+        },
+    }
+    if header_str(signals.headers, header::ACCEPT_LANGUAGE.as_str()).is_none() {
+        score += 15;
+    }
+    if header_str(signals.headers, header::ACCEPT.as_str()).is_none() {
+        score += 10;
+    }
+    if let Some(ja3) = config
+        .ja3_header
+        .as_deref()
+        .and_then(|name| header_str(signals.headers, name))
+    {
+        if config
+            .suspicious_ja3
+            .iter()
+            .any(|suspicious| suspicious.eq_ignore_ascii_case(ja3))
+        {
+            score += 50;
+        }
+    }
+
+    score += SCORERS
+        .read()
+        .iter()
+        .map(|scorer| scorer(signals))
+        .sum::<u32>();
+    score.min(100)
+}
+
+fn verdict(group: &BotRouteGroup, score: u32) -> BotVerdict {
+    if score >= group.block_score {
+        BotVerdict::Block
+    } else if score >= group.captcha_score {
+        BotVerdict::Captcha
+    } else {
+        BotVerdict::Allow
+    }
+}
+
+/// 蜜罐字段是否被填写，只检查 JSON 和表单请求体
+fn honeypot_filled(headers: &HeaderMap, body: &[u8], field: &str) -> bool {
+    let content_type = header_str(headers, header::CONTENT_TYPE.as_str()).unwrap_or_default();
+    if content_type.starts_with("application/json") {
+        serde_json::from_slice::<Value>(body)
+            .ok()
+            .and_then(|body| body.get(field).cloned())
+            .is_some_and(|value| match value {
+                Value::Null => false,
+                Value::String(value) => !value.is_empty(),
+                _ => true,
+            })
+    } else if content_type.starts_with("application/x-www-form-urlencoded") {
+        form_urlencoded::parse(body).any(|(name, value)| name == field && !value.is_empty())
+    } else {
+        false
+    }
+}
+
+async fn captcha_passed(config: &BotDetectionConfig, headers: &HeaderMap) -> bool {
+    let Some(token) = header_str(headers, &config.captcha_header) else {
+        return false;
+    };
+    match CAPTCHA_VERIFIER.get() {
+        Some(verifier) => verifier.verify(token).await,
+        None => false,
+    }
+}
+
+fn reject(code: u16, message: &str) -> Response {
+    (StatusCode::FORBIDDEN, Res::<()>::new_error(code, message)).into_response()
+}
+
+/// 机器人检测中间件，只对配置的路由组生效
+pub async fn bot_detection_middleware(
+    config: Arc<BotDetectionConfig>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let path = req.uri().path().to_string();
+    let Some(group) = config.route_group(&path) else {
+        return next.run(req).await;
+    };
+
+    let (parts, body) = req.into_parts();
+    let (body, honeypot) = if group.honeypot {
+        match to_bytes(body, MAX_INSPECTED_BODY).await {
+            Ok(bytes) => {
+                let filled = honeypot_filled(&parts.headers, &bytes, &config.honeypot_field);
+                (Body::from(bytes), filled)
+            },
+            Err(_) => return reject(BOT_BLOCKED_CODE, "Request body too large"),
+        }
+    } else {
+        (body, false)
+    };
+
+    let signals = BotSignals {
+        path: &path,
+        headers: &parts.headers,
+        honeypot_filled: honeypot,
+    };
+    let score = score(&config, &signals);
+    let verdict = match verdict(group, score) {
+        BotVerdict::Captcha if captcha_passed(&config, &parts.headers).await => BotVerdict::Allow,
+        verdict => verdict,
+    };
+
+    if verdict != BotVerdict::Allow {
+        tracing::info!(
+            target: "metrics",
+            event = "bot_detected",
+            path = %path,
+            score,
+            honeypot,
+            verdict = ?verdict,
+        );
+    }
+    match verdict {
+        BotVerdict::Allow => next.run(Request::from_parts(parts, body)).await,
+        BotVerdict::Captcha => reject(CAPTCHA_REQUIRED_CODE, "Captcha required"),
+        BotVerdict::Block => reject(BOT_BLOCKED_CODE, "Request blocked"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn browser_headers() -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            HeaderValue::from_static("Mozilla/5.0 (Windows NT 10.0; Win64; x64) Chrome/126.0"),
+        );
+        headers.insert(header::ACCEPT, HeaderValue::from_static("application/json"));
+        headers.insert(header::ACCEPT_LANGUAGE, HeaderValue::from_static("zh-CN"));
+        headers
+    }
+
+    #[test]
+    fn test_score_and_verdict() {
+        let config = BotDetectionConfig {
+            ja3_header: Some("X-JA3-Fingerprint".to_string()),
+            suspicious_ja3: vec!["e7d705a3286e19ea42f587b344ee6865".to_string()],
+            ..Default::default()
+        };
+        let group = config.route_group("/auth/login").unwrap();
+        let mut headers = browser_headers();
+        fn signals(headers: &HeaderMap, honeypot_filled: bool) -> BotSignals<'_> {
+            BotSignals {
+                path: "/auth/login",
+                headers,
+                honeypot_filled,
+            }
+        }
+
+        assert_eq!(
+            verdict(group, score(&config, &signals(&headers, false))),
+            BotVerdict::Allow
+        );
+        assert_eq!(
+            verdict(group, score(&config, &signals(&headers, true))),
+            BotVerdict::Block
+        );
+
+        headers.insert(
+            "X-JA3-Fingerprint",
+            HeaderValue::from_static("e7d705a3286e19ea42f587b344ee6865"),
+        );
+        assert_eq!(
+            verdict(group, score(&config, &signals(&headers, false))),
+            BotVerdict::Captcha
+        );
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::USER_AGENT,
+            HeaderValue::from_static("python-requests/2.31"),
+        );
+        assert_eq!(
+            verdict(group, score(&config, &signals(&headers, false))),
+            BotVerdict::Block
+        );
+    }
+
+    #[test]
+    fn test_honeypot_filled() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/json"),
+        );
+        assert!(honeypot_filled(&headers, br#"{"website":"x"}"#, "website"));
+        assert!(!honeypot_filled(&headers, br#"{"website":""}"#, "website"));
+
+        headers.insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static("application/x-www-form-urlencoded"),
+        );
+        assert!(honeypot_filled(
+            &headers,
+            b"identifier=a&website=x",
+            "website"
+        ));
+        assert!(!honeypot_filled(&headers, b"identifier=a", "website"));
+    }
+}
@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use tower_layer::Layer;
+use tower_service::Service;
+
+use crate::web::{auth::User, error::AppError, operation_log::OperationLogLayer, res::Res};
+
+/// 紧急访问会话已结束时返回的业务码
+pub const BREAK_GLASS_SESSION_ENDED_CODE: u16 = 10406;
+
+/// 紧急访问令牌的 `amr`
+pub const BREAK_GLASS_AUTH_METHOD: &str = "break-glass";
+
+/// 紧急访问令牌的用户 ID 前缀，后接会话 ID
+pub const BREAK_GLASS_SUBJECT_PREFIX: &str = "break-glass:";
+
+/// 查询紧急访问会话状态，由紧急访问服务实现
+#[async_trait]
+pub trait BreakGlassSessionChecker: Send + Sync {
+    /// 会话未过期且未被提前结束时返回 `true`
+    async fn is_active(&self, session_id: &str) -> Result<bool, AppError>;
+}
+
+/// 紧急访问会话中间件，须位于 JWT 鉴权内层以读取当前用户
+///
+/// 普通令牌直接放行。紧急访问令牌先确认会话仍然有效，令牌本身在会话到期时同时过期，
+/// 这里用于管理员提前结束会话；随后完整记录请求和响应到操作日志。
+/// 与其他准入检查不同，会话查询失败时拒绝请求
+pub async fn break_glass_middleware(
+    checker: Arc<dyn BreakGlassSessionChecker>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+    if !user.authenticated_with(BREAK_GLASS_AUTH_METHOD) {
+        return next.run(req).await;
+    }
+
+    let user_id = user.user_id();
+    let session_id = user_id
+        .strip_prefix(BREAK_GLASS_SUBJECT_PREFIX)
+        .unwrap_or(&user_id);
+    let active = checker.is_active(session_id).await.unwrap_or_else(|e| {
+        tracing::error!(error = %e.message(), "Failed to check break-glass session");
+        false
+    });
+    if !active {
+        tracing::info!(
+            target: "metrics",
+            event = "break_glass_session_rejected",
+            session_id = %session_id,
+        );
+        return (
+            StatusCode::UNAUTHORIZED,
+            Res::<()> {
+                code: BREAK_GLASS_SESSION_ENDED_CODE,
+                data: None,
+                msg: "Break-glass session has ended".to_string(),
+                success: false,
+            },
+        )
+            .into_response();
+    }
+
+    tracing::info!(
+        target: "metrics",
+        event = "break_glass_request",
+        session_id = %session_id,
+        method = %req.method(),
+        path = %req.uri().path(),
+    );
+    let mut service = OperationLogLayer::new(true)
+        .with_module("紧急访问", "紧急访问会话请求")
+        .layer(next);
+    match service.call(req).await {
+        Ok(response) => response,
+        Err(infallible) => match infallible {},
+    }
+}
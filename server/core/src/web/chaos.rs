@@ -0,0 +1,321 @@
+use std::{
+    io,
+    sync::atomic::{AtomicBool, AtomicU64, Ordering},
+    time::Duration,
+};
+
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::{Local, NaiveDateTime};
+use http::Request;
+use once_cell::sync::Lazy;
+use parking_lot::RwLock;
+use serde::{Deserialize, Serialize};
+use validator::Validate;
+
+use crate::web::{error::AppError, res::Res};
+
+/// 故障注入管理接口本身不受影响，避免注入后无法撤销
+const CHAOS_ROUTE_PREFIX: &str = "/chaos";
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static FAULTS: Lazy<RwLock<Vec<ActiveFault>>> = Lazy::new(|| RwLock::new(Vec::new()));
+
+/// 故障注入目标
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FaultTarget {
+    /// 进入的 HTTP 请求
+    Http,
+    Database,
+    Redis,
+    Mongo,
+    S3,
+}
+
+impl FaultTarget {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FaultTarget::Http => "http",
+            FaultTarget::Database => "database",
+            FaultTarget::Redis => "redis",
+            FaultTarget::Mongo => "mongo",
+            FaultTarget::S3 => "s3",
+        }
+    }
+}
+
+/// 注入的故障类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(
+    tag = "type",
+    rename_all = "lowercase",
+    rename_all_fields = "camelCase"
+)]
+pub enum FaultKind {
+    /// 增加延迟后继续处理
+    Latency { delay_ms: u64 },
+    /// 直接返回错误，HTTP 目标返回该状态码，依赖目标返回 503
+    Error { status: u16 },
+    /// 中断连接，HTTP 目标在响应体中途断开，依赖目标按连接失败处理
+    Abort,
+}
+
+/// 故障注入规则
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FaultRule {
+    pub id: String,
+    pub target: FaultTarget,
+    pub fault: FaultKind,
+    /// 命中概率（0-100）
+    pub percentage: f64,
+    /// 只对该前缀的请求路径生效，仅用于 HTTP 目标
+    pub route_prefix: Option<String>,
+    pub created_by: String,
+    pub created_at: NaiveDateTime,
+    /// 到期自动失效，防止遗留的故障影响正常流量
+    pub expires_at: NaiveDateTime,
+    /// 已注入次数
+    pub hits: u64,
+}
+
+/// 注入故障的参数
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FaultSpec {
+    pub target: FaultTarget,
+    pub fault: FaultKind,
+    /// 命中概率（0-100），默认 100
+    #[validate(range(
+        min = 0.0,
+        max = 100.0,
+        message = "Percentage must be between 0 and 100"
+    ))]
+    #[serde(default = "default_percentage")]
+    pub percentage: f64,
+    /// 只对该前缀的请求路径生效，仅用于 HTTP 目标
+    pub route_prefix: Option<String>,
+    /// 生效时长（秒），默认 300
+    #[validate(range(
+        min = 1,
+        max = 3600,
+        message = "Duration must be between 1 and 3600 seconds"
+    ))]
+    #[serde(default = "default_fault_seconds")]
+    pub duration_seconds: u64,
+}
+
+fn default_percentage() -> f64 {
+    100.0
+}
+
+fn default_fault_seconds() -> u64 {
+    300
+}
+
+struct ActiveFault {
+    rule: FaultRule,
+    hits: AtomicU64,
+}
+
+impl ActiveFault {
+    fn snapshot(&self) -> FaultRule {
+        FaultRule {
+            hits: self.hits.load(Ordering::Relaxed),
+            ..self.rule.clone()
+        }
+    }
+
+    fn applies_to(&self, target: FaultTarget, path: Option<&str>) -> bool {
+        self.rule.target == target
+            && match (&self.rule.route_prefix, path) {
+                (Some(prefix), Some(path)) => path.starts_with(prefix.as_str()),
+                (Some(_), None) => false,
+                (None, _) => true,
+            }
+    }
+}
+
+fn refresh_active(faults: &[ActiveFault]) {
+    ACTIVE.store(!faults.is_empty(), Ordering::Relaxed);
+}
+
+/// 移除已过期的规则
+fn purge_expired() {
+    let now = Local::now().naive_local();
+    let mut faults = FAULTS.write();
+    faults.retain(|fault| fault.rule.expires_at > now);
+    refresh_active(&faults);
+}
+
+pub fn add_fault(rule: FaultRule) {
+    let mut faults = FAULTS.write();
+    faults.push(ActiveFault {
+        rule,
+        hits: AtomicU64::new(0),
+    });
+    refresh_active(&faults);
+}
+
+/// 移除指定规则，不存在时返回 `false`
+pub fn remove_fault(id: &str) -> bool {
+    let mut faults = FAULTS.write();
+    let before = faults.len();
+    faults.retain(|fault| fault.rule.id != id);
+    refresh_active(&faults);
+    faults.len() != before
+}
+
+/// 移除全部规则，返回移除的条数
+pub fn clear_faults() -> usize {
+    let mut faults = FAULTS.write();
+    let cleared = faults.len();
+    faults.clear();
+    refresh_active(&faults);
+    cleared
+}
+
+pub fn list_faults() -> Vec<FaultRule> {
+    purge_expired();
+    FAULTS.read().iter().map(ActiveFault::snapshot).collect()
+}
+
+/// 按规则顺序选取第一条命中的故障
+fn pick_fault(target: FaultTarget, path: Option<&str>) -> Option<FaultKind> {
+    if !ACTIVE.load(Ordering::Relaxed) {
+        return None;
+    }
+    purge_expired();
+
+    let faults = FAULTS.read();
+    let fault = faults.iter().find(|fault| {
+        fault.applies_to(target, path) && rand::random::<f64>() * 100.0 < fault.rule.percentage
+    })?;
+    fault.hits.fetch_add(1, Ordering::Relaxed);
+    tracing::info!(
+        target: "metrics",
+        event = "chaos_fault_injected",
+        rule = %fault.rule.id,
+        fault_target = target.as_str(),
+        path = path.unwrap_or_default(),
+    );
+    Some(fault.rule.fault)
+}
+
+/// 在访问外部依赖前调用，命中规则时增加延迟或返回错误
+pub async fn inject_fault(target: FaultTarget) -> Result<(), AppError> {
+    match pick_fault(target, None) {
+        None => Ok(()),
+        Some(FaultKind::Latency { delay_ms }) => {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            Ok(())
+        },
+        Some(FaultKind::Error { .. }) => Err(AppError::dependency(format!(
+            "Injected fault: {} unavailable",
+            target.as_str()
+        ))),
+        Some(FaultKind::Abort) => Err(AppError::dependency(format!(
+            "Injected fault: {} connection reset",
+            target.as_str()
+        ))),
+    }
+}
+
+/// 故障注入中间件，对命中规则的请求增加延迟、返回错误或中断连接
+pub async fn chaos_middleware(req: Request<Body>, next: Next) -> Response {
+    let path = req.uri().path();
+    if path.starts_with(CHAOS_ROUTE_PREFIX) {
+        return next.run(req).await;
+    }
+
+    match pick_fault(FaultTarget::Http, Some(path)) {
+        None => next.run(req).await,
+        Some(FaultKind::Latency { delay_ms }) => {
+            tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+            next.run(req).await
+        },
+        Some(FaultKind::Error { status }) => {
+            let status = StatusCode::from_u16(status).unwrap_or(StatusCode::SERVICE_UNAVAILABLE);
+            (
+                status,
+                Res::<()>::new_error(status.as_u16(), "Injected fault"),
+            )
+                .into_response()
+        },
+        // 响应体读取出错时连接被直接断开，客户端观察到的是连接重置
+        Some(FaultKind::Abort) => Response::new(Body::from_stream(futures::stream::once(async {
+            Err::<bytes::Bytes, _>(io::Error::new(
+                io::ErrorKind::ConnectionReset,
+                "Injected fault",
+            ))
+        }))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(id: &str, target: FaultTarget, percentage: f64, prefix: Option<&str>) -> FaultRule {
+        let now = Local::now().naive_local();
+        FaultRule {
+            id: id.to_string(),
+            target,
+            fault: FaultKind::Error { status: 500 },
+            percentage,
+            route_prefix: prefix.map(str::to_string),
+            created_by: "test".to_string(),
+            created_at: now,
+            expires_at: now + chrono::Duration::minutes(5),
+            hits: 0,
+        }
+    }
+
+    #[test]
+    fn test_fault_kind_deserialize() {
+        let fault: FaultKind = serde_json::from_str(r#"{"type":"latency","delayMs":200}"#).unwrap();
+        assert_eq!(fault, FaultKind::Latency { delay_ms: 200 });
+        let fault: FaultKind = serde_json::from_str(r#"{"type":"abort"}"#).unwrap();
+        assert_eq!(fault, FaultKind::Abort);
+    }
+
+    #[test]
+    fn test_applies_to_route_prefix() {
+        let fault = ActiveFault {
+            rule: rule("a", FaultTarget::Http, 100.0, Some("/user")),
+            hits: AtomicU64::new(0),
+        };
+        assert!(fault.applies_to(FaultTarget::Http, Some("/user/1")));
+        assert!(!fault.applies_to(FaultTarget::Http, Some("/role")));
+        assert!(!fault.applies_to(FaultTarget::Database, Some("/user/1")));
+    }
+
+    #[tokio::test]
+    async fn test_inject_fault_lifecycle() {
+        add_fault(rule("never", FaultTarget::Redis, 0.0, None));
+        assert!(inject_fault(FaultTarget::Redis).await.is_ok());
+
+        add_fault(rule("always", FaultTarget::Redis, 100.0, None));
+        assert_eq!(
+            inject_fault(FaultTarget::Redis).await.unwrap_err().code,
+            503
+        );
+        assert!(inject_fault(FaultTarget::Mongo).await.is_ok());
+
+        let hits = list_faults()
+            .into_iter()
+            .find(|fault| fault.id == "always")
+            .map(|fault| fault.hits);
+        assert_eq!(hits, Some(1));
+
+        assert!(remove_fault("always"));
+        assert!(!remove_fault("always"));
+        assert!(inject_fault(FaultTarget::Redis).await.is_ok());
+        clear_faults();
+    }
+}
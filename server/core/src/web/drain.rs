@@ -0,0 +1,9 @@
+use axum::{body::Body, middleware::Next, response::Response};
+use http::Request;
+use server_global::global;
+
+/// 登记进行中的请求，实例排空时等待其完成后再退出
+pub async fn in_flight_middleware(req: Request<Body>, next: Next) -> Response {
+    let _guard = global::track_work();
+    next.run(req).await
+}
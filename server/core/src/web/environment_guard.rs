@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use serde::Serialize;
+use server_config::SensitiveOperationConfig;
+
+use crate::web::{auth::User, error::AppError, res::Res};
+
+/// 生产环境调用敏感接口缺少紧急授权或审批时返回的业务码
+pub const BREAK_GLASS_REQUIRED_CODE: u16 = 10401;
+
+/// 紧急授权标记，值为本次操作的原因
+pub const BREAK_GLASS_HEADER: &str = "x-break-glass";
+
+/// 审批 ID
+pub const BREAK_GLASS_APPROVAL_HEADER: &str = "x-break-glass-approval";
+
+/// 返回给前端的紧急授权要求
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakGlassRequired {
+    /// 固定为 `breakGlassRequired`，前端据此引导用户申请审批
+    pub reason: &'static str,
+    /// 被拦截的接口，申请审批时原样提交
+    pub method: String,
+    pub path: String,
+}
+
+/// 核销敏感操作审批，由敏感操作服务实现
+#[async_trait]
+pub trait SensitiveOperationApprover: Send + Sync {
+    /// 审批存在、已由其他管理员批准、申请人和接口与本次请求一致时核销并返回 `true`
+    async fn consume_approval(
+        &self,
+        user: &User,
+        approval_id: &str,
+        method: &str,
+        path: &str,
+        reason: &str,
+    ) -> Result<bool, AppError>;
+}
+
+fn header<'a>(req: &'a Request<Body>, name: &str) -> Option<&'a str> {
+    req.headers()
+        .get(name)
+        .and_then(|value| value.to_str().ok())
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+}
+
+/// 敏感操作防护中间件，仅在生产环境挂载，须位于 JWT 鉴权内层以读取当前用户
+///
+/// 审批一经核销即失效，须挂载在 Casbin 等准入检查的内层，
+/// 避免被其他检查拒绝的请求消耗审批。与其他准入检查不同，审批查询失败时拒绝请求
+pub async fn environment_guard_middleware(
+    config: Arc<SensitiveOperationConfig>,
+    approver: Arc<dyn SensitiveOperationApprover>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let method = req.method().as_str().to_string();
+    let path = req.uri().path().to_string();
+    if config.rule(&method, &path).is_none() {
+        return next.run(req).await;
+    }
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+
+    let approved = match (
+        header(&req, BREAK_GLASS_HEADER),
+        header(&req, BREAK_GLASS_APPROVAL_HEADER),
+    ) {
+        (Some(reason), Some(approval_id)) => approver
+            .consume_approval(user, approval_id, &method, &path, reason)
+            .await
+            .unwrap_or_else(|e| {
+                tracing::error!(error = %e.message(), "Failed to consume break-glass approval");
+                false
+            }),
+        _ => false,
+    };
+    if approved {
+        tracing::info!(
+            target: "metrics",
+            event = "break_glass_used",
+            method = %method,
+            path = %path,
+            user_id = %user.user_id(),
+        );
+        return next.run(req).await;
+    }
+
+    tracing::info!(
+        target: "metrics",
+        event = "sensitive_operation_blocked",
+        method = %method,
+        path = %path,
+        user_id = %user.user_id(),
+    );
+    (
+        StatusCode::FORBIDDEN,
+        Res {
+            code: BREAK_GLASS_REQUIRED_CODE,
+            data: Some(BreakGlassRequired {
+                reason: "breakGlassRequired",
+                method,
+                path,
+            }),
+            msg: "Break-glass approval is required in production".to_string(),
+            success: false,
+        },
+    )
+        .into_response()
+}
@@ -1,34 +1,200 @@
-use axum::response::{IntoResponse, Response};
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
 use mongodb::error::{Error as MongoError, ErrorKind};
 use redis::RedisError;
 use sea_orm::DbErr;
 
 use crate::web::{jwt::JwtError, res::Res};
 
+/// 错误分类，决定响应的 HTTP 状态码，业务码仍由各模块的错误定义
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorCategory {
+    /// 请求参数或业务规则校验失败
+    Validation,
+    /// 资源不存在
+    NotFound,
+    /// 与已有数据冲突，如唯一键重复、状态不允许
+    Conflict,
+    /// 未登录或凭证无效
+    Unauthorized,
+    /// 已登录但无权访问
+    Forbidden,
+    /// 数据库、Redis、S3 等依赖不可用
+    Dependency,
+    /// 服务内部错误
+    Internal,
+}
+
+impl ErrorCategory {
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ErrorCategory::Validation => StatusCode::BAD_REQUEST,
+            ErrorCategory::NotFound => StatusCode::NOT_FOUND,
+            ErrorCategory::Conflict => StatusCode::CONFLICT,
+            ErrorCategory::Unauthorized => StatusCode::UNAUTHORIZED,
+            ErrorCategory::Forbidden => StatusCode::FORBIDDEN,
+            ErrorCategory::Dependency => StatusCode::SERVICE_UNAVAILABLE,
+            ErrorCategory::Internal => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// 按错误码推断分类：HTTP 状态码按语义归类，业务码（1000 及以上）视为业务规则校验失败
+    pub fn from_code(code: u16) -> Self {
+        match code {
+            401 => ErrorCategory::Unauthorized,
+            403 => ErrorCategory::Forbidden,
+            404 => ErrorCategory::NotFound,
+            409 => ErrorCategory::Conflict,
+            502..=504 => ErrorCategory::Dependency,
+            400..=499 => ErrorCategory::Validation,
+            500..=599 => ErrorCategory::Internal,
+            1000.. => ErrorCategory::Validation,
+            _ => ErrorCategory::Internal,
+        }
+    }
+}
+
 pub trait ApiError {
     fn code(&self) -> u16;
     fn message(&self) -> String;
+
+    fn category(&self) -> ErrorCategory {
+        ErrorCategory::from_code(self.code())
+    }
 }
 
+/// 应用错误，按分类区分，分类决定 HTTP 状态码，业务码和消息原样返回给调用方
 #[derive(Debug)]
-pub struct AppError {
-    pub code: u16,
-    pub message: String,
+pub enum AppError {
+    Validation { code: u16, message: String },
+    NotFound { code: u16, message: String },
+    Conflict { code: u16, message: String },
+    Unauthorized { code: u16, message: String },
+    Forbidden { code: u16, message: String },
+    Dependency { code: u16, message: String },
+    Internal { code: u16, message: String },
+}
+
+impl AppError {
+    pub fn new(category: ErrorCategory, code: u16, message: impl Into<String>) -> Self {
+        let message = message.into();
+        match category {
+            ErrorCategory::Validation => AppError::Validation { code, message },
+            ErrorCategory::NotFound => AppError::NotFound { code, message },
+            ErrorCategory::Conflict => AppError::Conflict { code, message },
+            ErrorCategory::Unauthorized => AppError::Unauthorized { code, message },
+            ErrorCategory::Forbidden => AppError::Forbidden { code, message },
+            ErrorCategory::Dependency => AppError::Dependency { code, message },
+            ErrorCategory::Internal => AppError::Internal { code, message },
+        }
+    }
+
+    /// 业务码取分类对应的 HTTP 状态码
+    pub fn of(category: ErrorCategory, message: impl Into<String>) -> Self {
+        Self::new(category, category.status().as_u16(), message)
+    }
+
+    pub fn validation(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Validation, message)
+    }
+
+    pub fn not_found(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::NotFound, message)
+    }
+
+    pub fn conflict(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Conflict, message)
+    }
+
+    pub fn unauthorized(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Unauthorized, message)
+    }
+
+    pub fn forbidden(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Forbidden, message)
+    }
+
+    pub fn dependency(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Dependency, message)
+    }
+
+    pub fn internal(message: impl Into<String>) -> Self {
+        Self::of(ErrorCategory::Internal, message)
+    }
+
+    /// 由模块错误构造，保留其业务码和分类
+    pub fn from_api_error<E: ApiError>(err: &E) -> Self {
+        Self::new(err.category(), err.code(), err.message())
+    }
+
+    pub fn category(&self) -> ErrorCategory {
+        match self {
+            AppError::Validation { .. } => ErrorCategory::Validation,
+            AppError::NotFound { .. } => ErrorCategory::NotFound,
+            AppError::Conflict { .. } => ErrorCategory::Conflict,
+            AppError::Unauthorized { .. } => ErrorCategory::Unauthorized,
+            AppError::Forbidden { .. } => ErrorCategory::Forbidden,
+            AppError::Dependency { .. } => ErrorCategory::Dependency,
+            AppError::Internal { .. } => ErrorCategory::Internal,
+        }
+    }
+
+    pub fn code(&self) -> u16 {
+        match self {
+            AppError::Validation { code, .. }
+            | AppError::NotFound { code, .. }
+            | AppError::Conflict { code, .. }
+            | AppError::Unauthorized { code, .. }
+            | AppError::Forbidden { code, .. }
+            | AppError::Dependency { code, .. }
+            | AppError::Internal { code, .. } => *code,
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        match self {
+            AppError::Validation { message, .. }
+            | AppError::NotFound { message, .. }
+            | AppError::Conflict { message, .. }
+            | AppError::Unauthorized { message, .. }
+            | AppError::Forbidden { message, .. }
+            | AppError::Dependency { message, .. }
+            | AppError::Internal { message, .. } => message,
+        }
+    }
+}
+
+impl std::fmt::Display for AppError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}] {}", self.code(), self.message())
+    }
 }
 
+impl std::error::Error for AppError {}
+
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
-        Res::<()>::new_error(self.code, self.message.as_str()).into_response()
+        (
+            self.category().status(),
+            Res::<()>::new_error(self.code(), self.message()),
+        )
+            .into_response()
     }
 }
 
 impl ApiError for AppError {
     fn code(&self) -> u16 {
-        self.code
+        AppError::code(self)
     }
 
     fn message(&self) -> String {
-        self.message.to_string()
+        AppError::message(self).to_string()
+    }
+
+    fn category(&self) -> ErrorCategory {
+        AppError::category(self)
     }
 }
 
@@ -57,29 +223,54 @@ impl ApiError for DbErr {
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            DbErr::ConnectionAcquire(_) | DbErr::Conn(_) => ErrorCategory::Dependency,
+            DbErr::RecordNotFound(_) | DbErr::RecordNotUpdated => ErrorCategory::NotFound,
+            DbErr::Exec(_) | DbErr::Query(_) if is_unique_violation(self) => {
+                ErrorCategory::Conflict
+            },
+            _ => ErrorCategory::from_code(self.code()),
+        }
+    }
+}
+
+/// 唯一约束冲突，PostgreSQL、MySQL、SQLite 的报错文本不同
+fn is_unique_violation(err: &DbErr) -> bool {
+    let message = err.to_string().to_lowercase();
+    message.contains("duplicate key")
+        || message.contains("duplicate entry")
+        || message.contains("unique constraint")
 }
 
 impl From<DbErr> for AppError {
     fn from(err: DbErr) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
 
 impl From<JwtError> for AppError {
     fn from(err: JwtError) -> Self {
-        AppError {
-            code: 400,
-            message: err.to_string(),
-        }
+        AppError::new(ErrorCategory::Unauthorized, 400, err.to_string())
     }
 }
 
 impl From<RedisError> for AppError {
     fn from(err: RedisError) -> Self {
         use redis::ErrorKind;
+        let unavailable = err.is_io_error()
+            || err.is_connection_refusal()
+            || err.is_timeout()
+            || matches!(
+                err.kind(),
+                ErrorKind::BusyLoadingError | ErrorKind::AuthenticationFailed
+            );
+        let category = if unavailable {
+            ErrorCategory::Dependency
+        } else {
+            ErrorCategory::Internal
+        };
         let code = match err.kind() {
             ErrorKind::ResponseError => 500,        // Redis响应错误
             ErrorKind::AuthenticationFailed => 401, // 认证失败
@@ -98,7 +289,7 @@ impl From<RedisError> for AppError {
             format!("{}", err)
         };
 
-        AppError { code, message }
+        AppError::new(category, code, message)
     }
 }
 
@@ -128,9 +319,34 @@ impl From<MongoError> for AppError {
             _ => 500,                                       // 其他未知错误
         };
 
-        AppError {
-            code,
-            message: err.to_string(),
-        }
+        let category = match code {
+            // 认证错误是服务端连接 MongoDB 的凭证问题，不是调用方未登录
+            401 | 503 => ErrorCategory::Dependency,
+            _ => ErrorCategory::from_code(code),
+        };
+
+        AppError::new(category, code, err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_category_from_code() {
+        assert_eq!(ErrorCategory::from_code(404), ErrorCategory::NotFound);
+        assert_eq!(ErrorCategory::from_code(422), ErrorCategory::Validation);
+        assert_eq!(ErrorCategory::from_code(503), ErrorCategory::Dependency);
+        assert_eq!(ErrorCategory::from_code(500), ErrorCategory::Internal);
+        assert_eq!(ErrorCategory::from_code(1004), ErrorCategory::Validation);
+
+        let err = AppError::from(DbErr::RecordNotFound("user".to_string()));
+        assert!(matches!(err, AppError::NotFound { code: 404, .. }));
+        assert_eq!(err.category(), ErrorCategory::NotFound);
+        assert_eq!(
+            AppError::dependency("down").into_response().status(),
+            StatusCode::SERVICE_UNAVAILABLE
+        );
     }
 }
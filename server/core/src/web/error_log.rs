@@ -0,0 +1,59 @@
+use std::collections::VecDeque;
+
+use chrono::{Local, NaiveDateTime};
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// 最多保留的日志条数，超过后丢弃最早的记录
+const CAPACITY: usize = 500;
+
+static RECENT: Lazy<Mutex<VecDeque<ErrorLogEntry>>> =
+    Lazy::new(|| Mutex::new(VecDeque::with_capacity(CAPACITY)));
+
+/// 本实例最近的告警和错误日志，用于生成诊断包
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ErrorLogEntry {
+    pub timestamp: NaiveDateTime,
+    pub level: String,
+    pub target: String,
+    pub message: String,
+}
+
+/// 记录一条日志，由日志订阅层调用，写入前应已完成脱敏
+pub fn record(level: impl Into<String>, target: impl Into<String>, message: impl Into<String>) {
+    let mut recent = RECENT.lock();
+    if recent.len() == CAPACITY {
+        recent.pop_front();
+    }
+    recent.push_back(ErrorLogEntry {
+        timestamp: Local::now().naive_local(),
+        level: level.into(),
+        target: target.into(),
+        message: message.into(),
+    });
+}
+
+/// 最近的日志，按时间从早到晚排列
+pub fn recent_errors() -> Vec<ErrorLogEntry> {
+    RECENT.lock().iter().cloned().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_keeps_latest() {
+        for i in 0..CAPACITY + 10 {
+            record("ERROR", "test", format!("error {}", i));
+        }
+        let recent = recent_errors();
+        assert_eq!(recent.len(), CAPACITY);
+        assert_eq!(
+            recent.last().unwrap().message,
+            format!("error {}", CAPACITY + 9)
+        );
+    }
+}
@@ -0,0 +1,152 @@
+use std::{collections::HashSet, sync::Arc};
+
+use axum::{
+    body::{to_bytes, Body},
+    http::header,
+    middleware::Next,
+    response::Response,
+};
+use http::Request;
+use serde_json::{Map, Value};
+use server_config::FieldAccessConfig;
+
+use crate::web::{auth::User, res::ErrorCode};
+
+/// 查询参数名，如 `?fields=id,userName,status`
+pub const FIELDS_PARAM: &str = "fields";
+
+/// 请求的字段，未携带 `fields` 参数或参数为空时返回 `None`
+fn requested_fields(query: Option<&str>) -> Option<HashSet<String>> {
+    let fields: HashSet<String> = form_urlencoded::parse(query?.as_bytes())
+        .filter(|(key, _)| key == FIELDS_PARAM)
+        .flat_map(|(_, value)| {
+            value
+                .split(',')
+                .map(|field| field.trim().to_string())
+                .collect::<Vec<_>>()
+        })
+        .filter(|field| !field.is_empty())
+        .collect();
+    (!fields.is_empty()).then_some(fields)
+}
+
+fn trim_object(object: &mut Map<String, Value>, fields: Option<&HashSet<String>>, hidden: &[&str]) {
+    object.retain(|key, _| {
+        fields.is_none_or(|fields| fields.contains(key)) && !hidden.contains(&key.as_str())
+    });
+}
+
+/// 裁剪响应中的 `data`：分页结果裁剪 `records` 中的每条记录，列表裁剪每个元素，对象裁剪自身
+fn trim_data(data: &mut Value, fields: Option<&HashSet<String>>, hidden: &[&str]) {
+    match data {
+        Value::Object(object) if object.contains_key("records") && object.contains_key("total") => {
+            if let Some(Value::Array(records)) = object.get_mut("records") {
+                for record in records {
+                    if let Value::Object(record) = record {
+                        trim_object(record, fields, hidden);
+                    }
+                }
+            }
+        },
+        Value::Object(object) => trim_object(object, fields, hidden),
+        Value::Array(items) => {
+            for item in items {
+                if let Value::Object(item) = item {
+                    trim_object(item, fields, hidden);
+                }
+            }
+        },
+        _ => {},
+    }
+}
+
+/// 字段裁剪中间件，须位于 JWT 鉴权内层以读取当前用户的角色
+///
+/// 携带 `fields` 参数时只返回请求的顶层字段，减少移动端的响应体积；
+/// 字段权限规则中当前用户不能查看的字段始终移除。失败响应和非 JSON 响应原样返回
+pub async fn field_selection_middleware(
+    config: Arc<FieldAccessConfig>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let fields = requested_fields(req.uri().query());
+    let roles = req
+        .extensions()
+        .get::<User>()
+        .map(User::subject)
+        .unwrap_or_default();
+    let hidden: Vec<String> = config
+        .hidden_fields(req.uri().path(), &roles)
+        .into_iter()
+        .map(str::to_string)
+        .collect();
+    if fields.is_none() && hidden.is_empty() {
+        return next.run(req).await;
+    }
+
+    let response = next.run(req).await;
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("application/json"));
+    if !is_json || response.extensions().get::<ErrorCode>().is_some() {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let bytes = match to_bytes(body, usize::MAX).await {
+        Ok(bytes) => bytes,
+        Err(e) => {
+            tracing::error!("Failed to read response body for field selection: {}", e);
+            return Response::from_parts(parts, Body::empty());
+        },
+    };
+    let mut value: Value = match serde_json::from_slice(&bytes) {
+        Ok(value) => value,
+        Err(_) => return Response::from_parts(parts, Body::from(bytes)),
+    };
+
+    if let Some(data) = value.get_mut("data") {
+        let hidden: Vec<&str> = hidden.iter().map(String::as_str).collect();
+        trim_data(data, fields.as_ref(), &hidden);
+    }
+    let body = serde_json::to_vec(&value).unwrap_or_else(|_| bytes.to_vec());
+    parts.headers.remove(header::CONTENT_LENGTH);
+    Response::from_parts(parts, Body::from(body))
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_trim_page_records() {
+        let fields = requested_fields(Some("current=1&fields=id,%20userName,phoneNumber"));
+        let mut data = json!({
+            "records": [
+                { "id": "1", "userName": "soybean", "phoneNumber": "100", "status": "1" }
+            ],
+            "total": 1,
+            "page": 1,
+        });
+
+        trim_data(&mut data, fields.as_ref(), &["phoneNumber"]);
+        assert_eq!(
+            data["records"][0],
+            json!({ "id": "1", "userName": "soybean" })
+        );
+        assert_eq!(data["total"], 1);
+    }
+
+    #[test]
+    fn test_hidden_fields_without_selection() {
+        assert!(requested_fields(Some("fields=")).is_none());
+
+        let mut data = json!({ "id": "1", "email": "a@b.c" });
+        trim_data(&mut data, None, &["email"]);
+        assert_eq!(data, json!({ "id": "1" }));
+    }
+}
@@ -1,7 +1,8 @@
 use std::{error::Error, fmt};
 
 use chrono::{Duration, Utc};
-use jsonwebtoken::{decode, encode, Header, TokenData};
+use jsonwebtoken::{decode, encode, Header, TokenData, Validation};
+use serde::{Deserialize, Serialize};
 use server_config::JwtConfig;
 use server_global::global;
 use ulid::Ulid;
@@ -44,10 +45,88 @@ impl fmt::Display for JwtError {
 
 impl Error for JwtError {}
 
+/// 令牌类型
+///
+/// 未配置 `refresh_secret` 时访问令牌和刷新令牌使用同一密钥签名，验证时按类型区分，
+/// 两种令牌不能互相替代
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenType {
+    Access,
+    Refresh,
+}
+
+/// 令牌类型与期望不符时返回验证错误
+fn expect_type(typ: TokenType, expected: TokenType) -> Result<(), JwtError> {
+    if typ == expected {
+        Ok(())
+    } else {
+        Err(JwtError::TokenValidationError(format!(
+            "expected {:?} token, got {:?}",
+            expected, typ
+        )))
+    }
+}
+
+/// 刷新令牌的声明
+///
+/// 只记录换发访问令牌所需的会话信息，角色等在刷新时重新读取。缺少 `username` 和 `role`，
+/// 不能作为访问令牌通过验证
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RefreshClaims {
+    pub sub: String,
+    pub aud: String,
+    pub domain: String,
+    /// 原始登录的认证时间，刷新不视为重新认证
+    pub auth_time: usize,
+    #[serde(default)]
+    pub amr: Vec<String>,
+    /// 本次登录已刷新的次数
+    #[serde(default)]
+    pub refresh_count: u32,
+
+    exp: Option<usize>,
+    iss: Option<String>,
+    iat: Option<usize>,
+    jti: Option<String>,
+    typ: TokenType,
+}
+
+impl RefreshClaims {
+    pub fn new(
+        sub: String,
+        aud: String,
+        domain: String,
+        auth_time: usize,
+        amr: Vec<String>,
+        refresh_count: u32,
+    ) -> Self {
+        Self {
+            sub,
+            aud,
+            domain,
+            auth_time,
+            amr,
+            refresh_count,
+            exp: None,
+            iss: None,
+            iat: None,
+            jti: None,
+            typ: TokenType::Refresh,
+        }
+    }
+}
+
 pub struct JwtUtils;
 
 impl JwtUtils {
     pub async fn generate_token(claims: &Claims) -> Result<String, JwtError> {
+        let jwt_config = global::get_config::<JwtConfig>().await.unwrap();
+        Self::generate_token_with_ttl(claims, jwt_config.expire).await
+    }
+
+    /// 生成指定有效期（秒）的令牌，用于紧急访问等不使用默认有效期的会话
+    pub async fn generate_token_with_ttl(claims: &Claims, ttl: i64) -> Result<String, JwtError> {
         let keys_arc = global::KEYS.get().ok_or(JwtError::KeysNotInitialized)?;
 
         let keys = keys_arc.lock().await;
@@ -57,13 +136,16 @@ impl JwtUtils {
         let now = Utc::now();
         let timestamp = now.timestamp() as usize;
         let jwt_config = global::get_config::<JwtConfig>().await.unwrap();
-        claims_clone.set_exp((now + Duration::seconds(jwt_config.expire)).timestamp() as usize);
+        claims_clone.set_exp((now + Duration::seconds(ttl)).timestamp() as usize);
         claims_clone.set_iss(jwt_config.issuer.to_string());
         claims_clone.set_iat(timestamp);
-        claims_clone.set_nbf(timestamp);
+        claims_clone.set_nbf(not_before(timestamp, jwt_config.nbf_offset));
         claims_clone.set_jti(Ulid::new().to_string());
+        if claims_clone.auth_time().is_none() {
+            claims_clone.set_auth_time(timestamp);
+        }
 
-        let token = encode(&Header::default(), &claims_clone, &keys.encoding)
+        let token = encode(&Header::new(keys.algorithm), &claims_clone, &keys.encoding)
             .map_err(|e| JwtError::TokenCreationError(e.to_string()));
 
         if let Ok(ref tok) = token {
@@ -73,6 +155,45 @@ impl JwtUtils {
         token
     }
 
+    /// 签发刷新令牌，有效期为 `refresh_expire`，使用 `REFRESH_KEYS` 签名
+    pub async fn generate_refresh_token(claims: &RefreshClaims) -> Result<String, JwtError> {
+        let keys_arc = global::REFRESH_KEYS
+            .get()
+            .ok_or(JwtError::KeysNotInitialized)?;
+        let keys = keys_arc.lock().await;
+
+        let jwt_config = global::get_config::<JwtConfig>().await.unwrap();
+        let now = Utc::now();
+        let mut claims_clone = claims.clone();
+        claims_clone.exp =
+            Some((now + Duration::seconds(jwt_config.refresh_expire)).timestamp() as usize);
+        claims_clone.iss = Some(jwt_config.issuer.to_string());
+        claims_clone.iat = Some(now.timestamp() as usize);
+        claims_clone.jti = Some(Ulid::new().to_string());
+
+        encode(&Header::new(keys.algorithm), &claims_clone, &keys.encoding)
+            .map_err(|e| JwtError::TokenCreationError(e.to_string()))
+    }
+
+    pub async fn validate_refresh_token(
+        token: &str,
+        audience: &str,
+    ) -> Result<RefreshClaims, JwtError> {
+        let keys_arc = global::REFRESH_KEYS
+            .get()
+            .ok_or(JwtError::KeysNotInitialized)?;
+        let keys = keys_arc.lock().await;
+
+        // 沿用访问令牌的签发者、偏差容忍和受众设置，签名算法以刷新令牌的密钥为准
+        let mut validation = Self::validation_for(audience).await?;
+        validation.algorithms = vec![keys.algorithm];
+        let claims = decode::<RefreshClaims>(token, &keys.decoding, &validation)
+            .map(|data| data.claims)
+            .map_err(|e| JwtError::TokenValidationError(e.to_string()))?;
+        expect_type(claims.typ, TokenType::Refresh)?;
+        Ok(claims)
+    }
+
     pub async fn validate_token(
         token: &str,
         audience: &str,
@@ -80,14 +201,77 @@ impl JwtUtils {
         let keys_arc = global::KEYS.get().ok_or(JwtError::KeysNotInitialized)?;
 
         let keys = keys_arc.lock().await;
+        let validation = Self::validation_for(audience).await?;
+
+        let data = decode::<Claims>(token, &keys.decoding, &validation)
+            .map_err(|e| JwtError::TokenValidationError(e.to_string()))?;
+        expect_type(data.claims.typ(), TokenType::Access)?;
+        Ok(data)
+    }
+
+    /// 全局校验规则加上路由分组的受众，配置的额外受众同样接受
+    async fn validation_for(audience: &str) -> Result<Validation, JwtError> {
         let validation_arc = global::VALIDATION
             .get()
             .ok_or(JwtError::ValidationNotInitialized)?;
-        let validation = validation_arc.lock().await;
+        let mut validation = validation_arc.lock().await.clone();
+        add_audience(&mut validation, audience);
+        Ok(validation)
+    }
+}
+
+/// 令牌的生效时间：签发时间之后再过 `nbf_offset` 秒，校验时仍容忍 `leeway_seconds` 的偏差
+fn not_before(issued_at: usize, nbf_offset: u64) -> usize {
+    issued_at.saturating_add(nbf_offset as usize)
+}
+
+/// 在配置的额外受众之外接受指定受众
+fn add_audience(validation: &mut Validation, audience: &str) {
+    let mut audiences = validation.aud.take().unwrap_or_default();
+    audiences.insert(audience.to_string());
+    validation.aud = Some(audiences);
+}
+
+#[cfg(test)]
+mod tests {
+    use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey};
+    use serde_json::{json, Value};
+
+    use super::*;
+
+    fn token(aud: &str) -> String {
+        let claims = json!({ "sub": "alice", "aud": aud, "exp": 4_102_444_800u64 });
+        encode(
+            &Header::default(),
+            &claims,
+            &EncodingKey::from_secret(b"secret"),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_not_before() {
+        assert_eq!(not_before(1_700_000_000, 0), 1_700_000_000);
+        assert_eq!(not_before(1_700_000_000, 30), 1_700_000_030);
+    }
+
+    #[test]
+    fn test_add_audience() {
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_audience(&["shared"]);
+        add_audience(&mut validation, "management");
+
+        let key = DecodingKey::from_secret(b"secret");
+        for aud in ["management", "shared"] {
+            assert!(decode::<Value>(&token(aud), &key, &validation).is_ok());
+        }
+        assert!(decode::<Value>(&token("mobile"), &key, &validation).is_err());
 
-        let mut validation_clone = validation.clone();
-        validation_clone.set_audience(&[audience.to_string()]);
-        decode::<Claims>(token, &keys.decoding, &validation_clone)
-            .map_err(|e| JwtError::TokenValidationError(e.to_string()))
+        // 未配置额外受众时只接受路由分组的受众
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.aud = None;
+        add_audience(&mut validation, "management");
+        assert!(decode::<Value>(&token("management"), &key, &validation).is_ok());
+        assert!(decode::<Value>(&token("shared"), &key, &validation).is_err());
     }
 }
@@ -1,12 +1,34 @@
+pub mod access_log;
+pub mod admission;
 pub mod auth;
+pub mod bot_guard;
+pub mod break_glass;
+#[cfg(feature = "chaos")]
+pub mod chaos;
+pub mod drain;
+pub mod environment_guard;
 pub mod error;
+pub mod error_log;
+pub mod field_selection;
 pub mod jwt;
+pub mod module_gate;
 pub mod page;
+pub mod personal_token;
+pub mod policy_gate;
+pub mod query_counter;
+pub mod recorder;
+pub mod request_limits;
 pub mod res;
+pub mod rls;
+pub mod statement_cache;
+pub mod step_up;
+pub mod telemetry;
+pub mod tenant_gate;
+pub mod usage;
 pub mod util;
 pub mod validator;
 
-pub use request_id::{RequestId, RequestIdLayer};
+pub use request_id::{trace_context_middleware, RequestId, RequestIdLayer};
 
 pub mod operation_log;
 mod request_id;
@@ -0,0 +1,70 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use serde::Serialize;
+
+use crate::web::{error::AppError, res::Res};
+
+/// 模块已停用时返回的业务码
+pub const MODULE_DISABLED_CODE: u16 = 10501;
+
+/// 返回给前端的停用说明
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleDisabled {
+    /// 固定为 `featureDisabled`，前端据此隐藏或提示功能不可用
+    pub reason: &'static str,
+    pub module: String,
+}
+
+/// 查询请求路径所属的模块是否停用，由模块服务实现
+#[async_trait]
+pub trait ModuleStatusChecker: Send + Sync {
+    /// 路径所属模块已停用时返回模块编码，不属于可停用模块或模块启用时返回 `None`
+    async fn disabled_module(&self, path: &str) -> Result<Option<String>, AppError>;
+}
+
+/// 模块开关中间件，包裹全部路由，停用模块的路由在鉴权之前返回 404
+///
+/// 查询失败时放行并记录日志，避免参数表不可用时所有模块都被关闭
+pub async fn module_gate_middleware(
+    checker: Arc<dyn ModuleStatusChecker>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let module = match checker.disabled_module(req.uri().path()).await {
+        Ok(Some(module)) => module,
+        Ok(None) => return next.run(req).await,
+        Err(e) => {
+            tracing::error!(error = %e.message(), "Failed to check module status");
+            return next.run(req).await;
+        },
+    };
+
+    tracing::info!(
+        target: "metrics",
+        event = "module_request_rejected",
+        module = %module,
+        path = %req.uri().path(),
+    );
+    (
+        StatusCode::NOT_FOUND,
+        Res {
+            code: MODULE_DISABLED_CODE,
+            msg: format!("Module '{}' is disabled", module),
+            data: Some(ModuleDisabled {
+                reason: "featureDisabled",
+                module,
+            }),
+            success: false,
+        },
+    )
+        .into_response()
+}
@@ -27,14 +27,91 @@ const USER_AGENT_HEADER: &str = "user-agent";
 const UNKNOWN_REQUEST_ID: &str = "unknown";
 const DEFAULT_BODY_CAPACITY: usize = 1024 * 16; // 16KB 默认缓冲区大小
 
+/// 操作日志的记录粒度
+///
+/// 路由通过 `.layer(AuditVerbosity::Metadata)` 声明，声明写入响应扩展，由操作日志中间件读取；
+/// 未声明时使用 `OperationLogLayer` 的默认粒度
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum AuditVerbosity {
+    /// 不记录
+    None,
+    /// 只记录请求方法、地址、查询参数、用户和耗时，不记录请求体和响应
+    Metadata,
+    /// 同时记录请求体和响应
+    #[default]
+    FullBody,
+}
+
+impl<S> Layer<S> for AuditVerbosity {
+    type Service = AuditVerbosityService<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        AuditVerbosityService {
+            inner: service,
+            verbosity: *self,
+        }
+    }
+}
+
+/// 把路由声明的记录粒度写入响应扩展
+#[derive(Clone)]
+pub struct AuditVerbosityService<S> {
+    inner: S,
+    verbosity: AuditVerbosity,
+}
+
+impl<S> Service<Request<Body>> for AuditVerbosityService<S>
+where
+    S: Service<Request<Body>, Response = Response<Body>> + Send + 'static,
+    S::Future: Send + 'static,
+{
+    type Error = S::Error;
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+    type Response = Response<Body>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: Request<Body>) -> Self::Future {
+        let verbosity = self.verbosity;
+        let future = self.inner.call(req);
+        Box::pin(async move {
+            let mut response = future.await?;
+            response.extensions_mut().insert(verbosity);
+            Ok(response)
+        })
+    }
+}
+
 #[derive(Clone)]
 pub struct OperationLogLayer {
     pub enabled: bool,
+    /// 日志中的模块名和描述
+    pub module: Option<(String, String)>,
+    /// 路由未声明记录粒度时使用
+    pub verbosity: AuditVerbosity,
 }
 
 impl OperationLogLayer {
     pub fn new(enabled: bool) -> Self {
-        Self { enabled }
+        Self {
+            enabled,
+            module: None,
+            verbosity: AuditVerbosity::default(),
+        }
+    }
+
+    /// 设置日志中的模块名和描述
+    pub fn with_module(mut self, module_name: &str, description: &str) -> Self {
+        self.module = Some((module_name.to_string(), description.to_string()));
+        self
+    }
+
+    /// 设置默认的记录粒度
+    pub fn with_verbosity(mut self, verbosity: AuditVerbosity) -> Self {
+        self.verbosity = verbosity;
+        self
     }
 }
 
@@ -51,6 +128,8 @@ where
         OperationLogMiddleware {
             inner: service,
             enabled: self.enabled,
+            module: self.module.clone(),
+            verbosity: self.verbosity,
         }
     }
 }
@@ -59,6 +138,8 @@ where
 pub struct OperationLogMiddleware<S> {
     inner: S,
     enabled: bool,
+    module: Option<(String, String)>,
+    verbosity: AuditVerbosity,
 }
 
 impl<S> Service<Request<Body>> for OperationLogMiddleware<S>
@@ -84,6 +165,11 @@ where
         }
 
         let mut inner = self.inner.clone();
+        let (module_name, description) = self
+            .module
+            .clone()
+            .unwrap_or_else(|| ("TODO".to_string(), "TODO".to_string()));
+        let default_verbosity = self.verbosity;
         Box::pin(async move {
             let start_time = Local::now().naive_local();
             let (parts, body) = req.into_parts();
@@ -107,10 +193,20 @@ where
                 let req = Request::from_parts(parts, Body::from(bytes.clone()));
                 let response = inner.call(req).await?;
 
+                let verbosity = response
+                    .extensions()
+                    .get::<AuditVerbosity>()
+                    .copied()
+                    .unwrap_or(default_verbosity);
+                if verbosity == AuditVerbosity::None {
+                    return Ok(response);
+                }
+
                 let (response_parts, response_body) = response.into_parts();
                 let response_bytes = to_bytes(response_body, usize::MAX)
                     .await
                     .unwrap_or_default();
+                let full_body = verbosity == AuditVerbosity::FullBody;
 
                 let end_time = Local::now().naive_local();
                 let duration = (end_time - start_time).num_milliseconds() as i32;
@@ -119,24 +215,27 @@ where
                     user_id,
                     username,
                     domain,
-                    module_name: "TODO".to_string(),
-                    description: "TODO".to_string(),
+                    module_name,
+                    description,
                     request_id,
                     method,
                     url: uri,
                     ip,
                     user_agent,
                     params,
-                    body: (!bytes.is_empty())
+                    body: (full_body && !bytes.is_empty())
                         .then(|| serde_json::from_slice(&bytes).ok())
                         .flatten(),
-                    response: serde_json::from_slice(&response_bytes).ok(),
+                    response: full_body
+                        .then(|| serde_json::from_slice(&response_bytes).ok())
+                        .flatten(),
                     start_time,
                     end_time,
                     duration,
                     created_at: start_time,
                 };
 
+                OperationLogContext::set(context.clone()).await;
                 global::send_dyn_event(
                     SystemEvent::AuditOperationLoggedEvent.as_ref(),
                     Box::new(context),
@@ -317,6 +416,9 @@ mod tests {
             .unwrap()
     }
 
+    /// 操作日志上下文是全局的，读写它的测试串行执行
+    static CONTEXT_LOCK: tokio::sync::Mutex<()> = tokio::sync::Mutex::const_new(());
+
     /// 验证上下文
     async fn assert_context(method: &str, uri: &str, params: Option<Value>, body: Option<Value>) {
         let ctx = OperationLogContext::get()
@@ -382,6 +484,7 @@ mod tests {
             ),
         ];
 
+        let _guard = CONTEXT_LOCK.lock().await;
         let service = tower::service_fn(|_req: Request<Body>| async move {
             Ok::<_, Infallible>(Response::new(Body::from("ok")))
         });
@@ -397,20 +500,23 @@ mod tests {
             }
 
             let mut middleware = OperationLogMiddleware {
-                inner: service.clone(),
+                inner: service,
                 enabled: true,
+                module: None,
+                verbosity: AuditVerbosity::FullBody,
             };
 
             let request = create_request(method.clone(), uri, body.clone());
             let _ = middleware.call(request).await.unwrap();
 
-            assert_context(&method.to_string(), uri, params, body).await;
+            assert_context(method.as_ref(), uri, params, body).await;
         }
     }
 
     #[tokio::test]
     async fn test_operation_log_error_cases() {
         println!("\n=== Testing Error Cases ===");
+        let _guard = CONTEXT_LOCK.lock().await;
 
         let service = tower::service_fn(|req: Request<Body>| async move {
             // 检查请求体大小
@@ -444,8 +550,10 @@ mod tests {
             println!("\n▶ 测试错误场景: {:?}", expected_status);
 
             let mut middleware = OperationLogMiddleware {
-                inner: service.clone(),
+                inner: service,
                 enabled: true,
+                module: None,
+                verbosity: AuditVerbosity::FullBody,
             };
 
             let mut request = create_request(method, uri, body);
@@ -465,6 +573,7 @@ mod tests {
     #[tokio::test]
     async fn test_disabled_middleware() {
         println!("\n=== Testing Disabled Middleware ===");
+        let _guard = CONTEXT_LOCK.lock().await;
         OperationLogContext::clear().await;
 
         let service = tower::service_fn(|_req: Request<Body>| async move {
@@ -474,6 +583,8 @@ mod tests {
         let mut middleware = OperationLogMiddleware {
             inner: service,
             enabled: false,
+            module: None,
+            verbosity: AuditVerbosity::FullBody,
         };
 
         let request = create_request(Method::POST, "/test", Some(json!({"test": true})));
@@ -482,4 +593,22 @@ mod tests {
         assert_eq!(response.status(), StatusCode::OK);
         assert!(OperationLogContext::get().await.is_none());
     }
+
+    #[tokio::test]
+    async fn test_route_declares_verbosity() {
+        let service = tower::service_fn(|_req: Request<Body>| async move {
+            Ok::<_, Infallible>(Response::new(Body::from("ok")))
+        });
+
+        let mut service = AuditVerbosity::Metadata.layer(service);
+        let response = service
+            .call(create_request(Method::GET, "/test", None))
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.extensions().get::<AuditVerbosity>(),
+            Some(&AuditVerbosity::Metadata)
+        );
+    }
 }
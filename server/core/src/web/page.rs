@@ -1,4 +1,7 @@
 use serde::{de::Error as DeError, Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+use crate::web::error::AppError;
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct PageRequest {
@@ -39,10 +42,135 @@ impl Default for PageRequest {
     }
 }
 
+/// 查询参数 `include`，请求在响应中嵌入的关联数据，如 `include=roles,organization`
+///
+/// 各接口声明自己支持的关联，服务层按页批量加载，不逐条查询
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct IncludeRequest {
+    #[serde(default)]
+    pub include: Option<String>,
+}
+
+impl IncludeRequest {
+    /// 请求的关联，不在 `allowed` 中的关联返回校验错误
+    pub fn relations(&self, allowed: &[&str]) -> Result<Vec<String>, AppError> {
+        let relations: Vec<String> = self
+            .include
+            .as_deref()
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|relation| !relation.is_empty())
+            .map(str::to_string)
+            .collect();
+        if let Some(unknown) = relations
+            .iter()
+            .find(|relation| !allowed.contains(&relation.as_str()))
+        {
+            return Err(AppError::validation(format!(
+                "Unsupported include '{}', expected one of: {}",
+                unknown,
+                allowed.join(", ")
+            )));
+        }
+        Ok(relations)
+    }
+}
+
+/// 分页列表的统一响应结构，各模块的列表接口都返回此结构，前端表格组件按同一方式读取
 #[derive(Debug, Serialize, Default)]
-pub struct PaginatedData<T> {
-    pub current: u64,
-    pub size: u64,
-    pub total: u64,
+#[serde(rename_all = "camelCase")]
+pub struct PageResult<T> {
     pub records: Vec<T>,
+    pub total: u64,
+    pub page: u64,
+    pub page_size: u64,
+    pub has_next: bool,
+    /// 列表的汇总数据，如金额合计、按状态计数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<Value>,
+    /// 按 `include` 参数嵌入的关联数据，键为关联名，值为记录 ID 到关联数据的映射
+    #[serde(skip_serializing_if = "Map::is_empty")]
+    pub included: Map<String, Value>,
+}
+
+impl<T> PageResult<T> {
+    pub fn new(request: &PageRequest, total: u64, records: Vec<T>) -> Self {
+        Self {
+            records,
+            total,
+            page: request.current,
+            page_size: request.size,
+            has_next: request.current.saturating_mul(request.size) < total,
+            summary: None,
+            included: Map::new(),
+        }
+    }
+
+    /// 嵌入一种关联数据
+    pub fn with_included<S: Serialize>(mut self, relation: &str, data: S) -> Self {
+        if let Ok(data) = serde_json::to_value(data) {
+            self.included.insert(relation.to_string(), data);
+        }
+        self
+    }
+
+    /// 附加汇总数据，序列化失败时不附加
+    pub fn with_summary<S: Serialize>(mut self, summary: S) -> Self {
+        self.summary = serde_json::to_value(summary).ok();
+        self
+    }
+
+    /// 转换记录类型，分页信息和汇总数据保持不变
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> PageResult<U> {
+        PageResult {
+            records: self.records.into_iter().map(f).collect(),
+            total: self.total,
+            page: self.page,
+            page_size: self.page_size,
+            has_next: self.has_next,
+            summary: self.summary,
+            included: self.included,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_page_result_has_next() {
+        let request = PageRequest {
+            current: 2,
+            size: 10,
+        };
+        assert!(PageResult::new(&request, 21, vec![0; 10]).has_next);
+        assert!(!PageResult::new(&request, 20, vec![0; 10]).has_next);
+
+        let value = serde_json::to_value(
+            PageResult::new(&request, 21, vec![1]).with_summary(serde_json::json!({ "sum": 1 })),
+        )
+        .unwrap();
+        assert_eq!(value["pageSize"], 10);
+        assert_eq!(value["hasNext"], true);
+        assert_eq!(value["summary"]["sum"], 1);
+        assert!(value.get("included").is_none());
+    }
+
+    #[test]
+    fn test_include_relations() {
+        let request = IncludeRequest {
+            include: Some("roles, organization".to_string()),
+        };
+        assert_eq!(
+            request.relations(&["organization", "roles"]).unwrap(),
+            vec!["roles", "organization"]
+        );
+        assert!(request.relations(&["roles"]).is_err());
+        assert!(IncludeRequest::default()
+            .relations(&["roles"])
+            .unwrap()
+            .is_empty());
+    }
 }
@@ -0,0 +1,89 @@
+use async_trait::async_trait;
+use http::Method;
+
+use crate::web::{auth::User, error::AppError};
+
+/// 个人访问令牌的前缀，据此与 JWT 区分
+pub const PERSONAL_TOKEN_PREFIX: &str = "sbp_";
+
+/// 个人访问令牌认证的 `amr`
+pub const PERSONAL_TOKEN_AUTH_METHOD: &str = "pat";
+
+/// 令牌的授权范围不包含当前请求时返回的业务码
+pub const PERSONAL_TOKEN_SCOPE_DENIED_CODE: u16 = 10601;
+
+/// 令牌解析结果：令牌所属用户（角色为用户当前的角色）和令牌的授权范围
+#[derive(Debug, Clone)]
+pub struct PersonalTokenGrant {
+    pub user: User,
+    pub scopes: Vec<String>,
+}
+
+/// 解析个人访问令牌，由个人访问令牌服务实现
+#[async_trait]
+pub trait PersonalTokenResolver: Send + Sync {
+    /// 令牌不存在、已过期或所属用户不可用时返回 `None`
+    async fn resolve(
+        &self,
+        token: &str,
+        audience: &str,
+    ) -> Result<Option<PersonalTokenGrant>, AppError>;
+}
+
+pub fn is_personal_token(token: &str) -> bool {
+    token.starts_with(PERSONAL_TOKEN_PREFIX)
+}
+
+/// 路由分组，即路径的第一段，与遥测统计的分组一致
+fn route_group(path: &str) -> &str {
+    path.trim_start_matches('/').split('/').next().unwrap_or("")
+}
+
+/// 授权范围的格式是否合法：`<路由分组|*>:<read|write>`
+pub fn is_valid_scope(scope: &str) -> bool {
+    let Some((group, access)) = scope.split_once(':') else {
+        return false;
+    };
+    let group_valid = group == "*"
+        || (!group.is_empty()
+            && group
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_'));
+    group_valid && matches!(access, "read" | "write")
+}
+
+/// 授权范围是否允许该请求
+///
+/// 只读方法需要 `read` 或 `write`，其他方法需要 `write`；令牌只能缩小用户的权限，
+/// 接口本身的权限仍由 Casbin 按用户的角色校验
+pub fn scope_allows(scopes: &[String], method: &Method, path: &str) -> bool {
+    let read_only = matches!(*method, Method::GET | Method::HEAD | Method::OPTIONS);
+    let group = route_group(path);
+    scopes.iter().any(|scope| {
+        let Some((scope_group, access)) = scope.split_once(':') else {
+            return false;
+        };
+        (scope_group == "*" || scope_group == group)
+            && (access == "write" || (read_only && access == "read"))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_allows() {
+        let scopes = vec!["*:read".to_string(), "user:write".to_string()];
+        assert!(scope_allows(&scopes, &Method::GET, "/role/1"));
+        assert!(scope_allows(&scopes, &Method::PUT, "/user"));
+        assert!(!scope_allows(&scopes, &Method::DELETE, "/role/1"));
+        assert!(!scope_allows(&[], &Method::GET, "/user"));
+
+        assert!(is_valid_scope("api-usage:read"));
+        assert!(is_valid_scope("*:write"));
+        assert!(!is_valid_scope("user:admin"));
+        assert!(!is_valid_scope("User:read"));
+        assert!(!is_valid_scope("read"));
+    }
+}
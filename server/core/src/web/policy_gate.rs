@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use serde::Serialize;
+use server_config::PolicyGateConfig;
+
+use crate::web::{auth::User, break_glass::BREAK_GLASS_AUTH_METHOD, error::AppError, res::Res};
+
+/// 需要确认政策文档时返回的业务码
+pub const POLICY_ACCEPTANCE_REQUIRED_CODE: u16 = 10001;
+
+/// 查询和确认待确认文档的接口，始终放行
+const ACCEPTANCE_PATHS: &[&str] = &["/policy/pending", "/policy/accept"];
+
+/// 用户尚未确认的政策文档
+#[derive(Debug, Clone, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingPolicy {
+    pub document_id: String,
+    pub code: String,
+    pub version: i32,
+    pub title: String,
+}
+
+/// 返回给前端的确认要求
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PolicyAcceptanceRequired {
+    /// 固定为 `policyAcceptanceRequired`，前端据此跳转到确认页面
+    pub reason: &'static str,
+    pub policies: Vec<PendingPolicy>,
+}
+
+/// 查询用户未确认的政策文档，由政策服务实现
+#[async_trait]
+pub trait PolicyAcceptanceChecker: Send + Sync {
+    async fn pending_policies(&self, user: &User) -> Result<Vec<PendingPolicy>, AppError>;
+}
+
+fn is_exempt(config: &PolicyGateConfig, path: &str) -> bool {
+    ACCEPTANCE_PATHS.contains(&path) || config.is_exempt(path)
+}
+
+/// 政策文档确认中间件，须位于 JWT 鉴权内层以读取当前用户
+///
+/// 查询失败时放行并记录日志，避免政策表不可用时所有接口都被拦截
+pub async fn policy_gate_middleware(
+    config: Arc<PolicyGateConfig>,
+    checker: Arc<dyn PolicyAcceptanceChecker>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    if is_exempt(&config, req.uri().path()) {
+        return next.run(req).await;
+    }
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+    // 紧急访问会话不对应具体用户，无法确认政策
+    if user.authenticated_with(BREAK_GLASS_AUTH_METHOD) {
+        return next.run(req).await;
+    }
+
+    let policies = match checker.pending_policies(user).await {
+        Ok(policies) => policies,
+        Err(e) => {
+            tracing::error!(error = %e.message(), "Failed to check policy acceptance");
+            return next.run(req).await;
+        },
+    };
+    if policies.is_empty() {
+        return next.run(req).await;
+    }
+
+    tracing::info!(
+        target: "metrics",
+        event = "policy_acceptance_required",
+        path = %req.uri().path(),
+        user_id = %user.user_id(),
+    );
+    (
+        StatusCode::FORBIDDEN,
+        Res {
+            code: POLICY_ACCEPTANCE_REQUIRED_CODE,
+            data: Some(PolicyAcceptanceRequired {
+                reason: "policyAcceptanceRequired",
+                policies,
+            }),
+            msg: "Policy acceptance required".to_string(),
+            success: false,
+        },
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_acceptance_paths_are_exempt() {
+        let config = PolicyGateConfig {
+            enabled: true,
+            exempt_paths: vec!["/auth/getUserInfo".to_string()],
+            ..Default::default()
+        };
+
+        assert!(is_exempt(&config, "/policy/pending"));
+        assert!(is_exempt(&config, "/policy/accept"));
+        assert!(is_exempt(&config, "/auth/getUserInfo"));
+        assert!(!is_exempt(&config, "/user"));
+        // 文档管理接口不放行
+        assert!(!is_exempt(&config, "/policy"));
+    }
+}
@@ -0,0 +1,162 @@
+use std::{collections::HashMap, sync::Arc};
+
+use axum::{body::Body, extract::MatchedPath, middleware::Next, response::Response};
+use http::Request;
+use parking_lot::Mutex;
+
+use super::RequestId;
+
+tokio::task_local! {
+    static QUERY_COUNTS: Arc<Mutex<HashMap<String, usize>>>;
+}
+
+/// 记录一次数据库执行，只在查询计数中间件的作用域内统计
+pub(crate) fn record_query(sql: &str) {
+    let _ = QUERY_COUNTS.try_with(|counts| {
+        *counts.lock().entry(fingerprint(sql)).or_default() += 1;
+    });
+}
+
+/// 语句指纹：字面量和绑定参数替换为 `?`，`IN` 列表折叠为 `(...)`，空白合并
+///
+/// 逐行查询产生的语句只有参数不同，指纹相同即可归为同一条
+fn fingerprint(sql: &str) -> String {
+    let mut normalized = String::with_capacity(sql.len());
+    let mut chars = sql.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                // 字符串字面量，`''` 为转义的单引号
+                while let Some(c) = chars.next() {
+                    if c == '\'' {
+                        if chars.peek() == Some(&'\'') {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+                normalized.push('?');
+            },
+            '$' | '?' => {
+                while chars.peek().is_some_and(char::is_ascii_digit) {
+                    chars.next();
+                }
+                normalized.push('?');
+            },
+            c if c.is_ascii_digit()
+                && !normalized
+                    .chars()
+                    .next_back()
+                    .is_some_and(|last| last.is_alphanumeric() || last == '_') =>
+            {
+                while chars
+                    .peek()
+                    .is_some_and(|c| c.is_ascii_digit() || *c == '.')
+                {
+                    chars.next();
+                }
+                normalized.push('?');
+            },
+            c if c.is_whitespace() => {
+                if !normalized.ends_with(' ') {
+                    normalized.push(' ');
+                }
+            },
+            c => normalized.push(c),
+        }
+    }
+
+    let mut fingerprint = normalized.trim().to_string();
+    while let Some(start) = fingerprint.find("(?, ?") {
+        let end = fingerprint[start..]
+            .find(')')
+            .map_or(fingerprint.len(), |end| start + end + 1);
+        fingerprint.replace_range(start..end, "(...)");
+    }
+    fingerprint
+}
+
+/// 同一指纹执行次数超过阈值的语句，按次数从多到少排列
+fn repeated_statements(counts: &HashMap<String, usize>, threshold: usize) -> Vec<(&str, usize)> {
+    let mut repeated: Vec<(&str, usize)> = counts
+        .iter()
+        .filter(|(_, count)| **count > threshold)
+        .map(|(sql, count)| (sql.as_str(), *count))
+        .collect();
+    repeated.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+    repeated
+}
+
+/// N+1 查询检测中间件，仅在调试构建中挂载
+///
+/// 统计请求期间每种语句指纹的执行次数，超过阈值时记录告警，包含路由和重复的语句，
+/// 用于在上线前发现逐行查询关联数据的代码。不改变请求和响应
+pub async fn query_counter_middleware(
+    threshold: usize,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let method = req.method().clone();
+    let route = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| req.uri().path().to_string());
+    let request_id = req
+        .extensions()
+        .get::<RequestId>()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+
+    let counts = Arc::new(Mutex::new(HashMap::new()));
+    let response = QUERY_COUNTS.scope(counts.clone(), next.run(req)).await;
+
+    let counts = counts.lock();
+    for (sql, count) in repeated_statements(&counts, threshold) {
+        tracing::warn!(
+            request_id = %request_id,
+            "Possible N+1 query on {} {}: statement executed {} times (threshold {}): {}",
+            method,
+            route,
+            count,
+            threshold,
+            sql
+        );
+    }
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fingerprint_normalizes_parameters() {
+        assert_eq!(
+            fingerprint("SELECT \"id\" FROM \"sys_role\"\n  WHERE \"id\" = $1 LIMIT 1"),
+            "SELECT \"id\" FROM \"sys_role\" WHERE \"id\" = ? LIMIT ?"
+        );
+        assert_eq!(
+            fingerprint("SELECT * FROM t2 WHERE name = 'it''s' AND id IN ($1, $2, $3)"),
+            "SELECT * FROM t2 WHERE name = ? AND id IN (...)"
+        );
+        assert_eq!(
+            fingerprint("SELECT * FROM t WHERE id IN ($1, $2)"),
+            fingerprint("SELECT * FROM t WHERE id IN ($1, $2, $3, $4)")
+        );
+    }
+
+    #[test]
+    fn test_repeated_statements_over_threshold() {
+        let counts = HashMap::from([
+            ("SELECT a".to_string(), 12),
+            ("SELECT b".to_string(), 10),
+            ("SELECT c".to_string(), 30),
+        ]);
+        assert_eq!(
+            repeated_statements(&counts, 10),
+            vec![("SELECT c", 30), ("SELECT a", 12)]
+        );
+    }
+}
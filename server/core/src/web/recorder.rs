@@ -0,0 +1,598 @@
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::Instant,
+};
+
+use axum::{
+    body::{to_bytes, Body},
+    extract::MatchedPath,
+    http::{header, HeaderMap},
+    middleware::Next,
+    response::Response,
+};
+use chrono::{Local, NaiveDateTime};
+use http::{Request, Uri};
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use sea_orm::{metric::Info, DatabaseConnection};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use ulid::Ulid;
+
+use super::{auth::User, query_counter, statement_cache, RequestId};
+
+/// 脱敏后的占位值
+pub const REDACTED: &str = "***";
+
+/// 字段名（忽略大小写、`_` 和 `-`）包含以下片段时视为敏感字段
+const SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "passwd",
+    "secret",
+    "token",
+    "authorization",
+    "cookie",
+    "credential",
+    "apikey",
+    "accesskey",
+    "privatekey",
+    "signature",
+];
+
+/// 每个请求最多保留的不同 SQL 语句数
+const MAX_DB_STATEMENTS: usize = 20;
+/// 单条 SQL 最多保留的字符数
+const MAX_SQL_CHARS: usize = 500;
+/// 录制相关接口本身不录制，避免录制结果相互嵌套
+const RECORDER_ROUTE_PREFIX: &str = "/recorder";
+
+static ACTIVE: AtomicBool = AtomicBool::new(false);
+static RECORDER: Lazy<RwLock<RecorderState>> = Lazy::new(|| RwLock::new(RecorderState::default()));
+
+tokio::task_local! {
+    static DB_CAPTURE: Arc<Mutex<DbCapture>>;
+}
+
+/// 录制会话
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordingSession {
+    pub id: String,
+    /// 只录制该用户的请求
+    pub user_id: Option<String>,
+    /// 只录制请求 ID 匹配该模式的请求，支持 `*` 通配
+    pub request_id_pattern: Option<String>,
+    /// 最多保留的请求数
+    pub capacity: usize,
+    /// 单个请求/响应体最多记录的字节数
+    pub max_body_bytes: usize,
+    pub started_by: String,
+    pub started_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+impl RecordingSession {
+    fn matches(&self, user_id: Option<&str>, request_id: &str) -> bool {
+        let user_matched = self
+            .user_id
+            .as_deref()
+            .is_none_or(|expected| user_id == Some(expected));
+        let request_matched = self
+            .request_id_pattern
+            .as_deref()
+            .is_none_or(|pattern| wildcard_match(pattern, request_id));
+        user_matched && request_matched
+    }
+}
+
+/// 单条 SQL 语句的执行摘要
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbStatementSummary {
+    /// SQL 文本，参数以占位符表示，不含绑定值
+    pub sql: String,
+    pub count: usize,
+    pub failed: usize,
+    pub total_ms: f64,
+}
+
+/// 请求期间的数据库交互摘要
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbSummary {
+    pub statements: usize,
+    pub failed: usize,
+    pub total_ms: f64,
+    /// 按耗时排序的语句明细，最多保留 `MAX_DB_STATEMENTS` 条
+    pub top: Vec<DbStatementSummary>,
+}
+
+#[derive(Default)]
+struct DbCapture {
+    statements: HashMap<String, DbStatementSummary>,
+}
+
+impl DbCapture {
+    fn push(&mut self, sql: &str, elapsed_ms: f64, failed: bool) {
+        let sql: String = sql.chars().take(MAX_SQL_CHARS).collect();
+        let entry = self
+            .statements
+            .entry(sql.clone())
+            .or_insert_with(|| DbStatementSummary {
+                sql,
+                count: 0,
+                failed: 0,
+                total_ms: 0.0,
+            });
+        entry.count += 1;
+        entry.total_ms += elapsed_ms;
+        if failed {
+            entry.failed += 1;
+        }
+    }
+
+    fn summarize(&self) -> DbSummary {
+        let mut top: Vec<DbStatementSummary> = self.statements.values().cloned().collect();
+        top.sort_by(|a, b| b.total_ms.total_cmp(&a.total_ms));
+        let summary = DbSummary {
+            statements: top.iter().map(|statement| statement.count).sum(),
+            failed: top.iter().map(|statement| statement.failed).sum(),
+            total_ms: top.iter().map(|statement| statement.total_ms).sum(),
+            top: Vec::new(),
+        };
+        top.truncate(MAX_DB_STATEMENTS);
+        DbSummary { top, ..summary }
+    }
+}
+
+/// 录制的请求，敏感字段已脱敏
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordedTrace {
+    pub id: String,
+    pub session_id: String,
+    pub request_id: String,
+    pub user_id: Option<String>,
+    pub username: Option<String>,
+    pub method: String,
+    /// 路由模板
+    pub route: Option<String>,
+    /// 请求地址（路径和查询参数）
+    pub uri: String,
+    pub headers: BTreeMap<String, String>,
+    pub request_body: Option<Value>,
+    pub status: u16,
+    pub response_body: Option<Value>,
+    pub duration_ms: u64,
+    pub db: DbSummary,
+    pub recorded_at: NaiveDateTime,
+}
+
+/// 录制状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecorderStatus {
+    /// 进行中的会话，已停止或过期时为空
+    pub session: Option<RecordingSession>,
+    pub recorded: usize,
+    /// 超出容量被丢弃的请求数
+    pub dropped: u64,
+}
+
+#[derive(Default)]
+struct RecorderState {
+    session: Option<RecordingSession>,
+    traces: VecDeque<RecordedTrace>,
+    dropped: u64,
+}
+
+impl RecorderState {
+    /// 会话过期时自动停止
+    fn expire(&mut self) {
+        if self
+            .session
+            .as_ref()
+            .is_some_and(|session| session.expires_at <= Local::now().naive_local())
+        {
+            self.session = None;
+            ACTIVE.store(false, Ordering::Relaxed);
+        }
+    }
+
+    fn status(&self) -> RecorderStatus {
+        RecorderStatus {
+            session: self.session.clone(),
+            recorded: self.traces.len(),
+            dropped: self.dropped,
+        }
+    }
+}
+
+/// 开启录制会话，替换进行中的会话并清空之前的录制结果
+pub fn start_recording(session: RecordingSession) {
+    let mut state = RECORDER.write();
+    state.session = Some(session);
+    state.traces.clear();
+    state.dropped = 0;
+    ACTIVE.store(true, Ordering::Relaxed);
+}
+
+/// 停止录制，已录制的请求保留到下次开启或手动清空
+pub fn stop_recording() -> Option<RecordingSession> {
+    let mut state = RECORDER.write();
+    ACTIVE.store(false, Ordering::Relaxed);
+    state.session.take()
+}
+
+pub fn recorder_status() -> RecorderStatus {
+    let mut state = RECORDER.write();
+    state.expire();
+    state.status()
+}
+
+pub fn recorded_traces() -> Vec<RecordedTrace> {
+    RECORDER.read().traces.iter().cloned().collect()
+}
+
+/// 清空录制结果，返回清除的条数
+pub fn clear_recorded_traces() -> usize {
+    let mut state = RECORDER.write();
+    state.dropped = 0;
+    std::mem::take(&mut state.traces).len()
+}
+
+/// 为数据库连接注册执行回调，录制中的请求据此汇总数据库交互，调试构建据此检测 N+1 查询，
+/// 同时按 `statement_cache_capacity` 估算连接池的语句缓存命中率
+pub fn attach_db_recorder(
+    db: &mut DatabaseConnection,
+    pool: &str,
+    statement_cache_capacity: usize,
+) {
+    let statement_cache = statement_cache::register_pool(pool, statement_cache_capacity);
+    db.set_metric_callback(move |info| {
+        statement_cache.lock().observe(&info.statement.sql);
+        record_db_metric(info);
+    });
+}
+
+fn record_db_metric(info: &Info<'_>) {
+    query_counter::record_query(&info.statement.sql);
+    let _ = DB_CAPTURE.try_with(|capture| {
+        capture.lock().push(
+            &info.statement.sql,
+            info.elapsed.as_secs_f64() * 1000.0,
+            info.failed,
+        )
+    });
+}
+
+/// 判断请求是否需要录制，返回当前会话
+fn matching_session(req: &Request<Body>) -> Option<RecordingSession> {
+    if !ACTIVE.load(Ordering::Relaxed) || req.uri().path().starts_with(RECORDER_ROUTE_PREFIX) {
+        return None;
+    }
+
+    let user_id = req.extensions().get::<User>().map(User::user_id);
+    let request_id = req.extensions().get::<RequestId>()?.to_string();
+
+    let mut state = RECORDER.write();
+    state.expire();
+    state
+        .session
+        .as_ref()
+        .filter(|session| session.matches(user_id.as_deref(), &request_id))
+        .cloned()
+}
+
+/// 请求录制中间件，需位于鉴权和请求 ID 中间件内层
+///
+/// 只缓冲 JSON 和表单请求体以及 JSON 响应体，文件上传和下载不受影响
+pub async fn recorder_middleware(req: Request<Body>, next: Next) -> Response {
+    let Some(session) = matching_session(&req) else {
+        return next.run(req).await;
+    };
+
+    let started_at = Instant::now();
+    let (parts, body) = req.into_parts();
+    let user = parts.extensions.get::<User>().cloned();
+    let request_id = parts
+        .extensions
+        .get::<RequestId>()
+        .map(ToString::to_string)
+        .unwrap_or_default();
+    let route = parts
+        .extensions
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string());
+
+    let (body, request_body) = if captures_body(&parts.headers) {
+        match to_bytes(body, usize::MAX).await {
+            Ok(bytes) => {
+                let captured = capture_body(&parts.headers, &bytes, session.max_body_bytes);
+                (Body::from(bytes), captured)
+            },
+            Err(_) => (Body::empty(), None),
+        }
+    } else {
+        (body, None)
+    };
+
+    let method = parts.method.to_string();
+    let uri = redact_uri(&parts.uri);
+    let headers = redact_headers(&parts.headers);
+
+    let capture = Arc::new(Mutex::new(DbCapture::default()));
+    let response = DB_CAPTURE
+        .scope(capture.clone(), next.run(Request::from_parts(parts, body)))
+        .await;
+
+    let (response_parts, body) = response.into_parts();
+    let (body, response_body) = if is_json(&response_parts.headers) {
+        match to_bytes(body, usize::MAX).await {
+            Ok(bytes) => {
+                let captured =
+                    capture_body(&response_parts.headers, &bytes, session.max_body_bytes);
+                (Body::from(bytes), captured)
+            },
+            Err(_) => (Body::empty(), None),
+        }
+    } else {
+        (body, None)
+    };
+
+    let trace = RecordedTrace {
+        id: Ulid::new().to_string(),
+        session_id: session.id.clone(),
+        request_id,
+        user_id: user.as_ref().map(User::user_id),
+        username: user.as_ref().map(User::username),
+        method,
+        route,
+        uri,
+        headers,
+        request_body,
+        status: response_parts.status.as_u16(),
+        response_body,
+        duration_ms: started_at.elapsed().as_millis() as u64,
+        db: capture.lock().summarize(),
+        recorded_at: Local::now().naive_local(),
+    };
+    store_trace(&session, trace);
+
+    Response::from_parts(response_parts, body)
+}
+
+fn store_trace(session: &RecordingSession, trace: RecordedTrace) {
+    let mut state = RECORDER.write();
+    // 会话已被替换或停止时丢弃
+    if state.session.as_ref().map(|current| current.id.as_str()) != Some(session.id.as_str()) {
+        return;
+    }
+    while state.traces.len() >= session.capacity.max(1) {
+        state.traces.pop_front();
+        state.dropped += 1;
+    }
+    state.traces.push_back(trace);
+}
+
+fn content_type(headers: &HeaderMap) -> &str {
+    headers
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default()
+}
+
+fn is_json(headers: &HeaderMap) -> bool {
+    content_type(headers).starts_with(mime::APPLICATION_JSON.as_ref())
+}
+
+fn is_form(headers: &HeaderMap) -> bool {
+    content_type(headers).starts_with(mime::APPLICATION_WWW_FORM_URLENCODED.as_ref())
+}
+
+fn captures_body(headers: &HeaderMap) -> bool {
+    is_json(headers) || is_form(headers)
+}
+
+/// 解析并脱敏请求/响应体，超出长度限制时只记录长度
+fn capture_body(headers: &HeaderMap, bytes: &[u8], max_body_bytes: usize) -> Option<Value> {
+    if bytes.is_empty() {
+        return None;
+    }
+    if bytes.len() > max_body_bytes {
+        return Some(Value::String(format!("<{} bytes omitted>", bytes.len())));
+    }
+
+    if is_form(headers) {
+        let fields = form_urlencoded::parse(bytes)
+            .map(|(key, value)| (key.into_owned(), Value::String(value.into_owned())))
+            .collect::<Map<String, Value>>();
+        return Some(redact_json(Value::Object(fields)));
+    }
+
+    Some(match serde_json::from_slice(bytes) {
+        Ok(value) => redact_json(value),
+        Err(_) => Value::String(String::from_utf8_lossy(bytes).into_owned()),
+    })
+}
+
+fn is_sensitive(key: &str) -> bool {
+    let normalized: String = key
+        .chars()
+        .filter(|c| *c != '_' && *c != '-')
+        .flat_map(char::to_lowercase)
+        .collect();
+    SENSITIVE_KEYS
+        .iter()
+        .any(|sensitive| normalized.contains(sensitive))
+}
+
+/// 递归替换敏感字段的值
+pub fn redact_json(value: Value) -> Value {
+    match value {
+        Value::Object(fields) => Value::Object(
+            fields
+                .into_iter()
+                .map(|(key, value)| {
+                    let value = if is_sensitive(&key) {
+                        Value::String(REDACTED.to_string())
+                    } else {
+                        redact_json(value)
+                    };
+                    (key, value)
+                })
+                .collect(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(redact_json).collect()),
+        other => other,
+    }
+}
+
+fn redact_headers(headers: &HeaderMap) -> BTreeMap<String, String> {
+    headers
+        .iter()
+        .map(|(name, value)| {
+            let value = if is_sensitive(name.as_str()) {
+                REDACTED.to_string()
+            } else {
+                String::from_utf8_lossy(value.as_bytes()).into_owned()
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+fn redact_uri(uri: &Uri) -> String {
+    let Some(query) = uri.query() else {
+        return uri.path().to_string();
+    };
+
+    let query = form_urlencoded::Serializer::new(String::new())
+        .extend_pairs(
+            form_urlencoded::parse(query.as_bytes()).map(|(key, value)| {
+                let value = if is_sensitive(&key) {
+                    REDACTED.into()
+                } else {
+                    value
+                };
+                (key, value)
+            }),
+        )
+        .finish();
+    format!("{}?{}", uri.path(), query)
+}
+
+/// 通配匹配，`*` 匹配任意长度的字符
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let mut parts = pattern.split('*');
+    let Some(first) = parts.next() else {
+        return text.is_empty();
+    };
+    let Some(mut rest) = text.strip_prefix(first) else {
+        return false;
+    };
+
+    let parts: Vec<&str> = parts.collect();
+    let Some((last, middle)) = parts.split_last() else {
+        return rest.is_empty();
+    };
+    for part in middle {
+        match rest.find(part) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.len() >= last.len() && rest.ends_with(last)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("01J*", "01JABC"));
+        assert!(wildcard_match("*ABC", "01JABC"));
+        assert!(wildcard_match("01*B*C", "01JABC"));
+        assert!(wildcard_match("01JABC", "01JABC"));
+        assert!(wildcard_match("*", "anything"));
+        assert!(!wildcard_match("01J*", "02JABC"));
+        assert!(!wildcard_match("01JABC", "01JABCD"));
+        assert!(!wildcard_match("AB*AB", "AB"));
+    }
+
+    #[test]
+    fn test_redact_json_masks_nested_secrets() {
+        let redacted = redact_json(json!({
+            "identifier": "Soybean",
+            "password": "123456",
+            "nested": { "accessToken": "abc", "items": [{ "client_secret": "x", "id": 1 }] },
+        }));
+        assert_eq!(
+            redacted,
+            json!({
+                "identifier": "Soybean",
+                "password": REDACTED,
+                "nested": { "accessToken": REDACTED, "items": [{ "client_secret": REDACTED, "id": 1 }] },
+            })
+        );
+    }
+
+    #[test]
+    fn test_redact_uri_masks_sensitive_query() {
+        let uri: Uri = "/user?current=1&token=abc".parse().unwrap();
+        assert_eq!(redact_uri(&uri), "/user?current=1&token=***");
+        let uri: Uri = "/user/1".parse().unwrap();
+        assert_eq!(redact_uri(&uri), "/user/1");
+    }
+
+    #[test]
+    fn test_capture_body_omits_large_payload() {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::CONTENT_TYPE, "application/json".parse().unwrap());
+        assert_eq!(
+            capture_body(&headers, br#"{"name":"a"}"#, 4),
+            Some(Value::String("<12 bytes omitted>".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_db_capture_summarizes_statements() {
+        let mut capture = DbCapture::default();
+        capture.push("SELECT 1", 2.0, false);
+        capture.push("SELECT 1", 3.0, false);
+        capture.push("UPDATE t SET a = $1", 10.0, true);
+
+        let summary = capture.summarize();
+        assert_eq!(summary.statements, 3);
+        assert_eq!(summary.failed, 1);
+        assert_eq!(summary.total_ms, 15.0);
+        assert_eq!(summary.top[0].sql, "UPDATE t SET a = $1");
+        assert_eq!(summary.top[1].count, 2);
+    }
+
+    #[test]
+    fn test_session_matches_filters() {
+        let now = Local::now().naive_local();
+        let session = RecordingSession {
+            id: "s".to_string(),
+            user_id: Some("1".to_string()),
+            request_id_pattern: Some("01J*".to_string()),
+            capacity: 10,
+            max_body_bytes: 1024,
+            started_by: "admin".to_string(),
+            started_at: now,
+            expires_at: now,
+        };
+        assert!(session.matches(Some("1"), "01JABC"));
+        assert!(!session.matches(Some("2"), "01JABC"));
+        assert!(!session.matches(None, "01JABC"));
+        assert!(!session.matches(Some("1"), "02JABC"));
+    }
+}
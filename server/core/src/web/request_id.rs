@@ -3,7 +3,9 @@ use std::{
     task::{Context, Poll},
 };
 
+use axum::{body::Body, middleware::Next, response::Response};
 use http::Request;
+use server_global::global;
 use tower_layer::Layer;
 use tower_service::Service;
 use ulid::Ulid;
@@ -64,3 +66,13 @@ impl<S> Layer<S> for RequestIdLayer {
         RequestIdService { inner }
     }
 }
+
+/// 以请求 ID 作为 trace id 执行后续处理
+///
+/// 请求处理过程中发出的事件和派生的异步任务会继承该 trace id，需放在 `RequestIdLayer` 内层
+pub async fn trace_context_middleware(req: Request<Body>, next: Next) -> Response {
+    match req.extensions().get::<RequestId>().map(ToString::to_string) {
+        Some(trace_id) => global::with_trace_id(trace_id, next.run(req)).await,
+        None => next.run(req).await,
+    }
+}
@@ -0,0 +1,159 @@
+use std::{
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use bytes::Bytes;
+use http::{HeaderMap, Request};
+use http_body::{Body as HttpBody, Frame, SizeHint};
+use server_config::{ServerConfig, TrailerPolicy};
+
+use crate::web::res::Res;
+
+/// 请求头名称和值的总字节数，尾部字段按同样方式计算
+fn header_size(headers: &HeaderMap) -> usize {
+    headers
+        .iter()
+        .map(|(name, value)| name.as_str().len() + value.len())
+        .sum()
+}
+
+/// 超出上限时返回说明，未超出时返回 `None`
+fn check_headers(headers: &HeaderMap, config: &ServerConfig) -> Option<String> {
+    if headers.len() > config.max_header_count {
+        return Some(format!(
+            "Too many header fields: {} exceeds the limit of {}",
+            headers.len(),
+            config.max_header_count
+        ));
+    }
+    let size = header_size(headers);
+    (size > config.max_header_size).then(|| {
+        format!(
+            "Header fields too large: {} bytes exceeds the limit of {}",
+            size, config.max_header_size
+        )
+    })
+}
+
+/// 请求头和 URI 大小限制中间件，包裹全部路由，在其他处理之前拒绝超限的请求
+///
+/// 尾部字段在读取请求体时才会到达，按 `server.trailers` 丢弃或与请求头共用上限
+pub async fn request_limits_middleware(
+    config: Arc<ServerConfig>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let uri_length = req
+        .uri()
+        .path_and_query()
+        .map(|path_and_query| path_and_query.as_str().len())
+        .unwrap_or_default();
+    let rejection = if uri_length > config.max_uri_length {
+        Some((
+            StatusCode::URI_TOO_LONG,
+            format!(
+                "URI too long: {} bytes exceeds the limit of {}",
+                uri_length, config.max_uri_length
+            ),
+        ))
+    } else {
+        check_headers(req.headers(), &config)
+            .map(|message| (StatusCode::REQUEST_HEADER_FIELDS_TOO_LARGE, message))
+    };
+
+    if let Some((status, message)) = rejection {
+        tracing::info!(
+            target: "metrics",
+            event = "request_limit_exceeded",
+            status = status.as_u16(),
+            path = %req.uri().path(),
+        );
+        return (status, Res::<()>::new_error(status.as_u16(), &message)).into_response();
+    }
+
+    let req = req.map(|body| {
+        Body::new(TrailerLimitedBody {
+            inner: body,
+            config: config.clone(),
+        })
+    });
+    next.run(req).await
+}
+
+/// 按配置处理尾部字段的请求体
+struct TrailerLimitedBody {
+    inner: Body,
+    config: Arc<ServerConfig>,
+}
+
+impl HttpBody for TrailerLimitedBody {
+    type Data = Bytes;
+    type Error = axum::Error;
+
+    fn poll_frame(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Frame<Self::Data>, Self::Error>>> {
+        let frame = match Pin::new(&mut self.inner).poll_frame(cx) {
+            Poll::Ready(Some(Ok(frame))) => frame,
+            other => return other,
+        };
+        let Some(trailers) = frame.trailers_ref() else {
+            return Poll::Ready(Some(Ok(frame)));
+        };
+        match self.config.trailers {
+            // 尾部字段是请求体的最后一帧，丢弃后请求体即结束
+            TrailerPolicy::Discard => Poll::Ready(None),
+            TrailerPolicy::Limit => match check_headers(trailers, &self.config) {
+                Some(message) => Poll::Ready(Some(Err(axum::Error::new(message)))),
+                None => Poll::Ready(Some(Ok(frame))),
+            },
+        }
+    }
+
+    fn is_end_stream(&self) -> bool {
+        self.inner.is_end_stream()
+    }
+
+    fn size_hint(&self) -> SizeHint {
+        self.inner.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use http::HeaderValue;
+
+    use super::*;
+
+    #[test]
+    fn test_check_headers() {
+        let config = ServerConfig {
+            max_header_size: 32,
+            max_header_count: 2,
+            ..Default::default()
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert("cookie", HeaderValue::from_static("a=1"));
+        assert_eq!(check_headers(&headers, &config), None);
+
+        headers.insert("x-trace", HeaderValue::from_static("0123456789abcdef0123"));
+        assert!(check_headers(&headers, &config)
+            .unwrap()
+            .starts_with("Header fields too large"));
+
+        headers.insert("x-trace", HeaderValue::from_static("1"));
+        headers.append("x-trace", HeaderValue::from_static("2"));
+        assert!(check_headers(&headers, &config)
+            .unwrap()
+            .starts_with("Too many header fields"));
+    }
+}
@@ -7,7 +7,11 @@ use axum::{
 };
 use serde::Serialize;
 
-use crate::web::page::PaginatedData;
+use crate::web::page::PageResult;
+
+/// 失败响应的业务码，写入响应扩展，供中间件在不读取响应体的情况下判断请求是否失败
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ErrorCode(pub u16);
 
 #[derive(Debug, Serialize, Default)]
 pub struct Res<T> {
@@ -19,7 +23,7 @@ pub struct Res<T> {
 
 #[allow(dead_code)]
 impl<T: Serialize> Res<T> {
-    pub fn new_paginated(data: PaginatedData<T>) -> Res<PaginatedData<T>> {
+    pub fn new_paginated(data: PageResult<T>) -> Res<PageResult<T>> {
         Res {
             code: StatusCode::OK.as_u16(),
             data: Some(data),
@@ -70,6 +74,11 @@ where
     T: Serialize + Send + Sync + Debug + 'static,
 {
     fn into_response(self) -> Response {
-        Json(self).into_response()
+        let error_code = (!self.success).then_some(ErrorCode(self.code));
+        let mut response = Json(self).into_response();
+        if let Some(error_code) = error_code {
+            response.extensions_mut().insert(error_code);
+        }
+        response
     }
 }
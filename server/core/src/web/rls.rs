@@ -0,0 +1,60 @@
+use axum::{body::Body, middleware::Next, response::Response};
+use http::Request;
+
+use super::auth::User;
+
+/// 当前用户 ID 所在的会话变量，RLS 策略中通过 `current_setting('app.current_user_id', true)` 读取
+pub const RLS_USER_SETTING: &str = "app.current_user_id";
+
+/// 当前租户（用户所属的域）所在的会话变量
+pub const RLS_TENANT_SETTING: &str = "app.tenant_id";
+
+tokio::task_local! {
+    static RLS_CONTEXT: RlsContext;
+}
+
+/// 请求对应的行级安全上下文
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RlsContext {
+    pub user_id: String,
+    pub tenant_id: String,
+}
+
+/// 当前请求的行级安全上下文，不在中间件作用域内（未鉴权的请求、后台任务）时返回 `None`
+///
+/// 上下文保存在任务局部变量中，`tokio::spawn` 出的任务不会继承
+pub fn current_context() -> Option<RlsContext> {
+    RLS_CONTEXT.try_with(Clone::clone).ok()
+}
+
+/// 行级安全上下文中间件，须位于 JWT 鉴权内层以读取当前用户
+///
+/// 只记录上下文，会话变量由 `db_helper::RlsConnection` 在执行语句时设置
+pub async fn rls_context_middleware(req: Request<Body>, next: Next) -> Response {
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+    let context = RlsContext {
+        user_id: user.user_id(),
+        tenant_id: user.domain(),
+    };
+    RLS_CONTEXT.scope(context, next.run(req)).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_context_in_scope() {
+        assert_eq!(current_context(), None);
+
+        let context = RlsContext {
+            user_id: "1".to_string(),
+            tenant_id: "built-in".to_string(),
+        };
+        RLS_CONTEXT.sync_scope(context.clone(), || {
+            assert_eq!(current_context(), Some(context.clone()));
+        });
+    }
+}
@@ -0,0 +1,138 @@
+use std::{
+    collections::{hash_map::DefaultHasher, BTreeMap, HashMap},
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use once_cell::sync::Lazy;
+use parking_lot::{Mutex, RwLock};
+use serde::Serialize;
+
+static TRACKERS: Lazy<RwLock<HashMap<String, Arc<Mutex<CacheTracker>>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+
+/// 连接池的预编译语句缓存统计
+///
+/// sqlx 不公开缓存命中情况，这里按连接池执行的语句序列模拟同容量的 LRU 缓存估算，
+/// 各连接负载相近时与单个连接的实际命中率一致。连接池重建后重新统计
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StatementCacheStats {
+    pub capacity: usize,
+    pub hits: u64,
+    pub misses: u64,
+    pub evictions: u64,
+    /// 命中率，尚未执行语句时为 0
+    pub hit_rate: f64,
+    /// 当前缓存的语句数
+    pub cached: usize,
+}
+
+/// 按 LRU 淘汰模拟单个连接的语句缓存，只保存 SQL 的哈希
+pub(crate) struct CacheTracker {
+    capacity: usize,
+    tick: u64,
+    /// SQL 哈希到最近一次使用的序号
+    entries: HashMap<u64, u64>,
+    /// 使用序号到 SQL 哈希，序号最小的最先淘汰
+    order: BTreeMap<u64, u64>,
+    hits: u64,
+    misses: u64,
+    evictions: u64,
+}
+
+impl CacheTracker {
+    fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            order: BTreeMap::new(),
+            hits: 0,
+            misses: 0,
+            evictions: 0,
+        }
+    }
+
+    pub(crate) fn observe(&mut self, sql: &str) {
+        let mut hasher = DefaultHasher::new();
+        sql.hash(&mut hasher);
+        let hash = hasher.finish();
+
+        self.tick += 1;
+        match self.entries.insert(hash, self.tick) {
+            Some(last_used) => {
+                self.order.remove(&last_used);
+                self.hits += 1;
+            },
+            None => self.misses += 1,
+        }
+        self.order.insert(self.tick, hash);
+
+        while self.entries.len() > self.capacity {
+            let Some((_, evicted)) = self.order.pop_first() else {
+                break;
+            };
+            self.entries.remove(&evicted);
+            self.evictions += 1;
+        }
+    }
+
+    fn stats(&self) -> StatementCacheStats {
+        let total = self.hits + self.misses;
+        StatementCacheStats {
+            capacity: self.capacity,
+            hits: self.hits,
+            misses: self.misses,
+            evictions: self.evictions,
+            hit_rate: if total == 0 {
+                0.0
+            } else {
+                self.hits as f64 / total as f64
+            },
+            cached: self.entries.len(),
+        }
+    }
+}
+
+/// 为连接池登记语句缓存统计，同名连接池重建时从零开始
+pub(crate) fn register_pool(pool: &str, capacity: usize) -> Arc<Mutex<CacheTracker>> {
+    let tracker = Arc::new(Mutex::new(CacheTracker::new(capacity)));
+    TRACKERS.write().insert(pool.to_string(), tracker.clone());
+    tracker
+}
+
+/// 连接池的语句缓存统计，连接池未登记时返回 `None`
+pub fn statement_cache_stats(pool: &str) -> Option<StatementCacheStats> {
+    TRACKERS
+        .read()
+        .get(pool)
+        .map(|tracker| tracker.lock().stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cache_tracker_evicts_least_recently_used() {
+        let mut tracker = CacheTracker::new(2);
+        tracker.observe("SELECT 1");
+        tracker.observe("SELECT 2");
+        tracker.observe("SELECT 1");
+        tracker.observe("SELECT 3");
+        tracker.observe("SELECT 2");
+
+        let stats = tracker.stats();
+        assert_eq!(stats.hits, 1);
+        assert_eq!(stats.misses, 4);
+        assert_eq!(stats.evictions, 2);
+        assert_eq!(stats.cached, 2);
+        assert_eq!(stats.hit_rate, 0.2);
+
+        let mut tracker = CacheTracker::new(0);
+        tracker.observe("SELECT 1");
+        tracker.observe("SELECT 1");
+        assert_eq!(tracker.stats().hits, 0);
+    }
+}
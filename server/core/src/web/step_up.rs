@@ -0,0 +1,138 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use chrono::Utc;
+use http::Request;
+use serde::Serialize;
+use server_config::{StepUpConfig, StepUpRule};
+
+use crate::web::{auth::User, break_glass::BREAK_GLASS_AUTH_METHOD, res::Res};
+
+/// 需要重新认证时返回的业务码
+pub const STEP_UP_REQUIRED_CODE: u16 = 9801;
+
+/// 返回给前端的二次认证要求
+#[derive(Debug, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct StepUpChallenge {
+    /// 固定为 `stepUpRequired`，前端据此弹出重新认证窗口而不是跳转登录页
+    pub reason: &'static str,
+    /// 认证有效期（秒）
+    pub max_age: u64,
+    /// 是否需要使用通行密钥重新认证
+    pub require_passkey: bool,
+}
+
+/// 检查用户的认证时间和方式是否满足规则，满足时返回 `None`
+fn check(
+    rule: &StepUpRule,
+    default_max_age: u64,
+    user: &User,
+    now: u64,
+) -> Option<StepUpChallenge> {
+    let max_age = rule.max_age.unwrap_or(default_max_age);
+    let fresh = user
+        .auth_time()
+        .is_some_and(|auth_time| now.saturating_sub(auth_time as u64) <= max_age);
+    let method_ok = !rule.require_passkey || user.authenticated_with("passkey");
+
+    (!fresh || !method_ok).then_some(StepUpChallenge {
+        reason: "stepUpRequired",
+        max_age,
+        require_passkey: rule.require_passkey,
+    })
+}
+
+/// 敏感接口二次认证中间件，须位于 JWT 鉴权内层以读取当前用户
+pub async fn step_up_middleware(
+    config: Arc<StepUpConfig>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(rule) = config.rule(req.method().as_str(), req.uri().path()) else {
+        return next.run(req).await;
+    };
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+    // 紧急凭证只能使用一次，会话内无法重新认证
+    if user.authenticated_with(BREAK_GLASS_AUTH_METHOD) {
+        return next.run(req).await;
+    }
+
+    match check(rule, config.max_age, user, Utc::now().timestamp() as u64) {
+        None => next.run(req).await,
+        Some(challenge) => {
+            tracing::info!(
+                target: "metrics",
+                event = "step_up_required",
+                path = %req.uri().path(),
+                user_id = %user.user_id(),
+                require_passkey = challenge.require_passkey,
+            );
+            (
+                StatusCode::UNAUTHORIZED,
+                Res {
+                    code: STEP_UP_REQUIRED_CODE,
+                    data: Some(challenge),
+                    msg: "Re-authentication required".to_string(),
+                    success: false,
+                },
+            )
+                .into_response()
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::web::auth::Claims;
+
+    fn user(auth_time: Option<usize>, amr: &[&str]) -> User {
+        let mut claims = Claims::new(
+            "1".to_string(),
+            "aud".to_string(),
+            "admin".to_string(),
+            vec![],
+            "built-in".to_string(),
+            None,
+        );
+        if let Some(auth_time) = auth_time {
+            claims.set_auth_time(auth_time);
+        }
+        claims.set_amr(amr.iter().map(ToString::to_string).collect());
+        User::from(claims)
+    }
+
+    #[test]
+    fn test_check_auth_time_and_method() {
+        let rule = StepUpRule {
+            path: "/user".to_string(),
+            method: Some("DELETE".to_string()),
+            max_age: None,
+            require_passkey: false,
+        };
+
+        assert_eq!(check(&rule, 300, &user(Some(1_000), &["pwd"]), 1_200), None);
+        assert!(check(&rule, 300, &user(Some(1_000), &["pwd"]), 1_400).is_some());
+        // 旧令牌没有认证时间，始终要求重新认证
+        assert!(check(&rule, 300, &user(None, &[]), 1_000).is_some());
+
+        let rule = StepUpRule {
+            require_passkey: true,
+            ..rule
+        };
+        let challenge = check(&rule, 300, &user(Some(1_000), &["pwd"]), 1_100).unwrap();
+        assert!(challenge.require_passkey);
+        assert_eq!(
+            check(&rule, 300, &user(Some(1_000), &["pwd", "passkey"]), 1_100),
+            None
+        );
+    }
+}
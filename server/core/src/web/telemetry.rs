@@ -0,0 +1,138 @@
+use std::{collections::HashMap, time::Instant};
+
+use axum::{body::Body, extract::MatchedPath, middleware::Next, response::Response};
+use http::Request;
+use once_cell::sync::Lazy;
+use parking_lot::Mutex;
+use serde::Serialize;
+
+/// 延迟分桶的上界（毫秒），超过最后一个上界的请求计入溢出桶
+const BUCKET_BOUNDS_MS: [u64; 11] = [5, 10, 25, 50, 100, 250, 500, 1000, 2500, 5000, 10000];
+
+/// 未匹配到路由的请求（404）统一归入该分组，不记录原始路径
+const UNMATCHED_GROUP: &str = "unmatched";
+
+static LATENCIES: Lazy<Mutex<HashMap<String, LatencyHistogram>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 单个路由分组的延迟分布，只保存各分桶的请求数
+#[derive(Debug, Clone, Default)]
+struct LatencyHistogram {
+    counts: [u64; BUCKET_BOUNDS_MS.len() + 1],
+}
+
+impl LatencyHistogram {
+    fn record(&mut self, millis: u64) {
+        let bucket = BUCKET_BOUNDS_MS
+            .iter()
+            .position(|bound| millis <= *bound)
+            .unwrap_or(BUCKET_BOUNDS_MS.len());
+        self.counts[bucket] += 1;
+    }
+
+    fn requests(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// 百分位所在分桶的上界，落在溢出桶时返回最后一个上界
+    fn percentile(&self, percentile: f64) -> u64 {
+        let target = (self.requests() as f64 * percentile).ceil() as u64;
+        let mut seen = 0;
+        for (bucket, count) in self.counts.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return BUCKET_BOUNDS_MS[bucket.min(BUCKET_BOUNDS_MS.len() - 1)];
+            }
+        }
+        BUCKET_BOUNDS_MS[BUCKET_BOUNDS_MS.len() - 1]
+    }
+}
+
+/// 路由分组的延迟统计
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteLatency {
+    /// 路由模板的第一段，如 `/user/{id}` 归入 `user`
+    pub group: String,
+    pub requests: u64,
+    /// P95 延迟所在分桶的上界（毫秒）
+    pub p95_ms: u64,
+}
+
+/// 路由分组：只取路由模板的第一段，不包含路径参数的实际值
+fn route_group(matched_path: Option<&str>) -> String {
+    let Some(path) = matched_path else {
+        return UNMATCHED_GROUP.to_string();
+    };
+    match path.trim_start_matches('/').split('/').next() {
+        Some(segment) if !segment.is_empty() => segment.to_string(),
+        _ => "root".to_string(),
+    }
+}
+
+fn snapshot(latencies: &HashMap<String, LatencyHistogram>) -> Vec<RouteLatency> {
+    let mut snapshot: Vec<RouteLatency> = latencies
+        .iter()
+        .map(|(group, histogram)| RouteLatency {
+            group: group.clone(),
+            requests: histogram.requests(),
+            p95_ms: histogram.percentile(0.95),
+        })
+        .collect();
+    snapshot.sort_by(|a, b| a.group.cmp(&b.group));
+    snapshot
+}
+
+/// 当前统计周期内各路由分组的延迟
+pub fn latency_snapshot() -> Vec<RouteLatency> {
+    snapshot(&LATENCIES.lock())
+}
+
+/// 取出当前统计周期的延迟并开始新的周期，上报成功后调用
+pub fn take_latency_snapshot() -> Vec<RouteLatency> {
+    snapshot(&std::mem::take(&mut *LATENCIES.lock()))
+}
+
+/// 遥测延迟统计中间件，按路由分组记录处理耗时
+pub async fn telemetry_middleware(req: Request<Body>, next: Next) -> Response {
+    let group = route_group(
+        req.extensions()
+            .get::<MatchedPath>()
+            .map(MatchedPath::as_str),
+    );
+    let started_at = Instant::now();
+    let response = next.run(req).await;
+    let millis = started_at.elapsed().as_millis() as u64;
+
+    LATENCIES.lock().entry(group).or_default().record(millis);
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_route_group() {
+        assert_eq!(route_group(Some("/user/{id}")), "user");
+        assert_eq!(route_group(Some("/")), "root");
+        assert_eq!(route_group(None), UNMATCHED_GROUP);
+    }
+
+    #[test]
+    fn test_percentile() {
+        let mut histogram = LatencyHistogram::default();
+        for _ in 0..94 {
+            histogram.record(3);
+        }
+        for _ in 0..6 {
+            histogram.record(300);
+        }
+        assert_eq!(histogram.requests(), 100);
+        assert_eq!(histogram.percentile(0.95), 500);
+        assert_eq!(histogram.percentile(0.5), 5);
+
+        histogram.record(60_000);
+        assert_eq!(histogram.percentile(1.0), 10000);
+    }
+}
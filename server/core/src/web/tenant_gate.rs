@@ -0,0 +1,74 @@
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use axum::{
+    body::Body,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
+};
+use http::Request;
+use serde::Serialize;
+
+use crate::web::{auth::User, error::AppError, res::Res};
+
+/// 租户已停用时返回的业务码
+pub const TENANT_SUSPENDED_CODE: u16 = 10301;
+
+/// 返回给前端的停用说明
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantSuspended {
+    /// 固定为 `tenantSuspended`，前端据此展示停用页面
+    pub reason: &'static str,
+    pub message: String,
+}
+
+/// 查询租户是否停用，由租户服务实现
+#[async_trait]
+pub trait TenantStatusChecker: Send + Sync {
+    /// 租户停用时返回展示给用户的说明，正常或不是受管租户时返回 `None`
+    async fn suspension(&self, domain: &str) -> Result<Option<String>, AppError>;
+}
+
+/// 租户停用中间件，须位于 JWT 鉴权内层以读取当前用户的域
+///
+/// 查询失败时放行并记录日志，避免租户表不可用时所有接口都被拦截
+pub async fn tenant_gate_middleware(
+    checker: Arc<dyn TenantStatusChecker>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let Some(user) = req.extensions().get::<User>() else {
+        return next.run(req).await;
+    };
+
+    let message = match checker.suspension(&user.domain()).await {
+        Ok(Some(message)) => message,
+        Ok(None) => return next.run(req).await,
+        Err(e) => {
+            tracing::error!(error = %e.message(), "Failed to check tenant status");
+            return next.run(req).await;
+        },
+    };
+
+    tracing::info!(
+        target: "metrics",
+        event = "tenant_request_rejected",
+        domain = %user.domain(),
+        path = %req.uri().path(),
+    );
+    (
+        StatusCode::FORBIDDEN,
+        Res {
+            code: TENANT_SUSPENDED_CODE,
+            data: Some(TenantSuspended {
+                reason: "tenantSuspended",
+                message: message.clone(),
+            }),
+            msg: message,
+            success: false,
+        },
+    )
+        .into_response()
+}
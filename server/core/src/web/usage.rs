@@ -0,0 +1,75 @@
+use axum::{body::Body, middleware::Next, response::Response};
+use http::Request;
+use server_constant::definition::consts::SystemEvent;
+use server_global::global;
+
+use crate::{
+    sign::ApiKeyIdentity,
+    web::{auth::User, res::ErrorCode},
+};
+
+/// 调用方类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UsageSubjectKind {
+    User,
+    ApiKey,
+}
+
+impl UsageSubjectKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            UsageSubjectKind::User => "user",
+            UsageSubjectKind::ApiKey => "api_key",
+        }
+    }
+}
+
+/// 一次 API 调用，由统计中间件发送，监听器累加到 Redis 的小时计数
+#[derive(Debug, Clone)]
+pub struct ApiUsageEvent {
+    pub kind: UsageSubjectKind,
+    /// 用户 ID 或 API Key 标识
+    pub subject: String,
+    /// 展示用的名称，用户为用户名
+    pub label: String,
+    /// 是否失败，HTTP 状态码非 2xx 或返回了错误业务码
+    pub error: bool,
+}
+
+/// API 调用统计中间件，须位于 JWT 鉴权和 API Key 校验内层以识别调用方
+///
+/// 同时携带 API Key 和登录态的请求按 API Key 统计，无法识别调用方的请求不统计
+pub async fn usage_middleware(req: Request<Body>, next: Next) -> Response {
+    let caller = req
+        .extensions()
+        .get::<ApiKeyIdentity>()
+        .map(|identity| {
+            (
+                UsageSubjectKind::ApiKey,
+                identity.0.clone(),
+                identity.0.clone(),
+            )
+        })
+        .or_else(|| {
+            req.extensions()
+                .get::<User>()
+                .map(|user| (UsageSubjectKind::User, user.user_id(), user.username()))
+        });
+
+    let response = next.run(req).await;
+
+    if let Some((kind, subject, label)) = caller {
+        let error =
+            !response.status().is_success() || response.extensions().get::<ErrorCode>().is_some();
+        global::send_dyn_event(
+            SystemEvent::ApiUsageEvent.as_ref(),
+            Box::new(ApiUsageEvent {
+                kind,
+                subject,
+                label,
+                error,
+            }),
+        );
+    }
+    response
+}
@@ -2,20 +2,30 @@ use std::{
     any::{Any, TypeId},
     collections::HashMap,
     future::Future,
-    pin::Pin,
-    sync::Arc,
+    pin::{pin, Pin},
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
 };
 
 use aws_sdk_s3::Client as S3Client;
 use chrono::NaiveDateTime;
 use http::Method;
-use jsonwebtoken::{DecodingKey, EncodingKey, Validation};
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Validation};
 use mongodb::Client as MongoClient;
 use once_cell::sync::Lazy;
-use redis::{cluster::ClusterClient, Client};
+use redis::{
+    aio::{ConnectionManager, ConnectionManagerConfig},
+    cluster::ClusterClient,
+    cluster_async::ClusterConnection,
+    Client, RedisResult,
+};
 use sea_orm::DatabaseConnection;
 use serde_json::Value;
-use tokio::sync::{mpsc, Mutex, OnceCell, RwLock};
+use tokio::sync::{mpsc, Mutex, Notify, OnceCell, RwLock};
+use tracing::{Instrument, Span};
+use ulid::Ulid;
 
 use crate::project_info;
 
@@ -42,16 +52,96 @@ pub async fn get_config<T: 'static + Any + Send + Sync>() -> Option<Arc<T>> {
 // 数据库连接
 //*****************************************************************************
 
+/// 主数据库连接在连接池管理中使用的名称
+pub const PRIMARY_DB_NAME: &str = "primary";
+
 pub static GLOBAL_PRIMARY_DB: Lazy<RwLock<Option<Arc<DatabaseConnection>>>> =
     Lazy::new(|| RwLock::new(None));
 pub static GLOBAL_DB_POOL: Lazy<RwLock<HashMap<String, Arc<DatabaseConnection>>>> =
     Lazy::new(|| RwLock::new(HashMap::new()));
+/// 各数据库的只读副本连接，按数据库名称（主数据库为 `PRIMARY_DB_NAME`）存放
+pub static GLOBAL_DB_REPLICAS: Lazy<RwLock<HashMap<String, Vec<Arc<DatabaseConnection>>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
 
 // Redis连接
 #[derive(Clone)]
 pub enum RedisConnection {
-    Single(Arc<Client>),
-    Cluster(Arc<ClusterClient>),
+    Single(Arc<RedisPool>),
+    Cluster(Arc<RedisClusterPool>),
+}
+
+/// 固定数量的连接槽位，按轮询分配，槽位首次使用时建立连接
+struct ConnectionSlots<C> {
+    slots: Vec<OnceCell<C>>,
+    next: AtomicUsize,
+}
+
+impl<C: Clone> ConnectionSlots<C> {
+    fn new(size: usize) -> Self {
+        Self {
+            slots: (0..size.max(1)).map(|_| OnceCell::new()).collect(),
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    async fn get<F, Fut>(&self, connect: F) -> RedisResult<C>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = RedisResult<C>>,
+    {
+        let index = self.next.fetch_add(1, Ordering::Relaxed) % self.slots.len();
+        // 建立失败的槽位保持为空，下次分配到时重新建立
+        self.slots[index].get_or_try_init(connect).await.cloned()
+    }
+}
+
+/// 单机 Redis 连接池
+///
+/// 每个槽位持有一个自动重连的多路复用连接，连接超时、响应超时与重试策略由
+/// `ConnectionManagerConfig` 决定
+pub struct RedisPool {
+    client: Client,
+    config: ConnectionManagerConfig,
+    slots: ConnectionSlots<ConnectionManager>,
+}
+
+impl RedisPool {
+    pub fn new(client: Client, size: usize, config: ConnectionManagerConfig) -> Self {
+        Self {
+            client,
+            config,
+            slots: ConnectionSlots::new(size),
+        }
+    }
+
+    /// 取出一个连接，连接可以克隆后并发使用
+    pub async fn get_connection(&self) -> RedisResult<ConnectionManager> {
+        self.slots
+            .get(|| ConnectionManager::new_with_config(self.client.clone(), self.config.clone()))
+            .await
+    }
+}
+
+/// Redis 集群连接池
+///
+/// 连接超时、响应超时与重试策略在构建 `ClusterClient` 时设置
+pub struct RedisClusterPool {
+    client: ClusterClient,
+    slots: ConnectionSlots<ClusterConnection>,
+}
+
+impl RedisClusterPool {
+    pub fn new(client: ClusterClient, size: usize) -> Self {
+        Self {
+            client,
+            slots: ConnectionSlots::new(size),
+        }
+    }
+
+    /// 取出一个集群连接，连接可以克隆后并发使用
+    pub async fn get_connection(&self) -> RedisResult<ClusterConnection> {
+        self.slots.get(|| self.client.get_async_connection()).await
+    }
 }
 
 pub static GLOBAL_PRIMARY_REDIS: Lazy<RwLock<Option<RedisConnection>>> =
@@ -82,31 +172,247 @@ pub static GLOBAL_S3_POOL: Lazy<RwLock<HashMap<String, Arc<S3Client>>>> =
 // JWT 密钥和验证
 //*****************************************************************************
 
+#[derive(Clone)]
 pub struct Keys {
+    pub algorithm: Algorithm,
     pub encoding: EncodingKey,
     pub decoding: DecodingKey,
 }
 
 impl Keys {
+    /// HS256 共享密钥
     pub fn new(secret: &[u8]) -> Self {
         Self {
+            algorithm: Algorithm::HS256,
             encoding: EncodingKey::from_secret(secret),
             decoding: DecodingKey::from_secret(secret),
         }
     }
+
+    /// PEM 格式的密钥对，只支持 RS256 和 ES256
+    pub fn from_pem(
+        algorithm: Algorithm,
+        private_pem: &[u8],
+        public_pem: &[u8],
+    ) -> jsonwebtoken::errors::Result<Self> {
+        let (encoding, decoding) = match algorithm {
+            Algorithm::ES256 => (
+                EncodingKey::from_ec_pem(private_pem)?,
+                DecodingKey::from_ec_pem(public_pem)?,
+            ),
+            Algorithm::RS256 => (
+                EncodingKey::from_rsa_pem(private_pem)?,
+                DecodingKey::from_rsa_pem(public_pem)?,
+            ),
+            _ => return Err(jsonwebtoken::errors::ErrorKind::InvalidAlgorithm.into()),
+        };
+        Ok(Self {
+            algorithm,
+            encoding,
+            decoding,
+        })
+    }
 }
 
 pub static KEYS: OnceCell<Arc<Mutex<Keys>>> = OnceCell::const_new();
+/// 刷新令牌的签名密钥，未配置 `refresh_secret` 时与 `KEYS` 相同
+pub static REFRESH_KEYS: OnceCell<Arc<Mutex<Keys>>> = OnceCell::const_new();
 pub static VALIDATION: OnceCell<Arc<Mutex<Validation>>> = OnceCell::const_new();
 
+//*****************************************************************************
+// 链路追踪上下文
+//*****************************************************************************
+
+tokio::task_local! {
+    static CURRENT_TRACE_ID: String;
+}
+
+/// 跨异步边界传递的追踪上下文
+///
+/// 请求派生的事件、定时任务及其重试通过它继承同一个 trace id，
+/// 并以 `follows_from` 关联发起方的 span
+#[derive(Debug, Clone)]
+pub struct TraceContext {
+    pub trace_id: String,
+    origin: Span,
+}
+
+impl TraceContext {
+    /// 捕获当前上下文，不在任何链路中时开启新的链路
+    pub fn capture() -> Self {
+        let trace_id = current_trace_id().unwrap_or_else(|| Ulid::new().to_string());
+        Self {
+            trace_id,
+            origin: Span::current(),
+        }
+    }
+
+    /// 开启新的链路，用于定时任务等没有发起方的场景
+    pub fn new_root() -> Self {
+        Self {
+            trace_id: Ulid::new().to_string(),
+            origin: Span::none(),
+        }
+    }
+
+    fn task_span(&self, task: &str, attempt: u32) -> Span {
+        let span = tracing::info_span!(
+            parent: None,
+            "async_task",
+            task = %task,
+            trace_id = %self.trace_id,
+            attempt,
+        );
+        span.follows_from(&self.origin);
+        span
+    }
+
+    /// 在此上下文中执行异步任务
+    pub async fn scope<F: Future>(&self, task: &str, future: F) -> F::Output {
+        let span = self.task_span(task, 1);
+        CURRENT_TRACE_ID
+            .scope(self.trace_id.clone(), future.instrument(span))
+            .await
+    }
+
+    /// 在此上下文中执行可重试的异步任务
+    ///
+    /// 每次尝试使用独立的 span，并通过 `follows_from` 链接到上一次尝试
+    pub async fn retry<T, E, F, Fut>(
+        &self,
+        task: &str,
+        max_attempts: u32,
+        mut operation: F,
+    ) -> Result<T, E>
+    where
+        F: FnMut(u32) -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+    {
+        let mut previous: Option<Span> = None;
+        let mut attempt = 1;
+        loop {
+            let span = self.task_span(task, attempt);
+            if let Some(previous) = &previous {
+                span.follows_from(previous);
+            }
+
+            let result = CURRENT_TRACE_ID
+                .scope(
+                    self.trace_id.clone(),
+                    operation(attempt).instrument(span.clone()),
+                )
+                .await;
+            match result {
+                Err(_) if attempt < max_attempts => {
+                    previous = Some(span);
+                    attempt += 1;
+                },
+                result => return result,
+            }
+        }
+    }
+}
+
+/// 获取当前链路的 trace id
+pub fn current_trace_id() -> Option<String> {
+    CURRENT_TRACE_ID.try_with(Clone::clone).ok()
+}
+
+/// 以指定 trace id 执行异步任务，HTTP 请求以请求 ID 作为 trace id
+pub async fn with_trace_id<F: Future>(trace_id: String, future: F) -> F::Output {
+    CURRENT_TRACE_ID.scope(trace_id, future).await
+}
+
+//*****************************************************************************
+// 实例排空
+//*****************************************************************************
+
+static DRAINING: AtomicBool = AtomicBool::new(false);
+static DRAIN_COMPLETED: AtomicBool = AtomicBool::new(false);
+static DRAIN_COMPLETED_NOTIFY: Notify = Notify::const_new();
+static IN_FLIGHT_WORK: AtomicUsize = AtomicUsize::new(0);
+static WORK_IDLE_NOTIFY: Notify = Notify::const_new();
+
+/// 进行中的工作（HTTP 请求、后台任务），释放时计数减一
+pub struct WorkGuard(());
+
+impl Drop for WorkGuard {
+    fn drop(&mut self) {
+        if IN_FLIGHT_WORK.fetch_sub(1, Ordering::AcqRel) == 1 {
+            WORK_IDLE_NOTIFY.notify_waiters();
+        }
+    }
+}
+
+/// 登记一项进行中的工作，排空时会等待其完成
+pub fn track_work() -> WorkGuard {
+    IN_FLIGHT_WORK.fetch_add(1, Ordering::AcqRel);
+    WorkGuard(())
+}
+
+/// 当前进行中的工作数量
+pub fn in_flight_work() -> usize {
+    IN_FLIGHT_WORK.load(Ordering::Acquire)
+}
+
+/// 实例是否正在排空，排空期间就绪检查失败且不再领取新的后台任务
+pub fn is_draining() -> bool {
+    DRAINING.load(Ordering::Acquire)
+}
+
+/// 开始排空，已在排空中时返回 `false`
+pub fn begin_drain() -> bool {
+    !DRAINING.swap(true, Ordering::AcqRel)
+}
+
+/// 等待所有进行中的工作完成
+pub async fn wait_for_idle() {
+    loop {
+        let mut notified = pin!(WORK_IDLE_NOTIFY.notified());
+        notified.as_mut().enable();
+        if in_flight_work() == 0 {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// 标记排空完成，唤醒等待退出的任务
+pub fn complete_drain() {
+    DRAIN_COMPLETED.store(true, Ordering::Release);
+    DRAIN_COMPLETED_NOTIFY.notify_waiters();
+}
+
+/// 等待排空完成，用于触发进程退出
+pub async fn wait_drain_completed() {
+    let mut notified = pin!(DRAIN_COMPLETED_NOTIFY.notified());
+    notified.as_mut().enable();
+    if DRAIN_COMPLETED.load(Ordering::Acquire) {
+        return;
+    }
+    notified.await;
+}
+
 //*****************************************************************************
 // 事件通道
 //*****************************************************************************
 
+/// 携带追踪上下文的动态类型事件
+pub struct TracedEvent {
+    pub context: TraceContext,
+    pub payload: Box<dyn Any + Send>,
+}
+
+impl TracedEvent {
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.payload.downcast_ref::<T>()
+    }
+}
+
 /// 事件通道条目
 struct DynChannelEntry {
     name: String,
-    tx: mpsc::UnboundedSender<Box<dyn Any + Send>>,
+    tx: mpsc::UnboundedSender<TracedEvent>,
 }
 
 /// 事件通道管理器
@@ -127,7 +433,7 @@ type DynFuture = dyn Future<Output = ()> + Send + 'static;
 type StringListener = Box<dyn FnOnce(mpsc::UnboundedReceiver<String>) -> Pin<Box<DynFuture>>>;
 type DynListener = (
     String,
-    Box<dyn Fn(mpsc::UnboundedReceiver<Box<dyn Any + Send>>) -> Pin<Box<DynFuture>>>,
+    Box<dyn Fn(mpsc::UnboundedReceiver<TracedEvent>) -> Pin<Box<DynFuture>>>,
 );
 
 /// 获取字符串事件发送器
@@ -138,7 +444,7 @@ pub async fn get_string_sender() -> mpsc::UnboundedSender<String> {
 
 /// 获取动态类型事件发送器
 #[inline]
-pub async fn get_dyn_sender(name: &str) -> Option<mpsc::UnboundedSender<Box<dyn Any + Send>>> {
+pub async fn get_dyn_sender(name: &str) -> Option<mpsc::UnboundedSender<TracedEvent>> {
     let channels = EVENT_CHANNELS.lock().await;
     channels
         .dyn_channels
@@ -181,12 +487,27 @@ pub async fn register_event_listeners(
 // 路由信息收集
 //*****************************************************************************
 
+/// 路由的权限声明
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RouteGuard {
+    /// 需要 Casbin 接口权限，同步为接口列表
+    #[default]
+    Permission,
+    /// 登录即可访问，如当前用户的个人设置
+    Authenticated,
+    /// 无需登录，如登录接口
+    Public,
+}
+
 #[derive(Clone, Debug)]
 pub struct RouteInfo {
     pub path: String,
     pub method: Method,
     pub service_name: String,
     pub summary: String,
+    pub guard: RouteGuard,
+    /// 接口分组，用于 OpenAPI 文档
+    pub tags: Vec<String>,
 }
 
 impl RouteInfo {
@@ -196,8 +517,32 @@ impl RouteInfo {
             method,
             service_name: service_name.to_string(),
             summary: summary.to_string(),
+            guard: RouteGuard::Permission,
+            tags: Vec::new(),
         }
     }
+
+    pub fn with_tags<S: AsRef<str>>(mut self, tags: &[S]) -> Self {
+        self.tags = tags.iter().map(|tag| tag.as_ref().to_string()).collect();
+        self
+    }
+
+    /// 声明为只需登录的接口，不同步为接口权限
+    pub fn authenticated(mut self) -> Self {
+        self.guard = RouteGuard::Authenticated;
+        self
+    }
+
+    /// 声明为公开接口，不同步为接口权限
+    pub fn public(mut self) -> Self {
+        self.guard = RouteGuard::Public;
+        self
+    }
+
+    /// 是否为写操作
+    pub fn is_mutating(&self) -> bool {
+        !matches!(self.method, Method::GET | Method::HEAD | Method::OPTIONS)
+    }
 }
 
 pub static ROUTE_COLLECTOR: Lazy<Mutex<Vec<RouteInfo>>> = Lazy::new(|| Mutex::new(Vec::new()));
@@ -214,6 +559,103 @@ pub async fn clear_routes() {
     ROUTE_COLLECTOR.lock().await.clear();
 }
 
+/// 路由实际经过的中间件，由路由初始化时的挂载信息生成
+#[derive(Clone, Debug)]
+pub struct RouteStack {
+    pub route: RouteInfo,
+    /// 挂载时是否启用了 Casbin 接口权限
+    pub casbin: bool,
+    /// 按请求经过的顺序排列，最外层在前
+    pub layers: Vec<String>,
+}
+
+pub static ROUTE_STACKS: Lazy<Mutex<Vec<RouteStack>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+pub async fn set_route_stacks(stacks: Vec<RouteStack>) {
+    *ROUTE_STACKS.lock().await = stacks;
+}
+
+pub async fn get_route_stacks() -> Vec<RouteStack> {
+    ROUTE_STACKS.lock().await.clone()
+}
+
+//*****************************************************************************
+// 菜单信息收集
+//*****************************************************************************
+
+/// 模块声明的菜单，启动时按 `route_name` 补齐到 `sys_menu`
+#[derive(Clone, Debug)]
+pub struct MenuInfo {
+    pub route_name: String,
+    pub route_path: String,
+    pub component: String,
+    /// 是否为目录
+    pub directory: bool,
+    /// 上级菜单的 `route_name`，顶级菜单为空
+    pub parent: Option<String>,
+    pub icon: Option<String>,
+    pub sequence: i32,
+    /// 隐藏菜单高亮的菜单，如详情页高亮列表页
+    pub active_menu: Option<String>,
+}
+
+impl MenuInfo {
+    pub fn menu(route_name: &str, route_path: &str, component: &str) -> Self {
+        MenuInfo {
+            route_name: route_name.to_string(),
+            route_path: route_path.to_string(),
+            component: component.to_string(),
+            directory: false,
+            parent: None,
+            icon: None,
+            sequence: 0,
+            active_menu: None,
+        }
+    }
+
+    pub fn directory(route_name: &str, route_path: &str) -> Self {
+        MenuInfo {
+            directory: true,
+            ..Self::menu(route_name, route_path, "layout.base")
+        }
+    }
+
+    pub fn with_parent(mut self, parent: &str) -> Self {
+        self.parent = Some(parent.to_string());
+        self
+    }
+
+    pub fn with_icon(mut self, icon: &str) -> Self {
+        self.icon = Some(icon.to_string());
+        self
+    }
+
+    pub fn with_sequence(mut self, sequence: i32) -> Self {
+        self.sequence = sequence;
+        self
+    }
+
+    /// 不在菜单中显示，访问时高亮 `active_menu`
+    pub fn hidden(mut self, active_menu: &str) -> Self {
+        self.active_menu = Some(active_menu.to_string());
+        self
+    }
+}
+
+pub static MENU_COLLECTOR: Lazy<Mutex<Vec<MenuInfo>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+pub async fn add_menu(menu: MenuInfo) {
+    MENU_COLLECTOR.lock().await.push(menu);
+}
+
+pub async fn get_collected_menus() -> Vec<MenuInfo> {
+    MENU_COLLECTOR.lock().await.clone()
+}
+
+pub async fn clear_menus() {
+    MENU_COLLECTOR.lock().await.clear();
+}
+
 //*****************************************************************************
 // 操作日志
 //*****************************************************************************
@@ -267,9 +709,13 @@ pub fn send_string_event(msg: String) {
     });
 }
 
-/// 异步发送动态类型事件
+/// 异步发送动态类型事件，事件携带发送方的追踪上下文
 #[inline]
 pub fn send_dyn_event(event_name: &'static str, event: Box<dyn Any + Send>) {
+    let event = TracedEvent {
+        context: TraceContext::capture(),
+        payload: event,
+    };
     tokio::spawn(async move {
         if let Some(sender) = get_dyn_sender(event_name).await {
             let _ = sender.send(event);
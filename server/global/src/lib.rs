@@ -1,4 +1,4 @@
-pub use jsonwebtoken::Validation;
+pub use jsonwebtoken::{Algorithm, Validation};
 
 pub mod global;
 
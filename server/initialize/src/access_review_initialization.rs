@@ -0,0 +1,56 @@
+use std::time::Duration;
+
+use server_config::ComplianceConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysAccessReviewService, TAccessReviewService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 定期检查间隔，实际发起周期由 `compliance.access_review.interval_days` 决定
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 启动权限复核定时任务
+///
+/// 每小时检查一次距上次复核是否超过配置的周期，未开启时不启动。
+/// 集群部署时只有主节点发起复核
+pub async fn initialize_access_review_scheduler() {
+    let enabled = get_config::<ComplianceConfig>()
+        .await
+        .is_some_and(|config| config.access_review.enabled);
+    if !enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("access_review").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "access_review_scheduler",
+                    SysAccessReviewService.start_scheduled_review(),
+                )
+                .await;
+            match result {
+                Ok(Some(review)) => project_info!("Access review '{}' started", review.name),
+                Ok(None) => {},
+                Err(e) => project_error!("Scheduled access review failed: {:?}", e),
+            }
+        }
+    });
+
+    project_info!("Access review scheduler started");
+}
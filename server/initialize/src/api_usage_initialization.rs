@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use server_config::ApiUsageConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysApiUsageService, TApiUsageService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 检查已结束小时的间隔，小时结束后尽快汇总
+const ROLLUP_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+/// 启动 API 调用统计汇总任务
+///
+/// 未启用 `api_usage` 时不启动；定期把 Redis 中已结束小时的计数写入数据库并清理过期记录，集群部署时只有主节点执行
+pub async fn initialize_api_usage_rollup_job() {
+    let enabled = get_config::<ApiUsageConfig>()
+        .await
+        .is_some_and(|config| config.enabled);
+    if !enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(ROLLUP_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("api_usage").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope("api_usage_rollup_job", SysApiUsageService.rollup())
+                .await;
+            if let Err(e) = result {
+                project_error!("Api usage rollup job failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!("Api usage rollup job started");
+}
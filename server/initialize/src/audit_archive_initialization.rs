@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+use server_config::ComplianceConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, SysOperationLogService, TOperationLogService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动审计日志归档任务
+///
+/// 未开启 `compliance.audit_archive.enabled` 时不启动，集群部署时只有主节点执行
+pub async fn initialize_audit_archive_job() {
+    let Some(config) = get_config::<ComplianceConfig>()
+        .await
+        .map(|config| config.audit_archive.clone())
+    else {
+        return;
+    };
+    if !config.enabled || config.interval == 0 {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(config.interval));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader() || is_draining() || jobs_paused().await {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "audit_archive_job",
+                    SysOperationLogService.archive_closed_partitions(),
+                )
+                .await;
+            match result {
+                Ok(0) => {},
+                Ok(count) => project_info!("Archived {} audit log records", count),
+                Err(e) => project_error!("Audit archive job failed: {:?}", e),
+            }
+        }
+    });
+
+    project_info!(
+        "Audit archive job started, bucket: {}, hot months: {}",
+        config.bucket,
+        config.hot_months
+    );
+}
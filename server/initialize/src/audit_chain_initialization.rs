@@ -0,0 +1,65 @@
+use std::time::Duration;
+
+use server_config::ComplianceConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, AuditChainVerifyRequest, SysOperationLogService,
+    TOperationLogService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动审计日志哈希链定期校验任务
+///
+/// `compliance.audit_chain.verify_interval` 为 0 时不启动，集群部署时只有主节点执行
+pub async fn initialize_audit_chain_job() {
+    let verify_interval = get_config::<ComplianceConfig>()
+        .await
+        .map(|config| config.audit_chain.verify_interval)
+        .unwrap_or_default();
+    if verify_interval == 0 {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(verify_interval));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader() || is_draining() || jobs_paused().await {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "audit_chain_job",
+                    SysOperationLogService
+                        .verify_audit_chain(AuditChainVerifyRequest { domain: None }),
+                )
+                .await;
+            match result {
+                Ok(report) => {
+                    for partition in report.partitions {
+                        if let Some(divergence) = partition.divergence {
+                            project_error!(
+                                "Audit chain diverged in domain '{}' at record {} (seq {}): {:?}",
+                                partition.domain,
+                                divergence.id,
+                                divergence.chain_seq,
+                                divergence.reason
+                            );
+                        }
+                    }
+                },
+                Err(e) => project_error!("Audit chain job failed: {:?}", e),
+            }
+        }
+    });
+
+    project_info!(
+        "Audit chain verification job started, interval: {}s",
+        verify_interval
+    );
+}
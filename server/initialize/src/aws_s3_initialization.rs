@@ -1,12 +1,17 @@
 #![allow(dead_code)]
-use std::{process, sync::Arc};
+use std::{process, sync::Arc, time::Duration};
 
-use aws_config::BehaviorVersion;
+use aws_config::{
+    environment::EnvironmentVariableCredentialsProvider,
+    imds::credentials::ImdsCredentialsProvider, profile::ProfileFileCredentialsProvider,
+    provider_config::ProviderConfig, retry::RetryConfig, timeout::TimeoutConfig,
+    web_identity_token::WebIdentityTokenCredentialsProvider, BehaviorVersion,
+};
 use aws_sdk_s3::{
-    config::{Credentials, Region},
+    config::{Builder as S3ConfigBuilder, Credentials, Region, SharedCredentialsProvider},
     Client as S3Client,
 };
-use server_config::{OptionalConfigs, S3Config, S3InstancesConfig};
+use server_config::{OptionalConfigs, S3Config, S3CredentialSource, S3InstancesConfig};
 use server_global::global::{get_config, GLOBAL_PRIMARY_S3, GLOBAL_S3_POOL};
 
 use crate::{project_error, project_info};
@@ -66,7 +71,54 @@ async fn init_s3_connection(name: &str, config: &S3Config) -> Result<(), String>
     }
 }
 
-async fn create_s3_client(config: &S3Config) -> Result<S3Client, String> {
+/// 按配置的凭证来源创建凭证提供者，返回 `None` 时使用 SDK 默认凭证链
+///
+/// 除静态密钥外的来源获取的都是临时凭证，过期前由 SDK 自动刷新
+fn credentials_provider(config: &S3Config) -> Option<SharedCredentialsProvider> {
+    let static_keys = || {
+        (!config.access_key_id.is_empty() && !config.secret_access_key.is_empty()).then(|| {
+            SharedCredentialsProvider::new(Credentials::new(
+                config.access_key_id.clone(),
+                config.secret_access_key.expose_secret().to_string(),
+                None,
+                None,
+                "soybean-admin-rust",
+            ))
+        })
+    };
+    let Some(credentials) = &config.credentials else {
+        return static_keys();
+    };
+
+    let provider_config =
+        ProviderConfig::without_region().with_region(Some(Region::new(config.region.clone())));
+    let provider = match credentials.source {
+        S3CredentialSource::Static => return static_keys(),
+        S3CredentialSource::Env => {
+            SharedCredentialsProvider::new(EnvironmentVariableCredentialsProvider::new())
+        },
+        S3CredentialSource::Imds => SharedCredentialsProvider::new(
+            ImdsCredentialsProvider::builder()
+                .configure(&provider_config)
+                .build(),
+        ),
+        S3CredentialSource::WebIdentity => SharedCredentialsProvider::new(
+            WebIdentityTokenCredentialsProvider::builder()
+                .configure(&provider_config)
+                .build(),
+        ),
+        S3CredentialSource::Profile => {
+            let mut builder = ProfileFileCredentialsProvider::builder().configure(&provider_config);
+            if let Some(profile) = &credentials.profile {
+                builder = builder.profile_name(profile);
+            }
+            SharedCredentialsProvider::new(builder.build())
+        },
+    };
+    Some(provider)
+}
+
+pub(crate) async fn create_s3_client(config: &S3Config) -> Result<S3Client, String> {
     let mut aws_config_builder =
         aws_config::defaults(BehaviorVersion::latest()).region(Region::new(config.region.clone()));
 
@@ -74,18 +126,33 @@ async fn create_s3_client(config: &S3Config) -> Result<S3Client, String> {
         aws_config_builder = aws_config_builder.endpoint_url(endpoint);
     }
 
-    if !config.access_key_id.is_empty() && !config.secret_access_key.is_empty() {
-        aws_config_builder = aws_config_builder.credentials_provider(Credentials::new(
-            config.access_key_id.clone(),
-            config.secret_access_key.clone(),
-            None,
-            None,
-            "soybean-admin-rust",
-        ));
+    if let Some(provider) = credentials_provider(config) {
+        aws_config_builder = aws_config_builder.credentials_provider(provider);
     }
 
+    if let Some(max_attempts) = config.max_attempts {
+        aws_config_builder = aws_config_builder
+            .retry_config(RetryConfig::standard().with_max_attempts(max_attempts));
+    }
+
+    let mut timeout_builder = TimeoutConfig::builder();
+    if let Some(secs) = config.connect_timeout {
+        timeout_builder = timeout_builder.connect_timeout(Duration::from_secs(secs));
+    }
+    if let Some(secs) = config.operation_timeout {
+        timeout_builder = timeout_builder.operation_timeout(Duration::from_secs(secs));
+    }
+    if let Some(secs) = config.operation_attempt_timeout {
+        timeout_builder = timeout_builder.operation_attempt_timeout(Duration::from_secs(secs));
+    }
+    aws_config_builder = aws_config_builder.timeout_config(timeout_builder.build());
+
     let aws_config = aws_config_builder.load().await;
-    let client = S3Client::new(&aws_config);
+    let s3_config = S3ConfigBuilder::from(&aws_config)
+        .accelerate(config.accelerate)
+        .force_path_style(config.force_path_style)
+        .build();
+    let client = S3Client::from_conf(s3_config);
 
     // 验证 S3 客户端连接
     match client.list_buckets().send().await {
@@ -239,6 +306,33 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_credentials_provider() {
+        let config = S3Config {
+            region: "us-east-1".to_string(),
+            ..Default::default()
+        };
+        assert!(credentials_provider(&config).is_none());
+
+        let config = S3Config {
+            access_key_id: "test_key".to_string(),
+            secret_access_key: "test_secret".into(),
+            ..config
+        };
+        assert!(credentials_provider(&config).is_some());
+
+        let config = S3Config {
+            access_key_id: String::new(),
+            secret_access_key: Default::default(),
+            credentials: Some(server_config::S3CredentialsConfig {
+                source: S3CredentialSource::Env,
+                profile: None,
+            }),
+            ..config
+        };
+        assert!(credentials_provider(&config).is_some());
+    }
+
     #[tokio::test]
     async fn test_s3_pool_operations() {
         setup_logger();
@@ -250,8 +344,9 @@ mod tests {
             s3: S3Config {
                 region: "us-east-1".to_string(),
                 access_key_id: "test_key".to_string(),
-                secret_access_key: "test_secret".to_string(),
+                secret_access_key: "test_secret".into(),
                 endpoint: Some("http://localhost:4566".to_string()),
+                ..Default::default()
             },
         };
 
@@ -0,0 +1,51 @@
+use std::time::Duration;
+
+use server_config::{ClusterConfig, ServerConfig};
+use server_global::global::{get_config, TraceContext};
+use server_service::admin::{SysClusterService, TClusterService};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 注册集群成员并启动心跳任务
+///
+/// 心跳同时负责主节点选举，需在 Redis 初始化之后、后台任务启动之前调用
+pub async fn initialize_cluster_membership() {
+    let server_config = get_config::<ServerConfig>().await.unwrap();
+    let cluster_config = get_config::<ClusterConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+
+    let hostname = std::env::var("HOSTNAME").unwrap_or_else(|_| "localhost".to_string());
+    let host = format!("{}:{}", hostname, server_config.port);
+    let member = SysClusterService::register_local_instance(host, server_config.role);
+
+    // 先同步完成一次心跳，确保后台任务启动时已确定主节点身份
+    if let Err(e) = SysClusterService.heartbeat().await {
+        project_error!("Cluster heartbeat failed: {:?}", e);
+    }
+
+    let period = Duration::from_secs(cluster_config.heartbeat_interval.max(1));
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        ticker.tick().await;
+
+        loop {
+            ticker.tick().await;
+            let result = TraceContext::new_root()
+                .scope("cluster_heartbeat", SysClusterService.heartbeat())
+                .await;
+            if let Err(e) = result {
+                project_error!("Cluster heartbeat failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!(
+        "Cluster instance {} registered, heartbeat interval: {}s",
+        member.instance_id,
+        cluster_config.heartbeat_interval
+    );
+}
@@ -0,0 +1,181 @@
+use std::collections::HashSet;
+
+use migration::{Migrator, MigratorTrait};
+use server_config::{Config, CONFIG_SCHEMA_VERSION};
+use server_global::global::get_config;
+
+use crate::{db_initialization::get_primary_db_connection, project_error, project_info};
+
+/// 程序版本号
+const BINARY_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// 兼容性问题及处理建议
+#[derive(Debug, PartialEq)]
+struct CompatibilityIssue {
+    problem: String,
+    guidance: String,
+}
+
+/// 检查配置文件的结构版本
+fn check_config_version(config_version: Option<u32>) -> Option<CompatibilityIssue> {
+    let version = config_version.unwrap_or(1);
+    if version > CONFIG_SCHEMA_VERSION {
+        return Some(CompatibilityIssue {
+            problem: format!(
+                "config file declares config_version {} but binary {} supports up to {}",
+                version, BINARY_VERSION, CONFIG_SCHEMA_VERSION
+            ),
+            guidance: "upgrade the binary to the release the config file was written for, \
+                       or use the config file shipped with this release"
+                .to_string(),
+        });
+    }
+    (version < CONFIG_SCHEMA_VERSION).then(|| CompatibilityIssue {
+        problem: format!(
+            "config file declares config_version {} but binary {} expects {}",
+            version, BINARY_VERSION, CONFIG_SCHEMA_VERSION
+        ),
+        guidance: format!(
+            "follow the migration guide in README_ENV_CONFIG.md, then set config_version: {}",
+            CONFIG_SCHEMA_VERSION
+        ),
+    })
+}
+
+/// 比较程序内置的迁移与数据库中已执行的迁移
+///
+/// 有未执行的迁移说明数据库结构落后于程序；数据库中存在程序不认识的迁移说明程序版本落后于数据库，
+/// 通常是回滚了程序但没有回滚数据库
+fn check_migrations(known: &[String], applied: &[String]) -> Vec<CompatibilityIssue> {
+    let known_set: HashSet<&String> = known.iter().collect();
+    let applied_set: HashSet<&String> = applied.iter().collect();
+    let mut issues = Vec::new();
+
+    let pending: Vec<&str> = known
+        .iter()
+        .filter(|name| !applied_set.contains(name))
+        .map(String::as_str)
+        .collect();
+    if !pending.is_empty() {
+        issues.push(CompatibilityIssue {
+            problem: format!(
+                "database schema is behind binary {}: {} pending migration(s): {}",
+                BINARY_VERSION,
+                pending.len(),
+                pending.join(", ")
+            ),
+            guidance: "back up the database and run `cargo run -p migration -- up` \
+                       before starting this binary"
+                .to_string(),
+        });
+    }
+
+    let unknown: Vec<&str> = applied
+        .iter()
+        .filter(|name| !known_set.contains(name))
+        .map(String::as_str)
+        .collect();
+    if !unknown.is_empty() {
+        issues.push(CompatibilityIssue {
+            problem: format!(
+                "database schema is ahead of binary {}: {} migration(s) unknown to this binary: {}",
+                BINARY_VERSION,
+                unknown.len(),
+                unknown.join(", ")
+            ),
+            guidance: format!(
+                "deploy the release that applied these migrations or later, or roll them back \
+                 with `cargo run -p migration -- down -n {}` using that release",
+                unknown.len()
+            ),
+        });
+    }
+    issues
+}
+
+/// 启动时检查数据库结构、配置文件与程序版本是否兼容
+///
+/// 默认只记录警告并给出处理建议，开启 `server.strict_compatibility` 时发现问题拒绝启动
+pub async fn check_compatibility() {
+    let Some(config) = get_config::<Config>().await else {
+        return;
+    };
+    let mut issues: Vec<CompatibilityIssue> = check_config_version(config.config_version)
+        .into_iter()
+        .collect();
+
+    match get_primary_db_connection().await {
+        Some(db) => match Migrator::get_migration_models(db.as_ref()).await {
+            Ok(models) => {
+                let known: Vec<String> = Migrator::migrations()
+                    .iter()
+                    .map(|migration| migration.name().to_string())
+                    .collect();
+                let applied: Vec<String> = models.into_iter().map(|model| model.version).collect();
+                issues.extend(check_migrations(&known, &applied));
+            },
+            Err(e) => project_error!("Failed to read applied migrations: {}", e),
+        },
+        None => project_error!("Skipping schema compatibility check: database not connected"),
+    }
+
+    if issues.is_empty() {
+        project_info!(
+            "Compatibility check passed: binary {}, config_version {}",
+            BINARY_VERSION,
+            config.config_version.unwrap_or(1)
+        );
+        return;
+    }
+
+    let strict = config.server.strict_compatibility;
+    for issue in &issues {
+        if strict {
+            project_error!("Incompatible: {}", issue.problem);
+        } else {
+            tracing::warn!("Incompatible: {}", issue.problem);
+        }
+        project_info!("  -> {}", issue.guidance);
+    }
+    if strict {
+        project_error!(
+            "Found {} compatibility issue(s) with server.strict_compatibility enabled",
+            issues.len()
+        );
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn test_check_config_version() {
+        assert_eq!(check_config_version(None), None);
+        assert_eq!(check_config_version(Some(CONFIG_SCHEMA_VERSION)), None);
+        assert!(check_config_version(Some(CONFIG_SCHEMA_VERSION + 1))
+            .unwrap()
+            .problem
+            .contains("supports up to"));
+    }
+
+    #[test]
+    fn test_check_migrations() {
+        let known = names(&["m1", "m2", "m3"]);
+        assert!(check_migrations(&known, &known).is_empty());
+
+        let issues = check_migrations(&known, &names(&["m1"]));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].problem.contains("2 pending migration(s): m2, m3"));
+
+        let issues = check_migrations(&known, &names(&["m1", "m2", "m3", "m4"]));
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].problem.contains("unknown to this binary: m4"));
+        assert!(issues[0].guidance.contains("down -n 1"));
+    }
+}
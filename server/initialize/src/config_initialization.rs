@@ -1,4 +1,4 @@
-use crate::{project_error, project_info};
+use crate::{project_error, project_info, sync_db_pools, sync_redis_pools};
 
 /// 初始化配置（仅从文件加载，保持向后兼容）
 pub async fn initialize_config(file_path: &str) {
@@ -21,12 +21,15 @@ pub async fn initialize_config(file_path: &str) {
 /// - `env_prefix`: 环境变量前缀（可选，默认为 "APP"）
 ///
 /// # 示例
-/// ```rust
+/// ```rust,no_run
+/// # use server_initialize::initialize_config_with_env;
+/// # async fn run() {
 /// // 使用默认前缀 "APP"
 /// initialize_config_with_env("application.yaml", None).await;
 ///
 /// // 使用自定义前缀 "MYAPP"
 /// initialize_config_with_env("application.yaml", Some("MYAPP")).await;
+/// # }
 /// ```
 pub async fn initialize_config_with_env(file_path: &str, env_prefix: Option<&str>) {
     let prefix = env_prefix.unwrap_or("APP");
@@ -56,12 +59,15 @@ pub async fn initialize_config_with_env(file_path: &str, env_prefix: Option<&str
 /// - `env_prefix`: 环境变量前缀（可选，默认为 "APP"）
 ///
 /// # 示例
-/// ```rust
+/// ```rust,no_run
+/// # use server_initialize::initialize_config_from_env_only;
+/// # async fn run() {
 /// // 使用默认前缀 "APP"
 /// initialize_config_from_env_only(None).await;
 ///
 /// // 使用自定义前缀 "MYAPP"
 /// initialize_config_from_env_only(Some("MYAPP")).await;
+/// # }
 /// ```
 pub async fn initialize_config_from_env_only(env_prefix: Option<&str>) {
     let prefix = env_prefix.unwrap_or("APP");
@@ -93,12 +99,15 @@ pub async fn initialize_config_from_env_only(env_prefix: Option<&str>) {
 /// - `env_prefix`: 环境变量前缀（可选，默认为 "APP"）
 ///
 /// # 示例
-/// ```rust
+/// ```rust,no_run
+/// # use server_initialize::initialize_config_with_multi_instance_env;
+/// # async fn run() {
 /// // 使用默认前缀 "APP"
 /// initialize_config_with_multi_instance_env("application.yaml", None).await;
 ///
 /// // 使用自定义前缀 "MYAPP"
 /// initialize_config_with_multi_instance_env("application.yaml", Some("MYAPP")).await;
+/// # }
 /// ```
 pub async fn initialize_config_with_multi_instance_env(file_path: &str, env_prefix: Option<&str>) {
     let prefix = env_prefix.unwrap_or("APP");
@@ -117,3 +126,105 @@ pub async fn initialize_config_with_multi_instance_env(file_path: &str, env_pref
         },
     }
 }
+
+/// 从 etcd 初始化配置并监听变更
+///
+/// 设置了 `ETCD_ENDPOINTS` 和 `ETCD_CONFIG_PREFIX` 时生效，返回 `true`；
+/// 未设置时返回 `false`，由调用方回退到配置文件
+///
+/// # 示例
+/// ```rust,no_run
+/// # use server_initialize::{initialize_config_from_etcd, initialize_config_with_multi_instance_env};
+/// # async fn run() {
+/// if !initialize_config_from_etcd(None).await {
+///     initialize_config_with_multi_instance_env("application.yaml", None).await;
+/// }
+/// # }
+/// ```
+pub async fn initialize_config_from_etcd(env_prefix: Option<&str>) -> bool {
+    let Some(settings) = server_config::EtcdSettings::from_env() else {
+        return false;
+    };
+
+    match server_config::init_from_etcd(settings, env_prefix).await {
+        Ok(_) => {
+            project_info!("Configuration initialized successfully from etcd")
+        },
+        Err(e) => {
+            project_error!("Failed to initialize config from etcd: {:?}", e);
+        },
+    }
+    true
+}
+
+/// 从 Consul KV 初始化配置
+///
+/// 设置了 `CONSUL_HTTP_ADDR` 和 `CONSUL_CONFIG_PREFIX` 时生效，返回 `true`；
+/// 未设置时返回 `false`，由调用方回退到配置文件。开启 `CONSUL_WATCH`（默认）时监听变更，
+/// 新配置生效后按 `database_instances`、`redis_instances` 同步连接池
+pub async fn initialize_config_from_consul() -> bool {
+    let Some(settings) = server_config::ConsulSettings::from_env() else {
+        return false;
+    };
+
+    match server_config::init_from_consul(&settings.addr, &settings.prefix).await {
+        Ok(loader) => {
+            project_info!("Configuration initialized successfully from consul");
+            if settings.watch {
+                loader.watch(|| async {
+                    sync_db_pools().await;
+                    sync_redis_pools().await;
+                });
+            }
+        },
+        Err(e) => {
+            project_error!("Failed to initialize config from consul: {:?}", e);
+        },
+    }
+    true
+}
+
+/// 从 Nacos 配置中心初始化配置并订阅变更
+///
+/// 设置了 `NACOS_SERVER_ADDR` 和 `NACOS_DATA_ID` 时生效，返回 `true`；
+/// 未设置时返回 `false`，由调用方回退到配置文件
+pub async fn initialize_config_from_nacos() -> bool {
+    let Some(settings) = server_config::NacosSettings::from_env() else {
+        return false;
+    };
+
+    match server_config::init_from_nacos(&settings.server_addr, &settings.data_id, &settings.group)
+        .await
+    {
+        Ok(_) => {
+            project_info!("Configuration initialized successfully from nacos")
+        },
+        Err(e) => {
+            project_error!("Failed to initialize config from nacos: {:?}", e);
+        },
+    }
+    true
+}
+
+/// 从 Apollo 配置中心初始化配置并订阅发布通知
+///
+/// 设置了 `APOLLO_CONFIG_SERVICE` 和 `APOLLO_APP_ID` 时生效，返回 `true`；
+/// 未设置时返回 `false`，由调用方回退到配置文件
+pub async fn initialize_config_from_apollo() -> bool {
+    let Some(settings) = server_config::ApolloSettings::from_env() else {
+        return false;
+    };
+    let namespaces: Vec<&str> = settings.namespaces.iter().map(String::as_str).collect();
+
+    match server_config::init_from_apollo(&settings.config_service, &settings.app_id, &namespaces)
+        .await
+    {
+        Ok(_) => {
+            project_info!("Configuration initialized successfully from apollo")
+        },
+        Err(e) => {
+            project_error!("Failed to initialize config from apollo: {:?}", e);
+        },
+    }
+    true
+}
@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use server_config::ConsistencyConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysConsistencyService, TConsistencyService,
+};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动数据一致性检查任务
+///
+/// 未开启 `consistency.enabled` 时不启动，集群部署时只有主节点检查；
+/// 第一次检查在启动一个周期后进行，避免每次重启都全量扫描对象存储
+pub async fn initialize_consistency_job() {
+    let Some(config) = get_config::<ConsistencyConfig>()
+        .await
+        .filter(|config| config.enabled)
+    else {
+        return;
+    };
+    let period = Duration::from_secs(config.interval);
+
+    tokio::spawn(async move {
+        let mut ticker = interval_at(Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("consistency").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "consistency_job",
+                    SysConsistencyService.run_scheduled_check(),
+                )
+                .await;
+            match result {
+                Ok(report) if report.has_issues() => project_info!(
+                    "Consistency check found issues in {} categories",
+                    report
+                        .findings
+                        .iter()
+                        .filter(|finding| finding.count > 0 || finding.error.is_some())
+                        .count()
+                ),
+                Ok(_) => {},
+                Err(e) => project_error!("Scheduled consistency check failed: {:?}", e),
+            }
+        }
+    });
+
+    project_info!(
+        "Consistency check enabled, running every {}s",
+        config.interval
+    );
+}
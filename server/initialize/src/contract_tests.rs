@@ -0,0 +1,693 @@
+//! 接口契约测试
+//!
+//! 针对种子数据库逐个调用接口，将响应 JSON 归纳为结构描述后与
+//! `server/resources/contracts` 下的基准文件比对，响应结构发生非预期变化时测试失败。
+//!
+//! - `CONTRACT_TEST_DATABASE_URL`: 专用测试库连接串，本地未设置时跳过，设置了 `CI` 时测试失败；
+//!   **测试会清空并重建该库**，并将全部接口授权给登录用户的角色
+//! - `CONTRACT_TEST_USERNAME` / `CONTRACT_TEST_PASSWORD`: 登录账号，默认 `Soybean` / `123456`
+//! - `UPDATE_CONTRACTS=1`: 以本次响应重写基准文件，用于有意变更接口结构后更新契约
+
+use std::{collections::BTreeSet, net::SocketAddr, path::PathBuf};
+
+use axum::{body::Body, extract::ConnectInfo, Router};
+use http::{header, Method, Request};
+use http_body_util::BodyExt;
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{ConnectionTrait, Statement};
+use serde_json::{json, Value};
+use server_global::global::{get_collected_routes, RouteGuard};
+use tower::ServiceExt;
+
+/// 单个接口的调用用例
+struct ContractCase {
+    /// 基准文件名
+    name: &'static str,
+    method: Method,
+    /// 与 `RouteInfo` 一致的路由模板，用于覆盖率检查
+    route: &'static str,
+    /// 实际请求地址
+    uri: &'static str,
+    body: Option<Value>,
+}
+
+impl ContractCase {
+    fn get(name: &'static str, route: &'static str, uri: &'static str) -> Self {
+        Self {
+            name,
+            method: Method::GET,
+            route,
+            uri,
+            body: None,
+        }
+    }
+}
+
+/// 已注册但不纳入契约测试的接口及原因
+///
+/// 写操作会改变种子数据，导致后续用例结果依赖执行顺序，由各模块自身的测试覆盖
+const UNCOVERED_ROUTES: &[(&str, &str, &str)] = &[
+    ("POST", "/authorization/assign-permission", "写操作"),
+    ("POST", "/authorization/assign-routes", "写操作"),
+    ("POST", "/route", "写操作"),
+    ("PUT", "/route", "写操作"),
+    ("DELETE", "/route/:id", "写操作"),
+    ("POST", "/user", "写操作"),
+    ("PUT", "/user", "写操作"),
+    ("DELETE", "/user/:id", "写操作"),
+    ("GET", "/user/add_policies", "写操作"),
+    ("GET", "/user/remove_policies", "写操作"),
+    ("POST", "/domain", "写操作"),
+    ("PUT", "/domain", "写操作"),
+    ("DELETE", "/domain/:id", "写操作"),
+    ("POST", "/role", "写操作"),
+    ("PUT", "/role", "写操作"),
+    ("DELETE", "/role/:id", "写操作"),
+    ("POST", "/access-key", "写操作"),
+    ("DELETE", "/access-key/:id", "写操作"),
+    ("POST", "/file", "写操作，依赖对象存储"),
+    ("GET", "/file/:id/download", "返回文件内容而非 JSON"),
+    ("DELETE", "/file/:id", "写操作"),
+    ("POST", "/file/quarantine/:id/release", "写操作"),
+    ("DELETE", "/file/quarantine/:id", "写操作"),
+    ("POST", "/file/:id/shares", "写操作"),
+    ("DELETE", "/file/shares/:id", "写操作"),
+    ("POST", "/config/staged", "写操作"),
+    ("POST", "/config/staged/canary", "写操作"),
+    ("POST", "/config/staged/promote", "写操作"),
+    ("POST", "/config/staged/rollback", "写操作"),
+    ("PUT", "/db-pool/:name", "写操作"),
+    ("POST", "/admin/drain", "会使测试进程进入排空状态"),
+    ("GET", "/admin/openapi.json", "随路由清单变化"),
+    ("GET", "/profiling/cpu", "返回二进制剖析文件"),
+    ("POST", "/recorder/start", "写操作"),
+    ("POST", "/recorder/stop", "写操作"),
+    ("DELETE", "/recorder/traces", "写操作"),
+    ("POST", "/recorder/replay", "需要可访问的目标环境"),
+    ("POST", "/chaos/faults", "写操作，会影响其他用例"),
+    ("DELETE", "/chaos/faults", "写操作"),
+    ("DELETE", "/chaos/faults/:id", "写操作"),
+    ("POST", "/alert-rule", "写操作"),
+    ("PUT", "/alert-rule", "写操作"),
+    ("DELETE", "/alert-rule/:id", "写操作"),
+    ("POST", "/maintenance-window", "写操作"),
+    ("PUT", "/maintenance-window", "写操作"),
+    ("DELETE", "/maintenance-window/:id", "写操作"),
+    ("POST", "/access-review", "写操作"),
+    ("POST", "/access-review/:id/close", "写操作"),
+    ("PUT", "/access-review/items/:id/decision", "写操作"),
+    ("POST", "/policy", "写操作"),
+    ("GET", "/metering/export", "返回 CSV 而非 JSON"),
+    ("POST", "/tenant", "写操作"),
+    ("GET", "/tenant/:id/export", "返回档案附件而非统一响应"),
+    ("POST", "/tenant/import", "写操作"),
+    ("PUT", "/tenant/:id/suspend", "写操作"),
+    ("PUT", "/tenant/:id/resume", "写操作"),
+    ("POST", "/tenant/:id/purge", "写操作"),
+    ("POST", "/tenant/:id/purge/confirm", "写操作"),
+    ("DELETE", "/tenant/:id/purge", "写操作"),
+    ("POST", "/sensitive-operation/approval", "写操作"),
+    (
+        "POST",
+        "/sensitive-operation/approval/:id/approve",
+        "写操作",
+    ),
+    ("DELETE", "/break-glass/session/:id", "写操作"),
+    ("DELETE", "/deletion/:target/:id", "写操作"),
+    ("POST", "/diagnostics/bundle", "写操作，依赖对象存储"),
+    ("POST", "/consistency/check", "写操作，可能修复数据"),
+    ("PUT", "/module/:code", "写操作"),
+    ("PUT", "/route/:id/parent", "写操作"),
+    ("PUT", "/org/:id/parent", "写操作"),
+    ("POST", "/region/versions", "写操作"),
+    ("PUT", "/region/versions/:version/activate", "写操作"),
+    ("DELETE", "/region/versions/:version", "写操作"),
+    (
+        "GET",
+        "/cluster/members",
+        "依赖 Redis，测试环境只提供数据库",
+    ),
+    (
+        "GET",
+        "/api-usage/current",
+        "依赖 Redis，测试环境只提供数据库",
+    ),
+    (
+        "GET",
+        "/sensitive-operation/approval/:id",
+        "依赖 Redis，测试环境只提供数据库",
+    ),
+    (
+        "GET",
+        "/break-glass/session/:id",
+        "依赖 Redis，测试环境只提供数据库",
+    ),
+];
+
+fn contract_cases() -> Vec<ContractCase> {
+    let mut cases = vec![
+        ContractCase::get(
+            "route_constant_routes",
+            "/route/getConstantRoutes",
+            "/route/getConstantRoutes",
+        ),
+        ContractCase::get("auth_user_info", "/auth/getUserInfo", "/auth/getUserInfo"),
+        ContractCase::get("auth_passkeys", "/auth/passkeys", "/auth/passkeys"),
+        ContractCase::get(
+            "notification_page",
+            "/notification",
+            "/notification?current=1&size=10",
+        ),
+        ContractCase::get(
+            "notification_preference",
+            "/notification/preference",
+            "/notification/preference",
+        ),
+        ContractCase::get(
+            "auth_user_routes",
+            "/auth/getUserRoutes",
+            "/auth/getUserRoutes",
+        ),
+        ContractCase::get(
+            "authorization_user_routes",
+            "/authorization/getUserRoutes",
+            "/authorization/getUserRoutes",
+        ),
+        ContractCase::get("route_tree", "/route/tree", "/route/tree"),
+        ContractCase::get("route_page", "/route", "/route?current=1&size=10"),
+        ContractCase::get("route_detail", "/route/:id", "/route/50"),
+        ContractCase::get(
+            "route_auth_route",
+            "/route/auth-route/:roleId",
+            "/route/auth-route/1",
+        ),
+        ContractCase::get("user_all", "/user/users", "/user/users"),
+        ContractCase::get("user_page", "/user", "/user?current=1&size=10"),
+        ContractCase::get("user_detail", "/user/:id", "/user/1"),
+        ContractCase::get(
+            "user_permissions",
+            "/user/:id/permissions",
+            "/user/1/permissions",
+        ),
+        ContractCase::get("domain_page", "/domain", "/domain?current=1&size=10"),
+        ContractCase::get("domain_detail", "/domain/:id", "/domain/1"),
+        ContractCase::get("role_page", "/role", "/role?current=1&size=10"),
+        ContractCase::get("role_detail", "/role/:id", "/role/1"),
+        ContractCase::get(
+            "api_endpoint_page",
+            "/api-endpoint",
+            "/api-endpoint?current=1&size=10",
+        ),
+        ContractCase::get(
+            "api_endpoint_auth",
+            "/api-endpoint/auth-api-endpoint/:roleCode",
+            "/api-endpoint/auth-api-endpoint/ROLE_SUPER",
+        ),
+        ContractCase::get(
+            "api_endpoint_tree",
+            "/api-endpoint/tree",
+            "/api-endpoint/tree",
+        ),
+        ContractCase::get(
+            "access_key_page",
+            "/access-key",
+            "/access-key?current=1&size=10",
+        ),
+        ContractCase::get(
+            "login_log_page",
+            "/login-log",
+            "/login-log?current=1&size=10",
+        ),
+        ContractCase::get(
+            "operation_log_page",
+            "/operation-log",
+            "/operation-log?current=1&size=10",
+        ),
+        ContractCase::get(
+            "operation_log_history",
+            "/operation-log/history",
+            "/operation-log/history?start=2026-01-01T00:00:00&end=2026-12-31T00:00:00&current=1&size=10",
+        ),
+        ContractCase::get(
+            "operation_log_archives",
+            "/operation-log/archives",
+            "/operation-log/archives",
+        ),
+        ContractCase::get(
+            "operation_log_chain_verify",
+            "/operation-log/chain/verify",
+            "/operation-log/chain/verify",
+        ),
+        ContractCase::get("file_page", "/file", "/file?current=1&size=10"),
+        ContractCase::get("file_quarantine", "/file/quarantine", "/file/quarantine"),
+        ContractCase::get("file_shares", "/file/:id/shares", "/file/1/shares"),
+        ContractCase::get("file_url", "/file/:id/url", "/file/1/url"),
+        ContractCase::get("config_staged", "/config/staged", "/config/staged"),
+        ContractCase::get("db_pool_list", "/db-pool", "/db-pool"),
+        ContractCase::get("migration_plan", "/migrations/plan", "/migrations/plan"),
+        ContractCase::get("instance_admission", "/admin/admission", "/admin/admission"),
+        ContractCase::get(
+            "instance_middleware_stack",
+            "/admin/middleware-stack",
+            "/admin/middleware-stack",
+        ),
+        ContractCase::get("org_page", "/org", "/org?current=1&size=10"),
+        ContractCase::get("recorder_status", "/recorder", "/recorder"),
+        ContractCase::get(
+            "telemetry_preview",
+            "/telemetry/preview",
+            "/telemetry/preview",
+        ),
+        ContractCase::get("recorder_traces", "/recorder/traces", "/recorder/traces"),
+        ContractCase::get(
+            "alert_rule_page",
+            "/alert-rule",
+            "/alert-rule?current=1&size=10",
+        ),
+        ContractCase::get("alert_rule_detail", "/alert-rule/:id", "/alert-rule/1"),
+        ContractCase::get(
+            "maintenance_window_page",
+            "/maintenance-window",
+            "/maintenance-window?current=1&size=10",
+        ),
+        ContractCase::get(
+            "maintenance_window_detail",
+            "/maintenance-window/:id",
+            "/maintenance-window/1",
+        ),
+        ContractCase::get(
+            "maintenance_banner",
+            "/maintenance/banner",
+            "/maintenance/banner",
+        ),
+        ContractCase::get(
+            "api_usage_page",
+            "/api-usage",
+            "/api-usage?current=1&size=10",
+        ),
+        ContractCase::get(
+            "api_usage_trend",
+            "/api-usage/trend",
+            "/api-usage/trend?subjectKind=user&subject=1",
+        ),
+        ContractCase::get("metering_page", "/metering", "/metering?current=1&size=10"),
+        ContractCase::get("tenant_page", "/tenant", "/tenant?current=1&size=10"),
+        ContractCase::get("tenant_detail", "/tenant/:id", "/tenant/1"),
+        ContractCase::get(
+            "access_review_page",
+            "/access-review",
+            "/access-review?current=1&size=10",
+        ),
+        ContractCase::get(
+            "access_review_report",
+            "/access-review/:id/report",
+            "/access-review/1/report",
+        ),
+        ContractCase::get(
+            "access_review_items",
+            "/access-review/:id/items",
+            "/access-review/1/items?current=1&size=10",
+        ),
+        ContractCase::get("policy_page", "/policy", "/policy?current=1&size=10"),
+        ContractCase::get("policy_pending", "/policy/pending", "/policy/pending"),
+        ContractCase::get(
+            "policy_acceptances",
+            "/policy/:id/acceptances",
+            "/policy/1/acceptances?current=1&size=10",
+        ),
+        ContractCase::get(
+            "deletion_preview",
+            "/deletion/:target/:id/preview",
+            "/deletion/role/1/preview",
+        ),
+        ContractCase::get(
+            "references",
+            "/references/:entity/:id",
+            "/references/role/1",
+        ),
+        ContractCase::get("route_children", "/route/:id/children", "/route/0/children"),
+        ContractCase::get(
+            "route_subtree",
+            "/route/:id/subtree",
+            "/route/1/subtree?depth=2",
+        ),
+        ContractCase::get("org_children", "/org/:id/children", "/org/0/children"),
+        ContractCase::get("org_subtree", "/org/:id/subtree", "/org/1/subtree?depth=2"),
+        ContractCase::get("region_versions", "/region/versions", "/region/versions"),
+        ContractCase::get("module_list", "/module", "/module"),
+    ];
+
+    #[cfg(feature = "profiling")]
+    cases.push(ContractCase::get(
+        "profiling_runtime",
+        "/profiling/runtime",
+        "/profiling/runtime",
+    ));
+
+    #[cfg(feature = "chaos")]
+    cases.push(ContractCase::get(
+        "chaos_faults",
+        "/chaos/faults",
+        "/chaos/faults",
+    ));
+
+    cases.push(ContractCase {
+        name: "auth_login_invalid",
+        method: Method::POST,
+        route: "/auth/login",
+        uri: "/auth/login",
+        body: Some(json!({ "identifier": "nobody", "password": "invalid-password" })),
+    });
+
+    cases
+}
+
+/// 将 JSON 值归纳为结构描述：标量替换为类型名，数组合并所有元素的结构
+fn json_shape(value: &Value) -> Value {
+    match value {
+        Value::Null => Value::String("null".to_string()),
+        Value::Bool(_) => Value::String("boolean".to_string()),
+        Value::Number(_) => Value::String("number".to_string()),
+        Value::String(_) => Value::String("string".to_string()),
+        Value::Array(items) => {
+            let merged = items.iter().map(json_shape).reduce(merge_shape);
+            Value::Array(merged.into_iter().collect())
+        },
+        Value::Object(fields) => Value::Object(
+            fields
+                .iter()
+                .map(|(key, value)| (key.clone(), json_shape(value)))
+                .collect(),
+        ),
+    }
+}
+
+/// 合并两个结构描述，类型不一致时以 `a|b` 表示
+fn merge_shape(left: Value, right: Value) -> Value {
+    match (left, right) {
+        (Value::Object(mut left), Value::Object(right)) => {
+            let missing: Vec<String> = left
+                .keys()
+                .filter(|key| !right.contains_key(*key))
+                .cloned()
+                .collect();
+            for (key, value) in right {
+                let merged = match left.remove(&key) {
+                    Some(existing) => merge_shape(existing, value),
+                    None => optional(value),
+                };
+                left.insert(key, merged);
+            }
+            for key in missing {
+                if let Some(value) = left.remove(&key) {
+                    left.insert(key, optional(value));
+                }
+            }
+            Value::Object(left)
+        },
+        (Value::Array(left), Value::Array(right)) => Value::Array(
+            left.into_iter()
+                .chain(right)
+                .reduce(merge_shape)
+                .into_iter()
+                .collect(),
+        ),
+        (left, right) if left == right => left,
+        (left, right) => union(&[left, right]),
+    }
+}
+
+/// 仅在部分数组元素中出现的字段标记为可缺省
+fn optional(shape: Value) -> Value {
+    union(&[shape, Value::String("undefined".to_string())])
+}
+
+fn union(shapes: &[Value]) -> Value {
+    let mut names = BTreeSet::new();
+    for shape in shapes {
+        match shape {
+            Value::String(name) => names.extend(name.split('|').map(str::to_string)),
+            other => {
+                names.insert(other.to_string());
+            },
+        }
+    }
+    Value::String(names.into_iter().collect::<Vec<_>>().join("|"))
+}
+
+/// 基准文件内容：HTTP 状态、业务码和响应结构
+fn contract_snapshot(status: u16, body: &Value) -> Value {
+    json!({
+        "status": status,
+        "code": body.get("code").cloned().unwrap_or(Value::Null),
+        "shape": json_shape(body),
+    })
+}
+
+fn workspace_root() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("../..")
+}
+
+fn contracts_dir() -> PathBuf {
+    workspace_root().join("server/resources/contracts")
+}
+
+async fn send(app: &Router, case: &ContractCase, token: Option<&str>) -> (u16, Value) {
+    let mut builder = Request::builder()
+        .method(case.method.clone())
+        .uri(case.uri)
+        .header(header::USER_AGENT, "contract-tests");
+    if let Some(token) = token {
+        builder = builder.header(header::AUTHORIZATION, format!("Bearer {}", token));
+    }
+    let body = match &case.body {
+        Some(body) => {
+            builder = builder.header(header::CONTENT_TYPE, "application/json");
+            Body::from(body.to_string())
+        },
+        None => Body::empty(),
+    };
+    let mut request = builder.body(body).unwrap();
+    request
+        .extensions_mut()
+        .insert(ConnectInfo(SocketAddr::from(([127, 0, 0, 1], 0))));
+
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status().as_u16();
+    let bytes = response.into_body().collect().await.unwrap().to_bytes();
+    let body = serde_json::from_slice(&bytes)
+        .unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()));
+    (status, body)
+}
+
+/// 初始化种子数据库和全部路由，返回路由和登录令牌
+async fn prepare(database_url: &str) -> (Router, String) {
+    // 路由初始化按工作区根目录读取 casbin 模型文件
+    std::env::set_current_dir(workspace_root()).unwrap();
+    std::env::set_var("APP_DATABASE_URL", database_url);
+
+    let db = sea_orm::Database::connect(database_url).await.unwrap();
+    Migrator::fresh(&db).await.unwrap();
+    db.close().await.unwrap();
+
+    crate::initialize_config_with_multi_instance_env(
+        "server/resources/application-test.yaml",
+        None,
+    )
+    .await;
+    crate::init_xdb().await.unwrap();
+    crate::init_primary_connection().await;
+    crate::initialize_keys_and_validation().await;
+    crate::initialize_event_channel().await;
+    let app = crate::initialize_admin_router().await;
+
+    let username = std::env::var("CONTRACT_TEST_USERNAME").unwrap_or_else(|_| "Soybean".into());
+    let password = std::env::var("CONTRACT_TEST_PASSWORD").unwrap_or_else(|_| "123456".into());
+    let login = ContractCase {
+        name: "auth_login",
+        method: Method::POST,
+        route: "/auth/login",
+        uri: "/auth/login",
+        body: Some(json!({ "identifier": username, "password": password })),
+    };
+    let (_, body) = send(&app, &login, None).await;
+    let token = body
+        .pointer("/data/token")
+        .and_then(Value::as_str)
+        .unwrap_or_else(|| panic!("login failed: {}", body))
+        .to_string();
+
+    // 种子数据只为角色授权了初始接口，后续新增的接口需要授权后才能返回实际响应
+    grant_all_endpoints(&app, &token, database_url, &username).await;
+
+    (app, token)
+}
+
+/// 通过授权接口将启动时同步的全部接口授权给登录用户的角色
+async fn grant_all_endpoints(app: &Router, token: &str, database_url: &str, username: &str) {
+    let db = sea_orm::Database::connect(database_url).await.unwrap();
+    let backend = db.get_database_backend();
+    let column = |row: &sea_orm::QueryResult, name: &str| row.try_get::<String>("", name).unwrap();
+
+    let permissions: Vec<String> = db
+        .query_all(Statement::from_string(
+            backend,
+            "SELECT id FROM sys_endpoint",
+        ))
+        .await
+        .unwrap()
+        .iter()
+        .map(|row| column(row, "id"))
+        .collect();
+    let roles = db
+        .query_all(Statement::from_sql_and_values(
+            backend,
+            "SELECT r.id, u.domain FROM sys_role r \
+             JOIN sys_user_role ur ON ur.role_id = r.id \
+             JOIN sys_user u ON u.id = ur.user_id \
+             WHERE u.username = $1",
+            [username.into()],
+        ))
+        .await
+        .unwrap();
+    db.close().await.unwrap();
+
+    for role in &roles {
+        let grant = ContractCase {
+            name: "authorization_assign_permission",
+            method: Method::POST,
+            route: "/authorization/assign-permission",
+            uri: "/authorization/assign-permission",
+            body: Some(json!({
+                "domain": column(role, "domain"),
+                "roleId": column(role, "id"),
+                "permissions": permissions,
+            })),
+        };
+        let (_, body) = send(app, &grant, Some(token)).await;
+        assert_eq!(
+            body.get("code").and_then(Value::as_u64),
+            Some(200),
+            "grant failed: {}",
+            body
+        );
+    }
+}
+
+/// 比对单个用例，返回不一致说明
+fn check_contract(name: &str, actual: &Value, update: bool) -> Option<String> {
+    let path = contracts_dir().join(format!("{}.json", name));
+    let rendered = format!("{}\n", serde_json::to_string_pretty(actual).unwrap());
+
+    if update {
+        std::fs::create_dir_all(contracts_dir()).unwrap();
+        std::fs::write(&path, rendered).unwrap();
+        return None;
+    }
+
+    let Ok(expected) = std::fs::read_to_string(&path) else {
+        return Some(format!(
+            "{}: golden file {} missing, run with UPDATE_CONTRACTS=1 to record it",
+            name,
+            path.display()
+        ));
+    };
+    let expected: Value = serde_json::from_str(&expected).unwrap();
+    (&expected != actual).then(|| {
+        format!(
+            "{}: response shape changed\n--- expected\n{}\n+++ actual\n{}",
+            name,
+            serde_json::to_string_pretty(&expected).unwrap(),
+            rendered.trim_end()
+        )
+    })
+}
+
+#[test]
+fn test_json_shape_replaces_scalars() {
+    let shape = json_shape(&json!({
+        "code": 200,
+        "msg": "ok",
+        "success": true,
+        "data": null,
+    }));
+    assert_eq!(
+        shape,
+        json!({
+            "code": "number",
+            "msg": "string",
+            "success": "boolean",
+            "data": "null",
+        })
+    );
+}
+
+#[test]
+fn test_json_shape_merges_array_elements() {
+    let shape = json_shape(&json!([
+        { "id": "1", "pid": null },
+        { "id": "2", "pid": "1", "children": [] },
+    ]));
+    assert_eq!(
+        shape,
+        json!([{ "id": "string", "pid": "null|string", "children": "[]|undefined" }])
+    );
+}
+
+#[test]
+fn test_json_shape_ignores_values_and_length() {
+    let first = json_shape(&json!({ "records": [{ "id": "1" }, { "id": "2" }], "total": 2 }));
+    let second = json_shape(&json!({ "records": [{ "id": "9" }], "total": 1 }));
+    assert_eq!(first, second);
+    assert_eq!(json_shape(&json!([])), json!([]));
+}
+
+#[tokio::test]
+async fn test_api_contracts() {
+    let Ok(database_url) = std::env::var("CONTRACT_TEST_DATABASE_URL") else {
+        // CI 中缺少测试库时直接失败，避免未比对任何契约就通过
+        assert!(
+            std::env::var_os("CI").is_none(),
+            "CONTRACT_TEST_DATABASE_URL must be set when running in CI"
+        );
+        eprintln!("CONTRACT_TEST_DATABASE_URL not set, skipping contract tests");
+        return;
+    };
+    let update = std::env::var("UPDATE_CONTRACTS").is_ok_and(|value| value == "1");
+
+    let (app, token) = prepare(&database_url).await;
+    let cases = contract_cases();
+
+    // 新增接口必须补充用例或显式说明不覆盖的原因
+    let covered: BTreeSet<(String, &str)> = cases
+        .iter()
+        .map(|case| (case.method.to_string(), case.route))
+        .chain(
+            UNCOVERED_ROUTES
+                .iter()
+                .map(|(method, route, _)| (method.to_string(), *route)),
+        )
+        .collect();
+    let mut failures: Vec<String> = get_collected_routes()
+        .await
+        .into_iter()
+        .filter(|route| route.guard == RouteGuard::Permission)
+        .filter(|route| !covered.contains(&(route.method.to_string(), route.path.as_str())))
+        .map(|route| format!("{} {}: no contract case", route.method, route.path))
+        .collect();
+
+    for case in &cases {
+        let (status, body) = send(&app, case, Some(&token)).await;
+        if let Some(failure) = check_contract(case.name, &contract_snapshot(status, &body), update)
+        {
+            failures.push(failure);
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} contract failure(s):\n\n{}",
+        failures.len(),
+        failures.join("\n\n")
+    );
+}
@@ -0,0 +1,53 @@
+use server_global::global;
+use server_service::admin::{
+    flush_siem_events, SysClusterService, SysInstanceService, TClusterService,
+};
+
+use crate::{project_error, project_info};
+
+/// 监听 SIGUSR1，收到后开始排空实例
+pub async fn initialize_drain_signal() {
+    #[cfg(unix)]
+    {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut signal = match signal(SignalKind::user_defined1()) {
+            Ok(signal) => signal,
+            Err(e) => {
+                project_error!("Failed to listen for SIGUSR1: {}", e);
+                return;
+            },
+        };
+
+        tokio::spawn(async move {
+            while signal.recv().await.is_some() {
+                if let Err(e) = SysInstanceService::start_drain("SIGUSR1").await {
+                    project_info!("Ignoring SIGUSR1: {}", e);
+                }
+            }
+        });
+        project_info!("Drain signal handler installed (SIGUSR1)");
+    }
+}
+
+/// 等待退出信号：Ctrl+C 或实例排空完成
+pub async fn shutdown_signal() {
+    tokio::select! {
+        result = tokio::signal::ctrl_c() => {
+            if let Err(e) = result {
+                project_error!("Failed to listen for shutdown signal: {}", e);
+            }
+        },
+        _ = global::wait_drain_completed() => {},
+    }
+}
+
+/// 退出前的清理，注销集群成员身份以便尽快让出主节点
+pub async fn finalize_shutdown() {
+    project_info!("Shutting down");
+    if let Err(e) = SysClusterService.deregister().await {
+        project_error!("Failed to deregister cluster instance: {:?}", e);
+    }
+    // 发送缓冲区中剩余的 SIEM 事件
+    flush_siem_events().await;
+}
@@ -3,8 +3,8 @@ use server_global::global;
 
 pub async fn initialize_event_channel() {
     use server_service::admin::{
-        api_key_validate_listener, auth_login_listener, jwt_created_listener,
-        sys_operation_log_listener,
+        api_key_validate_listener, api_usage_listener, auth_login_listener, jwt_created_listener,
+        security_alert_listener, sys_operation_log_listener,
     };
 
     global::register_event_listeners(
@@ -22,6 +22,14 @@ pub async fn initialize_event_channel() {
                 SystemEvent::AuthApiKeyValidatedEvent.to_string(),
                 Box::new(|rx| Box::pin(api_key_validate_listener(rx))),
             ),
+            (
+                SystemEvent::SecuritySignalEvent.to_string(),
+                Box::new(|rx| Box::pin(security_alert_listener(rx))),
+            ),
+            (
+                SystemEvent::ApiUsageEvent.to_string(),
+                Box::new(|rx| Box::pin(api_usage_listener(rx))),
+            ),
         ],
     )
     .await;
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use server_config::StorageConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{is_cluster_leader, jobs_paused, SysFileService, TFileService};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动文件存储 GC 任务
+///
+/// 定期清理引用计数为零且超过宽限期的内容对象，未配置文件存储时不启动。
+/// 集群部署时只有主节点实际执行清理
+pub async fn initialize_file_storage_gc() {
+    let config = match get_config::<StorageConfig>().await {
+        Some(config) => config,
+        None => return,
+    };
+
+    let period = Duration::from_secs(config.gc_interval.max(1));
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            // GC 为单例任务，仅在主节点执行；排空期间不再开始新一轮
+            if !is_cluster_leader() || is_draining() || jobs_paused().await {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "file_storage_gc",
+                    SysFileService.collect_unreferenced_blobs(),
+                )
+                .await;
+            if let Err(e) = result {
+                project_error!("File storage GC failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!(
+        "File storage GC started, interval: {}s, grace period: {}s",
+        config.gc_interval,
+        config.gc_grace_period
+    );
+}
@@ -0,0 +1,47 @@
+use std::time::Duration;
+
+use server_config::ComplianceConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{is_cluster_leader, jobs_paused, SysUserService, TUserService};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 检查间隔，禁用按天计算，每小时检查一次即可
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 启动长期未登录账号禁用任务
+///
+/// 未开启 `compliance.inactive_account.enabled` 时不启动，集群部署时只有主节点执行
+pub async fn initialize_inactive_account_job() {
+    let enabled = get_config::<ComplianceConfig>()
+        .await
+        .is_some_and(|config| config.inactive_account.enabled);
+    if !enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(CHECK_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader() || is_draining() || jobs_paused().await {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "inactive_account_job",
+                    SysUserService.disable_inactive_users(),
+                )
+                .await;
+            if let Err(e) = result {
+                project_error!("Inactive account job failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!("Inactive account job started");
+}
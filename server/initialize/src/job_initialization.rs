@@ -0,0 +1,34 @@
+use crate::{
+    finalize_shutdown, initialize_access_review_scheduler, initialize_api_usage_rollup_job,
+    initialize_audit_archive_job, initialize_audit_chain_job, initialize_consistency_job,
+    initialize_file_storage_gc, initialize_inactive_account_job, initialize_metering_job,
+    initialize_notification_digest_job, initialize_telemetry_job, initialize_tenant_purge_job,
+    project_info, shutdown_signal,
+};
+
+/// 启动所有后台任务（队列消费、定时任务）
+///
+/// 仅在 `server.role` 为 `worker` 或 `all` 时调用
+pub async fn initialize_background_jobs() {
+    initialize_file_storage_gc().await;
+    initialize_access_review_scheduler().await;
+    initialize_inactive_account_job().await;
+    initialize_notification_digest_job().await;
+    initialize_api_usage_rollup_job().await;
+    initialize_metering_job().await;
+    initialize_tenant_purge_job().await;
+    initialize_audit_chain_job().await;
+    initialize_audit_archive_job().await;
+    initialize_telemetry_job().await;
+    initialize_consistency_job().await;
+
+    project_info!("Background jobs initialized");
+}
+
+/// 以 worker 角色运行，不监听端口，直到收到退出信号或排空完成
+pub async fn run_worker() {
+    project_info!("Running in worker mode, waiting for shutdown signal");
+
+    shutdown_signal().await;
+    finalize_shutdown().await;
+}
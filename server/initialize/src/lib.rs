@@ -1,34 +1,73 @@
 pub use access_key_initialization::initialize_access_key;
+pub use access_review_initialization::initialize_access_review_scheduler;
+pub use api_usage_initialization::initialize_api_usage_rollup_job;
+pub use audit_archive_initialization::initialize_audit_archive_job;
+pub use audit_chain_initialization::initialize_audit_chain_job;
 pub use aws_s3_initialization::{init_primary_s3, init_s3_pools};
 pub use casbin_initialization::initialize_casbin;
+pub use cluster_initialization::initialize_cluster_membership;
+pub use compatibility_initialization::check_compatibility;
 pub use config_initialization::{
-    initialize_config, initialize_config_from_env_only, initialize_config_with_env,
-    initialize_config_with_multi_instance_env,
+    initialize_config, initialize_config_from_apollo, initialize_config_from_consul,
+    initialize_config_from_env_only, initialize_config_from_etcd, initialize_config_from_nacos,
+    initialize_config_with_env, initialize_config_with_multi_instance_env,
 };
-pub use db_initialization::{init_db_pools, init_primary_connection};
+pub use consistency_initialization::initialize_consistency_job;
+pub use db_initialization::{init_db_pools, init_primary_connection, sync_db_pools};
+pub use drain_initialization::{finalize_shutdown, initialize_drain_signal, shutdown_signal};
 pub use event_channel_initialization::initialize_event_channel;
+pub use file_storage_initialization::initialize_file_storage_gc;
+pub use inactive_account_initialization::initialize_inactive_account_job;
 pub use ip2region_initialization::init_xdb;
+pub use job_initialization::{initialize_background_jobs, run_worker};
 pub use jwt_initialization::initialize_keys_and_validation;
 pub use log_tracing_init::initialize_log_tracing;
+pub use metering_initialization::initialize_metering_job;
 pub use mongo_initialization::{init_mongo_pools, init_primary_mongo};
-pub use redis_initialization::{init_primary_redis, init_redis_pools};
+pub use notification_initialization::initialize_notification_digest_job;
+pub use preflight_initialization::run_preflight;
+pub use redis_initialization::{init_primary_redis, init_redis_pools, sync_redis_pools};
 pub use router_initialization::initialize_admin_router;
+pub use runtime_initialization::{build_runtime, load_runtime_config};
 pub use server_global::{project_error, project_info};
-pub use server_initialization::get_server_address;
+pub use server_initialization::{get_server_address, get_server_role, serve};
+pub use siem_initialization::initialize_siem_exporter;
+pub use telemetry_initialization::initialize_telemetry_job;
+pub use tenant_initialization::{initialize_tenant_pools, initialize_tenant_purge_job};
 
 mod access_key_initialization;
+mod access_review_initialization;
+mod api_usage_initialization;
+mod audit_archive_initialization;
+mod audit_chain_initialization;
 mod aws_s3_initialization;
 mod casbin_initialization;
+mod cluster_initialization;
+mod compatibility_initialization;
 mod config_initialization;
+mod consistency_initialization;
+#[cfg(test)]
+mod contract_tests;
 mod db_initialization;
+mod drain_initialization;
 mod event_channel_initialization;
+mod file_storage_initialization;
+mod inactive_account_initialization;
 mod ip2region_initialization;
+mod job_initialization;
 mod jwt_initialization;
 mod log_tracing_init;
+mod metering_initialization;
 mod mongo_initialization;
+mod notification_initialization;
+mod preflight_initialization;
 mod redis_initialization;
 mod router_initialization;
+mod runtime_initialization;
 mod server_initialization;
+mod siem_initialization;
+mod telemetry_initialization;
+mod tenant_initialization;
 
 // TODO: axum_test_helpers不兼容axum 0.8.x
 // #[cfg(test)]
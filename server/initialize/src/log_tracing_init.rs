@@ -1,27 +1,85 @@
+use std::fmt::{Debug, Write};
+
+use server_config::redact_text;
+use server_core::web::error_log;
+use tracing::{
+    field::{Field, Visit},
+    Event, Level, Subscriber,
+};
 use tracing_log::LogTracer;
-use tracing_subscriber::{layer::SubscriberExt, EnvFilter, Registry};
+use tracing_subscriber::{
+    layer::{Context, SubscriberExt},
+    EnvFilter, Layer, Registry,
+};
 
 use crate::{project_error, project_info};
 
+/// 收集告警和错误日志到内存，供诊断包使用
+struct ErrorLogLayer;
+
+#[derive(Default)]
+struct MessageVisitor(String);
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn Debug) {
+        if !self.0.is_empty() {
+            self.0.push(' ');
+        }
+        if field.name() == "message" {
+            let _ = write!(self.0, "{:?}", value);
+        } else {
+            let _ = write!(self.0, "{}={:?}", field.name(), value);
+        }
+    }
+}
+
+impl<S: Subscriber> Layer<S> for ErrorLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > Level::WARN {
+            return;
+        }
+        let mut visitor = MessageVisitor::default();
+        event.record(&mut visitor);
+        error_log::record(
+            metadata.level().as_str(),
+            metadata.target(),
+            redact_text(&visitor.0),
+        );
+    }
+}
+
 pub async fn initialize_log_tracing() {
     if let Err(e) = LogTracer::init() {
         project_error!("Failed to set logger: {}", e);
         return;
     }
 
-    let env_filter = if cfg!(debug_assertions) {
-        EnvFilter::new("debug,sea_orm=debug")
+    let mut directives = if cfg!(debug_assertions) {
+        "debug,sea_orm=debug".to_string()
     } else {
-        EnvFilter::new("info,sea_orm=info")
+        "info,sea_orm=info".to_string()
     };
+    // tokio-console 依赖 tokio 运行时发出的 trace 级事件
+    if cfg!(feature = "tokio-console") {
+        directives.push_str(",tokio=trace,runtime=trace");
+    }
+    let env_filter = EnvFilter::new(directives);
 
     let fmt_layer = tracing_subscriber::fmt::layer()
         .with_target(true)
         .with_ansi(true);
 
+    #[cfg(feature = "tokio-console")]
+    let console_layer = Some(console_subscriber::spawn());
+    #[cfg(not(feature = "tokio-console"))]
+    let console_layer: Option<tracing_subscriber::layer::Identity> = None;
+
     let subscriber = Registry::default()
         .with(env_filter)
         .with(fmt_layer)
+        .with(console_layer)
+        .with(ErrorLogLayer)
         .with(tracing_error::ErrorLayer::default());
 
     if let Err(e) = tracing::subscriber::set_global_default(subscriber) {
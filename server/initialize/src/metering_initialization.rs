@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use server_config::MeteringConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysMeteringService, TMeteringService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 检查间隔：前一天已汇总时只重试未成功的推送，日期切换后一小时内完成汇总
+const METERING_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// 启动每日计量任务
+///
+/// 未启用 `metering` 时不启动，集群部署时只有主节点执行
+pub async fn initialize_metering_job() {
+    let enabled = get_config::<MeteringConfig>()
+        .await
+        .is_some_and(|config| config.enabled);
+    if !enabled {
+        return;
+    }
+
+    tokio::spawn(async move {
+        let mut ticker = interval(METERING_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("metering").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope("metering_job", SysMeteringService.run_daily())
+                .await;
+            if let Err(e) = result {
+                project_error!("Metering job failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!("Metering job started");
+}
@@ -1,15 +1,44 @@
 #![allow(dead_code)]
-use mongodb::Client;
+use mongodb::{error::Result as MongoResult, options::ClientOptions, Client};
 use server_config::{MongoConfig, MongoInstancesConfig, OptionalConfigs};
 use server_global::global::{get_config, GLOBAL_MONGO_POOL, GLOBAL_PRIMARY_MONGO};
-use std::{process, sync::Arc};
+use std::{process, sync::Arc, time::Duration};
 
 use crate::{project_error, project_info};
 
+/// 解析 URI 后用结构化配置覆盖对应的参数
+async fn client_options(config: &MongoConfig) -> MongoResult<ClientOptions> {
+    let mut options = ClientOptions::parse(config.uri.expose_secret()).await?;
+    apply_options(&mut options, config);
+    Ok(options)
+}
+
+fn apply_options(options: &mut ClientOptions, config: &MongoConfig) {
+    if let Some(max_pool_size) = config.max_pool_size {
+        options.max_pool_size = Some(max_pool_size);
+    }
+    if let Some(min_pool_size) = config.min_pool_size {
+        options.min_pool_size = Some(min_pool_size);
+    }
+    if let Some(connect_timeout) = config.connect_timeout {
+        options.connect_timeout = Some(Duration::from_secs(connect_timeout));
+    }
+    if let Some(server_selection_timeout) = config.server_selection_timeout {
+        options.server_selection_timeout = Some(Duration::from_secs(server_selection_timeout));
+    }
+    if let Some(default_database) = &config.default_database {
+        options.default_database = Some(default_database.clone());
+    }
+}
+
+async fn create_client(config: &MongoConfig) -> MongoResult<Client> {
+    Client::with_options(client_options(config).await?)
+}
+
 /// 初始化主MongoDB
 pub async fn init_primary_mongo() {
     if let Some(config) = get_config::<MongoConfig>().await {
-        match Client::with_uri_str(&config.uri).await {
+        match create_client(&config).await {
             Ok(client) => {
                 if let Err(e) = client.list_database_names().await {
                     project_error!("Failed to connect to MongoDB: {}", e);
@@ -49,7 +78,7 @@ pub async fn init_mongo_pool(
 }
 
 async fn init_mongo_connection(name: &str, config: &MongoConfig) -> Result<(), String> {
-    match Client::with_uri_str(&config.uri).await {
+    match create_client(config).await {
         Ok(client) => {
             if let Err(e) = client.list_database_names().await {
                 let error_msg = format!("Failed to connect to MongoDB '{}': {}", name, e);
@@ -170,6 +199,30 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn test_apply_options() {
+        let mut options =
+            ClientOptions::parse("mongodb://localhost:27017/app?maxPoolSize=20&minPoolSize=2")
+                .await
+                .unwrap();
+        let config = MongoConfig {
+            max_pool_size: Some(50),
+            server_selection_timeout: Some(5),
+            default_database: Some("audit".to_string()),
+            ..MongoConfig::from_uri("mongodb://localhost:27017/app")
+        };
+        apply_options(&mut options, &config);
+
+        assert_eq!(options.max_pool_size, Some(50));
+        // 未配置的选项沿用 URI 参数
+        assert_eq!(options.min_pool_size, Some(2));
+        assert_eq!(
+            options.server_selection_timeout,
+            Some(Duration::from_secs(5))
+        );
+        assert_eq!(options.default_database.as_deref(), Some("audit"));
+    }
+
     #[tokio::test]
     async fn test_mongo_pool_operations() {
         setup_logger();
@@ -177,9 +230,7 @@ mod tests {
 
         let test_config = MongoInstancesConfig {
             name: "test_mongo".to_string(),
-            mongo: MongoConfig {
-                uri: "mongodb://localhost:27017".to_string(),
-            },
+            mongo: MongoConfig::from_uri("mongodb://localhost:27017"),
         };
 
         let result = init_mongo_pool(Some(vec![test_config.clone()])).await;
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use server_config::NotificationConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysNotificationService, TNotificationService,
+};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动通知摘要任务
+///
+/// 每隔 `notification.digest_interval` 秒汇总一次待发送的低优先级通知，集群部署时只有主节点执行
+pub async fn initialize_notification_digest_job() {
+    let digest_interval = get_config::<NotificationConfig>()
+        .await
+        .map(|config| config.digest_interval)
+        .unwrap_or_else(|| NotificationConfig::default().digest_interval);
+
+    tokio::spawn(async move {
+        let mut ticker = interval(Duration::from_secs(digest_interval.max(1)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("notification").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope(
+                    "notification_digest_job",
+                    SysNotificationService.send_digests(),
+                )
+                .await;
+            if let Err(e) = result {
+                project_error!("Notification digest job failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!("Notification digest job started");
+}
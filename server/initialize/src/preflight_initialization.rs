@@ -0,0 +1,304 @@
+use std::fmt::Display;
+
+use aws_sdk_s3::{primitives::ByteStream, Client as S3Client};
+use chrono::Local;
+use migration::{Migrator, MigratorTrait};
+use mongodb::{bson::doc, Client as MongoClient};
+use sea_orm::{Database, DatabaseConnection};
+use server_config::{Config, DatabaseConfig, MongoConfig, RedisConfig, StorageConfig};
+use server_global::global::get_config;
+
+use crate::{
+    aws_s3_initialization::create_s3_client,
+    db_initialization::{build_connect_options, pool_options},
+    redis_initialization::create_redis_connection,
+};
+
+/// 单项检查结果
+struct PreflightCheck {
+    name: String,
+    result: Result<String, String>,
+}
+
+#[derive(Default)]
+struct PreflightReport {
+    checks: Vec<PreflightCheck>,
+}
+
+impl PreflightReport {
+    fn record(&mut self, name: impl Into<String>, result: Result<String, String>) {
+        self.checks.push(PreflightCheck {
+            name: name.into(),
+            result,
+        });
+    }
+
+    fn passed(&self) -> bool {
+        self.checks.iter().all(|check| check.result.is_ok())
+    }
+
+    fn print(&self) {
+        let width = self
+            .checks
+            .iter()
+            .map(|check| check.name.len())
+            .max()
+            .unwrap_or(0);
+
+        println!("Preflight report");
+        for check in &self.checks {
+            let (status, detail) = match &check.result {
+                Ok(detail) => ("  OK", detail),
+                Err(detail) => ("FAIL", detail),
+            };
+            println!(
+                "[{}] {:<width$}  {}",
+                status,
+                check.name,
+                detail,
+                width = width
+            );
+        }
+
+        let failed = self
+            .checks
+            .iter()
+            .filter(|check| check.result.is_err())
+            .count();
+        if failed == 0 {
+            println!("Preflight passed: {} checks", self.checks.len());
+        } else {
+            println!(
+                "Preflight failed: {} of {} checks failed",
+                failed,
+                self.checks.len()
+            );
+        }
+    }
+}
+
+fn describe<E: Display>(e: E) -> String {
+    e.to_string()
+}
+
+/// 启动前检查，用于发布流水线切流前确认新版本可以正常启动
+///
+/// 依次校验配置、连接所有已配置的依赖、检查数据库迁移状态和存储桶读写权限，
+/// 打印报告后返回是否全部通过，不启动 HTTP 服务
+pub async fn run_preflight(config_path: &str) -> bool {
+    let mut report = PreflightReport::default();
+
+    if let Err(e) = server_config::init_from_file_with_multi_instance_env(config_path, None).await {
+        report.record("config", Err(describe(e)));
+        report.print();
+        return false;
+    }
+    let Some(config) = get_config::<Config>().await else {
+        report.record("config", Err("configuration not loaded".to_string()));
+        report.print();
+        return false;
+    };
+    report.record(
+        "config",
+        server_config::validate_config(&config)
+            .map(|_| format!("loaded and validated {}", config_path))
+            .map_err(describe),
+    );
+
+    check_databases(&mut report, &config).await;
+    check_redis(&mut report, &config).await;
+    check_mongo(&mut report, &config).await;
+    check_s3(&mut report, &config).await;
+
+    report.print();
+    report.passed()
+}
+
+async fn check_databases(report: &mut PreflightReport, config: &Config) {
+    match connect_database(&config.database).await {
+        Ok(db) => {
+            report.record("database:primary", Ok("connected".to_string()));
+            report.record("migrations", check_migrations(&db).await);
+        },
+        Err(e) => {
+            report.record("database:primary", Err(e));
+            report.record(
+                "migrations",
+                Err("skipped, primary database unavailable".to_string()),
+            );
+        },
+    }
+
+    check_replicas(report, "primary", &config.database).await;
+    for instance in config.database_instances.iter().flatten() {
+        report.record(
+            format!("database:{}", instance.name),
+            connect_database(&instance.database)
+                .await
+                .map(|_| "connected".to_string()),
+        );
+        check_replicas(report, &instance.name, &instance.database).await;
+    }
+}
+
+async fn connect_database(config: &DatabaseConfig) -> Result<DatabaseConnection, String> {
+    let db = Database::connect(build_connect_options(config))
+        .await
+        .map_err(describe)?;
+    db.ping().await.map_err(describe)?;
+    Ok(db)
+}
+
+/// 只读副本不可用时启动后读请求回退到其余副本或主库，这里仍按失败报告
+async fn check_replicas(report: &mut PreflightReport, name: &str, config: &DatabaseConfig) {
+    for (index, url) in config.replica_connect_urls().into_iter().enumerate() {
+        let result = async {
+            let db = Database::connect(pool_options(config, url))
+                .await
+                .map_err(describe)?;
+            db.ping().await.map_err(describe)
+        }
+        .await;
+        report.record(
+            format!("database:{}:replica{}", name, index),
+            result.map(|_| "connected".to_string()),
+        );
+    }
+}
+
+async fn check_migrations(db: &DatabaseConnection) -> Result<String, String> {
+    let pending = Migrator::get_pending_migrations(db)
+        .await
+        .map_err(describe)?;
+    if pending.is_empty() {
+        return Ok("up to date".to_string());
+    }
+
+    let names: Vec<&str> = pending.iter().map(|migration| migration.name()).collect();
+    Err(format!("{} pending: {}", names.len(), names.join(", ")))
+}
+
+async fn check_redis(report: &mut PreflightReport, config: &Config) {
+    let primary = config.redis.iter().map(|redis| ("primary", redis));
+    let instances = config
+        .redis_instances
+        .iter()
+        .flatten()
+        .map(|instance| (instance.name.as_str(), &instance.redis));
+
+    for (name, redis) in primary.chain(instances) {
+        report.record(format!("redis:{}", name), ping_redis(redis).await);
+    }
+}
+
+async fn ping_redis(config: &RedisConfig) -> Result<String, String> {
+    // 建立连接时已执行 PING
+    create_redis_connection(config)
+        .await
+        .map(|_| "PING ok".to_string())
+}
+
+async fn check_mongo(report: &mut PreflightReport, config: &Config) {
+    let primary = config.mongo.iter().map(|mongo| ("primary", mongo));
+    let instances = config
+        .mongo_instances
+        .iter()
+        .flatten()
+        .map(|instance| (instance.name.as_str(), &instance.mongo));
+
+    for (name, mongo) in primary.chain(instances) {
+        report.record(format!("mongo:{}", name), ping_mongo(mongo).await);
+    }
+}
+
+async fn ping_mongo(config: &MongoConfig) -> Result<String, String> {
+    let client = MongoClient::with_uri_str(config.uri.expose_secret())
+        .await
+        .map_err(describe)?;
+    client
+        .database("admin")
+        .run_command(doc! { "ping": 1 })
+        .await
+        .map_err(describe)?;
+    Ok("ping ok".to_string())
+}
+
+async fn check_s3(report: &mut PreflightReport, config: &Config) {
+    let storage = get_config::<StorageConfig>().await;
+    let primary = config.s3.iter().map(|s3| (None, s3));
+    let instances = config
+        .s3_instances
+        .iter()
+        .flatten()
+        .map(|instance| (Some(instance.name.as_str()), &instance.s3));
+
+    for (name, s3) in primary.chain(instances) {
+        let label = format!("s3:{}", name.unwrap_or("primary"));
+        let client = match create_s3_client(s3).await {
+            Ok(client) => client,
+            Err(e) => {
+                report.record(label, Err(e));
+                continue;
+            },
+        };
+        report.record(label, Ok("connected".to_string()));
+
+        // 文件存储使用的实例额外检查存储桶读写权限
+        if let Some(storage) = storage
+            .as_deref()
+            .filter(|storage| storage.s3_instance.as_deref() == name)
+        {
+            let Some(bucket) = storage.resolve_bucket(s3) else {
+                report.record("storage", Err("no bucket configured".to_string()));
+                continue;
+            };
+            report.record(
+                format!("storage:{}", bucket),
+                check_bucket_access(&client, storage, &bucket).await,
+            );
+        }
+    }
+}
+
+async fn check_bucket_access(
+    client: &S3Client,
+    storage: &StorageConfig,
+    bucket: &str,
+) -> Result<String, String> {
+    client
+        .head_bucket()
+        .bucket(bucket)
+        .send()
+        .await
+        .map_err(|e| format!("bucket not accessible: {}", describe(e)))?;
+
+    let key = format!(
+        "{}/.preflight-{}",
+        storage.blob_prefix.trim_end_matches('/'),
+        Local::now().timestamp_millis()
+    );
+    client
+        .put_object()
+        .bucket(bucket)
+        .key(&key)
+        .body(ByteStream::from_static(b"preflight"))
+        .send()
+        .await
+        .map_err(|e| format!("write denied: {}", describe(e)))?;
+    client
+        .get_object()
+        .bucket(bucket)
+        .key(&key)
+        .send()
+        .await
+        .map_err(|e| format!("read denied: {}", describe(e)))?;
+    client
+        .delete_object()
+        .bucket(bucket)
+        .key(&key)
+        .send()
+        .await
+        .map_err(|e| format!("delete denied: {}", describe(e)))?;
+
+    Ok("read/write/delete ok".to_string())
+}
@@ -1,8 +1,17 @@
 #![allow(dead_code)]
-use redis::{cluster::ClusterClient, Client};
-use server_config::{OptionalConfigs, RedisConfig, RedisInstancesConfig, RedisMode};
-use server_global::global::{get_config, RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL};
-use std::{process, sync::Arc};
+use redis::{
+    aio::{ConnectionLike, ConnectionManagerConfig},
+    cluster::ClusterClientBuilder,
+    Client, ClientTlsConfig, ConnectionAddr, ConnectionInfo, IntoConnectionInfo, TlsCertificates,
+};
+use server_config::{
+    OptionalConfigs, RedisConfig, RedisInstancesConfig, RedisMode, RedisTlsConfig,
+};
+use server_global::global::{
+    get_config, RedisClusterPool, RedisConnection, RedisPool, GLOBAL_PRIMARY_REDIS,
+    GLOBAL_REDIS_POOL,
+};
+use std::{process, sync::Arc, time::Duration};
 
 use crate::{project_error, project_info};
 
@@ -29,7 +38,9 @@ pub async fn init_primary_redis() {
     }
 }
 
-async fn create_redis_connection(config: &RedisConfig) -> Result<RedisConnection, String> {
+pub(crate) async fn create_redis_connection(
+    config: &RedisConfig,
+) -> Result<RedisConnection, String> {
     if config.mode == RedisMode::Cluster {
         create_cluster_connection(config).await
     } else {
@@ -42,26 +53,29 @@ async fn create_single_connection(config: &RedisConfig) -> Result<RedisConnectio
         .get_url()
         .ok_or_else(|| "URL is required for single mode Redis".to_string())?;
 
-    let client = redis::Client::open(url.as_str())
-        .map_err(|e| format!("Failed to create Redis client: {}", e))?;
-
-    test_single_connection(&client).await?;
-
-    Ok(RedisConnection::Single(Arc::new(client)))
-}
+    let info = connection_info(&url, config)?;
+    let client = match tls_certificates(&config.tls)? {
+        Some(certificates) => Client::build_with_tls(info, certificates),
+        None => Client::open(info),
+    }
+    .map_err(|e| format!("Failed to create Redis client: {}", e))?;
+
+    let mut manager_config = ConnectionManagerConfig::new()
+        .set_connection_timeout(Duration::from_secs(config.connection_timeout))
+        .set_number_of_retries(config.retry.max_retries as usize)
+        .set_max_delay(config.retry.max_delay_ms);
+    if let Some(timeout) = response_timeout(config) {
+        manager_config = manager_config.set_response_timeout(timeout);
+    }
 
-async fn test_single_connection(client: &Client) -> Result<(), String> {
-    let mut con = client
-        .get_multiplexed_async_connection()
+    let pool = RedisPool::new(client, config.pool_size, manager_config);
+    let mut con = pool
+        .get_connection()
         .await
         .map_err(|e| format!("Failed to create connection manager: {}", e))?;
+    ping(&mut con).await?;
 
-    let _: String = redis::cmd("PING")
-        .query_async(&mut con)
-        .await
-        .map_err(|e| format!("Failed to connect to Redis: {}", e))?;
-
-    Ok(())
+    Ok(RedisConnection::Single(Arc::new(pool)))
 }
 
 async fn create_cluster_connection(config: &RedisConfig) -> Result<RedisConnection, String> {
@@ -73,23 +87,105 @@ async fn create_cluster_connection(config: &RedisConfig) -> Result<RedisConnecti
         return Err("Cluster mode requires at least one URL".to_string());
     }
 
-    let client =
-        redis::cluster::ClusterClient::new(urls.iter().map(|s| s.as_str()).collect::<Vec<_>>())
-            .map_err(|e| format!("Failed to create Redis cluster client: {}", e))?;
+    let nodes = urls
+        .iter()
+        .map(|url| connection_info(url, config))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut builder = ClusterClientBuilder::new(nodes)
+        .connection_timeout(Duration::from_secs(config.connection_timeout))
+        .retries(config.retry.max_retries)
+        .max_retry_wait(config.retry.max_delay_ms);
+    if let Some(timeout) = response_timeout(config) {
+        builder = builder.response_timeout(timeout);
+    }
+    if let Some(certificates) = tls_certificates(&config.tls)? {
+        builder = builder.certs(certificates);
+    }
+    let client = builder
+        .build()
+        .map_err(|e| format!("Failed to create Redis cluster client: {}", e))?;
 
-    test_cluster_connection(&client).await?;
+    let pool = RedisClusterPool::new(client, config.pool_size);
+    let mut con = pool
+        .get_connection()
+        .await
+        .map_err(|e| format!("Failed to connect to Redis cluster: {}", e))?;
+    ping(&mut con).await?;
 
-    Ok(RedisConnection::Cluster(Arc::new(client)))
+    Ok(RedisConnection::Cluster(Arc::new(pool)))
 }
 
-async fn test_cluster_connection(client: &ClusterClient) -> Result<(), String> {
-    let mut con = client
-        .get_async_connection()
-        .await
-        .map_err(|e| format!("Failed to connect to Redis cluster: {}", e))?;
+/// 解析连接地址，开启 TLS 时把 `redis://` 地址升级为 TLS 连接
+fn connection_info(url: &str, config: &RedisConfig) -> Result<ConnectionInfo, String> {
+    let mut info = url
+        .into_connection_info()
+        .map_err(|e| format!("Invalid Redis URL: {}", e))?;
+    if !config.use_tls() {
+        return Ok(info);
+    }
 
+    let skip_verify = config.tls.insecure_skip_verify;
+    info.addr = match info.addr {
+        ConnectionAddr::Tcp(host, port) => ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure: skip_verify,
+            tls_params: None,
+        },
+        ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure,
+            tls_params,
+        } => ConnectionAddr::TcpTls {
+            host,
+            port,
+            insecure: insecure || skip_verify,
+            tls_params,
+        },
+        ConnectionAddr::Unix(_) => {
+            return Err("TLS is not supported for unix socket connections".to_string())
+        },
+    };
+    Ok(info)
+}
+
+/// 读取自定义证书，未配置时返回 `None`，使用系统信任的根证书
+fn tls_certificates(tls: &RedisTlsConfig) -> Result<Option<TlsCertificates>, String> {
+    if !tls.has_certificates() {
+        return Ok(None);
+    }
+
+    let root_cert = tls.ca_cert.as_deref().map(read_pem).transpose()?;
+    let client_tls = match (&tls.client_cert, &tls.client_key) {
+        (Some(cert), Some(key)) => Some(ClientTlsConfig {
+            client_cert: read_pem(cert)?,
+            client_key: read_pem(key.expose_secret())?,
+        }),
+        _ => None,
+    };
+    Ok(Some(TlsCertificates {
+        client_tls,
+        root_cert,
+    }))
+}
+
+/// 以 `-----BEGIN` 开头的值按 PEM 内容使用，否则按文件路径读取
+fn read_pem(value: &str) -> Result<Vec<u8>, String> {
+    if value.trim_start().starts_with("-----BEGIN") {
+        return Ok(value.as_bytes().to_vec());
+    }
+    std::fs::read(value).map_err(|e| format!("Failed to read TLS file '{}': {}", value, e))
+}
+
+/// 命令响应超时，配置为 0 时不限制
+fn response_timeout(config: &RedisConfig) -> Option<Duration> {
+    (config.response_timeout > 0).then(|| Duration::from_secs(config.response_timeout))
+}
+
+async fn ping<C: ConnectionLike>(con: &mut C) -> Result<(), String> {
     let _: String = redis::cmd("PING")
-        .query_async(&mut con)
+        .query_async(con)
         .await
         .map_err(|e| format!("Failed to connect to Redis: {}", e))?;
 
@@ -136,6 +232,27 @@ pub async fn init_redis_pools() {
     }
 }
 
+/// 按当前配置同步 Redis 实例连接：新增的实例建立连接，配置中已删除的实例移除连接，
+/// 已存在的实例保持不变
+pub async fn sync_redis_pools() {
+    let instances = get_config::<OptionalConfigs<RedisInstancesConfig>>()
+        .await
+        .and_then(|config| config.configs.clone())
+        .unwrap_or_default();
+    let existing: Vec<String> = GLOBAL_REDIS_POOL.read().await.keys().cloned().collect();
+
+    for instance in &instances {
+        if !existing.contains(&instance.name) {
+            let _ = init_redis_connection(&instance.name, &instance.redis).await;
+        }
+    }
+    for name in existing {
+        if !instances.iter().any(|instance| instance.name == name) {
+            let _ = remove_redis_pool(&name).await;
+        }
+    }
+}
+
 pub async fn get_primary_redis() -> Option<RedisConnection> {
     GLOBAL_PRIMARY_REDIS.read().await.clone()
 }
@@ -163,6 +280,7 @@ mod tests {
     use crate::initialize_config;
     use log::LevelFilter;
     use redis::AsyncCommands;
+    use server_config::{RedisRetryConfig, RedisTlsConfig};
     use simple_logger::SimpleLogger;
     use tokio::sync::Mutex;
 
@@ -184,14 +302,14 @@ mod tests {
         match connection {
             RedisConnection::Single(client) => {
                 let mut con = client
-                    .get_multiplexed_async_connection()
+                    .get_connection()
                     .await
                     .map_err(|e| format!("Failed to get connection: {}", e))?;
                 test_redis_basic_operations(&mut con).await
             },
             RedisConnection::Cluster(client) => {
                 let mut con = client
-                    .get_async_connection()
+                    .get_connection()
                     .await
                     .map_err(|e| format!("Failed to get cluster connection: {}", e))?;
                 test_redis_basic_operations(&mut con).await
@@ -257,9 +375,15 @@ mod tests {
             name: "test_single".to_string(),
             redis: RedisConfig {
                 mode: RedisMode::Single,
-                url: Some("redis://:123456@bytebytebrew.local:26379/11".to_string()),
+                url: Some("redis://:123456@bytebytebrew.local:26379/11".into()),
                 urls: None,
+                pool_size: 2,
+                connection_timeout: 5,
+                response_timeout: 0,
+                retry: RedisRetryConfig::default(),
+                tls: RedisTlsConfig::default(),
             },
+            fallback: None,
         };
 
         let result = init_redis_pool(Some(vec![single_config.clone()])).await;
@@ -321,4 +445,27 @@ mod tests {
             GLOBAL_REDIS_POOL.read().await.len()
         );
     }
+
+    #[test]
+    fn test_connection_info_tls_upgrade() {
+        let mut config: RedisConfig = serde_json::from_value(serde_json::json!({
+            "mode": "single",
+            "url": "redis://cache:6379/0",
+        }))
+        .unwrap();
+        let info = connection_info("redis://cache:6379/0", &config).unwrap();
+        assert!(matches!(info.addr, ConnectionAddr::Tcp(_, 6379)));
+
+        config.tls.enabled = true;
+        config.tls.insecure_skip_verify = true;
+        let info = connection_info("redis://cache:6379/0", &config).unwrap();
+        assert!(matches!(
+            info.addr,
+            ConnectionAddr::TcpTls { insecure: true, .. }
+        ));
+        assert!(tls_certificates(&config.tls).unwrap().is_none());
+
+        config.tls.ca_cert = Some("/nonexistent/ca.pem".to_string());
+        assert!(tls_certificates(&config.tls).is_err());
+    }
 }
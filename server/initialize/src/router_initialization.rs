@@ -4,25 +4,65 @@ use axum::{body::Body, http::StatusCode, response::IntoResponse, Extension, Rout
 use axum_casbin::CasbinAxumLayer;
 use chrono::Local;
 use http::Request;
-use server_config::Config;
+use server_config::{
+    AccessLogConfig, ApiUsageConfig, ComplianceConfig, ConcurrencyConfig, Config, RecorderConfig,
+    SecurityConfig, ServerConfig, TelemetryConfig,
+};
 use server_constant::definition::Audience;
 use server_core::sign::{
     api_key_middleware, protect_route, ApiKeySource, ApiKeyValidation, ComplexApiKeyConfig,
     SimpleApiKeyConfig, ValidatorType,
 };
-use server_core::web::{RequestId, RequestIdLayer};
-use server_global::global::{clear_routes, get_collected_routes, get_config};
-use server_middleware::jwt_auth_middleware;
+use server_core::web::{
+    access_log::{access_log_middleware, AccessLogger},
+    admission::{admission_middleware, init_admission_controller},
+    bot_guard::bot_detection_middleware,
+    break_glass::{break_glass_middleware, BreakGlassSessionChecker},
+    drain::in_flight_middleware,
+    environment_guard::{environment_guard_middleware, SensitiveOperationApprover},
+    field_selection::field_selection_middleware,
+    module_gate::{module_gate_middleware, ModuleStatusChecker},
+    personal_token::PersonalTokenResolver,
+    policy_gate::{policy_gate_middleware, PolicyAcceptanceChecker},
+    query_counter::query_counter_middleware,
+    recorder::recorder_middleware,
+    request_limits::request_limits_middleware,
+    rls::rls_context_middleware,
+    step_up::step_up_middleware,
+    telemetry::telemetry_middleware,
+    tenant_gate::{tenant_gate_middleware, TenantStatusChecker},
+    trace_context_middleware,
+    usage::usage_middleware,
+    RequestId, RequestIdLayer,
+};
+use server_global::global::{
+    clear_menus, clear_routes, get_collected_menus, get_collected_routes, get_config,
+    set_route_stacks, RouteGuard, RouteInfo, RouteStack,
+};
+use server_middleware::bearer_auth_middleware;
 use server_router::admin::{
-    SysAccessKeyRouter, SysAuthenticationRouter, SysDomainRouter, SysEndpointRouter,
-    SysLoginLogRouter, SysMenuRouter, SysOperationLogRouter, SysOrganizationRouter, SysRoleRouter,
-    SysSandboxRouter, SysUserRouter,
+    SysAccessKeyRouter, SysAccessReviewRouter, SysAlertRuleRouter, SysApiUsageRouter,
+    SysAuthenticationRouter, SysBreakGlassRouter, SysClusterRouter, SysConfigRouter,
+    SysConsistencyRouter, SysDbPoolRouter, SysDeletionRouter, SysDiagnosticsRouter,
+    SysDomainRouter, SysEndpointRouter, SysFileRouter, SysInstanceRouter, SysLoginLogRouter,
+    SysMaintenanceWindowRouter, SysMenuRouter, SysMeteringRouter, SysMigrationRouter,
+    SysModuleRouter, SysNotificationRouter, SysOperationLogRouter, SysOrganizationRouter,
+    SysPasskeyRouter, SysPersonalTokenRouter, SysPolicyRouter, SysRecorderRouter,
+    SysReferenceRouter, SysRegionRouter, SysRoleRouter, SysSandboxRouter,
+    SysSensitiveOperationRouter, SysTelemetryRouter, SysTenantRouter, SysUserRouter,
 };
 use server_service::{
     admin::{
-        SysAccessKeyService, SysAuthService, SysAuthorizationService, SysDomainService,
-        SysEndpointService, SysLoginLogService, SysMenuService, SysOperationLogService,
-        SysOrganizationService, SysRoleService, SysUserService, TEndpointService,
+        module_for_path, SysAccessKeyService, SysAccessReviewService, SysAlertRuleService,
+        SysApiUsageService, SysAuthService, SysAuthorizationService, SysBreakGlassService,
+        SysClusterService, SysConfigService, SysConsistencyService, SysDbPoolService,
+        SysDeletionService, SysDiagnosticsService, SysDomainService, SysEndpointService,
+        SysFileService, SysInstanceService, SysLoginLogService, SysMaintenanceWindowService,
+        SysMenuService, SysMeteringService, SysMigrationService, SysModuleService,
+        SysNotificationService, SysOperationLogService, SysOrganizationService, SysPasskeyService,
+        SysPersonalTokenService, SysPolicyService, SysRecorderService, SysReferenceService,
+        SysRegionService, SysRoleService, SysSensitiveOperationService, SysTelemetryService,
+        SysTenantService, SysUserService, TEndpointService, TMenuService,
     },
     SysEndpoint,
 };
@@ -31,6 +71,15 @@ use tracing::info_span;
 
 use crate::{initialize_casbin, project_error, project_info};
 
+/// 一组路由挂载时实际应用的防护
+struct MountedRoutes {
+    routes: Vec<RouteInfo>,
+    need_casbin: bool,
+    need_auth: bool,
+    /// 挂载时应用的中间件，最外层在前
+    layers: Vec<&'static str>,
+}
+
 #[derive(Clone)]
 pub enum Services<T: Send + Sync + 'static> {
     None(std::marker::PhantomData<T>),
@@ -45,13 +94,51 @@ async fn apply_layers<T: Send + Sync + 'static>(
     api_validation: Option<ApiKeyValidation>,
     casbin: Option<CasbinAxumLayer>,
     audience: Audience,
-) -> Router {
+) -> (Router, Vec<&'static str>) {
+    let mut layers = Vec::new();
     let mut router = match services {
         Services::None(_) => router,
         Services::Single(service) => router.layer(Extension(service)),
     };
 
+    // 字段裁剪紧贴处理函数，外层的录制和调用统计看到的是裁剪后的响应
+    let field_access = Arc::new(
+        get_config::<SecurityConfig>()
+            .await
+            .map(|config| config.field_access.clone())
+            .unwrap_or_default(),
+    );
+    router = router.layer(axum::middleware::from_fn(move |req, next| {
+        field_selection_middleware(field_access.clone(), req, next)
+    }));
+    layers.push("field_selection");
+
+    // 调用统计读取鉴权和 API Key 校验写入的身份，位于最内层
+    let api_usage_enabled = get_config::<ApiUsageConfig>()
+        .await
+        .is_some_and(|config| config.enabled);
+    if api_usage_enabled {
+        router = router.layer(axum::middleware::from_fn(usage_middleware));
+        layers.push("api_usage");
+    }
+
+    // N+1 查询检测只在调试构建中挂载，发布构建不统计
+    if cfg!(debug_assertions) {
+        let threshold = get_config::<RecorderConfig>()
+            .await
+            .map(|config| config.n_plus_one_threshold)
+            .unwrap_or_else(|| RecorderConfig::default().n_plus_one_threshold);
+        if threshold > 0 {
+            router = router.layer(axum::middleware::from_fn(move |req, next| {
+                query_counter_middleware(threshold, req, next)
+            }));
+            layers.push("query_counter");
+        }
+    }
+
+    // 请求录制位于鉴权内层，以便按用户筛选
     router = router
+        .layer(axum::middleware::from_fn(recorder_middleware))
         .layer(
             TraceLayer::new_for_http().make_span_with(|request: &Request<Body>| {
                 let request_id = request
@@ -67,11 +154,33 @@ async fn apply_layers<T: Send + Sync + 'static>(
                 )
             }),
         )
+        .layer(axum::middleware::from_fn(trace_context_middleware))
         .layer(RequestIdLayer);
+    layers.extend(["recorder", "trace", "trace_context", "request_id"]);
+
+    // 生产环境的敏感操作防护位于 Casbin、API Key 和行级安全的内层，
+    // 被这些检查拒绝的请求不会核销审批
+    let production = get_config::<ServerConfig>()
+        .await
+        .is_some_and(|config| config.environment.is_production());
+    if need_auth && production {
+        let sensitive_operation = Arc::new(
+            get_config::<SecurityConfig>()
+                .await
+                .map(|config| config.sensitive_operation.clone())
+                .unwrap_or_default(),
+        );
+        let approver: Arc<dyn SensitiveOperationApprover> = Arc::new(SysSensitiveOperationService);
+        router = router.layer(axum::middleware::from_fn(move |req, next| {
+            environment_guard_middleware(sensitive_operation.clone(), approver.clone(), req, next)
+        }));
+        layers.push("environment_guard");
+    }
 
     if need_casbin {
         if let Some(casbin) = casbin {
             router = router.layer(Extension(casbin.clone())).layer(casbin);
+            layers.push("casbin");
         }
     }
 
@@ -79,41 +188,91 @@ async fn apply_layers<T: Send + Sync + 'static>(
         router = router.layer(axum::middleware::from_fn(move |req, next| {
             api_key_middleware(validation.clone(), req, next)
         }));
+        layers.push("api_key");
     }
 
     if need_auth {
+        // 行级安全上下文包裹处理函数，须位于鉴权内层
+        let row_level_security = get_config::<SecurityConfig>()
+            .await
+            .is_some_and(|config| config.row_level_security.enabled);
+        if row_level_security {
+            router = router.layer(axum::middleware::from_fn(rls_context_middleware));
+            layers.push("rls_context");
+        }
+        // 二次认证需要读取 JWT 解析出的用户，位于鉴权内层
+        let step_up = get_config::<SecurityConfig>()
+            .await
+            .map(|config| config.step_up.clone())
+            .unwrap_or_default();
+        if step_up.enabled {
+            let step_up = Arc::new(step_up);
+            router = router.layer(axum::middleware::from_fn(move |req, next| {
+                step_up_middleware(step_up.clone(), req, next)
+            }));
+            layers.push("step_up");
+        }
+        let policy_gate = get_config::<ComplianceConfig>()
+            .await
+            .map(|config| config.policy_gate.clone())
+            .unwrap_or_default();
+        if policy_gate.enabled {
+            let policy_gate = Arc::new(policy_gate);
+            let checker: Arc<dyn PolicyAcceptanceChecker> = Arc::new(SysPolicyService);
+            router = router.layer(axum::middleware::from_fn(move |req, next| {
+                policy_gate_middleware(policy_gate.clone(), checker.clone(), req, next)
+            }));
+            layers.push("policy_gate");
+        }
+        let tenant_checker: Arc<dyn TenantStatusChecker> = Arc::new(SysTenantService);
         router = router.layer(axum::middleware::from_fn(move |req, next| {
-            jwt_auth_middleware(req, next, audience.as_str())
+            tenant_gate_middleware(tenant_checker.clone(), req, next)
         }));
+        layers.push("tenant_gate");
+        // 紧急访问会话紧贴鉴权，记录包括准入拦截在内的全部请求
+        let session_checker: Arc<dyn BreakGlassSessionChecker> = Arc::new(SysBreakGlassService);
+        router = router.layer(axum::middleware::from_fn(move |req, next| {
+            break_glass_middleware(session_checker.clone(), req, next)
+        }));
+        // 个人访问令牌和 JWT 共用 Bearer 认证，按令牌前缀区分
+        let token_resolver: Arc<dyn PersonalTokenResolver> = Arc::new(SysPersonalTokenService);
+        router = router.layer(axum::middleware::from_fn(move |req, next| {
+            bearer_auth_middleware(token_resolver.clone(), req, next, audience.as_str())
+        }));
+        layers.extend(["break_glass", "jwt_auth"]);
     }
 
-    router
+    layers.reverse();
+    (router, layers)
 }
 
 pub async fn initialize_admin_router() -> Router {
     clear_routes().await;
+    clear_menus().await;
     project_info!("Initializing admin router");
 
     let app_config = get_config::<Config>().await.unwrap();
     let casbin_layer = initialize_casbin(
         "server/resources/rbac_model.conf",
-        app_config.database.url.as_str(),
+        &app_config.database.connect_url(),
     )
     .await
     .unwrap();
 
     // 初始化验证器
     // 根据是否配置了 Redis 来选择 nonce 存储实现
-    let nonce_store_factory =
-        if let Some(_) = crate::redis_initialization::get_primary_redis().await {
-            // 如果 Redis 可用，使用 Redis 作为 nonce 存储
-            project_info!("Using Redis for nonce storage");
-            server_core::sign::create_redis_nonce_store_factory("api_key")
-        } else {
-            // 否则使用内存存储
-            project_info!("Using memory for nonce storage");
-            server_core::sign::create_memory_nonce_store_factory()
-        };
+    let nonce_store_factory = if crate::redis_initialization::get_primary_redis()
+        .await
+        .is_some()
+    {
+        // 如果 Redis 可用，使用 Redis 作为 nonce 存储
+        project_info!("Using Redis for nonce storage");
+        server_core::sign::create_redis_nonce_store_factory("api_key")
+    } else {
+        // 否则使用内存存储
+        project_info!("Using memory for nonce storage");
+        server_core::sign::create_memory_nonce_store_factory()
+    };
 
     server_core::sign::init_validators_with_nonce_store(None, nonce_store_factory.clone()).await;
 
@@ -155,35 +314,55 @@ pub async fn initialize_admin_router() -> Router {
     let audience = Audience::ManagementPlatform;
     let casbin = Some(casbin_layer);
     let mut app = Router::new();
+    let mut mounts: Vec<MountedRoutes> = Vec::new();
+
+    // 记录路由初始化期间声明的路由和挂载时应用的防护，用于启动时检查
+    macro_rules! track_mount {
+        ($router:expr, $need_casbin:expr, $need_auth:expr) => {{
+            let declared = get_collected_routes().await.len();
+            let router = $router;
+            mounts.push(MountedRoutes {
+                routes: get_collected_routes().await.split_off(declared),
+                need_casbin: $need_casbin,
+                need_auth: $need_auth,
+                layers: Vec::new(),
+            });
+            router
+        }};
+    }
 
     macro_rules! merge_router {
         ($router:expr, None, $need_casbin:expr, $need_auth:expr, $api_validation:expr) => {
-            app = app.merge(
-                apply_layers(
-                    $router,
-                    Services::None(std::marker::PhantomData::<()>),
-                    $need_casbin,
-                    $need_auth,
-                    $api_validation,
-                    casbin.clone(),
-                    audience,
-                )
-                .await,
-            );
+            let (router, layers) = apply_layers(
+                track_mount!($router, $need_casbin, $need_auth),
+                Services::None(std::marker::PhantomData::<()>),
+                $need_casbin,
+                $need_auth,
+                $api_validation,
+                casbin.clone(),
+                audience,
+            )
+            .await;
+            if let Some(mount) = mounts.last_mut() {
+                mount.layers = layers;
+            }
+            app = app.merge(router);
         };
         ($router:expr, $service:expr, $need_casbin:expr, $need_auth:expr, $api_validation:expr) => {
-            app = app.merge(
-                apply_layers(
-                    $router,
-                    Services::Single(Arc::new($service)),
-                    $need_casbin,
-                    $need_auth,
-                    $api_validation,
-                    casbin.clone(),
-                    audience,
-                )
-                .await,
-            );
+            let (router, layers) = apply_layers(
+                track_mount!($router, $need_casbin, $need_auth),
+                Services::Single(Arc::new($service)),
+                $need_casbin,
+                $need_auth,
+                $api_validation,
+                casbin.clone(),
+                audience,
+            )
+            .await;
+            if let Some(mount) = mounts.last_mut() {
+                mount.layers = layers;
+            }
+            app = app.merge(router);
         };
     }
 
@@ -195,14 +374,25 @@ pub async fn initialize_admin_router() -> Router {
         None
     );
 
-    let auth_router = SysAuthenticationRouter::init_authorization_router()
-        .await
-        .layer(Extension(Arc::new(SysAuthService) as Arc<SysAuthService>))
-        .layer(Extension(
-            Arc::new(SysAuthorizationService) as Arc<SysAuthorizationService>
-        ));
+    merge_router!(
+        SysMaintenanceWindowRouter::init_banner_router().await,
+        SysMaintenanceWindowService,
+        false,
+        false,
+        None
+    );
+
+    let auth_router = track_mount!(
+        SysAuthenticationRouter::init_authorization_router().await,
+        true,
+        true
+    )
+    .layer(Extension(Arc::new(SysAuthService) as Arc<SysAuthService>))
+    .layer(Extension(
+        Arc::new(SysAuthorizationService) as Arc<SysAuthorizationService>
+    ));
 
-    let auth_router = apply_layers(
+    let (auth_router, layers) = apply_layers(
         auth_router,
         Services::None(std::marker::PhantomData::<()>),
         true,
@@ -212,6 +402,9 @@ pub async fn initialize_admin_router() -> Router {
         audience,
     )
     .await;
+    if let Some(mount) = mounts.last_mut() {
+        mount.layers = layers;
+    }
 
     app = app.merge(auth_router);
 
@@ -223,6 +416,46 @@ pub async fn initialize_admin_router() -> Router {
         None
     );
 
+    merge_router!(
+        SysPasskeyRouter::init_passkey_router().await,
+        SysPasskeyService,
+        false,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysPersonalTokenRouter::init_personal_token_router().await,
+        SysPersonalTokenService,
+        false,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysNotificationRouter::init_notification_router().await,
+        SysNotificationService,
+        false,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysPolicyRouter::init_acceptance_router().await,
+        SysPolicyService,
+        false,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysRegionRouter::init_region_query_router().await,
+        SysRegionService,
+        false,
+        true,
+        None
+    );
+
     merge_router!(
         SysMenuRouter::init_menu_router().await,
         SysMenuService,
@@ -260,6 +493,34 @@ pub async fn initialize_admin_router() -> Router {
         true,
         None
     );
+    merge_router!(
+        SysReferenceRouter::init_reference_router().await,
+        SysReferenceService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysDeletionRouter::init_deletion_router().await,
+        SysDeletionService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysConsistencyRouter::init_consistency_router().await,
+        SysConsistencyService,
+        true,
+        true,
+        None
+    );
+    merge_router!(
+        SysDiagnosticsRouter::init_diagnostics_router().await,
+        SysDiagnosticsService,
+        true,
+        true,
+        None
+    );
     merge_router!(
         SysEndpointRouter::init_endpoint_router().await,
         SysEndpointService,
@@ -289,6 +550,200 @@ pub async fn initialize_admin_router() -> Router {
         None
     );
 
+    merge_router!(
+        SysFileRouter::init_file_router().await,
+        SysFileService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysFileRouter::init_share_router().await,
+        SysFileService,
+        false,
+        false,
+        None
+    );
+
+    merge_router!(
+        SysClusterRouter::init_cluster_router().await,
+        SysClusterService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysConfigRouter::init_config_router().await,
+        SysConfigService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysDbPoolRouter::init_db_pool_router().await,
+        SysDbPoolService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysMigrationRouter::init_migration_router().await,
+        SysMigrationService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysModuleRouter::init_module_router().await,
+        SysModuleService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysInstanceRouter::init_health_router().await,
+        SysInstanceService,
+        false,
+        false,
+        None
+    );
+
+    merge_router!(
+        SysInstanceRouter::init_instance_router().await,
+        SysInstanceService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysAlertRuleRouter::init_alert_rule_router().await,
+        SysAlertRuleService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysMaintenanceWindowRouter::init_maintenance_window_router().await,
+        SysMaintenanceWindowService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysApiUsageRouter::init_api_usage_router().await,
+        SysApiUsageService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysMeteringRouter::init_metering_router().await,
+        SysMeteringService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysTelemetryRouter::init_telemetry_router().await,
+        SysTelemetryService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysTenantRouter::init_tenant_router().await,
+        SysTenantService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysSensitiveOperationRouter::init_sensitive_operation_router().await,
+        SysSensitiveOperationService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysBreakGlassRouter::init_activation_router().await,
+        SysBreakGlassService,
+        false,
+        false,
+        None
+    );
+
+    merge_router!(
+        SysBreakGlassRouter::init_break_glass_router().await,
+        SysBreakGlassService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysAccessReviewRouter::init_access_review_router().await,
+        SysAccessReviewService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysPolicyRouter::init_policy_router().await,
+        SysPolicyService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysRegionRouter::init_region_router().await,
+        SysRegionService,
+        true,
+        true,
+        None
+    );
+
+    merge_router!(
+        SysRecorderRouter::init_recorder_router().await,
+        SysRecorderService,
+        true,
+        true,
+        None
+    );
+
+    #[cfg(feature = "profiling")]
+    merge_router!(
+        server_router::admin::SysProfilingRouter::init_profiling_router().await,
+        server_service::admin::SysProfilingService,
+        true,
+        true,
+        None
+    );
+
+    #[cfg(feature = "chaos")]
+    merge_router!(
+        server_router::admin::SysChaosRouter::init_chaos_router().await,
+        server_service::admin::SysChaosService,
+        true,
+        true,
+        None
+    );
+
     merge_router!(
         SysOrganizationRouter::init_organization_router().await,
         SysOrganizationService,
@@ -296,6 +751,13 @@ pub async fn initialize_admin_router() -> Router {
         false,
         None
     );
+    merge_router!(
+        SysOrganizationRouter::init_protected_organization_router().await,
+        SysOrganizationService,
+        true,
+        true,
+        None
+    );
 
     // sandbox
     merge_router!(
@@ -314,8 +776,103 @@ pub async fn initialize_admin_router() -> Router {
     );
 
     app = app.fallback(handler_404);
+    // 包裹全部路由的中间件，最内层在前
+    let mut global_layers = Vec::new();
+    // 故障注入包裹全部业务路由，模拟的延迟同样计入在途请求
+    #[cfg(feature = "chaos")]
+    {
+        app = app.layer(axum::middleware::from_fn(
+            server_core::web::chaos::chaos_middleware,
+        ));
+        global_layers.push("chaos");
+    }
+    // 机器人检测只作用于配置的公开路由组，在业务处理和鉴权之前拦截
+    let bot_detection = get_config::<SecurityConfig>()
+        .await
+        .map(|config| config.bot_detection.clone())
+        .unwrap_or_default();
+    if bot_detection.enabled {
+        project_info!(
+            "Bot detection enabled for {} route group(s)",
+            bot_detection.route_groups.len()
+        );
+        let bot_detection = Arc::new(bot_detection);
+        app = app.layer(axum::middleware::from_fn(move |req, next| {
+            bot_detection_middleware(bot_detection.clone(), req, next)
+        }));
+        global_layers.push("bot_detection");
+    }
+    // 遥测只统计进入业务处理的请求，被准入控制拒绝的请求不计入延迟
+    let telemetry_enabled = get_config::<TelemetryConfig>()
+        .await
+        .is_some_and(|config| config.enabled);
+    if telemetry_enabled {
+        app = app.layer(axum::middleware::from_fn(telemetry_middleware));
+        global_layers.push("telemetry");
+    }
+    // 停用模块的路由在鉴权之前返回 404，如同路由不存在
+    let module_checker: Arc<dyn ModuleStatusChecker> = Arc::new(SysModuleService);
+    app = app.layer(axum::middleware::from_fn(move |req, next| {
+        module_gate_middleware(module_checker.clone(), req, next)
+    }));
+    global_layers.push("module_gate");
+    // 位于准入控制内层，被拒绝的请求不计入排空等待
+    app = app.layer(axum::middleware::from_fn(in_flight_middleware));
+    global_layers.push("in_flight");
 
+    // 准入控制包裹全部路由，在鉴权之前拒绝超出上限的请求
+    let concurrency = get_config::<ConcurrencyConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    if let Some(controller) = init_admission_controller(concurrency.clone()) {
+        project_info!(
+            "Admission control enabled: mode {:?}, initial limit {}, queue depth {}",
+            concurrency.mode,
+            controller.limit(),
+            concurrency.queue_depth
+        );
+        app = app.layer(axum::middleware::from_fn(move |req, next| {
+            admission_middleware(controller.clone(), req, next)
+        }));
+        global_layers.push("admission");
+    }
+    // 请求头和 URI 限制位于最外层，超限的请求不进入准入排队
+    let server_config = Arc::new(
+        get_config::<ServerConfig>()
+            .await
+            .map(|config| (*config).clone())
+            .unwrap_or_default(),
+    );
+    app = app.layer(axum::middleware::from_fn(move |req, next| {
+        request_limits_middleware(server_config.clone(), req, next)
+    }));
+    global_layers.push("request_limits");
+    // 访问日志位于最外层，被限制或拒绝的请求同样记录
+    let access_log = get_config::<AccessLogConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    if access_log.enabled {
+        let output = access_log.output.clone();
+        match AccessLogger::start(access_log) {
+            Ok(logger) => {
+                project_info!("Access log enabled, writing to {}", output);
+                let logger = Arc::new(logger);
+                app = app.layer(axum::middleware::from_fn(move |req, next| {
+                    access_log_middleware(logger.clone(), req, next)
+                }));
+                global_layers.push("access_log");
+            },
+            Err(e) => project_error!("Failed to open access log {}: {}", output, e),
+        }
+    }
+    global_layers.reverse();
+
+    lint_route_permissions(&mounts).await;
+    record_route_stacks(&mounts, &global_layers).await;
     process_collected_routes().await;
+    process_collected_menus().await;
     project_info!("Admin router initialization completed");
 
     app
@@ -325,14 +882,115 @@ async fn handler_404() -> impl IntoResponse {
     (StatusCode::NOT_FOUND, "nothing to see here")
 }
 
+/// 检查写接口的权限声明与挂载时实际应用的防护是否一致
+///
+/// 声明需要接口权限却未经过 Casbin、声明需要登录却未经过鉴权的写接口视为未受保护；
+/// 只需登录或公开的写接口须用 `RouteInfo::authenticated`、`RouteInfo::public` 显式声明。
+/// 默认只记录警告，开启 `server.strict_route_permissions` 时拒绝启动
+async fn lint_route_permissions(mounts: &[MountedRoutes]) {
+    let mut problems = Vec::new();
+    for mount in mounts {
+        for route in mount.routes.iter().filter(|route| route.is_mutating()) {
+            let problem = match route.guard {
+                RouteGuard::Permission if !mount.need_casbin => {
+                    "has no permission guard, annotate it with .authenticated() or .public() if intended"
+                },
+                RouteGuard::Authenticated if !mount.need_auth => {
+                    "is declared as authenticated but mounted without authentication"
+                },
+                _ => continue,
+            };
+            problems.push(format!(
+                "{} {} ({}) {}",
+                route.method, route.path, route.service_name, problem
+            ));
+        }
+    }
+    if problems.is_empty() {
+        return;
+    }
+
+    tracing::info!(
+        target: "metrics",
+        event = "unprotected_routes",
+        count = problems.len(),
+    );
+    let strict = get_config::<ServerConfig>()
+        .await
+        .is_some_and(|config| config.strict_route_permissions);
+    for problem in &problems {
+        if strict {
+            project_error!("Unprotected route: {}", problem);
+        } else {
+            tracing::warn!("Unprotected route: {}", problem);
+        }
+    }
+    if strict {
+        project_error!(
+            "Found {} unprotected mutating route(s) with server.strict_route_permissions enabled",
+            problems.len()
+        );
+        std::process::exit(1);
+    }
+}
+
+/// 按挂载信息和配置生成每个路由实际经过的中间件
+///
+/// 二次认证、敏感操作防护、协议确认和机器人检测只作用于匹配规则的接口，按声明的路径逐一判断
+async fn record_route_stacks(mounts: &[MountedRoutes], global_layers: &[&'static str]) {
+    let security = get_config::<SecurityConfig>().await;
+    let policy_gate = get_config::<ComplianceConfig>()
+        .await
+        .map(|config| config.policy_gate.clone())
+        .unwrap_or_default();
+
+    let applies = |layer: &str, route: &RouteInfo| {
+        let method = route.method.as_str();
+        let path = route.path.as_str();
+        match layer {
+            "bot_detection" => security
+                .as_ref()
+                .is_some_and(|config| config.bot_detection.route_group(path).is_some()),
+            "step_up" => security
+                .as_ref()
+                .is_some_and(|config| config.step_up.rule(method, path).is_some()),
+            "environment_guard" => security
+                .as_ref()
+                .is_some_and(|config| config.sensitive_operation.rule(method, path).is_some()),
+            "policy_gate" => !policy_gate.is_exempt(path),
+            "module_gate" => module_for_path(path).is_some(),
+            _ => true,
+        }
+    };
+
+    let stacks = mounts
+        .iter()
+        .flat_map(|mount| {
+            mount.routes.iter().map(|route| RouteStack {
+                route: route.clone(),
+                casbin: mount.layers.contains(&"casbin"),
+                layers: global_layers
+                    .iter()
+                    .chain(mount.layers.iter())
+                    .filter(|layer| applies(layer, route))
+                    .map(|layer| layer.to_string())
+                    .collect(),
+            })
+        })
+        .collect();
+    set_route_stacks(stacks).await;
+}
+
 async fn process_collected_routes() {
     let routes = get_collected_routes().await;
+    // 只需登录或公开的接口不参与接口权限分配
     let endpoints: Vec<SysEndpoint> = routes
         .into_iter()
+        .filter(|route| route.guard == RouteGuard::Permission)
         .map(|route| {
             let resource = route.path.split('/').nth(1).unwrap_or("").to_string();
             SysEndpoint {
-                id: generate_id(&route.path, &route.method.to_string()),
+                id: generate_id(&route.path, route.method.as_ref()),
                 path: route.path.clone(),
                 method: route.method.to_string(),
                 action: "rw".to_string(),
@@ -356,6 +1014,18 @@ async fn process_collected_routes() {
     }
 }
 
+async fn process_collected_menus() {
+    let menus = get_collected_menus().await;
+    match SysMenuService.sync_menus(menus).await {
+        Ok(_) => {
+            project_info!("Module menus synced successfully")
+        },
+        Err(e) => {
+            project_error!("Failed to sync module menus: {:?}", e)
+        },
+    }
+}
+
 fn generate_id(path: &str, method: &str) -> String {
     use std::{
         collections::hash_map::DefaultHasher,
@@ -0,0 +1,66 @@
+use serde::Deserialize;
+use server_config::RuntimeConfig;
+use tokio::runtime::{Builder, Runtime};
+
+/// 仅读取配置中的 `runtime` 部分，其余配置项的错误不影响运行时构建
+#[derive(Deserialize)]
+struct RuntimeSection {
+    #[serde(default)]
+    runtime: Option<RuntimeConfig>,
+}
+
+/// 读取运行时配置
+///
+/// 运行时需先于日志和全局配置创建，因此单独加载一次配置文件和环境变量。
+/// 读取失败时使用默认值，错误会在随后的完整配置初始化中报告
+pub fn load_runtime_config(config_path: &str) -> RuntimeConfig {
+    server_config::load_config_with_env::<RuntimeSection>(config_path, None)
+        .ok()
+        .and_then(|section| section.runtime)
+        .unwrap_or_default()
+}
+
+/// 按 `runtime` 配置构建多线程 Tokio 运行时
+pub fn build_runtime(config: &RuntimeConfig) -> std::io::Result<Runtime> {
+    let mut builder = Builder::new_multi_thread();
+    builder.enable_all();
+
+    // tokio 对 0 值直接 panic，这里忽略并沿用默认值
+    if let Some(worker_threads) = config.worker_threads.filter(|n| *n > 0) {
+        builder.worker_threads(worker_threads);
+    }
+    if let Some(blocking_threads) = config.blocking_threads.filter(|n| *n > 0) {
+        builder.max_blocking_threads(blocking_threads);
+    }
+    if let Some(stack_size) = config.thread_stack_size {
+        builder.thread_stack_size(stack_size);
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_runtime_with_config() {
+        let config = RuntimeConfig {
+            worker_threads: Some(2),
+            blocking_threads: Some(4),
+            thread_stack_size: Some(4 * 1024 * 1024),
+        };
+        let runtime = build_runtime(&config).unwrap();
+        assert_eq!(runtime.metrics().num_workers(), 2);
+    }
+
+    #[test]
+    fn test_build_runtime_ignores_zero_threads() {
+        let config = RuntimeConfig {
+            worker_threads: Some(0),
+            blocking_threads: Some(0),
+            thread_stack_size: None,
+        };
+        assert!(build_runtime(&config).is_ok());
+    }
+}
@@ -1,9 +1,23 @@
-use std::error::Error;
+use std::{error::Error, net::SocketAddr, time::Duration};
 
-use server_config::ServerConfig;
+use axum::Router;
+use hyper_util::{
+    rt::{TokioExecutor, TokioIo, TokioTimer},
+    server::{conn::auto, graceful::GracefulShutdown},
+    service::TowerToHyperService,
+};
+use server_config::{ServerConfig, ServerRole};
 use server_global::global;
+use tokio::net::TcpListener;
+use tower::Service;
 
-use crate::project_info;
+use crate::{project_error, project_info, shutdown_signal};
+
+/// 请求行、请求头分隔符等不计入请求头大小限制的开销
+const HEAD_OVERHEAD: usize = 1024;
+
+/// HTTP 解析器读缓冲区的最小值
+const MIN_BUF_SIZE: usize = 8192;
 
 pub async fn get_server_address() -> Result<String, Box<dyn Error>> {
     let server_config = global::get_config::<ServerConfig>().await.unwrap();
@@ -11,3 +25,96 @@ pub async fn get_server_address() -> Result<String, Box<dyn Error>> {
     project_info!("Server address configured: {}", addr);
     Ok(addr)
 }
+
+/// 获取进程角色
+pub async fn get_server_role() -> ServerRole {
+    let role = global::get_config::<ServerConfig>()
+        .await
+        .map(|config| config.role)
+        .unwrap_or_default();
+    project_info!("Server role configured: {:?}", role);
+    role
+}
+
+/// 解析请求行和请求头时读缓冲区的上限
+///
+/// 略大于中间件检查的 URI 和请求头上限，超出不多的请求仍由中间件返回带说明的 414/431，
+/// 超大的请求头在读入缓冲区时就被解析器以 431 拒绝，不会先占满内存
+fn max_buf_size(config: &ServerConfig) -> usize {
+    // 每个请求头另有 `: ` 和换行共 4 字节
+    let head = config.max_uri_length + config.max_header_size + config.max_header_count * 4;
+    (head + HEAD_OVERHEAD).max(MIN_BUF_SIZE)
+}
+
+/// 在监听端口上提供 HTTP/1 服务，收到停机信号后停止接受连接，等待在途请求完成
+///
+/// 与 `axum::serve` 相同，只是在连接级别设置解析器的缓冲区和请求头数量上限
+pub async fn serve(listener: TcpListener, app: Router) {
+    let config = global::get_config::<ServerConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    let mut builder = auto::Builder::new(TokioExecutor::new()).http1_only();
+    builder
+        .http1()
+        .timer(TokioTimer::new())
+        .max_buf_size(max_buf_size(&config))
+        .max_headers(config.max_header_count);
+
+    let mut make_service = app.into_make_service_with_connect_info::<SocketAddr>();
+    let graceful = GracefulShutdown::new();
+    let shutdown = shutdown_signal();
+    tokio::pin!(shutdown);
+
+    loop {
+        let (stream, remote_addr) = tokio::select! {
+            accepted = listener.accept() => match accepted {
+                Ok(accepted) => accepted,
+                Err(e) => {
+                    // 文件描述符耗尽等错误，稍后重试
+                    project_error!("Failed to accept connection: {}", e);
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                    continue;
+                },
+            },
+            _ = &mut shutdown => break,
+        };
+
+        let Ok(service) = make_service.call(remote_addr).await;
+        let service = TowerToHyperService::new(service);
+        let connection = builder
+            .serve_connection_with_upgrades(TokioIo::new(stream), service)
+            .into_owned();
+        let connection = graceful.watch(connection);
+        tokio::spawn(async move {
+            if let Err(e) = connection.await {
+                tracing::debug!("Connection from {} closed with error: {}", remote_addr, e);
+            }
+        });
+    }
+
+    drop(listener);
+    graceful.shutdown().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_max_buf_size() {
+        let config = ServerConfig::default();
+        assert_eq!(
+            max_buf_size(&config),
+            8 * 1024 + 32 * 1024 + 100 * 4 + HEAD_OVERHEAD
+        );
+
+        let config = ServerConfig {
+            max_header_size: 1024,
+            max_uri_length: 1024,
+            max_header_count: 10,
+            ..Default::default()
+        };
+        assert_eq!(max_buf_size(&config), MIN_BUF_SIZE);
+    }
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+use server_config::SiemConfig;
+use server_global::global::get_config;
+use server_service::admin::{flush_siem_events, init_siem_exporter};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::project_info;
+
+/// 启动 SIEM 导出任务，未启用时不启动
+///
+/// 需在事件通道初始化之后调用，之前产生的事件不会导出
+pub async fn initialize_siem_exporter() {
+    let config = get_config::<SiemConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    if !init_siem_exporter(config.clone()) {
+        return;
+    }
+
+    let period = Duration::from_secs(config.flush_interval.max(1));
+    tokio::spawn(async move {
+        let mut ticker = interval(period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            flush_siem_events().await;
+        }
+    });
+
+    project_info!(
+        "SIEM exporter started: {:?} over {:?} to {}, flush interval: {}s",
+        config.format,
+        config.transport,
+        config.endpoint,
+        config.flush_interval
+    );
+}
@@ -0,0 +1,50 @@
+use std::time::Duration;
+
+use server_config::TelemetryConfig;
+use server_global::global::{get_config, is_draining, track_work, TraceContext};
+use server_service::admin::{
+    is_cluster_leader, jobs_paused, module_enabled, SysTelemetryService, TTelemetryService,
+};
+use tokio::time::{interval_at, Instant, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 启动匿名遥测上报任务
+///
+/// 未开启 `telemetry.enabled` 时不启动，集群部署时只有主节点上报；
+/// 第一次上报在启动一个周期后进行，以便积累延迟数据
+pub async fn initialize_telemetry_job() {
+    let Some(config) = get_config::<TelemetryConfig>()
+        .await
+        .filter(|config| config.enabled && config.endpoint.is_some())
+    else {
+        return;
+    };
+    let period = Duration::from_secs(config.interval);
+
+    tokio::spawn(async move {
+        let mut ticker = interval_at(Instant::now() + period, period);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader()
+                || is_draining()
+                || jobs_paused().await
+                || !module_enabled("telemetry").await
+            {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope("telemetry_job", SysTelemetryService.report())
+                .await;
+            // 遥测失败不影响业务，下个周期重试
+            if let Err(e) = result {
+                project_error!("Telemetry report failed: {:?}", e);
+            }
+        }
+    });
+
+    project_info!("Telemetry enabled, reporting every {}s", config.interval);
+}
@@ -0,0 +1,45 @@
+use std::time::Duration;
+
+use server_global::global::{is_draining, track_work, TraceContext};
+use server_service::admin::{is_cluster_leader, jobs_paused, SysTenantService, TTenantService};
+use tokio::time::{interval, MissedTickBehavior};
+
+use crate::{project_error, project_info};
+
+/// 检查间隔，删除时间以天为单位，按小时检查足够及时
+const TENANT_PURGE_INTERVAL: Duration = Duration::from_secs(3600);
+
+/// 为独立 schema 的租户注册连接池，须在主库连接初始化之后调用
+pub async fn initialize_tenant_pools() {
+    if let Err(e) = SysTenantService.restore_tenant_pools().await {
+        project_error!("Failed to restore tenant pools: {:?}", e);
+    }
+}
+
+/// 启动租户删除任务
+///
+/// 删除等待期已结束的租户，集群部署时只有主节点执行
+pub async fn initialize_tenant_purge_job() {
+    tokio::spawn(async move {
+        let mut ticker = interval(TENANT_PURGE_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            if !is_cluster_leader() || is_draining() || jobs_paused().await {
+                continue;
+            }
+            let _work = track_work();
+            let result = TraceContext::new_root()
+                .scope("tenant_purge_job", SysTenantService.run_due_purges())
+                .await;
+            match result {
+                Ok(0) => {},
+                Ok(purged) => project_info!("Purged {} tenants", purged),
+                Err(e) => project_error!("Tenant purge job failed: {:?}", e),
+            }
+        }
+    });
+
+    project_info!("Tenant purge job started");
+}
@@ -0,0 +1,127 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    };
+
+    use async_trait::async_trait;
+    use axum::{
+        body::Body,
+        http::{Request, StatusCode},
+        routing::get,
+        Router,
+    };
+    use axum_casbin::{
+        casbin::{DefaultModel, FileAdapter},
+        CasbinAxumLayer, CasbinVals,
+    };
+    use server_config::{SensitiveOperationConfig, SensitiveOperationRule};
+    use server_core::web::{
+        auth::{Claims, User},
+        environment_guard::{
+            environment_guard_middleware, SensitiveOperationApprover, BREAK_GLASS_APPROVAL_HEADER,
+            BREAK_GLASS_HEADER,
+        },
+        error::AppError,
+    };
+    use tower::ServiceExt;
+
+    /// 记录核销次数的审批
+    #[derive(Default)]
+    struct CountingApprover {
+        consumed: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl SensitiveOperationApprover for CountingApprover {
+        async fn consume_approval(
+            &self,
+            _user: &User,
+            _approval_id: &str,
+            _method: &str,
+            _path: &str,
+            _reason: &str,
+        ) -> Result<bool, AppError> {
+            self.consumed.fetch_add(1, Ordering::SeqCst);
+            Ok(true)
+        }
+    }
+
+    /// 按 apply_layers 的顺序挂载：敏感操作防护位于 Casbin 内层
+    async fn build_app(approver: Arc<CountingApprover>, role: &str) -> Router {
+        let m = DefaultModel::from_file("../../axum-casbin/examples/rbac_with_domains_model.conf")
+            .await
+            .unwrap();
+        let a = FileAdapter::new("../../axum-casbin/examples/rbac_with_domains_policy.csv");
+        let casbin_middleware = CasbinAxumLayer::new(m, a).await.unwrap();
+
+        let config = Arc::new(SensitiveOperationConfig {
+            approval_ttl: 600,
+            rules: vec![SensitiveOperationRule {
+                path: "/pen/1".to_string(),
+                method: "GET".to_string(),
+            }],
+        });
+        let approver: Arc<dyn SensitiveOperationApprover> = approver;
+        let user = User::from(Claims::new(
+            "admin".to_string(),
+            "management-platform".to_string(),
+            "alice".to_string(),
+            vec![role.to_string()],
+            "domain1".to_string(),
+            None,
+        ));
+
+        Router::new()
+            .route("/pen/1", get(|| async { "purged" }))
+            .layer(axum::middleware::from_fn(move |req, next| {
+                environment_guard_middleware(config.clone(), approver.clone(), req, next)
+            }))
+            .layer(casbin_middleware)
+            .layer(axum::middleware::from_fn(
+                move |mut req: Request<Body>, next: axum::middleware::Next| {
+                    let user = user.clone();
+                    async move {
+                        req.extensions_mut().insert(CasbinVals {
+                            subject: user.subject(),
+                            domain: Some(user.domain()),
+                        });
+                        req.extensions_mut().insert(user);
+                        next.run(req).await
+                    }
+                },
+            ))
+    }
+
+    fn break_glass_request() -> Request<Body> {
+        Request::builder()
+            .uri("/pen/1")
+            .header(BREAK_GLASS_HEADER, "incident-42")
+            .header(BREAK_GLASS_APPROVAL_HEADER, "approval-1")
+            .body(Body::empty())
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_casbin_denial_keeps_approval() {
+        let approver = Arc::new(CountingApprover::default());
+        let app = build_app(approver.clone(), "guest").await;
+
+        let response = app.oneshot(break_glass_request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert_eq!(approver.consumed.load(Ordering::SeqCst), 0);
+    }
+
+    #[tokio::test]
+    async fn test_authorized_request_consumes_approval() {
+        let approver = Arc::new(CountingApprover::default());
+        let app = build_app(approver.clone(), "admin").await;
+
+        let response = app.oneshot(break_glass_request()).await.unwrap();
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(approver.consumed.load(Ordering::SeqCst), 1);
+    }
+}
@@ -1,4 +1,7 @@
-use server_core::web::{auth::Claims, jwt::JwtUtils};
+use server_core::web::{
+    auth::Claims,
+    jwt::{JwtUtils, RefreshClaims, TokenType},
+};
 
 #[cfg(test)]
 mod tests {
@@ -22,6 +25,17 @@ mod tests {
         claims
     }
 
+    fn create_refresh_claims(audience: &str) -> RefreshClaims {
+        RefreshClaims::new(
+            "user123".to_string(),
+            audience.to_string(),
+            "example_domain".to_string(),
+            1_700_000_000,
+            vec!["pwd".to_string()],
+            2,
+        )
+    }
+
     static INITIALIZED: Mutex<Option<Arc<()>>> = Mutex::const_new(None);
 
     async fn init() {
@@ -101,4 +115,51 @@ mod tests {
         let result = JwtUtils::validate_token(&invalid_token, "audience").await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn test_validate_refresh_token_success() {
+        init().await;
+
+        let token = JwtUtils::generate_refresh_token(&create_refresh_claims("audience"))
+            .await
+            .unwrap();
+
+        let claims = JwtUtils::validate_refresh_token(&token, "audience")
+            .await
+            .unwrap();
+        assert_eq!(claims.sub, "user123");
+        assert_eq!(claims.auth_time, 1_700_000_000);
+        assert_eq!(claims.amr, vec!["pwd".to_string()]);
+        assert_eq!(claims.refresh_count, 2);
+        assert!(JwtUtils::validate_refresh_token(&token, "other_audience")
+            .await
+            .is_err());
+    }
+
+    #[tokio::test]
+    async fn test_token_types_are_not_interchangeable() {
+        init().await;
+
+        // 示例配置未设置 refresh_secret，两种令牌使用同一密钥签名
+        let access_token = JwtUtils::generate_token(&create_claims(
+            "https://github.com/ByteByteBrew/soybean-admin-rust",
+            "audience",
+        ))
+        .await
+        .unwrap();
+        let refresh_token = JwtUtils::generate_refresh_token(&create_refresh_claims("audience"))
+            .await
+            .unwrap();
+
+        let access = JwtUtils::validate_token(&access_token, "audience")
+            .await
+            .unwrap();
+        assert_eq!(access.claims.typ(), TokenType::Access);
+        assert!(JwtUtils::validate_refresh_token(&access_token, "audience")
+            .await
+            .is_err());
+        assert!(JwtUtils::validate_token(&refresh_token, "audience")
+            .await
+            .is_err());
+    }
 }
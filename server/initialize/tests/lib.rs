@@ -1,2 +1,3 @@
+mod environment_guard;
 mod jwt;
 mod jwt_auth_middleware;
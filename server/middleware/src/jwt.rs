@@ -1,9 +1,22 @@
+use std::sync::Arc;
+
 use axum::{
-    body::Body, extract::Request, http::StatusCode, middleware::Next, response::IntoResponse,
+    body::Body,
+    extract::Request,
+    http::StatusCode,
+    middleware::Next,
+    response::{IntoResponse, Response},
 };
 use axum_casbin::CasbinVals;
 use headers::{authorization::Bearer, Authorization, HeaderMapExt};
-use server_core::web::{auth::User, jwt::JwtUtils, res::Res};
+use server_core::web::{
+    auth::{scope_user, User},
+    jwt::JwtUtils,
+    personal_token::{
+        is_personal_token, scope_allows, PersonalTokenResolver, PERSONAL_TOKEN_SCOPE_DENIED_CODE,
+    },
+    res::Res,
+};
 
 pub async fn jwt_auth_middleware(
     mut req: Request<Body>,
@@ -29,9 +42,9 @@ pub async fn jwt_auth_middleware(
                 subject: user.subject(),
                 domain: Option::from(user.domain()),
             };
-            req.extensions_mut().insert(user);
+            req.extensions_mut().insert(user.clone());
             req.extensions_mut().insert(vals);
-            next.run(req).await.into_response()
+            scope_user(user, next.run(req)).await.into_response()
         },
         Err(err) => {
             Res::<String>::new_error(StatusCode::UNAUTHORIZED.as_u16(), err.to_string().as_str())
@@ -39,3 +52,57 @@ pub async fn jwt_auth_middleware(
         },
     }
 }
+
+/// 同时接受 JWT 和个人访问令牌的鉴权中间件
+///
+/// 以 `sbp_` 开头的令牌按个人访问令牌解析，用户的角色取自数据库中的当前角色，
+/// 请求还需在令牌的授权范围内；其他令牌交给 [`jwt_auth_middleware`]
+pub async fn bearer_auth_middleware(
+    resolver: Arc<dyn PersonalTokenResolver>,
+    mut req: Request<Body>,
+    next: Next,
+    audience: &str,
+) -> Response {
+    let token = match req.headers().typed_get::<Authorization<Bearer>>() {
+        Some(auth) if is_personal_token(auth.token()) => auth.token().to_string(),
+        _ => {
+            return jwt_auth_middleware(req, next, audience)
+                .await
+                .into_response()
+        },
+    };
+
+    let grant = match resolver.resolve(&token, audience).await {
+        Ok(Some(grant)) => grant,
+        Ok(None) => {
+            return Res::<String>::new_error(
+                StatusCode::UNAUTHORIZED.as_u16(),
+                "Invalid or expired personal access token",
+            )
+            .into_response();
+        },
+        Err(e) => {
+            return Res::<String>::new_error(StatusCode::UNAUTHORIZED.as_u16(), e.message())
+                .into_response();
+        },
+    };
+    if !scope_allows(&grant.scopes, req.method(), req.uri().path()) {
+        return (
+            StatusCode::FORBIDDEN,
+            Res::<String>::new_error(
+                PERSONAL_TOKEN_SCOPE_DENIED_CODE,
+                "Personal access token scope does not allow this request",
+            ),
+        )
+            .into_response();
+    }
+
+    let user = grant.user;
+    let vals = CasbinVals {
+        subject: user.subject(),
+        domain: Option::from(user.domain()),
+    };
+    req.extensions_mut().insert(user.clone());
+    req.extensions_mut().insert(vals);
+    scope_user(user, next.run(req)).await.into_response()
+}
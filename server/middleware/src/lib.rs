@@ -1,3 +1,3 @@
 mod jwt;
 
-pub use jwt::jwt_auth_middleware;
+pub use jwt::{bearer_auth_middleware, jwt_auth_middleware};
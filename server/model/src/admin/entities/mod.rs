@@ -5,14 +5,34 @@ pub mod prelude;
 pub mod casbin_rule;
 pub mod sea_orm_active_enums;
 pub mod sys_access_key;
+pub mod sys_access_review;
+pub mod sys_access_review_item;
+pub mod sys_alert_rule;
+pub mod sys_api_usage;
 pub mod sys_domain;
 pub mod sys_endpoint;
+pub mod sys_file;
+pub mod sys_file_blob;
+pub mod sys_file_share;
 pub mod sys_login_log;
+pub mod sys_maintenance_window;
 pub mod sys_menu;
+pub mod sys_metering;
+pub mod sys_notification;
+pub mod sys_notification_preference;
 pub mod sys_operation_log;
+pub mod sys_operation_log_archive;
 pub mod sys_organization;
+pub mod sys_param;
+pub mod sys_personal_token;
+pub mod sys_policy_acceptance;
+pub mod sys_policy_document;
+pub mod sys_region;
+pub mod sys_region_version;
 pub mod sys_role;
 pub mod sys_role_menu;
+pub mod sys_tenant;
 pub mod sys_tokens;
 pub mod sys_user;
+pub mod sys_user_passkey;
 pub mod sys_user_role;
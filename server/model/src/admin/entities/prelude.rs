@@ -2,10 +2,23 @@
 
 pub use super::{
     casbin_rule::Entity as CasbinRule, sys_access_key::Entity as SysAccessKey,
-    sys_domain::Entity as SysDomain, sys_endpoint::Entity as SysEndpoint,
-    sys_login_log::Entity as SysLoginLog, sys_menu::Entity as SysMenu,
-    sys_operation_log::Entity as SysOperationLog, sys_organization::Entity as SysOrganization,
-    sys_role::Entity as SysRole, sys_role_menu::Entity as SysRoleMenu,
+    sys_access_review::Entity as SysAccessReview,
+    sys_access_review_item::Entity as SysAccessReviewItem, sys_alert_rule::Entity as SysAlertRule,
+    sys_api_usage::Entity as SysApiUsage, sys_domain::Entity as SysDomain,
+    sys_endpoint::Entity as SysEndpoint, sys_file::Entity as SysFile,
+    sys_file_blob::Entity as SysFileBlob, sys_file_share::Entity as SysFileShare,
+    sys_login_log::Entity as SysLoginLog, sys_maintenance_window::Entity as SysMaintenanceWindow,
+    sys_menu::Entity as SysMenu, sys_metering::Entity as SysMetering,
+    sys_notification::Entity as SysNotification,
+    sys_notification_preference::Entity as SysNotificationPreference,
+    sys_operation_log::Entity as SysOperationLog,
+    sys_operation_log_archive::Entity as SysOperationLogArchive,
+    sys_organization::Entity as SysOrganization, sys_param::Entity as SysParam,
+    sys_personal_token::Entity as SysPersonalToken,
+    sys_policy_acceptance::Entity as SysPolicyAcceptance,
+    sys_policy_document::Entity as SysPolicyDocument, sys_region::Entity as SysRegion,
+    sys_region_version::Entity as SysRegionVersion, sys_role::Entity as SysRole,
+    sys_role_menu::Entity as SysRoleMenu, sys_tenant::Entity as SysTenant,
     sys_tokens::Entity as SysTokens, sys_user::Entity as SysUser,
-    sys_user_role::Entity as SysUserRole,
+    sys_user_passkey::Entity as SysUserPasskey, sys_user_role::Entity as SysUserRole,
 };
@@ -28,3 +28,225 @@ pub enum Status {
     #[serde(rename = "enabled")]
     Enabled,
 }
+
+/// 文件病毒扫描状态
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum FileScanStatus {
+    /// 等待扫描或扫描失败，需人工复核
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "clean")]
+    #[serde(rename = "clean")]
+    Clean,
+    /// 扫描命中，已隔离，禁止普通下载
+    #[sea_orm(string_value = "quarantined")]
+    #[serde(rename = "quarantined")]
+    Quarantined,
+    /// 管理员复核后放行
+    #[sea_orm(string_value = "released")]
+    #[serde(rename = "released")]
+    Released,
+}
+
+impl FileScanStatus {
+    /// 是否允许普通下载
+    pub fn is_downloadable(&self) -> bool {
+        matches!(self, FileScanStatus::Clean | FileScanStatus::Released)
+    }
+}
+
+/// 告警规则监听的安全信号
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AlertSignalKind {
+    /// 账号登录失败
+    #[sea_orm(string_value = "login_failed")]
+    #[serde(rename = "login_failed")]
+    LoginFailed,
+    /// 角色被授予接口权限或成员
+    #[sea_orm(string_value = "permission_escalation")]
+    #[serde(rename = "permission_escalation")]
+    PermissionEscalation,
+    /// 文件下载等数据导出
+    #[sea_orm(string_value = "mass_export")]
+    #[serde(rename = "mass_export")]
+    MassExport,
+    /// 使用紧急凭证
+    #[sea_orm(string_value = "break_glass_used")]
+    #[serde(rename = "break_glass_used")]
+    BreakGlassUsed,
+}
+
+/// 告警通知渠道
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AlertChannel {
+    /// POST JSON 到目标地址
+    #[sea_orm(string_value = "webhook")]
+    #[serde(rename = "webhook")]
+    Webhook,
+    /// 通过邮件网关发送到目标邮箱
+    #[sea_orm(string_value = "email")]
+    #[serde(rename = "email")]
+    Email,
+    /// 仅写入日志
+    #[sea_orm(string_value = "log")]
+    #[serde(rename = "log")]
+    Log,
+}
+
+/// 角色的数据范围，拥有多个角色时取最宽的范围
+#[derive(
+    Debug, Clone, Default, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum DataScope {
+    /// 不限制
+    #[default]
+    #[sea_orm(string_value = "all")]
+    #[serde(rename = "all")]
+    All,
+    /// 所属组织及其全部下级组织
+    #[sea_orm(string_value = "org_subtree")]
+    #[serde(rename = "org_subtree")]
+    OrgSubtree,
+    /// 仅所属组织
+    #[sea_orm(string_value = "org")]
+    #[serde(rename = "org")]
+    Org,
+    /// 仅本人，存储值不能用 `self`：DeriveActiveEnum 会把它生成为关键字 `Self`
+    #[sea_orm(string_value = "self_only")]
+    #[serde(rename = "self_only")]
+    SelfOnly,
+}
+
+/// 权限复核活动状态
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AccessReviewStatus {
+    #[sea_orm(string_value = "open")]
+    #[serde(rename = "open")]
+    Open,
+    /// 已关闭，不再接受复核决定
+    #[sea_orm(string_value = "closed")]
+    #[serde(rename = "closed")]
+    Closed,
+}
+
+/// 权限复核决定
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum AccessReviewDecision {
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    /// 确认保留现有角色
+    #[sea_orm(string_value = "confirmed")]
+    #[serde(rename = "confirmed")]
+    Confirmed,
+    /// 撤销用户的全部角色
+    #[sea_orm(string_value = "revoked")]
+    #[serde(rename = "revoked")]
+    Revoked,
+}
+
+/// 通知优先级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum NotificationPriority {
+    /// 开启摘要的用户汇总到摘要邮件
+    #[sea_orm(string_value = "low")]
+    #[serde(rename = "low")]
+    Low,
+    #[sea_orm(string_value = "normal")]
+    #[serde(rename = "normal")]
+    Normal,
+    /// 忽略免打扰时段和静音的分类，立即发送
+    #[sea_orm(string_value = "high")]
+    #[serde(rename = "high")]
+    High,
+}
+
+/// 通知的邮件投递状态
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum NotificationEmailStatus {
+    /// 用户关闭了邮件或没有邮箱
+    #[sea_orm(string_value = "skipped")]
+    #[serde(rename = "skipped")]
+    Skipped,
+    /// 等待汇总到摘要邮件
+    #[sea_orm(string_value = "pending")]
+    #[serde(rename = "pending")]
+    Pending,
+    #[sea_orm(string_value = "sent")]
+    #[serde(rename = "sent")]
+    Sent,
+    #[sea_orm(string_value = "failed")]
+    #[serde(rename = "failed")]
+    Failed,
+}
+
+/// 维护窗口的时间设定方式
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum MaintenanceScheduleKind {
+    /// 按 cron 表达式周期性开始，每次持续 `duration_minutes` 分钟
+    #[sea_orm(string_value = "cron")]
+    #[serde(rename = "cron")]
+    Cron,
+    /// 从 `start_at` 到 `end_at` 的单次窗口
+    #[sea_orm(string_value = "range")]
+    #[serde(rename = "range")]
+    Range,
+}
+
+/// API 调用方类型
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Hash, EnumIter, DeriveActiveEnum, Deserialize, Serialize,
+)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum ApiUsageSubjectKind {
+    #[sea_orm(string_value = "user")]
+    #[serde(rename = "user")]
+    User,
+    #[sea_orm(string_value = "api_key")]
+    #[serde(rename = "api_key")]
+    ApiKey,
+}
+
+/// 租户数据隔离方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum TenantIsolation {
+    /// 与其他租户共用主库，按域区分数据
+    #[sea_orm(string_value = "shared")]
+    #[serde(rename = "shared")]
+    Shared,
+    /// 在主库中创建独立 schema
+    #[sea_orm(string_value = "schema")]
+    #[serde(rename = "schema")]
+    Schema,
+    /// 使用 `database_instances` 中配置的独立数据库
+    #[sea_orm(string_value = "database")]
+    #[serde(rename = "database")]
+    Database,
+}
+
+/// 租户状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumIter, DeriveActiveEnum, Deserialize, Serialize)]
+#[sea_orm(rs_type = "String", db_type = "String(StringLen::None)")]
+pub enum TenantStatus {
+    #[sea_orm(string_value = "active")]
+    #[serde(rename = "active")]
+    Active,
+    #[sea_orm(string_value = "suspended")]
+    #[serde(rename = "suspended")]
+    Suspended,
+    /// 已确认删除，等待期结束后删除
+    #[sea_orm(string_value = "purge_scheduled")]
+    #[serde(rename = "purge_scheduled")]
+    PurgeScheduled,
+}
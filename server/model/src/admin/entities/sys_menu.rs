@@ -50,4 +50,4 @@ impl Related<super::sys_role_menu::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+crate::audit_stamped!(created, updated);
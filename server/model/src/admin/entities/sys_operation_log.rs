@@ -1,10 +1,10 @@
 //! `SeaORM` Entity, @generated by sea-orm-codegen 1.0.0
 
 use sea_orm::entity::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::Value as JsonValue;
 
-#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
+#[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize, Deserialize)]
 #[sea_orm(table_name = "sys_operation_log")]
 #[serde(rename_all = "camelCase")]
 pub struct Model {
@@ -40,6 +40,11 @@ pub struct Model {
     pub end_time: DateTime,
     pub duration: i32,
     pub created_at: DateTime,
+    pub chain_seq: Option<i64>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub prev_hash: Option<String>,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub hash: Option<String>,
 }
 
 #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
@@ -3,7 +3,7 @@
 use sea_orm::entity::prelude::*;
 use serde::Serialize;
 
-use super::sea_orm_active_enums::Status;
+use super::sea_orm_active_enums::{DataScope, Status};
 
 #[derive(Clone, Debug, PartialEq, DeriveEntityModel, Eq, Serialize)]
 #[sea_orm(table_name = "sys_role")]
@@ -19,6 +19,10 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub pid: String,
     pub status: Status,
+    pub data_scope: DataScope,
+    /// 数据范围的根组织，未设置时以用户所属组织为根
+    #[sea_orm(column_type = "Text", nullable)]
+    pub scope_organization_id: Option<String>,
     pub created_at: DateTime,
     #[sea_orm(column_type = "Text")]
     pub created_by: String,
@@ -47,4 +51,4 @@ impl Related<super::sys_user_role::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+crate::audit_stamped!(created, updated);
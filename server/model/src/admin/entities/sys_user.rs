@@ -26,6 +26,8 @@ pub struct Model {
     #[sea_orm(column_type = "Text")]
     pub nick_name: String,
     pub status: Status,
+    #[sea_orm(column_type = "Text", nullable)]
+    pub organization_id: Option<String>,
     pub created_at: DateTime,
     #[sea_orm(column_type = "Text")]
     pub created_by: String,
@@ -58,4 +60,4 @@ impl Related<super::sys_user_role::Entity> for Entity {
     }
 }
 
-impl ActiveModelBehavior for ActiveModel {}
+crate::audit_stamped!(created, updated);
@@ -1,23 +1,82 @@
 pub use sys_access_key::{AccessKeyPageRequest, CreateAccessKeyInput};
-pub use sys_authentication::LoginInput;
+pub use sys_access_review::{
+    AccessReviewItemPageRequest, AccessReviewPageRequest, DecideAccessReviewInput,
+    StartAccessReviewInput,
+};
+pub use sys_alert_rule::{AlertRulePageRequest, CreateAlertRuleInput, UpdateAlertRuleInput};
+pub use sys_api_usage::{ApiUsagePageRequest, ApiUsageTrendRequest};
+pub use sys_authentication::{LoginInput, ReauthInput, RefreshTokenInput};
 pub use sys_authorization::{AssignPermissionDto, AssignRouteDto, AssignUserDto};
+pub use sys_break_glass::BreakGlassInput;
+pub use sys_config::{CanaryConfigInput, StageConfigInput};
+pub use sys_consistency::ConsistencyCheckInput;
+pub use sys_db_pool::ResizeDbPoolInput;
+pub use sys_deletion::{DeleteWithCascadeInput, DeletionTarget};
 pub use sys_domain::{CreateDomainInput, DomainPageRequest, UpdateDomainInput};
 pub use sys_endpoint::EndpointPageRequest;
+pub use sys_file::{
+    CreateFileShareInput, DownloadSharedFileInput, FilePageRequest, ReviewFileInput,
+    UploadFileInput,
+};
 pub use sys_login_log::LoginLogPageRequest;
-pub use sys_menu::{CreateMenuInput, UpdateMenuInput};
-pub use sys_operation_log::OperationLogPageRequest;
+pub use sys_maintenance_window::{
+    CreateMaintenanceWindowInput, MaintenanceWindowPageRequest, UpdateMaintenanceWindowInput,
+};
+pub use sys_menu::{CreateMenuInput, MenuPageRequest, UpdateMenuInput};
+pub use sys_metering::{MeteringExportRequest, MeteringPageRequest};
+pub use sys_module::UpdateModuleInput;
+pub use sys_notification::{NotificationPageRequest, UpdateNotificationPreferenceInput};
+pub use sys_operation_log::{
+    AuditChainVerifyRequest, OperationLogHistoryRequest, OperationLogPageRequest,
+};
 pub use sys_organization::OrganizationPageRequest;
+pub use sys_passkey::{FinishPasskeyInput, StartPasskeyLoginInput, StartPasskeyRegistrationInput};
+pub use sys_personal_token::CreatePersonalTokenInput;
+pub use sys_policy::{
+    AcceptPolicyInput, PolicyAcceptancePageRequest, PolicyDocumentPageRequest, PublishPolicyInput,
+};
+pub use sys_profiling::{CpuProfileInput, ProfileFormat};
+pub use sys_recorder::{ReplayRecordingInput, StartRecordingInput};
+pub use sys_region::{ImportRegionInput, RegionFormat};
 pub use sys_role::{CreateRoleInput, RolePageRequest, UpdateRoleInput};
+pub use sys_sensitive_operation::RequestSensitiveOperationInput;
+pub use sys_tenant::{
+    ConfirmTenantPurgeInput, ImportTenantInput, ProvisionTenantInput, SuspendTenantInput,
+    TenantPageRequest,
+};
+pub use sys_tree::{MoveTreeNodeInput, SubtreeRequest};
 pub use sys_user::{CreateUserInput, UpdateUserInput, UserPageRequest};
 
 mod sys_access_key;
+mod sys_access_review;
+mod sys_alert_rule;
+mod sys_api_usage;
 mod sys_authentication;
 mod sys_authorization;
+mod sys_break_glass;
+mod sys_config;
+mod sys_consistency;
+mod sys_db_pool;
+mod sys_deletion;
 mod sys_domain;
 mod sys_endpoint;
+mod sys_file;
 mod sys_login_log;
+mod sys_maintenance_window;
 mod sys_menu;
+mod sys_metering;
+mod sys_module;
+mod sys_notification;
 mod sys_operation_log;
 mod sys_organization;
+mod sys_passkey;
+mod sys_personal_token;
+mod sys_policy;
+mod sys_profiling;
+mod sys_recorder;
+mod sys_region;
 mod sys_role;
+mod sys_sensitive_operation;
+mod sys_tenant;
+mod sys_tree;
 mod sys_user;
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::AccessReviewDecision;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccessReviewPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+/// 手动发起权限复核，未指定期限时使用配置的 `due_days`
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StartAccessReviewInput {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    #[validate(range(min = 1, max = 365, message = "Due days must be between 1 and 365"))]
+    pub due_days: Option<u32>,
+}
+
+/// 复核条目查询，`mine` 为真时只返回分派给当前用户的条目
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewItemPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub decision: Option<AccessReviewDecision>,
+    #[serde(default)]
+    pub mine: bool,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DecideAccessReviewInput {
+    pub decision: AccessReviewDecision,
+    #[validate(length(max = 500, message = "Comment must not exceed 500 characters"))]
+    pub comment: Option<String>,
+}
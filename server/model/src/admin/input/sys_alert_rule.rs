@@ -0,0 +1,64 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::{AlertChannel, AlertSignalKind};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AlertRulePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+/// 告警规则：同一对象在 `window_seconds` 内出现 `threshold` 次信号即触发，
+/// 触发后 `cooldown_seconds` 内不再重复通知
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AlertRuleInput {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    pub signal_kind: AlertSignalKind,
+    #[validate(range(
+        min = 1,
+        max = 10000,
+        message = "Threshold must be between 1 and 10000"
+    ))]
+    pub threshold: i32,
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Window must be between 1 and 86400 seconds"
+    ))]
+    pub window_seconds: i32,
+    #[validate(range(
+        min = 0,
+        max = 86400,
+        message = "Cooldown must be between 0 and 86400 seconds"
+    ))]
+    pub cooldown_seconds: i32,
+    pub channel: AlertChannel,
+    /// webhook 渠道为回调地址，email 渠道为收件人，多个收件人以逗号分隔
+    #[validate(length(max = 500, message = "Target must not exceed 500 characters"))]
+    pub target: Option<String>,
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+pub type CreateAlertRuleInput = AlertRuleInput;
+
+#[derive(Deserialize, Validate)]
+pub struct UpdateAlertRuleInput {
+    pub id: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub rule: AlertRuleInput,
+}
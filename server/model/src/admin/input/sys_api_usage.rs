@@ -0,0 +1,28 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+
+use crate::admin::entities::sea_orm_active_enums::ApiUsageSubjectKind;
+
+/// 按调用方汇总的调用统计查询，时间范围默认为最近 7 天
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsagePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub subject_kind: Option<ApiUsageSubjectKind>,
+    /// 按调用方 ID 或名称模糊匹配
+    pub keywords: Option<String>,
+    pub start: Option<NaiveDateTime>,
+    pub end: Option<NaiveDateTime>,
+}
+
+/// 单个调用方的小时趋势查询，时间范围默认为最近 7 天
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageTrendRequest {
+    pub subject_kind: ApiUsageSubjectKind,
+    pub subject: String,
+    pub start: Option<NaiveDateTime>,
+    pub end: Option<NaiveDateTime>,
+}
@@ -8,3 +8,18 @@ pub struct LoginInput {
     #[validate(length(min = 6, message = "Password cannot be empty"))]
     pub password: String,
 }
+
+/// 敏感操作前重新验证当前用户的密码
+#[derive(Deserialize, Validate)]
+pub struct ReauthInput {
+    #[validate(length(min = 6, message = "Password cannot be empty"))]
+    pub password: String,
+}
+
+/// 用刷新令牌换发新的令牌对
+#[derive(Deserialize, Validate)]
+pub struct RefreshTokenInput {
+    #[serde(rename = "refreshToken")]
+    #[validate(length(min = 1, message = "Refresh token cannot be empty"))]
+    pub refresh_token: String,
+}
@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 使用紧急凭证，`operator` 为实际操作人，凭证本身不对应任何用户
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakGlassInput {
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Credential must be between 1 and 200 characters"
+    ))]
+    pub credential: String,
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Operator must be between 1 and 64 characters"
+    ))]
+    pub operator: String,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+}
@@ -0,0 +1,24 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 上传候选配置
+#[derive(Debug, Deserialize, Validate)]
+pub struct StageConfigInput {
+    /// 配置格式：yaml、toml 或 json
+    #[validate(length(min = 1, max = 10, message = "Format must be yaml, toml or json"))]
+    pub format: String,
+    #[validate(length(min = 1, message = "Config content must not be empty"))]
+    pub content: String,
+}
+
+/// 灰度应用候选配置
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CanaryConfigInput {
+    /// 灰度生效的子系统，如 server、jwt、database、redis、mongo、s3、storage
+    #[validate(length(min = 1, message = "At least one subsystem is required"))]
+    pub subsystems: Vec<String>,
+    /// 仅预演，不实际生效
+    #[serde(default)]
+    pub dry_run: bool,
+}
@@ -0,0 +1,9 @@
+use serde::Deserialize;
+
+/// 手动执行一致性检查的参数
+#[derive(Debug, Deserialize)]
+pub struct ConsistencyCheckInput {
+    /// 是否同时修复可以安全修复的类别，默认只报告
+    #[serde(default)]
+    pub repair: bool,
+}
@@ -0,0 +1,16 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 调整数据库连接池容量
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ResizeDbPoolInput {
+    #[validate(range(
+        min = 1,
+        max = 1000,
+        message = "Max connections must be between 1 and 1000"
+    ))]
+    pub max_connections: u32,
+    #[validate(range(max = 1000, message = "Min connections must not exceed 1000"))]
+    pub min_connections: u32,
+}
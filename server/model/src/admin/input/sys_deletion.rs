@@ -0,0 +1,17 @@
+use serde::{Deserialize, Serialize};
+
+/// 支持级联规则的删除对象
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DeletionTarget {
+    Organization,
+    Role,
+}
+
+/// 删除参数，预览和执行删除共用
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeleteWithCascadeInput {
+    /// 删除组织时，组织下用户改挂到的组织；未指定时组织下有用户则拒绝删除
+    pub reassign_to: Option<String>,
+}
@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FilePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+/// 上传文件参数，由 multipart 请求解析而来
+#[derive(Debug)]
+pub struct UploadFileInput {
+    pub domain: String,
+    pub file_name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+    pub created_by: String,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct ReviewFileInput {
+    #[validate(length(max = 500, message = "Reason must not exceed 500 characters"))]
+    pub reason: Option<String>,
+}
+
+/// 创建分享链接参数，`expires_in` 未指定时使用配置的默认有效期
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateFileShareInput {
+    /// 有效期（秒），超过配置的最大有效期时按最大有效期处理
+    #[validate(range(min = 60, message = "Expiry must be at least 60 seconds"))]
+    pub expires_in: Option<u64>,
+    /// 访问密码，为空时不需要密码
+    #[validate(length(
+        min = 4,
+        max = 64,
+        message = "Password must be between 4 and 64 characters"
+    ))]
+    pub password: Option<String>,
+    /// 最大下载次数，为空时不限制
+    #[validate(range(min = 1, message = "Max downloads must be at least 1"))]
+    pub max_downloads: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct DownloadSharedFileInput {
+    #[validate(length(max = 64, message = "Password must not exceed 64 characters"))]
+    pub password: Option<String>,
+}
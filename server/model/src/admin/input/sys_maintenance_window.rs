@@ -0,0 +1,66 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::entities::sea_orm_active_enums::MaintenanceScheduleKind;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct MaintenanceWindowPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+/// 维护窗口：`cron` 方式按表达式（分 时 日 月 周，服务器本地时间）周期性开始，
+/// 每次持续 `duration_minutes` 分钟，`start_at`、`end_at` 可选，用于限定生效区间；
+/// `range` 方式为 `start_at` 到 `end_at` 的单次窗口
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceWindowInput {
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    /// 横幅展示的提示文字
+    #[validate(length(max = 500, message = "Message must not exceed 500 characters"))]
+    pub message: Option<String>,
+    pub schedule_kind: MaintenanceScheduleKind,
+    #[validate(length(max = 100, message = "Cron must not exceed 100 characters"))]
+    pub cron: Option<String>,
+    #[validate(range(
+        min = 1,
+        max = 10080,
+        message = "Duration must be between 1 and 10080 minutes"
+    ))]
+    pub duration_minutes: Option<i32>,
+    pub start_at: Option<NaiveDateTime>,
+    pub end_at: Option<NaiveDateTime>,
+    /// 窗口内不发送安全告警
+    #[serde(default = "default_true")]
+    pub suppress_alerts: bool,
+    /// 窗口内暂停非关键的后台任务
+    #[serde(default = "default_true")]
+    pub pause_jobs: bool,
+    /// 窗口开始前和进行中向前端展示横幅
+    #[serde(default = "default_true")]
+    pub show_banner: bool,
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+pub type CreateMaintenanceWindowInput = MaintenanceWindowInput;
+
+#[derive(Deserialize, Validate)]
+pub struct UpdateMaintenanceWindowInput {
+    pub id: String,
+    #[serde(flatten)]
+    #[validate(nested)]
+    pub window: MaintenanceWindowInput,
+}
@@ -0,0 +1,23 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+
+/// 计量记录查询，日期范围为闭区间，不指定时不限
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeteringPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub domain: Option<String>,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+}
+
+/// 计量记录导出，条件与分页查询相同
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MeteringExportRequest {
+    pub domain: Option<String>,
+    pub start: Option<NaiveDate>,
+    pub end: Option<NaiveDate>,
+}
@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 启用或停用功能模块
+#[derive(Debug, Deserialize, Validate)]
+pub struct UpdateModuleInput {
+    pub enabled: bool,
+}
@@ -0,0 +1,30 @@
+use chrono::NaiveTime;
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+/// 站内信查询，`unread` 为真时只返回未读通知
+#[derive(Debug, Serialize, Deserialize)]
+pub struct NotificationPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    #[serde(default)]
+    pub unread: bool,
+}
+
+/// 修改当前用户的通知偏好
+///
+/// 免打扰时段的开始和结束须同时设置，开始晚于结束时表示跨越零点
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateNotificationPreferenceInput {
+    pub email_enabled: bool,
+    pub in_app_enabled: bool,
+    #[validate(length(max = 50, message = "At most 50 categories can be muted"))]
+    #[serde(default)]
+    pub muted_categories: Vec<String>,
+    pub quiet_start: Option<NaiveTime>,
+    pub quiet_end: Option<NaiveTime>,
+    #[serde(default)]
+    pub digest_enabled: bool,
+}
@@ -1,3 +1,4 @@
+use chrono::NaiveDateTime;
 use serde::{Deserialize, Serialize};
 use server_core::web::page::PageRequest;
 
@@ -7,3 +8,23 @@ pub struct OperationLogPageRequest {
     pub page_details: PageRequest,
     pub keywords: Option<String>,
 }
+
+/// 审计日志哈希链校验条件
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AuditChainVerifyRequest {
+    /// 只校验指定域的分区，未指定时校验全部分区
+    pub domain: Option<String>,
+}
+
+/// 按时间范围查询操作日志，范围内已归档的分区从归档对象读取
+#[derive(Debug, Serialize, Deserialize)]
+pub struct OperationLogHistoryRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    /// 起始时间（含）
+    pub start: NaiveDateTime,
+    /// 结束时间（不含）
+    pub end: NaiveDateTime,
+    pub domain: Option<String>,
+    pub keywords: Option<String>,
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+use serde_json::Value;
+use validator::Validate;
+
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPasskeyRegistrationInput {
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Device name must be between 1 and 64 characters"
+    ))]
+    pub device_name: String,
+}
+
+/// 完成注册或登录时提交的浏览器凭证，`credential` 为
+/// `navigator.credentials.create/get` 返回值的 JSON 形式
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct FinishPasskeyInput {
+    #[validate(length(min = 1, message = "Challenge id cannot be empty"))]
+    pub challenge_id: String,
+    pub credential: Value,
+}
+
+/// 开始通行密钥登录：二次验证时提交密码登录返回的 `ticket`，
+/// 免密登录时提交用户名
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StartPasskeyLoginInput {
+    pub ticket: Option<String>,
+    pub identifier: Option<String>,
+}
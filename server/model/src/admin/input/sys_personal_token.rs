@@ -0,0 +1,22 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 创建个人访问令牌
+///
+/// `scopes` 形如 `<路由分组>:<read|write>`，路由分组为路径的第一段，`*` 表示全部分组，
+/// 如 `*:read`、`user:write`；`write` 包含 `read`
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatePersonalTokenInput {
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Token name must be between 1 and 64 characters"
+    ))]
+    pub name: String,
+    #[validate(length(min = 1, max = 20, message = "Select between 1 and 20 scopes"))]
+    pub scopes: Vec<String>,
+    /// 有效天数
+    #[validate(range(min = 1, max = 365, message = "Expiry must be between 1 and 365 days"))]
+    pub expire_days: u32,
+}
@@ -0,0 +1,44 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PolicyDocumentPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub code: Option<String>,
+}
+
+/// 发布政策文档的新版本，版本号在同一编码下自动递增
+#[derive(Debug, Deserialize, Validate)]
+pub struct PublishPolicyInput {
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Code must be between 1 and 50 characters"
+    ))]
+    pub code: String,
+    #[validate(length(
+        min = 1,
+        max = 200,
+        message = "Title must be between 1 and 200 characters"
+    ))]
+    pub title: String,
+    #[validate(length(min = 1, message = "Content must not be empty"))]
+    pub content: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct PolicyAcceptancePageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+}
+
+/// 确认政策文档，只能确认各编码的最新版本
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct AcceptPolicyInput {
+    #[validate(length(min = 1, message = "At least one document is required"))]
+    pub document_ids: Vec<String>,
+}
@@ -0,0 +1,33 @@
+use serde::Deserialize;
+
+/// CPU 剖析结果格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProfileFormat {
+    /// 火焰图 SVG
+    #[default]
+    Flamegraph,
+    /// pprof protobuf，可用 `go tool pprof` 分析
+    Protobuf,
+}
+
+/// CPU 剖析参数
+#[derive(Debug, Deserialize)]
+pub struct CpuProfileInput {
+    /// 采样时长（秒），默认 30
+    #[serde(default = "default_profile_seconds")]
+    pub seconds: u64,
+    /// 采样频率（Hz），默认 99
+    #[serde(default = "default_profile_frequency")]
+    pub frequency: i32,
+    #[serde(default)]
+    pub format: ProfileFormat,
+}
+
+fn default_profile_seconds() -> u64 {
+    30
+}
+
+fn default_profile_frequency() -> i32 {
+    99
+}
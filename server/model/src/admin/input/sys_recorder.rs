@@ -0,0 +1,58 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 开启请求录制，`user_id` 和 `request_id_pattern` 至少指定一个，同时指定时需同时满足
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct StartRecordingInput {
+    /// 只录制该用户的请求
+    pub user_id: Option<String>,
+    /// 只录制请求 ID 匹配该模式的请求，支持 `*` 通配
+    #[validate(length(
+        min = 1,
+        max = 64,
+        message = "Request id pattern must be 1-64 characters"
+    ))]
+    pub request_id_pattern: Option<String>,
+    /// 最多保留的请求数，默认及上限为 `recorder.max_capacity`
+    #[validate(range(min = 1, message = "Capacity must be at least 1"))]
+    pub capacity: Option<usize>,
+    /// 录制时长（秒），到期自动停止，默认 600
+    #[validate(range(
+        min = 1,
+        max = 86400,
+        message = "Duration must be between 1 and 86400 seconds"
+    ))]
+    #[serde(default = "default_recording_seconds")]
+    pub duration_seconds: u64,
+}
+
+fn default_recording_seconds() -> u64 {
+    600
+}
+
+/// 将录制的请求回放到指定环境
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayRecordingInput {
+    /// 目标环境地址，如 `https://staging.example.com`
+    #[validate(url(message = "Base url must be a valid url"))]
+    pub base_url: String,
+    /// 目标环境的访问令牌，录制时令牌已脱敏，需重新提供
+    pub token: Option<String>,
+    /// 指定回放的记录，为空时按录制顺序回放全部
+    #[serde(default)]
+    pub trace_ids: Vec<String>,
+    /// 单个请求的超时（秒），默认 30
+    #[validate(range(
+        min = 1,
+        max = 300,
+        message = "Timeout must be between 1 and 300 seconds"
+    ))]
+    #[serde(default = "default_replay_timeout")]
+    pub timeout_seconds: u64,
+}
+
+fn default_replay_timeout() -> u64 {
+    30
+}
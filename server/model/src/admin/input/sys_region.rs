@@ -0,0 +1,38 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 行政区划数据集格式
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RegionFormat {
+    /// 嵌套 JSON：`[{ "code": "11", "name": "北京市", "children": [...] }]`
+    #[default]
+    Json,
+    /// 逗号分隔的 `code,name,parentCode`，顶级区划的 `parentCode` 为空或 `0`，首行可以是表头
+    Csv,
+    /// GB/T 2260 六位代码表，每行 `code name`，按代码推断上级
+    Gb2260,
+}
+
+/// 导入一个版本的行政区划数据
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportRegionInput {
+    /// 版本号，如数据集的发布年份 `2023`，不能与已有版本重复
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Version must be between 1 and 50 characters"
+    ))]
+    pub version: String,
+    /// 数据来源，如数据集名称或下载地址
+    #[validate(length(max = 500, message = "Source must not exceed 500 characters"))]
+    pub source: Option<String>,
+    #[serde(default)]
+    pub format: RegionFormat,
+    #[validate(length(min = 1, message = "Content must not be empty"))]
+    pub content: String,
+    /// 导入后立即启用该版本
+    #[serde(default)]
+    pub activate: bool,
+}
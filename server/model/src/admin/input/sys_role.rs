@@ -2,7 +2,7 @@ use serde::{Deserialize, Serialize};
 use server_core::web::page::PageRequest;
 use validator::Validate;
 
-use crate::admin::entities::sea_orm_active_enums::Status;
+use crate::admin::entities::sea_orm_active_enums::{DataScope, Status};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RolePageRequest {
@@ -12,6 +12,7 @@ pub struct RolePageRequest {
 }
 
 #[derive(Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
 pub struct RoleInput {
     pub pid: String,
     #[validate(length(
@@ -29,6 +30,11 @@ pub struct RoleInput {
     pub status: Status,
     #[validate(length(max = 200, message = "Description must not exceed 200 characters"))]
     pub description: Option<String>,
+    /// 数据范围，用于将管理权限限定在组织子树内
+    #[serde(default)]
+    pub data_scope: DataScope,
+    /// 数据范围的根组织，未设置时以用户所属组织为根
+    pub scope_organization_id: Option<String>,
 }
 
 pub type CreateRoleInput = RoleInput;
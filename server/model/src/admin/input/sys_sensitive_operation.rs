@@ -0,0 +1,26 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 申请调用生产环境的敏感接口，`method` 和 `path` 取自拦截响应
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestSensitiveOperationInput {
+    #[validate(length(
+        min = 1,
+        max = 10,
+        message = "Method must be between 1 and 10 characters"
+    ))]
+    pub method: String,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Path must be between 1 and 500 characters"
+    ))]
+    pub path: String,
+    #[validate(length(
+        min = 1,
+        max = 500,
+        message = "Reason must be between 1 and 500 characters"
+    ))]
+    pub reason: String,
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+use server_core::web::page::PageRequest;
+use validator::Validate;
+
+use crate::admin::{
+    entities::sea_orm_active_enums::{TenantIsolation, TenantStatus},
+    output::TenantArchive,
+};
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct TenantPageRequest {
+    #[serde(flatten)]
+    pub page_details: PageRequest,
+    pub keywords: Option<String>,
+    pub status: Option<TenantStatus>,
+}
+
+/// 开通租户：创建域、管理员角色和管理员账号，
+/// `isolation` 为 `database` 时 `db_instance` 须为 `database_instances` 中已配置的实例
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ProvisionTenantInput {
+    /// 租户编码，即域编码，只能包含小写字母、数字、`-` 和 `_`
+    #[validate(length(
+        min = 2,
+        max = 32,
+        message = "Code must be between 2 and 32 characters"
+    ))]
+    pub code: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    #[validate(length(max = 500, message = "Description must not exceed 500 characters"))]
+    pub description: Option<String>,
+    pub isolation: TenantIsolation,
+    pub db_instance: Option<String>,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Admin username must be between 1 and 50 characters"
+    ))]
+    pub admin_username: String,
+    #[validate(length(
+        min = 6,
+        max = 64,
+        message = "Admin password must be between 6 and 64 characters"
+    ))]
+    pub admin_password: String,
+    #[validate(length(
+        min = 1,
+        max = 50,
+        message = "Admin nick name must be between 1 and 50 characters"
+    ))]
+    pub admin_nick_name: String,
+    #[validate(email(message = "Invalid email format"))]
+    pub admin_email: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate)]
+pub struct SuspendTenantInput {
+    /// 展示给租户用户的停用说明
+    #[validate(length(max = 200, message = "Reason must not exceed 200 characters"))]
+    pub reason: Option<String>,
+}
+
+/// 确认删除租户，须提供申请删除时返回的确认码并再次输入租户编码
+#[derive(Debug, Deserialize, Validate)]
+pub struct ConfirmTenantPurgeInput {
+    pub token: String,
+    pub code: String,
+}
+
+/// 从导出档案导入租户，可以使用与源租户不同的编码和隔离方式
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportTenantInput {
+    #[validate(length(
+        min = 2,
+        max = 32,
+        message = "Code must be between 2 and 32 characters"
+    ))]
+    pub code: String,
+    #[validate(length(
+        min = 1,
+        max = 100,
+        message = "Name must be between 1 and 100 characters"
+    ))]
+    pub name: String,
+    pub isolation: TenantIsolation,
+    pub db_instance: Option<String>,
+    pub archive: TenantArchive,
+}
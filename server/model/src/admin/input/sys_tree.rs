@@ -0,0 +1,19 @@
+use serde::Deserialize;
+use validator::Validate;
+
+/// 查询子树
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SubtreeRequest {
+    /// 向下展开的层数，1 表示只加载直接下级，未指定时加载整棵子树
+    pub depth: Option<u32>,
+}
+
+/// 连同下级节点一起移动到新的上级
+#[derive(Debug, Deserialize, Validate)]
+#[serde(rename_all = "camelCase")]
+pub struct MoveTreeNodeInput {
+    /// 新的上级节点 ID，`0` 表示移动到根
+    #[validate(length(min = 1, message = "Parent id cannot be empty"))]
+    pub pid: String,
+}
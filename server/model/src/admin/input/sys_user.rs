@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use server_core::web::page::PageRequest;
+use server_core::web::page::{IncludeRequest, PageRequest};
 use validator::Validate;
 
 use crate::admin::entities::sea_orm_active_enums::Status;
@@ -9,6 +9,8 @@ pub struct UserPageRequest {
     #[serde(flatten)]
     pub page_details: PageRequest,
     pub keywords: Option<String>,
+    #[serde(flatten)]
+    pub include: IncludeRequest,
 }
 
 #[derive(Deserialize, Validate)]
@@ -39,6 +41,8 @@ pub struct UserInput {
     #[validate(length(max = 20, message = "Phone number must not exceed 20 characters"))]
     pub phone_number: Option<String>,
     pub status: Status,
+    /// 所属组织
+    pub organization_id: Option<String>,
 }
 
 pub type CreateUserInput = UserInput;
@@ -1,11 +1,72 @@
+pub use sys_access_review::{AccessReviewDepartment, AccessReviewReport};
+pub use sys_api_usage::ApiUsageSummary;
 pub use sys_authentication::{AuthOutput, UserInfoOutput, UserRoute};
+pub use sys_break_glass::{BreakGlassOutput, BreakGlassSession};
+pub use sys_cluster::ClusterMember;
+pub use sys_consistency::{ConsistencyFinding, ConsistencyReport};
+pub use sys_db_pool::DbPoolInfo;
+pub use sys_deletion::{CascadeAction, CascadeDependent, CascadeEffect, DeletionPreview};
+pub use sys_diagnostics::DiagnosticsBundle;
 pub use sys_domain::DomainOutput;
 pub use sys_endpoint::EndpointTree;
+pub use sys_file::{FileDownload, FileShareLink, FileUrl, SharedFileInfo};
+pub use sys_instance::{DrainStatus, RouteMiddlewareStack};
+pub use sys_maintenance_window::MaintenanceBanner;
 pub use sys_menu::{MenuRoute, MenuTree, RouteMeta};
-pub use sys_user::{UserWithDomainAndOrgOutput, UserWithoutPassword};
+pub use sys_metering::MeteringExport;
+pub use sys_migration::{MigrationPlan, MigrationRisk, PendingMigration};
+pub use sys_module::ModuleStatus;
+pub use sys_notification::NotificationPreferenceOutput;
+pub use sys_operation_log::{
+    AuditChainDivergence, AuditChainDivergenceReason, AuditChainPartition, AuditChainReport,
+};
+pub use sys_passkey::PasskeyChallengeOutput;
+pub use sys_personal_token::{CreatedPersonalToken, PersonalTokenInfo};
+pub use sys_profiling::{ProfileReport, RuntimeStats};
+pub use sys_recorder::ReplayResult;
+pub use sys_reference::{EntityReferences, ReferenceCount};
+pub use sys_region::Region;
+pub use sys_sensitive_operation::SensitiveOperationApproval;
+pub use sys_telemetry::{TelemetryInstances, TelemetryReport, TELEMETRY_SCHEMA_VERSION};
+pub use sys_tenant::{
+    ArchivedAccessKey, ArchivedFile, ArchivedPolicy, ArchivedRole, ArchivedRoleMenu, ArchivedUser,
+    TenantArchive, TenantArchivePayload, TenantExport, TenantImportResult, TenantPurgeConfirmation,
+    TENANT_ARCHIVE_VERSION,
+};
+pub use sys_tree::TreeNode;
+pub use sys_user::{
+    DataScopeOutput, EffectivePermission, EffectivePermissionsOutput, UserWithDomainAndOrgOutput,
+    UserWithoutPassword,
+};
 
+mod sys_access_review;
+mod sys_api_usage;
 mod sys_authentication;
+mod sys_break_glass;
+mod sys_cluster;
+mod sys_consistency;
+mod sys_db_pool;
+mod sys_deletion;
+mod sys_diagnostics;
 mod sys_domain;
 mod sys_endpoint;
+mod sys_file;
+mod sys_instance;
+mod sys_maintenance_window;
 mod sys_menu;
+mod sys_metering;
+mod sys_migration;
+mod sys_module;
+mod sys_notification;
+mod sys_operation_log;
+mod sys_passkey;
+mod sys_personal_token;
+mod sys_profiling;
+mod sys_recorder;
+mod sys_reference;
+mod sys_region;
+mod sys_sensitive_operation;
+mod sys_telemetry;
+mod sys_tenant;
+mod sys_tree;
 mod sys_user;
@@ -0,0 +1,27 @@
+use serde::Serialize;
+
+use crate::admin::entities::{
+    sys_access_review::Model as SysAccessReviewModel,
+    sys_access_review_item::Model as SysAccessReviewItemModel,
+};
+
+/// 按组织汇总的复核报告
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewReport {
+    pub review: SysAccessReviewModel,
+    pub departments: Vec<AccessReviewDepartment>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessReviewDepartment {
+    /// 未归属组织的用户为空
+    pub organization_id: Option<String>,
+    pub organization_name: Option<String>,
+    pub total: usize,
+    pub pending: usize,
+    pub confirmed: usize,
+    pub revoked: usize,
+    pub items: Vec<SysAccessReviewItemModel>,
+}
@@ -0,0 +1,40 @@
+use serde::Serialize;
+
+use crate::admin::entities::sea_orm_active_enums::ApiUsageSubjectKind;
+
+/// 调用方在一段时间内的请求数和错误数
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiUsageSummary {
+    pub subject_kind: ApiUsageSubjectKind,
+    pub subject: String,
+    pub label: String,
+    pub requests: i64,
+    pub errors: i64,
+    /// 错误率，0 到 1
+    pub error_rate: f64,
+}
+
+impl ApiUsageSummary {
+    pub fn new(
+        subject_kind: ApiUsageSubjectKind,
+        subject: String,
+        label: String,
+        requests: i64,
+        errors: i64,
+    ) -> Self {
+        let error_rate = if requests > 0 {
+            errors as f64 / requests as f64
+        } else {
+            0.0
+        };
+        Self {
+            subject_kind,
+            subject,
+            label,
+            requests,
+            errors,
+            error_rate,
+        }
+    }
+}
@@ -8,6 +8,10 @@ pub struct AuthOutput {
     // 为了复用soybean-admin-nestjs前端,暂时弃用
     // pub access_token: String,
     pub refresh_token: String,
+    /// 需要通行密钥二次验证时返回，此时 `token` 为空，
+    /// 前端用该票据调用 `/auth/passkey/login/start` 继续登录
+    #[serde(rename = "passkeyTicket", skip_serializing_if = "Option::is_none")]
+    pub passkey_ticket: Option<String>,
 }
 
 #[derive(Debug, Serialize)]
@@ -0,0 +1,24 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// 紧急访问会话，保存在主 Redis 中，到期或被提前结束后删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakGlassSession {
+    pub id: String,
+    pub operator: String,
+    pub reason: String,
+    pub role: String,
+    pub domain: String,
+    pub client_ip: String,
+    pub started_at: NaiveDateTime,
+    pub expires_at: NaiveDateTime,
+}
+
+/// 使用紧急凭证的结果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakGlassOutput {
+    pub token: String,
+    pub session: BreakGlassSession,
+}
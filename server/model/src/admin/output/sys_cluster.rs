@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// 集群成员信息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ClusterMember {
+    pub instance_id: String,
+    /// 实例主机名及监听地址
+    pub host: String,
+    /// 进程角色：api、worker 或 all
+    pub role: String,
+    pub started_at: NaiveDateTime,
+    pub last_heartbeat: NaiveDateTime,
+    /// 是否为当前主节点
+    #[serde(default)]
+    pub leader: bool,
+}
@@ -0,0 +1,35 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 单个类别的检查结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyFinding {
+    /// 检查类别，如 `orphaned_user_role`
+    pub category: String,
+    /// 发现的问题数量
+    pub count: usize,
+    /// 部分问题记录，用于定位
+    pub samples: Vec<String>,
+    /// 已自动修复的数量
+    pub repaired: usize,
+    /// 检查失败时的原因，失败的类别不影响其余类别
+    pub error: Option<String>,
+}
+
+/// 数据一致性检查报告
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConsistencyReport {
+    pub checked_at: NaiveDateTime,
+    pub findings: Vec<ConsistencyFinding>,
+}
+
+impl ConsistencyReport {
+    /// 是否发现了问题或有类别检查失败
+    pub fn has_issues(&self) -> bool {
+        self.findings
+            .iter()
+            .any(|finding| finding.count > 0 || finding.error.is_some())
+    }
+}
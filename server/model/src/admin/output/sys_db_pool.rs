@@ -0,0 +1,16 @@
+use serde::Serialize;
+use server_core::web::statement_cache::StatementCacheStats;
+
+/// 数据库连接池信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DbPoolInfo {
+    /// 连接池名称，主数据库为 `primary`
+    pub name: String,
+    pub max_connections: u32,
+    pub min_connections: u32,
+    /// 每个连接的预编译语句缓存容量
+    pub statement_cache_capacity: usize,
+    /// 语句缓存命中统计，连接池尚未建立时为空
+    pub statement_cache: Option<StatementCacheStats>,
+}
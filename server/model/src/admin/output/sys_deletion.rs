@@ -0,0 +1,56 @@
+use serde::Serialize;
+
+use crate::admin::input::DeletionTarget;
+
+/// 被删除对象的关联数据
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CascadeDependent {
+    /// 下级组织
+    ChildOrganizations,
+    /// 归属于该组织的用户
+    OrganizationUsers,
+    /// 以该组织为数据范围根组织的角色
+    ScopedRoles,
+    /// 角色的用户分配
+    RoleUsers,
+    /// 角色的菜单分配
+    RoleMenus,
+    /// 角色的接口授权策略
+    RolePermissions,
+}
+
+/// 关联数据的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CascadeAction {
+    /// 存在关联数据时拒绝删除
+    Block,
+    /// 改挂到指定对象，未指定时按 `Block` 处理
+    Reassign,
+    /// 随删除一并解除关联
+    Detach,
+}
+
+/// 一条级联规则对关联数据的影响
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CascadeEffect {
+    pub dependent: CascadeDependent,
+    pub action: CascadeAction,
+    /// 受影响的关联数据条数
+    pub count: u64,
+    /// 是否阻止删除
+    pub blocking: bool,
+}
+
+/// 删除预览
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DeletionPreview {
+    pub target: DeletionTarget,
+    pub id: String,
+    /// 没有阻止删除的关联数据时为 `true`
+    pub allowed: bool,
+    pub effects: Vec<CascadeEffect>,
+}
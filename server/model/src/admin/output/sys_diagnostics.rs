@@ -0,0 +1,12 @@
+use serde::Serialize;
+
+use crate::admin::entities::sys_file::Model as SysFileModel;
+
+/// 生成的诊断包，通过文件下载接口获取 ZIP 内容
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnosticsBundle {
+    pub file: SysFileModel,
+    /// 收集失败的部分及原因，失败的部分在诊断包中记录为错误信息
+    pub failures: Vec<String>,
+}
@@ -0,0 +1,44 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+use crate::admin::entities::{
+    sys_file::Model as SysFileModel, sys_file_share::Model as SysFileShareModel,
+};
+
+/// 文件下载内容
+#[derive(Debug)]
+pub struct FileDownload {
+    pub file: SysFileModel,
+    pub data: Vec<u8>,
+}
+
+/// 文件访问地址，配置了 `public_base_url` 时为长期有效的公开地址
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileUrl {
+    pub url: String,
+    /// 预签名地址的过期时间，公开地址为空
+    pub expires_at: Option<NaiveDateTime>,
+}
+
+/// 新建的分享链接，令牌只在创建时返回一次，库中仅保存其哈希
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileShareLink {
+    pub share: SysFileShareModel,
+    pub token: String,
+    pub url: String,
+}
+
+/// 分享链接对外展示的文件信息，不包含文件 ID 等内部字段
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SharedFileInfo {
+    pub file_name: String,
+    pub content_type: String,
+    pub size: i64,
+    pub expires_at: NaiveDateTime,
+    pub password_required: bool,
+    /// 剩余下载次数，为空时不限制
+    pub remaining_downloads: Option<i32>,
+}
@@ -0,0 +1,26 @@
+use serde::Serialize;
+
+/// 实例排空状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DrainStatus {
+    pub draining: bool,
+    /// 进行中的请求和后台任务数量
+    pub in_flight: usize,
+}
+
+/// 单个路由实际经过的中间件
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RouteMiddlewareStack {
+    pub path: String,
+    pub method: String,
+    pub service_name: String,
+    pub summary: String,
+    /// 权限声明：permission、authenticated 或 public
+    pub guard: String,
+    /// 拥有该接口权限的角色，未启用 Casbin 时为空
+    pub roles: Vec<String>,
+    /// 按请求经过的顺序排列，最外层在前
+    pub layers: Vec<String>,
+}
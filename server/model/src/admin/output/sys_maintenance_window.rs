@@ -0,0 +1,16 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 前端轮询的维护横幅，包含进行中和即将开始的窗口
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MaintenanceBanner {
+    pub id: String,
+    pub name: String,
+    pub message: Option<String>,
+    /// 本次窗口的开始和结束时间，周期窗口为最近一次
+    pub start_at: NaiveDateTime,
+    pub end_at: NaiveDateTime,
+    /// 是否正在进行
+    pub active: bool,
+}
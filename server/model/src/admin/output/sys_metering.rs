@@ -0,0 +1,7 @@
+/// 导出的计量 CSV，由接口以附件形式返回
+#[derive(Debug)]
+pub struct MeteringExport {
+    pub csv: String,
+    /// 水印编号，未启用水印时为空
+    pub watermark_id: Option<String>,
+}
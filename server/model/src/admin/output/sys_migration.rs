@@ -0,0 +1,36 @@
+use serde::Serialize;
+
+/// 迁移风险等级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MigrationRisk {
+    /// 新建表、类型或写入初始数据
+    Low,
+    /// 可能长时间持有锁，例如在已有表上建索引、添加带默认值的列
+    Medium,
+    /// 会重写表、丢失数据或在非空表上失败
+    High,
+    /// 无法推导出 SQL，需人工审阅迁移代码
+    Unknown,
+}
+
+/// 待执行的迁移
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PendingMigration {
+    pub name: String,
+    /// 迁移将执行的 SQL，无法推导时为空
+    pub statements: Vec<String>,
+    pub risk: MigrationRisk,
+    /// 风险判定依据
+    pub reasons: Vec<String>,
+}
+
+/// 迁移计划
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MigrationPlan {
+    pub pending: Vec<PendingMigration>,
+    /// 所有待执行迁移中的最高风险，无待执行迁移时为空
+    pub highest_risk: Option<MigrationRisk>,
+}
@@ -0,0 +1,17 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 功能模块及其启用状态
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ModuleStatus {
+    /// 模块编码，对应系统参数 `module.<code>.enabled`
+    pub code: String,
+    pub name: String,
+    /// 模块的路由前缀，停用后这些路由返回 404
+    pub route_prefixes: Vec<String>,
+    pub enabled: bool,
+    /// 最后修改人，从未修改过时为空
+    pub updated_by: Option<String>,
+    pub updated_at: Option<NaiveDateTime>,
+}
@@ -0,0 +1,48 @@
+use chrono::NaiveTime;
+use serde::Serialize;
+
+use crate::admin::entities::sys_notification_preference::Model as SysNotificationPreferenceModel;
+
+/// 通知偏好，未设置过的用户返回默认值
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NotificationPreferenceOutput {
+    pub email_enabled: bool,
+    pub in_app_enabled: bool,
+    pub muted_categories: Vec<String>,
+    pub quiet_start: Option<NaiveTime>,
+    pub quiet_end: Option<NaiveTime>,
+    pub digest_enabled: bool,
+}
+
+impl Default for NotificationPreferenceOutput {
+    fn default() -> Self {
+        Self {
+            email_enabled: true,
+            in_app_enabled: true,
+            muted_categories: Vec::new(),
+            quiet_start: None,
+            quiet_end: None,
+            digest_enabled: false,
+        }
+    }
+}
+
+impl From<SysNotificationPreferenceModel> for NotificationPreferenceOutput {
+    fn from(model: SysNotificationPreferenceModel) -> Self {
+        Self {
+            email_enabled: model.email_enabled,
+            in_app_enabled: model.in_app_enabled,
+            muted_categories: model
+                .muted_categories
+                .split(',')
+                .map(str::trim)
+                .filter(|category| !category.is_empty())
+                .map(ToString::to_string)
+                .collect(),
+            quiet_start: model.quiet_start,
+            quiet_end: model.quiet_end,
+            digest_enabled: model.digest_enabled,
+        }
+    }
+}
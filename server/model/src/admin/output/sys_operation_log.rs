@@ -0,0 +1,47 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 审计日志哈希链校验结果
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditChainReport {
+    /// 所有分区均未发现断链
+    pub intact: bool,
+    pub verified_at: NaiveDateTime,
+    pub partitions: Vec<AuditChainPartition>,
+}
+
+/// 单个分区的校验结果，分区按域划分
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditChainPartition {
+    pub domain: String,
+    /// 发现断链前已校验通过的记录数
+    pub verified_records: u64,
+    /// 第一条断链的记录，完整时为空
+    pub divergence: Option<AuditChainDivergence>,
+}
+
+/// 断链的记录
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AuditChainDivergence {
+    pub id: String,
+    pub chain_seq: i64,
+    pub reason: AuditChainDivergenceReason,
+    /// 按链计算出的应有值：`HashMismatch` 时为本条记录的哈希，其余为前一条记录的哈希或序号
+    pub expected: String,
+    /// 记录中保存的值
+    pub actual: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum AuditChainDivergenceReason {
+    /// 序号不连续，中间的记录被删除
+    SequenceGap,
+    /// 保存的前一条哈希与链不一致，前一条记录被删除或替换
+    PrevHashMismatch,
+    /// 记录内容与保存的哈希不一致，记录被修改
+    HashMismatch,
+}
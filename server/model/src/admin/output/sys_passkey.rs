@@ -0,0 +1,10 @@
+use serde::Serialize;
+use serde_json::Value;
+
+/// 通行密钥注册或登录挑战，`options` 原样传给 `navigator.credentials.create/get`
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PasskeyChallengeOutput {
+    pub challenge_id: String,
+    pub options: Value,
+}
@@ -0,0 +1,25 @@
+use chrono::NaiveDateTime;
+use serde::Serialize;
+
+/// 个人访问令牌，不含令牌原文
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalTokenInfo {
+    pub id: String,
+    pub name: String,
+    /// 令牌末尾几位，用于辨认
+    pub token_hint: String,
+    pub scopes: Vec<String>,
+    pub expires_at: NaiveDateTime,
+    pub last_used_at: Option<NaiveDateTime>,
+    pub created_at: NaiveDateTime,
+}
+
+/// 新创建的个人访问令牌，`token` 只在此时返回一次
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreatedPersonalToken {
+    pub token: String,
+    #[serde(flatten)]
+    pub info: PersonalTokenInfo,
+}
@@ -0,0 +1,25 @@
+use serde::Serialize;
+
+/// 性能剖析结果
+#[derive(Debug)]
+pub struct ProfileReport {
+    pub file_name: String,
+    pub content_type: String,
+    pub data: Vec<u8>,
+}
+
+/// 进程内存及异步运行时统计
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuntimeStats {
+    /// 常驻内存（字节）
+    pub resident_bytes: u64,
+    /// 常驻内存峰值（字节）
+    pub peak_resident_bytes: u64,
+    /// 虚拟内存（字节）
+    pub virtual_bytes: u64,
+    /// tokio 工作线程数
+    pub worker_threads: usize,
+    /// 存活的 tokio 任务数
+    pub alive_tasks: usize,
+}
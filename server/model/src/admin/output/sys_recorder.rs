@@ -0,0 +1,19 @@
+use serde::Serialize;
+
+/// 单条记录的回放结果
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplayResult {
+    pub trace_id: String,
+    pub method: String,
+    pub uri: String,
+    pub original_status: u16,
+    /// 录制时响应中的业务码
+    pub original_code: Option<i64>,
+    pub replay_status: Option<u16>,
+    pub replay_code: Option<i64>,
+    /// HTTP 状态和业务码是否与录制时一致
+    pub matched: bool,
+    pub duration_ms: u64,
+    pub error: Option<String>,
+}
@@ -0,0 +1,24 @@
+use serde::Serialize;
+
+/// 一种引用关系的引用数
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReferenceCount {
+    /// 关系名称，如 `users`、`roleMenus`
+    pub relation: String,
+    /// 引用方所在的表和列
+    pub table: String,
+    pub column: String,
+    pub count: u64,
+}
+
+/// 引用某条数据的全部关联数据
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EntityReferences {
+    pub entity: String,
+    pub id: String,
+    /// 全部关系的引用数之和，为 0 时没有任何数据引用该条数据
+    pub total: u64,
+    pub relations: Vec<ReferenceCount>,
+}
@@ -0,0 +1,11 @@
+use serde::Serialize;
+
+/// 地址选择器使用的行政区划节点
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Region {
+    pub code: String,
+    pub name: String,
+    /// 层级，省级为 1
+    pub level: i16,
+}
@@ -0,0 +1,18 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+/// 敏感操作审批，保存在主 Redis 中，到期或核销后删除
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SensitiveOperationApproval {
+    pub id: String,
+    pub method: String,
+    pub path: String,
+    pub reason: String,
+    pub requester_id: String,
+    pub requester: String,
+    /// 批准人，未批准时为空
+    pub approver: Option<String>,
+    pub approved_at: Option<NaiveDateTime>,
+    pub expires_at: NaiveDateTime,
+}
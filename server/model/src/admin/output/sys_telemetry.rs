@@ -0,0 +1,38 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+use server_core::web::telemetry::RouteLatency;
+
+/// 遥测报告的结构版本，字段含义变化时递增
+pub const TELEMETRY_SCHEMA_VERSION: u32 = 1;
+
+/// 匿名遥测报告
+///
+/// 只包含汇总数据，不包含主机名、连接串、用户、租户等可识别部署的信息
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryReport {
+    pub schema_version: u32,
+    /// 服务端版本号
+    pub version: String,
+    pub os: String,
+    pub arch: String,
+    /// 报告日期，只精确到天
+    pub report_date: NaiveDate,
+    /// 已启用的可选模块，按名称排序
+    pub modules: Vec<String>,
+    pub instances: TelemetryInstances,
+    /// 本统计周期内各路由分组的请求数和 P95 延迟
+    pub latencies: Vec<RouteLatency>,
+}
+
+/// 各类实例的数量
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TelemetryInstances {
+    /// 在线的服务实例数
+    pub servers: usize,
+    pub databases: usize,
+    pub redis: usize,
+    pub mongo: usize,
+    pub s3: usize,
+}
@@ -0,0 +1,135 @@
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+
+use crate::admin::entities::{
+    sea_orm_active_enums::{DataScope, Status, TenantIsolation},
+    sys_tenant::Model as SysTenantModel,
+};
+
+/// 申请删除租户后返回的确认码
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantPurgeConfirmation {
+    pub token: String,
+    pub expires_at: NaiveDateTime,
+}
+
+/// 租户导出档案的格式版本，格式不兼容时递增
+pub const TENANT_ARCHIVE_VERSION: u32 = 1;
+
+/// 租户导出档案
+///
+/// 档案不含任何 ID，导入时按编码、用户名和菜单路由名重新关联，
+/// `checksum` 为 `payload` JSON 序列化结果的 SHA-256
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantArchive {
+    pub version: u32,
+    pub checksum: String,
+    /// 导出水印，不参与校验和计算
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub watermark: Option<String>,
+    pub payload: TenantArchivePayload,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantArchivePayload {
+    pub exported_at: NaiveDateTime,
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub isolation: TenantIsolation,
+    /// 租户管理员角色编码，导入时按目标租户编码重新生成
+    pub admin_role_code: String,
+    pub roles: Vec<ArchivedRole>,
+    pub users: Vec<ArchivedUser>,
+    pub role_menus: Vec<ArchivedRoleMenu>,
+    pub policies: Vec<ArchivedPolicy>,
+    pub access_keys: Vec<ArchivedAccessKey>,
+    pub files: Vec<ArchivedFile>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedRole {
+    pub code: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub parent_code: Option<String>,
+    pub data_scope: DataScope,
+}
+
+/// 密码以哈希导出，导入后原密码仍可登录
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedUser {
+    pub username: String,
+    pub password: String,
+    pub nick_name: String,
+    pub avatar: Option<String>,
+    pub email: Option<String>,
+    pub phone_number: Option<String>,
+    pub status: Status,
+    pub roles: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedRoleMenu {
+    pub role_code: String,
+    pub route_name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedPolicy {
+    pub role_code: String,
+    pub path: String,
+    pub method: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedAccessKey {
+    pub access_key_id: String,
+    pub access_key_secret: String,
+    pub status: Status,
+    pub description: Option<String>,
+}
+
+/// 文件内容以十六进制编码，`created_by` 为上传者用户名
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ArchivedFile {
+    pub file_name: String,
+    pub content_type: String,
+    pub created_by: String,
+    pub content: String,
+}
+
+/// 导出的档案内容，由接口以附件形式返回
+#[derive(Debug)]
+pub struct TenantExport {
+    pub file_name: String,
+    pub data: Vec<u8>,
+    /// 水印编号，未启用水印时为空
+    pub watermark_id: Option<String>,
+}
+
+/// 导入结果，跳过的数据需要人工处理
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TenantImportResult {
+    pub tenant: SysTenantModel,
+    pub users: usize,
+    pub roles: usize,
+    pub policies: usize,
+    pub files: usize,
+    /// 目标环境中不存在对应路由名的菜单
+    pub skipped_menus: Vec<String>,
+    /// 目标环境中已存在相同 AccessKey ID 的密钥
+    pub skipped_access_keys: Vec<String>,
+    /// 上传失败的文件名
+    pub skipped_files: Vec<String>,
+}
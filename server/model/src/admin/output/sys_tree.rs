@@ -0,0 +1,14 @@
+use serde::Serialize;
+
+/// 树形数据的节点
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TreeNode<T> {
+    #[serde(flatten)]
+    pub node: T,
+    /// 是否有下级节点，懒加载时据此显示展开按钮
+    pub has_children: bool,
+    /// 已加载的下级节点，超出查询深度或懒加载时为空
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub children: Option<Vec<TreeNode<T>>>,
+}
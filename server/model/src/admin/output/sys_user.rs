@@ -2,6 +2,7 @@ use chrono::NaiveDateTime;
 use sea_orm::FromQueryResult;
 use serde::Serialize;
 
+use super::UserRoute;
 use crate::admin::entities::{sea_orm_active_enums::Status, sys_user::Model as SysUserModel};
 
 #[derive(Debug, FromQueryResult)]
@@ -12,6 +13,7 @@ pub struct UserWithDomainAndOrgOutput {
     pub password: String,
     pub nick_name: String,
     pub avatar: Option<String>,
+    pub status: Status,
     pub domain_code: String,
     pub domain_name: String,
 }
@@ -27,6 +29,7 @@ pub struct UserWithoutPassword {
     pub email: Option<String>,
     pub phone_number: Option<String>,
     pub status: Status,
+    pub organization_id: Option<String>,
     pub created_at: NaiveDateTime,
     pub created_by: String,
     pub updated_at: Option<NaiveDateTime>,
@@ -44,6 +47,7 @@ impl From<SysUserModel> for UserWithoutPassword {
             email: model.email,
             phone_number: model.phone_number,
             status: model.status,
+            organization_id: model.organization_id,
             created_at: model.created_at,
             created_by: model.created_by,
             updated_at: model.updated_at,
@@ -51,3 +55,31 @@ impl From<SysUserModel> for UserWithoutPassword {
         }
     }
 }
+
+/// 用户的有效权限，供管理员排查用户看不到菜单或无法调用接口的问题
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectivePermissionsOutput {
+    pub user: UserWithoutPassword,
+    pub roles: Vec<String>,
+    /// 各角色在用户所属域下的接口权限
+    pub permissions: Vec<EffectivePermission>,
+    pub routes: UserRoute,
+    pub data_scope: DataScopeOutput,
+}
+
+#[derive(Debug, Serialize, PartialEq, Eq, PartialOrd, Ord)]
+pub struct EffectivePermission {
+    pub role: String,
+    pub path: String,
+    pub method: String,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DataScopeOutput {
+    /// 不限制数据范围
+    pub unrestricted: bool,
+    /// 可管理的组织，不限制时为空
+    pub organization_ids: Vec<String>,
+}
@@ -0,0 +1,105 @@
+use chrono::{Local, NaiveDateTime};
+use sea_orm::{ActiveValue, Set};
+use server_core::web::auth::current_user;
+
+/// 没有请求用户（后台任务、启动时初始化）时写入的操作人
+pub const SYSTEM_ACTOR: &str = "system";
+
+/// 当前操作人，取请求用户的用户名
+pub fn current_actor() -> String {
+    current_user()
+        .map(|user| user.username())
+        .unwrap_or_else(|| SYSTEM_ACTOR.to_string())
+}
+
+/// 插入时填充创建时间和创建人，调用方已设置的值保持不变
+pub fn stamp_created(
+    created_at: &mut ActiveValue<NaiveDateTime>,
+    created_by: &mut ActiveValue<String>,
+) {
+    if created_at.is_not_set() {
+        *created_at = Set(Local::now().naive_local());
+    }
+    if created_by.is_not_set() {
+        *created_by = Set(current_actor());
+    }
+}
+
+/// 更新时填充更新时间和更新人，调用方已设置的值保持不变
+pub fn stamp_updated(
+    updated_at: &mut ActiveValue<Option<NaiveDateTime>>,
+    updated_by: &mut ActiveValue<Option<String>>,
+) {
+    if !updated_at.is_set() {
+        *updated_at = Set(Some(Local::now().naive_local()));
+    }
+    if !updated_by.is_set() {
+        *updated_by = Set(Some(current_actor()));
+    }
+}
+
+/// 为带审计列的实体实现 `ActiveModelBehavior`，通过 `insert`、`update` 和 `save` 保存时自动填充审计列
+///
+/// - `audit_stamped!(created)`：插入时填充 `created_at`、`created_by`
+/// - `audit_stamped!(created, updated)`：另外在更新时填充 `updated_at`、`updated_by`
+///
+/// 操作人取当前请求的用户名，没有请求用户时为 `system`。
+/// `insert_many`、`update_many` 等批量语句不经过保存钩子，需自行设置。
+///
+/// 带 `created_by` 的实体都应使用该宏。其余实体只有时间戳列，没有操作人列；
+/// `sys_param` 通过 `ON CONFLICT` 批量写入，同样不经过钩子，由调用方写入用户名
+#[macro_export]
+macro_rules! audit_stamped {
+    (created) => {
+        #[::sea_orm::prelude::async_trait::async_trait]
+        impl ActiveModelBehavior for ActiveModel {
+            async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                if insert {
+                    $crate::audit::stamp_created(&mut self.created_at, &mut self.created_by);
+                }
+                Ok(self)
+            }
+        }
+    };
+    (created, updated) => {
+        #[::sea_orm::prelude::async_trait::async_trait]
+        impl ActiveModelBehavior for ActiveModel {
+            async fn before_save<C>(mut self, _db: &C, insert: bool) -> Result<Self, DbErr>
+            where
+                C: ConnectionTrait,
+            {
+                if insert {
+                    $crate::audit::stamp_created(&mut self.created_at, &mut self.created_by);
+                } else {
+                    $crate::audit::stamp_updated(&mut self.updated_at, &mut self.updated_by);
+                }
+                Ok(self)
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use sea_orm::ActiveValue::{NotSet, Unchanged};
+
+    use super::*;
+
+    #[test]
+    fn test_stamp_keeps_explicit_values() {
+        let mut created_at = NotSet;
+        let mut created_by = Set("importer".to_string());
+        stamp_created(&mut created_at, &mut created_by);
+        assert!(created_at.is_set());
+        assert_eq!(created_by, Set("importer".to_string()));
+
+        let mut updated_at = Unchanged(None);
+        let mut updated_by = Unchanged(Some("admin".to_string()));
+        stamp_updated(&mut updated_at, &mut updated_by);
+        assert!(updated_at.is_set());
+        assert_eq!(updated_by, Set(Some(SYSTEM_ACTOR.to_string())));
+    }
+}
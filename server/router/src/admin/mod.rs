@@ -1,23 +1,83 @@
 pub use sys_access_key_route::SysAccessKeyRouter;
+pub use sys_access_review_route::SysAccessReviewRouter;
+pub use sys_alert_rule_route::SysAlertRuleRouter;
+pub use sys_api_usage_route::SysApiUsageRouter;
 pub use sys_authentication_route::SysAuthenticationRouter;
+pub use sys_break_glass_route::SysBreakGlassRouter;
+#[cfg(feature = "chaos")]
+pub use sys_chaos_route::SysChaosRouter;
+pub use sys_cluster_route::SysClusterRouter;
+pub use sys_config_route::SysConfigRouter;
+pub use sys_consistency_route::SysConsistencyRouter;
+pub use sys_db_pool_route::SysDbPoolRouter;
+pub use sys_deletion_route::SysDeletionRouter;
+pub use sys_diagnostics_route::SysDiagnosticsRouter;
 pub use sys_domain_route::SysDomainRouter;
 pub use sys_endpoint_route::SysEndpointRouter;
+pub use sys_file_route::SysFileRouter;
+pub use sys_instance_route::SysInstanceRouter;
 pub use sys_login_log_route::SysLoginLogRouter;
+pub use sys_maintenance_window_route::SysMaintenanceWindowRouter;
 pub use sys_menu_route::SysMenuRouter;
+pub use sys_metering_route::SysMeteringRouter;
+pub use sys_migration_route::SysMigrationRouter;
+pub use sys_module_route::SysModuleRouter;
+pub use sys_notification_route::SysNotificationRouter;
 pub use sys_operation_log_route::SysOperationLogRouter;
 pub use sys_organization_route::SysOrganizationRouter;
+pub use sys_passkey_route::SysPasskeyRouter;
+pub use sys_personal_token_route::SysPersonalTokenRouter;
+pub use sys_policy_route::SysPolicyRouter;
+#[cfg(feature = "profiling")]
+pub use sys_profiling_route::SysProfilingRouter;
+pub use sys_recorder_route::SysRecorderRouter;
+pub use sys_reference_route::SysReferenceRouter;
+pub use sys_region_route::SysRegionRouter;
 pub use sys_role_route::SysRoleRouter;
 pub use sys_sandbox_route::SysSandboxRouter;
+pub use sys_sensitive_operation_route::SysSensitiveOperationRouter;
+pub use sys_telemetry_route::SysTelemetryRouter;
+pub use sys_tenant_route::SysTenantRouter;
 pub use sys_user_route::SysUserRouter;
 
 mod sys_access_key_route;
+mod sys_access_review_route;
+mod sys_alert_rule_route;
+mod sys_api_usage_route;
 mod sys_authentication_route;
+mod sys_break_glass_route;
+#[cfg(feature = "chaos")]
+mod sys_chaos_route;
+mod sys_cluster_route;
+mod sys_config_route;
+mod sys_consistency_route;
+mod sys_db_pool_route;
+mod sys_deletion_route;
+mod sys_diagnostics_route;
 mod sys_domain_route;
 mod sys_endpoint_route;
+mod sys_file_route;
+mod sys_instance_route;
 mod sys_login_log_route;
+mod sys_maintenance_window_route;
 mod sys_menu_route;
+mod sys_metering_route;
+mod sys_migration_route;
+mod sys_module_route;
+mod sys_notification_route;
 mod sys_operation_log_route;
 mod sys_organization_route;
+mod sys_passkey_route;
+mod sys_personal_token_route;
+mod sys_policy_route;
+#[cfg(feature = "profiling")]
+mod sys_profiling_route;
+mod sys_recorder_route;
+mod sys_reference_route;
+mod sys_region_route;
 mod sys_role_route;
 mod sys_sandbox_route;
+mod sys_sensitive_operation_route;
+mod sys_telemetry_route;
+mod sys_tenant_route;
 mod sys_user_route;
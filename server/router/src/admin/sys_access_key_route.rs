@@ -1,38 +1,32 @@
-use axum::{
-    http::Method,
-    routing::{delete, get, post},
-    Router,
-};
+use axum::Router;
 use server_api::admin::SysAccessKeyApi;
-use server_global::global::{add_route, RouteInfo};
+use server_global::global::{add_menu, MenuInfo};
+
+use crate::RouteManifest;
 
 pub struct SysAccessKeyRouter;
 
 impl SysAccessKeyRouter {
     pub async fn init_access_key_router() -> Router {
-        let base_path = "/access-key";
-        let service_name = "SysAccessKeyApi";
-
-        let routes = vec![
-            RouteInfo::new(base_path, Method::GET, service_name, "获取访问密钥列表"),
-            RouteInfo::new(base_path, Method::POST, service_name, "创建访问密钥"),
-            RouteInfo::new(
-                &format!("{}/:id", base_path),
-                Method::DELETE,
-                service_name,
-                "删除访问密钥",
-            ),
-        ];
+        let menus =
+            vec![
+                MenuInfo::menu("access-key", "/access-key", "layout.base$view.access-key")
+                    .with_icon("carbon:document-signed"),
+            ];
 
-        for route in routes {
-            add_route(route).await;
+        for menu in menus {
+            add_menu(menu).await;
         }
 
-        let router = Router::new()
-            .route("/", get(SysAccessKeyApi::get_paginated_access_keys))
-            .route("/", post(SysAccessKeyApi::create_access_key))
-            .route("/{id}", delete(SysAccessKeyApi::delete_access_key));
-
-        Router::new().nest(base_path, router)
+        RouteManifest::new("/access-key", "SysAccessKeyApi")
+            .get(
+                "/",
+                SysAccessKeyApi::get_paginated_access_keys,
+                "获取访问密钥列表",
+            )
+            .post("/", SysAccessKeyApi::create_access_key, "创建访问密钥")
+            .delete("/{id}", SysAccessKeyApi::delete_access_key, "删除访问密钥")
+            .build()
+            .await
     }
 }
@@ -0,0 +1,40 @@
+use axum::Router;
+use server_api::admin::SysAccessReviewApi;
+
+use crate::RouteManifest;
+
+pub struct SysAccessReviewRouter;
+
+impl SysAccessReviewRouter {
+    pub async fn init_access_review_router() -> Router {
+        RouteManifest::new("/access-review", "SysAccessReviewApi")
+            .get(
+                "/",
+                SysAccessReviewApi::get_paginated_reviews,
+                "获取权限复核列表",
+            )
+            .post("/", SysAccessReviewApi::start_review, "发起权限复核")
+            .get(
+                "/{id}/report",
+                SysAccessReviewApi::get_report,
+                "获取权限复核报告",
+            )
+            .get(
+                "/{id}/items",
+                SysAccessReviewApi::get_paginated_items,
+                "获取权限复核条目",
+            )
+            .post(
+                "/{id}/close",
+                SysAccessReviewApi::close_review,
+                "关闭权限复核",
+            )
+            .put(
+                "/items/{id}/decision",
+                SysAccessReviewApi::decide_item,
+                "提交权限复核决定",
+            )
+            .build()
+            .await
+    }
+}
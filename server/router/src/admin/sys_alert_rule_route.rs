@@ -0,0 +1,23 @@
+use axum::Router;
+use server_api::admin::SysAlertRuleApi;
+
+use crate::RouteManifest;
+
+pub struct SysAlertRuleRouter;
+
+impl SysAlertRuleRouter {
+    pub async fn init_alert_rule_router() -> Router {
+        RouteManifest::new("/alert-rule", "SysAlertRuleApi")
+            .get(
+                "/",
+                SysAlertRuleApi::get_paginated_rules,
+                "获取告警规则列表",
+            )
+            .post("/", SysAlertRuleApi::create_rule, "创建告警规则")
+            .get("/{id}", SysAlertRuleApi::get_rule, "获取告警规则详情")
+            .put("/", SysAlertRuleApi::update_rule, "更新告警规则")
+            .delete("/{id}", SysAlertRuleApi::delete_rule, "删除告警规则")
+            .build()
+            .await
+    }
+}
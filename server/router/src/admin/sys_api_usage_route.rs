@@ -0,0 +1,25 @@
+use axum::Router;
+use server_api::admin::SysApiUsageApi;
+
+use crate::RouteManifest;
+
+pub struct SysApiUsageRouter;
+
+impl SysApiUsageRouter {
+    pub async fn init_api_usage_router() -> Router {
+        RouteManifest::new("/api-usage", "SysApiUsageApi")
+            .get(
+                "/",
+                SysApiUsageApi::get_paginated_usage,
+                "获取 API 调用统计",
+            )
+            .get("/trend", SysApiUsageApi::get_trend, "获取 API 调用趋势")
+            .get(
+                "/current",
+                SysApiUsageApi::get_current_usage,
+                "获取当前小时 API 调用统计",
+            )
+            .build()
+            .await
+    }
+}
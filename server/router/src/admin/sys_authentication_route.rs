@@ -1,58 +1,78 @@
-use axum::{
-    http::Method,
-    routing::{get, post},
-    Router,
-};
+use axum::Router;
 use server_api::admin::SysAuthenticationApi;
-use server_global::global::{add_route, RouteInfo};
+use server_core::web::operation_log::AuditVerbosity;
+
+use crate::RouteManifest;
 
 pub struct SysAuthenticationRouter;
 
 impl SysAuthenticationRouter {
     pub async fn init_authentication_router() -> Router {
-        let router = Router::new().route("/login", post(SysAuthenticationApi::login_handler));
-        Router::new().nest("/auth", router)
+        RouteManifest::new("/auth", "SysAuthenticationApi")
+            .post("/login", SysAuthenticationApi::login_handler, "登录")
+            .public()
+            .post(
+                "/refreshToken",
+                SysAuthenticationApi::refresh_token_handler,
+                "刷新令牌",
+            )
+            .public()
+            .post(
+                "/passkey/login/start",
+                SysAuthenticationApi::start_passkey_login,
+                "开始通行密钥登录",
+            )
+            .public()
+            .post(
+                "/passkey/login/finish",
+                SysAuthenticationApi::finish_passkey_login,
+                "完成通行密钥登录",
+            )
+            .public()
+            .build()
+            .await
     }
 
     pub async fn init_protected_router() -> Router {
-        let router = Router::new()
-            .route("/getUserInfo", get(SysAuthenticationApi::get_user_info))
-            .route("/getUserRoutes", get(SysAuthenticationApi::get_user_routes));
-
-        Router::new().nest("/auth", router)
+        RouteManifest::new("/auth", "SysAuthenticationApi")
+            .get(
+                "/getUserInfo",
+                SysAuthenticationApi::get_user_info,
+                "获取用户信息",
+            )
+            .get(
+                "/getUserRoutes",
+                SysAuthenticationApi::get_user_routes,
+                "获取用户路由",
+            )
+            .post("/reauth", SysAuthenticationApi::reauth_handler, "重新认证")
+            // 请求体包含密码
+            .layer(AuditVerbosity::Metadata)
+            .all_authenticated()
+            .build()
+            .await
     }
 
     pub async fn init_authorization_router() -> Router {
-        let base_path = "/authorization";
-        let service_name = "SysAuthorizationApi";
-
-        let routes = vec![
-            RouteInfo::new(
-                &format!("{}/assign-permission", base_path),
-                Method::POST,
-                service_name,
+        RouteManifest::new("/authorization", "SysAuthorizationApi")
+            .get(
+                "/getUserRoutes",
+                SysAuthenticationApi::get_user_routes,
+                "获取用户路由",
+            )
+            .post(
+                "/assign-permission",
+                SysAuthenticationApi::assign_permission,
                 "分配权限",
-            ),
-            RouteInfo::new(
-                &format!("{}/assign-routes", base_path),
-                Method::POST,
-                service_name,
+            )
+            .layer(AuditVerbosity::FullBody)
+            .post(
+                "/assign-routes",
+                SysAuthenticationApi::assign_routes,
                 "分配路由",
-            ),
-        ];
-
-        for route in routes {
-            add_route(route).await;
-        }
-
-        let authorization_router = Router::new()
-            .route("/getUserRoutes", get(SysAuthenticationApi::get_user_routes))
-            .route(
-                "/assign-permission",
-                post(SysAuthenticationApi::assign_permission),
             )
-            .route("/assign-routes", post(SysAuthenticationApi::assign_routes));
-
-        Router::new().nest(base_path, authorization_router)
+            .layer(AuditVerbosity::FullBody)
+            .build()
+            .await
     }
 }
@@ -0,0 +1,33 @@
+use axum::Router;
+use server_api::admin::SysBreakGlassApi;
+
+use crate::RouteManifest;
+
+pub struct SysBreakGlassRouter;
+
+impl SysBreakGlassRouter {
+    /// 使用紧急凭证，不需要登录
+    pub async fn init_activation_router() -> Router {
+        RouteManifest::new("/auth", "SysBreakGlassApi")
+            .post("/break-glass", SysBreakGlassApi::activate, "使用紧急凭证")
+            .public()
+            .build()
+            .await
+    }
+
+    pub async fn init_break_glass_router() -> Router {
+        RouteManifest::new("/break-glass", "SysBreakGlassApi")
+            .get(
+                "/session/{id}",
+                SysBreakGlassApi::get_session,
+                "获取紧急访问会话",
+            )
+            .delete(
+                "/session/{id}",
+                SysBreakGlassApi::end_session,
+                "结束紧急访问会话",
+            )
+            .build()
+            .await
+    }
+}
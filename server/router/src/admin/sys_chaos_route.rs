@@ -0,0 +1,18 @@
+use axum::Router;
+use server_api::admin::SysChaosApi;
+
+use crate::RouteManifest;
+
+pub struct SysChaosRouter;
+
+impl SysChaosRouter {
+    pub async fn init_chaos_router() -> Router {
+        RouteManifest::new("/chaos", "SysChaosApi")
+            .get("/faults", SysChaosApi::get_faults, "获取故障注入规则")
+            .post("/faults", SysChaosApi::inject_fault, "注入故障")
+            .delete("/faults", SysChaosApi::clear_faults, "清除全部故障")
+            .delete("/faults/{id}", SysChaosApi::remove_fault, "移除故障")
+            .build()
+            .await
+    }
+}
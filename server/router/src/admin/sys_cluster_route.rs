@@ -0,0 +1,15 @@
+use axum::Router;
+use server_api::admin::SysClusterApi;
+
+use crate::RouteManifest;
+
+pub struct SysClusterRouter;
+
+impl SysClusterRouter {
+    pub async fn init_cluster_router() -> Router {
+        RouteManifest::new("/cluster", "SysClusterApi")
+            .get("/members", SysClusterApi::get_members, "获取集群成员列表")
+            .build()
+            .await
+    }
+}
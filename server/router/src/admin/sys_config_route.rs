@@ -0,0 +1,35 @@
+use axum::Router;
+use server_api::admin::SysConfigApi;
+
+use crate::RouteManifest;
+
+pub struct SysConfigRouter;
+
+impl SysConfigRouter {
+    pub async fn init_config_router() -> Router {
+        RouteManifest::new("/config", "SysConfigApi")
+            .get(
+                "/staged",
+                SysConfigApi::get_staged_config,
+                "获取候选配置状态",
+            )
+            .post("/staged", SysConfigApi::stage_config, "上传候选配置")
+            .post(
+                "/staged/canary",
+                SysConfigApi::apply_canary,
+                "灰度应用候选配置",
+            )
+            .post(
+                "/staged/promote",
+                SysConfigApi::promote_config,
+                "全量生效候选配置",
+            )
+            .post(
+                "/staged/rollback",
+                SysConfigApi::rollback_config,
+                "回滚候选配置",
+            )
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,15 @@
+use axum::Router;
+use server_api::admin::SysConsistencyApi;
+
+use crate::RouteManifest;
+
+pub struct SysConsistencyRouter;
+
+impl SysConsistencyRouter {
+    pub async fn init_consistency_router() -> Router {
+        RouteManifest::new("/consistency", "SysConsistencyApi")
+            .post("/check", SysConsistencyApi::run_check, "执行数据一致性检查")
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,16 @@
+use axum::Router;
+use server_api::admin::SysDbPoolApi;
+
+use crate::RouteManifest;
+
+pub struct SysDbPoolRouter;
+
+impl SysDbPoolRouter {
+    pub async fn init_db_pool_router() -> Router {
+        RouteManifest::new("/db-pool", "SysDbPoolApi")
+            .get("/", SysDbPoolApi::get_pools, "获取数据库连接池列表")
+            .put("/{name}", SysDbPoolApi::resize_pool, "调整数据库连接池容量")
+            .build()
+            .await
+    }
+}
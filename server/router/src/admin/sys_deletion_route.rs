@@ -0,0 +1,24 @@
+use axum::Router;
+use server_api::admin::SysDeletionApi;
+
+use crate::RouteManifest;
+
+pub struct SysDeletionRouter;
+
+impl SysDeletionRouter {
+    pub async fn init_deletion_router() -> Router {
+        RouteManifest::new("/deletion", "SysDeletionApi")
+            .get(
+                "/{target}/{id}/preview",
+                SysDeletionApi::preview_deletion,
+                "预览级联删除影响",
+            )
+            .delete(
+                "/{target}/{id}",
+                SysDeletionApi::delete_with_cascade,
+                "按级联规则删除",
+            )
+            .build()
+            .await
+    }
+}
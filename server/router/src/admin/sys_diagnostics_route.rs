@@ -0,0 +1,15 @@
+use axum::Router;
+use server_api::admin::SysDiagnosticsApi;
+
+use crate::RouteManifest;
+
+pub struct SysDiagnosticsRouter;
+
+impl SysDiagnosticsRouter {
+    pub async fn init_diagnostics_router() -> Router {
+        RouteManifest::new("/diagnostics", "SysDiagnosticsApi")
+            .post("/bundle", SysDiagnosticsApi::generate_bundle, "生成诊断包")
+            .build()
+            .await
+    }
+}
@@ -1,42 +1,21 @@
-use axum::{http::Method, routing::get, Router};
+use axum::Router;
 use server_api::admin::SysEndpointApi;
-use server_global::global::{add_route, RouteInfo};
+
+use crate::RouteManifest;
 
 pub struct SysEndpointRouter;
 
 impl SysEndpointRouter {
     pub async fn init_endpoint_router() -> Router {
-        let base_path = "/api-endpoint";
-        let service_name = "SysEndpointApi";
-
-        let routes = vec![
-            RouteInfo::new(base_path, Method::GET, service_name, "获取接口列表"),
-            RouteInfo::new(
-                &format!("{}/auth-api-endpoint/:roleCode", base_path),
-                Method::GET,
-                service_name,
-                "获取角色API权限",
-            ),
-            RouteInfo::new(
-                &format!("{}/tree", base_path),
-                Method::GET,
-                service_name,
-                "获取接口树",
-            ),
-        ];
-
-        for route in routes {
-            add_route(route).await;
-        }
-
-        let router = Router::new()
-            .route("/", get(SysEndpointApi::get_paginated_endpoints))
-            .route(
+        RouteManifest::new("/api-endpoint", "SysEndpointApi")
+            .get("/", SysEndpointApi::get_paginated_endpoints, "获取接口列表")
+            .get(
                 "/auth-api-endpoint/{roleCode}",
-                get(SysEndpointApi::get_auth_endpoints),
+                SysEndpointApi::get_auth_endpoints,
+                "获取角色API权限",
             )
-            .route("/tree", get(SysEndpointApi::tree_endpoint));
-
-        Router::new().nest(base_path, router)
+            .get("/tree", SysEndpointApi::tree_endpoint, "获取接口树")
+            .build()
+            .await
     }
 }
@@ -0,0 +1,68 @@
+use axum::{extract::DefaultBodyLimit, Router};
+use server_api::admin::SysFileApi;
+use server_config::StorageConfig;
+use server_global::global::get_config;
+
+use crate::RouteManifest;
+
+pub struct SysFileRouter;
+
+impl SysFileRouter {
+    pub async fn init_file_router() -> Router {
+        let max_upload_size = get_config::<StorageConfig>()
+            .await
+            .map(|config| config.max_upload_size)
+            .unwrap_or(100 * 1024 * 1024);
+
+        RouteManifest::new("/file", "SysFileApi")
+            .get("/", SysFileApi::get_paginated_files, "获取文件列表")
+            .post("/", SysFileApi::upload_file, "上传文件")
+            .layer(DefaultBodyLimit::max(max_upload_size))
+            .get("/{id}/download", SysFileApi::download_file, "下载文件")
+            .get("/{id}/url", SysFileApi::get_file_url, "获取文件访问地址")
+            .delete("/{id}", SysFileApi::delete_file, "删除文件")
+            .get(
+                "/quarantine",
+                SysFileApi::get_paginated_quarantined_files,
+                "获取隔离文件复核队列",
+            )
+            .post(
+                "/quarantine/{id}/release",
+                SysFileApi::release_file,
+                "放行隔离文件",
+            )
+            .delete("/quarantine/{id}", SysFileApi::purge_file, "清除隔离文件")
+            .get(
+                "/{id}/shares",
+                SysFileApi::get_share_links,
+                "获取文件分享链接",
+            )
+            .post(
+                "/{id}/shares",
+                SysFileApi::create_share_link,
+                "创建文件分享链接",
+            )
+            .delete(
+                "/shares/{id}",
+                SysFileApi::revoke_share_link,
+                "撤销文件分享链接",
+            )
+            .build()
+            .await
+    }
+
+    /// 文件分享链接路由，凭令牌免登录访问
+    pub async fn init_share_router() -> Router {
+        RouteManifest::new("/share", "SysFileApi")
+            .get("/{token}", SysFileApi::get_shared_file, "获取分享文件信息")
+            .public()
+            .post(
+                "/{token}/download",
+                SysFileApi::download_shared_file,
+                "下载分享文件",
+            )
+            .public()
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,41 @@
+use axum::Router;
+use server_api::admin::SysInstanceApi;
+
+use crate::RouteManifest;
+
+pub struct SysInstanceRouter;
+
+impl SysInstanceRouter {
+    pub async fn init_instance_router() -> Router {
+        RouteManifest::new("/admin", "SysInstanceApi")
+            .get(
+                "/admission",
+                SysInstanceApi::get_admission_status,
+                "获取并发准入控制状态",
+            )
+            .post("/drain", SysInstanceApi::drain, "排空当前实例")
+            .get(
+                "/middleware-stack",
+                SysInstanceApi::get_middleware_stack,
+                "获取路由的中间件栈",
+            )
+            .get(
+                "/openapi.json",
+                SysInstanceApi::get_openapi_document,
+                "获取 OpenAPI 文档",
+            )
+            .build()
+            .await
+    }
+
+    /// 健康检查路由，不需要鉴权
+    pub async fn init_health_router() -> Router {
+        RouteManifest::new("/health", "SysInstanceApi")
+            .get("/live", SysInstanceApi::liveness, "存活检查")
+            .public()
+            .get("/ready", SysInstanceApi::readiness, "就绪检查")
+            .public()
+            .build()
+            .await
+    }
+}
@@ -1,27 +1,33 @@
-use axum::{http::Method, routing::get, Router};
+use axum::Router;
 use server_api::admin::SysLoginLogApi;
-use server_global::global::{add_route, RouteInfo};
+use server_core::web::operation_log::AuditVerbosity;
+use server_global::global::{add_menu, MenuInfo};
+
+use crate::RouteManifest;
 
 pub struct SysLoginLogRouter;
 
 impl SysLoginLogRouter {
     pub async fn init_login_log_router() -> Router {
-        let base_path = "/login-log";
-        let service_name = "SysLoginLogApi";
-
-        let routes = vec![RouteInfo::new(
-            base_path,
-            Method::GET,
-            service_name,
-            "获取登录日志列表",
-        )];
+        let menus = vec![
+            MenuInfo::directory("log", "/log").with_icon("carbon:cloud-logging"),
+            MenuInfo::menu("log_login", "/log/login", "view.log_login")
+                .with_parent("log")
+                .with_icon("carbon:login"),
+        ];
 
-        for route in routes {
-            add_route(route).await;
+        for menu in menus {
+            add_menu(menu).await;
         }
 
-        let router = Router::new().route("/", get(SysLoginLogApi::get_paginated_login_logs));
-
-        Router::new().nest(base_path, router)
+        RouteManifest::new("/login-log", "SysLoginLogApi")
+            .get(
+                "/",
+                SysLoginLogApi::get_paginated_login_logs,
+                "获取登录日志列表",
+            )
+            .layer(AuditVerbosity::Metadata)
+            .build()
+            .await
     }
 }
@@ -0,0 +1,44 @@
+use axum::Router;
+use server_api::admin::SysMaintenanceWindowApi;
+
+use crate::RouteManifest;
+
+pub struct SysMaintenanceWindowRouter;
+
+impl SysMaintenanceWindowRouter {
+    pub async fn init_maintenance_window_router() -> Router {
+        RouteManifest::new("/maintenance-window", "SysMaintenanceWindowApi")
+            .get(
+                "/",
+                SysMaintenanceWindowApi::get_paginated_windows,
+                "获取维护窗口列表",
+            )
+            .post("/", SysMaintenanceWindowApi::create_window, "创建维护窗口")
+            .get(
+                "/{id}",
+                SysMaintenanceWindowApi::get_window,
+                "获取维护窗口详情",
+            )
+            .put("/", SysMaintenanceWindowApi::update_window, "更新维护窗口")
+            .delete(
+                "/{id}",
+                SysMaintenanceWindowApi::delete_window,
+                "删除维护窗口",
+            )
+            .build()
+            .await
+    }
+
+    /// 前端轮询的维护横幅，无需登录，登录页也能展示
+    pub async fn init_banner_router() -> Router {
+        RouteManifest::new("", "SysMaintenanceWindowApi")
+            .get(
+                "/maintenance/banner",
+                SysMaintenanceWindowApi::get_banners,
+                "获取维护横幅",
+            )
+            .public()
+            .build()
+            .await
+    }
+}
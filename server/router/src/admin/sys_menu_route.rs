@@ -1,70 +1,65 @@
-use axum::{
-    http::Method,
-    routing::{delete, get, post, put},
-    Router,
-};
+use axum::Router;
 use server_api::admin::SysMenuApi;
 use server_core::web::operation_log::OperationLogLayer;
-use server_global::global::{add_route, RouteInfo};
+use server_global::global::{add_menu, MenuInfo};
+
+use crate::RouteManifest;
 
 pub struct SysMenuRouter;
 
 impl SysMenuRouter {
     pub async fn init_menu_router() -> Router {
-        let router = Router::new().route(
-            "/getConstantRoutes",
-            get(SysMenuApi::get_constant_routes).layer(OperationLogLayer::new(true)),
-        );
-        Router::new().nest("/route", router)
+        RouteManifest::new("/route", "SysMenuApi")
+            .get(
+                "/getConstantRoutes",
+                SysMenuApi::get_constant_routes,
+                "获取常量路由",
+            )
+            .layer(OperationLogLayer::new(true))
+            .public()
+            .build()
+            .await
     }
 
     pub async fn init_protected_menu_router() -> Router {
-        let base_path = "/route";
-        let service_name = "SysMenuApi";
-
-        let routes = vec![
-            RouteInfo::new(
-                &format!("{}/tree", base_path),
-                Method::GET,
-                service_name,
-                "获取菜单树",
-            ),
-            RouteInfo::new(base_path, Method::GET, service_name, "获取菜单列表"),
-            RouteInfo::new(base_path, Method::POST, service_name, "创建菜单"),
-            RouteInfo::new(
-                &format!("{}/:id", base_path),
-                Method::GET,
-                service_name,
-                "获取菜单详情",
-            ),
-            RouteInfo::new(base_path, Method::PUT, service_name, "更新菜单"),
-            RouteInfo::new(
-                &format!("{}/:id", base_path),
-                Method::DELETE,
-                service_name,
-                "删除菜单",
-            ),
-            RouteInfo::new(
-                &format!("{}/auth-route/:roleId", base_path),
-                Method::GET,
-                service_name,
-                "获取角色菜单",
-            ),
+        let menus = vec![
+            MenuInfo::directory("manage", "/manage")
+                .with_icon("carbon:cloud-service-management")
+                .with_sequence(4),
+            MenuInfo::menu("manage_menu", "/manage/menu", "view.manage_menu")
+                .with_parent("manage")
+                .with_icon("material-symbols:route")
+                .with_sequence(2),
         ];
 
-        for route in routes {
-            add_route(route).await;
+        for menu in menus {
+            add_menu(menu).await;
         }
 
-        let router = Router::new()
-            .route("/tree", get(SysMenuApi::tree_menu))
-            .route("/", get(SysMenuApi::get_menu_list))
-            .route("/", post(SysMenuApi::create_menu))
-            .route("/{id}", get(SysMenuApi::get_menu))
-            .route("/", put(SysMenuApi::update_menu))
-            .route("/{id}", delete(SysMenuApi::delete_menu))
-            .route("/auth-route/{roleId}", get(SysMenuApi::get_auth_routes));
-
-        Router::new().nest(base_path, router)
+        RouteManifest::new("/route", "SysMenuApi")
+            .get("/tree", SysMenuApi::tree_menu, "获取菜单树")
+            .get("/", SysMenuApi::get_menu_list, "获取菜单列表")
+            .post("/", SysMenuApi::create_menu, "创建菜单")
+            .get("/{id}", SysMenuApi::get_menu, "获取菜单详情")
+            .put("/", SysMenuApi::update_menu, "更新菜单")
+            .delete("/{id}", SysMenuApi::delete_menu, "删除菜单")
+            .get(
+                "/auth-route/{roleId}",
+                SysMenuApi::get_auth_routes,
+                "获取角色菜单",
+            )
+            .get(
+                "/{id}/children",
+                SysMenuApi::get_menu_children,
+                "获取下级菜单",
+            )
+            .get(
+                "/{id}/subtree",
+                SysMenuApi::get_menu_subtree,
+                "获取菜单子树",
+            )
+            .put("/{id}/parent", SysMenuApi::move_menu, "移动菜单")
+            .build()
+            .await
     }
 }
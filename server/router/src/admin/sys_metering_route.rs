@@ -0,0 +1,20 @@
+use axum::Router;
+use server_api::admin::SysMeteringApi;
+
+use crate::RouteManifest;
+
+pub struct SysMeteringRouter;
+
+impl SysMeteringRouter {
+    pub async fn init_metering_router() -> Router {
+        RouteManifest::new("/metering", "SysMeteringApi")
+            .get(
+                "/",
+                SysMeteringApi::get_paginated_metering,
+                "获取计量记录列表",
+            )
+            .get("/export", SysMeteringApi::export_metering, "导出计量记录")
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,19 @@
+use axum::Router;
+use server_api::admin::SysMigrationApi;
+
+use crate::RouteManifest;
+
+pub struct SysMigrationRouter;
+
+impl SysMigrationRouter {
+    pub async fn init_migration_router() -> Router {
+        RouteManifest::new("/migrations", "SysMigrationApi")
+            .get(
+                "/plan",
+                SysMigrationApi::get_migration_plan,
+                "预览待执行的数据库迁移",
+            )
+            .build()
+            .await
+    }
+}
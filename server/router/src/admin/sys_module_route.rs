@@ -0,0 +1,16 @@
+use axum::Router;
+use server_api::admin::SysModuleApi;
+
+use crate::RouteManifest;
+
+pub struct SysModuleRouter;
+
+impl SysModuleRouter {
+    pub async fn init_module_router() -> Router {
+        RouteManifest::new("/module", "SysModuleApi")
+            .get("/", SysModuleApi::get_modules, "获取功能模块列表")
+            .put("/{code}", SysModuleApi::update_module, "启用或停用功能模块")
+            .build()
+            .await
+    }
+}
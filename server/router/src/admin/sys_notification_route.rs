@@ -0,0 +1,37 @@
+use axum::Router;
+use server_api::admin::SysNotificationApi;
+
+use crate::RouteManifest;
+
+pub struct SysNotificationRouter;
+
+impl SysNotificationRouter {
+    /// 当前用户的站内信和通知偏好，只需登录，不做接口权限校验
+    pub async fn init_notification_router() -> Router {
+        RouteManifest::new("/notification", "SysNotificationApi")
+            .get(
+                "/",
+                SysNotificationApi::get_paginated_notifications,
+                "获取站内信列表",
+            )
+            .put("/{id}/read", SysNotificationApi::mark_read, "标记已读")
+            .put(
+                "/read-all",
+                SysNotificationApi::mark_all_read,
+                "全部标记已读",
+            )
+            .get(
+                "/preference",
+                SysNotificationApi::get_preference,
+                "获取通知偏好",
+            )
+            .put(
+                "/preference",
+                SysNotificationApi::update_preference,
+                "更新通知偏好",
+            )
+            .all_authenticated()
+            .build()
+            .await
+    }
+}
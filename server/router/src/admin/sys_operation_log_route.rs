@@ -1,28 +1,49 @@
-use axum::{http::Method, routing::get, Router};
+use axum::Router;
 use server_api::admin::SysOperationLogApi;
-use server_global::global::{add_route, RouteInfo};
+use server_core::web::operation_log::AuditVerbosity;
+use server_global::global::{add_menu, MenuInfo};
+
+use crate::RouteManifest;
 
 pub struct SysOperationLogRouter;
 
 impl SysOperationLogRouter {
     pub async fn init_operation_log_router() -> Router {
-        let base_path = "/operation-log";
-        let service_name = "SysOperationLogApi";
-
-        let routes = vec![RouteInfo::new(
-            base_path,
-            Method::GET,
-            service_name,
-            "获取操作日志列表",
-        )];
+        let menus = vec![
+            MenuInfo::directory("log", "/log").with_icon("carbon:cloud-logging"),
+            MenuInfo::menu("log_operation", "/log/operation", "view.log_operation")
+                .with_parent("log")
+                .with_icon("carbon:operations-record"),
+        ];
 
-        for route in routes {
-            add_route(route).await;
+        for menu in menus {
+            add_menu(menu).await;
         }
 
-        let router =
-            Router::new().route("/", get(SysOperationLogApi::get_paginated_operation_logs));
-
-        Router::new().nest(base_path, router)
+        RouteManifest::new("/operation-log", "SysOperationLogApi")
+            .get(
+                "/",
+                SysOperationLogApi::get_paginated_operation_logs,
+                "获取操作日志列表",
+            )
+            .get(
+                "/history",
+                SysOperationLogApi::get_operation_log_history,
+                "按时间范围查询操作日志",
+            )
+            .get(
+                "/archives",
+                SysOperationLogApi::get_operation_log_archives,
+                "获取审计日志归档列表",
+            )
+            .get(
+                "/chain/verify",
+                SysOperationLogApi::verify_audit_chain,
+                "校验审计日志哈希链",
+            )
+            // 查询结果本身就是操作日志，只记录查询条件
+            .layer(AuditVerbosity::Metadata)
+            .build()
+            .await
     }
 }
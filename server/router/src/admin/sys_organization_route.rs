@@ -1,27 +1,40 @@
-use axum::{http::Method, routing::get, Router};
+use axum::Router;
 use server_api::admin::SysOrganizationApi;
-use server_global::global::{add_route, RouteInfo};
+
+use crate::RouteManifest;
 
 pub struct SysOrganizationRouter;
 
 impl SysOrganizationRouter {
     pub async fn init_organization_router() -> Router {
-        let base_path = "/org";
-        let service_name = "SysOrganizationApi";
-
-        let routes = vec![RouteInfo::new(
-            base_path,
-            Method::GET,
-            service_name,
-            "获取组织列表",
-        )];
-
-        for route in routes {
-            add_route(route).await;
-        }
-
-        let router = Router::new().route("/", get(SysOrganizationApi::get_paginated_organizations));
+        RouteManifest::new("/org", "SysOrganizationApi")
+            .get(
+                "/",
+                SysOrganizationApi::get_paginated_organizations,
+                "获取组织列表",
+            )
+            .build()
+            .await
+    }
 
-        Router::new().nest(base_path, router)
+    pub async fn init_protected_organization_router() -> Router {
+        RouteManifest::new("/org", "SysOrganizationApi")
+            .get(
+                "/{id}/children",
+                SysOrganizationApi::get_organization_children,
+                "获取下级组织",
+            )
+            .get(
+                "/{id}/subtree",
+                SysOrganizationApi::get_organization_subtree,
+                "获取组织子树",
+            )
+            .put(
+                "/{id}/parent",
+                SysOrganizationApi::move_organization,
+                "移动组织",
+            )
+            .build()
+            .await
     }
 }
@@ -0,0 +1,28 @@
+use axum::Router;
+use server_api::admin::SysPasskeyApi;
+
+use crate::RouteManifest;
+
+pub struct SysPasskeyRouter;
+
+impl SysPasskeyRouter {
+    /// 当前用户管理自己的通行密钥，只需登录，不做接口权限校验
+    pub async fn init_passkey_router() -> Router {
+        RouteManifest::new("/auth/passkeys", "SysPasskeyApi")
+            .get("/", SysPasskeyApi::list_passkeys, "获取通行密钥列表")
+            .post(
+                "/register/start",
+                SysPasskeyApi::start_registration,
+                "开始注册通行密钥",
+            )
+            .post(
+                "/register/finish",
+                SysPasskeyApi::finish_registration,
+                "完成注册通行密钥",
+            )
+            .delete("/{id}", SysPasskeyApi::delete_passkey, "删除通行密钥")
+            .all_authenticated()
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,27 @@
+use axum::Router;
+use server_api::admin::SysPersonalTokenApi;
+
+use crate::RouteManifest;
+
+pub struct SysPersonalTokenRouter;
+
+impl SysPersonalTokenRouter {
+    /// 当前用户管理自己的个人访问令牌，只需登录，不做接口权限校验
+    pub async fn init_personal_token_router() -> Router {
+        RouteManifest::new("/auth/personal-tokens", "SysPersonalTokenApi")
+            .get(
+                "/",
+                SysPersonalTokenApi::list_tokens,
+                "获取个人访问令牌列表",
+            )
+            .post("/", SysPersonalTokenApi::create_token, "创建个人访问令牌")
+            .delete(
+                "/{id}",
+                SysPersonalTokenApi::revoke_token,
+                "吊销个人访问令牌",
+            )
+            .all_authenticated()
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,39 @@
+use axum::Router;
+use server_api::admin::SysPolicyApi;
+
+use crate::RouteManifest;
+
+pub struct SysPolicyRouter;
+
+impl SysPolicyRouter {
+    pub async fn init_policy_router() -> Router {
+        RouteManifest::new("/policy", "SysPolicyApi")
+            .get(
+                "/",
+                SysPolicyApi::get_paginated_documents,
+                "获取政策文档列表",
+            )
+            .post("/", SysPolicyApi::publish_document, "发布政策文档")
+            .get(
+                "/{id}/acceptances",
+                SysPolicyApi::get_paginated_acceptances,
+                "获取政策文档确认记录",
+            )
+            .build()
+            .await
+    }
+
+    /// 当前用户查询和确认待确认的政策文档，只需登录，不做接口权限校验
+    pub async fn init_acceptance_router() -> Router {
+        RouteManifest::new("/policy", "SysPolicyApi")
+            .get(
+                "/pending",
+                SysPolicyApi::get_pending_documents,
+                "获取待确认的政策文档",
+            )
+            .post("/accept", SysPolicyApi::accept_documents, "确认政策文档")
+            .all_authenticated()
+            .build()
+            .await
+    }
+}
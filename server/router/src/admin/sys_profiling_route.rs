@@ -0,0 +1,24 @@
+use axum::Router;
+use server_api::admin::SysProfilingApi;
+
+use crate::RouteManifest;
+
+pub struct SysProfilingRouter;
+
+impl SysProfilingRouter {
+    pub async fn init_profiling_router() -> Router {
+        RouteManifest::new("/profiling", "SysProfilingApi")
+            .get(
+                "/cpu",
+                SysProfilingApi::capture_cpu_profile,
+                "采集 CPU 剖析",
+            )
+            .get(
+                "/runtime",
+                SysProfilingApi::get_runtime_stats,
+                "获取内存及运行时统计",
+            )
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,20 @@
+use axum::Router;
+use server_api::admin::SysRecorderApi;
+
+use crate::RouteManifest;
+
+pub struct SysRecorderRouter;
+
+impl SysRecorderRouter {
+    pub async fn init_recorder_router() -> Router {
+        RouteManifest::new("/recorder", "SysRecorderApi")
+            .get("/", SysRecorderApi::get_status, "获取请求录制状态")
+            .post("/start", SysRecorderApi::start, "开启请求录制")
+            .post("/stop", SysRecorderApi::stop, "停止请求录制")
+            .get("/traces", SysRecorderApi::get_traces, "获取录制的请求")
+            .delete("/traces", SysRecorderApi::clear_traces, "清空录制的请求")
+            .post("/replay", SysRecorderApi::replay, "回放录制的请求")
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,19 @@
+use axum::Router;
+use server_api::admin::SysReferenceApi;
+
+use crate::RouteManifest;
+
+pub struct SysReferenceRouter;
+
+impl SysReferenceRouter {
+    pub async fn init_reference_router() -> Router {
+        RouteManifest::new("/references", "SysReferenceApi")
+            .get(
+                "/{entity}/{id}",
+                SysReferenceApi::get_references,
+                "查询数据的引用情况",
+            )
+            .build()
+            .await
+    }
+}
@@ -0,0 +1,45 @@
+use axum::Router;
+use server_api::admin::SysRegionApi;
+
+use crate::RouteManifest;
+
+pub struct SysRegionRouter;
+
+impl SysRegionRouter {
+    pub async fn init_region_router() -> Router {
+        RouteManifest::new("/region", "SysRegionApi")
+            .get(
+                "/versions",
+                SysRegionApi::get_versions,
+                "获取行政区划版本列表",
+            )
+            .post("/versions", SysRegionApi::import_regions, "导入行政区划")
+            .put(
+                "/versions/{version}/activate",
+                SysRegionApi::activate_version,
+                "启用行政区划版本",
+            )
+            .delete(
+                "/versions/{version}",
+                SysRegionApi::delete_version,
+                "删除行政区划版本",
+            )
+            .build()
+            .await
+    }
+
+    /// 地址选择器读取行政区划，只需登录，不做接口权限校验
+    pub async fn init_region_query_router() -> Router {
+        RouteManifest::new("/region", "SysRegionApi")
+            .get("/tree", SysRegionApi::get_region_tree, "获取行政区划树")
+            .get(
+                "/{code}/children",
+                SysRegionApi::get_children,
+                "获取下级行政区划",
+            )
+            .get("/{code}/path", SysRegionApi::get_path, "获取行政区划路径")
+            .all_authenticated()
+            .build()
+            .await
+    }
+}
@@ -1,20 +1,36 @@
-use axum::{routing::get, Router};
+use axum::Router;
 use server_api::admin::SysSandboxApi;
 
+use crate::RouteManifest;
+
+/// 沙箱接口只校验 API Key，不需要登录
 pub struct SysSandboxRouter;
 
 impl SysSandboxRouter {
     const BASE_PATH: &str = "/sandbox";
-    pub async fn init_simple_sandbox_router() -> Router {
-        let router =
-            Router::new().route("/simple-api-key", get(SysSandboxApi::test_simple_api_key));
+    const SERVICE_NAME: &str = "SysSandboxApi";
 
-        Router::new().nest(Self::BASE_PATH, router)
+    pub async fn init_simple_sandbox_router() -> Router {
+        RouteManifest::new(Self::BASE_PATH, Self::SERVICE_NAME)
+            .get(
+                "/simple-api-key",
+                SysSandboxApi::test_simple_api_key,
+                "测试简单 API Key",
+            )
+            .public()
+            .build()
+            .await
     }
 
     pub async fn init_complex_sandbox_router() -> Router {
-        let router =
-            Router::new().route("/complex-api-key", get(SysSandboxApi::test_complex_api_key));
-        Router::new().nest(Self::BASE_PATH, router)
+        RouteManifest::new(Self::BASE_PATH, Self::SERVICE_NAME)
+            .get(
+                "/complex-api-key",
+                SysSandboxApi::test_complex_api_key,
+                "测试签名 API Key",
+            )
+            .public()
+            .build()
+            .await
     }
 }
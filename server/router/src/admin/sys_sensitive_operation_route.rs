@@ -0,0 +1,29 @@
+use axum::Router;
+use server_api::admin::SysSensitiveOperationApi;
+
+use crate::RouteManifest;
+
+pub struct SysSensitiveOperationRouter;
+
+impl SysSensitiveOperationRouter {
+    pub async fn init_sensitive_operation_router() -> Router {
+        RouteManifest::new("/sensitive-operation", "SysSensitiveOperationApi")
+            .post(
+                "/approval",
+                SysSensitiveOperationApi::request_approval,
+                "申请紧急授权",
+            )
+            .get(
+                "/approval/{id}",
+                SysSensitiveOperationApi::get_approval,
+                "获取紧急授权审批",
+            )
+            .post(
+                "/approval/{id}/approve",
+                SysSensitiveOperationApi::approve,
+                "批准紧急授权",
+            )
+            .build()
+            .await
+    }
+}
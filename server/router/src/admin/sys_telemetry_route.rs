@@ -0,0 +1,19 @@
+use axum::Router;
+use server_api::admin::SysTelemetryApi;
+
+use crate::RouteManifest;
+
+pub struct SysTelemetryRouter;
+
+impl SysTelemetryRouter {
+    pub async fn init_telemetry_router() -> Router {
+        RouteManifest::new("/telemetry", "SysTelemetryApi")
+            .get(
+                "/preview",
+                SysTelemetryApi::preview_report,
+                "预览匿名遥测报告",
+            )
+            .build()
+            .await
+    }
+}
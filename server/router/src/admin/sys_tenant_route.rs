@@ -0,0 +1,36 @@
+use axum::{extract::DefaultBodyLimit, Router};
+use server_api::admin::SysTenantApi;
+use server_config::TenantConfig;
+use server_global::global::get_config;
+
+use crate::RouteManifest;
+
+pub struct SysTenantRouter;
+
+impl SysTenantRouter {
+    pub async fn init_tenant_router() -> Router {
+        let max_archive_size = get_config::<TenantConfig>()
+            .await
+            .map(|config| config.max_archive_size)
+            .unwrap_or_else(|| TenantConfig::default().max_archive_size);
+
+        RouteManifest::new("/tenant", "SysTenantApi")
+            .get("/", SysTenantApi::get_paginated_tenants, "获取租户列表")
+            .post("/", SysTenantApi::provision_tenant, "开通租户")
+            .get("/{id}", SysTenantApi::get_tenant, "获取租户详情")
+            .get("/{id}/export", SysTenantApi::export_tenant, "导出租户")
+            .post("/import", SysTenantApi::import_tenant, "导入租户")
+            .layer(DefaultBodyLimit::max(max_archive_size))
+            .put("/{id}/suspend", SysTenantApi::suspend_tenant, "停用租户")
+            .put("/{id}/resume", SysTenantApi::resume_tenant, "恢复租户")
+            .post("/{id}/purge", SysTenantApi::request_purge, "申请删除租户")
+            .post(
+                "/{id}/purge/confirm",
+                SysTenantApi::confirm_purge,
+                "确认删除租户",
+            )
+            .delete("/{id}/purge", SysTenantApi::cancel_purge, "撤销删除租户")
+            .build()
+            .await
+    }
+}
@@ -1,68 +1,53 @@
-use axum::{
-    http::Method,
-    routing::{delete, get, post, put},
-    Router,
-};
+use axum::Router;
 use server_api::admin::SysUserApi;
-use server_global::global::{add_route, RouteInfo};
+use server_global::global::{add_menu, MenuInfo};
+
+use crate::RouteManifest;
 
 pub struct SysUserRouter;
 
 impl SysUserRouter {
     pub async fn init_user_router() -> Router {
-        let base_path = "/user";
-        let service_name = "SysUserApi";
-
-        let routes = vec![
-            RouteInfo::new(
-                &format!("{}/users", base_path),
-                Method::GET,
-                service_name,
-                "获取所有用户",
-            ),
-            RouteInfo::new(base_path, Method::GET, service_name, "获取用户列表"),
-            RouteInfo::new(base_path, Method::POST, service_name, "创建用户"),
-            RouteInfo::new(
-                &format!("{}/:id", base_path),
-                Method::GET,
-                service_name,
-                "获取用户详情",
-            ),
-            RouteInfo::new(base_path, Method::PUT, service_name, "更新用户"),
-            RouteInfo::new(
-                &format!("{}/:id", base_path),
-                Method::DELETE,
-                service_name,
-                "删除用户",
-            ),
-            RouteInfo::new(
-                &format!("{}/add_policies", base_path),
-                Method::GET,
-                service_name,
-                "添加用户策略",
-            ),
-            RouteInfo::new(
-                &format!("{}/remove_policies", base_path),
-                Method::GET,
-                service_name,
-                "删除用户策略",
-            ),
+        let menus = vec![
+            MenuInfo::directory("manage", "/manage")
+                .with_icon("carbon:cloud-service-management")
+                .with_sequence(4),
+            MenuInfo::menu("manage_user", "/manage/user", "view.manage_user")
+                .with_parent("manage")
+                .with_icon("ic:round-manage-accounts"),
+            MenuInfo::menu(
+                "manage_user-detail",
+                "/manage/user-detail/:id",
+                "view.manage_user-detail",
+            )
+            .with_parent("manage")
+            .with_sequence(3)
+            .hidden("manage_user"),
         ];
 
-        for route in routes {
-            add_route(route).await;
+        for menu in menus {
+            add_menu(menu).await;
         }
 
-        let router = Router::new()
-            .route("/users", get(SysUserApi::get_all_users))
-            .route("/", get(SysUserApi::get_paginated_users))
-            .route("/", post(SysUserApi::create_user))
-            .route("/{id}", get(SysUserApi::get_user))
-            .route("/", put(SysUserApi::update_user))
-            .route("/{id}", delete(SysUserApi::delete_user))
-            .route("/add_policies", get(SysUserApi::add_policies))
-            .route("/remove_policies", get(SysUserApi::remove_policies));
-
-        Router::new().nest(base_path, router)
+        RouteManifest::new("/user", "SysUserApi")
+            .get("/users", SysUserApi::get_all_users, "获取所有用户")
+            .get("/", SysUserApi::get_paginated_users, "获取用户列表")
+            .post("/", SysUserApi::create_user, "创建用户")
+            .get("/{id}", SysUserApi::get_user, "获取用户详情")
+            .get(
+                "/{id}/permissions",
+                SysUserApi::get_effective_permissions,
+                "查看用户有效权限",
+            )
+            .put("/", SysUserApi::update_user, "更新用户")
+            .delete("/{id}", SysUserApi::delete_user, "删除用户")
+            .get("/add_policies", SysUserApi::add_policies, "添加用户策略")
+            .get(
+                "/remove_policies",
+                SysUserApi::remove_policies,
+                "删除用户策略",
+            )
+            .build()
+            .await
     }
 }
@@ -1 +1,4 @@
 pub mod admin;
+mod manifest;
+
+pub use manifest::RouteManifest;
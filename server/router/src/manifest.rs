@@ -0,0 +1,214 @@
+use std::convert::Infallible;
+
+use axum::{
+    extract::Request,
+    handler::Handler,
+    http::Method,
+    response::IntoResponse,
+    routing::{on, MethodFilter, MethodRouter, Route},
+    Router,
+};
+use server_global::global::{add_route, RouteGuard, RouteInfo};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// 模块的路由清单
+///
+/// 每个接口只声明一次：路径、处理函数、说明、权限声明和标签。`build` 时把接口登记到路由收集器，
+/// 接口权限同步、启动时的权限检查、中间件栈诊断和 OpenAPI 文档都读取收集器，
+/// 同时生成挂载在 `base_path` 下的路由。路径使用 axum 的 `{param}` 语法，登记时转换为 `:param`
+///
+/// # 示例
+/// ```rust,ignore
+/// RouteManifest::new("/user", "SysUserApi")
+///     .get("/", SysUserApi::get_paginated_users, "获取用户列表")
+///     .delete("/{id}", SysUserApi::delete_user, "删除用户")
+///     .build()
+///     .await
+/// ```
+pub struct RouteManifest {
+    base_path: String,
+    service_name: String,
+    tags: Vec<String>,
+    routes: Vec<ManifestRoute>,
+}
+
+struct ManifestRoute {
+    path: String,
+    method: Method,
+    handler: MethodRouter,
+    summary: String,
+    guard: RouteGuard,
+}
+
+impl RouteManifest {
+    /// 标签默认为服务名
+    pub fn new(base_path: &str, service_name: &str) -> Self {
+        Self {
+            base_path: base_path.trim_end_matches('/').to_string(),
+            service_name: service_name.to_string(),
+            tags: vec![service_name.to_string()],
+            routes: Vec::new(),
+        }
+    }
+
+    /// 替换清单中全部接口的标签
+    pub fn with_tags(mut self, tags: &[&str]) -> Self {
+        self.tags = tags.iter().map(|tag| tag.to_string()).collect();
+        self
+    }
+
+    /// 声明接口，默认需要接口权限
+    pub fn route<H, T>(mut self, method: Method, path: &str, handler: H, summary: &str) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        let filter = MethodFilter::try_from(method.clone())
+            .unwrap_or_else(|_| panic!("unsupported method {} for route {}", method, path));
+        self.routes.push(ManifestRoute {
+            path: path.to_string(),
+            method,
+            handler: on(filter, handler),
+            summary: summary.to_string(),
+            guard: RouteGuard::Permission,
+        });
+        self
+    }
+
+    pub fn get<H, T>(self, path: &str, handler: H, summary: &str) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        self.route(Method::GET, path, handler, summary)
+    }
+
+    pub fn post<H, T>(self, path: &str, handler: H, summary: &str) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        self.route(Method::POST, path, handler, summary)
+    }
+
+    pub fn put<H, T>(self, path: &str, handler: H, summary: &str) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        self.route(Method::PUT, path, handler, summary)
+    }
+
+    pub fn delete<H, T>(self, path: &str, handler: H, summary: &str) -> Self
+    where
+        H: Handler<T, ()>,
+        T: 'static,
+    {
+        self.route(Method::DELETE, path, handler, summary)
+    }
+
+    /// 把最近声明的接口标记为只需登录
+    pub fn authenticated(self) -> Self {
+        self.with_last_guard(RouteGuard::Authenticated)
+    }
+
+    /// 把最近声明的接口标记为公开
+    pub fn public(self) -> Self {
+        self.with_last_guard(RouteGuard::Public)
+    }
+
+    /// 把清单中全部接口标记为只需登录
+    pub fn all_authenticated(mut self) -> Self {
+        for route in &mut self.routes {
+            route.guard = RouteGuard::Authenticated;
+        }
+        self
+    }
+
+    /// 为最近声明的接口添加中间件，如请求体大小限制、操作日志详细程度
+    pub fn layer<L>(mut self, layer: L) -> Self
+    where
+        L: Layer<Route> + Clone + Send + Sync + 'static,
+        L::Service: Service<Request> + Clone + Send + Sync + 'static,
+        <L::Service as Service<Request>>::Response: IntoResponse + 'static,
+        <L::Service as Service<Request>>::Error: Into<Infallible> + 'static,
+        <L::Service as Service<Request>>::Future: Send + 'static,
+    {
+        if let Some(mut route) = self.routes.pop() {
+            route.handler = route.handler.layer(layer);
+            self.routes.push(route);
+        }
+        self
+    }
+
+    fn with_last_guard(mut self, guard: RouteGuard) -> Self {
+        if let Some(route) = self.routes.last_mut() {
+            route.guard = guard;
+        }
+        self
+    }
+
+    /// 登记清单中的接口并生成路由
+    pub async fn build(self) -> Router {
+        let mut router = Router::new();
+        for route in self.routes {
+            let mut info = RouteInfo::new(
+                &declared_path(&self.base_path, &route.path),
+                route.method,
+                &self.service_name,
+                &route.summary,
+            )
+            .with_tags(&self.tags);
+            info.guard = route.guard;
+            add_route(info).await;
+
+            // 同一路径的不同方法由 axum 合并
+            router = router.route(&route.path, route.handler);
+        }
+
+        if self.base_path.is_empty() {
+            router
+        } else {
+            Router::new().nest(&self.base_path, router)
+        }
+    }
+}
+
+/// 登记到路由收集器的完整路径：`/` 对应 `base_path` 本身，`{param}` 转换为 `:param`
+fn declared_path(base_path: &str, path: &str) -> String {
+    let path = if path == "/" {
+        base_path.to_string()
+    } else {
+        format!("{}{}", base_path, path)
+    };
+    path.split('/')
+        .map(|segment| match segment.strip_prefix('{') {
+            Some(param) => format!(":{}", param.trim_end_matches('}')),
+            None => segment.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_declared_path() {
+        assert_eq!(declared_path("/user", "/"), "/user");
+        assert_eq!(
+            declared_path("/user", "/{id}/permissions"),
+            "/user/:id/permissions"
+        );
+        assert_eq!(
+            declared_path("/route", "/auth-route/{roleId}"),
+            "/route/auth-route/:roleId"
+        );
+        assert_eq!(
+            declared_path("", "/maintenance/banner"),
+            "/maintenance/banner"
+        );
+    }
+}
@@ -1,5 +1,30 @@
 pub mod sys_access_key_error;
+pub mod sys_access_review_error;
+pub mod sys_alert_rule_error;
+pub mod sys_break_glass_error;
+#[cfg(feature = "chaos")]
+pub mod sys_chaos_error;
+pub mod sys_config_error;
+pub mod sys_db_pool_error;
+pub mod sys_deletion_error;
 pub mod sys_domain_error;
+pub mod sys_file_error;
+pub mod sys_instance_error;
+pub mod sys_maintenance_window_error;
 pub mod sys_menu_error;
+pub mod sys_migration_error;
+pub mod sys_module_error;
+pub mod sys_notification_error;
+pub mod sys_passkey_error;
+pub mod sys_personal_token_error;
+pub mod sys_policy_error;
+#[cfg(feature = "profiling")]
+pub mod sys_profiling_error;
+pub mod sys_recorder_error;
+pub mod sys_reference_error;
+pub mod sys_region_error;
 pub mod sys_role_error;
+pub mod sys_sensitive_operation_error;
+pub mod sys_tenant_error;
+pub mod sys_tree_error;
 pub mod sys_user_error;
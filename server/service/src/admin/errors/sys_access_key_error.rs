@@ -1,4 +1,4 @@
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -17,13 +17,16 @@ impl ApiError for AccessKeyError {
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            AccessKeyError::AccessKeyNotFound => ErrorCategory::NotFound,
+        }
+    }
 }
 
 impl From<AccessKeyError> for AppError {
     fn from(err: AccessKeyError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
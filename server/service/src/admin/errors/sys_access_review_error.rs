@@ -0,0 +1,49 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AccessReviewError {
+    #[error("Access review not found")]
+    ReviewNotFound,
+    #[error("Access review item not found")]
+    ItemNotFound,
+    #[error("Access review is closed")]
+    ReviewClosed,
+    #[error("You are not the reviewer of this item")]
+    NotReviewer,
+    #[error("Decision must be confirmed or revoked")]
+    InvalidDecision,
+}
+
+impl ApiError for AccessReviewError {
+    fn code(&self) -> u16 {
+        match self {
+            AccessReviewError::ReviewNotFound => 9901,
+            AccessReviewError::ItemNotFound => 9902,
+            AccessReviewError::ReviewClosed => 9903,
+            AccessReviewError::NotReviewer => 9904,
+            AccessReviewError::InvalidDecision => 9905,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            AccessReviewError::ReviewNotFound | AccessReviewError::ItemNotFound => {
+                ErrorCategory::NotFound
+            },
+            AccessReviewError::ReviewClosed => ErrorCategory::Conflict,
+            AccessReviewError::NotReviewer => ErrorCategory::Forbidden,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<AccessReviewError> for AppError {
+    fn from(err: AccessReviewError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
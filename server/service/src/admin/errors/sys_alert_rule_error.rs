@@ -0,0 +1,43 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum AlertRuleError {
+    #[error("Alert rule not found")]
+    RuleNotFound,
+    #[error("Alert rule with this name already exists")]
+    DuplicateName,
+    #[error("Webhook channel requires an http(s) target url")]
+    InvalidWebhookTarget,
+    #[error("Email channel requires at least one recipient")]
+    MissingRecipient,
+}
+
+impl ApiError for AlertRuleError {
+    fn code(&self) -> u16 {
+        match self {
+            AlertRuleError::RuleNotFound => 9501,
+            AlertRuleError::DuplicateName => 9502,
+            AlertRuleError::InvalidWebhookTarget => 9503,
+            AlertRuleError::MissingRecipient => 9504,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            AlertRuleError::RuleNotFound => ErrorCategory::NotFound,
+            AlertRuleError::DuplicateName => ErrorCategory::Conflict,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<AlertRuleError> for AppError {
+    fn from(err: AlertRuleError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,44 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum BreakGlassError {
+    #[error("Break-glass access is not enabled")]
+    NotEnabled,
+    #[error("Invalid break-glass credential")]
+    InvalidCredential,
+    #[error("Break-glass credential has already been used and must be rotated")]
+    CredentialUsed,
+    #[error("Break-glass session not found or expired")]
+    SessionNotFound,
+}
+
+impl ApiError for BreakGlassError {
+    fn code(&self) -> u16 {
+        match self {
+            BreakGlassError::NotEnabled => 10407,
+            BreakGlassError::InvalidCredential => 10408,
+            BreakGlassError::CredentialUsed => 10409,
+            BreakGlassError::SessionNotFound => 10410,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            BreakGlassError::NotEnabled => ErrorCategory::Forbidden,
+            BreakGlassError::InvalidCredential => ErrorCategory::Unauthorized,
+            BreakGlassError::CredentialUsed => ErrorCategory::Conflict,
+            BreakGlassError::SessionNotFound => ErrorCategory::NotFound,
+        }
+    }
+}
+
+impl From<BreakGlassError> for AppError {
+    fn from(err: BreakGlassError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,39 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ChaosError {
+    #[error("Injected error status must be between 400 and 599")]
+    InvalidStatus,
+    #[error("Route prefix only applies to http faults")]
+    RoutePrefixNotSupported,
+    #[error("Fault rule not found")]
+    FaultNotFound,
+}
+
+impl ApiError for ChaosError {
+    fn code(&self) -> u16 {
+        match self {
+            ChaosError::InvalidStatus => 9401,
+            ChaosError::RoutePrefixNotSupported => 9402,
+            ChaosError::FaultNotFound => 9403,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            ChaosError::FaultNotFound => ErrorCategory::NotFound,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<ChaosError> for AppError {
+    fn from(err: ChaosError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,50 @@
+use server_config::ConfigError;
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ConfigStagingError {
+    #[error("Failed to parse candidate config: {0}")]
+    ParseFailed(String),
+    #[error("Candidate config is invalid: {0}")]
+    ValidationFailed(String),
+    #[error("{0}")]
+    StagingFailed(String),
+}
+
+impl ApiError for ConfigStagingError {
+    fn code(&self) -> u16 {
+        match self {
+            ConfigStagingError::ParseFailed(_) => 7001,
+            ConfigStagingError::ValidationFailed(_) => 7002,
+            ConfigStagingError::StagingFailed(_) => 7003,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            ConfigStagingError::StagingFailed(_) => ErrorCategory::Internal,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<ConfigError> for ConfigStagingError {
+    fn from(err: ConfigError) -> Self {
+        match err {
+            ConfigError::ValidationError(msg) => ConfigStagingError::ValidationFailed(msg),
+            ConfigError::StagingError(msg) => ConfigStagingError::StagingFailed(msg),
+            other => ConfigStagingError::ParseFailed(other.to_string()),
+        }
+    }
+}
+
+impl From<ConfigStagingError> for AppError {
+    fn from(err: ConfigStagingError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
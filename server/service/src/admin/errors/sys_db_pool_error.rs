@@ -0,0 +1,40 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DbPoolError {
+    #[error("Database pool not found")]
+    PoolNotFound,
+    #[error("Min connections must not exceed max connections")]
+    InvalidPoolSize,
+    #[error("Failed to rebuild database pool: {0}")]
+    RebuildFailed(String),
+}
+
+impl ApiError for DbPoolError {
+    fn code(&self) -> u16 {
+        match self {
+            DbPoolError::PoolNotFound => 8001,
+            DbPoolError::InvalidPoolSize => 8002,
+            DbPoolError::RebuildFailed(_) => 8003,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            DbPoolError::PoolNotFound => ErrorCategory::NotFound,
+            DbPoolError::RebuildFailed(_) => ErrorCategory::Dependency,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<DbPoolError> for AppError {
+    fn from(err: DbPoolError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,40 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DeletionError {
+    #[error("Deletion is blocked by dependent records, see the deletion preview")]
+    Blocked,
+    #[error("Record to delete not found")]
+    TargetNotFound,
+    #[error("Reassign target must be another existing organization")]
+    InvalidReassignTarget,
+}
+
+impl ApiError for DeletionError {
+    fn code(&self) -> u16 {
+        match self {
+            DeletionError::Blocked => 10411,
+            DeletionError::TargetNotFound => 10412,
+            DeletionError::InvalidReassignTarget => 10413,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            DeletionError::Blocked => ErrorCategory::Conflict,
+            DeletionError::TargetNotFound => ErrorCategory::NotFound,
+            DeletionError::InvalidReassignTarget => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<DeletionError> for AppError {
+    fn from(err: DeletionError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
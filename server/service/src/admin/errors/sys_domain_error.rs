@@ -1,4 +1,4 @@
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -26,13 +26,18 @@ impl ApiError for DomainError {
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            DomainError::DomainNotFound => ErrorCategory::NotFound,
+            DomainError::DuplicateCode | DomainError::DuplicateName => ErrorCategory::Conflict,
+            DomainError::BuiltInDomain => ErrorCategory::Forbidden,
+        }
+    }
 }
 
 impl From<DomainError> for AppError {
     fn from(err: DomainError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
@@ -0,0 +1,76 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum FileError {
+    #[error("File not found")]
+    FileNotFound,
+    #[error("Uploaded file is empty")]
+    EmptyFile,
+    #[error("File storage is not configured")]
+    StorageNotConfigured,
+    #[error("File content is missing from storage")]
+    BlobNotFound,
+    #[error("Storage operation failed: {0}")]
+    StorageOperation(String),
+    #[error("File is quarantined pending review")]
+    FileQuarantined,
+    #[error("File is not quarantined")]
+    FileNotQuarantined,
+    #[error("Share link not found")]
+    ShareNotFound,
+    #[error("Share link has expired or been revoked")]
+    ShareUnavailable,
+    #[error("Share link download limit reached")]
+    ShareDownloadLimitReached,
+    #[error("Share link password is incorrect")]
+    SharePasswordInvalid,
+    #[error("Too many incorrect share link passwords, try again later")]
+    SharePasswordLocked,
+}
+
+impl ApiError for FileError {
+    fn code(&self) -> u16 {
+        match self {
+            FileError::FileNotFound => 6001,
+            FileError::EmptyFile => 6002,
+            FileError::StorageNotConfigured => 6003,
+            FileError::BlobNotFound => 6004,
+            FileError::StorageOperation(_) => 6005,
+            FileError::FileQuarantined => 6006,
+            FileError::FileNotQuarantined => 6007,
+            FileError::ShareNotFound => 6008,
+            FileError::ShareUnavailable => 6009,
+            FileError::ShareDownloadLimitReached => 6010,
+            FileError::SharePasswordInvalid => 6011,
+            FileError::SharePasswordLocked => 6012,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            FileError::FileNotFound | FileError::BlobNotFound | FileError::ShareNotFound => {
+                ErrorCategory::NotFound
+            },
+            FileError::StorageNotConfigured => ErrorCategory::Internal,
+            FileError::StorageOperation(_) => ErrorCategory::Dependency,
+            FileError::FileQuarantined
+            | FileError::ShareUnavailable
+            | FileError::ShareDownloadLimitReached
+            | FileError::SharePasswordInvalid
+            | FileError::SharePasswordLocked => ErrorCategory::Forbidden,
+            FileError::FileNotQuarantined => ErrorCategory::Conflict,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<FileError> for AppError {
+    fn from(err: FileError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
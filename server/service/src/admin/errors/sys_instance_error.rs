@@ -0,0 +1,32 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum InstanceError {
+    #[error("Instance is already draining")]
+    AlreadyDraining,
+}
+
+impl ApiError for InstanceError {
+    fn code(&self) -> u16 {
+        match self {
+            InstanceError::AlreadyDraining => 9101,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            InstanceError::AlreadyDraining => ErrorCategory::Conflict,
+        }
+    }
+}
+
+impl From<InstanceError> for AppError {
+    fn from(err: InstanceError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,43 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MaintenanceWindowError {
+    #[error("Maintenance window not found")]
+    WindowNotFound,
+    #[error("Maintenance window with this name already exists")]
+    DuplicateName,
+    #[error("Cron schedule requires a valid five-field cron expression and a duration")]
+    InvalidCron,
+    #[error("Start time must be earlier than end time")]
+    InvalidRange,
+}
+
+impl ApiError for MaintenanceWindowError {
+    fn code(&self) -> u16 {
+        match self {
+            MaintenanceWindowError::WindowNotFound => 10201,
+            MaintenanceWindowError::DuplicateName => 10202,
+            MaintenanceWindowError::InvalidCron => 10203,
+            MaintenanceWindowError::InvalidRange => 10204,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            MaintenanceWindowError::WindowNotFound => ErrorCategory::NotFound,
+            MaintenanceWindowError::DuplicateName => ErrorCategory::Conflict,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<MaintenanceWindowError> for AppError {
+    fn from(err: MaintenanceWindowError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -1,4 +1,4 @@
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -21,13 +21,17 @@ impl ApiError for MenuError {
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            MenuError::MenuNotFound => ErrorCategory::NotFound,
+            MenuError::DuplicateRouteName => ErrorCategory::Conflict,
+        }
+    }
 }
 
 impl From<MenuError> for AppError {
     fn from(err: MenuError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
@@ -0,0 +1,32 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum MigrationError {
+    #[error("Failed to read migration status: {0}")]
+    StatusUnavailable(String),
+}
+
+impl ApiError for MigrationError {
+    fn code(&self) -> u16 {
+        match self {
+            MigrationError::StatusUnavailable(_) => 9201,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            MigrationError::StatusUnavailable(_) => ErrorCategory::Dependency,
+        }
+    }
+}
+
+impl From<MigrationError> for AppError {
+    fn from(err: MigrationError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
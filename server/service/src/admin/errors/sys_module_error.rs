@@ -0,0 +1,32 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ModuleError {
+    #[error("Module not found")]
+    ModuleNotFound,
+}
+
+impl ApiError for ModuleError {
+    fn code(&self) -> u16 {
+        match self {
+            ModuleError::ModuleNotFound => 10502,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            ModuleError::ModuleNotFound => ErrorCategory::NotFound,
+        }
+    }
+}
+
+impl From<ModuleError> for AppError {
+    fn from(err: ModuleError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,36 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum NotificationError {
+    #[error("Notification not found")]
+    NotificationNotFound,
+    #[error("Quiet hours start and end must be set together")]
+    IncompleteQuietHours,
+}
+
+impl ApiError for NotificationError {
+    fn code(&self) -> u16 {
+        match self {
+            NotificationError::NotificationNotFound => 10101,
+            NotificationError::IncompleteQuietHours => 10102,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            NotificationError::NotificationNotFound => ErrorCategory::NotFound,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<NotificationError> for AppError {
+    fn from(err: NotificationError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,54 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PasskeyError {
+    #[error("Passkey authentication is not enabled")]
+    NotEnabled,
+    #[error("Passwordless login is not enabled")]
+    PasswordlessDisabled,
+    #[error("Passkey challenge has expired, please try again")]
+    ChallengeExpired,
+    #[error("Passkey verification failed")]
+    VerificationFailed,
+    #[error("No passkey registered for this user")]
+    NoCredentials,
+    #[error("Passkey not found")]
+    PasskeyNotFound,
+}
+
+impl ApiError for PasskeyError {
+    fn code(&self) -> u16 {
+        match self {
+            PasskeyError::NotEnabled => 9701,
+            PasskeyError::PasswordlessDisabled => 9702,
+            PasskeyError::ChallengeExpired => 9703,
+            PasskeyError::VerificationFailed => 9704,
+            PasskeyError::NoCredentials => 9705,
+            PasskeyError::PasskeyNotFound => 9706,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            PasskeyError::NotEnabled | PasskeyError::PasswordlessDisabled => {
+                ErrorCategory::Forbidden
+            },
+            PasskeyError::ChallengeExpired | PasskeyError::VerificationFailed => {
+                ErrorCategory::Unauthorized
+            },
+            PasskeyError::PasskeyNotFound => ErrorCategory::NotFound,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<PasskeyError> for AppError {
+    fn from(err: PasskeyError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,43 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PersonalTokenError {
+    #[error("Personal access token not found")]
+    TokenNotFound,
+    #[error("Too many personal access tokens, revoke unused tokens first")]
+    TooManyTokens,
+    #[error("Invalid scope: {0}")]
+    InvalidScope(String),
+    #[error("Personal access tokens cannot be managed with a personal access token")]
+    ManagedWithPersonalToken,
+}
+
+impl ApiError for PersonalTokenError {
+    fn code(&self) -> u16 {
+        match self {
+            PersonalTokenError::TokenNotFound => 10602,
+            PersonalTokenError::TooManyTokens => 10603,
+            PersonalTokenError::InvalidScope(_) => 10604,
+            PersonalTokenError::ManagedWithPersonalToken => 10605,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            PersonalTokenError::TokenNotFound => ErrorCategory::NotFound,
+            PersonalTokenError::ManagedWithPersonalToken => ErrorCategory::Forbidden,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<PersonalTokenError> for AppError {
+    fn from(err: PersonalTokenError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
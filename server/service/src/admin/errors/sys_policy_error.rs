@@ -0,0 +1,36 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PolicyError {
+    #[error("Policy document not found")]
+    DocumentNotFound,
+    #[error("Only the latest version of a policy document can be accepted")]
+    NotLatestVersion,
+}
+
+impl ApiError for PolicyError {
+    fn code(&self) -> u16 {
+        match self {
+            PolicyError::DocumentNotFound => 10002,
+            PolicyError::NotLatestVersion => 10003,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            PolicyError::DocumentNotFound => ErrorCategory::NotFound,
+            PolicyError::NotLatestVersion => ErrorCategory::Conflict,
+        }
+    }
+}
+
+impl From<PolicyError> for AppError {
+    fn from(err: PolicyError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,46 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProfilingError {
+    #[error("A CPU profile is already being captured")]
+    AlreadyRunning,
+    #[error("Profile duration must be between 1 and {0} seconds")]
+    InvalidDuration(u64),
+    #[error("Sampling frequency must be between 1 and {0} Hz")]
+    InvalidFrequency(i32),
+    #[error("Failed to capture profile: {0}")]
+    CaptureFailed(String),
+    #[error("Runtime statistics are not supported on this platform")]
+    Unsupported,
+}
+
+impl ApiError for ProfilingError {
+    fn code(&self) -> u16 {
+        match self {
+            ProfilingError::AlreadyRunning => 9001,
+            ProfilingError::InvalidDuration(_) => 9002,
+            ProfilingError::InvalidFrequency(_) => 9003,
+            ProfilingError::CaptureFailed(_) => 9004,
+            ProfilingError::Unsupported => 9005,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            ProfilingError::AlreadyRunning => ErrorCategory::Conflict,
+            ProfilingError::CaptureFailed(_) => ErrorCategory::Internal,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<ProfilingError> for AppError {
+    fn from(err: ProfilingError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
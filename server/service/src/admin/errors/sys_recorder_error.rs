@@ -0,0 +1,43 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RecorderError {
+    #[error("Request recording is disabled")]
+    Disabled,
+    #[error("Either userId or requestIdPattern is required")]
+    MissingFilter,
+    #[error("No recorded traces to replay")]
+    NothingToReplay,
+    #[error("Failed to build replay client: {0}")]
+    ReplayClient(String),
+}
+
+impl ApiError for RecorderError {
+    fn code(&self) -> u16 {
+        match self {
+            RecorderError::Disabled => 9301,
+            RecorderError::MissingFilter => 9302,
+            RecorderError::NothingToReplay => 9303,
+            RecorderError::ReplayClient(_) => 9304,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            RecorderError::Disabled => ErrorCategory::Forbidden,
+            RecorderError::ReplayClient(_) => ErrorCategory::Dependency,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<RecorderError> for AppError {
+    fn from(err: RecorderError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
@@ -0,0 +1,36 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ReferenceError {
+    #[error("Entity has no registered relations")]
+    UnknownEntity,
+    #[error("Invalid id for entity")]
+    InvalidId,
+}
+
+impl ApiError for ReferenceError {
+    fn code(&self) -> u16 {
+        match self {
+            ReferenceError::UnknownEntity => 10414,
+            ReferenceError::InvalidId => 10415,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            ReferenceError::UnknownEntity => ErrorCategory::NotFound,
+            ReferenceError::InvalidId => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<ReferenceError> for AppError {
+    fn from(err: ReferenceError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
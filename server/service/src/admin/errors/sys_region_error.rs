@@ -0,0 +1,51 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum RegionError {
+    #[error("Region version not found")]
+    VersionNotFound,
+    #[error("Region version already exists")]
+    VersionExists,
+    #[error("The active region version cannot be deleted")]
+    VersionActive,
+    #[error("No region version is active")]
+    NoActiveVersion,
+    #[error("Region not found")]
+    RegionNotFound,
+    #[error("Invalid region data: {0}")]
+    InvalidData(String),
+}
+
+impl ApiError for RegionError {
+    fn code(&self) -> u16 {
+        match self {
+            RegionError::VersionNotFound => 10419,
+            RegionError::VersionExists => 10420,
+            RegionError::VersionActive => 10421,
+            RegionError::NoActiveVersion => 10422,
+            RegionError::RegionNotFound => 10423,
+            RegionError::InvalidData(_) => 10424,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            RegionError::VersionNotFound
+            | RegionError::NoActiveVersion
+            | RegionError::RegionNotFound => ErrorCategory::NotFound,
+            RegionError::VersionExists | RegionError::VersionActive => ErrorCategory::Conflict,
+            RegionError::InvalidData(_) => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<RegionError> for AppError {
+    fn from(err: RegionError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
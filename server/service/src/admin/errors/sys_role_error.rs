@@ -1,4 +1,4 @@
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -8,6 +8,9 @@ pub enum RoleError {
 
     #[error("Duplicate role code")]
     DuplicateRoleCode,
+
+    #[error("Data scope organization not found")]
+    ScopeOrganizationNotFound,
 }
 
 impl ApiError for RoleError {
@@ -15,19 +18,26 @@ impl ApiError for RoleError {
         match self {
             RoleError::RoleNotFound => 4001,
             RoleError::DuplicateRoleCode => 4002,
+            RoleError::ScopeOrganizationNotFound => 4003,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            RoleError::RoleNotFound | RoleError::ScopeOrganizationNotFound => {
+                ErrorCategory::NotFound
+            },
+            RoleError::DuplicateRoleCode => ErrorCategory::Conflict,
+        }
+    }
 }
 
 impl From<RoleError> for AppError {
     fn from(err: RoleError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
@@ -0,0 +1,44 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum SensitiveOperationError {
+    #[error("Approval not found or expired")]
+    ApprovalNotFound,
+    #[error("Endpoint is not a sensitive operation")]
+    NotSensitiveOperation,
+    #[error("Approval has already been approved")]
+    AlreadyApproved,
+    #[error("Approval must be granted by another administrator")]
+    SelfApproval,
+}
+
+impl ApiError for SensitiveOperationError {
+    fn code(&self) -> u16 {
+        match self {
+            SensitiveOperationError::ApprovalNotFound => 10402,
+            SensitiveOperationError::NotSensitiveOperation => 10403,
+            SensitiveOperationError::AlreadyApproved => 10404,
+            SensitiveOperationError::SelfApproval => 10405,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            SensitiveOperationError::ApprovalNotFound => ErrorCategory::NotFound,
+            SensitiveOperationError::AlreadyApproved => ErrorCategory::Conflict,
+            SensitiveOperationError::SelfApproval => ErrorCategory::Forbidden,
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<SensitiveOperationError> for AppError {
+    fn from(err: SensitiveOperationError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
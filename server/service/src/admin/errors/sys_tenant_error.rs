@@ -0,0 +1,60 @@
+use server_core::web::{
+    error::{ApiError, AppError, ErrorCategory},
+    tenant_gate::TENANT_SUSPENDED_CODE,
+};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TenantError {
+    #[error("{0}")]
+    TenantSuspended(String),
+    #[error("Tenant not found")]
+    TenantNotFound,
+    #[error("Tenant code must start with a lowercase letter and contain only lowercase letters, digits, '-' or '_'")]
+    InvalidCode,
+    #[error("Database instance is missing or not configured")]
+    InvalidDbInstance,
+    #[error("Operation is not allowed in the current tenant status")]
+    InvalidStatus,
+    #[error("Purge confirmation is invalid or expired")]
+    InvalidPurgeConfirmation,
+    #[error("Admin template role not found")]
+    TemplateRoleNotFound,
+    #[error("Invalid tenant archive: {0}")]
+    InvalidArchive(String),
+}
+
+impl ApiError for TenantError {
+    fn code(&self) -> u16 {
+        match self {
+            TenantError::TenantSuspended(_) => TENANT_SUSPENDED_CODE,
+            TenantError::TenantNotFound => 10302,
+            TenantError::InvalidCode => 10303,
+            TenantError::InvalidDbInstance => 10304,
+            TenantError::InvalidStatus => 10305,
+            TenantError::InvalidPurgeConfirmation => 10306,
+            TenantError::TemplateRoleNotFound => 10307,
+            TenantError::InvalidArchive(_) => 10308,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            TenantError::TenantSuspended(_) => ErrorCategory::Forbidden,
+            TenantError::TenantNotFound | TenantError::TemplateRoleNotFound => {
+                ErrorCategory::NotFound
+            },
+            _ => ErrorCategory::Validation,
+        }
+    }
+}
+
+impl From<TenantError> for AppError {
+    fn from(err: TenantError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
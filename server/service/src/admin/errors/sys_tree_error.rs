@@ -0,0 +1,40 @@
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum TreeError {
+    #[error("Tree node not found")]
+    NodeNotFound,
+    #[error("Target parent node not found")]
+    ParentNotFound,
+    #[error("Cannot move a node under itself or its descendants")]
+    CyclicMove,
+}
+
+impl ApiError for TreeError {
+    fn code(&self) -> u16 {
+        match self {
+            TreeError::NodeNotFound => 10416,
+            TreeError::ParentNotFound => 10417,
+            TreeError::CyclicMove => 10418,
+        }
+    }
+
+    fn message(&self) -> String {
+        format!("{}", self)
+    }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            TreeError::NodeNotFound => ErrorCategory::NotFound,
+            TreeError::ParentNotFound => ErrorCategory::Validation,
+            TreeError::CyclicMove => ErrorCategory::Conflict,
+        }
+    }
+}
+
+impl From<TreeError> for AppError {
+    fn from(err: TreeError) -> Self {
+        AppError::from_api_error(&err)
+    }
+}
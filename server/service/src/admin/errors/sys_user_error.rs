@@ -1,4 +1,4 @@
-use server_core::web::error::{ApiError, AppError};
+use server_core::web::error::{ApiError, AppError, ErrorCategory};
 use thiserror::Error;
 
 #[derive(Error, Debug)]
@@ -13,6 +13,12 @@ pub enum UserError {
     UsernameAlreadyExists,
     #[error("Invalid user status")]
     InvalidUserStatus,
+    #[error("User is outside of your data scope")]
+    OutOfDataScope,
+    #[error("Invalid or expired refresh token")]
+    InvalidRefreshToken,
+    #[error("Refresh limit reached, please log in again")]
+    RefreshLimitExceeded,
 }
 
 impl ApiError for UserError {
@@ -23,19 +29,32 @@ impl ApiError for UserError {
             UserError::AuthenticationFailed => 1003,
             UserError::UsernameAlreadyExists => 1004,
             UserError::InvalidUserStatus => 1005,
+            UserError::OutOfDataScope => 1006,
+            UserError::InvalidRefreshToken => 1007,
+            UserError::RefreshLimitExceeded => 1008,
         }
     }
 
     fn message(&self) -> String {
         format!("{}", self)
     }
+
+    fn category(&self) -> ErrorCategory {
+        match self {
+            UserError::UserNotFound => ErrorCategory::NotFound,
+            UserError::WrongPassword
+            | UserError::AuthenticationFailed
+            | UserError::InvalidRefreshToken
+            | UserError::RefreshLimitExceeded => ErrorCategory::Unauthorized,
+            UserError::UsernameAlreadyExists => ErrorCategory::Conflict,
+            UserError::OutOfDataScope => ErrorCategory::Forbidden,
+            _ => ErrorCategory::Validation,
+        }
+    }
 }
 
 impl From<UserError> for AppError {
     fn from(err: UserError) -> Self {
-        AppError {
-            code: err.code(),
-            message: err.message(),
-        }
+        AppError::from_api_error(&err)
     }
 }
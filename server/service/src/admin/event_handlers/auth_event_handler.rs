@@ -23,7 +23,7 @@ pub struct AuthEventHandler;
 
 impl AuthEventHandler {
     pub async fn handle_login(event: AuthEvent) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         // 处理登录日志
         let login_log_event = LoginLogEvent {
@@ -1,5 +1,5 @@
 use chrono::Local;
-use sea_orm::{ActiveModelTrait, DatabaseConnection, Set};
+use sea_orm::{ActiveModelTrait, ConnectionTrait, Set};
 use server_core::web::error::AppError;
 use server_model::admin::entities::sys_login_log::ActiveModel as SysLoginLogActiveModel;
 use ulid::Ulid;
@@ -17,7 +17,7 @@ pub struct LoginLogEvent {
 }
 
 impl LoginLogEvent {
-    pub async fn handle(self, db: &DatabaseConnection) -> Result<(), AppError> {
+    pub async fn handle<C: ConnectionTrait>(self, db: &C) -> Result<(), AppError> {
         SysLoginLogActiveModel {
             id: Set(Ulid::new().to_string()),
             user_id: Set(self.user_id),
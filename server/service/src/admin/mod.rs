@@ -1,15 +1,37 @@
+pub use crate::helper::{
+    export_watermark_helper::WATERMARK_HEADER,
+    maintenance_helper::jobs_paused,
+    module_helper::{module_enabled, module_for_path},
+    siem_helper::{flush_siem_events, init_siem_exporter},
+};
 pub use errors::*;
+pub use server_config::StagedConfigStatus;
 pub use server_model::admin::{
     entities::{
         prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysUser},
         sys_access_key::Model as SysAccessKeyModel,
+        sys_access_review::Model as SysAccessReviewModel,
+        sys_access_review_item::Model as SysAccessReviewItemModel,
+        sys_alert_rule::Model as SysAlertRuleModel,
+        sys_api_usage::Model as SysApiUsageModel,
         sys_domain::Model as SysDomainModel,
         sys_endpoint::Model as SysEndpointModel,
+        sys_file::Model as SysFileModel,
+        sys_file_share::Model as SysFileShareModel,
         sys_login_log::Model as SysLoginLogModel,
+        sys_maintenance_window::Model as SysMaintenanceWindowModel,
         sys_menu::Model as SysMenuModel,
+        sys_metering::Model as SysMeteringModel,
+        sys_notification::Model as SysNotificationModel,
         sys_operation_log::Model as SysOperationLogModel,
+        sys_operation_log_archive::Model as SysOperationLogArchiveModel,
         sys_organization::Model as SysOrganizationModel,
+        sys_policy_acceptance::Model as SysPolicyAcceptanceModel,
+        sys_policy_document::Model as SysPolicyDocumentModel,
+        sys_region_version::Model as SysRegionVersionModel,
         sys_role::Model as SysRoleModel,
+        sys_tenant::Model as SysTenantModel,
+        sys_user_passkey::Model as SysUserPasskeyModel,
     },
     input::*,
     output::*,
@@ -17,32 +39,94 @@ pub use server_model::admin::{
 pub use sys_access_key_service::{
     api_key_validate_listener, SysAccessKeyService, TAccessKeyService,
 };
+pub use sys_access_review_service::{SysAccessReviewService, TAccessReviewService};
+pub use sys_alert_rule_service::{security_alert_listener, SysAlertRuleService, TAlertRuleService};
+pub use sys_api_usage_service::{api_usage_listener, SysApiUsageService, TApiUsageService};
 pub use sys_auth_service::{
     auth_login_listener, jwt_created_listener, SysAuthService, TAuthService,
 };
 pub use sys_authorization_service::{SysAuthorizationService, TAuthorizationService};
+pub use sys_break_glass_service::{SysBreakGlassService, TBreakGlassService};
+#[cfg(feature = "chaos")]
+pub use sys_chaos_service::{SysChaosService, TChaosService};
+pub use sys_cluster_service::{is_cluster_leader, SysClusterService, TClusterService};
+pub use sys_config_service::{SysConfigService, TConfigService};
+pub use sys_consistency_service::{SysConsistencyService, TConsistencyService};
+pub use sys_db_pool_service::{SysDbPoolService, TDbPoolService};
+pub use sys_deletion_service::{SysDeletionService, TDeletionService};
+pub use sys_diagnostics_service::{SysDiagnosticsService, TDiagnosticsService};
 pub use sys_domain_service::{SysDomainService, TDomainService};
 pub use sys_endpoint_service::{SysEndpointService, TEndpointService};
+pub use sys_file_service::{SysFileService, TFileService};
+pub use sys_instance_service::{SysInstanceService, TInstanceService};
 pub use sys_login_log_service::{SysLoginLogService, TLoginLogService};
+pub use sys_maintenance_window_service::{SysMaintenanceWindowService, TMaintenanceWindowService};
 pub use sys_menu_service::{SysMenuService, TMenuService};
+pub use sys_metering_service::{SysMeteringService, TMeteringService};
+pub use sys_migration_service::{SysMigrationService, TMigrationService};
+pub use sys_module_service::{SysModuleService, TModuleService};
+pub use sys_notification_service::{SysNotificationService, TNotificationService};
 pub use sys_operation_log_service::{
     sys_operation_log_listener, SysOperationLogService, TOperationLogService,
 };
 pub use sys_organization_service::{SysOrganizationService, TOrganizationService};
+pub use sys_passkey_service::{SysPasskeyService, TPasskeyService};
+pub use sys_personal_token_service::{SysPersonalTokenService, TPersonalTokenService};
+pub use sys_policy_service::{SysPolicyService, TPolicyService};
+#[cfg(feature = "profiling")]
+pub use sys_profiling_service::{SysProfilingService, TProfilingService};
+pub use sys_recorder_service::{SysRecorderService, TRecorderService};
+pub use sys_reference_service::{SysReferenceService, TReferenceService};
+pub use sys_region_service::{SysRegionService, TRegionService};
 pub use sys_role_service::{SysRoleService, TRoleService};
+pub use sys_sensitive_operation_service::{
+    SysSensitiveOperationService, TSensitiveOperationService,
+};
+pub use sys_telemetry_service::{SysTelemetryService, TTelemetryService};
+pub use sys_tenant_service::{SysTenantService, TTenantService};
 pub use sys_user_service::{SysUserService, TUserService};
 pub mod dto;
 pub mod errors;
 mod sys_access_key_service;
+mod sys_access_review_service;
+mod sys_alert_rule_service;
+mod sys_api_usage_service;
 mod sys_auth_service;
 mod sys_authorization_service;
+mod sys_break_glass_service;
+#[cfg(feature = "chaos")]
+mod sys_chaos_service;
+mod sys_cluster_service;
+mod sys_config_service;
+mod sys_consistency_service;
+mod sys_db_pool_service;
+mod sys_deletion_service;
+mod sys_diagnostics_service;
 mod sys_domain_service;
 mod sys_endpoint_service;
+mod sys_file_service;
+mod sys_instance_service;
 mod sys_login_log_service;
+mod sys_maintenance_window_service;
 mod sys_menu_service;
+mod sys_metering_service;
+mod sys_migration_service;
+mod sys_module_service;
+mod sys_notification_service;
 mod sys_operation_log_service;
 mod sys_organization_service;
+mod sys_passkey_service;
+mod sys_personal_token_service;
+mod sys_policy_service;
+#[cfg(feature = "profiling")]
+mod sys_profiling_service;
+mod sys_recorder_service;
+mod sys_reference_service;
+mod sys_region_service;
 mod sys_role_service;
+mod sys_sensitive_operation_service;
+mod sys_telemetry_service;
+mod sys_tenant_service;
 mod sys_user_service;
 
 mod event_handlers;
@@ -1,16 +1,13 @@
-use std::any::Any;
-
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
-    QueryFilter, Set, TransactionTrait,
+    QueryFilter, Set,
 };
 use server_core::{
     sign::{ApiKeyEvent, ValidatorType},
-    web::{error::AppError, page::PaginatedData},
+    web::{error::AppError, page::PageResult},
 };
-use server_global::project_info;
+use server_global::{global::TracedEvent, project_info};
 use server_model::admin::{
     entities::{
         prelude::SysAccessKey,
@@ -33,7 +30,7 @@ pub trait TAccessKeyService {
     async fn find_paginated_access_keys(
         &self,
         params: AccessKeyPageRequest,
-    ) -> Result<PaginatedData<SysAccessKeyModel>, AppError>;
+    ) -> Result<PageResult<SysAccessKeyModel>, AppError>;
     async fn create_access_key(
         &self,
         input: CreateAccessKeyInput,
@@ -97,8 +94,8 @@ impl TAccessKeyService for SysAccessKeyService {
     async fn find_paginated_access_keys(
         &self,
         params: AccessKeyPageRequest,
-    ) -> Result<PaginatedData<SysAccessKeyModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PageResult<SysAccessKeyModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
         let mut query = SysAccessKey::find();
 
         if let Some(ref keywords) = params.keywords {
@@ -106,31 +103,22 @@ impl TAccessKeyService for SysAccessKeyService {
             query = query.filter(condition);
         }
 
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
 
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let paginator = query.paginate(&db, params.page_details.size);
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
     }
 
     async fn create_access_key(
         &self,
         input: CreateAccessKeyInput,
     ) -> Result<SysAccessKeyModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let txn = db.begin().await.map_err(AppError::from)?;
 
         let access_key_id = format!("AK{}", Ulid::new().to_string());
@@ -143,8 +131,6 @@ impl TAccessKeyService for SysAccessKeyService {
             description: Set(input.description),
             access_key_id: Set(access_key_id),
             access_key_secret: Set(access_key_secret),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
             ..Default::default()
         };
 
@@ -166,7 +152,7 @@ impl TAccessKeyService for SysAccessKeyService {
     }
 
     async fn delete_access_key(&self, id: &str) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let txn = db.begin().await.map_err(AppError::from)?;
 
         match self.delete_access_key_in_transaction(&txn, id).await {
@@ -182,10 +168,10 @@ impl TAccessKeyService for SysAccessKeyService {
     }
 
     async fn initialize_access_key(&self) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let access_keys = SysAccessKey::find()
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -205,12 +191,15 @@ impl TAccessKeyService for SysAccessKeyService {
 }
 
 #[instrument(skip(rx))]
-pub async fn api_key_validate_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
+pub async fn api_key_validate_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<TracedEvent>) {
     while let Some(event) = rx.recv().await {
         if let Some(api_key_event) = event.downcast_ref::<ApiKeyEvent>() {
-            project_info!("API key validated: {:?}", api_key_event);
+            event
+                .context
+                .scope("api_key_validated", async {
+                    project_info!("API key validated: {:?}", api_key_event);
+                })
+                .await;
         }
     }
 }
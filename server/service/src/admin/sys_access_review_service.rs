@@ -0,0 +1,531 @@
+use std::collections::{BTreeMap, HashMap};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDateTime};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel,
+    JoinType, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, RelationTrait, Set,
+};
+use serde_json::json;
+use server_config::{AccessReviewConfig, ComplianceConfig};
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{
+            SysAccessReview, SysAccessReviewItem, SysLoginLog, SysOrganization, SysUser,
+            SysUserRole,
+        },
+        sea_orm_active_enums::{AccessReviewDecision, AccessReviewStatus},
+        sys_access_review::{
+            ActiveModel as SysAccessReviewActiveModel, Column as SysAccessReviewColumn,
+            Model as SysAccessReviewModel,
+        },
+        sys_access_review_item::{
+            ActiveModel as SysAccessReviewItemActiveModel, Column as SysAccessReviewItemColumn,
+            Model as SysAccessReviewItemModel,
+        },
+        sys_login_log::Column as SysLoginLogColumn,
+        sys_organization::Column as SysOrganizationColumn,
+        sys_role::Column as SysRoleColumn,
+        sys_user::Column as SysUserColumn,
+        sys_user_role::{Column as SysUserRoleColumn, Relation as SysUserRoleRelation},
+    },
+    input::{
+        AccessReviewItemPageRequest, AccessReviewPageRequest, DecideAccessReviewInput,
+        StartAccessReviewInput,
+    },
+    output::{AccessReviewDepartment, AccessReviewReport},
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::sys_access_review_error::AccessReviewError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    data_scope_helper::{self, ResolvedScope},
+    db_helper,
+    notification_helper::{self, category, Notification},
+    organization_helper,
+};
+
+const AUDIT_MODULE: &str = "权限复核";
+
+#[async_trait]
+pub trait TAccessReviewService {
+    async fn find_paginated_reviews(
+        &self,
+        params: AccessReviewPageRequest,
+    ) -> Result<PageResult<SysAccessReviewModel>, AppError>;
+
+    async fn start_review(
+        &self,
+        input: StartAccessReviewInput,
+        operator: &User,
+    ) -> Result<SysAccessReviewModel, AppError>;
+
+    /// 距上次复核超过配置的周期时发起新一轮，由定时任务调用
+    async fn start_scheduled_review(&self) -> Result<Option<SysAccessReviewModel>, AppError>;
+
+    async fn get_report(&self, id: &str, operator: &User) -> Result<AccessReviewReport, AppError>;
+    async fn find_paginated_items(
+        &self,
+        id: &str,
+        params: AccessReviewItemPageRequest,
+        operator: &User,
+    ) -> Result<PageResult<SysAccessReviewItemModel>, AppError>;
+
+    async fn decide_item(
+        &self,
+        item_id: &str,
+        input: DecideAccessReviewInput,
+        operator: &User,
+    ) -> Result<SysAccessReviewItemModel, AppError>;
+    async fn close_review(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SysAccessReviewModel, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysAccessReviewService;
+
+async fn access_review_config() -> AccessReviewConfig {
+    global::get_config::<ComplianceConfig>()
+        .await
+        .map(|config| config.access_review.clone())
+        .unwrap_or_default()
+}
+
+impl SysAccessReviewService {
+    async fn get_review_by_id(&self, id: &str) -> Result<SysAccessReviewModel, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        SysAccessReview::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AccessReviewError::ReviewNotFound.into())
+    }
+
+    /// 生成复核活动，为每个用户创建一条复核条目
+    async fn create_review(
+        &self,
+        name: String,
+        due_days: u32,
+        created_by: String,
+    ) -> Result<SysAccessReviewModel, AppError> {
+        let config = access_review_config().await;
+        let db = db_helper::get_rls_connection().await?;
+
+        let users = SysUser::find()
+            .order_by_asc(SysUserColumn::Username)
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut user_roles: HashMap<String, Vec<String>> = HashMap::new();
+        for (user_id, code) in SysUserRole::find()
+            .select_only()
+            .column(SysUserRoleColumn::UserId)
+            .column(SysRoleColumn::Code)
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+            .into_tuple::<(String, String)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+        {
+            user_roles.entry(user_id).or_default().push(code);
+        }
+
+        let last_logins: HashMap<String, Option<NaiveDateTime>> = SysLoginLog::find()
+            .select_only()
+            .column(SysLoginLogColumn::UserId)
+            .expr(Expr::col(SysLoginLogColumn::LoginTime).max())
+            .group_by(SysLoginLogColumn::UserId)
+            .into_tuple::<(String, Option<NaiveDateTime>)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .collect();
+
+        let parents = organization_helper::load_parents().await?;
+
+        let reviewers = organization_helper::role_holders(&config.reviewer_role).await?;
+        let fallback_reviewer = config.fallback_reviewer.as_ref().and_then(|username| {
+            users
+                .iter()
+                .find(|user| &user.username == username)
+                .map(|user| user.id.clone())
+        });
+
+        let now = Local::now().naive_local();
+        let review = SysAccessReviewActiveModel {
+            id: Set(Ulid::new().to_string()),
+            name: Set(name),
+            status: Set(AccessReviewStatus::Open),
+            due_at: Set(now + Duration::days(i64::from(due_days))),
+            created_at: Set(now),
+            created_by: Set(created_by.clone()),
+            ..Default::default()
+        };
+        let mut assigned: BTreeMap<String, usize> = BTreeMap::new();
+        let items = users
+            .iter()
+            .map(|user| {
+                // 复核人不复核自己，交给上级组织
+                let reviewer_id = organization_helper::nearest_holder(
+                    &user.id,
+                    user.organization_id.as_deref(),
+                    &parents,
+                    &reviewers,
+                )
+                .or_else(|| fallback_reviewer.clone());
+                if let Some(reviewer_id) = &reviewer_id {
+                    *assigned.entry(reviewer_id.clone()).or_default() += 1;
+                }
+                SysAccessReviewItemActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    review_id: review.id.clone(),
+                    user_id: Set(user.id.clone()),
+                    username: Set(user.username.clone()),
+                    organization_id: Set(user.organization_id.clone()),
+                    roles: Set(user_roles
+                        .get(&user.id)
+                        .map(|roles| roles.join(","))
+                        .unwrap_or_default()),
+                    last_login_at: Set(last_logins.get(&user.id).copied().flatten()),
+                    reviewer_id: Set(reviewer_id),
+                    decision: Set(AccessReviewDecision::Pending),
+                    ..Default::default()
+                }
+            })
+            .collect::<Vec<_>>();
+        let item_count = items.len();
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let review = review.insert(&txn).await.map_err(AppError::from)?;
+        if !items.is_empty() {
+            SysAccessReviewItem::insert_many(items)
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "发起权限复核").with_detail(json!({
+                "reviewId": review.id,
+                "name": review.name,
+                "createdBy": created_by,
+                "items": item_count,
+            })),
+        );
+
+        for (reviewer_id, count) in assigned {
+            let notification = Notification::new(
+                reviewer_id,
+                category::ACCESS_REVIEW,
+                format!("权限复核：{}", review.name),
+                format!(
+                    "您有 {} 条权限复核待处理，请在 {} 前完成。",
+                    count,
+                    review.due_at.format("%Y-%m-%d %H:%M")
+                ),
+            );
+            if let Err(e) = notification_helper::notify(notification).await {
+                tracing::warn!(
+                    review = %review.id,
+                    error = %e,
+                    "Failed to notify access reviewer"
+                );
+            }
+        }
+        Ok(review)
+    }
+
+    /// 操作人能否处理该条目：分派给本人，或不受数据范围限制
+    async fn check_reviewer(
+        &self,
+        item: &SysAccessReviewItemModel,
+        operator: &User,
+    ) -> Result<(), AppError> {
+        if item.reviewer_id.as_deref() == Some(operator.user_id().as_str()) {
+            return Ok(());
+        }
+        match data_scope_helper::resolve(operator).await? {
+            ResolvedScope::All => Ok(()),
+            ResolvedScope::Limited { .. } => Err(AccessReviewError::NotReviewer.into()),
+        }
+    }
+}
+
+#[async_trait]
+impl TAccessReviewService for SysAccessReviewService {
+    async fn find_paginated_reviews(
+        &self,
+        params: AccessReviewPageRequest,
+    ) -> Result<PageResult<SysAccessReviewModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let mut query = SysAccessReview::find();
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any().add(SysAccessReviewColumn::Name.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        query = query.order_by_desc(SysAccessReviewColumn::CreatedAt);
+
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn start_review(
+        &self,
+        input: StartAccessReviewInput,
+        operator: &User,
+    ) -> Result<SysAccessReviewModel, AppError> {
+        let due_days = match input.due_days {
+            Some(due_days) => due_days,
+            None => access_review_config().await.due_days,
+        };
+        self.create_review(input.name, due_days, operator.username())
+            .await
+    }
+
+    #[instrument(skip(self))]
+    async fn start_scheduled_review(&self) -> Result<Option<SysAccessReviewModel>, AppError> {
+        let config = access_review_config().await;
+        if !config.enabled {
+            return Ok(None);
+        }
+
+        let db = db_helper::get_rls_connection().await?;
+        let last_started = SysAccessReview::find()
+            .select_only()
+            .column(SysAccessReviewColumn::CreatedAt)
+            .order_by_desc(SysAccessReviewColumn::CreatedAt)
+            .into_tuple::<NaiveDateTime>()
+            .one(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let now = Local::now().naive_local();
+        let due = last_started
+            .is_none_or(|started| now - started >= Duration::days(i64::from(config.interval_days)));
+        if !due {
+            return Ok(None);
+        }
+
+        let name = format!("定期权限复核 {}", now.format("%Y-%m-%d"));
+        self.create_review(name, config.due_days, "system".to_string())
+            .await
+            .map(Some)
+    }
+
+    async fn get_report(&self, id: &str, operator: &User) -> Result<AccessReviewReport, AppError> {
+        let review = self.get_review_by_id(id).await?;
+        let scope = data_scope_helper::resolve(operator).await?;
+        let db = db_helper::get_rls_connection().await?;
+
+        let items = SysAccessReviewItem::find()
+            .filter(SysAccessReviewItemColumn::ReviewId.eq(id))
+            .order_by_asc(SysAccessReviewItemColumn::Username)
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let organization_names: HashMap<String, String> = SysOrganization::find()
+            .select_only()
+            .column(SysOrganizationColumn::Id)
+            .column(SysOrganizationColumn::Name)
+            .into_tuple::<(String, String)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .collect();
+
+        // 受数据范围限制的复核人只能看到范围内或分派给自己的条目
+        let operator_id = operator.user_id();
+        let mut grouped: BTreeMap<Option<String>, Vec<SysAccessReviewItemModel>> = BTreeMap::new();
+        for item in items.into_iter().filter(|item| {
+            item.reviewer_id.as_deref() == Some(operator_id.as_str())
+                || scope.allows(&item.user_id, item.organization_id.as_deref())
+        }) {
+            grouped
+                .entry(item.organization_id.clone())
+                .or_default()
+                .push(item);
+        }
+
+        let departments = grouped
+            .into_iter()
+            .map(|(organization_id, items)| {
+                let count = |decision: AccessReviewDecision| {
+                    items
+                        .iter()
+                        .filter(|item| item.decision == decision)
+                        .count()
+                };
+                AccessReviewDepartment {
+                    organization_name: organization_id
+                        .as_ref()
+                        .and_then(|id| organization_names.get(id).cloned()),
+                    organization_id,
+                    total: items.len(),
+                    pending: count(AccessReviewDecision::Pending),
+                    confirmed: count(AccessReviewDecision::Confirmed),
+                    revoked: count(AccessReviewDecision::Revoked),
+                    items,
+                }
+            })
+            .collect();
+
+        Ok(AccessReviewReport {
+            review,
+            departments,
+        })
+    }
+
+    async fn find_paginated_items(
+        &self,
+        id: &str,
+        params: AccessReviewItemPageRequest,
+        operator: &User,
+    ) -> Result<PageResult<SysAccessReviewItemModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let mut query =
+            SysAccessReviewItem::find().filter(SysAccessReviewItemColumn::ReviewId.eq(id));
+
+        if let Some(decision) = params.decision {
+            query = query.filter(SysAccessReviewItemColumn::Decision.eq(decision));
+        }
+
+        let operator_id = operator.user_id();
+        if params.mine {
+            query = query.filter(SysAccessReviewItemColumn::ReviewerId.eq(operator_id));
+        } else if let ResolvedScope::Limited {
+            organizations,
+            user_id,
+        } = data_scope_helper::resolve(operator).await?
+        {
+            query = query.filter(
+                Condition::any()
+                    .add(SysAccessReviewItemColumn::OrganizationId.is_in(organizations))
+                    .add(SysAccessReviewItemColumn::UserId.eq(user_id))
+                    .add(SysAccessReviewItemColumn::ReviewerId.eq(operator_id)),
+            );
+        }
+
+        query = query.order_by_asc(SysAccessReviewItemColumn::Username);
+
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn decide_item(
+        &self,
+        item_id: &str,
+        input: DecideAccessReviewInput,
+        operator: &User,
+    ) -> Result<SysAccessReviewItemModel, AppError> {
+        if input.decision == AccessReviewDecision::Pending {
+            return Err(AccessReviewError::InvalidDecision.into());
+        }
+
+        let db = db_helper::get_rls_connection().await?;
+        let item = SysAccessReviewItem::find_by_id(item_id)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(AccessReviewError::ItemNotFound)?;
+        let review = self.get_review_by_id(&item.review_id).await?;
+        if review.status == AccessReviewStatus::Closed {
+            return Err(AccessReviewError::ReviewClosed.into());
+        }
+        self.check_reviewer(&item, operator).await?;
+
+        let user_id = item.user_id.clone();
+        let mut active = item.into_active_model();
+        active.decision = Set(input.decision.clone());
+        active.comment = Set(input.comment);
+        active.decided_at = Set(Some(Local::now().naive_local()));
+        active.decided_by = Set(Some(operator.username()));
+
+        // 撤销时移除用户的全部角色，用户重新登录后不再具有相应权限
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let item = active.update(&txn).await.map_err(AppError::from)?;
+        if input.decision == AccessReviewDecision::Revoked {
+            SysUserRole::delete_many()
+                .filter(SysUserRoleColumn::UserId.eq(&user_id))
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "提交权限复核决定")
+                .with_user(operator)
+                .with_detail(json!({
+                    "reviewId": item.review_id,
+                    "itemId": item.id,
+                    "userId": item.user_id,
+                    "username": item.username,
+                    "roles": item.roles,
+                    "decision": item.decision,
+                    "comment": item.comment,
+                })),
+        );
+        Ok(item)
+    }
+
+    async fn close_review(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SysAccessReviewModel, AppError> {
+        let review = self.get_review_by_id(id).await?;
+        if review.status == AccessReviewStatus::Closed {
+            return Err(AccessReviewError::ReviewClosed.into());
+        }
+
+        let db = db_helper::get_rls_connection().await?;
+        let pending = SysAccessReviewItem::find()
+            .filter(SysAccessReviewItemColumn::ReviewId.eq(id))
+            .filter(SysAccessReviewItemColumn::Decision.eq(AccessReviewDecision::Pending))
+            .count(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut review = review.into_active_model();
+        review.status = Set(AccessReviewStatus::Closed);
+        review.closed_at = Set(Some(Local::now().naive_local()));
+        review.closed_by = Set(Some(operator.username()));
+        let review = review.update(&db).await.map_err(AppError::from)?;
+
+        // 未处理的条目保留为 pending，作为未完成复核的证据
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "关闭权限复核")
+                .with_user(operator)
+                .with_detail(json!({ "reviewId": review.id, "pending": pending })),
+        );
+        Ok(review)
+    }
+}
@@ -0,0 +1,285 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, Set,
+};
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_global::{global::TracedEvent, project_error};
+use server_model::admin::{
+    entities::{
+        prelude::SysAlertRule,
+        sea_orm_active_enums::AlertChannel,
+        sys_alert_rule::{
+            ActiveModel as SysAlertRuleActiveModel, Column as SysAlertRuleColumn,
+            Model as SysAlertRuleModel,
+        },
+    },
+    input::{AlertRulePageRequest, CreateAlertRuleInput, UpdateAlertRuleInput},
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::sys_alert_rule_error::AlertRuleError;
+use crate::helper::{
+    alert_helper::{self, SecuritySignal},
+    audit_helper::{record_audit, AuditEntry},
+    db_helper, maintenance_helper,
+};
+
+#[async_trait]
+pub trait TAlertRuleService {
+    async fn find_paginated_rules(
+        &self,
+        params: AlertRulePageRequest,
+    ) -> Result<PageResult<SysAlertRuleModel>, AppError>;
+
+    async fn create_rule(
+        &self,
+        input: CreateAlertRuleInput,
+        operator: &User,
+    ) -> Result<SysAlertRuleModel, AppError>;
+    async fn get_rule(&self, id: &str) -> Result<SysAlertRuleModel, AppError>;
+    async fn update_rule(
+        &self,
+        input: UpdateAlertRuleInput,
+        operator: &User,
+    ) -> Result<SysAlertRuleModel, AppError>;
+    async fn delete_rule(&self, id: &str, operator: &User) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysAlertRuleService;
+
+impl SysAlertRuleService {
+    async fn check_rule(
+        &self,
+        id: Option<&str>,
+        input: &CreateAlertRuleInput,
+    ) -> Result<(), AppError> {
+        let target = input.target.as_deref().map(str::trim).unwrap_or_default();
+        match input.channel {
+            AlertChannel::Webhook
+                if !target.starts_with("http://") && !target.starts_with("https://") =>
+            {
+                return Err(AlertRuleError::InvalidWebhookTarget.into());
+            },
+            AlertChannel::Email if target.split(',').all(|to| to.trim().is_empty()) => {
+                return Err(AlertRuleError::MissingRecipient.into());
+            },
+            _ => {},
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let name_exists = SysAlertRule::find()
+            .filter(SysAlertRuleColumn::Name.eq(&input.name))
+            .filter(SysAlertRuleColumn::Id.ne(id.unwrap_or("-1")))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+
+        if name_exists {
+            return Err(AlertRuleError::DuplicateName.into());
+        }
+
+        Ok(())
+    }
+
+    /// 按信号类型匹配启用的规则，达到阈值的规则发送告警
+    async fn handle_signal(signal: &SecuritySignal) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let rules = SysAlertRule::find()
+            .filter(SysAlertRuleColumn::SignalKind.eq(signal.kind.clone()))
+            .filter(SysAlertRuleColumn::Enabled.eq(true))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        for rule in rules {
+            let Some(count) = alert_helper::observe(&rule, &signal.subject) else {
+                continue;
+            };
+
+            record_audit(
+                AuditEntry::new("安全告警", format!("触发告警规则 {}", rule.name)).with_detail(
+                    json!({
+                        "ruleId": rule.id,
+                        "subject": signal.subject,
+                        "actor": signal.actor,
+                        "count": count,
+                    }),
+                ),
+            );
+
+            let rule_id = rule.id.clone();
+            if maintenance_helper::alerts_suppressed().await {
+                tracing::info!(
+                    target: "metrics",
+                    event = "security_alert_suppressed",
+                    rule = %rule.name,
+                    subject = %signal.subject,
+                );
+            } else {
+                // 通知可能因外部服务超时而耗时较长，不阻塞后续信号的计数
+                let signal = signal.clone();
+                tokio::spawn(async move {
+                    if let Err(e) = alert_helper::deliver(&rule, &signal, count).await {
+                        project_error!("Failed to deliver alert '{}': {:?}", rule.name, e);
+                    }
+                });
+            }
+
+            SysAlertRule::update_many()
+                .col_expr(
+                    SysAlertRuleColumn::LastTriggeredAt,
+                    Expr::value(Local::now().naive_local()),
+                )
+                .filter(SysAlertRuleColumn::Id.eq(rule_id))
+                .exec(db.as_ref())
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TAlertRuleService for SysAlertRuleService {
+    async fn find_paginated_rules(
+        &self,
+        params: AlertRulePageRequest,
+    ) -> Result<PageResult<SysAlertRuleModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysAlertRule::find();
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any().add(SysAlertRuleColumn::Name.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        query = query.order_by_desc(SysAlertRuleColumn::CreatedAt);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn create_rule(
+        &self,
+        input: CreateAlertRuleInput,
+        operator: &User,
+    ) -> Result<SysAlertRuleModel, AppError> {
+        self.check_rule(None, &input).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let rule = SysAlertRuleActiveModel {
+            id: Set(Ulid::new().to_string()),
+            name: Set(input.name),
+            signal_kind: Set(input.signal_kind),
+            threshold: Set(input.threshold),
+            window_seconds: Set(input.window_seconds),
+            cooldown_seconds: Set(input.cooldown_seconds),
+            channel: Set(input.channel),
+            target: Set(input.target),
+            enabled: Set(input.enabled),
+            ..Default::default()
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("安全告警", "创建告警规则")
+                .with_user(operator)
+                .with_detail(json!(rule)),
+        );
+        Ok(rule)
+    }
+
+    async fn get_rule(&self, id: &str) -> Result<SysAlertRuleModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysAlertRule::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AlertRuleError::RuleNotFound.into())
+    }
+
+    async fn update_rule(
+        &self,
+        input: UpdateAlertRuleInput,
+        operator: &User,
+    ) -> Result<SysAlertRuleModel, AppError> {
+        let existing_rule = self.get_rule(&input.id).await?;
+        self.check_rule(Some(&input.id), &input.rule).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut rule: SysAlertRuleActiveModel = existing_rule.into();
+        rule.name = Set(input.rule.name);
+        rule.signal_kind = Set(input.rule.signal_kind);
+        rule.threshold = Set(input.rule.threshold);
+        rule.window_seconds = Set(input.rule.window_seconds);
+        rule.cooldown_seconds = Set(input.rule.cooldown_seconds);
+        rule.channel = Set(input.rule.channel);
+        rule.target = Set(input.rule.target);
+        rule.enabled = Set(input.rule.enabled);
+
+        let updated_rule = rule.update(db.as_ref()).await.map_err(AppError::from)?;
+        alert_helper::forget_rule(&updated_rule.id);
+
+        record_audit(
+            AuditEntry::new("安全告警", "更新告警规则")
+                .with_user(operator)
+                .with_detail(json!(updated_rule)),
+        );
+        Ok(updated_rule)
+    }
+
+    async fn delete_rule(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        let rule = self.get_rule(id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysAlertRule::delete_by_id(id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        alert_helper::forget_rule(id);
+
+        record_audit(
+            AuditEntry::new("安全告警", "删除告警规则")
+                .with_user(operator)
+                .with_detail(json!({ "id": rule.id, "name": rule.name })),
+        );
+        Ok(())
+    }
+}
+
+#[instrument(skip(rx))]
+pub async fn security_alert_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<TracedEvent>) {
+    while let Some(event) = rx.recv().await {
+        if let Some(signal) = event.downcast_ref::<SecuritySignal>() {
+            let result = event
+                .context
+                .scope("security_alert", SysAlertRuleService::handle_signal(signal))
+                .await;
+            if let Err(e) = result {
+                project_error!("Failed to handle security signal: {:?}", e);
+            }
+        } else {
+            project_error!("Received unknown event type in security alert listener");
+        }
+    }
+}
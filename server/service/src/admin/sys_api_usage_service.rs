@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDateTime};
+use sea_orm::{
+    sea_query::{Alias, Expr, OnConflict},
+    ActiveEnum, ColumnTrait, Condition, EntityTrait, Order, PaginatorTrait, QueryFilter,
+    QueryOrder, QuerySelect, Set,
+};
+use server_config::ApiUsageConfig;
+use server_core::web::{error::AppError, page::PageResult, usage::ApiUsageEvent};
+use server_global::{
+    global::{self, TracedEvent},
+    project_error,
+};
+use server_model::admin::{
+    entities::{
+        prelude::SysApiUsage,
+        sea_orm_active_enums::ApiUsageSubjectKind,
+        sys_api_usage::{
+            ActiveModel as SysApiUsageActiveModel, Column as SysApiUsageColumn,
+            Model as SysApiUsageModel,
+        },
+    },
+    input::{ApiUsagePageRequest, ApiUsageTrendRequest},
+    output::ApiUsageSummary,
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use crate::helper::{
+    api_usage_helper::{self, UsageCount, UsageKey},
+    db_helper,
+};
+
+/// 未指定时间范围时查询的天数
+const DEFAULT_RANGE_DAYS: i64 = 7;
+
+#[async_trait]
+pub trait TApiUsageService {
+    async fn find_paginated_usage(
+        &self,
+        params: ApiUsagePageRequest,
+    ) -> Result<PageResult<ApiUsageSummary>, AppError>;
+    async fn get_trend(
+        &self,
+        params: ApiUsageTrendRequest,
+    ) -> Result<Vec<SysApiUsageModel>, AppError>;
+    /// 当前小时尚未汇总的实时计数
+    async fn get_current_usage(&self) -> Result<Vec<ApiUsageSummary>, AppError>;
+
+    /// 把已结束小时的计数从 Redis 汇总到数据库，返回写入的记录数
+    async fn rollup(&self) -> Result<usize, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysApiUsageService;
+
+fn time_range(
+    start: Option<NaiveDateTime>,
+    end: Option<NaiveDateTime>,
+) -> (NaiveDateTime, NaiveDateTime) {
+    let end = end.unwrap_or_else(|| Local::now().naive_local());
+    let start = start.unwrap_or(end - Duration::days(DEFAULT_RANGE_DAYS));
+    (start, end)
+}
+
+fn subject_kind(kind: &str) -> Option<ApiUsageSubjectKind> {
+    ApiUsageSubjectKind::try_from_value(&kind.to_string()).ok()
+}
+
+impl SysApiUsageService {
+    async fn save_bucket(
+        bucket: NaiveDateTime,
+        counts: &HashMap<UsageKey, UsageCount>,
+    ) -> Result<(), AppError> {
+        let now = Local::now().naive_local();
+        let rows = counts
+            .iter()
+            .filter_map(|((kind, subject), count)| {
+                Some(SysApiUsageActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    bucket_start: Set(bucket),
+                    subject_kind: Set(subject_kind(kind)?),
+                    subject: Set(subject.clone()),
+                    label: Set(count.label.clone()),
+                    requests: Set(count.requests),
+                    errors: Set(count.errors),
+                    updated_at: Set(now),
+                })
+            })
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Ok(());
+        }
+
+        // 重试或迟到的计数累加到已有记录
+        let db = db_helper::get_db_connection().await?;
+        SysApiUsage::insert_many(rows)
+            .on_conflict(
+                OnConflict::columns([
+                    SysApiUsageColumn::BucketStart,
+                    SysApiUsageColumn::SubjectKind,
+                    SysApiUsageColumn::Subject,
+                ])
+                .value(
+                    SysApiUsageColumn::Requests,
+                    Expr::cust("sys_api_usage.requests + excluded.requests"),
+                )
+                .value(
+                    SysApiUsageColumn::Errors,
+                    Expr::cust("sys_api_usage.errors + excluded.errors"),
+                )
+                .update_columns([SysApiUsageColumn::Label, SysApiUsageColumn::UpdatedAt])
+                .to_owned(),
+            )
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TApiUsageService for SysApiUsageService {
+    async fn find_paginated_usage(
+        &self,
+        params: ApiUsagePageRequest,
+    ) -> Result<PageResult<ApiUsageSummary>, AppError> {
+        let db = db_helper::get_read_connection().await?;
+        let (start, end) = time_range(params.start, params.end);
+
+        let mut query = SysApiUsage::find()
+            .select_only()
+            .column(SysApiUsageColumn::SubjectKind)
+            .column(SysApiUsageColumn::Subject)
+            .column_as(Expr::col(SysApiUsageColumn::Label).max(), "label")
+            .column_as(
+                Expr::col(SysApiUsageColumn::Requests)
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "requests",
+            )
+            .column_as(
+                Expr::col(SysApiUsageColumn::Errors)
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "errors",
+            )
+            .filter(SysApiUsageColumn::BucketStart.gte(start))
+            .filter(SysApiUsageColumn::BucketStart.lt(end))
+            .group_by(SysApiUsageColumn::SubjectKind)
+            .group_by(SysApiUsageColumn::Subject);
+
+        if let Some(kind) = params.subject_kind {
+            query = query.filter(SysApiUsageColumn::SubjectKind.eq(kind));
+        }
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any()
+                .add(SysApiUsageColumn::Subject.contains(keywords))
+                .add(SysApiUsageColumn::Label.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        let query = query
+            .order_by(Expr::col(SysApiUsageColumn::Requests).sum(), Order::Desc)
+            .into_tuple::<(ApiUsageSubjectKind, String, String, i64, i64)>();
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let total = paginator.num_items().await.map_err(AppError::from)?;
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|(kind, subject, label, requests, errors)| {
+                ApiUsageSummary::new(kind, subject, label, requests, errors)
+            })
+            .collect();
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn get_trend(
+        &self,
+        params: ApiUsageTrendRequest,
+    ) -> Result<Vec<SysApiUsageModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let (start, end) = time_range(params.start, params.end);
+        SysApiUsage::find()
+            .filter(SysApiUsageColumn::SubjectKind.eq(params.subject_kind))
+            .filter(SysApiUsageColumn::Subject.eq(params.subject))
+            .filter(SysApiUsageColumn::BucketStart.gte(start))
+            .filter(SysApiUsageColumn::BucketStart.lt(end))
+            .order_by_asc(SysApiUsageColumn::BucketStart)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn get_current_usage(&self) -> Result<Vec<ApiUsageSummary>, AppError> {
+        let mut usage = api_usage_helper::current_usage()
+            .await?
+            .into_iter()
+            .filter_map(|((kind, subject), count)| {
+                Some(ApiUsageSummary::new(
+                    subject_kind(&kind)?,
+                    subject,
+                    count.label,
+                    count.requests,
+                    count.errors,
+                ))
+            })
+            .collect::<Vec<_>>();
+        usage.sort_by_key(|item| std::cmp::Reverse(item.requests));
+        Ok(usage)
+    }
+
+    async fn rollup(&self) -> Result<usize, AppError> {
+        let config = global::get_config::<ApiUsageConfig>()
+            .await
+            .map(|config| (*config).clone())
+            .unwrap_or_default();
+        let now = Local::now().naive_local();
+
+        let mut written = 0;
+        for bucket in api_usage_helper::completed_buckets(now) {
+            let counts = api_usage_helper::take_bucket(bucket).await?;
+            if counts.is_empty() {
+                continue;
+            }
+            if let Err(e) = Self::save_bucket(bucket, &counts).await {
+                api_usage_helper::restore_bucket(bucket, &counts).await?;
+                return Err(e);
+            }
+            written += counts.len();
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        SysApiUsage::delete_many()
+            .filter(
+                SysApiUsageColumn::BucketStart
+                    .lt(now - Duration::days(i64::from(config.retention_days))),
+            )
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        if written > 0 {
+            tracing::info!(target: "metrics", event = "api_usage_rolled_up", count = written);
+        }
+        Ok(written)
+    }
+}
+
+#[instrument(skip(rx))]
+pub async fn api_usage_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<TracedEvent>) {
+    while let Some(event) = rx.recv().await {
+        if let Some(usage) = event.downcast_ref::<ApiUsageEvent>() {
+            if let Err(e) = api_usage_helper::record(usage).await {
+                project_error!("Failed to record api usage: {:?}", e);
+            }
+        } else {
+            project_error!("Received unknown event type in api usage listener");
+        }
+    }
+}
@@ -1,43 +1,61 @@
-use std::any::Any;
-
 use async_trait::async_trait;
+use chrono::Utc;
 use sea_orm::{
-    ColumnTrait, DatabaseConnection, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect,
-    RelationTrait,
+    sea_query::Expr, ColumnTrait, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait,
+};
+use serde_json::json;
+use server_config::JwtConfig;
+use server_constant::definition::{
+    consts::{SystemEvent, TokenStatus},
+    Audience,
 };
-use server_constant::definition::{consts::SystemEvent, Audience};
 use server_core::web::{
-    auth::Claims,
+    auth::{Claims, User},
     error::AppError,
-    jwt::{JwtError, JwtUtils},
+    jwt::{JwtError, JwtUtils, RefreshClaims},
 };
-use server_global::global;
+use server_global::global::{self, TracedEvent};
 use server_model::admin::{
     entities::{
-        prelude::{SysRole, SysUser},
-        sea_orm_active_enums::Status,
+        prelude::{SysRole, SysTokens, SysUser},
+        sea_orm_active_enums::{AlertSignalKind, Status},
         sys_domain::Column as SysDomainColumn,
         sys_menu::{Column as SysMenuColumn, Entity as SysMenuEntity, Model as SysMenuModel},
         sys_role::{Column as SysRoleColumn, Entity as SysRoleEntity, Relation as SysRoleRelation},
         sys_role_menu::{Column as SysRoleMenuColumn, Entity as SysRoleMenuEntity},
+        sys_tokens::Column as SysTokensColumn,
         sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
         sys_user_role::Relation as SysUserRoleRelation,
     },
-    input::LoginInput,
-    output::{AuthOutput, MenuRoute, RouteMeta, UserRoute, UserWithDomainAndOrgOutput},
+    input::{
+        FinishPasskeyInput, LoginInput, ReauthInput, RefreshTokenInput, StartPasskeyLoginInput,
+    },
+    output::{
+        AuthOutput, MenuRoute, PasskeyChallengeOutput, RouteMeta, UserRoute,
+        UserWithDomainAndOrgOutput,
+    },
 };
 use server_utils::{SecureUtil, TreeBuilder};
 use thiserror::Error;
 use tokio::sync::mpsc;
-use tracing::{error, instrument};
-use ulid::Ulid;
+use tracing::instrument;
 
 use super::{
     dto::sys_auth_dto::LoginContext, event_handlers::auth_event_handler::AuthEventHandler,
 };
 use crate::{
-    admin::{event_handlers::auth_event_handler::AuthEvent, sys_user_error::UserError},
-    helper::db_helper,
+    admin::{
+        event_handlers::auth_event_handler::AuthEvent, sys_passkey_error::PasskeyError,
+        sys_user_error::UserError,
+    },
+    helper::{
+        alert_helper::{self, SecuritySignal},
+        db_helper, login_throttle_helper,
+        passkey_helper::{self, ChallengeKind, LoginState, SecondFactorTicket},
+        siem_helper::{self, SiemCategory, SiemEvent, SiemOutcome},
+        tenant_helper,
+    },
     project_error, project_info,
 };
 
@@ -51,6 +69,7 @@ macro_rules! select_user_with_domain_and_org_info {
             .column_as(SysUserColumn::Password, "password")
             .column_as(SysUserColumn::NickName, "nick_name")
             .column_as(SysUserColumn::Avatar, "avatar")
+            .column_as(SysUserColumn::Status, "status")
             .column_as(SysDomainColumn::Code, "domain_code")
             .column_as(SysDomainColumn::Name, "domain_name")
     }};
@@ -76,6 +95,34 @@ pub trait TAuthService: Send + Sync {
         role_codes: &[String],
         domain: &str,
     ) -> Result<UserRoute, AppError>;
+
+    /// 重新验证当前用户的身份，签发认证时间为当前的新令牌
+    async fn reauthenticate(
+        &self,
+        user: &User,
+        input: ReauthInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError>;
+
+    /// 开始通行密钥登录，用于密码登录后的二次验证或免密登录
+    async fn start_passkey_login(
+        &self,
+        input: StartPasskeyLoginInput,
+        domain: &str,
+    ) -> Result<PasskeyChallengeOutput, AppError>;
+
+    async fn finish_passkey_login(
+        &self,
+        input: FinishPasskeyInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError>;
+
+    /// 用刷新令牌换发新的令牌对，旧的刷新令牌随即失效
+    async fn refresh_token(
+        &self,
+        input: RefreshTokenInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError>;
 }
 
 #[derive(Clone)]
@@ -107,10 +154,40 @@ impl TAuthService for SysAuthService {
         input: LoginInput,
         context: LoginContext,
     ) -> Result<AuthOutput, AppError> {
+        // 连续失败的账号或 IP 先延迟再验证，拖慢暴力破解
+        login_throttle_helper::delay_login(&context.domain, &input.identifier, &context.client_ip)
+            .await;
+
         // 验证用户并获取角色
-        let (user, role_codes) = self
+        let verified = self
             .verify_user(&input.identifier, &input.password, &context.domain)
+            .await;
+        track_login_result(
+            &input.identifier,
+            &context,
+            verified.as_ref().map(|(user, _)| user),
+        )
+        .await;
+        let (user, role_codes) = verified?;
+
+        // 已注册通行密钥的用户需完成二次验证，此时只返回票据
+        if passkey_helper::requires_second_factor(&user.id).await? {
+            let config = passkey_helper::passkey_config().await;
+            let ticket = passkey_helper::save_challenge(
+                &config,
+                ChallengeKind::Ticket,
+                &SecondFactorTicket {
+                    user_id: user.id.clone(),
+                    domain: context.domain.clone(),
+                },
+            )
             .await?;
+            return Ok(AuthOutput {
+                token: String::new(),
+                refresh_token: String::new(),
+                passkey_ticket: Some(ticket),
+            });
+        }
 
         // 生成认证输出
         let auth_output = generate_auth_output(
@@ -120,6 +197,7 @@ impl TAuthService for SysAuthService {
             user.domain_code.clone(),
             None,
             context.audience,
+            &["pwd"],
         )
         .await?;
 
@@ -142,7 +220,7 @@ impl TAuthService for SysAuthService {
             });
         }
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let menu_ids = SysRoleMenuEntity::find()
             .select_only()
@@ -155,7 +233,7 @@ impl TAuthService for SysAuthService {
             .filter(SysRoleMenuColumn::Domain.eq(domain))
             .distinct()
             .into_tuple::<i32>()
-            .all(db.as_ref())
+            .all(&db)
             .await?;
 
         let menus = SysMenuEntity::find()
@@ -163,7 +241,7 @@ impl TAuthService for SysAuthService {
             .filter(SysMenuColumn::Status.eq(Status::Enabled))
             .order_by_asc(SysMenuColumn::Sequence)
             .into_model::<SysMenuModel>()
-            .all(db.as_ref())
+            .all(&db)
             .await?;
 
         let menu_routes: Vec<MenuRoute> = menus
@@ -216,6 +294,201 @@ impl TAuthService for SysAuthService {
 
         Ok(UserRoute { routes, home })
     }
+
+    #[instrument(skip(self, user, input), fields(user_id = %user.user_id()))]
+    async fn reauthenticate(
+        &self,
+        user: &User,
+        input: ReauthInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError> {
+        // 与登录走同一流程，共享节流、二次验证和登录日志
+        let input = LoginInput {
+            identifier: user.username(),
+            password: input.password,
+        };
+        let context = LoginContext {
+            domain: user.domain(),
+            ..context
+        };
+        self.pwd_login(input, context).await
+    }
+
+    #[instrument(skip(self, input), fields(domain = %domain))]
+    async fn start_passkey_login(
+        &self,
+        input: StartPasskeyLoginInput,
+        domain: &str,
+    ) -> Result<PasskeyChallengeOutput, AppError> {
+        let config = passkey_helper::enabled_config().await?;
+
+        let second_factor = input.ticket.is_some();
+        let user_id = match (input.ticket, input.identifier) {
+            (Some(ticket), _) => {
+                let ticket: SecondFactorTicket =
+                    passkey_helper::take_challenge(ChallengeKind::Ticket, &ticket).await?;
+                if ticket.domain != domain {
+                    return Err(PasskeyError::ChallengeExpired.into());
+                }
+                ticket.user_id
+            },
+            (None, Some(identifier)) if config.passwordless => {
+                let db = db_helper::get_rls_connection().await?;
+                // 用户不存在与未注册通行密钥返回相同错误，避免枚举账号
+                SysUser::find()
+                    .filter(SysUserColumn::Username.eq(identifier))
+                    .filter(SysDomainColumn::Code.eq(domain))
+                    .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
+                    .one(&db)
+                    .await
+                    .map_err(AppError::from)?
+                    .ok_or(PasskeyError::NoCredentials)?
+                    .id
+            },
+            _ => return Err(PasskeyError::PasswordlessDisabled.into()),
+        };
+
+        let passkeys = passkey_helper::find_passkeys(&user_id)
+            .await?
+            .into_iter()
+            .map(|(_, passkey)| passkey)
+            .collect::<Vec<_>>();
+        if passkeys.is_empty() {
+            return Err(PasskeyError::NoCredentials.into());
+        }
+
+        let (options, authentication) = passkey_helper::webauthn(&config)?
+            .start_passkey_authentication(&passkeys)
+            .map_err(passkey_helper::verification_error)?;
+        let challenge_id = passkey_helper::save_challenge(
+            &config,
+            ChallengeKind::Login,
+            &LoginState {
+                user_id,
+                domain: domain.to_string(),
+                second_factor,
+                authentication,
+            },
+        )
+        .await?;
+
+        Ok(PasskeyChallengeOutput {
+            challenge_id,
+            options: json!(options),
+        })
+    }
+
+    #[instrument(skip(self, input), fields(domain = %context.domain))]
+    async fn finish_passkey_login(
+        &self,
+        input: FinishPasskeyInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError> {
+        let config = passkey_helper::enabled_config().await?;
+        let state: LoginState =
+            passkey_helper::take_challenge(ChallengeKind::Login, &input.challenge_id).await?;
+
+        let db = db_helper::get_rls_connection().await?;
+        let user = select_user_with_domain_and_org_info!(SysUser::find())
+            .filter(SysUserColumn::Id.eq(&state.user_id))
+            .filter(SysDomainColumn::Code.eq(&state.domain))
+            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
+            .into_model::<UserWithDomainAndOrgOutput>()
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(UserError::UserNotFound))?;
+
+        login_throttle_helper::delay_login(&context.domain, &user.username, &context.client_ip)
+            .await;
+        let verified =
+            passkey_helper::complete_authentication(&config, &state, input.credential).await;
+        track_login_result(&user.username, &context, verified.as_ref().map(|_| &user)).await;
+        verified?;
+        if user.status != Status::Enabled {
+            return Err(AppError::from(UserError::InvalidUserStatus));
+        }
+
+        let role_codes = self.get_user_roles(&user.id, &db).await?;
+        let auth_methods: &[&str] = if state.second_factor {
+            &["pwd", "passkey"]
+        } else {
+            &["passkey"]
+        };
+        let auth_output = generate_auth_output(
+            user.id.clone(),
+            user.username.clone(),
+            role_codes,
+            user.domain_code.clone(),
+            None,
+            context.audience,
+            auth_methods,
+        )
+        .await?;
+
+        self.send_login_event(&user, &auth_output, &context).await;
+
+        Ok(auth_output)
+    }
+
+    #[instrument(skip(self, input))]
+    async fn refresh_token(
+        &self,
+        input: RefreshTokenInput,
+        context: LoginContext,
+    ) -> Result<AuthOutput, AppError> {
+        let mut session =
+            JwtUtils::validate_refresh_token(&input.refresh_token, context.audience.as_str())
+                .await
+                .map_err(|_| AppError::from(UserError::InvalidRefreshToken))?;
+        let max_refresh_count = global::get_config::<JwtConfig>()
+            .await
+            .map(|config| config.max_refresh_count)
+            .unwrap_or_default();
+        if max_refresh_count > 0 && session.refresh_count >= max_refresh_count {
+            return Err(UserError::RefreshLimitExceeded.into());
+        }
+
+        // 刷新令牌只能使用一次：对应的令牌记录从活跃改为已刷新才继续，重放的令牌在这里被拒绝
+        let db = db_helper::get_rls_connection().await?;
+        let consumed = SysTokens::update_many()
+            .col_expr(
+                SysTokensColumn::Status,
+                Expr::value(TokenStatus::Refreshed.to_string()),
+            )
+            .filter(SysTokensColumn::RefreshToken.eq(&input.refresh_token))
+            .filter(SysTokensColumn::Status.eq(TokenStatus::Active.to_string()))
+            .exec(&db)
+            .await
+            .map_err(AppError::from)?;
+        if consumed.rows_affected == 0 {
+            return Err(UserError::InvalidRefreshToken.into());
+        }
+
+        // 重新读取用户状态和角色，停用账号或调整角色在下次刷新时生效
+        let user = select_user_with_domain_and_org_info!(SysUser::find())
+            .filter(SysUserColumn::Id.eq(&session.sub))
+            .filter(SysDomainColumn::Code.eq(&session.domain))
+            .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
+            .into_model::<UserWithDomainAndOrgOutput>()
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| AppError::from(UserError::UserNotFound))?;
+        if user.status != Status::Enabled {
+            return Err(AppError::from(UserError::InvalidUserStatus));
+        }
+        tenant_helper::ensure_active(&user.domain).await?;
+
+        let role_codes = self.get_user_roles(&user.id, &db).await?;
+        session.refresh_count += 1;
+        let auth_output =
+            issue_token_pair(&session, user.username.clone(), role_codes, None).await?;
+
+        self.send_login_event(&user, &auth_output, &context).await;
+
+        Ok(auth_output)
+    }
 }
 
 impl SysAuthService {
@@ -226,20 +499,18 @@ impl SysAuthService {
         password: &str,
         domain: &str,
     ) -> Result<(UserWithDomainAndOrgOutput, Vec<String>), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let user = select_user_with_domain_and_org_info!(SysUser::find())
             .filter(SysUserColumn::Username.eq(identifier))
             .filter(SysDomainColumn::Code.eq(domain))
             .join(JoinType::InnerJoin, SysUserRelation::SysDomain.def())
             .into_model::<UserWithDomainAndOrgOutput>()
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?
             .ok_or_else(|| AppError::from(UserError::UserNotFound))?;
 
-        //TODO validate user status and domain status
-
         // 验证密码
         if !SecureUtil::verify_password(password.as_bytes(), &user.password)
             .map_err(|_| AppError::from(UserError::AuthenticationFailed))?
@@ -247,6 +518,12 @@ impl SysAuthService {
             return Err(AppError::from(UserError::WrongPassword));
         }
 
+        // 密码正确后再校验状态，避免通过状态差异探测账号
+        if user.status != Status::Enabled {
+            return Err(AppError::from(UserError::InvalidUserStatus));
+        }
+        tenant_helper::ensure_active(&user.domain).await?;
+
         // 获取角色
         let role_codes = self.get_user_roles(&user.id, &db).await?;
 
@@ -254,10 +531,10 @@ impl SysAuthService {
     }
 
     /// 获取用户角色
-    async fn get_user_roles(
+    async fn get_user_roles<C: ConnectionTrait>(
         &self,
         user_id: &str,
-        db: &DatabaseConnection,
+        db: &C,
     ) -> Result<Vec<String>, AppError> {
         SysRole::find()
             .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
@@ -321,6 +598,54 @@ impl SysAuthService {
     }
 }
 
+/// 记录登录结果：推送 SIEM，成功时清除失败计数，失败时累加计数并发送安全信号
+async fn track_login_result(
+    identifier: &str,
+    context: &LoginContext,
+    result: Result<&UserWithDomainAndOrgOutput, &AppError>,
+) {
+    export_login_event(identifier, context, result);
+    if result.is_ok() {
+        login_throttle_helper::reset_login_failures(&context.domain, identifier).await;
+    } else {
+        login_throttle_helper::record_login_failure(
+            &context.domain,
+            identifier,
+            &context.client_ip,
+        )
+        .await;
+        alert_helper::emit(
+            SecuritySignal::new(AlertSignalKind::LoginFailed, identifier)
+                .with_domain(&context.domain)
+                .with_detail(json!({ "ip": context.client_ip })),
+        );
+    }
+}
+
+/// 登录结果推送到 SIEM，失败时附带原因
+fn export_login_event(
+    identifier: &str,
+    context: &LoginContext,
+    result: Result<&UserWithDomainAndOrgOutput, &AppError>,
+) {
+    let outcome = match result {
+        Ok(_) => SiemOutcome::Success,
+        Err(_) => SiemOutcome::Failure,
+    };
+    siem_helper::export(SiemEvent {
+        user_id: result.ok().map(|user| user.id.clone()),
+        username: Some(identifier.to_string()),
+        domain: Some(context.domain.clone()),
+        source_ip: Some(context.client_ip.clone()),
+        request_id: Some(context.request_id.clone()),
+        detail: Some(json!({
+            "loginType": context.login_type,
+            "reason": result.err().map(|e| e.message().to_string()),
+        })),
+        ..SiemEvent::new(SiemCategory::Auth, "login", outcome)
+    });
+}
+
 #[allow(dead_code)]
 #[instrument(skip(sender, auth_event))]
 async fn send_auth_event(
@@ -340,31 +665,56 @@ pub async fn generate_auth_output(
     domain_code: String,
     organization_name: Option<String>,
     audience: Audience,
+    auth_methods: &[&str],
 ) -> Result<AuthOutput, JwtError> {
-    let claims = Claims::new(
+    let session = RefreshClaims::new(
         user_id,
         audience.as_str().to_string(),
+        domain_code,
+        Utc::now().timestamp() as usize,
+        auth_methods.iter().map(ToString::to_string).collect(),
+        0,
+    );
+    issue_token_pair(&session, username, role_codes, organization_name).await
+}
+
+/// 按登录会话签发访问令牌和刷新令牌，访问令牌沿用会话的认证时间和认证方式
+async fn issue_token_pair(
+    session: &RefreshClaims,
+    username: String,
+    role_codes: Vec<String>,
+    organization_name: Option<String>,
+) -> Result<AuthOutput, JwtError> {
+    let mut claims = Claims::new(
+        session.sub.clone(),
+        session.aud.clone(),
         username,
         role_codes,
-        domain_code,
+        session.domain.clone(),
         organization_name,
     );
+    claims.set_amr(session.amr.clone());
+    claims.set_auth_time(session.auth_time);
 
     let token = JwtUtils::generate_token(&claims).await?;
+    let refresh_token = JwtUtils::generate_refresh_token(session).await?;
 
     Ok(AuthOutput {
         token,
-        refresh_token: Ulid::new().to_string(),
+        refresh_token,
+        passkey_ticket: None,
     })
 }
 
 #[instrument(skip(rx))]
-pub async fn auth_login_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
+pub async fn auth_login_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<TracedEvent>) {
     while let Some(event) = rx.recv().await {
         if let Some(auth_event) = event.downcast_ref::<AuthEvent>() {
-            if let Err(e) = handle_auth_event(auth_event).await {
+            let result = event
+                .context
+                .scope("auth_login", handle_auth_event(auth_event))
+                .await;
+            if let Err(e) = result {
                 project_error!("Failed to handle AuthEvent: {:?}", e);
             }
         }
@@ -397,3 +747,167 @@ pub async fn jwt_created_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<S
         // TODO: Consider storing the token into the database
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, sync::Arc};
+
+    use sea_orm::{DatabaseBackend, MockDatabase, MockExecResult, Value};
+    use server_global::{global::GLOBAL_PRIMARY_DB, Algorithm, Validation};
+    use server_model::admin::entities::{
+        sea_orm_active_enums::DataScope, sys_role::Model as SysRoleModel,
+        sys_tenant::Model as SysTenantModel,
+    };
+    use tokio::sync::Mutex;
+
+    use super::*;
+
+    async fn init_jwt() {
+        global::init_config::<JwtConfig>(JwtConfig {
+            algorithm: Default::default(),
+            jwt_secret: "secret".into(),
+            private_key_path: None,
+            private_key: None,
+            public_key_path: None,
+            public_key: None,
+            issuer: "soybean".to_string(),
+            expire: 3600,
+            refresh_expire: 604800,
+            refresh_secret: None,
+            max_refresh_count: 0,
+            audience: Vec::new(),
+            leeway_seconds: 60,
+            nbf_offset: 0,
+        })
+        .await;
+
+        // 与未配置 refresh_secret 时相同，两种令牌共用密钥
+        let keys = global::Keys::new(b"secret");
+        let _ = global::KEYS.set(Arc::new(Mutex::new(keys.clone())));
+        let _ = global::REFRESH_KEYS.set(Arc::new(Mutex::new(keys)));
+        let mut validation = Validation::new(Algorithm::HS256);
+        validation.set_issuer(&["soybean"]);
+        let _ = global::VALIDATION.set(Arc::new(Mutex::new(validation)));
+    }
+
+    fn login_context() -> LoginContext {
+        LoginContext {
+            client_ip: "127.0.0.1".to_string(),
+            client_port: None,
+            address: String::new(),
+            user_agent: String::new(),
+            request_id: String::new(),
+            audience: Audience::ManagementPlatform,
+            login_type: "refresh".to_string(),
+            domain: "built-in".to_string(),
+        }
+    }
+
+    fn user_row() -> BTreeMap<&'static str, Value> {
+        BTreeMap::from([
+            ("id", "user123".into()),
+            ("domain", "built-in".into()),
+            ("username", "alice".into()),
+            ("password", "".into()),
+            ("nick_name", "Alice".into()),
+            ("avatar", Value::String(None)),
+            ("status", "enabled".into()),
+            ("domain_code", "built-in".into()),
+            ("domain_name", "Built-in".into()),
+        ])
+    }
+
+    fn role(code: &str) -> SysRoleModel {
+        SysRoleModel {
+            id: code.to_lowercase(),
+            code: code.to_string(),
+            name: code.to_string(),
+            description: None,
+            pid: "0".to_string(),
+            status: Status::Enabled,
+            data_scope: DataScope::All,
+            scope_organization_id: None,
+            created_at: Utc::now().naive_utc(),
+            created_by: "system".to_string(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    fn refresh_input(token: &str) -> RefreshTokenInput {
+        RefreshTokenInput {
+            refresh_token: token.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_refresh_token_rotation() {
+        init_jwt().await;
+        let service = SysAuthService;
+        let login = generate_auth_output(
+            "user123".to_string(),
+            "alice".to_string(),
+            vec!["R_USER".to_string()],
+            "built-in".to_string(),
+            None,
+            Audience::ManagementPlatform,
+            &["pwd"],
+        )
+        .await
+        .unwrap();
+
+        // 访问令牌不能用来换发，此时还未访问数据库
+        assert!(service
+            .refresh_token(refresh_input(&login.token), login_context())
+            .await
+            .is_err());
+
+        // 第一次刷新消费令牌记录，第二次（重放）没有可消费的记录
+        let db = MockDatabase::new(DatabaseBackend::Postgres)
+            .append_exec_results([
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 1,
+                },
+                MockExecResult {
+                    last_insert_id: 0,
+                    rows_affected: 0,
+                },
+            ])
+            .append_query_results([vec![user_row()]])
+            .append_query_results([Vec::<SysTenantModel>::new()])
+            .append_query_results([vec![role("R_ADMIN")]])
+            .into_connection();
+        GLOBAL_PRIMARY_DB.write().await.replace(Arc::new(db));
+
+        let rotated = service
+            .refresh_token(refresh_input(&login.refresh_token), login_context())
+            .await
+            .unwrap();
+        assert_ne!(rotated.refresh_token, login.refresh_token);
+
+        let audience = Audience::ManagementPlatform.as_str();
+        let session = JwtUtils::validate_refresh_token(&rotated.refresh_token, audience)
+            .await
+            .unwrap();
+        let original = JwtUtils::validate_refresh_token(&login.refresh_token, audience)
+            .await
+            .unwrap();
+        assert_eq!(session.refresh_count, 1);
+        assert_eq!(session.auth_time, original.auth_time);
+        assert_eq!(session.amr, vec!["pwd".to_string()]);
+
+        // 新的访问令牌使用重新读取的角色，认证时间沿用原始登录
+        let claims = JwtUtils::validate_token(&rotated.token, audience)
+            .await
+            .unwrap()
+            .claims;
+        assert_eq!(User::from(claims.clone()).subject(), vec!["R_ADMIN"]);
+        assert_eq!(claims.auth_time(), Some(original.auth_time));
+
+        assert!(service
+            .refresh_token(refresh_input(&login.refresh_token), login_context())
+            .await
+            .is_err());
+    }
+}
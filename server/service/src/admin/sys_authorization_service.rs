@@ -1,11 +1,13 @@
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use axum_casbin::casbin::{CoreApi, MgmtApi, RbacApi};
-use sea_orm::{ColumnTrait, EntityTrait, QueryFilter, TransactionTrait};
+use axum_casbin::casbin::{CoreApi, RbacApi};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serde_json::json;
 use server_core::web::error::AppError;
 use server_model::admin::entities::{
     prelude::{SysDomain, SysEndpoint, SysMenu, SysRole, SysRoleMenu, SysUser, SysUserRole},
+    sea_orm_active_enums::AlertSignalKind,
     sys_domain::Column as SysDomainColumn,
     sys_endpoint::Column as SysEndpointColumn,
     sys_menu::Column as SysMenuColumn,
@@ -16,9 +18,13 @@ use server_model::admin::entities::{
 use thiserror::Error;
 use tokio::sync::RwLock;
 
-use crate::helper::db_helper;
+use crate::helper::{
+    alert_helper::{self, SecuritySignal},
+    db_helper,
+};
 
 #[derive(Error, Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AuthorizationError {
     #[error("Domain not found")]
     DomainNotFound,
@@ -34,10 +40,7 @@ pub enum AuthorizationError {
 
 impl From<AuthorizationError> for AppError {
     fn from(error: AuthorizationError) -> Self {
-        AppError {
-            code: 400,
-            message: error.to_string(),
-        }
+        AppError::not_found(error.to_string())
     }
 }
 
@@ -49,7 +52,7 @@ pub trait TAuthorizationService: Send + Sync {
         domain: String,
         role_id: String,
         permissions: Vec<String>,
-        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync>>,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
     ) -> Result<(), AppError>;
 
     /// 为角色分配路由
@@ -73,37 +76,37 @@ impl SysAuthorizationService {
         domain_code: &str,
         role_id: &str,
     ) -> Result<(String, String, String), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let domain = SysDomain::find()
             .filter(SysDomainColumn::Code.eq(domain_code))
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?;
 
-        let domain = domain.ok_or_else(|| AuthorizationError::DomainNotFound)?;
+        let domain = domain.ok_or(AuthorizationError::DomainNotFound)?;
 
         let role = SysRole::find()
             .filter(SysRoleColumn::Id.eq(role_id))
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?;
 
-        let role = role.ok_or_else(|| AuthorizationError::RoleNotFound)?;
+        let role = role.ok_or(AuthorizationError::RoleNotFound)?;
 
         Ok((domain.code, role_id.to_string(), role.code))
     }
 
     async fn check_role(&self, role_id: &str) -> Result<String, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let role = SysRole::find()
             .filter(SysRoleColumn::Id.eq(role_id))
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?;
 
-        let role = role.ok_or_else(|| AuthorizationError::RoleNotFound)?;
+        let role = role.ok_or(AuthorizationError::RoleNotFound)?;
 
         Ok(role.code)
     }
@@ -114,7 +117,7 @@ impl SysAuthorizationService {
         role_code: &str,
         domain: &str,
         new_permissions: Vec<server_model::admin::entities::sys_endpoint::Model>,
-        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync>>,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
     ) -> Result<(), AppError> {
         let mut enforcer_write = enforcer.write().await;
         let existing_permissions =
@@ -166,20 +169,23 @@ impl SysAuthorizationService {
             let _ = enforcer_write
                 .remove_policies(policies_to_remove)
                 .await
-                .map_err(|e| AppError {
-                    code: 500,
-                    message: e.to_string(),
-                })?;
+                .map_err(|e| AppError::internal(e.to_string()))?;
         }
 
         if !policies_to_add.is_empty() {
+            let granted: Vec<String> = policies_to_add
+                .iter()
+                .map(|policy| format!("{} {}", policy[3], policy[2]))
+                .collect();
             let _ = enforcer_write
                 .add_policies(policies_to_add)
                 .await
-                .map_err(|e| AppError {
-                    code: 500,
-                    message: e.to_string(),
-                })?;
+                .map_err(|e| AppError::internal(e.to_string()))?;
+            alert_helper::emit(
+                SecuritySignal::new(AlertSignalKind::PermissionEscalation, role_code)
+                    .with_domain(domain)
+                    .with_detail(json!({ "permissions": granted })),
+            );
         }
 
         Ok(())
@@ -193,14 +199,14 @@ impl TAuthorizationService for SysAuthorizationService {
         domain: String,
         role_id: String,
         permissions: Vec<String>,
-        enforcer: Arc<RwLock<impl CoreApi + MgmtApi + RbacApi + Send + Sync>>,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
     ) -> Result<(), AppError> {
         let (domain_code, _, role_code) = self.check_domain_and_role(&domain, &role_id).await?;
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let permissions = SysEndpoint::find()
             .filter(SysEndpointColumn::Id.is_in(permissions))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -222,10 +228,10 @@ impl TAuthorizationService for SysAuthorizationService {
     ) -> Result<(), AppError> {
         let (domain_code, role_id, _) = self.check_domain_and_role(&domain, &role_id).await?;
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let routes = SysMenu::find()
             .filter(SysMenuColumn::Id.is_in(route_ids.clone()))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -239,7 +245,7 @@ impl TAuthorizationService for SysAuthorizationService {
                     .eq(&role_id)
                     .and(SysRoleMenuColumn::Domain.eq(&domain_code)),
             )
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -266,7 +272,6 @@ impl TAuthorizationService for SysAuthorizationService {
                     role_id: sea_orm::Set(role_id.clone()),
                     menu_id: sea_orm::Set(*route_id),
                     domain: sea_orm::Set(domain_code.clone()),
-                    ..Default::default()
                 })
                 .collect();
 
@@ -295,12 +300,12 @@ impl TAuthorizationService for SysAuthorizationService {
     }
 
     async fn assign_users(&self, role_id: String, user_ids: Vec<String>) -> Result<(), AppError> {
-        let _ = self.check_role(&role_id).await?;
+        let role_code = self.check_role(&role_id).await?;
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let users = SysUser::find()
             .filter(server_model::admin::entities::sys_user::Column::Id.is_in(user_ids.clone()))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -310,7 +315,7 @@ impl TAuthorizationService for SysAuthorizationService {
 
         let existing_user_roles = SysUserRole::find()
             .filter(SysUserRoleColumn::RoleId.eq(&role_id))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -339,7 +344,6 @@ impl TAuthorizationService for SysAuthorizationService {
                 .map(|user_id| SysUserRoleActiveModel {
                     role_id: sea_orm::Set(role_id.clone()),
                     user_id: sea_orm::Set(user_id.clone()),
-                    ..Default::default()
                 })
                 .collect();
 
@@ -363,6 +367,13 @@ impl TAuthorizationService for SysAuthorizationService {
 
         txn.commit().await.map_err(AppError::from)?;
 
+        if !new_user_ids.is_empty() {
+            alert_helper::emit(
+                SecuritySignal::new(AlertSignalKind::PermissionEscalation, role_code)
+                    .with_detail(json!({ "users": new_user_ids })),
+            );
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,327 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use sea_orm::{ColumnTrait, EntityTrait, JoinType, QueryFilter, QuerySelect, RelationTrait};
+use serde_json::json;
+use server_config::{BreakGlassConfig, SecurityConfig};
+use server_core::web::{
+    auth::{Claims, User},
+    break_glass::{BreakGlassSessionChecker, BREAK_GLASS_AUTH_METHOD, BREAK_GLASS_SUBJECT_PREFIX},
+    error::AppError,
+    jwt::JwtUtils,
+};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::SysUser,
+        sea_orm_active_enums::{AlertSignalKind, NotificationPriority},
+        sys_role::Column as SysRoleColumn,
+        sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
+        sys_user_role::Relation as SysUserRoleRelation,
+    },
+    input::BreakGlassInput,
+    output::{BreakGlassOutput, BreakGlassSession},
+};
+use server_utils::SecureUtil;
+use ulid::Ulid;
+
+use super::dto::sys_auth_dto::LoginContext;
+use crate::{
+    admin::sys_break_glass_error::BreakGlassError,
+    helper::{
+        alert_helper::{self, SecuritySignal},
+        audit_helper::{record_audit, AuditEntry},
+        db_helper, login_throttle_helper,
+        notification_helper::{self, category, Notification},
+        redis_helper::{self, RedisSource},
+        siem_helper::{self, SiemCategory, SiemEvent, SiemOutcome},
+    },
+    project_error,
+};
+
+const SESSION_KEY_PREFIX: &str = "soybean:break_glass:session";
+
+/// 已使用的凭证，按凭证哈希的摘要记录，更换凭证后自动重新封存
+const USED_CREDENTIAL_KEY_PREFIX: &str = "soybean:break_glass:used";
+
+/// 登录节流使用的账号标识
+const THROTTLE_IDENTIFIER: &str = "break-glass";
+
+#[async_trait]
+pub trait TBreakGlassService {
+    /// 使用紧急凭证开启临时会话并通知管理员
+    async fn activate(
+        &self,
+        input: BreakGlassInput,
+        context: LoginContext,
+    ) -> Result<BreakGlassOutput, AppError>;
+    async fn get_session(&self, id: &str) -> Result<BreakGlassSession, AppError>;
+    /// 提前结束会话，会话令牌随即失效
+    async fn end_session(&self, id: &str, operator: &User) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysBreakGlassService;
+
+async fn break_glass_config() -> BreakGlassConfig {
+    global::get_config::<SecurityConfig>()
+        .await
+        .map(|config| config.break_glass.clone())
+        .unwrap_or_default()
+}
+
+fn session_key(id: &str) -> String {
+    format!("{}:{}", SESSION_KEY_PREFIX, id)
+}
+
+fn used_credential_key(config: &BreakGlassConfig) -> String {
+    let fingerprint = hex::encode(
+        ring::digest::digest(&ring::digest::SHA256, config.credential_hash.as_bytes()).as_ref(),
+    );
+    format!("{}:{}", USED_CREDENTIAL_KEY_PREFIX, fingerprint)
+}
+
+async fn load_session(id: &str) -> Result<Option<BreakGlassSession>, AppError> {
+    let value = redis_helper::query::<Option<String>>(
+        RedisSource::Primary,
+        redis::cmd("GET").arg(session_key(id)),
+    )
+    .await?;
+    Ok(value.and_then(|value| match serde_json::from_str(&value) {
+        Ok(session) => Some(session),
+        Err(e) => {
+            project_error!("Failed to deserialize break-glass session: {}", e);
+            None
+        },
+    }))
+}
+
+/// 持有通知角色的全部用户，不限组织
+async fn notify_recipients(roles: &[String]) -> Result<Vec<String>, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    SysUser::find()
+        .select_only()
+        .column(SysUserColumn::Id)
+        .join(JoinType::InnerJoin, SysUserRelation::SysUserRole.def())
+        .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+        .filter(SysRoleColumn::Code.is_in(roles.to_vec()))
+        .distinct()
+        .into_tuple::<String>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)
+}
+
+/// 通知管理员，高优先级通知不受静音和免打扰时段影响
+async fn notify_admins(roles: Vec<String>, session: BreakGlassSession) {
+    let recipients = match notify_recipients(&roles).await {
+        Ok(recipients) => recipients,
+        Err(e) => {
+            project_error!(
+                "Failed to load break-glass notification recipients: {}",
+                e.message()
+            );
+            return;
+        },
+    };
+    let title = format!("紧急凭证已被 {} 使用", session.operator);
+    let content = format!(
+        "{} 于 {} 从 {} 使用紧急凭证获得 {} 角色，会话将在 {} 到期。原因：{}。\
+         如非预期，请立即结束会话 {} 并更换凭证。",
+        session.operator,
+        session.started_at.format("%Y-%m-%d %H:%M:%S"),
+        session.client_ip,
+        session.role,
+        session.expires_at.format("%Y-%m-%d %H:%M:%S"),
+        session.reason,
+        session.id
+    );
+    for recipient in recipients {
+        let notification = Notification::new(
+            recipient,
+            category::SECURITY,
+            title.clone(),
+            content.clone(),
+        )
+        .with_priority(NotificationPriority::High);
+        if let Err(e) = notification_helper::notify(notification).await {
+            tracing::warn!(
+                session_id = %session.id,
+                error = %e,
+                "Failed to send break-glass notification"
+            );
+        }
+    }
+}
+
+fn export_activation(input: &BreakGlassInput, context: &LoginContext, outcome: SiemOutcome) {
+    siem_helper::export(SiemEvent {
+        username: Some(input.operator.clone()),
+        source_ip: Some(context.client_ip.clone()),
+        request_id: Some(context.request_id.clone()),
+        detail: Some(json!({ "reason": input.reason })),
+        ..SiemEvent::new(SiemCategory::Auth, "break_glass", outcome)
+    });
+}
+
+impl SysBreakGlassService {
+    async fn verify_credential(
+        &self,
+        config: &BreakGlassConfig,
+        input: &BreakGlassInput,
+        context: &LoginContext,
+    ) -> Result<(), AppError> {
+        login_throttle_helper::delay_login(&config.domain, THROTTLE_IDENTIFIER, &context.client_ip)
+            .await;
+
+        let valid =
+            SecureUtil::verify_password(input.credential.as_bytes(), &config.credential_hash)
+                .unwrap_or(false);
+        if !valid {
+            login_throttle_helper::record_login_failure(
+                &config.domain,
+                THROTTLE_IDENTIFIER,
+                &context.client_ip,
+            )
+            .await;
+            export_activation(input, context, SiemOutcome::Failure);
+            return Err(BreakGlassError::InvalidCredential.into());
+        }
+        login_throttle_helper::reset_login_failures(&config.domain, THROTTLE_IDENTIFIER).await;
+
+        // 只有第一个写入标记的请求可以使用凭证
+        let claimed = redis_helper::query::<Option<String>>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(used_credential_key(config))
+                .arg(&input.operator)
+                .arg("NX"),
+        )
+        .await?;
+        if claimed.is_none() {
+            export_activation(input, context, SiemOutcome::Failure);
+            return Err(BreakGlassError::CredentialUsed.into());
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TBreakGlassService for SysBreakGlassService {
+    async fn activate(
+        &self,
+        input: BreakGlassInput,
+        context: LoginContext,
+    ) -> Result<BreakGlassOutput, AppError> {
+        let config = break_glass_config().await;
+        if !config.enabled {
+            return Err(BreakGlassError::NotEnabled.into());
+        }
+        self.verify_credential(&config, &input, &context).await?;
+
+        let now = Local::now().naive_local();
+        let session = BreakGlassSession {
+            id: Ulid::new().to_string(),
+            operator: input.operator.clone(),
+            reason: input.reason.clone(),
+            role: config.role.clone(),
+            domain: config.domain.clone(),
+            client_ip: context.client_ip.clone(),
+            started_at: now,
+            expires_at: now + Duration::seconds(config.ttl as i64),
+        };
+        let value = serde_json::to_string(&session).map_err(|e| {
+            AppError::internal(format!("Failed to serialize break-glass session: {}", e))
+        })?;
+        redis_helper::query::<()>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(session_key(&session.id))
+                .arg(value)
+                .arg("EX")
+                .arg(config.ttl),
+        )
+        .await?;
+
+        let mut claims = Claims::new(
+            format!("{}{}", BREAK_GLASS_SUBJECT_PREFIX, session.id),
+            context.audience.as_str().to_string(),
+            format!("break-glass ({})", input.operator),
+            vec![config.role.clone()],
+            config.domain.clone(),
+            None,
+        );
+        claims.set_amr(vec![BREAK_GLASS_AUTH_METHOD.to_string()]);
+        let token = JwtUtils::generate_token_with_ttl(&claims, config.ttl as i64)
+            .await
+            .map_err(|e| AppError::internal(e.to_string()))?;
+
+        let detail = json!({
+            "sessionId": session.id,
+            "operator": session.operator,
+            "reason": session.reason,
+            "role": session.role,
+            "ip": session.client_ip,
+            "expiresAt": session.expires_at,
+        });
+        record_audit(
+            AuditEntry::new("紧急访问", "使用紧急凭证")
+                .with_user(&User::from(claims))
+                .with_request_id(context.request_id.clone())
+                .with_detail(detail.clone()),
+        );
+        export_activation(&input, &context, SiemOutcome::Success);
+        alert_helper::emit(
+            SecuritySignal::new(AlertSignalKind::BreakGlassUsed, &session.id)
+                .with_domain(&session.domain)
+                .with_detail(detail),
+        );
+        tracing::info!(
+            target: "metrics",
+            event = "break_glass_activated",
+            session_id = %session.id,
+        );
+        tokio::spawn(notify_admins(config.notify_roles, session.clone()));
+
+        Ok(BreakGlassOutput { token, session })
+    }
+
+    async fn get_session(&self, id: &str) -> Result<BreakGlassSession, AppError> {
+        load_session(id)
+            .await?
+            .ok_or_else(|| BreakGlassError::SessionNotFound.into())
+    }
+
+    async fn end_session(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        let session = self.get_session(id).await?;
+        let deleted = redis_helper::query::<i64>(
+            RedisSource::Primary,
+            redis::cmd("DEL").arg(session_key(id)),
+        )
+        .await?;
+        if deleted == 0 {
+            return Err(BreakGlassError::SessionNotFound.into());
+        }
+
+        record_audit(
+            AuditEntry::new("紧急访问", "结束紧急访问会话")
+                .with_user(operator)
+                .with_detail(json!({
+                    "sessionId": session.id,
+                    "operator": session.operator,
+                    "startedAt": session.started_at,
+                })),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BreakGlassSessionChecker for SysBreakGlassService {
+    async fn is_active(&self, session_id: &str) -> Result<bool, AppError> {
+        redis_helper::query::<bool>(
+            RedisSource::Primary,
+            redis::cmd("EXISTS").arg(session_key(session_id)),
+        )
+        .await
+    }
+}
@@ -0,0 +1,87 @@
+use async_trait::async_trait;
+use chrono::Local;
+use serde_json::json;
+use server_core::web::{
+    auth::User,
+    chaos::{self, FaultKind, FaultRule, FaultSpec, FaultTarget},
+    error::AppError,
+};
+use ulid::Ulid;
+
+use super::sys_chaos_error::ChaosError;
+use crate::helper::audit_helper::{record_audit, AuditEntry};
+
+/// 故障注入，用于在受控条件下验证超时、重试和降级策略
+#[async_trait]
+pub trait TChaosService {
+    async fn list_faults(&self) -> Result<Vec<FaultRule>, AppError>;
+    async fn inject(&self, spec: FaultSpec, operator: &User) -> Result<FaultRule, AppError>;
+    async fn remove(&self, id: &str, operator: &User) -> Result<(), AppError>;
+    /// 移除全部规则，返回移除的条数
+    async fn clear(&self, operator: &User) -> Result<usize, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysChaosService;
+
+#[async_trait]
+impl TChaosService for SysChaosService {
+    async fn list_faults(&self) -> Result<Vec<FaultRule>, AppError> {
+        Ok(chaos::list_faults())
+    }
+
+    async fn inject(&self, spec: FaultSpec, operator: &User) -> Result<FaultRule, AppError> {
+        if let FaultKind::Error { status } = spec.fault {
+            if !(400..=599).contains(&status) {
+                return Err(ChaosError::InvalidStatus.into());
+            }
+        }
+        if spec.route_prefix.is_some() && spec.target != FaultTarget::Http {
+            return Err(ChaosError::RoutePrefixNotSupported.into());
+        }
+
+        let now = Local::now().naive_local();
+        let rule = FaultRule {
+            id: Ulid::new().to_string(),
+            target: spec.target,
+            fault: spec.fault,
+            percentage: spec.percentage,
+            route_prefix: spec.route_prefix,
+            created_by: operator.username(),
+            created_at: now,
+            expires_at: now + chrono::Duration::seconds(spec.duration_seconds as i64),
+            hits: 0,
+        };
+        chaos::add_fault(rule.clone());
+
+        record_audit(
+            AuditEntry::new("故障注入", "注入故障")
+                .with_user(operator)
+                .with_detail(json!(rule)),
+        );
+        Ok(rule)
+    }
+
+    async fn remove(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        if !chaos::remove_fault(id) {
+            return Err(ChaosError::FaultNotFound.into());
+        }
+
+        record_audit(
+            AuditEntry::new("故障注入", "移除故障")
+                .with_user(operator)
+                .with_detail(json!({ "id": id })),
+        );
+        Ok(())
+    }
+
+    async fn clear(&self, operator: &User) -> Result<usize, AppError> {
+        let cleared = chaos::clear_faults();
+        record_audit(
+            AuditEntry::new("故障注入", "清除全部故障")
+                .with_user(operator)
+                .with_detail(json!({ "cleared": cleared })),
+        );
+        Ok(cleared)
+    }
+}
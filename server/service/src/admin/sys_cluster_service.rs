@@ -0,0 +1,279 @@
+use std::sync::{
+    atomic::{AtomicBool, Ordering},
+    OnceLock,
+};
+
+use async_trait::async_trait;
+use chrono::{Local, Utc};
+use redis::Script;
+use server_config::{ClusterConfig, ServerRole};
+use server_core::web::error::AppError;
+use server_global::global;
+use server_model::admin::output::ClusterMember;
+use ulid::Ulid;
+
+use crate::{
+    helper::redis_helper::{self, RedisSource},
+    project_info,
+};
+
+/// 续约或抢占主节点租约，成功返回 1
+const ACQUIRE_LEADER_SCRIPT: &str = r#"
+local current = redis.call('GET', KEYS[1])
+if current == ARGV[1] then
+    redis.call('PEXPIRE', KEYS[1], ARGV[2])
+    return 1
+end
+if not current then
+    redis.call('SET', KEYS[1], ARGV[1], 'PX', ARGV[2])
+    return 1
+end
+return 0
+"#;
+
+/// 仅当租约属于自己时释放
+const RELEASE_LEADER_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
+
+static LOCAL_MEMBER: OnceLock<ClusterMember> = OnceLock::new();
+static IS_LEADER: AtomicBool = AtomicBool::new(false);
+
+/// 当前实例是否为主节点，单例任务执行前应先检查
+///
+/// 未配置 Redis 时按单节点处理，始终为主节点
+pub fn is_cluster_leader() -> bool {
+    IS_LEADER.load(Ordering::Relaxed)
+}
+
+#[async_trait]
+pub trait TClusterService {
+    /// 上报本实例心跳并参与主节点选举
+    async fn heartbeat(&self) -> Result<(), AppError>;
+    /// 获取在线的集群成员
+    async fn list_members(&self) -> Result<Vec<ClusterMember>, AppError>;
+    /// 注销本实例，主节点同时释放租约
+    async fn deregister(&self) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysClusterService;
+
+struct ClusterKeys {
+    members: String,
+    member_info: String,
+    leader: String,
+}
+
+impl SysClusterService {
+    /// 注册本实例信息，进程启动时调用一次
+    pub fn register_local_instance(host: String, role: ServerRole) -> &'static ClusterMember {
+        LOCAL_MEMBER.get_or_init(|| {
+            let now = Local::now().naive_local();
+            ClusterMember {
+                instance_id: Ulid::new().to_string(),
+                host,
+                role: role.as_str().to_string(),
+                started_at: now,
+                last_heartbeat: now,
+                leader: false,
+            }
+        })
+    }
+
+    fn local_member() -> Result<&'static ClusterMember, AppError> {
+        LOCAL_MEMBER
+            .get()
+            .ok_or_else(|| AppError::internal("Local cluster instance not registered"))
+    }
+
+    async fn cluster_config() -> ClusterConfig {
+        global::get_config::<ClusterConfig>()
+            .await
+            .map(|config| (*config).clone())
+            .unwrap_or_default()
+    }
+
+    fn keys(config: &ClusterConfig) -> ClusterKeys {
+        ClusterKeys {
+            members: format!("{}:members", config.key_prefix),
+            member_info: format!("{}:member-info", config.key_prefix),
+            leader: format!("{}:leader", config.key_prefix),
+        }
+    }
+
+    /// 上报心跳并续约主节点租约，返回本实例是否为主节点
+    async fn renew_membership(local: &ClusterMember) -> Result<bool, AppError> {
+        let config = Self::cluster_config().await;
+        let keys = Self::keys(&config);
+        let member = ClusterMember {
+            last_heartbeat: Local::now().naive_local(),
+            ..local.clone()
+        };
+        let payload =
+            serde_json::to_string(&member).map_err(|e| AppError::internal(e.to_string()))?;
+        let now_ms = Utc::now().timestamp_millis();
+
+        let mut pipeline = redis::pipe();
+        pipeline
+            .cmd("ZADD")
+            .arg(&keys.members)
+            .arg(now_ms)
+            .arg(&member.instance_id)
+            .ignore()
+            .cmd("HSET")
+            .arg(&keys.member_info)
+            .arg(&member.instance_id)
+            .arg(payload)
+            .ignore();
+        redis_helper::query_pipeline::<()>(RedisSource::Primary, &pipeline).await?;
+
+        let script = Script::new(ACQUIRE_LEADER_SCRIPT);
+        let mut invocation = script.key(&keys.leader);
+        invocation
+            .arg(&member.instance_id)
+            .arg(config.leader_ttl * 1000);
+        let acquired: i32 = redis_helper::invoke_script(RedisSource::Primary, &invocation).await?;
+
+        let is_leader = acquired == 1;
+        if is_leader {
+            Self::prune_members(&keys, now_ms - (config.member_ttl * 1000) as i64).await?;
+        }
+        Ok(is_leader)
+    }
+
+    /// 清理心跳超时的成员，仅由主节点执行
+    async fn prune_members(keys: &ClusterKeys, expired_before: i64) -> Result<(), AppError> {
+        let stale: Vec<String> = redis_helper::query(
+            RedisSource::Primary,
+            redis::cmd("ZRANGEBYSCORE")
+                .arg(&keys.members)
+                .arg("-inf")
+                .arg(expired_before),
+        )
+        .await?;
+        if stale.is_empty() {
+            return Ok(());
+        }
+
+        let mut pipeline = redis::pipe();
+        pipeline
+            .cmd("ZREM")
+            .arg(&keys.members)
+            .arg(&stale)
+            .ignore()
+            .cmd("HDEL")
+            .arg(&keys.member_info)
+            .arg(&stale)
+            .ignore();
+        redis_helper::query_pipeline::<()>(RedisSource::Primary, &pipeline).await
+    }
+}
+
+#[async_trait]
+impl TClusterService for SysClusterService {
+    async fn heartbeat(&self) -> Result<(), AppError> {
+        let local = Self::local_member()?;
+        if !redis_helper::is_available(RedisSource::Primary).await {
+            IS_LEADER.store(true, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        // 心跳失败时无法确认租约仍然有效，主动放弃主节点身份
+        let is_leader = match Self::renew_membership(local).await {
+            Ok(is_leader) => is_leader,
+            Err(e) => {
+                IS_LEADER.store(false, Ordering::Relaxed);
+                return Err(e);
+            },
+        };
+
+        if is_leader != IS_LEADER.swap(is_leader, Ordering::Relaxed) {
+            project_info!(
+                "Cluster leadership changed, instance {} leader: {}",
+                local.instance_id,
+                is_leader
+            );
+        }
+        Ok(())
+    }
+
+    async fn list_members(&self) -> Result<Vec<ClusterMember>, AppError> {
+        let local = Self::local_member()?;
+        if !redis_helper::is_available(RedisSource::Primary).await {
+            return Ok(vec![ClusterMember {
+                last_heartbeat: Local::now().naive_local(),
+                leader: true,
+                ..local.clone()
+            }]);
+        }
+
+        let config = Self::cluster_config().await;
+        let keys = Self::keys(&config);
+        let alive_since = Utc::now().timestamp_millis() - (config.member_ttl * 1000) as i64;
+
+        let ids: Vec<String> = redis_helper::query(
+            RedisSource::Primary,
+            redis::cmd("ZRANGEBYSCORE")
+                .arg(&keys.members)
+                .arg(alive_since)
+                .arg("+inf"),
+        )
+        .await?;
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let payloads: Vec<Option<String>> = redis_helper::query(
+            RedisSource::Primary,
+            redis::cmd("HMGET").arg(&keys.member_info).arg(&ids),
+        )
+        .await?;
+        let leader_id: Option<String> =
+            redis_helper::query(RedisSource::Primary, redis::cmd("GET").arg(&keys.leader)).await?;
+
+        let mut members: Vec<ClusterMember> = payloads
+            .into_iter()
+            .flatten()
+            .filter_map(|payload| serde_json::from_str::<ClusterMember>(&payload).ok())
+            .map(|mut member| {
+                member.leader = leader_id.as_deref() == Some(member.instance_id.as_str());
+                member
+            })
+            .collect();
+        members.sort_by_key(|a| a.started_at);
+        Ok(members)
+    }
+
+    async fn deregister(&self) -> Result<(), AppError> {
+        let local = Self::local_member()?;
+        IS_LEADER.store(false, Ordering::Relaxed);
+        if !redis_helper::is_available(RedisSource::Primary).await {
+            return Ok(());
+        }
+
+        let config = Self::cluster_config().await;
+        let keys = Self::keys(&config);
+
+        let mut pipeline = redis::pipe();
+        pipeline
+            .cmd("ZREM")
+            .arg(&keys.members)
+            .arg(&local.instance_id)
+            .ignore()
+            .cmd("HDEL")
+            .arg(&keys.member_info)
+            .arg(&local.instance_id)
+            .ignore();
+        redis_helper::query_pipeline::<()>(RedisSource::Primary, &pipeline).await?;
+
+        let script = Script::new(RELEASE_LEADER_SCRIPT);
+        let mut invocation = script.key(&keys.leader);
+        invocation.arg(&local.instance_id);
+        redis_helper::invoke_script::<i32>(RedisSource::Primary, &invocation).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,123 @@
+use async_trait::async_trait;
+use serde_json::json;
+use server_config::{ConfigSubsystem, StagedConfigStatus};
+use server_core::web::{auth::User, error::AppError};
+use server_model::admin::input::{CanaryConfigInput, StageConfigInput};
+
+use super::{sys_config_error::ConfigStagingError, SysDbPoolService};
+use crate::helper::audit_helper::{record_audit, AuditEntry};
+
+const AUDIT_MODULE: &str = "配置发布";
+
+/// 配置灰度发布
+///
+/// 候选配置依次经过上传校验、灰度（或预演）、全量生效/回滚，
+/// 每次状态变更都写入审计日志
+#[async_trait]
+pub trait TConfigService {
+    /// 获取当前候选配置状态
+    async fn get_staged_config(&self) -> Result<Option<StagedConfigStatus>, AppError>;
+    /// 上传并校验候选配置
+    async fn stage_config(
+        &self,
+        input: StageConfigInput,
+        operator: &User,
+    ) -> Result<StagedConfigStatus, AppError>;
+    /// 将候选配置应用到部分子系统，或仅预演
+    async fn apply_canary(
+        &self,
+        input: CanaryConfigInput,
+        operator: &User,
+    ) -> Result<StagedConfigStatus, AppError>;
+    /// 全量生效候选配置
+    async fn promote_config(&self, operator: &User) -> Result<StagedConfigStatus, AppError>;
+    /// 回滚到候选配置上传前的配置
+    async fn rollback_config(&self, operator: &User) -> Result<StagedConfigStatus, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysConfigService;
+
+impl SysConfigService {
+    fn audit(operator: &User, description: &str, status: &StagedConfigStatus) {
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, description)
+                .with_user(operator)
+                .with_detail(json!(status)),
+        );
+    }
+}
+
+#[async_trait]
+impl TConfigService for SysConfigService {
+    async fn get_staged_config(&self) -> Result<Option<StagedConfigStatus>, AppError> {
+        Ok(server_config::staged_config_status().await)
+    }
+
+    async fn stage_config(
+        &self,
+        input: StageConfigInput,
+        operator: &User,
+    ) -> Result<StagedConfigStatus, AppError> {
+        let candidate = server_config::parse_config_str(&input.format, &input.content)
+            .map_err(ConfigStagingError::from)?;
+
+        let status = server_config::stage_config(candidate, "api")
+            .await
+            .map_err(ConfigStagingError::from)?;
+
+        Self::audit(operator, "上传候选配置", &status);
+        Ok(status)
+    }
+
+    async fn apply_canary(
+        &self,
+        input: CanaryConfigInput,
+        operator: &User,
+    ) -> Result<StagedConfigStatus, AppError> {
+        let subsystems = input
+            .subsystems
+            .iter()
+            .map(|name| name.parse::<ConfigSubsystem>())
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(ConfigStagingError::from)?;
+
+        let previous_pools = SysDbPoolService::pool_configs().await;
+        let status = server_config::apply_canary(&subsystems, input.dry_run)
+            .await
+            .map_err(ConfigStagingError::from)?;
+        if !input.dry_run {
+            SysDbPoolService::reconcile_pools(&previous_pools).await;
+        }
+
+        let description = if input.dry_run {
+            "预演灰度配置"
+        } else {
+            "灰度应用配置"
+        };
+        Self::audit(operator, description, &status);
+        Ok(status)
+    }
+
+    async fn promote_config(&self, operator: &User) -> Result<StagedConfigStatus, AppError> {
+        let previous_pools = SysDbPoolService::pool_configs().await;
+        let status = server_config::promote_staged_config()
+            .await
+            .map_err(ConfigStagingError::from)?;
+        SysDbPoolService::reconcile_pools(&previous_pools).await;
+
+        Self::audit(operator, "全量生效配置", &status);
+        Ok(status)
+    }
+
+    async fn rollback_config(&self, operator: &User) -> Result<StagedConfigStatus, AppError> {
+        let previous_pools = SysDbPoolService::pool_configs().await;
+        let status = server_config::rollback_staged_config()
+            .await
+            .map_err(ConfigStagingError::from)?;
+        SysDbPoolService::reconcile_pools(&previous_pools).await;
+
+        Self::audit(operator, "回滚配置", &status);
+        Ok(status)
+    }
+}
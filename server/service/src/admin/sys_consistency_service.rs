@@ -0,0 +1,325 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    ColumnTrait, Condition, ConnectionTrait, EntityTrait, JoinType, QueryFilter, QuerySelect,
+    RelationTrait,
+};
+use serde_json::json;
+use server_config::{ConsistencyCategory, ConsistencyConfig, StorageConfig};
+use server_core::web::{auth::User, error::AppError};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{SysRoleMenu, SysUser, SysUserRole},
+        sys_menu::Column as SysMenuColumn,
+        sys_role::Column as SysRoleColumn,
+        sys_role_menu::{Model as SysRoleMenuModel, Relation as SysRoleMenuRelation},
+        sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
+        sys_user_role::{Model as SysUserRoleModel, Relation as SysUserRoleRelation},
+    },
+    input::ConsistencyCheckInput,
+    output::{ConsistencyFinding, ConsistencyReport},
+};
+
+use crate::{
+    admin::SysFileService,
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper,
+        notification_helper::{self, category, Notification},
+    },
+    project_error,
+};
+
+/// 报告中每个类别最多列出的问题记录数
+const MAX_SAMPLES: usize = 20;
+
+#[async_trait]
+pub trait TConsistencyService {
+    /// 手动执行一致性检查，`repair` 为真时修复全部可以安全修复的类别
+    async fn run_check(
+        &self,
+        input: ConsistencyCheckInput,
+        operator: &User,
+    ) -> Result<ConsistencyReport, AppError>;
+    /// 定期检查，修复 `consistency.auto_repair` 中的类别，发现问题时通知管理员
+    async fn run_scheduled_check(&self) -> Result<ConsistencyReport, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysConsistencyService;
+
+/// 指向已删除用户或角色的用户角色关联
+async fn find_orphaned_user_roles<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<SysUserRoleModel>, AppError> {
+    SysUserRole::find()
+        .join(JoinType::LeftJoin, SysUserRoleRelation::SysUser.def())
+        .join(JoinType::LeftJoin, SysUserRoleRelation::SysRole.def())
+        .filter(
+            Condition::any()
+                .add(SysUserColumn::Id.is_null())
+                .add(SysRoleColumn::Id.is_null()),
+        )
+        .all(db)
+        .await
+        .map_err(AppError::from)
+}
+
+/// 指向已删除角色或菜单的角色菜单授权
+async fn find_orphaned_role_menus<C: ConnectionTrait>(
+    db: &C,
+) -> Result<Vec<SysRoleMenuModel>, AppError> {
+    SysRoleMenu::find()
+        .join(JoinType::LeftJoin, SysRoleMenuRelation::SysRole.def())
+        .join(JoinType::LeftJoin, SysRoleMenuRelation::SysMenu.def())
+        .filter(
+            Condition::any()
+                .add(SysRoleColumn::Id.is_null())
+                .add(SysMenuColumn::Id.is_null()),
+        )
+        .all(db)
+        .await
+        .map_err(AppError::from)
+}
+
+async fn delete_orphaned_user_roles(
+    db: &db_helper::RlsConnection,
+    rows: &[SysUserRoleModel],
+) -> Result<usize, AppError> {
+    let txn = db.begin().await.map_err(AppError::from)?;
+    let mut deleted = 0;
+    for row in rows {
+        deleted += SysUserRole::delete_by_id((row.user_id.clone(), row.role_id.clone()))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?
+            .rows_affected;
+    }
+    txn.commit().await.map_err(AppError::from)?;
+    Ok(deleted as usize)
+}
+
+async fn delete_orphaned_role_menus(
+    db: &db_helper::RlsConnection,
+    rows: &[SysRoleMenuModel],
+) -> Result<usize, AppError> {
+    let txn = db.begin().await.map_err(AppError::from)?;
+    let mut deleted = 0;
+    for row in rows {
+        deleted +=
+            SysRoleMenu::delete_by_id((row.role_id.clone(), row.menu_id, row.domain.clone()))
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?
+                .rows_affected;
+    }
+    txn.commit().await.map_err(AppError::from)?;
+    Ok(deleted as usize)
+}
+
+fn finding(
+    category: ConsistencyCategory,
+    items: Vec<String>,
+    repaired: usize,
+) -> ConsistencyFinding {
+    ConsistencyFinding {
+        category: category.as_str().to_string(),
+        count: items.len(),
+        samples: items.into_iter().take(MAX_SAMPLES).collect(),
+        repaired,
+        error: None,
+    }
+}
+
+fn failed(category: ConsistencyCategory, e: &AppError) -> ConsistencyFinding {
+    ConsistencyFinding {
+        category: category.as_str().to_string(),
+        count: 0,
+        samples: Vec::new(),
+        repaired: 0,
+        error: Some(e.message().to_string()),
+    }
+}
+
+impl SysConsistencyService {
+    /// 依次检查全部类别，单个类别失败不影响其余类别；未配置文件存储时跳过文件类别
+    async fn check(&self, repair: &[ConsistencyCategory]) -> Result<ConsistencyReport, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let mut findings = Vec::new();
+
+        let category = ConsistencyCategory::OrphanedUserRole;
+        findings.push(match find_orphaned_user_roles(&db).await {
+            Ok(rows) => {
+                let repaired = if repair.contains(&category) && !rows.is_empty() {
+                    delete_orphaned_user_roles(&db, &rows).await?
+                } else {
+                    0
+                };
+                let items = rows
+                    .iter()
+                    .map(|row| format!("user {} / role {}", row.user_id, row.role_id))
+                    .collect();
+                finding(category, items, repaired)
+            },
+            Err(e) => failed(category, &e),
+        });
+
+        let category = ConsistencyCategory::OrphanedRoleMenu;
+        findings.push(match find_orphaned_role_menus(&db).await {
+            Ok(rows) => {
+                let repaired = if repair.contains(&category) && !rows.is_empty() {
+                    delete_orphaned_role_menus(&db, &rows).await?
+                } else {
+                    0
+                };
+                let items = rows
+                    .iter()
+                    .map(|row| {
+                        format!(
+                            "role {} / menu {} @ {}",
+                            row.role_id, row.menu_id, row.domain
+                        )
+                    })
+                    .collect();
+                finding(category, items, repaired)
+            },
+            Err(e) => failed(category, &e),
+        });
+
+        if global::get_config::<StorageConfig>().await.is_some() {
+            match SysFileService.find_storage_mismatches().await {
+                Ok((missing, unreferenced)) => {
+                    findings.push(finding(ConsistencyCategory::MissingFileObject, missing, 0));
+                    findings.push(finding(
+                        ConsistencyCategory::UnreferencedFileObject,
+                        unreferenced,
+                        0,
+                    ));
+                },
+                Err(e) => {
+                    findings.push(failed(ConsistencyCategory::MissingFileObject, &e));
+                    findings.push(failed(ConsistencyCategory::UnreferencedFileObject, &e));
+                },
+            }
+        }
+
+        Ok(ConsistencyReport {
+            checked_at: Local::now().naive_local(),
+            findings,
+        })
+    }
+}
+
+fn audit_detail(report: &ConsistencyReport) -> serde_json::Value {
+    json!({
+        "checkedAt": report.checked_at,
+        "findings": report
+            .findings
+            .iter()
+            .map(|finding| json!({
+                "category": finding.category,
+                "count": finding.count,
+                "repaired": finding.repaired,
+                "error": finding.error,
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// 接收报告的用户
+async fn notify_recipients(roles: &[String]) -> Result<Vec<String>, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    SysUser::find()
+        .select_only()
+        .column(SysUserColumn::Id)
+        .join(JoinType::InnerJoin, SysUserRelation::SysUserRole.def())
+        .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+        .filter(SysRoleColumn::Code.is_in(roles.to_vec()))
+        .distinct()
+        .into_tuple::<String>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)
+}
+
+async fn notify_admins(roles: &[String], report: &ConsistencyReport) {
+    let recipients = match notify_recipients(roles).await {
+        Ok(recipients) => recipients,
+        Err(e) => {
+            project_error!(
+                "Failed to load consistency report recipients: {}",
+                e.message()
+            );
+            return;
+        },
+    };
+    let issues: usize = report.findings.iter().map(|finding| finding.count).sum();
+    let title = format!("数据一致性检查发现 {} 个问题", issues);
+    let content = report
+        .findings
+        .iter()
+        .filter(|finding| finding.count > 0 || finding.error.is_some())
+        .map(|finding| match &finding.error {
+            Some(error) => format!("{}：检查失败，{}", finding.category, error),
+            None => format!(
+                "{}：{} 个，已修复 {} 个",
+                finding.category, finding.count, finding.repaired
+            ),
+        })
+        .collect::<Vec<_>>()
+        .join("；");
+    for recipient in recipients {
+        let notification = Notification::new(
+            recipient,
+            category::CONSISTENCY,
+            title.clone(),
+            content.clone(),
+        );
+        if let Err(e) = notification_helper::notify(notification).await {
+            tracing::warn!(error = %e, "Failed to send consistency report notification");
+        }
+    }
+}
+
+#[async_trait]
+impl TConsistencyService for SysConsistencyService {
+    async fn run_check(
+        &self,
+        input: ConsistencyCheckInput,
+        operator: &User,
+    ) -> Result<ConsistencyReport, AppError> {
+        let repair: Vec<ConsistencyCategory> = if input.repair {
+            ConsistencyCategory::ALL
+                .into_iter()
+                .filter(ConsistencyCategory::is_repairable)
+                .collect()
+        } else {
+            Vec::new()
+        };
+        let report = self.check(&repair).await?;
+
+        record_audit(
+            AuditEntry::new("数据一致性", "执行一致性检查")
+                .with_user(operator)
+                .with_detail(audit_detail(&report)),
+        );
+        Ok(report)
+    }
+
+    async fn run_scheduled_check(&self) -> Result<ConsistencyReport, AppError> {
+        let config = global::get_config::<ConsistencyConfig>()
+            .await
+            .map(|config| config.as_ref().clone())
+            .unwrap_or_default();
+        let report = self.check(&config.auto_repair).await?;
+
+        record_audit(
+            AuditEntry::new("数据一致性", "定期一致性检查").with_detail(audit_detail(&report)),
+        );
+        if report.has_issues() {
+            notify_admins(&config.notify_roles, &report).await;
+        }
+        Ok(report)
+    }
+}
@@ -0,0 +1,182 @@
+use async_trait::async_trait;
+use serde_json::json;
+use server_config::{Config, DatabaseConfig, DatabasesInstancesConfig, OptionalConfigs};
+use server_core::web::{auth::User, error::AppError, statement_cache::statement_cache_stats};
+use server_global::global;
+use server_model::admin::{input::ResizeDbPoolInput, output::DbPoolInfo};
+
+use super::sys_db_pool_error::DbPoolError;
+use crate::{
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper::{self, PRIMARY_DB_NAME},
+    },
+    project_error,
+};
+
+#[async_trait]
+pub trait TDbPoolService {
+    async fn list_pools(&self) -> Result<Vec<DbPoolInfo>, AppError>;
+    /// 运行时调整连接池容量，新连接池建立后替换旧连接池，旧连接池排空后关闭
+    async fn resize_pool(
+        &self,
+        name: &str,
+        input: ResizeDbPoolInput,
+        operator: &User,
+    ) -> Result<DbPoolInfo, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysDbPoolService;
+
+impl SysDbPoolService {
+    /// 获取所有连接池当前生效的配置，主数据库排在首位
+    pub(crate) async fn pool_configs() -> Vec<(String, DatabaseConfig)> {
+        let mut configs = Vec::new();
+
+        if let Some(primary) = global::get_config::<DatabaseConfig>().await {
+            configs.push((PRIMARY_DB_NAME.to_string(), (*primary).clone()));
+        }
+        if let Some(instances) =
+            global::get_config::<OptionalConfigs<DatabasesInstancesConfig>>().await
+        {
+            configs.extend(
+                instances
+                    .configs
+                    .iter()
+                    .flatten()
+                    .map(|instance| (instance.name.clone(), instance.database.clone())),
+            );
+        }
+
+        configs
+    }
+
+    /// 配置热更新后，对连接参数发生变化的连接池执行重建
+    pub(crate) async fn reconcile_pools(previous: &[(String, DatabaseConfig)]) {
+        for (name, config) in Self::pool_configs().await {
+            let Some((_, old)) = previous.iter().find(|(old_name, _)| old_name == &name) else {
+                continue;
+            };
+            if old == &config {
+                continue;
+            }
+
+            match db_helper::rebuild_connection(&name, &config).await {
+                Ok(()) => annotate_resize(&name, old, &config),
+                Err(e) => {
+                    project_error!("Failed to rebuild database pool '{}': {}", name, e.message())
+                },
+            }
+        }
+    }
+
+    /// 将调整后的连接池配置写回全局配置，保持与热更新配置一致
+    async fn store_pool_config(name: &str, config: DatabaseConfig) {
+        let Some(app_config) = global::get_config::<Config>().await else {
+            return;
+        };
+        let mut app_config = (*app_config).clone();
+
+        if name == PRIMARY_DB_NAME {
+            app_config.database = config.clone();
+            global::init_config::<DatabaseConfig>(config).await;
+        } else if let Some(instance) = app_config
+            .database_instances
+            .iter_mut()
+            .flatten()
+            .find(|instance| instance.name == name)
+        {
+            instance.database = config;
+            global::init_config::<OptionalConfigs<DatabasesInstancesConfig>>(
+                app_config.database_instances.clone().into(),
+            )
+            .await;
+        }
+
+        global::init_config::<Config>(app_config).await;
+    }
+}
+
+/// 以结构化事件记录连接池容量变化，供指标采集端打点
+fn annotate_resize(name: &str, old: &DatabaseConfig, new: &DatabaseConfig) {
+    tracing::info!(
+        target: "metrics",
+        event = "db_pool_resized",
+        pool = name,
+        old_max_connections = old.max_connections,
+        new_max_connections = new.max_connections,
+        old_min_connections = old.min_connections,
+        new_min_connections = new.min_connections,
+    );
+}
+
+#[async_trait]
+impl TDbPoolService for SysDbPoolService {
+    async fn list_pools(&self) -> Result<Vec<DbPoolInfo>, AppError> {
+        Ok(Self::pool_configs()
+            .await
+            .into_iter()
+            .map(|(name, config)| DbPoolInfo {
+                statement_cache: statement_cache_stats(&name),
+                name,
+                max_connections: config.max_connections,
+                min_connections: config.min_connections,
+                statement_cache_capacity: config.effective_statement_cache_capacity(),
+            })
+            .collect())
+    }
+
+    async fn resize_pool(
+        &self,
+        name: &str,
+        input: ResizeDbPoolInput,
+        operator: &User,
+    ) -> Result<DbPoolInfo, AppError> {
+        if input.min_connections > input.max_connections {
+            return Err(DbPoolError::InvalidPoolSize.into());
+        }
+
+        let (_, old) = Self::pool_configs()
+            .await
+            .into_iter()
+            .find(|(pool_name, _)| pool_name == name)
+            .ok_or(DbPoolError::PoolNotFound)?;
+
+        let config = DatabaseConfig {
+            max_connections: input.max_connections,
+            min_connections: input.min_connections,
+            ..old.clone()
+        };
+
+        db_helper::rebuild_connection(name, &config)
+            .await
+            .map_err(|e| DbPoolError::RebuildFailed(e.message().to_string()))?;
+        annotate_resize(name, &old, &config);
+        let statement_cache_capacity = config.effective_statement_cache_capacity();
+        Self::store_pool_config(name, config).await;
+
+        let info = DbPoolInfo {
+            name: name.to_string(),
+            max_connections: input.max_connections,
+            min_connections: input.min_connections,
+            statement_cache_capacity,
+            statement_cache: statement_cache_stats(name),
+        };
+
+        record_audit(
+            AuditEntry::new("数据库连接池", "调整连接池容量")
+                .with_user(operator)
+                .with_detail(json!({
+                    "pool": name,
+                    "old": {
+                        "maxConnections": old.max_connections,
+                        "minConnections": old.min_connections,
+                    },
+                    "new": info,
+                })),
+        );
+
+        Ok(info)
+    }
+}
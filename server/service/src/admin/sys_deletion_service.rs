@@ -0,0 +1,348 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, ConnectionTrait, EntityTrait, PaginatorTrait, QueryFilter,
+};
+use server_core::web::error::AppError;
+use server_model::{
+    admin::{
+        entities::{
+            casbin_rule::Column as CasbinRuleColumn,
+            prelude::{CasbinRule, SysOrganization, SysRole, SysRoleMenu, SysUser, SysUserRole},
+            sys_organization::Column as SysOrganizationColumn,
+            sys_role::Column as SysRoleColumn,
+            sys_role_menu::Column as SysRoleMenuColumn,
+            sys_user::Column as SysUserColumn,
+            sys_user_role::Column as SysUserRoleColumn,
+        },
+        input::{DeleteWithCascadeInput, DeletionTarget},
+        output::{CascadeAction, CascadeDependent, CascadeEffect, DeletionPreview},
+    },
+    audit::current_actor,
+};
+
+use super::sys_deletion_error::DeletionError;
+use crate::helper::db_helper;
+
+/// 级联规则：删除 `target` 时如何处理 `dependent`
+struct CascadeRule {
+    target: DeletionTarget,
+    dependent: CascadeDependent,
+    action: CascadeAction,
+}
+
+/// 级联规则表，按顺序评估和执行
+const CASCADE_RULES: &[CascadeRule] = &[
+    CascadeRule {
+        target: DeletionTarget::Organization,
+        dependent: CascadeDependent::ChildOrganizations,
+        action: CascadeAction::Block,
+    },
+    CascadeRule {
+        target: DeletionTarget::Organization,
+        dependent: CascadeDependent::ScopedRoles,
+        action: CascadeAction::Block,
+    },
+    CascadeRule {
+        target: DeletionTarget::Organization,
+        dependent: CascadeDependent::OrganizationUsers,
+        action: CascadeAction::Reassign,
+    },
+    CascadeRule {
+        target: DeletionTarget::Role,
+        dependent: CascadeDependent::RoleUsers,
+        action: CascadeAction::Detach,
+    },
+    CascadeRule {
+        target: DeletionTarget::Role,
+        dependent: CascadeDependent::RoleMenus,
+        action: CascadeAction::Detach,
+    },
+    CascadeRule {
+        target: DeletionTarget::Role,
+        dependent: CascadeDependent::RolePermissions,
+        action: CascadeAction::Detach,
+    },
+];
+
+fn rules_for(target: DeletionTarget) -> impl Iterator<Item = &'static CascadeRule> {
+    CASCADE_RULES
+        .iter()
+        .filter(move |rule| rule.target == target)
+}
+
+/// 评估一条规则的影响，没有关联数据时不阻止删除
+fn evaluate_rule(rule: &CascadeRule, count: u64, reassign: bool) -> CascadeEffect {
+    let blocking = count > 0
+        && match rule.action {
+            CascadeAction::Block => true,
+            CascadeAction::Reassign => !reassign,
+            CascadeAction::Detach => false,
+        };
+    CascadeEffect {
+        dependent: rule.dependent,
+        action: rule.action,
+        count,
+        blocking,
+    }
+}
+
+#[async_trait]
+pub trait TDeletionService {
+    async fn preview(
+        &self,
+        target: DeletionTarget,
+        id: &str,
+        input: DeleteWithCascadeInput,
+    ) -> Result<DeletionPreview, AppError>;
+
+    async fn delete(
+        &self,
+        target: DeletionTarget,
+        id: &str,
+        input: DeleteWithCascadeInput,
+    ) -> Result<DeletionPreview, AppError>;
+}
+
+/// 按级联规则删除组织、角色等被其他数据引用的对象
+///
+/// 预览与删除使用同一套规则，删除在事务内重新评估，避免预览后数据变化导致误删
+#[derive(Clone)]
+pub struct SysDeletionService;
+
+impl SysDeletionService {
+    async fn ensure_target_exists<C: ConnectionTrait>(
+        db: &C,
+        target: DeletionTarget,
+        id: &str,
+    ) -> Result<(), AppError> {
+        let exists = match target {
+            DeletionTarget::Organization => SysOrganization::find_by_id(id).count(db).await,
+            DeletionTarget::Role => SysRole::find_by_id(id).count(db).await,
+        }
+        .map_err(AppError::from)?;
+        if exists == 0 {
+            return Err(DeletionError::TargetNotFound.into());
+        }
+        Ok(())
+    }
+
+    async fn check_reassign_target<C: ConnectionTrait>(
+        db: &C,
+        target: DeletionTarget,
+        id: &str,
+        reassign_to: Option<&str>,
+    ) -> Result<(), AppError> {
+        let Some(reassign_to) = reassign_to else {
+            return Ok(());
+        };
+        if target != DeletionTarget::Organization || reassign_to == id {
+            return Err(DeletionError::InvalidReassignTarget.into());
+        }
+        let exists = SysOrganization::find_by_id(reassign_to)
+            .count(db)
+            .await
+            .map_err(AppError::from)?;
+        if exists == 0 {
+            return Err(DeletionError::InvalidReassignTarget.into());
+        }
+        Ok(())
+    }
+
+    async fn role_code<C: ConnectionTrait>(db: &C, id: &str) -> Result<String, AppError> {
+        SysRole::find_by_id(id)
+            .one(db)
+            .await
+            .map_err(AppError::from)?
+            .map(|role| role.code)
+            .ok_or_else(|| DeletionError::TargetNotFound.into())
+    }
+
+    async fn count_dependents<C: ConnectionTrait>(
+        db: &C,
+        dependent: CascadeDependent,
+        id: &str,
+    ) -> Result<u64, AppError> {
+        let count = match dependent {
+            CascadeDependent::ChildOrganizations => {
+                SysOrganization::find()
+                    .filter(SysOrganizationColumn::Pid.eq(id))
+                    .count(db)
+                    .await
+            },
+            CascadeDependent::OrganizationUsers => {
+                SysUser::find()
+                    .filter(SysUserColumn::OrganizationId.eq(id))
+                    .count(db)
+                    .await
+            },
+            CascadeDependent::ScopedRoles => {
+                SysRole::find()
+                    .filter(SysRoleColumn::ScopeOrganizationId.eq(id))
+                    .count(db)
+                    .await
+            },
+            CascadeDependent::RoleUsers => {
+                SysUserRole::find()
+                    .filter(SysUserRoleColumn::RoleId.eq(id))
+                    .count(db)
+                    .await
+            },
+            CascadeDependent::RoleMenus => {
+                SysRoleMenu::find()
+                    .filter(SysRoleMenuColumn::RoleId.eq(id))
+                    .count(db)
+                    .await
+            },
+            CascadeDependent::RolePermissions => {
+                let code = Self::role_code(db, id).await?;
+                CasbinRule::find()
+                    .filter(CasbinRuleColumn::Ptype.eq("p"))
+                    .filter(CasbinRuleColumn::V0.eq(code))
+                    .count(db)
+                    .await
+            },
+        };
+        count.map_err(AppError::from)
+    }
+
+    async fn evaluate<C: ConnectionTrait>(
+        db: &C,
+        target: DeletionTarget,
+        id: &str,
+        input: &DeleteWithCascadeInput,
+    ) -> Result<DeletionPreview, AppError> {
+        Self::ensure_target_exists(db, target, id).await?;
+        Self::check_reassign_target(db, target, id, input.reassign_to.as_deref()).await?;
+
+        let mut effects = Vec::new();
+        for rule in rules_for(target) {
+            let count = Self::count_dependents(db, rule.dependent, id).await?;
+            effects.push(evaluate_rule(rule, count, input.reassign_to.is_some()));
+        }
+
+        Ok(DeletionPreview {
+            target,
+            id: id.to_string(),
+            allowed: effects.iter().all(|effect| !effect.blocking),
+            effects,
+        })
+    }
+
+    /// 执行一条规则，`Block` 规则在评估阶段已拦截，这里无需处理
+    async fn apply_effect<C: ConnectionTrait>(
+        db: &C,
+        effect: &CascadeEffect,
+        id: &str,
+        input: &DeleteWithCascadeInput,
+    ) -> Result<(), AppError> {
+        if effect.count == 0 {
+            return Ok(());
+        }
+        match effect.dependent {
+            CascadeDependent::OrganizationUsers => {
+                // 批量更新不经过实体保存钩子，审计列需自行设置
+                SysUser::update_many()
+                    .col_expr(
+                        SysUserColumn::OrganizationId,
+                        Expr::value(input.reassign_to.clone()),
+                    )
+                    .col_expr(
+                        SysUserColumn::UpdatedAt,
+                        Expr::value(Local::now().naive_local()),
+                    )
+                    .col_expr(SysUserColumn::UpdatedBy, Expr::value(current_actor()))
+                    .filter(SysUserColumn::OrganizationId.eq(id))
+                    .exec(db)
+                    .await
+                    .map_err(AppError::from)?;
+            },
+            CascadeDependent::RoleUsers => {
+                SysUserRole::delete_many()
+                    .filter(SysUserRoleColumn::RoleId.eq(id))
+                    .exec(db)
+                    .await
+                    .map_err(AppError::from)?;
+            },
+            CascadeDependent::RoleMenus => {
+                SysRoleMenu::delete_many()
+                    .filter(SysRoleMenuColumn::RoleId.eq(id))
+                    .exec(db)
+                    .await
+                    .map_err(AppError::from)?;
+            },
+            CascadeDependent::RolePermissions => {
+                // 内存中的授权策略在重新加载策略后移除，角色已无用户分配，不会再被新签发的令牌命中
+                let code = Self::role_code(db, id).await?;
+                CasbinRule::delete_many()
+                    .filter(CasbinRuleColumn::Ptype.eq("p"))
+                    .filter(CasbinRuleColumn::V0.eq(code))
+                    .exec(db)
+                    .await
+                    .map_err(AppError::from)?;
+            },
+            CascadeDependent::ChildOrganizations | CascadeDependent::ScopedRoles => {},
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TDeletionService for SysDeletionService {
+    async fn preview(
+        &self,
+        target: DeletionTarget,
+        id: &str,
+        input: DeleteWithCascadeInput,
+    ) -> Result<DeletionPreview, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        Self::evaluate(&db, target, id, &input).await
+    }
+
+    async fn delete(
+        &self,
+        target: DeletionTarget,
+        id: &str,
+        input: DeleteWithCascadeInput,
+    ) -> Result<DeletionPreview, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        let preview = Self::evaluate(&txn, target, id, &input).await?;
+        if !preview.allowed {
+            return Err(DeletionError::Blocked.into());
+        }
+        for effect in &preview.effects {
+            Self::apply_effect(&txn, effect, id, &input).await?;
+        }
+        match target {
+            DeletionTarget::Organization => SysOrganization::delete_by_id(id).exec(&txn).await,
+            DeletionTarget::Role => SysRole::delete_by_id(id).exec(&txn).await,
+        }
+        .map_err(AppError::from)?;
+
+        txn.commit().await.map_err(AppError::from)?;
+        Ok(preview)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_evaluate_rule() {
+        let mut rules = rules_for(DeletionTarget::Organization);
+        let children = rules.next().unwrap();
+        assert!(evaluate_rule(children, 2, true).blocking);
+        assert!(!evaluate_rule(children, 0, false).blocking);
+
+        let users = rules_for(DeletionTarget::Organization)
+            .find(|rule| rule.dependent == CascadeDependent::OrganizationUsers)
+            .unwrap();
+        assert!(evaluate_rule(users, 3, false).blocking);
+        assert!(!evaluate_rule(users, 3, true).blocking);
+
+        assert!(rules_for(DeletionTarget::Role).all(|rule| !evaluate_rule(rule, 5, false).blocking));
+    }
+}
@@ -0,0 +1,170 @@
+use async_trait::async_trait;
+use chrono::Local;
+use migration::{Migrator, MigratorTrait};
+use serde::Serialize;
+use serde_json::{json, Value};
+use server_config::Config;
+use server_core::web::{admission, auth::User, error::AppError, error_log, telemetry};
+use server_global::global;
+use server_model::admin::{input::UploadFileInput, output::DiagnosticsBundle};
+use server_utils::ZipUtil;
+
+use crate::{
+    admin::{
+        SysClusterService, SysDbPoolService, SysFileService, SysInstanceService,
+        SysMigrationService, TClusterService, TDbPoolService, TFileService, TInstanceService,
+        TMigrationService,
+    },
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper,
+        redis_helper::{self, RedisSource},
+    },
+};
+
+#[async_trait]
+pub trait TDiagnosticsService {
+    /// 生成诊断包并保存到文件存储，用于附加到问题报告
+    ///
+    /// 包含脱敏后的生效配置、本实例最近的告警和错误日志、健康状态、迁移状态和指标汇总，
+    /// 单个部分收集失败不影响其余部分
+    async fn generate_bundle(&self, operator: &User) -> Result<DiagnosticsBundle, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysDiagnosticsService;
+
+/// 诊断包中的一个文件，收集失败时写入错误信息
+struct Section {
+    name: &'static str,
+    content: Result<Value, AppError>,
+}
+
+impl Section {
+    fn new<T: Serialize>(name: &'static str, content: Result<T, AppError>) -> Self {
+        let content = content.and_then(|content| {
+            serde_json::to_value(content).map_err(|e| AppError::internal(e.to_string()))
+        });
+        Self { name, content }
+    }
+}
+
+impl SysDiagnosticsService {
+    async fn effective_config() -> Result<Value, AppError> {
+        global::get_config::<Config>()
+            .await
+            .map(|config| config.to_redacted_value())
+            .ok_or_else(|| AppError::internal("Configuration not initialized"))
+    }
+
+    async fn health() -> Result<Value, AppError> {
+        let database = match db_helper::get_db_connection().await {
+            Ok(db) => db.ping().await.map_err(|e| e.to_string()),
+            Err(e) => Err(e.message().to_string()),
+        };
+        let members = SysClusterService
+            .list_members()
+            .await
+            .map_err(|e| e.message().to_string());
+
+        Ok(json!({
+            "drain": SysInstanceService.drain_status().await?,
+            "database": database.map(|_| "ok"),
+            "redis": redis_helper::is_available(RedisSource::Primary).await,
+            "clusterMembers": members,
+        }))
+    }
+
+    async fn migrations() -> Result<Value, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let applied: Vec<String> = Migrator::get_applied_migrations(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+
+        Ok(json!({
+            "applied": applied,
+            "plan": SysMigrationService.plan_migrations().await?,
+        }))
+    }
+
+    async fn metrics() -> Result<Value, AppError> {
+        Ok(json!({
+            "inFlightWork": global::in_flight_work(),
+            "admission": admission::admission_snapshot(),
+            "latencies": telemetry::latency_snapshot(),
+            "dbPools": SysDbPoolService.list_pools().await?,
+        }))
+    }
+}
+
+#[async_trait]
+impl TDiagnosticsService for SysDiagnosticsService {
+    async fn generate_bundle(&self, operator: &User) -> Result<DiagnosticsBundle, AppError> {
+        let generated_at = Local::now().naive_local();
+        let sections = vec![
+            Section::new("config.json", Self::effective_config().await),
+            Section::new("errors.json", Ok(error_log::recent_errors())),
+            Section::new("health.json", Self::health().await),
+            Section::new("migrations.json", Self::migrations().await),
+            Section::new("metrics.json", Self::metrics().await),
+        ];
+
+        let failures: Vec<String> = sections
+            .iter()
+            .filter_map(|section| {
+                section
+                    .content
+                    .as_ref()
+                    .err()
+                    .map(|e| format!("{}: {}", section.name, e.message()))
+            })
+            .collect();
+        let manifest = json!({
+            "version": env!("CARGO_PKG_VERSION"),
+            "os": std::env::consts::OS,
+            "arch": std::env::consts::ARCH,
+            "generatedAt": generated_at,
+            "generatedBy": operator.username(),
+            "failures": failures,
+        });
+
+        let mut files = vec![("manifest.json", render(&manifest))];
+        for section in sections {
+            let content = section
+                .content
+                .unwrap_or_else(|e| json!({ "error": e.message() }));
+            files.push((section.name, render(&content)));
+        }
+        let entries: Vec<(&str, &[u8])> = files
+            .iter()
+            .map(|(name, data)| (*name, data.as_slice()))
+            .collect();
+        let data = ZipUtil::store(&entries, generated_at).map_err(|e| {
+            AppError::internal(format!("Failed to build diagnostics bundle: {}", e))
+        })?;
+
+        let file = SysFileService
+            .upload_file(UploadFileInput {
+                domain: operator.domain(),
+                file_name: format!("diagnostics-{}.zip", generated_at.format("%Y%m%d%H%M%S")),
+                content_type: "application/zip".to_string(),
+                data,
+                created_by: operator.username(),
+            })
+            .await?;
+
+        record_audit(
+            AuditEntry::new("实例管理", "生成诊断包")
+                .with_user(operator)
+                .with_detail(json!({ "fileId": file.id, "failures": failures })),
+        );
+        Ok(DiagnosticsBundle { file, failures })
+    }
+}
+
+fn render(value: &Value) -> Vec<u8> {
+    serde_json::to_vec_pretty(value).unwrap_or_default()
+}
@@ -1,9 +1,8 @@
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, Set,
 };
-use server_core::web::{error::AppError, page::PaginatedData};
+use server_core::web::{error::AppError, page::PageResult};
 use server_model::admin::{
     entities::{
         prelude::SysDomain,
@@ -16,14 +15,20 @@ use server_model::admin::{
 };
 use ulid::Ulid;
 
-use crate::{admin::sys_domain_error::DomainError, helper::db_helper};
+use crate::{
+    admin::sys_domain_error::DomainError,
+    helper::{
+        cache_helper::{self, namespace},
+        db_helper,
+    },
+};
 
 #[async_trait]
 pub trait TDomainService {
     async fn find_paginated_domains(
         &self,
         params: DomainPageRequest,
-    ) -> Result<PaginatedData<SysDomainModel>, AppError>;
+    ) -> Result<PageResult<SysDomainModel>, AppError>;
 
     async fn create_domain(&self, input: CreateDomainInput) -> Result<SysDomainModel, AppError>;
     async fn get_domain(&self, id: &str) -> Result<SysDomainModel, AppError>;
@@ -77,7 +82,7 @@ impl TDomainService for SysDomainService {
     async fn find_paginated_domains(
         &self,
         params: DomainPageRequest,
-    ) -> Result<PaginatedData<SysDomainModel>, AppError> {
+    ) -> Result<PageResult<SysDomainModel>, AppError> {
         let db = db_helper::get_db_connection().await?;
         let mut query = SysDomain::find();
 
@@ -98,12 +103,7 @@ impl TDomainService for SysDomainService {
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
     }
 
     async fn create_domain(&self, input: CreateDomainInput) -> Result<SysDomainModel, AppError> {
@@ -118,8 +118,6 @@ impl TDomainService for SysDomainService {
             name: Set(input.name),
             description: Set(input.description),
             status: Set(Status::Enabled),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
             ..Default::default()
         };
 
@@ -128,12 +126,15 @@ impl TDomainService for SysDomainService {
     }
 
     async fn get_domain(&self, id: &str) -> Result<SysDomainModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysDomain::find_by_id(id)
-            .one(db.as_ref())
-            .await
-            .map_err(AppError::from)?
-            .ok_or_else(|| DomainError::DomainNotFound.into())
+        cache_helper::find_by_id(namespace::DOMAIN, id, || async {
+            let db = db_helper::get_db_connection().await?;
+            SysDomain::find_by_id(id)
+                .one(db.as_ref())
+                .await
+                .map_err(AppError::from)
+        })
+        .await?
+        .ok_or_else(|| DomainError::DomainNotFound.into())
     }
 
     async fn update_domain(&self, input: UpdateDomainInput) -> Result<SysDomainModel, AppError> {
@@ -6,10 +6,11 @@ use sea_orm::{
     ColumnTrait, Condition, DatabaseConnection, DeleteResult, EntityTrait, IntoActiveModel,
     PaginatorTrait, QueryFilter, Set,
 };
-use server_core::web::{error::AppError, page::PaginatedData};
+use server_core::web::{error::AppError, page::PageResult};
 use server_model::admin::{
     entities::{
-        prelude::SysEndpoint,
+        casbin_rule::Column as CasbinRuleColumn,
+        prelude::{CasbinRule, SysEndpoint},
         sys_endpoint::{
             ActiveModel as SysEndpointActiveModel, Column as SysEndpointColumn,
             Model as SysEndpointModel,
@@ -27,7 +28,7 @@ pub trait TEndpointService {
     async fn find_paginated_endpoints(
         &self,
         params: EndpointPageRequest,
-    ) -> Result<PaginatedData<SysEndpointModel>, AppError>;
+    ) -> Result<PageResult<SysEndpointModel>, AppError>;
 
     async fn tree_endpoint(&self) -> Result<Vec<EndpointTree>, AppError>;
 }
@@ -83,6 +84,49 @@ impl SysEndpointService {
             .map_err(AppError::from)
     }
 
+    /// 记录引用了未声明接口的权限策略
+    ///
+    /// 策略可能由管理员手动维护，只告警不删除
+    async fn flag_orphaned_policies(
+        &self,
+        db: &DatabaseConnection,
+        endpoints: &[SysEndpointModel],
+    ) -> Result<(), AppError> {
+        let policies = CasbinRule::find()
+            .filter(CasbinRuleColumn::Ptype.eq("p"))
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+
+        let orphaned: Vec<_> = policies
+            .iter()
+            .filter(|policy| {
+                !endpoints.iter().any(|endpoint| {
+                    policy.v2.as_deref() == Some(endpoint.path.as_str())
+                        && policy.v3.as_deref() == Some(endpoint.method.as_str())
+                })
+            })
+            .collect();
+        for policy in &orphaned {
+            tracing::warn!(
+                role = ?policy.v0,
+                domain = ?policy.v1,
+                path = ?policy.v2,
+                method = ?policy.v3,
+                "Permission policy references an undeclared endpoint"
+            );
+        }
+        if !orphaned.is_empty() {
+            tracing::info!(
+                target: "metrics",
+                event = "orphaned_permission_policies",
+                count = orphaned.len(),
+            );
+        }
+
+        Ok(())
+    }
+
     fn create_endpoint_tree(&self, endpoints: &[SysEndpointModel]) -> Vec<EndpointTree> {
         let mut controller_map: BTreeMap<String, EndpointTree> = BTreeMap::new();
 
@@ -156,13 +200,16 @@ impl TEndpointService for SysEndpointService {
             }
         }
 
+        self.flag_orphaned_policies(db.as_ref(), &new_endpoints)
+            .await?;
+
         Ok(())
     }
 
     async fn find_paginated_endpoints(
         &self,
         params: EndpointPageRequest,
-    ) -> Result<PaginatedData<SysEndpointModel>, AppError> {
+    ) -> Result<PageResult<SysEndpointModel>, AppError> {
         let db = db_helper::get_db_connection().await?;
         let mut query = SysEndpoint::find();
 
@@ -186,12 +233,7 @@ impl TEndpointService for SysEndpointService {
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
     }
 
     async fn tree_endpoint(&self) -> Result<Vec<EndpointTree>, AppError> {
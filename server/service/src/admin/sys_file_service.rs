@@ -0,0 +1,1281 @@
+use std::{collections::HashSet, sync::Arc};
+
+use async_trait::async_trait;
+use aws_sdk_s3::{
+    error::DisplayErrorContext,
+    primitives::ByteStream,
+    types::{CompletedMultipartUpload, CompletedPart},
+    Client as S3Client,
+};
+use chrono::{Duration, Local, NaiveDateTime};
+use futures::{stream, StreamExt, TryStreamExt};
+use ring::rand::{SecureRandom, SystemRandom};
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    ActiveModelTrait, ColumnTrait, Condition, DatabaseTransaction, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set,
+};
+use serde_json::json;
+use server_config::{S3Config, StorageConfig};
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{SysFile, SysFileBlob, SysFileShare},
+        sea_orm_active_enums::{AlertSignalKind, FileScanStatus},
+        sys_file::{
+            ActiveModel as SysFileActiveModel, Column as SysFileColumn, Model as SysFileModel,
+        },
+        sys_file_blob::{
+            ActiveModel as SysFileBlobActiveModel, Column as SysFileBlobColumn,
+            Model as SysFileBlobModel,
+        },
+        sys_file_share::{
+            ActiveModel as SysFileShareActiveModel, Column as SysFileShareColumn,
+            Model as SysFileShareModel,
+        },
+    },
+    input::{
+        CreateFileShareInput, DownloadSharedFileInput, FilePageRequest, ReviewFileInput,
+        UploadFileInput,
+    },
+    output::{FileDownload, FileShareLink, FileUrl, SharedFileInfo},
+};
+use server_utils::SecureUtil;
+use ulid::Ulid;
+
+use super::sys_file_error::FileError;
+use crate::{
+    helper::{
+        alert_helper::{self, SecuritySignal},
+        audit_helper::{record_audit, AuditEntry},
+        cache_helper::{self, namespace},
+        db_helper,
+        s3_helper::{self, S3Source},
+        share_attempt_helper,
+        virus_scan_helper::{self, ScanVerdict},
+    },
+    project_error, project_info,
+};
+
+#[async_trait]
+pub trait TFileService {
+    async fn find_paginated_files(
+        &self,
+        params: FilePageRequest,
+    ) -> Result<PageResult<SysFileModel>, AppError>;
+    async fn upload_file(&self, input: UploadFileInput) -> Result<SysFileModel, AppError>;
+    /// 下载文件，每次下载作为批量导出信号计数
+    async fn download_file(&self, id: &str, operator: &User) -> Result<FileDownload, AppError>;
+    /// 获取文件访问地址，与下载一样检查扫描状态并计入批量导出信号
+    async fn get_file_url(&self, id: &str, operator: &User) -> Result<FileUrl, AppError>;
+    async fn delete_file(&self, id: &str) -> Result<(), AppError>;
+
+    /// 获取待复核（隔离或扫描失败）的文件
+    async fn find_paginated_quarantined_files(
+        &self,
+        params: FilePageRequest,
+    ) -> Result<PageResult<SysFileModel>, AppError>;
+    /// 复核后放行文件，放行后允许普通下载
+    async fn release_file(
+        &self,
+        id: &str,
+        reviewer: &User,
+        input: ReviewFileInput,
+    ) -> Result<SysFileModel, AppError>;
+    /// 清除隔离文件，内容无其他引用时立即删除对象，不等待 GC 宽限期
+    async fn purge_file(
+        &self,
+        id: &str,
+        reviewer: &User,
+        input: ReviewFileInput,
+    ) -> Result<SysFileModel, AppError>;
+
+    /// 清理引用计数为零且超过宽限期的内容对象，返回清理数量
+    async fn collect_unreferenced_blobs(&self) -> Result<u64, AppError>;
+
+    /// 为文件创建公开分享链接，只能分享扫描通过或已放行的文件
+    async fn create_share_link(
+        &self,
+        file_id: &str,
+        operator: &User,
+        input: CreateFileShareInput,
+    ) -> Result<FileShareLink, AppError>;
+    /// 获取文件的全部分享链接，包括已过期和已撤销的
+    async fn find_share_links(&self, file_id: &str) -> Result<Vec<SysFileShareModel>, AppError>;
+    /// 撤销分享链接，撤销后链接立即失效
+    async fn revoke_share_link(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SysFileShareModel, AppError>;
+    /// 凭令牌获取分享的文件信息，不计下载次数
+    async fn get_shared_file(&self, token: &str) -> Result<SharedFileInfo, AppError>;
+    /// 凭令牌下载分享的文件，校验密码并累加下载次数
+    async fn download_shared_file(
+        &self,
+        token: &str,
+        input: DownloadSharedFileInput,
+        client_ip: &str,
+    ) -> Result<FileDownload, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysFileService;
+
+/// 内容寻址的对象存储
+struct BlobStore {
+    client: Arc<S3Client>,
+    config: Arc<StorageConfig>,
+    s3_config: S3Config,
+    bucket: String,
+}
+
+impl BlobStore {
+    async fn resolve() -> Result<Self, AppError> {
+        let config = global::get_config::<StorageConfig>()
+            .await
+            .ok_or(FileError::StorageNotConfigured)?;
+        let source = match &config.s3_instance {
+            Some(name) => S3Source::Named(name.clone()),
+            None => S3Source::Primary,
+        };
+        let s3_config = s3_helper::get_instance_config(&source).await?;
+        let bucket = config
+            .resolve_bucket(&s3_config)
+            .ok_or(FileError::StorageNotConfigured)?;
+        let client = s3_helper::get_client(source).await?;
+        Ok(Self {
+            client,
+            config,
+            s3_config,
+            bucket,
+        })
+    }
+
+    async fn put(&self, key: &str, content_type: &str, data: Vec<u8>) -> Result<(), AppError> {
+        if data.len() > self.s3_config.multipart_chunk_size() {
+            return self.put_multipart(key, content_type, data).await;
+        }
+
+        self.client
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .body(ByteStream::from(data))
+            .send()
+            .await
+            .map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+        Ok(())
+    }
+
+    /// 分片并发上传大对象，任一分片失败时中止上传，避免残留未完成的分片
+    async fn put_multipart(
+        &self,
+        key: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<(), AppError> {
+        let upload = self
+            .client
+            .create_multipart_upload()
+            .bucket(&self.bucket)
+            .key(key)
+            .content_type(content_type)
+            .send()
+            .await
+            .map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+        let upload_id = upload.upload_id().ok_or_else(|| {
+            FileError::StorageOperation("Missing multipart upload id".to_string())
+        })?;
+
+        match self.upload_parts(key, upload_id, &data).await {
+            Ok(parts) => {
+                self.client
+                    .complete_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(key)
+                    .upload_id(upload_id)
+                    .multipart_upload(
+                        CompletedMultipartUpload::builder()
+                            .set_parts(Some(parts))
+                            .build(),
+                    )
+                    .send()
+                    .await
+                    .map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+                Ok(())
+            },
+            Err(e) => {
+                if let Err(abort_err) = self
+                    .client
+                    .abort_multipart_upload()
+                    .bucket(&self.bucket)
+                    .key(key)
+                    .upload_id(upload_id)
+                    .send()
+                    .await
+                {
+                    project_error!(
+                        "Failed to abort multipart upload {}: {}",
+                        key,
+                        DisplayErrorContext(abort_err)
+                    );
+                }
+                Err(e)
+            },
+        }
+    }
+
+    async fn upload_parts(
+        &self,
+        key: &str,
+        upload_id: &str,
+        data: &[u8],
+    ) -> Result<Vec<CompletedPart>, AppError> {
+        let chunk_size = self.s3_config.multipart_chunk_size();
+        let concurrency = self.s3_config.multipart_concurrency();
+
+        // 先创建各分片的 future 再交给 stream 并发执行，
+        // 在闭包中创建借用 key/data 的 async 块会使 future 无法满足 Send
+        let uploads: Vec<_> = data
+            .chunks(chunk_size)
+            .enumerate()
+            // 分片编号从 1 开始
+            .map(|(index, chunk)| self.upload_part(key, upload_id, index as i32 + 1, chunk))
+            .collect();
+        let mut parts: Vec<CompletedPart> = stream::iter(uploads)
+            .buffer_unordered(concurrency)
+            .try_collect()
+            .await?;
+
+        parts.sort_by_key(|part| part.part_number());
+        Ok(parts)
+    }
+
+    async fn upload_part(
+        &self,
+        key: &str,
+        upload_id: &str,
+        part_number: i32,
+        chunk: &[u8],
+    ) -> Result<CompletedPart, AppError> {
+        let output = self
+            .client
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from(chunk.to_vec()))
+            .send()
+            .await
+            .map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+        Ok(CompletedPart::builder()
+            .part_number(part_number)
+            .set_e_tag(output.e_tag)
+            .build())
+    }
+
+    async fn get(&self, key: &str) -> Result<Vec<u8>, AppError> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.as_service_error().is_some_and(|err| err.is_no_such_key()) {
+                    FileError::BlobNotFound
+                } else {
+                    FileError::StorageOperation(DisplayErrorContext(e).to_string())
+                }
+            })?;
+        let data = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| FileError::StorageOperation(e.to_string()))?;
+        Ok(data.into_bytes().to_vec())
+    }
+
+    /// 列出对象键前缀下最后修改时间早于 `modified_before` 的全部对象
+    async fn list_keys(&self, modified_before: i64) -> Result<HashSet<String>, AppError> {
+        let prefix = format!("{}/", self.config.blob_prefix.trim_end_matches('/'));
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .prefix(prefix.trim_start_matches('/'))
+            .into_paginator()
+            .send();
+
+        let mut keys = HashSet::new();
+        while let Some(page) = pages.next().await {
+            let page =
+                page.map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+            keys.extend(
+                page.contents()
+                    .iter()
+                    .filter(|object| {
+                        object
+                            .last_modified()
+                            .is_some_and(|modified| modified.secs() < modified_before)
+                    })
+                    .filter_map(|object| object.key().map(str::to_string)),
+            );
+        }
+        Ok(keys)
+    }
+
+    async fn delete(&self, key: &str) -> Result<(), AppError> {
+        self.client
+            .delete_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(|e| FileError::StorageOperation(DisplayErrorContext(e).to_string()))?;
+        Ok(())
+    }
+}
+
+/// 每次下载或获取访问地址都作为批量导出信号计数
+fn emit_export_signal(file: &SysFileModel, operator: &User) {
+    alert_helper::emit(
+        SecuritySignal::new(AlertSignalKind::MassExport, operator.user_id())
+            .with_actor(operator.username())
+            .with_domain(operator.domain())
+            .with_detail(json!({ "fileId": file.id, "size": file.size })),
+    );
+}
+
+/// 计算内容哈希（SHA-256 十六进制）
+fn content_hash(data: &[u8]) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, data).as_ref())
+}
+
+/// 生成分享令牌（32 字节随机数的十六进制）
+fn generate_share_token() -> Result<String, AppError> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| AppError::internal("Failed to generate share token"))?;
+    Ok(hex::encode(bytes))
+}
+
+/// 分享链接当前是否可用，不可用时返回原因
+fn check_share_available(share: &SysFileShareModel, now: NaiveDateTime) -> Result<(), FileError> {
+    if share.revoked_at.is_some() || share.expires_at <= now {
+        return Err(FileError::ShareUnavailable);
+    }
+    if share
+        .max_downloads
+        .is_some_and(|max| share.download_count >= max)
+    {
+        return Err(FileError::ShareDownloadLimitReached);
+    }
+    Ok(())
+}
+
+/// 减少一次内容引用，返回新的引用计数及引用归零时间
+fn release_reference(ref_count: i32, now: NaiveDateTime) -> (i32, Option<NaiveDateTime>) {
+    let ref_count = (ref_count - 1).max(0);
+    (ref_count, (ref_count == 0).then_some(now))
+}
+
+/// 内容对象是否可以被 GC 清理：没有引用且归零时间早于宽限期截止时间
+fn is_collectable(blob: &SysFileBlobModel, deadline: NaiveDateTime) -> bool {
+    blob.ref_count <= 0
+        && blob
+            .zero_ref_at
+            .is_some_and(|zero_ref_at| zero_ref_at <= deadline)
+}
+
+impl SysFileService {
+    async fn create_file_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        store: &BlobStore,
+        input: UploadFileInput,
+        scan: (FileScanStatus, Option<String>),
+    ) -> Result<SysFileModel, AppError> {
+        let UploadFileInput {
+            domain,
+            file_name,
+            content_type,
+            data,
+            created_by,
+        } = input;
+
+        let hash = content_hash(&data);
+        let size = data.len() as i64;
+        let now = Local::now().naive_local();
+        let (scan_status, scan_result) = scan;
+
+        // 加锁读取，避免与 GC 任务并发时复用了即将被删除的对象
+        let existing = SysFileBlob::find_by_id(hash.as_str())
+            .lock_exclusive()
+            .one(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let storage_key = match existing {
+            Some(blob) => blob.storage_key,
+            None => {
+                // 相同内容的对象键一致，并发上传时重复写入不影响结果
+                let storage_key = store.config.blob_key(&hash);
+                store.put(&storage_key, &content_type, data).await?;
+                storage_key
+            },
+        };
+
+        let blob = SysFileBlobActiveModel {
+            hash: Set(hash.clone()),
+            size: Set(size),
+            storage_key: Set(storage_key),
+            ref_count: Set(1),
+            zero_ref_at: Set(None),
+            created_at: Set(now),
+        };
+
+        SysFileBlob::insert(blob)
+            .on_conflict(
+                OnConflict::column(SysFileBlobColumn::Hash)
+                    .value(
+                        SysFileBlobColumn::RefCount,
+                        Expr::col((SysFileBlob, SysFileBlobColumn::RefCount)).add(1),
+                    )
+                    .value(SysFileBlobColumn::ZeroRefAt, Expr::cust("NULL"))
+                    .to_owned(),
+            )
+            .exec_without_returning(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let file = SysFileActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(domain),
+            blob_hash: Set(hash),
+            file_name: Set(file_name),
+            content_type: Set(content_type),
+            size: Set(size),
+            created_at: Set(now),
+            created_by: Set(created_by),
+            scan_status: Set(scan_status),
+            scan_result: Set(scan_result),
+            reviewed_by: Set(None),
+            reviewed_at: Set(None),
+        };
+
+        file.insert(txn).await.map_err(AppError::from)
+    }
+
+    /// 删除文件记录并减少内容引用计数，引用归零时返回对应的内容对象
+    async fn delete_file_in_transaction(
+        &self,
+        txn: &DatabaseTransaction,
+        id: &str,
+    ) -> Result<(SysFileModel, Option<SysFileBlobModel>), AppError> {
+        let file = SysFile::find_by_id(id)
+            .one(txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::FileNotFound)?;
+
+        SysFile::delete_by_id(id)
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let blob = SysFileBlob::find_by_id(file.blob_hash.as_str())
+            .lock_exclusive()
+            .one(txn)
+            .await
+            .map_err(AppError::from)?;
+
+        let blob = match blob {
+            Some(blob) => blob,
+            None => return Ok((file, None)),
+        };
+
+        let (ref_count, zero_ref_at) =
+            release_reference(blob.ref_count, Local::now().naive_local());
+        let mut blob: SysFileBlobActiveModel = blob.into();
+        blob.ref_count = Set(ref_count);
+        if zero_ref_at.is_some() {
+            // 记录引用归零时间，宽限期过后由 GC 任务清理
+            blob.zero_ref_at = Set(zero_ref_at);
+        }
+        let blob = blob.update(txn).await.map_err(AppError::from)?;
+
+        Ok((file, (ref_count == 0).then_some(blob)))
+    }
+
+    /// 查找可下载的文件及其内容对象，隔离中的文件不可下载
+    async fn find_downloadable(
+        &self,
+        id: &str,
+    ) -> Result<(SysFileModel, SysFileBlobModel), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+
+        let (file, blob) = SysFile::find_by_id(id)
+            .find_also_related(SysFileBlob)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::FileNotFound)?;
+        if !file.scan_status.is_downloadable() {
+            return Err(FileError::FileQuarantined.into());
+        }
+        let blob = blob.ok_or(FileError::BlobNotFound)?;
+        Ok((file, blob))
+    }
+
+    /// 比较文件内容表与对象存储，返回（缺少对象的记录，没有记录的对象）的对象键
+    ///
+    /// 上传时先写对象再提交记录，只比较一小时前写入的数据，避免把进行中的上传误报为不一致
+    pub(crate) async fn find_storage_mismatches(
+        &self,
+    ) -> Result<(Vec<String>, Vec<String>), AppError> {
+        let store = BlobStore::resolve().await?;
+        let db = db_helper::get_rls_connection().await?;
+        let cutoff = Local::now() - Duration::hours(1);
+
+        let mut objects = store.list_keys(cutoff.timestamp()).await?;
+        let recorded: Vec<(String, NaiveDateTime)> = SysFileBlob::find()
+            .select_only()
+            .column(SysFileBlobColumn::StorageKey)
+            .column(SysFileBlobColumn::CreatedAt)
+            .into_tuple()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut missing: Vec<String> = recorded
+            .iter()
+            .filter(|(key, created_at)| {
+                *created_at < cutoff.naive_local() && !objects.contains(key)
+            })
+            .map(|(key, _)| key.clone())
+            .collect();
+        for (key, _) in &recorded {
+            objects.remove(key);
+        }
+        let mut unreferenced: Vec<String> = objects.into_iter().collect();
+        missing.sort();
+        unreferenced.sort();
+        Ok((missing, unreferenced))
+    }
+
+    /// 加锁读取待复核的文件，不经过缓存，复核以数据库中的当前状态为准
+    async fn find_quarantined_for_review(
+        &self,
+        txn: &DatabaseTransaction,
+        id: &str,
+    ) -> Result<SysFileModel, AppError> {
+        let file = SysFile::find_by_id(id)
+            .lock_exclusive()
+            .one(txn)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::FileNotFound)?;
+        if file.scan_status.is_downloadable() {
+            return Err(FileError::FileNotQuarantined.into());
+        }
+        Ok(file)
+    }
+
+    async fn find_file(&self, id: &str) -> Result<SysFileModel, AppError> {
+        cache_helper::find_by_id(namespace::FILE, id, || async {
+            let db = db_helper::get_rls_connection().await?;
+            SysFile::find_by_id(id)
+                .one(&db)
+                .await
+                .map_err(AppError::from)
+        })
+        .await?
+        .ok_or_else(|| FileError::FileNotFound.into())
+    }
+
+    /// 按令牌查找分享链接及其文件，令牌只以哈希形式比对
+    async fn find_share_by_token(
+        &self,
+        token: &str,
+    ) -> Result<(SysFileShareModel, SysFileModel), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let (share, file) = SysFileShare::find()
+            .filter(SysFileShareColumn::TokenHash.eq(content_hash(token.as_bytes())))
+            .find_also_related(SysFile)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::ShareNotFound)?;
+        let file = file.ok_or(FileError::ShareNotFound)?;
+        Ok((share, file))
+    }
+
+    /// 校验分享密码，失败次数按分享链接和来源 IP 节流
+    async fn verify_share_password(
+        &self,
+        share: &SysFileShareModel,
+        file: &SysFileModel,
+        password: Option<&str>,
+        client_ip: &str,
+    ) -> Result<(), AppError> {
+        let Some(ref password_hash) = share.password_hash else {
+            return Ok(());
+        };
+
+        let config = global::get_config::<StorageConfig>()
+            .await
+            .map(|config| config.share.clone())
+            .unwrap_or_default();
+        if share_attempt_helper::is_locked(&config, &share.id, client_ip) {
+            return Err(FileError::SharePasswordLocked.into());
+        }
+        let valid = password.is_some_and(|password| {
+            SecureUtil::verify_password(password.as_bytes(), password_hash).unwrap_or(false)
+        });
+        if !valid {
+            share_attempt_helper::record_failure(&config, &share.id, client_ip);
+            record_audit(
+                AuditEntry::new(
+                    "file",
+                    format!("Rejected share link password for file {}", file.id),
+                )
+                .with_detail(json!({
+                    "shareId": share.id,
+                    "fileId": file.id,
+                    "ip": client_ip,
+                })),
+            );
+            return Err(FileError::SharePasswordInvalid.into());
+        }
+        share_attempt_helper::reset(&share.id, client_ip);
+        Ok(())
+    }
+}
+
+/// 扫描上传内容，返回扫描状态及结果说明
+///
+/// 未配置扫描器时直接视为安全；扫描器不可用时根据 `fail_open` 决定放行还是进入待复核
+async fn scan_content(
+    config: &StorageConfig,
+    file_name: &str,
+    data: &[u8],
+) -> (FileScanStatus, Option<String>) {
+    let scanner = match &config.scanner {
+        Some(scanner) => scanner,
+        None => return (FileScanStatus::Clean, None),
+    };
+
+    match virus_scan_helper::scan_with_clamd(scanner, data).await {
+        Ok(ScanVerdict::Clean) => (FileScanStatus::Clean, None),
+        Ok(ScanVerdict::Infected(signature)) => {
+            project_info!("File '{}' quarantined: {}", file_name, signature);
+            (FileScanStatus::Quarantined, Some(signature))
+        },
+        Err(e) if scanner.fail_open => {
+            project_error!(
+                "Virus scan failed for '{}', allowed by fail_open: {}",
+                file_name,
+                e
+            );
+            (FileScanStatus::Clean, Some(e))
+        },
+        Err(e) => {
+            project_error!("Virus scan failed for '{}': {}", file_name, e);
+            (FileScanStatus::Pending, Some(e))
+        },
+    }
+}
+
+#[async_trait]
+impl TFileService for SysFileService {
+    async fn find_paginated_files(
+        &self,
+        params: FilePageRequest,
+    ) -> Result<PageResult<SysFileModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let mut query = SysFile::find().order_by_desc(SysFileColumn::CreatedAt);
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any()
+                .add(SysFileColumn::FileName.contains(keywords))
+                .add(SysFileColumn::Domain.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn upload_file(&self, input: UploadFileInput) -> Result<SysFileModel, AppError> {
+        if input.data.is_empty() {
+            return Err(FileError::EmptyFile.into());
+        }
+
+        let store = BlobStore::resolve().await?;
+        // 扫描在事务外进行，避免长时间持有数据库连接
+        let scan = scan_content(&store.config, &input.file_name, &input.data).await;
+
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        match self
+            .create_file_in_transaction(&txn, &store, input, scan)
+            .await
+        {
+            Ok(file) => {
+                txn.commit().await.map_err(AppError::from)?;
+                cache_helper::forget_not_found(namespace::FILE, &file.id).await;
+                Ok(file)
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                Err(e)
+            },
+        }
+    }
+
+    async fn download_file(&self, id: &str, operator: &User) -> Result<FileDownload, AppError> {
+        let (file, blob) = self.find_downloadable(id).await?;
+
+        let store = BlobStore::resolve().await?;
+        let data = store.get(&blob.storage_key).await?;
+
+        emit_export_signal(&file, operator);
+        Ok(FileDownload { file, data })
+    }
+
+    async fn get_file_url(&self, id: &str, operator: &User) -> Result<FileUrl, AppError> {
+        let (file, blob) = self.find_downloadable(id).await?;
+
+        let store = BlobStore::resolve().await?;
+        let file_url = match store.s3_config.public_url(&blob.storage_key) {
+            Some(url) => FileUrl {
+                url,
+                expires_at: None,
+            },
+            None => {
+                let disposition = format!(
+                    "attachment; filename*=UTF-8''{}",
+                    urlencoding::encode(&file.file_name)
+                );
+                let url = s3_helper::presign_get_object(
+                    &store.client,
+                    &store.s3_config,
+                    &store.bucket,
+                    &blob.storage_key,
+                    Some(disposition),
+                    Some(file.content_type.clone()),
+                )
+                .await?;
+                FileUrl {
+                    url,
+                    expires_at: Some(
+                        Local::now().naive_local()
+                            + Duration::seconds(store.s3_config.presign_expiry() as i64),
+                    ),
+                }
+            },
+        };
+
+        emit_export_signal(&file, operator);
+        Ok(file_url)
+    }
+
+    async fn delete_file(&self, id: &str) -> Result<(), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        match self.delete_file_in_transaction(&txn, id).await {
+            Ok(_) => {
+                txn.commit().await.map_err(AppError::from)?;
+                Ok(())
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                Err(e)
+            },
+        }
+    }
+
+    async fn find_paginated_quarantined_files(
+        &self,
+        params: FilePageRequest,
+    ) -> Result<PageResult<SysFileModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let mut query = SysFile::find()
+            .filter(
+                SysFileColumn::ScanStatus
+                    .is_in([FileScanStatus::Quarantined, FileScanStatus::Pending]),
+            )
+            .order_by_asc(SysFileColumn::CreatedAt);
+
+        if let Some(ref keywords) = params.keywords {
+            let condition = Condition::any()
+                .add(SysFileColumn::FileName.contains(keywords))
+                .add(SysFileColumn::ScanResult.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn release_file(
+        &self,
+        id: &str,
+        reviewer: &User,
+        input: ReviewFileInput,
+    ) -> Result<SysFileModel, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let result = async {
+            let file = self.find_quarantined_for_review(&txn, id).await?;
+            let previous_status = file.scan_status.clone();
+            let mut file: SysFileActiveModel = file.into();
+            file.scan_status = Set(FileScanStatus::Released);
+            file.reviewed_by = Set(Some(reviewer.user_id()));
+            file.reviewed_at = Set(Some(Local::now().naive_local()));
+            let file = file.update(&txn).await.map_err(AppError::from)?;
+            Ok::<_, AppError>((file, previous_status))
+        }
+        .await;
+        let (file, previous_status) = match result {
+            Ok(result) => {
+                txn.commit().await.map_err(AppError::from)?;
+                result
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        record_audit(
+            AuditEntry::new("file", format!("Released quarantined file {}", file.id))
+                .with_user(reviewer)
+                .with_detail(json!({
+                    "fileId": file.id,
+                    "fileName": file.file_name,
+                    "blobHash": file.blob_hash,
+                    "previousStatus": previous_status,
+                    "scanResult": file.scan_result,
+                    "reason": input.reason,
+                })),
+        );
+
+        Ok(file)
+    }
+
+    async fn purge_file(
+        &self,
+        id: &str,
+        reviewer: &User,
+        input: ReviewFileInput,
+    ) -> Result<SysFileModel, AppError> {
+        let store = BlobStore::resolve().await?;
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        let result = async {
+            self.find_quarantined_for_review(&txn, id).await?;
+            let (file, blob) = self.delete_file_in_transaction(&txn, id).await?;
+            if let Some(ref blob) = blob {
+                SysFileBlob::delete_by_id(blob.hash.as_str())
+                    .exec(&txn)
+                    .await
+                    .map_err(AppError::from)?;
+            }
+            Ok::<_, AppError>((file, blob))
+        }
+        .await;
+        let (file, blob) = match result {
+            Ok(result) => {
+                txn.commit().await.map_err(AppError::from)?;
+                result
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        // 事务提交后再删除对象，回滚时不会留下指向已删除对象的记录；
+        // 删除失败时对象成为无记录的对象，由存储一致性检查报告
+        if let Some(blob) = blob {
+            if let Err(e) = store.delete(&blob.storage_key).await {
+                project_error!("Failed to delete blob '{}': {:?}", blob.hash, e);
+            }
+        }
+
+        record_audit(
+            AuditEntry::new("file", format!("Purged quarantined file {}", file.id))
+                .with_user(reviewer)
+                .with_detail(json!({
+                    "fileId": file.id,
+                    "fileName": file.file_name,
+                    "blobHash": file.blob_hash,
+                    "scanResult": file.scan_result,
+                    "reason": input.reason,
+                })),
+        );
+
+        Ok(file)
+    }
+
+    async fn collect_unreferenced_blobs(&self) -> Result<u64, AppError> {
+        let store = BlobStore::resolve().await?;
+        let db = db_helper::get_rls_connection().await?;
+
+        let deadline =
+            Local::now().naive_local() - Duration::seconds(store.config.gc_grace_period as i64);
+
+        let candidates = SysFileBlob::find()
+            .filter(SysFileBlobColumn::RefCount.lte(0))
+            .filter(SysFileBlobColumn::ZeroRefAt.lte(deadline))
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut removed = 0;
+        for candidate in candidates {
+            let txn = db.begin().await.map_err(AppError::from)?;
+
+            // 加锁后重新确认引用计数，期间可能有相同内容被重新上传
+            let blob = SysFileBlob::find_by_id(candidate.hash.as_str())
+                .lock_exclusive()
+                .one(&txn)
+                .await
+                .map_err(AppError::from)?;
+
+            let blob = match blob {
+                Some(blob) if is_collectable(&blob, deadline) => blob,
+                _ => {
+                    txn.rollback().await.map_err(AppError::from)?;
+                    continue;
+                },
+            };
+
+            SysFileBlob::delete_by_id(blob.hash.as_str())
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+
+            match store.delete(&blob.storage_key).await {
+                Ok(_) => {
+                    txn.commit().await.map_err(AppError::from)?;
+                    removed += 1;
+                },
+                Err(e) => {
+                    project_error!("Failed to delete blob '{}': {:?}", blob.hash, e);
+                    txn.rollback().await.map_err(AppError::from)?;
+                },
+            }
+        }
+
+        if removed > 0 {
+            project_info!("Removed {} unreferenced blobs", removed);
+        }
+
+        Ok(removed)
+    }
+
+    async fn create_share_link(
+        &self,
+        file_id: &str,
+        operator: &User,
+        input: CreateFileShareInput,
+    ) -> Result<FileShareLink, AppError> {
+        let file = self.find_file(file_id).await?;
+        if !file.scan_status.is_downloadable() {
+            return Err(FileError::FileQuarantined.into());
+        }
+
+        let config = global::get_config::<StorageConfig>()
+            .await
+            .ok_or(FileError::StorageNotConfigured)?;
+        let ttl = config.share.effective_ttl(input.expires_in);
+        let password_hash = input
+            .password
+            .as_deref()
+            .filter(|password| !password.is_empty())
+            .map(|password| {
+                SecureUtil::hash_password(password.as_bytes())
+                    .map_err(|e| AppError::internal(e.to_string()))
+            })
+            .transpose()?;
+
+        let token = generate_share_token()?;
+        let now = Local::now().naive_local();
+        let share = SysFileShareActiveModel {
+            id: Set(Ulid::new().to_string()),
+            file_id: Set(file.id.clone()),
+            token_hash: Set(content_hash(token.as_bytes())),
+            password_hash: Set(password_hash),
+            expires_at: Set(now + Duration::seconds(ttl as i64)),
+            max_downloads: Set(input.max_downloads),
+            download_count: Set(0),
+            revoked_at: Set(None),
+            revoked_by: Set(None),
+            ..Default::default()
+        };
+        let db = db_helper::get_rls_connection().await?;
+        let share = share.insert(&db).await.map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("file", format!("Created share link for file {}", file.id))
+                .with_user(operator)
+                .with_detail(json!({
+                    "shareId": share.id,
+                    "fileId": file.id,
+                    "fileName": file.file_name,
+                    "expiresAt": share.expires_at,
+                    "maxDownloads": share.max_downloads,
+                    "passwordProtected": share.password_hash.is_some(),
+                })),
+        );
+
+        let url = config.share.share_url(&token);
+        Ok(FileShareLink { share, token, url })
+    }
+
+    async fn find_share_links(&self, file_id: &str) -> Result<Vec<SysFileShareModel>, AppError> {
+        let file = self.find_file(file_id).await?;
+        let db = db_helper::get_rls_connection().await?;
+        SysFileShare::find()
+            .filter(SysFileShareColumn::FileId.eq(file.id))
+            .order_by_desc(SysFileShareColumn::CreatedAt)
+            .all(&db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn revoke_share_link(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SysFileShareModel, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let share = SysFileShare::find_by_id(id)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::ShareNotFound)?;
+        if share.revoked_at.is_some() {
+            return Ok(share);
+        }
+
+        let mut share: SysFileShareActiveModel = share.into();
+        share.revoked_at = Set(Some(Local::now().naive_local()));
+        share.revoked_by = Set(Some(operator.username()));
+        let share = share.update(&db).await.map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new(
+                "file",
+                format!("Revoked share link for file {}", share.file_id),
+            )
+            .with_user(operator)
+            .with_detail(json!({
+                "shareId": share.id,
+                "fileId": share.file_id,
+                "downloadCount": share.download_count,
+            })),
+        );
+
+        Ok(share)
+    }
+
+    async fn get_shared_file(&self, token: &str) -> Result<SharedFileInfo, AppError> {
+        let (share, file) = self.find_share_by_token(token).await?;
+        check_share_available(&share, Local::now().naive_local())?;
+        if !file.scan_status.is_downloadable() {
+            return Err(FileError::FileQuarantined.into());
+        }
+
+        Ok(SharedFileInfo {
+            file_name: file.file_name,
+            content_type: file.content_type,
+            size: file.size,
+            expires_at: share.expires_at,
+            password_required: share.password_hash.is_some(),
+            remaining_downloads: share
+                .max_downloads
+                .map(|max| (max - share.download_count).max(0)),
+        })
+    }
+
+    async fn download_shared_file(
+        &self,
+        token: &str,
+        input: DownloadSharedFileInput,
+        client_ip: &str,
+    ) -> Result<FileDownload, AppError> {
+        let (share, file) = self.find_share_by_token(token).await?;
+        let now = Local::now().naive_local();
+        check_share_available(&share, now)?;
+        self.verify_share_password(&share, &file, input.password.as_deref(), client_ip)
+            .await?;
+        // 分享后文件可能被重新隔离，每次下载都重新检查
+        if !file.scan_status.is_downloadable() {
+            return Err(FileError::FileQuarantined.into());
+        }
+
+        let db = db_helper::get_rls_connection().await?;
+        let blob = SysFileBlob::find_by_id(file.blob_hash.as_str())
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(FileError::BlobNotFound)?;
+        let store = BlobStore::resolve().await?;
+        let data = store.get(&blob.storage_key).await?;
+
+        // 条件更新计数，并发下载时不会超过下载次数上限
+        let result = SysFileShare::update_many()
+            .col_expr(
+                SysFileShareColumn::DownloadCount,
+                Expr::col(SysFileShareColumn::DownloadCount).add(1),
+            )
+            .filter(SysFileShareColumn::Id.eq(share.id.as_str()))
+            .filter(SysFileShareColumn::RevokedAt.is_null())
+            .filter(SysFileShareColumn::ExpiresAt.gt(now))
+            .filter(
+                Condition::any()
+                    .add(SysFileShareColumn::MaxDownloads.is_null())
+                    .add(
+                        Expr::col(SysFileShareColumn::DownloadCount)
+                            .lt(Expr::col(SysFileShareColumn::MaxDownloads)),
+                    ),
+            )
+            .exec(&db)
+            .await
+            .map_err(AppError::from)?;
+        if result.rows_affected == 0 {
+            return Err(FileError::ShareDownloadLimitReached.into());
+        }
+
+        record_audit(
+            AuditEntry::new(
+                "file",
+                format!("Downloaded file {} via share link", file.id),
+            )
+            .with_detail(json!({
+                "shareId": share.id,
+                "fileId": file.id,
+                "fileName": file.file_name,
+                "downloadCount": share.download_count + 1,
+                "ip": client_ip,
+            })),
+        );
+
+        Ok(FileDownload { file, data })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn share(max_downloads: Option<i32>, download_count: i32) -> SysFileShareModel {
+        let now = Local::now().naive_local();
+        SysFileShareModel {
+            id: "share".to_string(),
+            file_id: "file".to_string(),
+            token_hash: content_hash(b"token"),
+            password_hash: None,
+            expires_at: now + Duration::hours(1),
+            max_downloads,
+            download_count,
+            revoked_at: None,
+            revoked_by: None,
+            created_at: now,
+            created_by: "admin".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_check_share_available() {
+        let now = Local::now().naive_local();
+        assert!(check_share_available(&share(None, 100), now).is_ok());
+        assert!(check_share_available(&share(Some(2), 1), now).is_ok());
+        assert!(matches!(
+            check_share_available(&share(Some(2), 2), now),
+            Err(FileError::ShareDownloadLimitReached)
+        ));
+        assert!(matches!(
+            check_share_available(&share(None, 0), now + Duration::hours(2)),
+            Err(FileError::ShareUnavailable)
+        ));
+
+        let mut revoked = share(None, 0);
+        revoked.revoked_at = Some(now);
+        assert!(matches!(
+            check_share_available(&revoked, now),
+            Err(FileError::ShareUnavailable)
+        ));
+    }
+
+    fn blob(ref_count: i32, zero_ref_at: Option<NaiveDateTime>) -> SysFileBlobModel {
+        SysFileBlobModel {
+            hash: content_hash(b"data"),
+            size: 4,
+            storage_key: "blobs/hash".to_string(),
+            ref_count,
+            zero_ref_at,
+            created_at: Local::now().naive_local(),
+        }
+    }
+
+    #[test]
+    fn test_release_reference() {
+        let now = Local::now().naive_local();
+        assert_eq!(release_reference(3, now), (2, None));
+        assert_eq!(release_reference(1, now), (0, Some(now)));
+        // 计数异常时不会减为负数
+        assert_eq!(release_reference(0, now), (0, Some(now)));
+    }
+
+    #[test]
+    fn test_is_collectable() {
+        let now = Local::now().naive_local();
+        let deadline = now - Duration::hours(1);
+        assert!(is_collectable(
+            &blob(0, Some(now - Duration::hours(2))),
+            deadline
+        ));
+        // 宽限期内、仍有引用或已被重新上传的内容不清理
+        assert!(!is_collectable(&blob(0, Some(now)), deadline));
+        assert!(!is_collectable(
+            &blob(1, Some(now - Duration::hours(2))),
+            deadline
+        ));
+        assert!(!is_collectable(&blob(0, None), deadline));
+    }
+
+    #[test]
+    fn test_generate_share_token() {
+        let token = generate_share_token().unwrap();
+        assert_eq!(token.len(), 64);
+        assert_ne!(token, generate_share_token().unwrap());
+    }
+}
@@ -0,0 +1,233 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use serde_json::{json, Value};
+use server_config::ServerConfig;
+use server_core::web::{
+    admission::{self, AdmissionSnapshot},
+    auth::User,
+    error::AppError,
+    field_selection::FIELDS_PARAM,
+};
+use server_global::global::{self, RouteGuard, RouteInfo};
+use server_model::admin::{
+    entities::{casbin_rule::Column as CasbinRuleColumn, prelude::CasbinRule},
+    output::{DrainStatus, RouteMiddlewareStack},
+};
+
+use super::sys_instance_error::InstanceError;
+use crate::{
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper,
+    },
+    project_error, project_info,
+};
+
+/// 当前实例的运行状态
+#[async_trait]
+pub trait TInstanceService {
+    /// 获取并发准入控制状态，未启用时返回 `None`
+    async fn admission_status(&self) -> Result<Option<AdmissionSnapshot>, AppError>;
+    /// 获取排空状态
+    async fn drain_status(&self) -> Result<DrainStatus, AppError>;
+    /// 开始排空：就绪检查失败、停止领取后台任务，等待进行中的工作完成后退出
+    async fn drain(&self, operator: &User) -> Result<DrainStatus, AppError>;
+    /// 列出已声明的路由及其实际经过的中间件，用于排查限流、鉴权等问题
+    async fn middleware_stack(&self) -> Result<Vec<RouteMiddlewareStack>, AppError>;
+    /// 由路由清单生成 OpenAPI 文档
+    async fn openapi_document(&self) -> Result<Value, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysInstanceService;
+
+/// 单个接口的 OpenAPI 描述，路径参数统一按字符串声明
+fn openapi_operation(route: &RouteInfo) -> Value {
+    let mut parameters: Vec<Value> = route
+        .path
+        .split('/')
+        .filter_map(|segment| segment.strip_prefix(':'))
+        .map(|name| {
+            json!({
+                "name": name,
+                "in": "path",
+                "required": true,
+                "schema": { "type": "string" },
+            })
+        })
+        .collect();
+    if route.method.as_str() == "GET" {
+        parameters.push(json!({
+            "name": FIELDS_PARAM,
+            "in": "query",
+            "required": false,
+            "description": "只返回逗号分隔的字段",
+            "schema": { "type": "string" },
+        }));
+    }
+    let mut operation = json!({
+        "operationId": format!(
+            "{}{}",
+            route.method.as_str().to_lowercase(),
+            route.path.replace([':', '-'], "").replace('/', "_")
+        ),
+        "summary": route.summary,
+        "tags": route.tags,
+        "parameters": parameters,
+        "responses": { "200": { "description": "OK" } },
+    });
+    if route.guard == RouteGuard::Public {
+        operation["security"] = json!([]);
+    }
+    operation
+}
+
+impl SysInstanceService {
+    /// 开始排空，HTTP 接口和 SIGUSR1 信号共用
+    pub async fn start_drain(source: &str) -> Result<(), InstanceError> {
+        if !global::begin_drain() {
+            return Err(InstanceError::AlreadyDraining);
+        }
+
+        let (delay, timeout) = global::get_config::<ServerConfig>()
+            .await
+            .map(|config| (config.drain_delay, config.drain_timeout))
+            .unwrap_or((5, 30));
+        project_info!(
+            "Draining instance (triggered by {}), in-flight work: {}",
+            source,
+            global::in_flight_work()
+        );
+
+        tokio::spawn(async move {
+            // 先让负载均衡通过就绪检查摘除实例，再等待存量工作
+            tokio::time::sleep(Duration::from_secs(delay)).await;
+            if tokio::time::timeout(Duration::from_secs(timeout), global::wait_for_idle())
+                .await
+                .is_err()
+            {
+                project_error!(
+                    "Drain timed out after {}s with {} in-flight work, shutting down anyway",
+                    timeout,
+                    global::in_flight_work()
+                );
+            } else {
+                project_info!("Instance drained, shutting down");
+            }
+            global::complete_drain();
+        });
+
+        Ok(())
+    }
+
+    fn status() -> DrainStatus {
+        DrainStatus {
+            draining: global::is_draining(),
+            in_flight: global::in_flight_work(),
+        }
+    }
+}
+
+#[async_trait]
+impl TInstanceService for SysInstanceService {
+    async fn admission_status(&self) -> Result<Option<AdmissionSnapshot>, AppError> {
+        Ok(admission::admission_snapshot())
+    }
+
+    async fn drain_status(&self) -> Result<DrainStatus, AppError> {
+        Ok(Self::status())
+    }
+
+    async fn drain(&self, operator: &User) -> Result<DrainStatus, AppError> {
+        Self::start_drain("api").await?;
+
+        let status = Self::status();
+        record_audit(
+            AuditEntry::new("实例管理", "排空实例")
+                .with_user(operator)
+                .with_detail(json!(status)),
+        );
+        Ok(status)
+    }
+
+    async fn middleware_stack(&self) -> Result<Vec<RouteMiddlewareStack>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let policies = CasbinRule::find()
+            .filter(CasbinRuleColumn::Ptype.eq("p"))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(global::get_route_stacks()
+            .await
+            .into_iter()
+            .map(|stack| {
+                let route = stack.route;
+                let method = route.method.to_string();
+                let mut roles: Vec<String> = if stack.casbin {
+                    policies
+                        .iter()
+                        .filter(|policy| {
+                            policy.v2.as_deref() == Some(route.path.as_str())
+                                && policy.v3.as_deref() == Some(method.as_str())
+                        })
+                        .filter_map(|policy| policy.v0.clone())
+                        .collect()
+                } else {
+                    Vec::new()
+                };
+                roles.sort();
+                roles.dedup();
+
+                RouteMiddlewareStack {
+                    path: route.path,
+                    method,
+                    service_name: route.service_name,
+                    summary: route.summary,
+                    guard: match route.guard {
+                        RouteGuard::Permission => "permission",
+                        RouteGuard::Authenticated => "authenticated",
+                        RouteGuard::Public => "public",
+                    }
+                    .to_string(),
+                    roles,
+                    layers: stack.layers,
+                }
+            })
+            .collect())
+    }
+
+    async fn openapi_document(&self) -> Result<Value, AppError> {
+        let mut paths = serde_json::Map::new();
+        for route in global::get_collected_routes().await {
+            let path = route
+                .path
+                .split('/')
+                .map(|segment| match segment.strip_prefix(':') {
+                    Some(name) => format!("{{{}}}", name),
+                    None => segment.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join("/");
+            let item = paths.entry(path).or_insert_with(|| json!({}));
+            item[route.method.as_str().to_lowercase()] = openapi_operation(&route);
+        }
+
+        Ok(json!({
+            "openapi": "3.0.3",
+            "info": {
+                "title": "soybean-admin-rust",
+                "version": env!("CARGO_PKG_VERSION"),
+            },
+            "paths": paths,
+            "components": {
+                "securitySchemes": {
+                    "bearerAuth": { "type": "http", "scheme": "bearer", "bearerFormat": "JWT" },
+                },
+            },
+            "security": [{ "bearerAuth": [] }],
+        }))
+    }
+}
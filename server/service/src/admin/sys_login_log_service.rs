@@ -1,6 +1,6 @@
 use async_trait::async_trait;
 use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder};
-use server_core::web::{error::AppError, page::PaginatedData};
+use server_core::web::{error::AppError, page::PageResult};
 use server_model::admin::{
     entities::{
         prelude::SysLoginLog,
@@ -16,7 +16,7 @@ pub trait TLoginLogService {
     async fn find_paginated_login_logs(
         &self,
         params: LoginLogPageRequest,
-    ) -> Result<PaginatedData<SysLoginLogModel>, AppError>;
+    ) -> Result<PageResult<SysLoginLogModel>, AppError>;
 }
 
 pub struct SysLoginLogService;
@@ -26,8 +26,8 @@ impl TLoginLogService for SysLoginLogService {
     async fn find_paginated_login_logs(
         &self,
         params: LoginLogPageRequest,
-    ) -> Result<PaginatedData<SysLoginLogModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PageResult<SysLoginLogModel>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysLoginLog::find();
 
         if let Some(ref keywords) = params.keywords {
@@ -42,23 +42,14 @@ impl TLoginLogService for SysLoginLogService {
 
         query = query.order_by_desc(SysLoginLogColumn::CreatedAt);
 
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
 
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let paginator = query.paginate(&db, params.page_details.size);
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
     }
 }
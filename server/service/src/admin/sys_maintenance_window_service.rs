@@ -0,0 +1,264 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
+    Set,
+};
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_model::admin::{
+    entities::{
+        prelude::SysMaintenanceWindow,
+        sea_orm_active_enums::MaintenanceScheduleKind,
+        sys_maintenance_window::{
+            ActiveModel as SysMaintenanceWindowActiveModel, Column as SysMaintenanceWindowColumn,
+            Model as SysMaintenanceWindowModel,
+        },
+    },
+    input::{
+        CreateMaintenanceWindowInput, MaintenanceWindowPageRequest, UpdateMaintenanceWindowInput,
+    },
+    output::MaintenanceBanner,
+};
+use ulid::Ulid;
+
+use super::sys_maintenance_window_error::MaintenanceWindowError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+    maintenance_helper::{self, CronSchedule},
+};
+
+/// 横幅提前展示即将开始的窗口的时间
+const BANNER_LOOKAHEAD: Duration = Duration::hours(24);
+
+#[async_trait]
+pub trait TMaintenanceWindowService {
+    async fn find_paginated_windows(
+        &self,
+        params: MaintenanceWindowPageRequest,
+    ) -> Result<PageResult<SysMaintenanceWindowModel>, AppError>;
+
+    async fn create_window(
+        &self,
+        input: CreateMaintenanceWindowInput,
+        operator: &User,
+    ) -> Result<SysMaintenanceWindowModel, AppError>;
+    async fn get_window(&self, id: &str) -> Result<SysMaintenanceWindowModel, AppError>;
+    async fn update_window(
+        &self,
+        input: UpdateMaintenanceWindowInput,
+        operator: &User,
+    ) -> Result<SysMaintenanceWindowModel, AppError>;
+    async fn delete_window(&self, id: &str, operator: &User) -> Result<(), AppError>;
+
+    /// 进行中和 24 小时内开始的窗口，按开始时间排序
+    async fn get_banners(&self) -> Result<Vec<MaintenanceBanner>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysMaintenanceWindowService;
+
+impl SysMaintenanceWindowService {
+    async fn check_window(
+        &self,
+        id: Option<&str>,
+        input: &CreateMaintenanceWindowInput,
+    ) -> Result<(), AppError> {
+        match input.schedule_kind {
+            MaintenanceScheduleKind::Cron => {
+                let valid = input
+                    .cron
+                    .as_deref()
+                    .and_then(CronSchedule::parse)
+                    .is_some()
+                    && input.duration_minutes.is_some();
+                if !valid {
+                    return Err(MaintenanceWindowError::InvalidCron.into());
+                }
+            },
+            MaintenanceScheduleKind::Range => {
+                if input.start_at.is_none() || input.end_at.is_none() {
+                    return Err(MaintenanceWindowError::InvalidRange.into());
+                }
+            },
+        }
+        if let (Some(start_at), Some(end_at)) = (input.start_at, input.end_at) {
+            if start_at >= end_at {
+                return Err(MaintenanceWindowError::InvalidRange.into());
+            }
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let name_exists = SysMaintenanceWindow::find()
+            .filter(SysMaintenanceWindowColumn::Name.eq(&input.name))
+            .filter(SysMaintenanceWindowColumn::Id.ne(id.unwrap_or("-1")))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+
+        if name_exists {
+            return Err(MaintenanceWindowError::DuplicateName.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TMaintenanceWindowService for SysMaintenanceWindowService {
+    async fn find_paginated_windows(
+        &self,
+        params: MaintenanceWindowPageRequest,
+    ) -> Result<PageResult<SysMaintenanceWindowModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysMaintenanceWindow::find();
+
+        if let Some(ref keywords) = params.keywords {
+            let condition =
+                Condition::any().add(SysMaintenanceWindowColumn::Name.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        query = query.order_by_desc(SysMaintenanceWindowColumn::CreatedAt);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn create_window(
+        &self,
+        input: CreateMaintenanceWindowInput,
+        operator: &User,
+    ) -> Result<SysMaintenanceWindowModel, AppError> {
+        self.check_window(None, &input).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let window = SysMaintenanceWindowActiveModel {
+            id: Set(Ulid::new().to_string()),
+            name: Set(input.name),
+            message: Set(input.message),
+            schedule_kind: Set(input.schedule_kind),
+            cron: Set(input.cron.map(|cron| cron.trim().to_string())),
+            duration_minutes: Set(input.duration_minutes),
+            start_at: Set(input.start_at),
+            end_at: Set(input.end_at),
+            suppress_alerts: Set(input.suppress_alerts),
+            pause_jobs: Set(input.pause_jobs),
+            show_banner: Set(input.show_banner),
+            enabled: Set(input.enabled),
+            ..Default::default()
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+        maintenance_helper::invalidate();
+
+        record_audit(
+            AuditEntry::new("维护窗口", "创建维护窗口")
+                .with_user(operator)
+                .with_detail(json!(window)),
+        );
+        Ok(window)
+    }
+
+    async fn get_window(&self, id: &str) -> Result<SysMaintenanceWindowModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysMaintenanceWindow::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| MaintenanceWindowError::WindowNotFound.into())
+    }
+
+    async fn update_window(
+        &self,
+        input: UpdateMaintenanceWindowInput,
+        operator: &User,
+    ) -> Result<SysMaintenanceWindowModel, AppError> {
+        let existing_window = self.get_window(&input.id).await?;
+        self.check_window(Some(&input.id), &input.window).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        let mut window: SysMaintenanceWindowActiveModel = existing_window.into();
+        window.name = Set(input.window.name);
+        window.message = Set(input.window.message);
+        window.schedule_kind = Set(input.window.schedule_kind);
+        window.cron = Set(input.window.cron.map(|cron| cron.trim().to_string()));
+        window.duration_minutes = Set(input.window.duration_minutes);
+        window.start_at = Set(input.window.start_at);
+        window.end_at = Set(input.window.end_at);
+        window.suppress_alerts = Set(input.window.suppress_alerts);
+        window.pause_jobs = Set(input.window.pause_jobs);
+        window.show_banner = Set(input.window.show_banner);
+        window.enabled = Set(input.window.enabled);
+
+        let updated_window = window.update(db.as_ref()).await.map_err(AppError::from)?;
+        maintenance_helper::invalidate();
+
+        record_audit(
+            AuditEntry::new("维护窗口", "更新维护窗口")
+                .with_user(operator)
+                .with_detail(json!(updated_window)),
+        );
+        Ok(updated_window)
+    }
+
+    async fn delete_window(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        let window = self.get_window(id).await?;
+
+        let db = db_helper::get_db_connection().await?;
+        SysMaintenanceWindow::delete_by_id(id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        maintenance_helper::invalidate();
+
+        record_audit(
+            AuditEntry::new("维护窗口", "删除维护窗口")
+                .with_user(operator)
+                .with_detail(json!({ "id": window.id, "name": window.name })),
+        );
+        Ok(())
+    }
+
+    async fn get_banners(&self) -> Result<Vec<MaintenanceBanner>, AppError> {
+        let now = Local::now().naive_local();
+        let mut banners = maintenance_helper::enabled_windows()
+            .await?
+            .into_iter()
+            .filter(|window| window.show_banner)
+            .filter_map(|window| {
+                let (occurrence, active) = match maintenance_helper::occurrence_at(&window, now) {
+                    Some(occurrence) => (occurrence, true),
+                    None => (
+                        maintenance_helper::next_occurrence(&window, now, BANNER_LOOKAHEAD)?,
+                        false,
+                    ),
+                };
+                Some(MaintenanceBanner {
+                    id: window.id,
+                    name: window.name,
+                    message: window.message,
+                    start_at: occurrence.0,
+                    end_at: occurrence.1,
+                    active,
+                })
+            })
+            .collect::<Vec<_>>();
+        banners.sort_by_key(|banner| banner.start_at);
+        Ok(banners)
+    }
+}
@@ -1,22 +1,34 @@
+use std::collections::HashMap;
+
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, EntityTrait, QueryFilter, Set};
 use server_core::web::{auth::User, error::AppError};
+use server_global::global::MenuInfo;
 use server_model::admin::{
     entities::{
         prelude::{SysMenu, SysRoleMenu},
-        sea_orm_active_enums::Status,
+        sea_orm_active_enums::{MenuType, Status},
         sys_menu::{
-            ActiveModel as SysMenuActiveModel, Column as SysMenuColumn, Model as SysMenuModel,
+            self, ActiveModel as SysMenuActiveModel, Column as SysMenuColumn, Model as SysMenuModel,
         },
         sys_role_menu::Column as SysRoleMenuColumn,
     },
-    input::{CreateMenuInput, UpdateMenuInput},
-    output::{MenuRoute, MenuTree, RouteMeta},
+    input::{CreateMenuInput, MoveTreeNodeInput, SubtreeRequest, UpdateMenuInput},
+    output::{MenuRoute, MenuTree, RouteMeta, TreeNode},
 };
 use server_utils::TreeBuilder;
 
-use crate::{admin::sys_menu_error::MenuError, helper::db_helper};
+use crate::{
+    admin::sys_menu_error::MenuError,
+    helper::{
+        cache_helper::{self, namespace},
+        db_helper, tree_helper,
+    },
+    project_info,
+};
+
+/// 模块自动创建的菜单的创建人，用于识别不再声明的菜单
+const MODULE_MENU_CREATOR: &str = "module";
 
 #[async_trait]
 pub trait TMenuService {
@@ -43,6 +55,20 @@ pub trait TMenuService {
         role_id: String,
         domain: String,
     ) -> Result<Vec<i32>, AppError>;
+
+    /// 按模块声明补齐菜单
+    ///
+    /// 只创建缺失的菜单，已存在的菜单保留管理员的修改；
+    /// 由模块创建、但已不再声明的菜单只告警不删除，其可能已分配给角色
+    async fn sync_menus(&self, menus: Vec<MenuInfo>) -> Result<(), AppError>;
+
+    async fn get_menu_children(&self, pid: &str) -> Result<Vec<TreeNode<SysMenuModel>>, AppError>;
+    async fn get_menu_subtree(
+        &self,
+        id: &str,
+        params: SubtreeRequest,
+    ) -> Result<TreeNode<SysMenuModel>, AppError>;
+    async fn move_menu(&self, id: &str, input: MoveTreeNodeInput) -> Result<(), AppError>;
 }
 
 #[derive(Clone)]
@@ -55,7 +81,7 @@ impl SysMenuService {
             pid: menu.pid.clone(),
             menu_type: menu.menu_type.clone(),
             menu_name: menu.menu_name.clone(),
-            icon_type: menu.icon_type.clone(),
+            icon_type: menu.icon_type,
             icon: menu.icon.clone(),
             route_name: menu.route_name.clone(),
             route_path: menu.route_path.clone(),
@@ -95,12 +121,12 @@ impl SysMenuService {
     }
 
     async fn check_menu_exists(&self, id: Option<i32>, route_name: &str) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let route_name_exists = SysMenu::find()
             .filter(SysMenuColumn::RouteName.eq(route_name))
             .filter(SysMenuColumn::Id.ne(id.unwrap_or(-1)))
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?
             .is_some();
@@ -116,12 +142,12 @@ impl SysMenuService {
 #[async_trait]
 impl TMenuService for SysMenuService {
     async fn tree_menu(&self) -> Result<Vec<MenuTree>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let menus = SysMenu::find()
             .filter(SysMenuColumn::Constant.eq(false))
             .filter(SysMenuColumn::Status.eq(Status::Enabled))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -130,23 +156,20 @@ impl TMenuService for SysMenuService {
     }
 
     async fn get_menu_list(&self) -> Result<Vec<MenuTree>, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        let menus = SysMenu::find()
-            .all(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let db = db_helper::get_rls_connection().await?;
+        let menus = SysMenu::find().all(&db).await.map_err(AppError::from)?;
 
         let menu_trees: Vec<MenuTree> = menus.iter().map(Self::build_menu_tree).collect();
         Ok(Self::build_tree_structure(menu_trees))
     }
 
     async fn get_constant_routes(&self) -> Result<Vec<MenuRoute>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let menus: Vec<SysMenuModel> = SysMenu::find()
             .filter(SysMenuColumn::Constant.eq(true))
             .filter(SysMenuColumn::Status.eq(Status::Enabled))
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -180,11 +203,11 @@ impl TMenuService for SysMenuService {
     async fn create_menu(
         &self,
         input: CreateMenuInput,
-        user: User,
+        _user: User,
     ) -> Result<SysMenuModel, AppError> {
         self.check_menu_exists(None, &input.route_name).await?;
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let menu = SysMenuActiveModel {
             menu_type: Set(input.menu_type),
@@ -205,30 +228,33 @@ impl TMenuService for SysMenuService {
             constant: Set(input.constant),
             href: Set(input.href),
             multi_tab: Set(input.multi_tab),
-
-            created_by: Set(user.user_id()),
             ..Default::default()
         };
 
-        let result = menu.insert(db.as_ref()).await.map_err(AppError::from)?;
+        let result = menu.insert(&db).await.map_err(AppError::from)?;
+        // 菜单 ID 自增，新 ID 可能此前被查询过
+        cache_helper::forget_not_found(namespace::MENU, &result.id.to_string()).await;
         Ok(result)
     }
 
     async fn get_menu(&self, id: i32) -> Result<SysMenuModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysMenu::find_by_id(id)
-            .one(db.as_ref())
-            .await
-            .map_err(AppError::from)?
-            .ok_or_else(|| MenuError::MenuNotFound.into())
+        cache_helper::find_by_id(namespace::MENU, &id.to_string(), || async {
+            let db = db_helper::get_rls_connection().await?;
+            SysMenu::find_by_id(id)
+                .one(&db)
+                .await
+                .map_err(AppError::from)
+        })
+        .await?
+        .ok_or_else(|| MenuError::MenuNotFound.into())
     }
 
     async fn update_menu(
         &self,
         input: UpdateMenuInput,
-        user: User,
+        _user: User,
     ) -> Result<SysMenuModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let existing_menu = self.get_menu(input.id).await?;
 
         self.check_menu_exists(Some(input.id), &input.menu.route_name)
@@ -254,17 +280,14 @@ impl TMenuService for SysMenuService {
         menu.href = Set(input.menu.href);
         menu.multi_tab = Set(input.menu.multi_tab);
 
-        menu.updated_at = Set(Some(Local::now().naive_local()));
-        menu.updated_by = Set(Some(user.user_id()));
-
-        let updated_menu = menu.update(db.as_ref()).await.map_err(AppError::from)?;
+        let updated_menu = menu.update(&db).await.map_err(AppError::from)?;
         Ok(updated_menu)
     }
 
     async fn delete_menu(&self, id: i32, _user: User) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         SysMenu::delete_by_id(id)
-            .exec(db.as_ref())
+            .exec(&db)
             .await
             .map_err(AppError::from)?;
         Ok(())
@@ -275,7 +298,7 @@ impl TMenuService for SysMenuService {
         role_id: String,
         domain: String,
     ) -> Result<Vec<i32>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
 
         let role_menus = SysRoleMenu::find()
             .filter(
@@ -283,7 +306,7 @@ impl TMenuService for SysMenuService {
                     .add(SysRoleMenuColumn::RoleId.eq(role_id))
                     .add(SysRoleMenuColumn::Domain.eq(domain)),
             )
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -300,10 +323,141 @@ impl TMenuService for SysMenuService {
                     .add(SysMenuColumn::Status.eq(Status::Enabled))
                     .add(SysMenuColumn::Constant.eq(false)),
             )
-            .all(db.as_ref())
+            .all(&db)
             .await
             .map_err(AppError::from)?;
 
         Ok(menus.iter().map(|menu| menu.id).collect())
     }
+
+    async fn sync_menus(&self, menus: Vec<MenuInfo>) -> Result<(), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let existing = SysMenu::find().all(&db).await.map_err(AppError::from)?;
+
+        // 多个模块声明同一目录时取第一次声明
+        let mut declared: Vec<MenuInfo> = Vec::new();
+        for menu in menus {
+            if !declared.iter().any(|m| m.route_name == menu.route_name) {
+                declared.push(menu);
+            }
+        }
+
+        let mut ids: HashMap<String, i32> = existing
+            .iter()
+            .map(|menu| (menu.route_name.clone(), menu.id))
+            .collect();
+        let mut pending: Vec<&MenuInfo> = declared
+            .iter()
+            .filter(|menu| !ids.contains_key(&menu.route_name))
+            .collect();
+        let mut created = Vec::new();
+        // 上级菜单可能也是本次新建的，逐层创建
+        while !pending.is_empty() {
+            let (ready, waiting): (Vec<&MenuInfo>, Vec<&MenuInfo>) =
+                pending.into_iter().partition(|menu| {
+                    menu.parent
+                        .as_ref()
+                        .is_none_or(|parent| ids.contains_key(parent))
+                });
+            if ready.is_empty() {
+                for menu in waiting {
+                    tracing::warn!(
+                        route_name = %menu.route_name,
+                        parent = ?menu.parent,
+                        "Module menu parent is not declared, skipped"
+                    );
+                }
+                break;
+            }
+
+            for menu in ready {
+                let pid = menu
+                    .parent
+                    .as_ref()
+                    .and_then(|parent| ids.get(parent))
+                    .map_or_else(|| "0".to_string(), ToString::to_string);
+                let model = SysMenuActiveModel {
+                    menu_type: Set(if menu.directory {
+                        MenuType::Directory
+                    } else {
+                        MenuType::Menu
+                    }),
+                    menu_name: Set(menu.route_name.clone()),
+                    icon_type: Set(Some(1)),
+                    icon: Set(menu.icon.clone()),
+                    route_name: Set(menu.route_name.clone()),
+                    route_path: Set(menu.route_path.clone()),
+                    component: Set(menu.component.clone()),
+                    path_param: Set(None),
+                    status: Set(Status::Enabled),
+                    active_menu: Set(menu.active_menu.clone()),
+                    hide_in_menu: Set(Some(menu.active_menu.is_some())),
+                    pid: Set(pid),
+                    sequence: Set(menu.sequence),
+                    i18n_key: Set(Some(format!("route.{}", menu.route_name))),
+                    keep_alive: Set(Some(false)),
+                    constant: Set(false),
+                    href: Set(None),
+                    multi_tab: Set(Some(false)),
+                    created_by: Set(MODULE_MENU_CREATOR.to_string()),
+                    ..Default::default()
+                }
+                .insert(&db)
+                .await
+                .map_err(AppError::from)?;
+                cache_helper::forget_not_found(namespace::MENU, &model.id.to_string()).await;
+                ids.insert(model.route_name.clone(), model.id);
+                created.push(model.route_name);
+            }
+            pending = waiting;
+        }
+        if !created.is_empty() {
+            project_info!("Created module menus: {}", created.join(", "));
+        }
+
+        let orphaned: Vec<&str> = existing
+            .iter()
+            .filter(|menu| {
+                menu.created_by == MODULE_MENU_CREATOR
+                    && !declared.iter().any(|m| m.route_name == menu.route_name)
+            })
+            .map(|menu| menu.route_name.as_str())
+            .collect();
+        for route_name in &orphaned {
+            tracing::warn!(
+                route_name = %route_name,
+                "Menu created by a module is no longer declared"
+            );
+        }
+        if !orphaned.is_empty() {
+            tracing::info!(
+                target: "metrics",
+                event = "orphaned_module_menus",
+                count = orphaned.len(),
+            );
+        }
+
+        Ok(())
+    }
+
+    /// 懒加载下级菜单，`pid` 为 `0` 时返回顶级菜单
+    async fn get_menu_children(&self, pid: &str) -> Result<Vec<TreeNode<SysMenuModel>>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        tree_helper::find_children::<sys_menu::Entity, _>(&db, pid).await
+    }
+
+    async fn get_menu_subtree(
+        &self,
+        id: &str,
+        params: SubtreeRequest,
+    ) -> Result<TreeNode<SysMenuModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        tree_helper::find_subtree::<sys_menu::Entity, _>(&db, id, params.depth).await
+    }
+
+    /// 连同下级菜单一起移动到新的上级菜单
+    async fn move_menu(&self, id: &str, input: MoveTreeNodeInput) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        tree_helper::move_subtree::<sys_menu::Entity>(db.as_ref(), id, &input.pid).await
+    }
 }
@@ -0,0 +1,481 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration as StdDuration,
+};
+
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDate, NaiveDateTime};
+use sea_orm::{
+    sea_query::{Alias, Expr, Func, OnConflict},
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Select, Set,
+};
+use serde_json::json;
+use server_config::MeteringConfig;
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{
+            SysAccessKey, SysApiUsage, SysDomain, SysFile, SysLoginLog, SysMetering, SysUser,
+        },
+        sea_orm_active_enums::ApiUsageSubjectKind,
+        sys_access_key::Column as SysAccessKeyColumn,
+        sys_api_usage::Column as SysApiUsageColumn,
+        sys_domain::Column as SysDomainColumn,
+        sys_file::Column as SysFileColumn,
+        sys_login_log::Column as SysLoginLogColumn,
+        sys_metering::{
+            ActiveModel as SysMeteringActiveModel, Column as SysMeteringColumn,
+            Model as SysMeteringModel,
+        },
+        sys_user::Column as SysUserColumn,
+    },
+    input::{MeteringExportRequest, MeteringPageRequest},
+    output::MeteringExport,
+};
+use ulid::Ulid;
+
+use crate::helper::{
+    db_helper::{self, ReadOnlyConnection},
+    export_watermark_helper,
+};
+
+const CSV_HEADER: &str = "domain,date,active_users,storage_bytes,api_calls";
+
+#[async_trait]
+pub trait TMeteringService {
+    async fn find_paginated_metering(
+        &self,
+        params: MeteringPageRequest,
+    ) -> Result<PageResult<SysMeteringModel>, AppError>;
+    /// 导出为 CSV，表头为 `domain,date,active_users,storage_bytes,api_calls`，
+    /// 启用内容水印时表头前有一行以 `#` 开头的水印注释
+    async fn export_csv(
+        &self,
+        params: MeteringExportRequest,
+        operator: &User,
+    ) -> Result<MeteringExport, AppError>;
+
+    /// 汇总指定日期各租户的用量并写入计量表，重复执行时覆盖并重新推送
+    async fn aggregate_day(&self, date: NaiveDate) -> Result<Vec<SysMeteringModel>, AppError>;
+    /// 每日任务：汇总前一天的用量（已汇总则跳过），再推送尚未推送的记录
+    async fn run_daily(&self) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysMeteringService;
+
+/// 计量报表的只读连接，配置了报表实例时使用该实例
+async fn report_connection() -> Result<ReadOnlyConnection, AppError> {
+    let instance = global::get_config::<MeteringConfig>()
+        .await
+        .and_then(|config| config.report_db_instance.clone());
+    match instance {
+        Some(name) => db_helper::get_read_only_connection(&name).await,
+        None => db_helper::get_read_connection().await,
+    }
+}
+
+fn filter_metering(
+    mut query: Select<SysMetering>,
+    domain: Option<&str>,
+    start: Option<NaiveDate>,
+    end: Option<NaiveDate>,
+) -> Select<SysMetering> {
+    if let Some(domain) = domain {
+        query = query.filter(SysMeteringColumn::Domain.eq(domain));
+    }
+    if let Some(start) = start {
+        query = query.filter(SysMeteringColumn::MeteringDate.gte(start));
+    }
+    if let Some(end) = end {
+        query = query.filter(SysMeteringColumn::MeteringDate.lte(end));
+    }
+    query
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+fn render_csv(records: &[SysMeteringModel]) -> String {
+    let mut csv = format!("{}\n", CSV_HEADER);
+    for record in records {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            csv_field(&record.domain),
+            record.metering_date,
+            record.active_users,
+            record.storage_bytes,
+            record.api_calls
+        ));
+    }
+    csv
+}
+
+fn push_error(e: reqwest::Error) -> AppError {
+    AppError::dependency(format!("Failed to push metering: {}", e))
+}
+
+impl SysMeteringService {
+    /// API 调用次数按租户汇总：用户按所属域，API Key 按签名校验的 AccessKeyId 所属域；
+    /// 简单校验的 API Key 只记录了掩码，无法归属租户，不计入
+    async fn api_calls_by_domain(
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<HashMap<String, i64>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let usage = SysApiUsage::find()
+            .select_only()
+            .column(SysApiUsageColumn::SubjectKind)
+            .column(SysApiUsageColumn::Subject)
+            .column_as(
+                Expr::col(SysApiUsageColumn::Requests)
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "requests",
+            )
+            .filter(SysApiUsageColumn::BucketStart.gte(start))
+            .filter(SysApiUsageColumn::BucketStart.lt(end))
+            .group_by(SysApiUsageColumn::SubjectKind)
+            .group_by(SysApiUsageColumn::Subject)
+            .into_tuple::<(ApiUsageSubjectKind, String, i64)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let subjects = |kind: ApiUsageSubjectKind| {
+            usage
+                .iter()
+                .filter(|(subject_kind, ..)| *subject_kind == kind)
+                .map(|(_, subject, _)| subject.clone())
+                .collect::<Vec<_>>()
+        };
+        let mut owners: HashMap<(ApiUsageSubjectKind, String), String> = HashMap::new();
+        let users = SysUser::find()
+            .select_only()
+            .column(SysUserColumn::Id)
+            .column(SysUserColumn::Domain)
+            .filter(SysUserColumn::Id.is_in(subjects(ApiUsageSubjectKind::User)))
+            .into_tuple::<(String, String)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+        owners.extend(
+            users
+                .into_iter()
+                .map(|(id, domain)| ((ApiUsageSubjectKind::User, id), domain)),
+        );
+        let keys = SysAccessKey::find()
+            .select_only()
+            .column(SysAccessKeyColumn::AccessKeyId)
+            .column(SysAccessKeyColumn::Domain)
+            .filter(SysAccessKeyColumn::AccessKeyId.is_in(subjects(ApiUsageSubjectKind::ApiKey)))
+            .into_tuple::<(String, String)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+        owners.extend(
+            keys.into_iter()
+                .map(|(id, domain)| ((ApiUsageSubjectKind::ApiKey, id), domain)),
+        );
+
+        let mut calls = HashMap::new();
+        for (kind, subject, requests) in usage {
+            if let Some(domain) = owners.get(&(kind, subject)) {
+                *calls.entry(domain.clone()).or_insert(0) += requests;
+            }
+        }
+        Ok(calls)
+    }
+
+    async fn push(
+        config: &MeteringConfig,
+        url: &str,
+        date: NaiveDate,
+        records: &[SysMeteringModel],
+    ) -> Result<(), AppError> {
+        let client = reqwest::Client::builder()
+            .timeout(StdDuration::from_secs(config.timeout))
+            .build()
+            .map_err(push_error)?;
+        let mut request = client.post(url);
+        if let Some(authorization) = &config.webhook_authorization {
+            request = request.header(reqwest::header::AUTHORIZATION, authorization);
+        }
+        request
+            .json(&json!({ "date": date, "tenants": records }))
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map(|_| ())
+            .map_err(push_error)
+    }
+}
+
+#[async_trait]
+impl TMeteringService for SysMeteringService {
+    async fn find_paginated_metering(
+        &self,
+        params: MeteringPageRequest,
+    ) -> Result<PageResult<SysMeteringModel>, AppError> {
+        let db = report_connection().await?;
+        let query = filter_metering(
+            SysMetering::find(),
+            params.domain.as_deref(),
+            params.start,
+            params.end,
+        )
+        .order_by_desc(SysMeteringColumn::MeteringDate)
+        .order_by_asc(SysMeteringColumn::Domain);
+
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn export_csv(
+        &self,
+        params: MeteringExportRequest,
+        operator: &User,
+    ) -> Result<MeteringExport, AppError> {
+        let db = report_connection().await?;
+        let records = filter_metering(
+            SysMetering::find(),
+            params.domain.as_deref(),
+            params.start,
+            params.end,
+        )
+        .order_by_asc(SysMeteringColumn::MeteringDate)
+        .order_by_asc(SysMeteringColumn::Domain)
+        .all(&db)
+        .await
+        .map_err(AppError::from)?;
+
+        let csv = render_csv(&records);
+        Ok(
+            match export_watermark_helper::issue("metering", operator).await {
+                Some(watermark) => MeteringExport {
+                    csv: watermark.prepend_csv(csv),
+                    watermark_id: Some(watermark.id),
+                },
+                None => MeteringExport {
+                    csv,
+                    watermark_id: None,
+                },
+            },
+        )
+    }
+
+    async fn aggregate_day(&self, date: NaiveDate) -> Result<Vec<SysMeteringModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let start = date.and_hms_opt(0, 0, 0).unwrap_or_default();
+        let end = start + Duration::days(1);
+
+        // 没有任何用量的租户也记录一行，便于计费系统对账
+        let mut usage: BTreeMap<String, (i64, i64, i64)> = SysDomain::find()
+            .select_only()
+            .column(SysDomainColumn::Code)
+            .into_tuple::<String>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|domain| (domain, (0, 0, 0)))
+            .collect();
+
+        let active_users = SysLoginLog::find()
+            .select_only()
+            .column(SysLoginLogColumn::Domain)
+            .column_as(
+                Expr::expr(Func::count_distinct(Expr::col(SysLoginLogColumn::UserId))),
+                "active_users",
+            )
+            .filter(SysLoginLogColumn::LoginTime.gte(start))
+            .filter(SysLoginLogColumn::LoginTime.lt(end))
+            .group_by(SysLoginLogColumn::Domain)
+            .into_tuple::<(String, i64)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+        for (domain, count) in active_users {
+            usage.entry(domain).or_default().0 = count;
+        }
+
+        // 存储为汇总时的快照
+        let storage = SysFile::find()
+            .select_only()
+            .column(SysFileColumn::Domain)
+            .column_as(
+                Expr::col(SysFileColumn::Size)
+                    .sum()
+                    .cast_as(Alias::new("bigint")),
+                "storage_bytes",
+            )
+            .group_by(SysFileColumn::Domain)
+            .into_tuple::<(String, i64)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+        for (domain, bytes) in storage {
+            usage.entry(domain).or_default().1 = bytes;
+        }
+
+        for (domain, calls) in Self::api_calls_by_domain(start, end).await? {
+            usage.entry(domain).or_default().2 = calls;
+        }
+
+        let now = Local::now().naive_local();
+        let rows = usage
+            .iter()
+            .map(
+                |(domain, (active_users, storage_bytes, api_calls))| SysMeteringActiveModel {
+                    id: Set(Ulid::new().to_string()),
+                    domain: Set(domain.clone()),
+                    metering_date: Set(date),
+                    active_users: Set(*active_users),
+                    storage_bytes: Set(*storage_bytes),
+                    api_calls: Set(*api_calls),
+                    created_at: Set(now),
+                    pushed_at: Set(None),
+                },
+            )
+            .collect::<Vec<_>>();
+        if rows.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        SysMetering::insert_many(rows)
+            .on_conflict(
+                OnConflict::columns([SysMeteringColumn::Domain, SysMeteringColumn::MeteringDate])
+                    .update_columns([
+                        SysMeteringColumn::ActiveUsers,
+                        SysMeteringColumn::StorageBytes,
+                        SysMeteringColumn::ApiCalls,
+                        SysMeteringColumn::CreatedAt,
+                        SysMeteringColumn::PushedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        for (domain, (active_users, storage_bytes, api_calls)) in &usage {
+            tracing::info!(
+                target: "metrics",
+                event = "tenant_metering",
+                domain = %domain,
+                date = %date,
+                active_users = active_users,
+                storage_bytes = storage_bytes,
+                api_calls = api_calls,
+            );
+        }
+
+        SysMetering::find()
+            .filter(SysMeteringColumn::MeteringDate.eq(date))
+            .order_by_asc(SysMeteringColumn::Domain)
+            .all(&db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn run_daily(&self) -> Result<(), AppError> {
+        let config = global::get_config::<MeteringConfig>()
+            .await
+            .map(|config| (*config).clone())
+            .unwrap_or_default();
+        let db = db_helper::get_rls_connection().await?;
+
+        let yesterday = Local::now().date_naive() - Duration::days(1);
+        let aggregated = SysMetering::find()
+            .filter(SysMeteringColumn::MeteringDate.eq(yesterday))
+            .count(&db)
+            .await
+            .map_err(AppError::from)?
+            > 0;
+        if !aggregated {
+            self.aggregate_day(yesterday).await?;
+        }
+
+        let Some(url) = config.webhook_url.as_deref() else {
+            return Ok(());
+        };
+        let pending = SysMetering::find()
+            .filter(SysMeteringColumn::PushedAt.is_null())
+            .order_by_asc(SysMeteringColumn::MeteringDate)
+            .order_by_asc(SysMeteringColumn::Domain)
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut by_date: BTreeMap<NaiveDate, Vec<SysMeteringModel>> = BTreeMap::new();
+        for record in pending {
+            by_date
+                .entry(record.metering_date)
+                .or_default()
+                .push(record);
+        }
+        // 按日期顺序推送，失败时停止，下次执行从失败的日期继续
+        for (date, records) in by_date {
+            Self::push(&config, url, date, &records).await?;
+            SysMetering::update_many()
+                .col_expr(
+                    SysMeteringColumn::PushedAt,
+                    Expr::value(Local::now().naive_local()),
+                )
+                .filter(SysMeteringColumn::MeteringDate.eq(date))
+                .filter(SysMeteringColumn::Id.is_in(records.iter().map(|record| record.id.clone())))
+                .exec(&db)
+                .await
+                .map_err(AppError::from)?;
+            tracing::info!(
+                target: "metrics",
+                event = "tenant_metering_pushed",
+                date = %date,
+                count = records.len(),
+            );
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_csv() {
+        let record = |domain: &str| SysMeteringModel {
+            id: "1".to_string(),
+            domain: domain.to_string(),
+            metering_date: NaiveDate::from_ymd_opt(2026, 10, 14).unwrap(),
+            active_users: 3,
+            storage_bytes: 1024,
+            api_calls: 42,
+            created_at: NaiveDate::from_ymd_opt(2026, 10, 15)
+                .unwrap()
+                .and_hms_opt(1, 0, 0)
+                .unwrap(),
+            pushed_at: None,
+        };
+
+        assert_eq!(
+            render_csv(&[record("built-in"), record("acme, \"inc\"")]),
+            "domain,date,active_users,storage_bytes,api_calls\n\
+             built-in,2026-10-14,3,1024,42\n\
+             \"acme, \"\"inc\"\"\",2026-10-14,3,1024,42\n"
+        );
+    }
+}
@@ -0,0 +1,247 @@
+use std::{
+    collections::HashSet,
+    sync::{Arc, Mutex},
+};
+
+use async_trait::async_trait;
+use migration::{MigrationTrait, Migrator, MigratorTrait, SchemaManager};
+use sea_orm::{
+    Database, DatabaseBackend, DbErr, ProxyDatabaseTrait, ProxyExecResult, ProxyRow, Statement,
+};
+use server_core::web::error::AppError;
+use server_model::admin::output::{MigrationPlan, MigrationRisk, PendingMigration};
+
+use super::sys_migration_error::MigrationError;
+use crate::helper::db_helper;
+
+#[async_trait]
+pub trait TMigrationService {
+    /// 列出待执行的迁移及其 SQL 和风险评估，不会修改数据库
+    async fn plan_migrations(&self) -> Result<MigrationPlan, AppError>;
+}
+
+/// 只记录语句、不连接数据库的代理后端
+///
+/// 迁移通过 `SchemaManager` 按 PostgreSQL 方言构建语句后交给该后端，执行类语句被记录下来，
+/// 查询类语句无法给出结果，直接返回错误
+#[derive(Debug, Default)]
+struct StatementRecorder {
+    statements: Arc<Mutex<Vec<String>>>,
+}
+
+#[async_trait]
+impl ProxyDatabaseTrait for StatementRecorder {
+    async fn query(&self, statement: Statement) -> Result<Vec<ProxyRow>, DbErr> {
+        Err(DbErr::Custom(format!(
+            "Query is not available while deriving migration SQL: {}",
+            statement
+        )))
+    }
+
+    async fn execute(&self, statement: Statement) -> Result<ProxyExecResult, DbErr> {
+        self.statements
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(statement.to_string());
+        Ok(ProxyExecResult::default())
+    }
+}
+
+#[derive(Clone)]
+pub struct SysMigrationService;
+
+impl SysMigrationService {
+    /// 在只记录语句的连接上执行迁移，返回生成的 SQL
+    ///
+    /// 迁移中包含查询（如判断表是否存在）时无法给出结果，返回 `None`
+    async fn derive_statements(migration: &dyn MigrationTrait) -> Option<Vec<String>> {
+        let recorder = StatementRecorder::default();
+        let statements = recorder.statements.clone();
+        let db = Database::connect_proxy(DatabaseBackend::Postgres, Arc::new(Box::new(recorder)))
+            .await
+            .ok()?;
+
+        migration.up(&SchemaManager::new(&db)).await.ok()?;
+
+        let statements = statements.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        Some(statements)
+    }
+}
+
+/// 根据 SQL 评估迁移风险
+fn assess_risk(statements: &[String]) -> (MigrationRisk, Vec<String>) {
+    let mut risk = MigrationRisk::Low;
+    let mut reasons = Vec::new();
+    let mut raise = |level: MigrationRisk, reason: String| {
+        risk = risk.max(level);
+        reasons.push(reason);
+    };
+
+    // 同一迁移中新建的表为空表，在其上建索引不影响线上写入
+    let created_tables: HashSet<String> = statements
+        .iter()
+        .filter_map(|statement| {
+            let sql = statement.to_uppercase();
+            let rest = sql.strip_prefix("CREATE TABLE ")?;
+            let rest = rest.strip_prefix("IF NOT EXISTS ").unwrap_or(rest);
+            rest.split_whitespace()
+                .next()
+                .map(|table| table.trim_matches('"').to_string())
+        })
+        .collect();
+    let index_target = |sql: &str| {
+        sql.split_once(" ON ")
+            .and_then(|(_, rest)| rest.split_whitespace().next())
+            .map(|table| table.trim_matches('"').to_string())
+    };
+
+    for statement in statements {
+        let sql = statement.to_uppercase();
+        let summary: String = statement.chars().take(80).collect();
+
+        if sql.starts_with("DROP TABLE") || sql.contains(" DROP COLUMN ") {
+            raise(MigrationRisk::High, format!("drops data: {}", summary));
+        }
+        if sql.starts_with("ALTER TABLE") && sql.contains(" TYPE ") {
+            raise(
+                MigrationRisk::High,
+                format!("column type change rewrites table: {}", summary),
+            );
+        }
+        if sql.starts_with("ALTER TABLE")
+            && sql.contains(" ADD COLUMN ")
+            && sql.contains("NOT NULL")
+        {
+            if sql.contains(" DEFAULT ") {
+                raise(
+                    MigrationRisk::Medium,
+                    format!("adds NOT NULL column with default: {}", summary),
+                );
+            } else {
+                raise(
+                    MigrationRisk::High,
+                    format!(
+                        "adds NOT NULL column without default, fails on non-empty table: {}",
+                        summary
+                    ),
+                );
+            }
+        }
+        if sql.starts_with("CREATE")
+            && sql.contains(" INDEX ")
+            && !sql.contains("CONCURRENTLY")
+            && !index_target(&sql).is_some_and(|table| created_tables.contains(&table))
+        {
+            raise(
+                MigrationRisk::Medium,
+                format!("index build blocks writes on existing tables: {}", summary),
+            );
+        }
+    }
+
+    (risk, reasons)
+}
+
+#[async_trait]
+impl TMigrationService for SysMigrationService {
+    async fn plan_migrations(&self) -> Result<MigrationPlan, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let pending_names: HashSet<String> = Migrator::get_pending_migrations(db.as_ref())
+            .await
+            .map_err(|e| MigrationError::StatusUnavailable(e.to_string()))?
+            .iter()
+            .map(|migration| migration.name().to_string())
+            .collect();
+
+        let mut pending = Vec::new();
+        for migration in Migrator::migrations() {
+            let name = migration.name().to_string();
+            if !pending_names.contains(&name) {
+                continue;
+            }
+
+            let plan = match Self::derive_statements(migration.as_ref()).await {
+                Some(statements) => {
+                    let (risk, reasons) = assess_risk(&statements);
+                    PendingMigration {
+                        name,
+                        statements,
+                        risk,
+                        reasons,
+                    }
+                },
+                None => PendingMigration {
+                    name,
+                    statements: Vec::new(),
+                    risk: MigrationRisk::Unknown,
+                    reasons: vec!["SQL could not be derived, review the migration code".to_string()],
+                },
+            };
+            pending.push(plan);
+        }
+
+        let highest_risk = pending.iter().map(|migration| migration.risk).max();
+        Ok(MigrationPlan {
+            pending,
+            highest_risk,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assess(statements: &[&str]) -> MigrationRisk {
+        let statements: Vec<String> = statements.iter().map(|s| s.to_string()).collect();
+        assess_risk(&statements).0
+    }
+
+    #[test]
+    fn test_assess_risk() {
+        assert_eq!(
+            assess(&[r#"CREATE TABLE "sys_file" ("id" varchar NOT NULL PRIMARY KEY)"#]),
+            MigrationRisk::Low
+        );
+        assert_eq!(
+            assess(&[r#"CREATE INDEX "idx_file_hash" ON "sys_file" ("hash")"#]),
+            MigrationRisk::Medium
+        );
+        assert_eq!(
+            assess(&[
+                r#"CREATE TABLE "sys_file" ("id" varchar NOT NULL PRIMARY KEY, "hash" varchar)"#,
+                r#"CREATE INDEX "idx_file_hash" ON "sys_file" ("hash")"#,
+            ]),
+            MigrationRisk::Low
+        );
+        assert_eq!(
+            assess(&[
+                r#"ALTER TABLE "sys_file" ADD COLUMN "scan_status" varchar NOT NULL DEFAULT 'clean'"#
+            ]),
+            MigrationRisk::Medium
+        );
+        assert_eq!(
+            assess(&[r#"ALTER TABLE "sys_file" ADD COLUMN "scan_status" varchar NOT NULL"#]),
+            MigrationRisk::High
+        );
+        assert_eq!(
+            assess(&[r#"ALTER TABLE "sys_file" ALTER COLUMN "size" TYPE bigint"#]),
+            MigrationRisk::High
+        );
+    }
+
+    #[tokio::test]
+    async fn test_derive_statements_without_database() {
+        let migration = Migrator::migrations()
+            .into_iter()
+            .find(|migration| migration.name().ends_with("_create_sys_param"))
+            .unwrap();
+
+        let statements = SysMigrationService::derive_statements(migration.as_ref())
+            .await
+            .unwrap();
+
+        assert_eq!(statements.len(), 1);
+        assert!(statements[0].starts_with(r#"CREATE TABLE IF NOT EXISTS "sys_param""#));
+    }
+}
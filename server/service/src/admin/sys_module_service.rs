@@ -0,0 +1,122 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{sea_query::OnConflict, EntityTrait, Set};
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError, module_gate::ModuleStatusChecker};
+use server_model::admin::{
+    entities::{
+        prelude::SysParam,
+        sys_param::{ActiveModel as SysParamActiveModel, Column as SysParamColumn},
+    },
+    input::UpdateModuleInput,
+    output::ModuleStatus,
+};
+
+use super::sys_module_error::ModuleError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+    module_helper::{self, ModuleDefinition, MODULES},
+};
+
+#[async_trait]
+pub trait TModuleService {
+    /// 可停用的模块及其启用状态
+    async fn list_modules(&self) -> Result<Vec<ModuleStatus>, AppError>;
+    /// 启用或停用模块，停用后模块路由返回 404、后台任务暂停，无需重新部署
+    ///
+    /// 本实例立即生效，集群中其他实例在开关缓存过期后生效
+    async fn update_module(
+        &self,
+        code: &str,
+        input: UpdateModuleInput,
+        operator: &User,
+    ) -> Result<ModuleStatus, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysModuleService;
+
+impl SysModuleService {
+    async fn module_status(module: &ModuleDefinition) -> Result<ModuleStatus, AppError> {
+        let params = module_helper::module_params().await?;
+        let param = params.get(&module_helper::param_key(module.code));
+        Ok(ModuleStatus {
+            code: module.code.to_string(),
+            name: module.name.to_string(),
+            route_prefixes: module
+                .route_prefixes
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            enabled: module_helper::is_enabled_value(param.map(|param| param.value.as_str())),
+            updated_by: param.map(|param| param.updated_by.clone()),
+            updated_at: param.map(|param| param.updated_at),
+        })
+    }
+}
+
+#[async_trait]
+impl TModuleService for SysModuleService {
+    async fn list_modules(&self) -> Result<Vec<ModuleStatus>, AppError> {
+        let mut modules = Vec::with_capacity(MODULES.len());
+        for module in MODULES {
+            modules.push(Self::module_status(module).await?);
+        }
+        Ok(modules)
+    }
+
+    async fn update_module(
+        &self,
+        code: &str,
+        input: UpdateModuleInput,
+        operator: &User,
+    ) -> Result<ModuleStatus, AppError> {
+        let module = module_helper::find_module(code).ok_or(ModuleError::ModuleNotFound)?;
+        let db = db_helper::get_db_connection().await?;
+
+        let param = SysParamActiveModel {
+            key: Set(module_helper::param_key(module.code)),
+            value: Set(input.enabled.to_string()),
+            updated_by: Set(operator.username()),
+            updated_at: Set(Local::now().naive_local()),
+        };
+        SysParam::insert(param)
+            .on_conflict(
+                OnConflict::column(SysParamColumn::Key)
+                    .update_columns([
+                        SysParamColumn::Value,
+                        SysParamColumn::UpdatedBy,
+                        SysParamColumn::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec_without_returning(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        module_helper::invalidate();
+
+        record_audit(
+            AuditEntry::new(
+                "模块管理",
+                if input.enabled {
+                    "启用模块"
+                } else {
+                    "停用模块"
+                },
+            )
+            .with_user(operator)
+            .with_detail(json!({ "module": module.code, "enabled": input.enabled })),
+        );
+        Self::module_status(module).await
+    }
+}
+
+#[async_trait]
+impl ModuleStatusChecker for SysModuleService {
+    async fn disabled_module(&self, path: &str) -> Result<Option<String>, AppError> {
+        module_helper::disabled_module_for_path(path)
+            .await
+            .map(|module| module.map(ToString::to_string))
+    }
+}
@@ -0,0 +1,173 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::{Expr, OnConflict},
+    ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_model::admin::{
+    entities::{
+        prelude::{SysNotification, SysNotificationPreference},
+        sys_notification::{Column as SysNotificationColumn, Model as SysNotificationModel},
+        sys_notification_preference::{
+            ActiveModel as SysNotificationPreferenceActiveModel,
+            Column as SysNotificationPreferenceColumn,
+        },
+    },
+    input::{NotificationPageRequest, UpdateNotificationPreferenceInput},
+    output::NotificationPreferenceOutput,
+};
+
+use super::sys_notification_error::NotificationError;
+use crate::helper::{db_helper, notification_helper};
+
+#[async_trait]
+pub trait TNotificationService {
+    async fn find_paginated_notifications(
+        &self,
+        params: NotificationPageRequest,
+        user: &User,
+    ) -> Result<PageResult<SysNotificationModel>, AppError>;
+    async fn mark_read(&self, id: &str, user: &User) -> Result<(), AppError>;
+    async fn mark_all_read(&self, user: &User) -> Result<(), AppError>;
+
+    async fn get_preference(&self, user: &User) -> Result<NotificationPreferenceOutput, AppError>;
+    async fn update_preference(
+        &self,
+        input: UpdateNotificationPreferenceInput,
+        user: &User,
+    ) -> Result<NotificationPreferenceOutput, AppError>;
+
+    /// 发送待汇总通知的摘要邮件，返回发送的邮件数
+    async fn send_digests(&self) -> Result<usize, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysNotificationService;
+
+#[async_trait]
+impl TNotificationService for SysNotificationService {
+    async fn find_paginated_notifications(
+        &self,
+        params: NotificationPageRequest,
+        user: &User,
+    ) -> Result<PageResult<SysNotificationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysNotification::find()
+            .filter(SysNotificationColumn::UserId.eq(user.user_id()))
+            .filter(SysNotificationColumn::InApp.eq(true));
+
+        if params.unread {
+            query = query.filter(SysNotificationColumn::ReadAt.is_null());
+        }
+
+        query = query.order_by_desc(SysNotificationColumn::CreatedAt);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn mark_read(&self, id: &str, user: &User) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let result = SysNotification::update_many()
+            .col_expr(
+                SysNotificationColumn::ReadAt,
+                Expr::value(Local::now().naive_local()),
+            )
+            .filter(SysNotificationColumn::Id.eq(id))
+            .filter(SysNotificationColumn::UserId.eq(user.user_id()))
+            .filter(SysNotificationColumn::InApp.eq(true))
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        if result.rows_affected == 0 {
+            return Err(NotificationError::NotificationNotFound.into());
+        }
+        Ok(())
+    }
+
+    async fn mark_all_read(&self, user: &User) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysNotification::update_many()
+            .col_expr(
+                SysNotificationColumn::ReadAt,
+                Expr::value(Local::now().naive_local()),
+            )
+            .filter(SysNotificationColumn::UserId.eq(user.user_id()))
+            .filter(SysNotificationColumn::ReadAt.is_null())
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        Ok(())
+    }
+
+    async fn get_preference(&self, user: &User) -> Result<NotificationPreferenceOutput, AppError> {
+        notification_helper::load_preference(&user.user_id()).await
+    }
+
+    async fn update_preference(
+        &self,
+        input: UpdateNotificationPreferenceInput,
+        user: &User,
+    ) -> Result<NotificationPreferenceOutput, AppError> {
+        if input.quiet_start.is_some() != input.quiet_end.is_some() {
+            return Err(NotificationError::IncompleteQuietHours.into());
+        }
+
+        let mut muted_categories = input
+            .muted_categories
+            .iter()
+            .map(|category| category.trim())
+            .filter(|category| !category.is_empty())
+            .collect::<Vec<_>>();
+        muted_categories.sort_unstable();
+        muted_categories.dedup();
+
+        let db = db_helper::get_db_connection().await?;
+        let preference = SysNotificationPreferenceActiveModel {
+            user_id: Set(user.user_id()),
+            email_enabled: Set(input.email_enabled),
+            in_app_enabled: Set(input.in_app_enabled),
+            muted_categories: Set(muted_categories.join(",")),
+            quiet_start: Set(input.quiet_start),
+            quiet_end: Set(input.quiet_end),
+            digest_enabled: Set(input.digest_enabled),
+            updated_at: Set(Local::now().naive_local()),
+        };
+        SysNotificationPreference::insert(preference)
+            .on_conflict(
+                OnConflict::column(SysNotificationPreferenceColumn::UserId)
+                    .update_columns([
+                        SysNotificationPreferenceColumn::EmailEnabled,
+                        SysNotificationPreferenceColumn::InAppEnabled,
+                        SysNotificationPreferenceColumn::MutedCategories,
+                        SysNotificationPreferenceColumn::QuietStart,
+                        SysNotificationPreferenceColumn::QuietEnd,
+                        SysNotificationPreferenceColumn::DigestEnabled,
+                        SysNotificationPreferenceColumn::UpdatedAt,
+                    ])
+                    .to_owned(),
+            )
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        notification_helper::load_preference(&user.user_id()).await
+    }
+
+    async fn send_digests(&self) -> Result<usize, AppError> {
+        notification_helper::send_digests().await
+    }
+}
@@ -1,33 +1,77 @@
-use std::any::Any;
-
 use async_trait::async_trait;
+use chrono::{Local, NaiveDateTime};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder,
-    Set,
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseConnection, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::{json, Value};
+use server_config::ComplianceConfig;
+use server_core::web::{error::AppError, page::PageResult};
+use server_global::{
+    global::{self, OperationLogContext, TracedEvent},
+    project_error,
 };
-use server_core::web::{error::AppError, page::PaginatedData};
-use server_global::{global::OperationLogContext, project_error};
 use server_model::admin::{
     entities::{
-        prelude::SysOperationLog,
+        prelude::{SysOperationLog, SysOperationLogArchive},
         sys_operation_log::{
             ActiveModel as SysOperationLogActiveModel, Column as SysOperationLogColumn,
             Model as SysOperationLogModel,
         },
+        sys_operation_log_archive::{
+            ActiveModel as SysOperationLogArchiveActiveModel,
+            Column as SysOperationLogArchiveColumn, Model as SysOperationLogArchiveModel,
+        },
+    },
+    input::{AuditChainVerifyRequest, OperationLogHistoryRequest, OperationLogPageRequest},
+    output::{
+        AuditChainDivergence, AuditChainDivergenceReason, AuditChainPartition, AuditChainReport,
     },
-    input::OperationLogPageRequest,
 };
 use tracing::instrument;
 use ulid::Ulid;
 
-use crate::helper::db_helper;
+use crate::helper::{
+    audit_archive_helper::{self, ArchiveStore},
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+    siem_helper::{self, SiemEvent},
+};
+
+/// 操作日志落库的最大尝试次数
+const OPERATION_LOG_MAX_ATTEMPTS: u32 = 3;
+
+/// 分区第一条记录的前一条哈希
+const GENESIS_HASH: &str = "0000000000000000000000000000000000000000000000000000000000000000";
+
+/// 校验哈希链时每批读取的记录数
+const CHAIN_VERIFY_BATCH_SIZE: u64 = 1000;
 
 #[async_trait]
 pub trait TOperationLogService {
     async fn find_paginated_operation_logs(
         &self,
         params: OperationLogPageRequest,
-    ) -> Result<PaginatedData<SysOperationLogModel>, AppError>;
+    ) -> Result<PageResult<SysOperationLogModel>, AppError>;
+
+    /// 校验审计日志哈希链，每个分区报告第一条断链的记录
+    async fn verify_audit_chain(
+        &self,
+        params: AuditChainVerifyRequest,
+    ) -> Result<AuditChainReport, AppError>;
+
+    /// 按时间范围查询操作日志，范围内已归档的分区从归档对象读取
+    async fn find_operation_log_history(
+        &self,
+        params: OperationLogHistoryRequest,
+    ) -> Result<PageResult<SysOperationLogModel>, AppError>;
+
+    async fn find_operation_log_archives(
+        &self,
+    ) -> Result<Vec<SysOperationLogArchiveModel>, AppError>;
+
+    /// 归档已关闭的分区并从数据库删除，返回归档的记录数
+    async fn archive_closed_partitions(&self) -> Result<u64, AppError>;
 
     async fn handle_operation_log_event(event: &OperationLogContext) -> Result<(), AppError>;
 }
@@ -39,49 +83,218 @@ impl TOperationLogService for SysOperationLogService {
     async fn find_paginated_operation_logs(
         &self,
         params: OperationLogPageRequest,
-    ) -> Result<PaginatedData<SysOperationLogModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PageResult<SysOperationLogModel>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysOperationLog::find();
 
         if let Some(ref keywords) = params.keywords {
-            let condition = Condition::any()
-                .add(SysOperationLogColumn::Domain.contains(keywords))
-                .add(SysOperationLogColumn::Username.contains(keywords))
-                .add(SysOperationLogColumn::Ip.contains(keywords))
-                .add(SysOperationLogColumn::UserAgent.contains(keywords));
-            query = query.filter(condition);
+            query = query.filter(keywords_condition(keywords));
         }
 
         query = query.order_by_desc(SysOperationLogColumn::CreatedAt);
 
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
 
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let paginator = query.paginate(&db, params.page_details.size);
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn verify_audit_chain(
+        &self,
+        params: AuditChainVerifyRequest,
+    ) -> Result<AuditChainReport, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+
+        let domains = match params.domain {
+            Some(domain) => vec![domain],
+            None => SysOperationLog::find()
+                .select_only()
+                .column(SysOperationLogColumn::Domain)
+                .filter(SysOperationLogColumn::ChainSeq.is_not_null())
+                .distinct()
+                .order_by_asc(SysOperationLogColumn::Domain)
+                .into_tuple::<String>()
+                .all(&db)
+                .await
+                .map_err(AppError::from)?,
+        };
+
+        let mut partitions = Vec::with_capacity(domains.len());
+        for domain in domains {
+            // 已归档的记录不在数据库中，从最后一个归档对象的链尾接着校验
+            let (last_seq, last_hash) = archived_chain_tail(&db, &domain)
+                .await?
+                .unwrap_or((0, GENESIS_HASH.to_string()));
+            let mut verifier = ChainVerifier::new(domain, last_seq, last_hash);
+            loop {
+                let records = SysOperationLog::find()
+                    .filter(SysOperationLogColumn::Domain.eq(verifier.domain.as_str()))
+                    .filter(SysOperationLogColumn::ChainSeq.gte(verifier.next_seq))
+                    .order_by_asc(SysOperationLogColumn::ChainSeq)
+                    .limit(CHAIN_VERIFY_BATCH_SIZE)
+                    .all(&db)
+                    .await
+                    .map_err(AppError::from)?;
+                let exhausted = (records.len() as u64) < CHAIN_VERIFY_BATCH_SIZE;
+                if records.iter().any(|record| !verifier.check(record)) || exhausted {
+                    break;
+                }
+            }
+            partitions.push(verifier.finish());
+        }
+
+        Ok(AuditChainReport {
+            intact: partitions.iter().all(|p| p.divergence.is_none()),
+            verified_at: Local::now().naive_local(),
+            partitions,
         })
     }
 
-    async fn handle_operation_log_event(event: &OperationLogContext) -> Result<(), AppError> {
+    async fn find_operation_log_history(
+        &self,
+        params: OperationLogHistoryRequest,
+    ) -> Result<PageResult<SysOperationLogModel>, AppError> {
+        if params.end <= params.start {
+            return Err(AppError::validation("end must be later than start"));
+        }
+        let db = db_helper::get_rls_connection().await?;
+
+        let mut query = SysOperationLog::find()
+            .filter(SysOperationLogColumn::CreatedAt.gte(params.start))
+            .filter(SysOperationLogColumn::CreatedAt.lt(params.end));
+        if let Some(ref domain) = params.domain {
+            query = query.filter(SysOperationLogColumn::Domain.eq(domain.as_str()));
+        }
+        if let Some(ref keywords) = params.keywords {
+            query = query.filter(keywords_condition(keywords));
+        }
+        query = query.order_by_desc(SysOperationLogColumn::CreatedAt);
+        let hot_total = query.clone().count(&db).await.map_err(AppError::from)?;
+
+        let archived = load_archived_records(&db, &params).await?;
+
+        // 数据库中的记录比归档的新，先取数据库再接归档记录
+        let size = params.page_details.size;
+        let offset = params.page_details.current.saturating_sub(1) * size;
+        let mut records = Vec::new();
+        if offset < hot_total {
+            records = query
+                .offset(offset)
+                .limit(size)
+                .all(&db)
+                .await
+                .map_err(AppError::from)?;
+        }
+        let total = hot_total + archived.len() as u64;
+        let remaining = (size as usize).saturating_sub(records.len());
+        records.extend(
+            archived
+                .into_iter()
+                .skip(offset.saturating_sub(hot_total) as usize)
+                .take(remaining),
+        );
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn find_operation_log_archives(
+        &self,
+    ) -> Result<Vec<SysOperationLogArchiveModel>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        SysOperationLogArchive::find()
+            .order_by_desc(SysOperationLogArchiveColumn::PeriodStart)
+            .order_by_asc(SysOperationLogArchiveColumn::Domain)
+            .all(&db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    async fn archive_closed_partitions(&self) -> Result<u64, AppError> {
+        let Some(compliance) = global::get_config::<ComplianceConfig>().await else {
+            return Ok(0);
+        };
+        let config = compliance.audit_archive.clone();
+        if !config.enabled {
+            return Ok(0);
+        }
+        let cutoff =
+            audit_archive_helper::hot_cutoff(Local::now().naive_local(), config.hot_months);
+        let store = ArchiveStore::resolve(config).await?;
+        // 归档按域遍历全部租户，由定时任务执行，不受行级安全约束
         let db = db_helper::get_db_connection().await?;
 
-        SysOperationLogActiveModel {
+        let domains = SysOperationLog::find()
+            .select_only()
+            .column(SysOperationLogColumn::Domain)
+            .filter(SysOperationLogColumn::CreatedAt.lt(cutoff))
+            .distinct()
+            .into_tuple::<String>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut archived = 0;
+        for domain in domains {
+            // 从最早的记录所在月份开始逐月归档，每次归档至少删除最早的一条记录
+            loop {
+                let oldest = SysOperationLog::find()
+                    .select_only()
+                    .column(SysOperationLogColumn::CreatedAt)
+                    .filter(SysOperationLogColumn::Domain.eq(domain.as_str()))
+                    .order_by_asc(SysOperationLogColumn::CreatedAt)
+                    .into_tuple::<NaiveDateTime>()
+                    .one(db.as_ref())
+                    .await
+                    .map_err(AppError::from)?;
+                let Some(oldest) = oldest else {
+                    break;
+                };
+                let period = audit_archive_helper::month_bounds(oldest);
+                if period.1 > cutoff {
+                    break;
+                }
+                archived += archive_partition(&db, &store, &domain, period).await?;
+            }
+        }
+        Ok(archived)
+    }
+
+    async fn handle_operation_log_event(event: &OperationLogContext) -> Result<(), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let domain = event.domain.clone().unwrap_or_default();
+
+        // 链尾由 (domain, chain_seq) 唯一索引保护，多个实例并发写入同一分区时
+        // 后提交的一方插入失败，由监听器重试时重新读取链尾
+        let tail = SysOperationLog::find()
+            .filter(SysOperationLogColumn::Domain.eq(domain.as_str()))
+            .filter(SysOperationLogColumn::ChainSeq.is_not_null())
+            .order_by_desc(SysOperationLogColumn::ChainSeq)
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?;
+        let (last_seq, prev_hash) = match tail {
+            Some(tail) => (
+                tail.chain_seq.unwrap_or_default(),
+                tail.hash.unwrap_or_default(),
+            ),
+            // 分区的记录已全部归档时接在归档的链尾之后
+            None => archived_chain_tail(&txn, &domain)
+                .await?
+                .unwrap_or((0, GENESIS_HASH.to_string())),
+        };
+        let chain_seq = last_seq + 1;
+
+        let record = SysOperationLogActiveModel {
             id: Set(Ulid::new().to_string()),
             user_id: Set(event.user_id.clone().unwrap_or_default()),
             username: Set(event.username.clone().unwrap_or_default()),
-            domain: Set(event.domain.clone().unwrap_or_default()),
+            domain: Set(domain),
             module_name: Set(event.module_name.clone()),
             description: Set(event.description.clone()),
             request_id: Set(event.request_id.clone()),
@@ -96,24 +309,375 @@ impl TOperationLogService for SysOperationLogService {
             end_time: Set(event.end_time),
             duration: Set(event.duration),
             created_at: Set(event.created_at),
+            chain_seq: Set(Some(chain_seq)),
+            prev_hash: Set(Some(prev_hash)),
+            hash: Set(None),
         }
-        .insert(db.as_ref())
+        .insert(&txn)
         .await
         .map_err(AppError::from)?;
 
+        // 哈希按数据库返回的记录计算，时间精度和 JSON 格式与校验时读到的一致
+        let hash = chain_hash(&record);
+        let mut record: SysOperationLogActiveModel = record.into();
+        record.hash = Set(Some(hash));
+        record.update(&txn).await.map_err(AppError::from)?;
+
+        txn.commit().await.map_err(AppError::from)?;
         Ok(())
     }
 }
 
+fn keywords_condition(keywords: &str) -> Condition {
+    Condition::any()
+        .add(SysOperationLogColumn::Domain.contains(keywords))
+        .add(SysOperationLogColumn::Username.contains(keywords))
+        .add(SysOperationLogColumn::Ip.contains(keywords))
+        .add(SysOperationLogColumn::UserAgent.contains(keywords))
+}
+
+/// 与 `keywords_condition` 一致的内存过滤，用于归档记录
+fn matches_keywords(record: &SysOperationLogModel, keywords: &str) -> bool {
+    record.domain.contains(keywords)
+        || record.username.contains(keywords)
+        || record.ip.contains(keywords)
+        || record
+            .user_agent
+            .as_deref()
+            .is_some_and(|user_agent| user_agent.contains(keywords))
+}
+
+/// 分区最后一个归档对象的链尾序号和哈希
+async fn archived_chain_tail<C: ConnectionTrait>(
+    conn: &C,
+    domain: &str,
+) -> Result<Option<(i64, String)>, AppError> {
+    let archive = SysOperationLogArchive::find()
+        .filter(SysOperationLogArchiveColumn::Domain.eq(domain))
+        .filter(SysOperationLogArchiveColumn::LastSeq.is_not_null())
+        .order_by_desc(SysOperationLogArchiveColumn::LastSeq)
+        .one(conn)
+        .await
+        .map_err(AppError::from)?;
+    Ok(archive.map(|archive| {
+        (
+            archive.last_seq.unwrap_or_default(),
+            archive.last_hash.unwrap_or_default(),
+        )
+    }))
+}
+
+/// 读取与查询范围重叠的归档对象，返回符合条件的记录，按时间倒序
+async fn load_archived_records<C: ConnectionTrait>(
+    db: &C,
+    params: &OperationLogHistoryRequest,
+) -> Result<Vec<SysOperationLogModel>, AppError> {
+    let mut query = SysOperationLogArchive::find()
+        .filter(SysOperationLogArchiveColumn::PeriodStart.lt(params.end))
+        .filter(SysOperationLogArchiveColumn::PeriodEnd.gt(params.start));
+    if let Some(ref domain) = params.domain {
+        query = query.filter(SysOperationLogArchiveColumn::Domain.eq(domain.as_str()));
+    }
+    let archives = query.all(db).await.map_err(AppError::from)?;
+    if archives.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    // 归档关闭后仍可查询已有的归档对象
+    let config = global::get_config::<ComplianceConfig>()
+        .await
+        .map(|config| config.audit_archive.clone())
+        .unwrap_or_default();
+    let store = ArchiveStore::resolve(config).await?;
+
+    let mut records = Vec::new();
+    for archive in archives {
+        let data = store.get(&archive.bucket, &archive.object_key).await?;
+        if sha256_hex(&data) != archive.sha256 {
+            return Err(AppError::internal(format!(
+                "Audit log archive {} does not match its checksum",
+                archive.object_key
+            )));
+        }
+        for line in data
+            .split(|byte| *byte == b'\n')
+            .filter(|line| !line.is_empty())
+        {
+            let record: SysOperationLogModel = serde_json::from_slice(line)
+                .map_err(|e| AppError::internal(format!("Invalid audit log archive: {}", e)))?;
+            let matched = record.created_at >= params.start
+                && record.created_at < params.end
+                && params
+                    .keywords
+                    .as_deref()
+                    .is_none_or(|keywords| matches_keywords(&record, keywords));
+            if matched {
+                records.push(record);
+            }
+        }
+    }
+    records.sort_by_key(|record| std::cmp::Reverse(record.created_at));
+    Ok(records)
+}
+
+/// 归档一个域在一个自然月内的记录
+///
+/// 链上的记录按序号整段归档：取本月最后一条记录的序号，序号不超过它的记录一起归档，
+/// 跨月写入的记录不会在链中间留下缺口
+async fn archive_partition(
+    db: &DatabaseConnection,
+    store: &ArchiveStore,
+    domain: &str,
+    (period_start, period_end): (NaiveDateTime, NaiveDateTime),
+) -> Result<u64, AppError> {
+    let max_seq = SysOperationLog::find()
+        .select_only()
+        .column_as(SysOperationLogColumn::ChainSeq.max(), "max_seq")
+        .filter(SysOperationLogColumn::Domain.eq(domain))
+        .filter(SysOperationLogColumn::CreatedAt.lt(period_end))
+        .into_tuple::<Option<i64>>()
+        .one(db)
+        .await
+        .map_err(AppError::from)?
+        .flatten();
+    let condition = Condition::any()
+        .add(
+            Condition::all()
+                .add(SysOperationLogColumn::ChainSeq.is_null())
+                .add(SysOperationLogColumn::CreatedAt.lt(period_end)),
+        )
+        .add_option(max_seq.map(|seq| SysOperationLogColumn::ChainSeq.lte(seq)));
+
+    let mut records = SysOperationLog::find()
+        .filter(SysOperationLogColumn::Domain.eq(domain))
+        .filter(condition.clone())
+        .all(db)
+        .await
+        .map_err(AppError::from)?;
+    if records.is_empty() {
+        return Ok(0);
+    }
+    // 启用哈希链前的记录在前，链上的记录按序号排列
+    records.sort_by_key(|record| {
+        (
+            record.chain_seq.is_some(),
+            record.chain_seq,
+            record.created_at,
+        )
+    });
+
+    let mut data = Vec::new();
+    for record in &records {
+        serde_json::to_writer(&mut data, record).map_err(|e| AppError::internal(e.to_string()))?;
+        data.push(b'\n');
+    }
+    let sha256 = sha256_hex(&data);
+    let chained: Vec<_> = records
+        .iter()
+        .filter(|record| record.chain_seq.is_some())
+        .collect();
+    let id = Ulid::new().to_string();
+    let object_key = store.object_key(domain, period_start, &id);
+    let retain_until = store.put(&object_key, data).await?;
+
+    let record_count = records.len() as u64;
+    let txn = db.begin().await.map_err(AppError::from)?;
+    SysOperationLogArchiveActiveModel {
+        id: Set(id),
+        domain: Set(domain.to_string()),
+        period_start: Set(period_start),
+        period_end: Set(period_end),
+        bucket: Set(store.bucket().to_string()),
+        object_key: Set(object_key.clone()),
+        record_count: Set(record_count as i64),
+        first_seq: Set(chained.first().and_then(|record| record.chain_seq)),
+        last_seq: Set(chained.last().and_then(|record| record.chain_seq)),
+        last_hash: Set(chained.last().and_then(|record| record.hash.clone())),
+        sha256: Set(sha256),
+        retain_until: Set(retain_until),
+        archived_at: Set(Local::now().naive_local()),
+    }
+    .insert(&txn)
+    .await
+    .map_err(AppError::from)?;
+
+    let deleted = SysOperationLog::delete_many()
+        .filter(SysOperationLogColumn::Domain.eq(domain))
+        .filter(condition)
+        .exec(&txn)
+        .await
+        .map_err(AppError::from)?;
+    // 删除的行数与归档的不一致说明期间有记录被改动，放弃本次删除，已写入的对象保留待核查
+    if deleted.rows_affected != record_count {
+        txn.rollback().await.map_err(AppError::from)?;
+        return Err(AppError::internal(format!(
+            "Audit log partition {} changed during archival, archived {} but matched {}",
+            object_key, record_count, deleted.rows_affected
+        )));
+    }
+    txn.commit().await.map_err(AppError::from)?;
+
+    record_audit(
+        AuditEntry::new("operation_log", "Archived audit log partition").with_detail(json!({
+            "domain": domain,
+            "period": period_start.format("%Y-%m").to_string(),
+            "objectKey": object_key,
+            "records": record_count,
+        })),
+    );
+    Ok(record_count)
+}
+
+fn sha256_hex(data: &[u8]) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, data).as_ref())
+}
+
+/// 按序号顺序逐条校验一个分区
+struct ChainVerifier {
+    domain: String,
+    next_seq: i64,
+    prev_hash: String,
+    verified_records: u64,
+    divergence: Option<AuditChainDivergence>,
+}
+
+impl ChainVerifier {
+    /// 从 `last_seq` 之后开始校验，`last_hash` 为其哈希
+    fn new(domain: String, last_seq: i64, last_hash: String) -> Self {
+        Self {
+            domain,
+            next_seq: last_seq + 1,
+            prev_hash: last_hash,
+            verified_records: 0,
+            divergence: None,
+        }
+    }
+
+    /// 校验下一条记录，发现断链时返回 `false`
+    fn check(&mut self, record: &SysOperationLogModel) -> bool {
+        let chain_seq = record.chain_seq.unwrap_or_default();
+        let expected_hash = chain_hash(record);
+        let divergence = if chain_seq != self.next_seq {
+            Some((
+                AuditChainDivergenceReason::SequenceGap,
+                self.next_seq.to_string(),
+                Some(chain_seq.to_string()),
+            ))
+        } else if record.prev_hash.as_deref() != Some(self.prev_hash.as_str()) {
+            Some((
+                AuditChainDivergenceReason::PrevHashMismatch,
+                self.prev_hash.clone(),
+                record.prev_hash.clone(),
+            ))
+        } else if record.hash.as_deref() != Some(expected_hash.as_str()) {
+            Some((
+                AuditChainDivergenceReason::HashMismatch,
+                expected_hash.clone(),
+                record.hash.clone(),
+            ))
+        } else {
+            None
+        };
+
+        if let Some((reason, expected, actual)) = divergence {
+            self.divergence = Some(AuditChainDivergence {
+                id: record.id.clone(),
+                chain_seq,
+                reason,
+                expected,
+                actual,
+            });
+            return false;
+        }
+
+        self.next_seq += 1;
+        self.prev_hash = expected_hash;
+        self.verified_records += 1;
+        true
+    }
+
+    fn finish(self) -> AuditChainPartition {
+        AuditChainPartition {
+            domain: self.domain,
+            verified_records: self.verified_records,
+            divergence: self.divergence,
+        }
+    }
+}
+
+/// 计算记录在链上的哈希，覆盖除 `hash` 以外的全部字段
+fn chain_hash(record: &SysOperationLogModel) -> String {
+    let content = json!([
+        record.chain_seq,
+        record.prev_hash,
+        record.id,
+        record.user_id,
+        record.username,
+        record.domain,
+        record.module_name,
+        record.description,
+        record.request_id,
+        record.method,
+        record.url,
+        record.ip,
+        record.user_agent,
+        record.params,
+        record.body,
+        record.response,
+        record.start_time,
+        record.end_time,
+        record.duration,
+        record.created_at,
+    ]);
+    let mut canonical = String::new();
+    write_canonical_json(&content, &mut canonical);
+    sha256_hex(canonical.as_bytes())
+}
+
+/// 对象按键排序输出，结果不依赖 JSON 对象的键顺序
+fn write_canonical_json(value: &Value, out: &mut String) {
+    match value {
+        Value::Array(items) => {
+            out.push('[');
+            for (index, item) in items.iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                write_canonical_json(item, out);
+            }
+            out.push(']');
+        },
+        Value::Object(map) => {
+            let mut entries: Vec<_> = map.iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(b.0));
+            out.push('{');
+            for (index, (key, item)) in entries.into_iter().enumerate() {
+                if index > 0 {
+                    out.push(',');
+                }
+                out.push_str(&Value::String(key.clone()).to_string());
+                out.push(':');
+                write_canonical_json(item, out);
+            }
+            out.push('}');
+        },
+        other => out.push_str(&other.to_string()),
+    }
+}
+
 #[instrument(skip(rx))]
-pub async fn sys_operation_log_listener(
-    mut rx: tokio::sync::mpsc::UnboundedReceiver<Box<dyn Any + Send>>,
-) {
+pub async fn sys_operation_log_listener(mut rx: tokio::sync::mpsc::UnboundedReceiver<TracedEvent>) {
     while let Some(event) = rx.recv().await {
         if let Some(operation_log_context) = event.downcast_ref::<OperationLogContext>() {
-            if let Err(e) =
-                SysOperationLogService::handle_operation_log_event(operation_log_context).await
-            {
+            siem_helper::export(SiemEvent::from_operation_log(operation_log_context));
+            // 数据库短暂不可用时重试，避免丢失审计记录
+            let result = event
+                .context
+                .retry("operation_log", OPERATION_LOG_MAX_ATTEMPTS, |_| {
+                    SysOperationLogService::handle_operation_log_event(operation_log_context)
+                })
+                .await;
+            if let Err(e) = result {
                 project_error!("Failed to handle operation log event: {:?}", e);
             }
         } else {
@@ -121,3 +685,99 @@ pub async fn sys_operation_log_listener(
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+
+    use super::*;
+
+    fn chained_records(count: i64) -> Vec<SysOperationLogModel> {
+        let time =
+            NaiveDateTime::parse_from_str("2026-10-15 10:00:00", "%Y-%m-%d %H:%M:%S").unwrap();
+        let mut prev_hash = GENESIS_HASH.to_string();
+        (1..=count)
+            .map(|chain_seq| {
+                let mut record = SysOperationLogModel {
+                    id: format!("log-{}", chain_seq),
+                    user_id: "u1".to_string(),
+                    username: "admin".to_string(),
+                    domain: "built-in".to_string(),
+                    module_name: "user".to_string(),
+                    description: "Update user".to_string(),
+                    request_id: "req".to_string(),
+                    method: "PUT".to_string(),
+                    url: "/user".to_string(),
+                    ip: "127.0.0.1".to_string(),
+                    user_agent: None,
+                    params: None,
+                    body: Some(json!({ "b": 1, "a": [true, null] })),
+                    response: None,
+                    start_time: time,
+                    end_time: time,
+                    duration: 3,
+                    created_at: time,
+                    chain_seq: Some(chain_seq),
+                    prev_hash: Some(prev_hash.clone()),
+                    hash: None,
+                };
+                record.hash = Some(chain_hash(&record));
+                prev_hash = record.hash.clone().unwrap();
+                record
+            })
+            .collect()
+    }
+
+    fn verify(records: &[SysOperationLogModel]) -> AuditChainPartition {
+        let mut verifier = ChainVerifier::new("built-in".to_string(), 0, GENESIS_HASH.to_string());
+        for record in records {
+            if !verifier.check(record) {
+                break;
+            }
+        }
+        verifier.finish()
+    }
+
+    #[test]
+    fn test_canonical_json_ignores_key_order() {
+        let mut left = String::new();
+        let mut right = String::new();
+        write_canonical_json(&json!({ "b": { "y": 1, "x": 2 }, "a": "s" }), &mut left);
+        write_canonical_json(&json!({ "a": "s", "b": { "x": 2, "y": 1 } }), &mut right);
+        assert_eq!(left, right);
+        assert_eq!(left, r#"{"a":"s","b":{"x":2,"y":1}}"#);
+    }
+
+    #[test]
+    fn test_verify_chain_reports_first_divergence() {
+        let records = chained_records(4);
+        let partition = verify(&records);
+        assert_eq!(partition.verified_records, 4);
+        assert!(partition.divergence.is_none());
+
+        let mut tampered = records.clone();
+        tampered[2].description = "Delete user".to_string();
+        let partition = verify(&tampered);
+        assert_eq!(partition.verified_records, 2);
+        let divergence = partition.divergence.unwrap();
+        assert_eq!(divergence.id, "log-3");
+        assert_eq!(divergence.reason, AuditChainDivergenceReason::HashMismatch);
+
+        let mut removed = records.clone();
+        removed.remove(1);
+        let divergence = verify(&removed).divergence.unwrap();
+        assert_eq!(divergence.id, "log-3");
+        assert_eq!(divergence.reason, AuditChainDivergenceReason::SequenceGap);
+
+        // 删除记录后重排序号，前一条哈希对不上
+        let mut renumbered = records;
+        renumbered.remove(1);
+        renumbered[1].chain_seq = Some(2);
+        let divergence = verify(&renumbered).divergence.unwrap();
+        assert_eq!(divergence.id, "log-3");
+        assert_eq!(
+            divergence.reason,
+            AuditChainDivergenceReason::PrevHashMismatch
+        );
+    }
+}
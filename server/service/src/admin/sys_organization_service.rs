@@ -1,22 +1,34 @@
 use async_trait::async_trait;
 use sea_orm::{ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter};
-use server_core::web::{error::AppError, page::PaginatedData};
+use server_core::web::{error::AppError, page::PageResult};
 use server_model::admin::{
     entities::{
         prelude::SysOrganization,
-        sys_organization::{Column as SysOrganizationColumn, Model as SysOrganizationModel},
+        sys_organization::{self, Column as SysOrganizationColumn, Model as SysOrganizationModel},
     },
-    input::OrganizationPageRequest,
+    input::{MoveTreeNodeInput, OrganizationPageRequest, SubtreeRequest},
+    output::TreeNode,
 };
 
-use crate::helper::db_helper;
+use crate::helper::{db_helper, tree_helper};
 
 #[async_trait]
 pub trait TOrganizationService {
     async fn find_paginated_organizations(
         &self,
         params: OrganizationPageRequest,
-    ) -> Result<PaginatedData<SysOrganizationModel>, AppError>;
+    ) -> Result<PageResult<SysOrganizationModel>, AppError>;
+
+    async fn get_organization_children(
+        &self,
+        pid: &str,
+    ) -> Result<Vec<TreeNode<SysOrganizationModel>>, AppError>;
+    async fn get_organization_subtree(
+        &self,
+        id: &str,
+        params: SubtreeRequest,
+    ) -> Result<TreeNode<SysOrganizationModel>, AppError>;
+    async fn move_organization(&self, id: &str, input: MoveTreeNodeInput) -> Result<(), AppError>;
 }
 
 pub struct SysOrganizationService;
@@ -26,7 +38,7 @@ impl TOrganizationService for SysOrganizationService {
     async fn find_paginated_organizations(
         &self,
         params: OrganizationPageRequest,
-    ) -> Result<PaginatedData<SysOrganizationModel>, AppError> {
+    ) -> Result<PageResult<SysOrganizationModel>, AppError> {
         let db = db_helper::get_db_connection().await?;
         let mut query = SysOrganization::find();
 
@@ -50,11 +62,31 @@ impl TOrganizationService for SysOrganizationService {
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    /// 懒加载下级组织，`pid` 为 `0` 时返回顶级组织
+    async fn get_organization_children(
+        &self,
+        pid: &str,
+    ) -> Result<Vec<TreeNode<SysOrganizationModel>>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        tree_helper::find_children::<sys_organization::Entity, _>(db.as_ref(), pid).await
+    }
+
+    async fn get_organization_subtree(
+        &self,
+        id: &str,
+        params: SubtreeRequest,
+    ) -> Result<TreeNode<SysOrganizationModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        tree_helper::find_subtree::<sys_organization::Entity, _>(db.as_ref(), id, params.depth)
+            .await
+    }
+
+    /// 连同下级组织一起移动到新的上级组织，用户和角色引用的组织 ID 不变
+    async fn move_organization(&self, id: &str, input: MoveTreeNodeInput) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        tree_helper::move_subtree::<sys_organization::Entity>(db.as_ref(), id, &input.pid).await
     }
 }
@@ -0,0 +1,158 @@
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, Set};
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError};
+use server_model::admin::{
+    entities::{
+        prelude::SysUserPasskey,
+        sys_user_passkey::{
+            ActiveModel as SysUserPasskeyActiveModel, Column as SysUserPasskeyColumn,
+            Model as SysUserPasskeyModel,
+        },
+    },
+    input::{FinishPasskeyInput, StartPasskeyRegistrationInput},
+    output::PasskeyChallengeOutput,
+};
+use ulid::Ulid;
+use webauthn_rs::prelude::RegisterPublicKeyCredential;
+
+use super::sys_passkey_error::PasskeyError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+    passkey_helper::{self, ChallengeKind, RegistrationState},
+};
+
+#[async_trait]
+pub trait TPasskeyService {
+    async fn list_passkeys(&self, user: &User) -> Result<Vec<SysUserPasskeyModel>, AppError>;
+
+    async fn start_registration(
+        &self,
+        input: StartPasskeyRegistrationInput,
+        user: &User,
+    ) -> Result<PasskeyChallengeOutput, AppError>;
+    async fn finish_registration(
+        &self,
+        input: FinishPasskeyInput,
+        user: &User,
+    ) -> Result<SysUserPasskeyModel, AppError>;
+    async fn delete_passkey(&self, id: &str, user: &User) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysPasskeyService;
+
+#[async_trait]
+impl TPasskeyService for SysPasskeyService {
+    async fn list_passkeys(&self, user: &User) -> Result<Vec<SysUserPasskeyModel>, AppError> {
+        passkey_helper::find_passkeys(&user.user_id())
+            .await
+            .map(|passkeys| passkeys.into_iter().map(|(record, _)| record).collect())
+    }
+
+    async fn start_registration(
+        &self,
+        input: StartPasskeyRegistrationInput,
+        user: &User,
+    ) -> Result<PasskeyChallengeOutput, AppError> {
+        let config = passkey_helper::enabled_config().await?;
+        let webauthn = passkey_helper::webauthn(&config)?;
+
+        // 排除已注册的凭证，避免同一认证器重复注册
+        let exclude_credentials = passkey_helper::find_passkeys(&user.user_id())
+            .await?
+            .into_iter()
+            .map(|(_, passkey)| passkey.cred_id().clone())
+            .collect::<Vec<_>>();
+        let (options, registration) = webauthn
+            .start_passkey_registration(
+                passkey_helper::user_handle(&user.user_id()),
+                &user.username(),
+                &user.username(),
+                Some(exclude_credentials),
+            )
+            .map_err(passkey_helper::verification_error)?;
+
+        let challenge_id = passkey_helper::save_challenge(
+            &config,
+            ChallengeKind::Registration,
+            &RegistrationState {
+                user_id: user.user_id(),
+                device_name: input.device_name,
+                registration,
+            },
+        )
+        .await?;
+
+        Ok(PasskeyChallengeOutput {
+            challenge_id,
+            options: json!(options),
+        })
+    }
+
+    async fn finish_registration(
+        &self,
+        input: FinishPasskeyInput,
+        user: &User,
+    ) -> Result<SysUserPasskeyModel, AppError> {
+        let config = passkey_helper::enabled_config().await?;
+        let state: RegistrationState =
+            passkey_helper::take_challenge(ChallengeKind::Registration, &input.challenge_id)
+                .await?;
+        if state.user_id != user.user_id() {
+            return Err(PasskeyError::ChallengeExpired.into());
+        }
+
+        let credential = serde_json::from_value::<RegisterPublicKeyCredential>(input.credential)
+            .map_err(|_| AppError::from(PasskeyError::VerificationFailed))?;
+        let passkey = passkey_helper::webauthn(&config)?
+            .finish_passkey_registration(&credential, &state.registration)
+            .map_err(passkey_helper::verification_error)?;
+
+        let db = db_helper::get_db_connection().await?;
+        let record = SysUserPasskeyActiveModel {
+            id: Set(Ulid::new().to_string()),
+            user_id: Set(state.user_id),
+            credential_id: Set(passkey_helper::credential_key(passkey.cred_id())),
+            device_name: Set(state.device_name),
+            credential: Set(serde_json::to_string(&passkey)
+                .map_err(|e| AppError::internal(format!("Failed to serialize passkey: {}", e)))?),
+            created_at: Set(Local::now().naive_local()),
+            ..Default::default()
+        }
+        .insert(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("通行密钥", "注册通行密钥")
+                .with_user(user)
+                .with_detail(json!({ "id": record.id, "deviceName": record.device_name })),
+        );
+        Ok(record)
+    }
+
+    async fn delete_passkey(&self, id: &str, user: &User) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let record = SysUserPasskey::find_by_id(id)
+            .filter(SysUserPasskeyColumn::UserId.eq(user.user_id()))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or(PasskeyError::PasskeyNotFound)?;
+
+        SysUserPasskey::delete_by_id(id)
+            .exec(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("通行密钥", "删除通行密钥")
+                .with_user(user)
+                .with_detail(json!({ "id": record.id, "deviceName": record.device_name })),
+        );
+        Ok(())
+    }
+}
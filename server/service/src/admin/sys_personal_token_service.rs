@@ -0,0 +1,292 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local, NaiveDateTime};
+use ring::rand::{SecureRandom, SystemRandom};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, JoinType, PaginatorTrait, QueryFilter, QueryOrder,
+    QuerySelect, RelationTrait, Set,
+};
+use serde_json::json;
+use server_core::web::{
+    auth::{Claims, User},
+    error::AppError,
+    personal_token::{
+        is_valid_scope, PersonalTokenGrant, PersonalTokenResolver, PERSONAL_TOKEN_AUTH_METHOD,
+        PERSONAL_TOKEN_PREFIX,
+    },
+};
+use server_model::admin::{
+    entities::{
+        prelude::{SysPersonalToken, SysRole, SysUser},
+        sea_orm_active_enums::Status,
+        sys_personal_token::{
+            ActiveModel as SysPersonalTokenActiveModel, Column as SysPersonalTokenColumn,
+            Model as SysPersonalTokenModel,
+        },
+        sys_role::Relation as SysRoleRelation,
+        sys_user::Column as SysUserColumn,
+        sys_user_role::Relation as SysUserRoleRelation,
+    },
+    input::CreatePersonalTokenInput,
+    output::{CreatedPersonalToken, PersonalTokenInfo},
+};
+use ulid::Ulid;
+
+use super::sys_personal_token_error::PersonalTokenError;
+use crate::{
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper,
+    },
+    project_error,
+};
+
+/// 每个用户最多持有的令牌数
+const MAX_TOKENS_PER_USER: u64 = 20;
+
+/// 列表中显示的令牌末尾字符数
+const TOKEN_HINT_LENGTH: usize = 4;
+
+/// 最后使用时间的更新间隔（秒），避免每个请求都写数据库
+const LAST_USED_UPDATE_INTERVAL: i64 = 60;
+
+#[async_trait]
+pub trait TPersonalTokenService {
+    /// 当前用户的个人访问令牌，包括已过期的令牌
+    async fn list_tokens(&self, user: &User) -> Result<Vec<PersonalTokenInfo>, AppError>;
+
+    /// 创建令牌，令牌原文只在返回值中出现一次
+    async fn create_token(
+        &self,
+        input: CreatePersonalTokenInput,
+        user: &User,
+    ) -> Result<CreatedPersonalToken, AppError>;
+
+    /// 吊销令牌，立即失效
+    async fn revoke_token(&self, id: &str, user: &User) -> Result<(), AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysPersonalTokenService;
+
+fn token_hash(token: &str) -> String {
+    hex::encode(ring::digest::digest(&ring::digest::SHA256, token.as_bytes()).as_ref())
+}
+
+/// 生成令牌：前缀加 32 字节随机数的十六进制
+fn generate_token() -> Result<String, AppError> {
+    let mut bytes = [0u8; 32];
+    SystemRandom::new()
+        .fill(&mut bytes)
+        .map_err(|_| AppError::internal("Failed to generate personal access token"))?;
+    Ok(format!("{}{}", PERSONAL_TOKEN_PREFIX, hex::encode(bytes)))
+}
+
+fn parse_scopes(scopes: &str) -> Vec<String> {
+    scopes.split_whitespace().map(str::to_string).collect()
+}
+
+fn token_info(record: SysPersonalTokenModel) -> PersonalTokenInfo {
+    PersonalTokenInfo {
+        scopes: parse_scopes(&record.scopes),
+        id: record.id,
+        name: record.name,
+        token_hint: record.token_hint,
+        expires_at: record.expires_at,
+        last_used_at: record.last_used_at,
+        created_at: record.created_at,
+    }
+}
+
+/// PAT 不能用于管理令牌，避免泄露的令牌为自己续期或扩大授权范围
+fn ensure_not_personal_token(user: &User) -> Result<(), AppError> {
+    if user.authenticated_with(PERSONAL_TOKEN_AUTH_METHOD) {
+        return Err(PersonalTokenError::ManagedWithPersonalToken.into());
+    }
+    Ok(())
+}
+
+fn last_used_stale(last_used_at: Option<NaiveDateTime>, now: NaiveDateTime) -> bool {
+    last_used_at.is_none_or(|last| now - last >= Duration::seconds(LAST_USED_UPDATE_INTERVAL))
+}
+
+#[async_trait]
+impl TPersonalTokenService for SysPersonalTokenService {
+    async fn list_tokens(&self, user: &User) -> Result<Vec<PersonalTokenInfo>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        SysPersonalToken::find()
+            .filter(SysPersonalTokenColumn::UserId.eq(user.user_id()))
+            .order_by_desc(SysPersonalTokenColumn::CreatedAt)
+            .all(&db)
+            .await
+            .map(|records| records.into_iter().map(token_info).collect())
+            .map_err(AppError::from)
+    }
+
+    async fn create_token(
+        &self,
+        input: CreatePersonalTokenInput,
+        user: &User,
+    ) -> Result<CreatedPersonalToken, AppError> {
+        ensure_not_personal_token(user)?;
+        if let Some(scope) = input.scopes.iter().find(|scope| !is_valid_scope(scope)) {
+            return Err(PersonalTokenError::InvalidScope(scope.clone()).into());
+        }
+
+        let db = db_helper::get_rls_connection().await?;
+        let count = SysPersonalToken::find()
+            .filter(SysPersonalTokenColumn::UserId.eq(user.user_id()))
+            .count(&db)
+            .await
+            .map_err(AppError::from)?;
+        if count >= MAX_TOKENS_PER_USER {
+            return Err(PersonalTokenError::TooManyTokens.into());
+        }
+
+        let token = generate_token()?;
+        let mut scopes = input.scopes;
+        scopes.sort();
+        scopes.dedup();
+        let now = Local::now().naive_local();
+        let record = SysPersonalTokenActiveModel {
+            id: Set(Ulid::new().to_string()),
+            user_id: Set(user.user_id()),
+            name: Set(input.name),
+            token_hash: Set(token_hash(&token)),
+            token_hint: Set(token[token.len() - TOKEN_HINT_LENGTH..].to_string()),
+            scopes: Set(scopes.join(" ")),
+            expires_at: Set(now + Duration::days(input.expire_days as i64)),
+            created_at: Set(now),
+            ..Default::default()
+        }
+        .insert(&db)
+        .await
+        .map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("个人访问令牌", "创建个人访问令牌")
+                .with_user(user)
+                .with_detail(json!({
+                    "id": record.id,
+                    "name": record.name,
+                    "scopes": scopes,
+                    "expiresAt": record.expires_at,
+                })),
+        );
+        Ok(CreatedPersonalToken {
+            token,
+            info: token_info(record),
+        })
+    }
+
+    async fn revoke_token(&self, id: &str, user: &User) -> Result<(), AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let record = SysPersonalToken::find_by_id(id)
+            .filter(SysPersonalTokenColumn::UserId.eq(user.user_id()))
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .ok_or(PersonalTokenError::TokenNotFound)?;
+
+        SysPersonalToken::delete_by_id(id)
+            .exec(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new("个人访问令牌", "吊销个人访问令牌")
+                .with_user(user)
+                .with_detail(json!({ "id": record.id, "name": record.name })),
+        );
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl PersonalTokenResolver for SysPersonalTokenService {
+    async fn resolve(
+        &self,
+        token: &str,
+        audience: &str,
+    ) -> Result<Option<PersonalTokenGrant>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        let now = Local::now().naive_local();
+        let Some(record) = SysPersonalToken::find()
+            .filter(SysPersonalTokenColumn::TokenHash.eq(token_hash(token)))
+            .filter(SysPersonalTokenColumn::ExpiresAt.gt(now))
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+        else {
+            return Ok(None);
+        };
+        let Some(owner) = SysUser::find_by_id(&record.user_id)
+            .one(&db)
+            .await
+            .map_err(AppError::from)?
+            .filter(|owner| owner.status == Status::Enabled)
+        else {
+            return Ok(None);
+        };
+
+        // 每次请求读取用户当前的角色，角色变更对已有令牌立即生效
+        let role_codes: Vec<String> = SysRole::find()
+            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysUser.def())
+            .filter(SysUserColumn::Id.eq(&owner.id))
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|role| role.code)
+            .collect();
+
+        if last_used_stale(record.last_used_at, now) {
+            let id = record.id.clone();
+            tokio::spawn(async move {
+                let result = SysPersonalTokenActiveModel {
+                    id: Set(id),
+                    last_used_at: Set(Some(now)),
+                    ..Default::default()
+                }
+                .update(&db)
+                .await;
+                if let Err(e) = result {
+                    project_error!("Failed to update personal token last used time: {}", e);
+                }
+            });
+        }
+
+        let mut claims = Claims::new(
+            owner.id,
+            audience.to_string(),
+            owner.username,
+            role_codes,
+            owner.domain,
+            None,
+        );
+        // 不设置 auth_time，需要二次认证的接口始终拒绝个人访问令牌
+        claims.set_amr(vec![PERSONAL_TOKEN_AUTH_METHOD.to_string()]);
+        Ok(Some(PersonalTokenGrant {
+            user: User::from(claims),
+            scopes: parse_scopes(&record.scopes),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_generate_token() {
+        let token = generate_token().unwrap();
+        assert!(token.starts_with(PERSONAL_TOKEN_PREFIX));
+        assert_eq!(token.len(), PERSONAL_TOKEN_PREFIX.len() + 64);
+        assert_ne!(token_hash(&token), token_hash(&generate_token().unwrap()));
+
+        let now = Local::now().naive_local();
+        assert!(last_used_stale(None, now));
+        assert!(!last_used_stale(Some(now - Duration::seconds(5)), now));
+        assert!(last_used_stale(Some(now - Duration::seconds(90)), now));
+    }
+}
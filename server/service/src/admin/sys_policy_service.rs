@@ -0,0 +1,472 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait,
+    QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::json;
+use server_config::ComplianceConfig;
+use server_core::web::{
+    auth::User,
+    error::AppError,
+    page::PageResult,
+    policy_gate::{PendingPolicy, PolicyAcceptanceChecker},
+};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{SysPolicyAcceptance, SysPolicyDocument},
+        sys_policy_acceptance::{
+            ActiveModel as SysPolicyAcceptanceActiveModel, Column as SysPolicyAcceptanceColumn,
+            Model as SysPolicyAcceptanceModel,
+        },
+        sys_policy_document::{
+            ActiveModel as SysPolicyDocumentActiveModel, Column as SysPolicyDocumentColumn,
+            Model as SysPolicyDocumentModel,
+        },
+    },
+    input::{
+        AcceptPolicyInput, PolicyAcceptancePageRequest, PolicyDocumentPageRequest,
+        PublishPolicyInput,
+    },
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::sys_policy_error::PolicyError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+};
+
+const AUDIT_MODULE: &str = "政策文档";
+
+/// 各编码最新版本的进程内缓存
+///
+/// `accepted` 记录已确认全部最新版本的用户，最新版本变化时清空，
+/// 确认过的用户在版本不变时不再查询数据库
+struct LatestPolicies {
+    loaded_at: Instant,
+    policies: Vec<PendingPolicy>,
+    accepted: HashSet<String>,
+}
+
+static LATEST: LazyLock<Mutex<Option<LatestPolicies>>> = LazyLock::new(|| Mutex::new(None));
+
+#[async_trait]
+pub trait TPolicyService {
+    async fn find_paginated_documents(
+        &self,
+        params: PolicyDocumentPageRequest,
+    ) -> Result<PageResult<SysPolicyDocumentModel>, AppError>;
+
+    /// 发布新版本，发布后所有用户须重新确认该编码
+    async fn publish_document(
+        &self,
+        input: PublishPolicyInput,
+        operator: &User,
+    ) -> Result<SysPolicyDocumentModel, AppError>;
+
+    async fn find_paginated_acceptances(
+        &self,
+        document_id: &str,
+        params: PolicyAcceptancePageRequest,
+    ) -> Result<PageResult<SysPolicyAcceptanceModel>, AppError>;
+
+    /// 当前用户尚未确认的最新版本文档
+    async fn get_pending_documents(
+        &self,
+        user: &User,
+    ) -> Result<Vec<SysPolicyDocumentModel>, AppError>;
+
+    async fn accept_documents(
+        &self,
+        input: AcceptPolicyInput,
+        user: &User,
+        ip: String,
+        user_agent: Option<String>,
+    ) -> Result<Vec<SysPolicyAcceptanceModel>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysPolicyService;
+
+/// 从全部版本中取出每个编码的最新版本，按编码排序
+fn latest_versions(documents: Vec<PendingPolicy>) -> Vec<PendingPolicy> {
+    let mut latest: HashMap<String, PendingPolicy> = HashMap::new();
+    for document in documents {
+        match latest.get(&document.code) {
+            Some(current) if current.version >= document.version => {},
+            _ => {
+                latest.insert(document.code.clone(), document);
+            },
+        }
+    }
+    let mut latest = latest.into_values().collect::<Vec<_>>();
+    latest.sort_by(|a, b| a.code.cmp(&b.code));
+    latest
+}
+
+/// 发布新版本后清除本节点的缓存，其他节点在缓存过期后生效
+fn invalidate_latest() {
+    *LATEST.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+impl SysPolicyService {
+    /// 从数据库读取各编码的最新版本
+    async fn load_latest(&self) -> Result<Vec<PendingPolicy>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let documents = SysPolicyDocument::find()
+            .select_only()
+            .column(SysPolicyDocumentColumn::Id)
+            .column(SysPolicyDocumentColumn::Code)
+            .column(SysPolicyDocumentColumn::Version)
+            .column(SysPolicyDocumentColumn::Title)
+            .into_tuple::<(String, String, i32, String)>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|(document_id, code, version, title)| PendingPolicy {
+                document_id,
+                code,
+                version,
+                title,
+            })
+            .collect();
+        Ok(latest_versions(documents))
+    }
+
+    /// 读取缓存的最新版本，过期时重新加载；用户已确认全部版本时返回 `None`
+    async fn cached_latest(&self, user_id: &str) -> Result<Option<Vec<PendingPolicy>>, AppError> {
+        let ttl = global::get_config::<ComplianceConfig>()
+            .await
+            .map(|config| config.policy_gate.cache_ttl)
+            .unwrap_or_default();
+
+        if let Some(cached) = LATEST
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|cached| cached.loaded_at.elapsed() < Duration::from_secs(ttl))
+        {
+            if cached.accepted.contains(user_id) {
+                return Ok(None);
+            }
+            return Ok(Some(cached.policies.clone()));
+        }
+
+        let policies = self.load_latest().await?;
+        let mut latest = LATEST.lock().unwrap_or_else(|e| e.into_inner());
+        let accepted = latest
+            .take()
+            .filter(|cached| cached.policies == policies)
+            .map(|cached| cached.accepted)
+            .unwrap_or_default();
+        *latest = Some(LatestPolicies {
+            loaded_at: Instant::now(),
+            policies: policies.clone(),
+            accepted,
+        });
+        Ok(Some(policies))
+    }
+
+    /// 用户在给定版本中尚未确认的部分
+    async fn unaccepted(
+        &self,
+        user_id: &str,
+        latest: Vec<PendingPolicy>,
+    ) -> Result<Vec<PendingPolicy>, AppError> {
+        if latest.is_empty() {
+            return Ok(latest);
+        }
+        let db = db_helper::get_db_connection().await?;
+        let accepted: HashSet<String> = SysPolicyAcceptance::find()
+            .select_only()
+            .column(SysPolicyAcceptanceColumn::DocumentId)
+            .filter(SysPolicyAcceptanceColumn::UserId.eq(user_id))
+            .filter(
+                SysPolicyAcceptanceColumn::DocumentId
+                    .is_in(latest.iter().map(|policy| policy.document_id.clone())),
+            )
+            .into_tuple::<String>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .collect();
+        Ok(latest
+            .into_iter()
+            .filter(|policy| !accepted.contains(&policy.document_id))
+            .collect())
+    }
+}
+
+#[async_trait]
+impl PolicyAcceptanceChecker for SysPolicyService {
+    async fn pending_policies(&self, user: &User) -> Result<Vec<PendingPolicy>, AppError> {
+        let user_id = user.user_id();
+        let Some(latest) = self.cached_latest(&user_id).await? else {
+            return Ok(Vec::new());
+        };
+        let pending = self.unaccepted(&user_id, latest).await?;
+        if pending.is_empty() {
+            if let Some(cached) = LATEST.lock().unwrap_or_else(|e| e.into_inner()).as_mut() {
+                cached.accepted.insert(user_id);
+            }
+        }
+        Ok(pending)
+    }
+}
+
+#[async_trait]
+impl TPolicyService for SysPolicyService {
+    async fn find_paginated_documents(
+        &self,
+        params: PolicyDocumentPageRequest,
+    ) -> Result<PageResult<SysPolicyDocumentModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysPolicyDocument::find();
+
+        if let Some(ref code) = params.code {
+            query = query.filter(SysPolicyDocumentColumn::Code.eq(code));
+        }
+
+        query = query
+            .order_by_asc(SysPolicyDocumentColumn::Code)
+            .order_by_desc(SysPolicyDocumentColumn::Version);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    #[instrument(skip(self, input, operator), fields(code = %input.code))]
+    async fn publish_document(
+        &self,
+        input: PublishPolicyInput,
+        operator: &User,
+    ) -> Result<SysPolicyDocumentModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+
+        let current = SysPolicyDocument::find()
+            .select_only()
+            .expr(Expr::col(SysPolicyDocumentColumn::Version).max())
+            .filter(SysPolicyDocumentColumn::Code.eq(&input.code))
+            .into_tuple::<Option<i32>>()
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .flatten()
+            .unwrap_or(0);
+
+        let document = SysPolicyDocumentActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(input.code),
+            version: Set(current + 1),
+            title: Set(input.title),
+            content: Set(input.content),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+
+        txn.commit().await.map_err(AppError::from)?;
+        invalidate_latest();
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "发布政策文档")
+                .with_user(operator)
+                .with_detail(json!({
+                    "documentId": document.id,
+                    "code": document.code,
+                    "version": document.version,
+                })),
+        );
+        Ok(document)
+    }
+
+    async fn find_paginated_acceptances(
+        &self,
+        document_id: &str,
+        params: PolicyAcceptancePageRequest,
+    ) -> Result<PageResult<SysPolicyAcceptanceModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysPolicyDocument::find_by_id(document_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or(PolicyError::DocumentNotFound)?;
+
+        let mut query = SysPolicyAcceptance::find()
+            .filter(SysPolicyAcceptanceColumn::DocumentId.eq(document_id));
+
+        if let Some(ref keywords) = params.keywords {
+            let condition =
+                Condition::any().add(SysPolicyAcceptanceColumn::Username.contains(keywords));
+            query = query.filter(condition);
+        }
+
+        query = query.order_by_desc(SysPolicyAcceptanceColumn::AcceptedAt);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn get_pending_documents(
+        &self,
+        user: &User,
+    ) -> Result<Vec<SysPolicyDocumentModel>, AppError> {
+        let latest = self.load_latest().await?;
+        let pending = self.unaccepted(&user.user_id(), latest).await?;
+        if pending.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        SysPolicyDocument::find()
+            .filter(
+                SysPolicyDocumentColumn::Id
+                    .is_in(pending.into_iter().map(|policy| policy.document_id)),
+            )
+            .order_by_asc(SysPolicyDocumentColumn::Code)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    #[instrument(skip(self, input, user, user_agent), fields(user_id = %user.user_id()))]
+    async fn accept_documents(
+        &self,
+        input: AcceptPolicyInput,
+        user: &User,
+        ip: String,
+        user_agent: Option<String>,
+    ) -> Result<Vec<SysPolicyAcceptanceModel>, AppError> {
+        let user_id = user.user_id();
+        let latest = self.load_latest().await?;
+        let mut accepting = Vec::new();
+        for document_id in input.document_ids.iter().collect::<HashSet<_>>() {
+            let policy = latest
+                .iter()
+                .find(|policy| &policy.document_id == document_id);
+            match policy {
+                Some(policy) => accepting.push(policy.clone()),
+                None => {
+                    let db = db_helper::get_db_connection().await?;
+                    let exists = SysPolicyDocument::find_by_id(document_id.as_str())
+                        .count(db.as_ref())
+                        .await
+                        .map_err(AppError::from)?
+                        > 0;
+                    return Err(if exists {
+                        PolicyError::NotLatestVersion.into()
+                    } else {
+                        PolicyError::DocumentNotFound.into()
+                    });
+                },
+            }
+        }
+
+        // 重复确认同一版本时保留最早的记录
+        let accepting = self.unaccepted(&user_id, accepting).await?;
+        let now = Local::now().naive_local();
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let mut acceptances = Vec::with_capacity(accepting.len());
+        for policy in accepting {
+            let acceptance = SysPolicyAcceptanceActiveModel {
+                id: Set(Ulid::new().to_string()),
+                document_id: Set(policy.document_id),
+                code: Set(policy.code),
+                version: Set(policy.version),
+                user_id: Set(user_id.clone()),
+                username: Set(user.username()),
+                accepted_at: Set(now),
+                ip: Set(ip.clone()),
+                user_agent: Set(user_agent.clone()),
+            }
+            .insert(&txn)
+            .await
+            .map_err(AppError::from)?;
+            acceptances.push(acceptance);
+        }
+        txn.commit().await.map_err(AppError::from)?;
+
+        if !acceptances.is_empty() {
+            record_audit(
+                AuditEntry::new(AUDIT_MODULE, "确认政策文档")
+                    .with_user(user)
+                    .with_detail(json!({
+                        "documents": acceptances
+                            .iter()
+                            .map(|acceptance| json!({
+                                "code": acceptance.code,
+                                "version": acceptance.version,
+                            }))
+                            .collect::<Vec<_>>(),
+                    })),
+            );
+        }
+        Ok(acceptances)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy(id: &str, code: &str, version: i32) -> PendingPolicy {
+        PendingPolicy {
+            document_id: id.to_string(),
+            code: code.to_string(),
+            version,
+            title: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_latest_versions() {
+        let latest = latest_versions(vec![
+            policy("tos-1", "tos", 1),
+            policy("privacy-1", "privacy", 1),
+            policy("tos-3", "tos", 3),
+            policy("tos-2", "tos", 2),
+        ]);
+
+        assert_eq!(
+            latest,
+            vec![policy("privacy-1", "privacy", 1), policy("tos-3", "tos", 3)]
+        );
+        assert!(latest_versions(Vec::new()).is_empty());
+    }
+}
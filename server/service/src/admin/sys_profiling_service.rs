@@ -0,0 +1,203 @@
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    thread,
+    time::Duration,
+};
+
+use async_trait::async_trait;
+use chrono::Local;
+use pprof::{protos::Message, ProfilerGuardBuilder};
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError};
+use server_model::admin::{
+    input::{CpuProfileInput, ProfileFormat},
+    output::{ProfileReport, RuntimeStats},
+};
+
+use super::sys_profiling_error::ProfilingError;
+use crate::helper::audit_helper::{record_audit, AuditEntry};
+
+const MAX_PROFILE_SECONDS: u64 = 120;
+const MAX_PROFILE_FREQUENCY: i32 = 1000;
+
+/// 采样期间会影响整个进程，同一时间只允许一次 CPU 剖析
+static PROFILING: AtomicBool = AtomicBool::new(false);
+
+/// 离开作用域时释放剖析占用标记
+struct ProfilingSlot;
+
+impl ProfilingSlot {
+    fn acquire() -> Result<Self, ProfilingError> {
+        PROFILING
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+            .map(|_| ProfilingSlot)
+            .map_err(|_| ProfilingError::AlreadyRunning)
+    }
+}
+
+impl Drop for ProfilingSlot {
+    fn drop(&mut self) {
+        PROFILING.store(false, Ordering::Release);
+    }
+}
+
+/// 运行时性能诊断，需启用 `profiling` 特性
+#[async_trait]
+pub trait TProfilingService {
+    /// 按指定时长采样 CPU，返回火焰图或 pprof protobuf
+    async fn capture_cpu_profile(
+        &self,
+        input: CpuProfileInput,
+        operator: &User,
+    ) -> Result<ProfileReport, AppError>;
+    /// 获取进程内存及异步运行时统计
+    async fn runtime_stats(&self) -> Result<RuntimeStats, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysProfilingService;
+
+impl SysProfilingService {
+    /// 阻塞采样，应在阻塞线程池中执行
+    fn sample_cpu(
+        seconds: u64,
+        frequency: i32,
+        format: ProfileFormat,
+    ) -> Result<Vec<u8>, ProfilingError> {
+        let guard = ProfilerGuardBuilder::default()
+            .frequency(frequency)
+            .blocklist(&["libc", "libgcc", "pthread", "vdso"])
+            .build()
+            .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))?;
+
+        thread::sleep(Duration::from_secs(seconds));
+
+        let report = guard
+            .report()
+            .build()
+            .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))?;
+
+        let mut data = Vec::new();
+        match format {
+            ProfileFormat::Flamegraph => report
+                .flamegraph(&mut data)
+                .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))?,
+            ProfileFormat::Protobuf => report
+                .pprof()
+                .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))?
+                .encode(&mut data)
+                .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))?,
+        }
+        Ok(data)
+    }
+}
+
+/// 从 `/proc/self/status` 内容中读取内存统计（单位 kB，转换为字节）
+fn parse_proc_status(status: &str) -> (u64, u64, u64) {
+    let field = |name: &str| {
+        status
+            .lines()
+            .find_map(|line| line.strip_prefix(name))
+            .and_then(|value| {
+                value
+                    .trim()
+                    .trim_end_matches("kB")
+                    .trim()
+                    .parse::<u64>()
+                    .ok()
+            })
+            .map(|kb| kb * 1024)
+            .unwrap_or_default()
+    };
+    (field("VmRSS:"), field("VmHWM:"), field("VmSize:"))
+}
+
+#[async_trait]
+impl TProfilingService for SysProfilingService {
+    async fn capture_cpu_profile(
+        &self,
+        input: CpuProfileInput,
+        operator: &User,
+    ) -> Result<ProfileReport, AppError> {
+        if !(1..=MAX_PROFILE_SECONDS).contains(&input.seconds) {
+            return Err(ProfilingError::InvalidDuration(MAX_PROFILE_SECONDS).into());
+        }
+        if !(1..=MAX_PROFILE_FREQUENCY).contains(&input.frequency) {
+            return Err(ProfilingError::InvalidFrequency(MAX_PROFILE_FREQUENCY).into());
+        }
+
+        let slot = ProfilingSlot::acquire()?;
+        record_audit(
+            AuditEntry::new("性能诊断", "采集 CPU 剖析")
+                .with_user(operator)
+                .with_detail(json!({
+                    "seconds": input.seconds,
+                    "frequency": input.frequency,
+                })),
+        );
+
+        let (seconds, frequency, format) = (input.seconds, input.frequency, input.format);
+        let data = tokio::task::spawn_blocking(move || {
+            let _slot = slot;
+            Self::sample_cpu(seconds, frequency, format)
+        })
+        .await
+        .map_err(|e| ProfilingError::CaptureFailed(e.to_string()))??;
+
+        let timestamp = Local::now().format("%Y%m%d%H%M%S");
+        let (file_name, content_type) = match format {
+            ProfileFormat::Flamegraph => (format!("cpu-{}.svg", timestamp), "image/svg+xml"),
+            ProfileFormat::Protobuf => {
+                (format!("cpu-{}.pb", timestamp), "application/octet-stream")
+            },
+        };
+
+        Ok(ProfileReport {
+            file_name,
+            content_type: content_type.to_string(),
+            data,
+        })
+    }
+
+    async fn runtime_stats(&self) -> Result<RuntimeStats, AppError> {
+        let status = tokio::fs::read_to_string("/proc/self/status")
+            .await
+            .map_err(|_| ProfilingError::Unsupported)?;
+        let (resident_bytes, peak_resident_bytes, virtual_bytes) = parse_proc_status(&status);
+
+        let metrics = tokio::runtime::Handle::current().metrics();
+        Ok(RuntimeStats {
+            resident_bytes,
+            peak_resident_bytes,
+            virtual_bytes,
+            worker_threads: metrics.num_workers(),
+            alive_tasks: metrics.num_alive_tasks(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_status() {
+        let status =
+            "Name:\tserver\nVmSize:\t  204800 kB\nVmHWM:\t   51200 kB\nVmRSS:\t   40960 kB\n";
+        assert_eq!(
+            parse_proc_status(status),
+            (40960 * 1024, 51200 * 1024, 204800 * 1024)
+        );
+    }
+
+    #[test]
+    fn test_profiling_slot_is_exclusive() {
+        let slot = ProfilingSlot::acquire().unwrap();
+        assert!(matches!(
+            ProfilingSlot::acquire(),
+            Err(ProfilingError::AlreadyRunning)
+        ));
+        drop(slot);
+        assert!(ProfilingSlot::acquire().is_ok());
+    }
+}
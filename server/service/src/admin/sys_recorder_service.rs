@@ -0,0 +1,242 @@
+use std::time::{Duration, Instant};
+
+use async_trait::async_trait;
+use chrono::Local;
+use serde_json::{json, Value};
+use server_config::RecorderConfig;
+use server_core::web::{
+    auth::User,
+    error::AppError,
+    recorder::{self, RecordedTrace, RecorderStatus, RecordingSession, REDACTED},
+};
+use server_global::global;
+use server_model::admin::{
+    input::{ReplayRecordingInput, StartRecordingInput},
+    output::ReplayResult,
+};
+use ulid::Ulid;
+
+use super::sys_recorder_error::RecorderError;
+use crate::helper::audit_helper::{record_audit, AuditEntry};
+
+/// 回放时不转发的请求头，由 HTTP 客户端按目标环境重新生成
+const SKIPPED_REPLAY_HEADERS: &[&str] = &[
+    "host",
+    "content-length",
+    "content-type",
+    "connection",
+    "accept-encoding",
+    "transfer-encoding",
+];
+
+#[async_trait]
+pub trait TRecorderService {
+    async fn status(&self) -> Result<RecorderStatus, AppError>;
+    /// 开启录制会话，替换进行中的会话并清空之前的录制结果
+    async fn start(
+        &self,
+        input: StartRecordingInput,
+        operator: &User,
+    ) -> Result<RecordingSession, AppError>;
+    async fn stop(&self, operator: &User) -> Result<RecorderStatus, AppError>;
+    async fn list_traces(&self) -> Result<Vec<RecordedTrace>, AppError>;
+    async fn clear_traces(&self, operator: &User) -> Result<usize, AppError>;
+    /// 按录制顺序将请求回放到目标环境，对比 HTTP 状态和业务码
+    async fn replay(
+        &self,
+        input: ReplayRecordingInput,
+        operator: &User,
+    ) -> Result<Vec<ReplayResult>, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysRecorderService;
+
+impl SysRecorderService {
+    async fn recorder_config() -> RecorderConfig {
+        global::get_config::<RecorderConfig>()
+            .await
+            .map(|config| (*config).clone())
+            .unwrap_or_default()
+    }
+}
+
+/// 从响应体中读取业务码
+fn response_code(body: Option<&Value>) -> Option<i64> {
+    body.and_then(|body| body.get("code"))
+        .and_then(Value::as_i64)
+}
+
+fn is_form(trace: &RecordedTrace) -> bool {
+    trace
+        .headers
+        .get("content-type")
+        .is_some_and(|value| value.starts_with("application/x-www-form-urlencoded"))
+}
+
+async fn replay_trace(
+    client: &reqwest::Client,
+    base_url: &str,
+    token: Option<&str>,
+    trace: &RecordedTrace,
+) -> ReplayResult {
+    let mut result = ReplayResult {
+        trace_id: trace.id.clone(),
+        method: trace.method.clone(),
+        uri: trace.uri.clone(),
+        original_status: trace.status,
+        original_code: response_code(trace.response_body.as_ref()),
+        replay_status: None,
+        replay_code: None,
+        matched: false,
+        duration_ms: 0,
+        error: None,
+    };
+
+    let method = match reqwest::Method::from_bytes(trace.method.as_bytes()) {
+        Ok(method) => method,
+        Err(e) => {
+            result.error = Some(e.to_string());
+            return result;
+        },
+    };
+
+    let mut request = client.request(method, format!("{}{}", base_url, trace.uri));
+    for (name, value) in &trace.headers {
+        if value == REDACTED || SKIPPED_REPLAY_HEADERS.contains(&name.as_str()) {
+            continue;
+        }
+        request = request.header(name, value);
+    }
+    if let Some(token) = token {
+        request = request.bearer_auth(token);
+    }
+    if let Some(body) = &trace.request_body {
+        request = if is_form(trace) {
+            request.form(body)
+        } else {
+            request.json(body)
+        };
+    }
+
+    let started_at = Instant::now();
+    match request.send().await {
+        Ok(response) => {
+            let status = response.status().as_u16();
+            let body = response.json::<Value>().await.ok();
+            result.replay_status = Some(status);
+            result.replay_code = response_code(body.as_ref());
+            result.matched =
+                status == result.original_status && result.replay_code == result.original_code;
+        },
+        Err(e) => result.error = Some(e.to_string()),
+    }
+    result.duration_ms = started_at.elapsed().as_millis() as u64;
+    result
+}
+
+#[async_trait]
+impl TRecorderService for SysRecorderService {
+    async fn status(&self) -> Result<RecorderStatus, AppError> {
+        Ok(recorder::recorder_status())
+    }
+
+    async fn start(
+        &self,
+        input: StartRecordingInput,
+        operator: &User,
+    ) -> Result<RecordingSession, AppError> {
+        let config = Self::recorder_config().await;
+        if !config.enabled {
+            return Err(RecorderError::Disabled.into());
+        }
+        if input.user_id.is_none() && input.request_id_pattern.is_none() {
+            return Err(RecorderError::MissingFilter.into());
+        }
+
+        let now = Local::now().naive_local();
+        let session = RecordingSession {
+            id: Ulid::new().to_string(),
+            user_id: input.user_id,
+            request_id_pattern: input.request_id_pattern,
+            capacity: input
+                .capacity
+                .unwrap_or(config.max_capacity)
+                .min(config.max_capacity),
+            max_body_bytes: config.max_body_bytes,
+            started_by: operator.username(),
+            started_at: now,
+            expires_at: now + chrono::Duration::seconds(input.duration_seconds as i64),
+        };
+        recorder::start_recording(session.clone());
+
+        record_audit(
+            AuditEntry::new("请求录制", "开启请求录制")
+                .with_user(operator)
+                .with_detail(json!(session)),
+        );
+        Ok(session)
+    }
+
+    async fn stop(&self, operator: &User) -> Result<RecorderStatus, AppError> {
+        if let Some(session) = recorder::stop_recording() {
+            record_audit(
+                AuditEntry::new("请求录制", "停止请求录制")
+                    .with_user(operator)
+                    .with_detail(json!({ "sessionId": session.id })),
+            );
+        }
+        Ok(recorder::recorder_status())
+    }
+
+    async fn list_traces(&self) -> Result<Vec<RecordedTrace>, AppError> {
+        Ok(recorder::recorded_traces())
+    }
+
+    async fn clear_traces(&self, operator: &User) -> Result<usize, AppError> {
+        let cleared = recorder::clear_recorded_traces();
+        record_audit(
+            AuditEntry::new("请求录制", "清空录制结果")
+                .with_user(operator)
+                .with_detail(json!({ "cleared": cleared })),
+        );
+        Ok(cleared)
+    }
+
+    async fn replay(
+        &self,
+        input: ReplayRecordingInput,
+        operator: &User,
+    ) -> Result<Vec<ReplayResult>, AppError> {
+        let traces: Vec<RecordedTrace> = recorder::recorded_traces()
+            .into_iter()
+            .filter(|trace| input.trace_ids.is_empty() || input.trace_ids.contains(&trace.id))
+            .collect();
+        if traces.is_empty() {
+            return Err(RecorderError::NothingToReplay.into());
+        }
+
+        let client = reqwest::Client::builder()
+            .timeout(Duration::from_secs(input.timeout_seconds))
+            .build()
+            .map_err(|e| RecorderError::ReplayClient(e.to_string()))?;
+        let base_url = input.base_url.trim_end_matches('/');
+
+        // 按录制顺序逐个回放，保持请求之间的先后依赖
+        let mut results = Vec::with_capacity(traces.len());
+        for trace in &traces {
+            results.push(replay_trace(&client, base_url, input.token.as_deref(), trace).await);
+        }
+
+        record_audit(
+            AuditEntry::new("请求录制", "回放录制请求")
+                .with_user(operator)
+                .with_detail(json!({
+                    "baseUrl": base_url,
+                    "replayed": results.len(),
+                    "matched": results.iter().filter(|result| result.matched).count(),
+                })),
+        );
+        Ok(results)
+    }
+}
@@ -0,0 +1,200 @@
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::{Alias, Asterisk, Expr, Func, Query, SimpleExpr},
+    ConnectionTrait,
+};
+use server_core::web::error::AppError;
+use server_model::admin::output::{EntityReferences, ReferenceCount};
+
+use super::sys_reference_error::ReferenceError;
+use crate::helper::db_helper;
+
+/// 引用方列的类型，决定被引用数据的 ID 如何绑定
+#[derive(Debug, Clone, Copy)]
+enum ColumnKind {
+    Text,
+    Integer,
+}
+
+/// 一种引用关系：`table.column` 保存被引用数据的 ID
+struct Relation {
+    name: &'static str,
+    table: &'static str,
+    column: &'static str,
+    kind: ColumnKind,
+}
+
+/// 可查询引用关系的数据
+struct ReferencedEntity {
+    name: &'static str,
+    relations: &'static [Relation],
+}
+
+const fn relation(
+    name: &'static str,
+    table: &'static str,
+    column: &'static str,
+    kind: ColumnKind,
+) -> Relation {
+    Relation {
+        name,
+        table,
+        column,
+        kind,
+    }
+}
+
+/// 引用关系注册表，新增外键或逻辑引用时在此登记
+const RELATION_REGISTRY: &[ReferencedEntity] = &[
+    ReferencedEntity {
+        name: "menu",
+        relations: &[
+            relation("childMenus", "sys_menu", "pid", ColumnKind::Text),
+            relation("roleMenus", "sys_role_menu", "menu_id", ColumnKind::Integer),
+        ],
+    },
+    ReferencedEntity {
+        name: "organization",
+        relations: &[
+            relation(
+                "childOrganizations",
+                "sys_organization",
+                "pid",
+                ColumnKind::Text,
+            ),
+            relation("users", "sys_user", "organization_id", ColumnKind::Text),
+            relation(
+                "scopedRoles",
+                "sys_role",
+                "scope_organization_id",
+                ColumnKind::Text,
+            ),
+            relation(
+                "accessReviewItems",
+                "sys_access_review_item",
+                "organization_id",
+                ColumnKind::Text,
+            ),
+        ],
+    },
+    ReferencedEntity {
+        name: "role",
+        relations: &[
+            relation("childRoles", "sys_role", "pid", ColumnKind::Text),
+            relation("userRoles", "sys_user_role", "role_id", ColumnKind::Text),
+            relation("roleMenus", "sys_role_menu", "role_id", ColumnKind::Text),
+        ],
+    },
+    ReferencedEntity {
+        name: "user",
+        relations: &[
+            relation("userRoles", "sys_user_role", "user_id", ColumnKind::Text),
+            relation("passkeys", "sys_user_passkey", "user_id", ColumnKind::Text),
+            relation("tokens", "sys_tokens", "user_id", ColumnKind::Text),
+            relation(
+                "notifications",
+                "sys_notification",
+                "user_id",
+                ColumnKind::Text,
+            ),
+            relation(
+                "accessReviewItems",
+                "sys_access_review_item",
+                "user_id",
+                ColumnKind::Text,
+            ),
+        ],
+    },
+];
+
+fn find_entity(name: &str) -> Option<&'static ReferencedEntity> {
+    RELATION_REGISTRY.iter().find(|entity| entity.name == name)
+}
+
+fn id_value(kind: ColumnKind, id: &str) -> Result<SimpleExpr, ReferenceError> {
+    match kind {
+        ColumnKind::Text => Ok(Expr::value(id)),
+        ColumnKind::Integer => id
+            .parse::<i64>()
+            .map(Expr::value)
+            .map_err(|_| ReferenceError::InvalidId),
+    }
+}
+
+#[async_trait]
+pub trait TReferenceService {
+    async fn find_references(&self, entity: &str, id: &str) -> Result<EntityReferences, AppError>;
+}
+
+/// 按注册表统计引用某条数据的关联数据，供前端在删除前提示
+#[derive(Clone)]
+pub struct SysReferenceService;
+
+impl SysReferenceService {
+    async fn count_references<C: ConnectionTrait>(
+        db: &C,
+        relation: &Relation,
+        id: &str,
+    ) -> Result<u64, AppError> {
+        let statement = Query::select()
+            .expr(Func::count(Expr::col(Asterisk)))
+            .from(Alias::new(relation.table))
+            .and_where(Expr::col(Alias::new(relation.column)).eq(id_value(relation.kind, id)?))
+            .to_owned();
+        let count: i64 = db
+            .query_one(db.get_database_backend().build(&statement))
+            .await
+            .map_err(AppError::from)?
+            .map(|row| row.try_get_by_index(0))
+            .transpose()
+            .map_err(AppError::from)?
+            .unwrap_or_default();
+        Ok(count.max(0) as u64)
+    }
+}
+
+#[async_trait]
+impl TReferenceService for SysReferenceService {
+    async fn find_references(&self, entity: &str, id: &str) -> Result<EntityReferences, AppError> {
+        let referenced = find_entity(entity).ok_or(ReferenceError::UnknownEntity)?;
+        // 引用数用于删除前检查，需包含其他租户的引用，不经过行级安全连接
+        let db = db_helper::get_db_connection().await?;
+
+        let mut relations = Vec::with_capacity(referenced.relations.len());
+        for relation in referenced.relations {
+            relations.push(ReferenceCount {
+                relation: relation.name.to_string(),
+                table: relation.table.to_string(),
+                column: relation.column.to_string(),
+                count: Self::count_references(db.as_ref(), relation, id).await?,
+            });
+        }
+
+        Ok(EntityReferences {
+            entity: referenced.name.to_string(),
+            id: id.to_string(),
+            total: relations.iter().map(|relation| relation.count).sum(),
+            relations,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relation_registry() {
+        assert!(find_entity("organization").is_some());
+        assert!(find_entity("tenant").is_none());
+
+        let menu = find_entity("menu").unwrap();
+        let role_menus = menu
+            .relations
+            .iter()
+            .find(|relation| relation.table == "sys_role_menu")
+            .unwrap();
+        assert!(id_value(role_menus.kind, "12").is_ok());
+        assert!(id_value(role_menus.kind, "abc").is_err());
+    }
+}
@@ -0,0 +1,642 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::{Arc, LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use async_trait::async_trait;
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder, Set,
+    TransactionTrait,
+};
+use serde::Deserialize;
+use serde_json::json;
+use server_core::web::{auth::User, error::AppError};
+use server_model::admin::{
+    entities::{
+        prelude::{SysRegion, SysRegionVersion},
+        sys_region::{ActiveModel as SysRegionActiveModel, Column as SysRegionColumn},
+        sys_region_version::{
+            ActiveModel as SysRegionVersionActiveModel, Column as SysRegionVersionColumn,
+            Model as SysRegionVersionModel,
+        },
+    },
+    input::{ImportRegionInput, RegionFormat},
+    output::{Region, TreeNode},
+};
+use tracing::instrument;
+use ulid::Ulid;
+
+use super::sys_region_error::RegionError;
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    db_helper,
+    tree_helper::{MAX_TREE_DEPTH, ROOT_PID},
+};
+
+const AUDIT_MODULE: &str = "行政区划";
+
+/// 启用版本的缓存时间，其他节点切换版本后最迟在此时间后生效
+const CACHE_TTL: Duration = Duration::from_secs(300);
+
+/// 单条插入语句的最大行数
+const INSERT_BATCH_SIZE: usize = 1000;
+
+/// 启用版本的全部区划，按上级编码索引
+struct RegionIndex {
+    regions: HashMap<String, (Region, String)>,
+    children: HashMap<String, Vec<String>>,
+}
+
+impl RegionIndex {
+    fn new(regions: Vec<ParsedRegion>) -> Self {
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        let mut index = HashMap::with_capacity(regions.len());
+        for region in regions {
+            children
+                .entry(region.pid.clone())
+                .or_default()
+                .push(region.code.clone());
+            index.insert(
+                region.code.clone(),
+                (
+                    Region {
+                        code: region.code,
+                        name: region.name,
+                        level: region.level,
+                    },
+                    region.pid,
+                ),
+            );
+        }
+        for codes in children.values_mut() {
+            codes.sort();
+        }
+        Self {
+            regions: index,
+            children,
+        }
+    }
+
+    /// `pid` 下最多 `depth` 层的区划，`depth` 为 0 时只标记是否有下级
+    fn subtree(&self, pid: &str, depth: u32) -> Vec<TreeNode<Region>> {
+        self.children
+            .get(pid)
+            .map(|codes| {
+                codes
+                    .iter()
+                    .filter_map(|code| self.regions.get(code))
+                    .map(|(region, _)| {
+                        let has_children = self.children.contains_key(&region.code);
+                        TreeNode {
+                            node: region.clone(),
+                            has_children,
+                            children: (depth > 1 && has_children)
+                                .then(|| self.subtree(&region.code, depth - 1)),
+                        }
+                    })
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// 从省级到 `code` 的各级区划
+    fn path(&self, code: &str) -> Option<Vec<Region>> {
+        let mut path = Vec::new();
+        let mut current = code;
+        while current != ROOT_PID && path.len() < MAX_TREE_DEPTH as usize {
+            let (region, pid) = self.regions.get(current)?;
+            path.push(region.clone());
+            current = pid;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
+
+/// 缓存的加载时间和内容
+type Cache = Mutex<Option<(Instant, Arc<RegionIndex>)>>;
+
+static CACHE: LazyLock<Cache> = LazyLock::new(|| Mutex::new(None));
+
+/// 导入或切换版本后清除本节点的缓存
+fn invalidate_cache() {
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 解析后待写入的区划
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ParsedRegion {
+    code: String,
+    name: String,
+    pid: String,
+    level: i16,
+}
+
+/// 嵌套 JSON 数据集中的节点，编码可以是字符串或数字
+#[derive(Deserialize)]
+struct RegionDocument {
+    code: serde_json::Value,
+    name: String,
+    #[serde(default)]
+    children: Vec<RegionDocument>,
+}
+
+fn document_code(code: &serde_json::Value) -> Result<String, RegionError> {
+    match code {
+        serde_json::Value::String(code) => Ok(code.trim().to_string()),
+        serde_json::Value::Number(code) => Ok(code.to_string()),
+        other => Err(RegionError::InvalidData(format!(
+            "invalid region code {}",
+            other
+        ))),
+    }
+}
+
+fn flatten_documents(
+    documents: Vec<RegionDocument>,
+    pid: &str,
+    rows: &mut Vec<(String, String, String)>,
+) -> Result<(), RegionError> {
+    for document in documents {
+        let code = document_code(&document.code)?;
+        flatten_documents(document.children, &code, rows)?;
+        rows.push((code, document.name.trim().to_string(), pid.to_string()));
+    }
+    Ok(())
+}
+
+/// GB/T 2260 代码的上级：`110000` 为省级，`110100` 的上级为 `110000`，
+/// `110101` 的上级为 `110100`，所在地级区划不存在时（如省直辖县）归到省级
+fn gb2260_parent(code: &str, codes: &HashSet<String>) -> String {
+    if code.ends_with("0000") {
+        return ROOT_PID.to_string();
+    }
+    let province = format!("{}0000", &code[..2]);
+    if code.ends_with("00") {
+        return province;
+    }
+    let city = format!("{}00", &code[..4]);
+    if codes.contains(&city) {
+        city
+    } else {
+        province
+    }
+}
+
+/// 按格式解析数据集，校验编码唯一、上级存在，并计算层级
+fn parse_regions(format: RegionFormat, content: &str) -> Result<Vec<ParsedRegion>, RegionError> {
+    let mut rows = Vec::new();
+    match format {
+        RegionFormat::Json => {
+            let documents: Vec<RegionDocument> = serde_json::from_str(content)
+                .map_err(|e| RegionError::InvalidData(e.to_string()))?;
+            flatten_documents(documents, ROOT_PID, &mut rows)?;
+        },
+        RegionFormat::Csv => {
+            for (index, line) in content.lines().enumerate() {
+                let fields: Vec<&str> = line
+                    .split(',')
+                    .map(|field| field.trim().trim_matches('"'))
+                    .collect();
+                match fields.as_slice() {
+                    [""] => continue,
+                    [code, ..] if index == 0 && code.eq_ignore_ascii_case("code") => continue,
+                    [code, name] => rows.push((code.to_string(), name.to_string(), String::new())),
+                    [code, name, pid] => {
+                        rows.push((code.to_string(), name.to_string(), pid.to_string()))
+                    },
+                    _ => {
+                        return Err(RegionError::InvalidData(format!(
+                            "line {}: expected code,name,parentCode",
+                            index + 1
+                        )))
+                    },
+                }
+            }
+            for row in &mut rows {
+                if row.2.is_empty() {
+                    row.2 = ROOT_PID.to_string();
+                }
+            }
+        },
+        RegionFormat::Gb2260 => {
+            let mut entries = Vec::new();
+            for (index, line) in content.lines().enumerate() {
+                let line = line.trim();
+                if line.is_empty() {
+                    continue;
+                }
+                let (code, name) = line
+                    .split_once(|c: char| c.is_whitespace() || c == ',')
+                    .map(|(code, name)| (code.trim(), name.trim()))
+                    .filter(|(code, _)| code.len() == 6 && code.bytes().all(|b| b.is_ascii_digit()))
+                    .ok_or_else(|| {
+                        RegionError::InvalidData(format!(
+                            "line {}: expected a 6-digit code followed by a name",
+                            index + 1
+                        ))
+                    })?;
+                entries.push((code.to_string(), name.to_string()));
+            }
+            let codes: HashSet<String> = entries.iter().map(|(code, _)| code.clone()).collect();
+            rows = entries
+                .into_iter()
+                .map(|(code, name)| {
+                    let pid = gb2260_parent(&code, &codes);
+                    (code, name, pid)
+                })
+                .collect();
+        },
+    }
+    resolve_levels(rows)
+}
+
+fn resolve_levels(rows: Vec<(String, String, String)>) -> Result<Vec<ParsedRegion>, RegionError> {
+    let mut parents: HashMap<&str, &str> = HashMap::with_capacity(rows.len());
+    for (code, name, pid) in &rows {
+        if code.is_empty() || code == ROOT_PID || name.is_empty() {
+            return Err(RegionError::InvalidData(format!(
+                "region '{}' must have a code and a name",
+                code
+            )));
+        }
+        if parents.insert(code, pid).is_some() {
+            return Err(RegionError::InvalidData(format!(
+                "duplicate region code {}",
+                code
+            )));
+        }
+    }
+
+    let level = |code: &str| -> Result<i16, RegionError> {
+        let mut level = 0;
+        let mut current = code;
+        while current != ROOT_PID {
+            current = parents.get(current).copied().ok_or_else(|| {
+                RegionError::InvalidData(format!("parent {} of region {} not found", current, code))
+            })?;
+            level += 1;
+            if level > MAX_TREE_DEPTH as i16 {
+                return Err(RegionError::InvalidData(format!(
+                    "region {} has a cyclic or too deep parent chain",
+                    code
+                )));
+            }
+        }
+        Ok(level)
+    };
+
+    let mut regions = rows
+        .iter()
+        .map(|(code, name, pid)| {
+            Ok(ParsedRegion {
+                level: level(code)?,
+                code: code.clone(),
+                name: name.clone(),
+                pid: pid.clone(),
+            })
+        })
+        .collect::<Result<Vec<_>, RegionError>>()?;
+    regions.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.code.cmp(&b.code)));
+    Ok(regions)
+}
+
+#[async_trait]
+pub trait TRegionService {
+    /// 启用版本中 `depth` 层以内的区划树，未指定时返回完整的树
+    async fn get_region_tree(&self, depth: Option<u32>) -> Result<Vec<TreeNode<Region>>, AppError>;
+
+    /// 懒加载直接下级，`code` 为 `0` 时返回省级区划
+    async fn get_children(&self, code: &str) -> Result<Vec<TreeNode<Region>>, AppError>;
+
+    /// 从省级到指定区划的各级区划，用于回显已保存的地址
+    async fn get_path(&self, code: &str) -> Result<Vec<Region>, AppError>;
+
+    async fn find_versions(&self) -> Result<Vec<SysRegionVersionModel>, AppError>;
+
+    /// 导入新版本，不影响正在使用的版本，`activate` 为 `true` 时导入后立即启用
+    async fn import_regions(
+        &self,
+        input: ImportRegionInput,
+        operator: &User,
+    ) -> Result<SysRegionVersionModel, AppError>;
+
+    async fn activate_version(
+        &self,
+        version: &str,
+        operator: &User,
+    ) -> Result<SysRegionVersionModel, AppError>;
+
+    /// 删除未启用的版本及其区划
+    async fn delete_version(&self, version: &str, operator: &User) -> Result<(), AppError>;
+}
+
+/// 行政区划（省/市/区县）数据
+///
+/// 每次导入生成一个版本，同一时间只有一个版本启用；地址选择器只读取启用的版本，
+/// 数据更新时先导入新版本，确认无误后再切换，切换前的版本保留用于回退
+#[derive(Clone)]
+pub struct SysRegionService;
+
+impl SysRegionService {
+    /// 读取缓存的启用版本，过期时重新加载
+    async fn cached_index(&self) -> Result<Arc<RegionIndex>, AppError> {
+        if let Some((_, index)) = CACHE
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .as_ref()
+            .filter(|(loaded_at, _)| loaded_at.elapsed() < CACHE_TTL)
+        {
+            return Ok(index.clone());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let version = SysRegionVersion::find()
+            .filter(SysRegionVersionColumn::Active.eq(true))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or(RegionError::NoActiveVersion)?;
+        let regions = SysRegion::find()
+            .filter(SysRegionColumn::Version.eq(&version.version))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|region| ParsedRegion {
+                code: region.code,
+                name: region.name,
+                pid: region.pid,
+                level: region.level,
+            })
+            .collect();
+
+        let index = Arc::new(RegionIndex::new(regions));
+        *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), index.clone()));
+        Ok(index)
+    }
+
+    async fn find_version(&self, version: &str) -> Result<SysRegionVersionModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysRegionVersion::find()
+            .filter(SysRegionVersionColumn::Version.eq(version))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| RegionError::VersionNotFound.into())
+    }
+}
+
+#[async_trait]
+impl TRegionService for SysRegionService {
+    async fn get_region_tree(&self, depth: Option<u32>) -> Result<Vec<TreeNode<Region>>, AppError> {
+        let depth = depth.unwrap_or(MAX_TREE_DEPTH).clamp(1, MAX_TREE_DEPTH);
+        Ok(self.cached_index().await?.subtree(ROOT_PID, depth))
+    }
+
+    async fn get_children(&self, code: &str) -> Result<Vec<TreeNode<Region>>, AppError> {
+        let index = self.cached_index().await?;
+        if code != ROOT_PID && !index.regions.contains_key(code) {
+            return Err(RegionError::RegionNotFound.into());
+        }
+        Ok(index.subtree(code, 0))
+    }
+
+    async fn get_path(&self, code: &str) -> Result<Vec<Region>, AppError> {
+        self.cached_index()
+            .await?
+            .path(code)
+            .ok_or_else(|| RegionError::RegionNotFound.into())
+    }
+
+    async fn find_versions(&self) -> Result<Vec<SysRegionVersionModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysRegionVersion::find()
+            .order_by_desc(SysRegionVersionColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)
+    }
+
+    #[instrument(skip(self, input, operator), fields(version = %input.version))]
+    async fn import_regions(
+        &self,
+        input: ImportRegionInput,
+        operator: &User,
+    ) -> Result<SysRegionVersionModel, AppError> {
+        let regions = parse_regions(input.format, &input.content)?;
+        if regions.is_empty() {
+            return Err(RegionError::InvalidData("no regions found".to_string()).into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let exists = SysRegionVersion::find()
+            .filter(SysRegionVersionColumn::Version.eq(&input.version))
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+        if exists {
+            return Err(RegionError::VersionExists.into());
+        }
+
+        if input.activate {
+            SysRegionVersion::update_many()
+                .col_expr(SysRegionVersionColumn::Active, Expr::value(false))
+                .filter(SysRegionVersionColumn::Active.eq(true))
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+        let version = SysRegionVersionActiveModel {
+            id: Set(Ulid::new().to_string()),
+            version: Set(input.version.clone()),
+            source: Set(input.source),
+            region_count: Set(regions.len() as i32),
+            active: Set(input.activate),
+            ..Default::default()
+        }
+        .insert(&txn)
+        .await
+        .map_err(AppError::from)?;
+
+        for batch in regions.chunks(INSERT_BATCH_SIZE) {
+            let rows = batch.iter().map(|region| SysRegionActiveModel {
+                id: Set(Ulid::new().to_string()),
+                version: Set(input.version.clone()),
+                code: Set(region.code.clone()),
+                name: Set(region.name.clone()),
+                pid: Set(region.pid.clone()),
+                level: Set(region.level),
+            });
+            SysRegion::insert_many(rows)
+                .exec(&txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        txn.commit().await.map_err(AppError::from)?;
+        if version.active {
+            invalidate_cache();
+        }
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "导入行政区划")
+                .with_user(operator)
+                .with_detail(json!({
+                    "version": version.version,
+                    "source": version.source,
+                    "regionCount": version.region_count,
+                    "active": version.active,
+                })),
+        );
+        Ok(version)
+    }
+
+    async fn activate_version(
+        &self,
+        version: &str,
+        operator: &User,
+    ) -> Result<SysRegionVersionModel, AppError> {
+        let target = self.find_version(version).await?;
+        if target.active {
+            return Ok(target);
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let previous = SysRegionVersion::find()
+            .filter(SysRegionVersionColumn::Active.eq(true))
+            .one(&txn)
+            .await
+            .map_err(AppError::from)?
+            .map(|previous| previous.version);
+        SysRegionVersion::update_many()
+            .col_expr(SysRegionVersionColumn::Active, Expr::value(false))
+            .filter(SysRegionVersionColumn::Active.eq(true))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        let mut active: SysRegionVersionActiveModel = target.into();
+        active.active = Set(true);
+        let target = active.update(&txn).await.map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+        invalidate_cache();
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "启用行政区划版本")
+                .with_user(operator)
+                .with_detail(json!({
+                    "version": target.version,
+                    "previousVersion": previous,
+                })),
+        );
+        Ok(target)
+    }
+
+    async fn delete_version(&self, version: &str, operator: &User) -> Result<(), AppError> {
+        let target = self.find_version(version).await?;
+        if target.active {
+            return Err(RegionError::VersionActive.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        SysRegion::delete_many()
+            .filter(SysRegionColumn::Version.eq(&target.version))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysRegionVersion::delete_by_id(target.id.clone())
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        record_audit(
+            AuditEntry::new(AUDIT_MODULE, "删除行政区划版本")
+                .with_user(operator)
+                .with_detail(json!({
+                    "version": target.version,
+                    "regionCount": target.region_count,
+                })),
+        );
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn codes(regions: &[ParsedRegion]) -> Vec<(&str, &str, i16)> {
+        regions
+            .iter()
+            .map(|region| (region.code.as_str(), region.pid.as_str(), region.level))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_regions() {
+        let nested = r#"[{ "code": "11", "name": "北京市", "children": [
+            { "code": 1101, "name": "市辖区", "children": [{ "code": "110101", "name": "东城区" }] }
+        ] }]"#;
+        assert_eq!(
+            codes(&parse_regions(RegionFormat::Json, nested).unwrap()),
+            vec![("11", "0", 1), ("1101", "11", 2), ("110101", "1101", 3)]
+        );
+
+        let csv = "code,name,parentCode\n42,湖北省,\n4201,武汉市,42\n420102,江岸区,4201\n";
+        assert_eq!(
+            codes(&parse_regions(RegionFormat::Csv, csv).unwrap()),
+            vec![("42", "0", 1), ("4201", "42", 2), ("420102", "4201", 3)]
+        );
+
+        let gb2260 = "420000 湖北省\n420100 武汉市\n420102 江岸区\n429004 仙桃市\n";
+        assert_eq!(
+            codes(&parse_regions(RegionFormat::Gb2260, gb2260).unwrap()),
+            vec![
+                ("420000", "0", 1),
+                ("420100", "420000", 2),
+                ("429004", "420000", 2),
+                ("420102", "420100", 3),
+            ]
+        );
+
+        assert!(parse_regions(RegionFormat::Csv, "42,湖北省,\n42,湖北省,\n").is_err());
+        assert!(parse_regions(RegionFormat::Csv, "4201,武汉市,42\n").is_err());
+        assert!(parse_regions(RegionFormat::Csv, "1,a,2\n2,b,1\n").is_err());
+    }
+
+    #[test]
+    fn test_region_index() {
+        let regions = parse_regions(
+            RegionFormat::Gb2260,
+            "420000 湖北省\n420100 武汉市\n420102 江岸区\n110000 北京市\n",
+        )
+        .unwrap();
+        let index = RegionIndex::new(regions);
+
+        let provinces = index.subtree(ROOT_PID, 0);
+        assert_eq!(provinces.len(), 2);
+        assert_eq!(provinces[0].node.code, "110000");
+        assert!(!provinces[0].has_children);
+        assert!(provinces[1].has_children && provinces[1].children.is_none());
+
+        let tree = index.subtree(ROOT_PID, 2);
+        let hubei = &tree[1].children.as_ref().unwrap()[0];
+        assert_eq!(hubei.node.code, "420100");
+        assert!(hubei.has_children && hubei.children.is_none());
+
+        let path = index.path("420102").unwrap();
+        assert_eq!(
+            path.iter()
+                .map(|region| region.name.as_str())
+                .collect::<Vec<_>>(),
+            vec!["湖北省", "武汉市", "江岸区"]
+        );
+        assert!(index.path("999999").is_none());
+    }
+}
@@ -1,21 +1,28 @@
 use async_trait::async_trait;
-use chrono::Local;
 use sea_orm::{
     ActiveModelTrait, ColumnTrait, Condition, EntityTrait, PaginatorTrait, QueryFilter, Set,
 };
-use server_core::web::{error::AppError, page::PaginatedData};
+use server_core::web::{error::AppError, page::PageResult};
 use server_model::admin::{
     entities::{
-        prelude::SysRole,
+        prelude::{SysOrganization, SysRole},
         sys_role::{
             ActiveModel as SysRoleActiveModel, Column as SysRoleColumn, Model as SysRoleModel,
         },
     },
-    input::{CreateRoleInput, RolePageRequest, UpdateRoleInput},
+    input::{
+        CreateRoleInput, DeleteWithCascadeInput, DeletionTarget, RolePageRequest, UpdateRoleInput,
+    },
 };
 
-use super::sys_role_error::RoleError;
-use crate::helper::db_helper;
+use super::{
+    sys_deletion_service::{SysDeletionService, TDeletionService},
+    sys_role_error::RoleError,
+};
+use crate::helper::{
+    cache_helper::{self, namespace},
+    db_helper,
+};
 use ulid::Ulid;
 
 #[async_trait]
@@ -23,7 +30,7 @@ pub trait TRoleService {
     async fn find_paginated_roles(
         &self,
         params: RolePageRequest,
-    ) -> Result<PaginatedData<SysRoleModel>, AppError>;
+    ) -> Result<PageResult<SysRoleModel>, AppError>;
 
     async fn create_role(&self, input: CreateRoleInput) -> Result<SysRoleModel, AppError>;
     async fn get_role(&self, id: &str) -> Result<SysRoleModel, AppError>;
@@ -51,6 +58,23 @@ impl SysRoleService {
 
         Ok(())
     }
+
+    /// 数据范围指定了根组织时校验组织存在
+    async fn check_scope_organization(
+        &self,
+        organization_id: Option<&str>,
+    ) -> Result<(), AppError> {
+        let Some(organization_id) = organization_id else {
+            return Ok(());
+        };
+        let db = db_helper::get_db_connection().await?;
+        SysOrganization::find_by_id(organization_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .map(|_| ())
+            .ok_or_else(|| RoleError::ScopeOrganizationNotFound.into())
+    }
 }
 
 #[async_trait]
@@ -58,8 +82,8 @@ impl TRoleService for SysRoleService {
     async fn find_paginated_roles(
         &self,
         params: RolePageRequest,
-    ) -> Result<PaginatedData<SysRoleModel>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+    ) -> Result<PageResult<SysRoleModel>, AppError> {
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysRole::find();
 
         if let Some(ref keywords) = params.keywords {
@@ -67,30 +91,23 @@ impl TRoleService for SysRoleService {
             query = query.filter(condition);
         }
 
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
 
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let paginator = query.paginate(&db, params.page_details.size);
         let records = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?;
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        Ok(PageResult::new(&params.page_details, total, records))
     }
 
     async fn create_role(&self, input: CreateRoleInput) -> Result<SysRoleModel, AppError> {
         let db = db_helper::get_db_connection().await?;
 
         self.check_role_exists(None, &input.code).await?;
+        self.check_scope_organization(input.scope_organization_id.as_deref())
+            .await?;
 
         let role = SysRoleActiveModel {
             id: Set(Ulid::new().to_string()),
@@ -99,8 +116,8 @@ impl TRoleService for SysRoleService {
             name: Set(input.name),
             status: Set(input.status),
             description: Set(input.description),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
+            data_scope: Set(input.data_scope),
+            scope_organization_id: Set(input.scope_organization_id),
             ..Default::default()
         };
 
@@ -109,12 +126,15 @@ impl TRoleService for SysRoleService {
     }
 
     async fn get_role(&self, id: &str) -> Result<SysRoleModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysRole::find_by_id(id)
-            .one(db.as_ref())
-            .await
-            .map_err(AppError::from)?
-            .ok_or_else(|| RoleError::RoleNotFound.into())
+        cache_helper::find_by_id(namespace::ROLE, id, || async {
+            let db = db_helper::get_db_connection().await?;
+            SysRole::find_by_id(id)
+                .one(db.as_ref())
+                .await
+                .map_err(AppError::from)
+        })
+        .await?
+        .ok_or_else(|| RoleError::RoleNotFound.into())
     }
 
     async fn update_role(&self, input: UpdateRoleInput) -> Result<SysRoleModel, AppError> {
@@ -122,6 +142,8 @@ impl TRoleService for SysRoleService {
 
         self.check_role_exists(Some(&input.id), &input.role.code)
             .await?;
+        self.check_scope_organization(input.role.scope_organization_id.as_deref())
+            .await?;
 
         let role: SysRoleActiveModel = SysRole::find_by_id(&input.id)
             .one(db.as_ref())
@@ -136,8 +158,8 @@ impl TRoleService for SysRoleService {
             code: Set(input.role.code),
             name: Set(input.role.name),
             description: Set(input.role.description),
-
-            updated_at: Set(Some(Local::now().naive_local())),
+            data_scope: Set(input.role.data_scope),
+            scope_organization_id: Set(input.role.scope_organization_id),
             ..role
         };
 
@@ -145,12 +167,11 @@ impl TRoleService for SysRoleService {
         Ok(updated_role)
     }
 
+    /// 按级联规则解除用户、菜单和接口授权后删除角色
     async fn delete_role(&self, id: &str) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysRole::delete_by_id(id)
-            .exec(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        SysDeletionService
+            .delete(DeletionTarget::Role, id, DeleteWithCascadeInput::default())
+            .await?;
         Ok(())
     }
 }
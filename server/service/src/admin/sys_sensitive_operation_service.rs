@@ -0,0 +1,219 @@
+use async_trait::async_trait;
+use chrono::{Duration, Local};
+use serde_json::json;
+use server_config::{SecurityConfig, SensitiveOperationConfig};
+use server_core::web::{
+    auth::User, environment_guard::SensitiveOperationApprover, error::AppError,
+};
+use server_global::global;
+use server_model::admin::{
+    input::RequestSensitiveOperationInput, output::SensitiveOperationApproval,
+};
+use ulid::Ulid;
+
+use crate::{
+    admin::sys_sensitive_operation_error::SensitiveOperationError,
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        redis_helper::{self, RedisSource},
+    },
+    project_error,
+};
+
+const APPROVAL_KEY_PREFIX: &str = "soybean:sensitive_operation";
+
+#[async_trait]
+pub trait TSensitiveOperationService {
+    /// 申请调用生产环境的敏感接口，审批在 `approval_ttl` 内有效
+    async fn request_approval(
+        &self,
+        input: RequestSensitiveOperationInput,
+        operator: &User,
+    ) -> Result<SensitiveOperationApproval, AppError>;
+    async fn get_approval(&self, id: &str) -> Result<SensitiveOperationApproval, AppError>;
+    /// 批准申请，批准人不能是申请人
+    async fn approve(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SensitiveOperationApproval, AppError>;
+}
+
+#[derive(Clone)]
+pub struct SysSensitiveOperationService;
+
+async fn sensitive_operation_config() -> SensitiveOperationConfig {
+    global::get_config::<SecurityConfig>()
+        .await
+        .map(|config| config.sensitive_operation.clone())
+        .unwrap_or_default()
+}
+
+fn approval_key(id: &str) -> String {
+    format!("{}:{}", APPROVAL_KEY_PREFIX, id)
+}
+
+fn serialize(approval: &SensitiveOperationApproval) -> Result<String, AppError> {
+    serde_json::to_string(approval).map_err(|e| {
+        AppError::internal(format!(
+            "Failed to serialize sensitive operation approval: {}",
+            e
+        ))
+    })
+}
+
+async fn load_approval(id: &str) -> Result<Option<SensitiveOperationApproval>, AppError> {
+    let value = redis_helper::query::<Option<String>>(
+        RedisSource::Primary,
+        redis::cmd("GET").arg(approval_key(id)),
+    )
+    .await?;
+    Ok(value.and_then(|value| match serde_json::from_str(&value) {
+        Ok(approval) => Some(approval),
+        Err(e) => {
+            project_error!("Failed to deserialize sensitive operation approval: {}", e);
+            None
+        },
+    }))
+}
+
+#[async_trait]
+impl TSensitiveOperationService for SysSensitiveOperationService {
+    async fn request_approval(
+        &self,
+        input: RequestSensitiveOperationInput,
+        operator: &User,
+    ) -> Result<SensitiveOperationApproval, AppError> {
+        let config = sensitive_operation_config().await;
+        if config.rule(&input.method, &input.path).is_none() {
+            return Err(SensitiveOperationError::NotSensitiveOperation.into());
+        }
+
+        let approval = SensitiveOperationApproval {
+            id: Ulid::new().to_string(),
+            method: input.method.to_uppercase(),
+            path: input.path,
+            reason: input.reason,
+            requester_id: operator.user_id(),
+            requester: operator.username(),
+            approver: None,
+            approved_at: None,
+            expires_at: Local::now().naive_local() + Duration::seconds(config.approval_ttl as i64),
+        };
+        redis_helper::query::<()>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(approval_key(&approval.id))
+                .arg(serialize(&approval)?)
+                .arg("EX")
+                .arg(config.approval_ttl),
+        )
+        .await?;
+
+        record_audit(
+            AuditEntry::new("敏感操作", "申请紧急授权")
+                .with_user(operator)
+                .with_detail(json!({
+                    "approvalId": approval.id,
+                    "method": approval.method,
+                    "path": approval.path,
+                    "reason": approval.reason,
+                })),
+        );
+        Ok(approval)
+    }
+
+    async fn get_approval(&self, id: &str) -> Result<SensitiveOperationApproval, AppError> {
+        load_approval(id)
+            .await?
+            .ok_or_else(|| SensitiveOperationError::ApprovalNotFound.into())
+    }
+
+    async fn approve(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<SensitiveOperationApproval, AppError> {
+        let mut approval = self.get_approval(id).await?;
+        if approval.approver.is_some() {
+            return Err(SensitiveOperationError::AlreadyApproved.into());
+        }
+        if approval.requester_id == operator.user_id() {
+            return Err(SensitiveOperationError::SelfApproval.into());
+        }
+
+        approval.approver = Some(operator.username());
+        approval.approved_at = Some(Local::now().naive_local());
+        // XX 避免审批恰好过期时写入一条没有过期时间的记录
+        let updated = redis_helper::query::<Option<String>>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(approval_key(id))
+                .arg(serialize(&approval)?)
+                .arg("XX")
+                .arg("KEEPTTL"),
+        )
+        .await?;
+        if updated.is_none() {
+            return Err(SensitiveOperationError::ApprovalNotFound.into());
+        }
+
+        record_audit(
+            AuditEntry::new("敏感操作", "批准紧急授权")
+                .with_user(operator)
+                .with_detail(json!({
+                    "approvalId": approval.id,
+                    "method": approval.method,
+                    "path": approval.path,
+                    "requester": approval.requester,
+                })),
+        );
+        Ok(approval)
+    }
+}
+
+#[async_trait]
+impl SensitiveOperationApprover for SysSensitiveOperationService {
+    async fn consume_approval(
+        &self,
+        user: &User,
+        approval_id: &str,
+        method: &str,
+        path: &str,
+        reason: &str,
+    ) -> Result<bool, AppError> {
+        let Some(approval) = load_approval(approval_id).await? else {
+            return Ok(false);
+        };
+        if approval.approver.is_none()
+            || approval.requester_id != user.user_id()
+            || !approval.method.eq_ignore_ascii_case(method)
+            || approval.path != path
+        {
+            return Ok(false);
+        }
+
+        // 并发请求只有删除成功的一方可以使用审批
+        let deleted = redis_helper::query::<i64>(
+            RedisSource::Primary,
+            redis::cmd("DEL").arg(approval_key(approval_id)),
+        )
+        .await?;
+        if deleted == 0 {
+            return Ok(false);
+        }
+
+        record_audit(
+            AuditEntry::new("敏感操作", "使用紧急授权")
+                .with_user(user)
+                .with_detail(json!({
+                    "approvalId": approval.id,
+                    "method": approval.method,
+                    "path": approval.path,
+                    "reason": reason,
+                    "approver": approval.approver,
+                })),
+        );
+        Ok(true)
+    }
+}
@@ -0,0 +1,1460 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use axum_casbin::casbin::{CoreApi, RbacApi};
+use chrono::{Duration, Local};
+use migration::{Migrator, MigratorTrait};
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, Condition, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    PaginatorTrait, QueryFilter, QueryOrder, QuerySelect, Set, TransactionTrait,
+};
+use serde_json::json;
+use server_config::{DatabaseConfig, TenantConfig};
+use server_core::{
+    sign::ValidatorType,
+    web::{auth::User, error::AppError, page::PageResult, tenant_gate::TenantStatusChecker},
+};
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        casbin_rule::Column as CasbinRuleColumn,
+        prelude::{
+            CasbinRule, SysAccessKey, SysDomain, SysFile, SysMenu, SysRole, SysRoleMenu, SysTenant,
+            SysUser, SysUserRole,
+        },
+        sea_orm_active_enums::{Status, TenantIsolation, TenantStatus},
+        sys_access_key::{
+            ActiveModel as SysAccessKeyActiveModel, Column as SysAccessKeyColumn,
+            Model as SysAccessKeyModel,
+        },
+        sys_domain::{ActiveModel as SysDomainActiveModel, Column as SysDomainColumn},
+        sys_file::Column as SysFileColumn,
+        sys_menu::Column as SysMenuColumn,
+        sys_role::{
+            ActiveModel as SysRoleActiveModel, Column as SysRoleColumn, Model as SysRoleModel,
+        },
+        sys_role_menu::{ActiveModel as SysRoleMenuActiveModel, Column as SysRoleMenuColumn},
+        sys_tenant::{
+            ActiveModel as SysTenantActiveModel, Column as SysTenantColumn, Model as SysTenantModel,
+        },
+        sys_user::{ActiveModel as SysUserActiveModel, Column as SysUserColumn},
+        sys_user_role::{ActiveModel as SysUserRoleActiveModel, Column as SysUserRoleColumn},
+    },
+    input::{
+        ConfirmTenantPurgeInput, ImportTenantInput, ProvisionTenantInput, SuspendTenantInput,
+        TenantPageRequest, UploadFileInput,
+    },
+    output::{
+        ArchivedAccessKey, ArchivedFile, ArchivedPolicy, ArchivedRole, ArchivedRoleMenu,
+        ArchivedUser, TenantArchive, TenantArchivePayload, TenantExport, TenantImportResult,
+        TenantPurgeConfirmation, TENANT_ARCHIVE_VERSION,
+    },
+};
+use server_utils::SecureUtil;
+use tokio::sync::RwLock;
+use ulid::Ulid;
+
+use crate::{
+    admin::{
+        sys_domain_error::DomainError, sys_tenant_error::TenantError, sys_user_error::UserError,
+        SysFileService, TFileService,
+    },
+    helper::{
+        audit_helper::{record_audit, AuditEntry},
+        db_helper, export_watermark_helper,
+        redis_helper::{self, RedisSource},
+        tenant_helper,
+    },
+    project_error, project_info,
+};
+
+/// 模板角色所在的域
+const TEMPLATE_DOMAIN: &str = "built-in";
+
+const PURGE_TOKEN_KEY_PREFIX: &str = "soybean:tenant_purge";
+
+#[async_trait]
+pub trait TTenantService {
+    async fn find_paginated_tenants(
+        &self,
+        params: TenantPageRequest,
+    ) -> Result<PageResult<SysTenantModel>, AppError>;
+    async fn get_tenant(&self, id: &str) -> Result<SysTenantModel, AppError>;
+
+    /// 开通租户：准备独立存储并执行迁移，再创建域、管理员角色和管理员账号，
+    /// 管理员角色复制模板角色的接口权限和菜单
+    async fn provision_tenant(
+        &self,
+        input: ProvisionTenantInput,
+        operator: &User,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
+    ) -> Result<SysTenantModel, AppError>;
+
+    /// 导出租户的账号、角色、授权、AccessKey 和文件，用于在环境间迁移租户
+    ///
+    /// 登录日志、操作日志和计量数据保留在源环境，独立存储中的业务数据随数据库备份迁移
+    async fn export_tenant(&self, id: &str, operator: &User) -> Result<TenantExport, AppError>;
+    /// 从导出档案导入为新租户，所有记录重新生成 ID
+    async fn import_tenant(
+        &self,
+        input: ImportTenantInput,
+        operator: &User,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
+    ) -> Result<TenantImportResult, AppError>;
+
+    /// 停用租户，已停用时只更新停用说明
+    async fn suspend_tenant(
+        &self,
+        id: &str,
+        input: SuspendTenantInput,
+        operator: &User,
+    ) -> Result<SysTenantModel, AppError>;
+    async fn resume_tenant(&self, id: &str, operator: &User) -> Result<SysTenantModel, AppError>;
+
+    /// 申请删除已停用的租户，返回有效期内使用的确认码
+    async fn request_purge(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<TenantPurgeConfirmation, AppError>;
+    /// 确认删除，等待期结束后由定时任务删除
+    async fn confirm_purge(
+        &self,
+        id: &str,
+        input: ConfirmTenantPurgeInput,
+        operator: &User,
+    ) -> Result<SysTenantModel, AppError>;
+    /// 撤销等待中的删除，租户恢复为停用状态
+    async fn cancel_purge(&self, id: &str, operator: &User) -> Result<SysTenantModel, AppError>;
+
+    /// 删除等待期已结束的租户，返回删除数量
+    async fn run_due_purges(&self) -> Result<usize, AppError>;
+    /// 启动时为独立 schema 的租户重建连接池
+    async fn restore_tenant_pools(&self) -> Result<(), AppError>;
+}
+
+/// 租户管理面向平台管理员，开通、导出和清理都要访问指定租户的数据，使用普通连接而非行级安全连接
+#[derive(Clone)]
+pub struct SysTenantService;
+
+async fn tenant_config() -> TenantConfig {
+    global::get_config::<TenantConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default()
+}
+
+fn purge_token_key(id: &str) -> String {
+    format!("{}:{}", PURGE_TOKEN_KEY_PREFIX, id)
+}
+
+/// 管理员角色编码，角色编码全局唯一，以模板角色加租户编码区分
+fn admin_role_code(template: &str, code: &str) -> String {
+    format!("{}_{}", template, code.to_uppercase().replace('-', "_"))
+}
+
+/// 档案内容的校验和，与导出时的序列化结果一致
+fn archive_checksum(payload: &TenantArchivePayload) -> Result<String, AppError> {
+    let data =
+        serde_json::to_vec(payload).map_err(|e| TenantError::InvalidArchive(e.to_string()))?;
+    Ok(hex::encode(
+        ring::digest::digest(&ring::digest::SHA256, &data).as_ref(),
+    ))
+}
+
+/// 将源租户的管理员角色编码改为目标租户的编码，无法识别时使用当前配置的模板角色
+fn remap_admin_role_code(
+    source_role_code: &str,
+    source: &str,
+    target: &str,
+    template: &str,
+) -> String {
+    let suffix = admin_role_code("", source);
+    let template = source_role_code
+        .strip_suffix(&suffix)
+        .filter(|prefix| !prefix.is_empty())
+        .unwrap_or(template);
+    admin_role_code(template, target)
+}
+
+/// 导入档案时在主库中写入的结果
+struct RestoredTenant {
+    tenant: SysTenantModel,
+    /// 档案中的角色编码到目标环境角色编码
+    role_codes: HashMap<String, String>,
+    /// 用户名到新用户 ID
+    user_ids: HashMap<String, String>,
+    roles: usize,
+    access_keys: Vec<SysAccessKeyModel>,
+    skipped_menus: Vec<String>,
+    skipped_access_keys: Vec<String>,
+}
+
+impl SysTenantService {
+    /// 检查目标租户编码、名称和存储是否可用，以及待创建的用户名是否已被占用
+    async fn check_target(
+        &self,
+        code: &str,
+        name: &str,
+        isolation: TenantIsolation,
+        db_instance: Option<&str>,
+        usernames: &[&str],
+    ) -> Result<(), AppError> {
+        if !tenant_helper::is_valid_code(code) {
+            return Err(TenantError::InvalidCode.into());
+        }
+
+        let db = db_helper::get_db_connection().await?;
+        let code_exists = SysDomain::find()
+            .filter(SysDomainColumn::Code.eq(code))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+        if code_exists {
+            return Err(DomainError::DuplicateCode.into());
+        }
+        let name_exists = SysDomain::find()
+            .filter(SysDomainColumn::Name.eq(name))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .is_some();
+        if name_exists {
+            return Err(DomainError::DuplicateName.into());
+        }
+        let username_exists = SysUser::find()
+            .filter(SysUserColumn::Username.is_in(usernames.iter().copied()))
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            > 0;
+        if username_exists {
+            return Err(UserError::UsernameAlreadyExists.into());
+        }
+
+        if isolation == TenantIsolation::Database {
+            let instance = db_instance.ok_or(TenantError::InvalidDbInstance)?;
+            db_helper::get_named_connection(instance)
+                .await
+                .map_err(|_| TenantError::InvalidDbInstance)?;
+        }
+        Ok(())
+    }
+
+    /// 为独立 schema 的租户注册连接池，连接默认使用租户 schema
+    async fn connect_schema(code: &str) -> Result<(), AppError> {
+        let primary = global::get_config::<DatabaseConfig>()
+            .await
+            .ok_or(TenantError::InvalidDbInstance)?;
+        let mut config = (*primary).clone();
+        config.url = tenant_helper::schema_url(
+            config.url.expose_secret(),
+            &tenant_helper::schema_name(code),
+        )
+        .into();
+        db_helper::rebuild_connection(&tenant_helper::pool_name(code), &config).await
+    }
+
+    /// 准备租户独立存储并执行迁移，共享模式不需要准备
+    async fn prepare_store(
+        &self,
+        code: &str,
+        isolation: TenantIsolation,
+        db_instance: Option<&str>,
+    ) -> Result<(), AppError> {
+        let connection = match isolation {
+            TenantIsolation::Shared => return Ok(()),
+            TenantIsolation::Schema => {
+                let db = db_helper::get_db_connection().await?;
+                db.execute_unprepared(&format!(
+                    "CREATE SCHEMA IF NOT EXISTS \"{}\"",
+                    tenant_helper::schema_name(code)
+                ))
+                .await
+                .map_err(AppError::from)?;
+                Self::connect_schema(code).await?;
+                db_helper::get_named_connection(&tenant_helper::pool_name(code)).await?
+            },
+            TenantIsolation::Database => {
+                db_helper::get_named_connection(db_instance.ok_or(TenantError::InvalidDbInstance)?)
+                    .await?
+            },
+        };
+        Migrator::up(connection.as_ref(), None)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 在主库中创建域、管理员角色、管理员账号和租户记录
+    async fn seed_tenant(
+        &self,
+        txn: &DatabaseTransaction,
+        input: &ProvisionTenantInput,
+        template_role: &SysRoleModel,
+        role_code: &str,
+        operator: &User,
+    ) -> Result<SysTenantModel, AppError> {
+        let now = Local::now().naive_local();
+        let domain = SysDomainActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(input.code.clone()),
+            name: Set(input.name.clone()),
+            description: Set(input.description.clone()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+
+        let role = SysRoleActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(role_code.to_string()),
+            name: Set(format!("{}{}", input.name, template_role.name)),
+            description: Set(Some(format!("租户 {} 的管理员", input.code))),
+            pid: Set(template_role.pid.clone()),
+            status: Set(Status::Enabled),
+            data_scope: Set(template_role.data_scope.clone()),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+
+        let menus = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::RoleId.eq(&template_role.id))
+            .filter(SysRoleMenuColumn::Domain.eq(TEMPLATE_DOMAIN))
+            .all(txn)
+            .await
+            .map_err(AppError::from)?;
+        if !menus.is_empty() {
+            SysRoleMenu::insert_many(menus.into_iter().map(|menu| SysRoleMenuActiveModel {
+                role_id: Set(role.id.clone()),
+                menu_id: Set(menu.menu_id),
+                domain: Set(input.code.clone()),
+            }))
+            .exec(txn)
+            .await
+            .map_err(AppError::from)?;
+        }
+
+        let admin = SysUserActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(input.code.clone()),
+            username: Set(input.admin_username.clone()),
+            password: Set(SecureUtil::hash_password(input.admin_password.as_bytes())
+                .map_err(|e| AppError::internal(format!("Failed to hash password: {}", e)))?),
+            built_in: Set(false),
+            nick_name: Set(input.admin_nick_name.clone()),
+            email: Set(input.admin_email.clone()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+        SysUserRoleActiveModel {
+            user_id: Set(admin.id),
+            role_id: Set(role.id.clone()),
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+
+        SysTenantActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(input.code.clone()),
+            domain_id: Set(domain.id),
+            isolation: Set(input.isolation),
+            db_instance: Set(match input.isolation {
+                TenantIsolation::Database => input.db_instance.clone(),
+                _ => None,
+            }),
+            admin_role_id: Set(role.id),
+            status: Set(TenantStatus::Active),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)
+    }
+
+    /// 在主库中按档案创建域、角色、用户、菜单授权、AccessKey 和租户记录
+    ///
+    /// 目标环境已存在同编码的角色时复用该角色，只有租户管理员角色按目标租户编码新建
+    async fn restore_archive(
+        &self,
+        txn: &DatabaseTransaction,
+        input: &ImportTenantInput,
+        admin_role_code: &str,
+        operator: &User,
+    ) -> Result<RestoredTenant, AppError> {
+        let payload = &input.archive.payload;
+        let now = Local::now().naive_local();
+        let domain = SysDomainActiveModel {
+            id: Set(Ulid::new().to_string()),
+            code: Set(input.code.clone()),
+            name: Set(input.name.clone()),
+            description: Set(payload.description.clone()),
+            status: Set(Status::Enabled),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+
+        let referenced_codes: Vec<&str> = payload
+            .roles
+            .iter()
+            .flat_map(|role| std::iter::once(role.code.as_str()).chain(role.parent_code.as_deref()))
+            .collect();
+        let mut role_ids: HashMap<String, String> = SysRole::find()
+            .filter(SysRoleColumn::Code.is_in(referenced_codes))
+            .all(txn)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|role| (role.code, role.id))
+            .collect();
+
+        let mut role_codes = HashMap::new();
+        let mut admin_role_id = None;
+        let mut created_roles = 0;
+        for role in &payload.roles {
+            let is_admin = role.code == payload.admin_role_code;
+            let code = if is_admin {
+                admin_role_code.to_string()
+            } else {
+                role.code.clone()
+            };
+            let id = match role_ids.get(&code).filter(|_| !is_admin) {
+                Some(id) => id.clone(),
+                None => {
+                    let pid = role
+                        .parent_code
+                        .as_ref()
+                        .and_then(|parent| role_ids.get(parent))
+                        .cloned()
+                        .unwrap_or_else(|| "0".to_string());
+                    let created = SysRoleActiveModel {
+                        id: Set(Ulid::new().to_string()),
+                        code: Set(code.clone()),
+                        name: Set(role.name.clone()),
+                        description: Set(role.description.clone()),
+                        pid: Set(pid),
+                        status: Set(Status::Enabled),
+                        data_scope: Set(role.data_scope.clone()),
+                        created_at: Set(now),
+                        created_by: Set(operator.username()),
+                        ..Default::default()
+                    }
+                    .insert(txn)
+                    .await
+                    .map_err(AppError::from)?;
+                    created_roles += 1;
+                    role_ids.insert(code.clone(), created.id.clone());
+                    created.id
+                },
+            };
+            if is_admin {
+                admin_role_id = Some(id.clone());
+            }
+            role_codes.insert(role.code.clone(), code);
+        }
+        let admin_role_id = admin_role_id
+            .ok_or_else(|| TenantError::InvalidArchive("admin role is missing".to_string()))?;
+        let role_id = |archived: &str| {
+            role_codes
+                .get(archived)
+                .and_then(|code| role_ids.get(code))
+                .cloned()
+        };
+
+        let mut user_ids = HashMap::new();
+        for user in &payload.users {
+            let created = SysUserActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain: Set(input.code.clone()),
+                username: Set(user.username.clone()),
+                password: Set(user.password.clone()),
+                built_in: Set(false),
+                avatar: Set(user.avatar.clone()),
+                email: Set(user.email.clone()),
+                phone_number: Set(user.phone_number.clone()),
+                nick_name: Set(user.nick_name.clone()),
+                status: Set(user.status.clone()),
+                created_at: Set(now),
+                created_by: Set(operator.username()),
+                ..Default::default()
+            }
+            .insert(txn)
+            .await
+            .map_err(AppError::from)?;
+            let links: Vec<_> = user
+                .roles
+                .iter()
+                .filter_map(|code| role_id(code))
+                .map(|role_id| SysUserRoleActiveModel {
+                    user_id: Set(created.id.clone()),
+                    role_id: Set(role_id),
+                })
+                .collect();
+            if !links.is_empty() {
+                SysUserRole::insert_many(links)
+                    .exec(txn)
+                    .await
+                    .map_err(AppError::from)?;
+            }
+            user_ids.insert(user.username.clone(), created.id);
+        }
+
+        let menu_ids: HashMap<String, i32> = SysMenu::find()
+            .filter(
+                SysMenuColumn::RouteName.is_in(
+                    payload
+                        .role_menus
+                        .iter()
+                        .map(|menu| menu.route_name.as_str()),
+                ),
+            )
+            .all(txn)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|menu| (menu.route_name, menu.id))
+            .collect();
+        let mut skipped_menus = Vec::new();
+        let mut role_menus = Vec::new();
+        for menu in &payload.role_menus {
+            match (role_id(&menu.role_code), menu_ids.get(&menu.route_name)) {
+                (Some(role_id), Some(menu_id)) => role_menus.push(SysRoleMenuActiveModel {
+                    role_id: Set(role_id),
+                    menu_id: Set(*menu_id),
+                    domain: Set(input.code.clone()),
+                }),
+                (_, None) if !skipped_menus.contains(&menu.route_name) => {
+                    skipped_menus.push(menu.route_name.clone())
+                },
+                _ => {},
+            }
+        }
+        if !role_menus.is_empty() {
+            SysRoleMenu::insert_many(role_menus)
+                .exec(txn)
+                .await
+                .map_err(AppError::from)?;
+        }
+
+        let existing_keys: Vec<String> = SysAccessKey::find()
+            .select_only()
+            .column(SysAccessKeyColumn::AccessKeyId)
+            .filter(
+                SysAccessKeyColumn::AccessKeyId.is_in(
+                    payload
+                        .access_keys
+                        .iter()
+                        .map(|key| key.access_key_id.as_str()),
+                ),
+            )
+            .into_tuple()
+            .all(txn)
+            .await
+            .map_err(AppError::from)?;
+        let mut access_keys = Vec::new();
+        for key in &payload.access_keys {
+            if existing_keys.contains(&key.access_key_id) {
+                continue;
+            }
+            let created = SysAccessKeyActiveModel {
+                id: Set(Ulid::new().to_string()),
+                domain: Set(input.code.clone()),
+                access_key_id: Set(key.access_key_id.clone()),
+                access_key_secret: Set(key.access_key_secret.clone()),
+                status: Set(key.status.clone()),
+                description: Set(key.description.clone()),
+                created_at: Set(now),
+                created_by: Set(operator.username()),
+            }
+            .insert(txn)
+            .await
+            .map_err(AppError::from)?;
+            access_keys.push(created);
+        }
+
+        let tenant = SysTenantActiveModel {
+            id: Set(Ulid::new().to_string()),
+            domain: Set(input.code.clone()),
+            domain_id: Set(domain.id),
+            isolation: Set(input.isolation),
+            db_instance: Set(match input.isolation {
+                TenantIsolation::Database => input.db_instance.clone(),
+                _ => None,
+            }),
+            admin_role_id: Set(admin_role_id),
+            status: Set(TenantStatus::Active),
+            created_at: Set(now),
+            created_by: Set(operator.username()),
+            ..Default::default()
+        }
+        .insert(txn)
+        .await
+        .map_err(AppError::from)?;
+
+        Ok(RestoredTenant {
+            tenant,
+            role_codes,
+            user_ids,
+            roles: created_roles,
+            access_keys,
+            skipped_menus,
+            skipped_access_keys: existing_keys,
+        })
+    }
+
+    async fn update_status(
+        &self,
+        tenant: SysTenantModel,
+        status: TenantStatus,
+        operator: &User,
+        apply: impl FnOnce(&mut SysTenantActiveModel),
+    ) -> Result<SysTenantModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut active: SysTenantActiveModel = tenant.into();
+        active.status = Set(status);
+        active.updated_at = Set(Some(Local::now().naive_local()));
+        active.updated_by = Set(Some(operator.username()));
+        apply(&mut active);
+        let updated = active.update(db.as_ref()).await.map_err(AppError::from)?;
+        tenant_helper::invalidate();
+        Ok(updated)
+    }
+
+    /// 删除租户在主库中的账号、角色、授权和文件，登录日志和操作日志保留用于审计
+    async fn purge_records(&self, tenant: &SysTenantModel) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+
+        // 逐个删除文件以维护内容引用计数，无引用的内容由文件 GC 清理
+        let file_ids = SysFile::find()
+            .select_only()
+            .column(SysFileColumn::Id)
+            .filter(SysFileColumn::Domain.eq(&tenant.domain))
+            .into_tuple::<String>()
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        for id in file_ids {
+            SysFileService.delete_file(&id).await?;
+        }
+
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let user_ids = SysUser::find()
+            .select_only()
+            .column(SysUserColumn::Id)
+            .filter(SysUserColumn::Domain.eq(&tenant.domain))
+            .into_tuple::<String>()
+            .all(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysUserRole::delete_many()
+            .filter(
+                Condition::any()
+                    .add(SysUserRoleColumn::UserId.is_in(user_ids))
+                    .add(SysUserRoleColumn::RoleId.eq(&tenant.admin_role_id)),
+            )
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysUser::delete_many()
+            .filter(SysUserColumn::Domain.eq(&tenant.domain))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysRoleMenu::delete_many()
+            .filter(SysRoleMenuColumn::Domain.eq(&tenant.domain))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysRole::delete_many()
+            .filter(SysRoleColumn::Id.eq(&tenant.admin_role_id))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        let access_key_ids: Vec<String> = SysAccessKey::find()
+            .select_only()
+            .column(SysAccessKeyColumn::AccessKeyId)
+            .filter(SysAccessKeyColumn::Domain.eq(&tenant.domain))
+            .into_tuple()
+            .all(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysAccessKey::delete_many()
+            .filter(SysAccessKeyColumn::Domain.eq(&tenant.domain))
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        // 内存中的授权策略属于已删除的域，不再被任何用户命中，重启后随表一并消失
+        CasbinRule::delete_many()
+            .filter(
+                Condition::any()
+                    .add(
+                        Condition::all()
+                            .add(CasbinRuleColumn::Ptype.eq("p"))
+                            .add(CasbinRuleColumn::V1.eq(&tenant.domain)),
+                    )
+                    .add(
+                        Condition::all()
+                            .add(CasbinRuleColumn::Ptype.eq("g"))
+                            .add(CasbinRuleColumn::V2.eq(&tenant.domain)),
+                    ),
+            )
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysDomain::delete_by_id(&tenant.domain_id)
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        SysTenant::delete_by_id(&tenant.id)
+            .exec(&txn)
+            .await
+            .map_err(AppError::from)?;
+        txn.commit().await.map_err(AppError::from)?;
+
+        for access_key_id in access_key_ids {
+            server_core::sign::remove_key(ValidatorType::Simple, &access_key_id).await;
+            server_core::sign::remove_key(ValidatorType::Complex, &access_key_id).await;
+        }
+        Ok(())
+    }
+
+    async fn purge_tenant(&self, tenant: &SysTenantModel) -> Result<(), AppError> {
+        self.purge_records(tenant).await?;
+
+        match tenant.isolation {
+            TenantIsolation::Shared => {},
+            TenantIsolation::Schema => {
+                db_helper::remove_connection(&tenant_helper::pool_name(&tenant.domain)).await;
+                let db = db_helper::get_db_connection().await?;
+                db.execute_unprepared(&format!(
+                    "DROP SCHEMA IF EXISTS \"{}\" CASCADE",
+                    tenant_helper::schema_name(&tenant.domain)
+                ))
+                .await
+                .map_err(AppError::from)?;
+            },
+            // 独立数据库由运维在配置中维护，不自动删除
+            TenantIsolation::Database => project_info!(
+                "Tenant '{}' purged, database instance '{}' must be decommissioned manually",
+                tenant.domain,
+                tenant.db_instance.as_deref().unwrap_or_default()
+            ),
+        }
+        tenant_helper::invalidate();
+
+        tracing::info!(target: "metrics", event = "tenant_purged", domain = %tenant.domain);
+        record_audit(AuditEntry::new("租户管理", "删除租户").with_detail(json!({
+            "domain": tenant.domain,
+            "isolation": tenant.isolation,
+            "requestedBy": tenant.purge_requested_by,
+        })));
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TTenantService for SysTenantService {
+    async fn find_paginated_tenants(
+        &self,
+        params: TenantPageRequest,
+    ) -> Result<PageResult<SysTenantModel>, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let mut query = SysTenant::find();
+
+        if let Some(ref keywords) = params.keywords {
+            query = query.filter(Condition::any().add(SysTenantColumn::Domain.contains(keywords)));
+        }
+        if let Some(status) = params.status {
+            query = query.filter(SysTenantColumn::Status.eq(status));
+        }
+
+        query = query.order_by_desc(SysTenantColumn::CreatedAt);
+
+        let total = query
+            .clone()
+            .count(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let paginator = query.paginate(db.as_ref(), params.page_details.size);
+        let records = paginator
+            .fetch_page(params.page_details.current - 1)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(PageResult::new(&params.page_details, total, records))
+    }
+
+    async fn get_tenant(&self, id: &str) -> Result<SysTenantModel, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        SysTenant::find_by_id(id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or_else(|| TenantError::TenantNotFound.into())
+    }
+
+    async fn provision_tenant(
+        &self,
+        input: ProvisionTenantInput,
+        operator: &User,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
+    ) -> Result<SysTenantModel, AppError> {
+        self.check_target(
+            &input.code,
+            &input.name,
+            input.isolation,
+            input.db_instance.as_deref(),
+            &[input.admin_username.as_str()],
+        )
+        .await?;
+
+        let config = tenant_config().await;
+        let db = db_helper::get_db_connection().await?;
+        let template_role = SysRole::find()
+            .filter(SysRoleColumn::Code.eq(&config.admin_template_role))
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or(TenantError::TemplateRoleNotFound)?;
+
+        // 先准备存储，失败时不留下域和账号
+        self.prepare_store(&input.code, input.isolation, input.db_instance.as_deref())
+            .await?;
+
+        let role_code = admin_role_code(&template_role.code, &input.code);
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let tenant = match self
+            .seed_tenant(&txn, &input, &template_role, &role_code, operator)
+            .await
+        {
+            Ok(tenant) => {
+                txn.commit().await.map_err(AppError::from)?;
+                tenant
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        {
+            let mut enforcer = enforcer.write().await;
+            let policies: Vec<Vec<String>> = enforcer
+                .get_filtered_policy(
+                    0,
+                    vec![template_role.code.clone(), TEMPLATE_DOMAIN.to_string()],
+                )
+                .into_iter()
+                .filter(|policy| policy.len() >= 4)
+                .map(|policy| {
+                    vec![
+                        role_code.clone(),
+                        input.code.clone(),
+                        policy[2].clone(),
+                        policy[3].clone(),
+                    ]
+                })
+                .collect();
+            // 同编码的租户删除后重新开通时，清除内存中残留的旧策略
+            let _ = enforcer
+                .remove_filtered_policy(1, vec![input.code.clone()])
+                .await;
+            if !policies.is_empty() {
+                enforcer
+                    .add_policies(policies)
+                    .await
+                    .map_err(|e| AppError::internal(e.to_string()))?;
+            }
+        }
+
+        record_audit(
+            AuditEntry::new("租户管理", "开通租户")
+                .with_user(operator)
+                .with_detail(json!({
+                    "tenant": tenant,
+                    "adminUsername": input.admin_username,
+                })),
+        );
+        Ok(tenant)
+    }
+
+    async fn export_tenant(&self, id: &str, operator: &User) -> Result<TenantExport, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        let db = db_helper::get_db_connection().await?;
+        let domain = SysDomain::find_by_id(&tenant.domain_id)
+            .one(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .ok_or(TenantError::TenantNotFound)?;
+
+        let users = SysUser::find()
+            .filter(SysUserColumn::Domain.eq(&tenant.domain))
+            .order_by_asc(SysUserColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let user_roles = SysUserRole::find()
+            .filter(SysUserRoleColumn::UserId.is_in(users.iter().map(|user| user.id.as_str())))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let role_menus = SysRoleMenu::find()
+            .filter(SysRoleMenuColumn::Domain.eq(&tenant.domain))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let role_ids: HashSet<&str> = user_roles
+            .iter()
+            .map(|link| link.role_id.as_str())
+            .chain(role_menus.iter().map(|menu| menu.role_id.as_str()))
+            .chain([tenant.admin_role_id.as_str()])
+            .collect();
+        let roles = SysRole::find()
+            .filter(SysRoleColumn::Id.is_in(role_ids))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let parent_codes: HashMap<String, String> = SysRole::find()
+            .filter(SysRoleColumn::Id.is_in(roles.iter().map(|role| role.pid.as_str())))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|role| (role.id, role.code))
+            .collect();
+        let role_codes: HashMap<&str, &str> = roles
+            .iter()
+            .map(|role| (role.id.as_str(), role.code.as_str()))
+            .collect();
+        let admin_role_code = role_codes
+            .get(tenant.admin_role_id.as_str())
+            .map(|code| code.to_string())
+            .ok_or(TenantError::TemplateRoleNotFound)?;
+
+        let menu_names: HashMap<i32, String> = SysMenu::find()
+            .filter(SysMenuColumn::Id.is_in(role_menus.iter().map(|menu| menu.menu_id)))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .map(|menu| (menu.id, menu.route_name))
+            .collect();
+
+        let policies = CasbinRule::find()
+            .filter(CasbinRuleColumn::Ptype.eq("p"))
+            .filter(CasbinRuleColumn::V1.eq(&tenant.domain))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        let access_keys = SysAccessKey::find()
+            .filter(SysAccessKeyColumn::Domain.eq(&tenant.domain))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut files = Vec::new();
+        let mut skipped_files = 0;
+        let records = SysFile::find()
+            .filter(SysFileColumn::Domain.eq(&tenant.domain))
+            .order_by_asc(SysFileColumn::CreatedAt)
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        for file in records {
+            // 隔离中的文件不随档案导出，避免绕过复核
+            if !file.scan_status.is_downloadable() {
+                skipped_files += 1;
+                continue;
+            }
+            let download = SysFileService.download_file(&file.id, operator).await?;
+            files.push(ArchivedFile {
+                created_by: file.created_by,
+                file_name: file.file_name,
+                content_type: file.content_type,
+                content: hex::encode(download.data),
+            });
+        }
+
+        let payload = TenantArchivePayload {
+            exported_at: Local::now().naive_local(),
+            code: domain.code,
+            name: domain.name,
+            description: domain.description,
+            isolation: tenant.isolation,
+            admin_role_code,
+            users: users
+                .iter()
+                .map(|user| ArchivedUser {
+                    username: user.username.clone(),
+                    password: user.password.clone(),
+                    nick_name: user.nick_name.clone(),
+                    avatar: user.avatar.clone(),
+                    email: user.email.clone(),
+                    phone_number: user.phone_number.clone(),
+                    status: user.status.clone(),
+                    roles: user_roles
+                        .iter()
+                        .filter(|link| link.user_id == user.id)
+                        .filter_map(|link| role_codes.get(link.role_id.as_str()))
+                        .map(|code| code.to_string())
+                        .collect(),
+                })
+                .collect(),
+            role_menus: role_menus
+                .iter()
+                .filter_map(|menu| {
+                    Some(ArchivedRoleMenu {
+                        role_code: role_codes.get(menu.role_id.as_str())?.to_string(),
+                        route_name: menu_names.get(&menu.menu_id)?.clone(),
+                    })
+                })
+                .collect(),
+            roles: roles
+                .iter()
+                .map(|role| ArchivedRole {
+                    code: role.code.clone(),
+                    name: role.name.clone(),
+                    description: role.description.clone(),
+                    parent_code: parent_codes.get(&role.pid).cloned(),
+                    data_scope: role.data_scope.clone(),
+                })
+                .collect(),
+            policies: policies
+                .into_iter()
+                .filter_map(|rule| {
+                    Some(ArchivedPolicy {
+                        role_code: rule.v0?,
+                        path: rule.v2?,
+                        method: rule.v3?,
+                    })
+                })
+                .collect(),
+            access_keys: access_keys
+                .into_iter()
+                .map(|key| ArchivedAccessKey {
+                    access_key_id: key.access_key_id,
+                    access_key_secret: key.access_key_secret,
+                    status: key.status,
+                    description: key.description,
+                })
+                .collect(),
+            files,
+        };
+        let watermark = export_watermark_helper::issue("tenant", operator).await;
+        let archive = TenantArchive {
+            version: TENANT_ARCHIVE_VERSION,
+            checksum: archive_checksum(&payload)?,
+            watermark: watermark
+                .as_ref()
+                .and_then(|watermark| watermark.embedded_text())
+                .map(str::to_string),
+            payload,
+        };
+        let data = serde_json::to_vec(&archive).map_err(|e| AppError::internal(e.to_string()))?;
+
+        tracing::info!(
+            target: "metrics",
+            event = "tenant_exported",
+            domain = %tenant.domain,
+            size = data.len(),
+        );
+        record_audit(
+            AuditEntry::new("租户管理", "导出租户")
+                .with_user(operator)
+                .with_detail(json!({
+                    "domain": tenant.domain,
+                    "users": archive.payload.users.len(),
+                    "files": archive.payload.files.len(),
+                    "skippedFiles": skipped_files,
+                    "watermarkId": watermark.as_ref().map(|watermark| &watermark.id),
+                })),
+        );
+        Ok(TenantExport {
+            file_name: format!("tenant-{}.json", tenant.domain),
+            data,
+            watermark_id: watermark.map(|watermark| watermark.id),
+        })
+    }
+
+    async fn import_tenant(
+        &self,
+        input: ImportTenantInput,
+        operator: &User,
+        enforcer: Arc<RwLock<impl CoreApi + RbacApi>>,
+    ) -> Result<TenantImportResult, AppError> {
+        let archive = &input.archive;
+        if archive.version != TENANT_ARCHIVE_VERSION {
+            return Err(TenantError::InvalidArchive(format!(
+                "unsupported version {}",
+                archive.version
+            ))
+            .into());
+        }
+        if archive_checksum(&archive.payload)? != archive.checksum {
+            return Err(TenantError::InvalidArchive("checksum mismatch".to_string()).into());
+        }
+        let payload = &archive.payload;
+
+        let usernames: Vec<&str> = payload
+            .users
+            .iter()
+            .map(|user| user.username.as_str())
+            .collect();
+        self.check_target(
+            &input.code,
+            &input.name,
+            input.isolation,
+            input.db_instance.as_deref(),
+            &usernames,
+        )
+        .await?;
+        // 先解码全部文件，档案损坏时不写入任何数据
+        let contents = payload
+            .files
+            .iter()
+            .map(|file| {
+                hex::decode(&file.content).map_err(|e| {
+                    TenantError::InvalidArchive(format!("file '{}': {}", file.file_name, e))
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        self.prepare_store(&input.code, input.isolation, input.db_instance.as_deref())
+            .await?;
+
+        let config = tenant_config().await;
+        let admin_role_code = remap_admin_role_code(
+            &payload.admin_role_code,
+            &payload.code,
+            &input.code,
+            &config.admin_template_role,
+        );
+        let db = db_helper::get_db_connection().await?;
+        let txn = db.begin().await.map_err(AppError::from)?;
+        let restored = match self
+            .restore_archive(&txn, &input, &admin_role_code, operator)
+            .await
+        {
+            Ok(restored) => {
+                txn.commit().await.map_err(AppError::from)?;
+                restored
+            },
+            Err(e) => {
+                txn.rollback().await.map_err(AppError::from)?;
+                return Err(e);
+            },
+        };
+
+        for key in &restored.access_keys {
+            server_core::sign::add_key(ValidatorType::Simple, &key.access_key_id, None).await;
+            server_core::sign::add_key(
+                ValidatorType::Complex,
+                &key.access_key_id,
+                Some(&key.access_key_secret),
+            )
+            .await;
+        }
+
+        let policies: Vec<Vec<String>> = payload
+            .policies
+            .iter()
+            .filter_map(|policy| {
+                Some(vec![
+                    restored.role_codes.get(&policy.role_code)?.clone(),
+                    input.code.clone(),
+                    policy.path.clone(),
+                    policy.method.clone(),
+                ])
+            })
+            .collect();
+        if !policies.is_empty() {
+            let mut enforcer = enforcer.write().await;
+            let _ = enforcer
+                .remove_filtered_policy(1, vec![input.code.clone()])
+                .await;
+            enforcer
+                .add_policies(policies.clone())
+                .await
+                .map_err(|e| AppError::internal(e.to_string()))?;
+        }
+
+        // 文件经正常上传流程写入，重新扫描并复用已有内容
+        let mut imported_files = 0;
+        let mut skipped_files = Vec::new();
+        for (file, data) in payload.files.iter().zip(contents) {
+            let upload = UploadFileInput {
+                domain: input.code.clone(),
+                file_name: file.file_name.clone(),
+                content_type: file.content_type.clone(),
+                data,
+                created_by: if restored.user_ids.contains_key(&file.created_by) {
+                    file.created_by.clone()
+                } else {
+                    operator.username()
+                },
+            };
+            match SysFileService.upload_file(upload).await {
+                Ok(_) => imported_files += 1,
+                Err(e) => {
+                    project_error!("Failed to import file '{}': {:?}", file.file_name, e);
+                    skipped_files.push(file.file_name.clone());
+                },
+            }
+        }
+
+        let result = TenantImportResult {
+            tenant: restored.tenant,
+            users: restored.user_ids.len(),
+            roles: restored.roles,
+            policies: policies.len(),
+            files: imported_files,
+            skipped_menus: restored.skipped_menus,
+            skipped_access_keys: restored.skipped_access_keys,
+            skipped_files,
+        };
+        tracing::info!(
+            target: "metrics",
+            event = "tenant_imported",
+            domain = %input.code,
+            source = %payload.code,
+        );
+        record_audit(
+            AuditEntry::new("租户管理", "导入租户")
+                .with_user(operator)
+                .with_detail(json!({
+                    "domain": input.code,
+                    "source": payload.code,
+                    "exportedAt": payload.exported_at,
+                    "result": {
+                        "users": result.users,
+                        "roles": result.roles,
+                        "policies": result.policies,
+                        "files": result.files,
+                        "skippedMenus": result.skipped_menus,
+                        "skippedAccessKeys": result.skipped_access_keys,
+                        "skippedFiles": result.skipped_files,
+                    },
+                })),
+        );
+        Ok(result)
+    }
+
+    async fn suspend_tenant(
+        &self,
+        id: &str,
+        input: SuspendTenantInput,
+        operator: &User,
+    ) -> Result<SysTenantModel, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.status == TenantStatus::PurgeScheduled {
+            return Err(TenantError::InvalidStatus.into());
+        }
+
+        let updated = self
+            .update_status(tenant, TenantStatus::Suspended, operator, |active| {
+                active.suspend_reason = Set(input.reason.clone());
+            })
+            .await?;
+        record_audit(
+            AuditEntry::new("租户管理", "停用租户")
+                .with_user(operator)
+                .with_detail(json!({ "domain": updated.domain, "reason": input.reason })),
+        );
+        Ok(updated)
+    }
+
+    async fn resume_tenant(&self, id: &str, operator: &User) -> Result<SysTenantModel, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.status != TenantStatus::Suspended {
+            return Err(TenantError::InvalidStatus.into());
+        }
+
+        let updated = self
+            .update_status(tenant, TenantStatus::Active, operator, |active| {
+                active.suspend_reason = Set(None);
+            })
+            .await?;
+        record_audit(
+            AuditEntry::new("租户管理", "恢复租户")
+                .with_user(operator)
+                .with_detail(json!({ "domain": updated.domain })),
+        );
+        Ok(updated)
+    }
+
+    async fn request_purge(
+        &self,
+        id: &str,
+        operator: &User,
+    ) -> Result<TenantPurgeConfirmation, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.status != TenantStatus::Suspended {
+            return Err(TenantError::InvalidStatus.into());
+        }
+
+        let config = tenant_config().await;
+        let token = Ulid::new().to_string();
+        redis_helper::query::<()>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(purge_token_key(id))
+                .arg(&token)
+                .arg("EX")
+                .arg(config.purge_confirm_ttl),
+        )
+        .await?;
+
+        record_audit(
+            AuditEntry::new("租户管理", "申请删除租户")
+                .with_user(operator)
+                .with_detail(json!({ "domain": tenant.domain })),
+        );
+        Ok(TenantPurgeConfirmation {
+            token,
+            expires_at: Local::now().naive_local()
+                + Duration::seconds(config.purge_confirm_ttl as i64),
+        })
+    }
+
+    async fn confirm_purge(
+        &self,
+        id: &str,
+        input: ConfirmTenantPurgeInput,
+        operator: &User,
+    ) -> Result<SysTenantModel, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.status != TenantStatus::Suspended {
+            return Err(TenantError::InvalidStatus.into());
+        }
+        if input.code != tenant.domain {
+            return Err(TenantError::InvalidPurgeConfirmation.into());
+        }
+        let token = redis_helper::query::<Option<String>>(
+            RedisSource::Primary,
+            redis::cmd("GETDEL").arg(purge_token_key(id)),
+        )
+        .await?;
+        if token.as_deref() != Some(input.token.as_str()) {
+            return Err(TenantError::InvalidPurgeConfirmation.into());
+        }
+
+        let config = tenant_config().await;
+        let scheduled_at =
+            Local::now().naive_local() + Duration::days(i64::from(config.purge_delay_days));
+        let updated = self
+            .update_status(tenant, TenantStatus::PurgeScheduled, operator, |active| {
+                active.purge_scheduled_at = Set(Some(scheduled_at));
+                active.purge_requested_by = Set(Some(operator.username()));
+            })
+            .await?;
+        record_audit(
+            AuditEntry::new("租户管理", "确认删除租户")
+                .with_user(operator)
+                .with_detail(json!({ "domain": updated.domain, "scheduledAt": scheduled_at })),
+        );
+        Ok(updated)
+    }
+
+    async fn cancel_purge(&self, id: &str, operator: &User) -> Result<SysTenantModel, AppError> {
+        let tenant = self.get_tenant(id).await?;
+        if tenant.status != TenantStatus::PurgeScheduled {
+            return Err(TenantError::InvalidStatus.into());
+        }
+
+        let updated = self
+            .update_status(tenant, TenantStatus::Suspended, operator, |active| {
+                active.purge_scheduled_at = Set(None);
+                active.purge_requested_by = Set(None);
+            })
+            .await?;
+        record_audit(
+            AuditEntry::new("租户管理", "撤销删除租户")
+                .with_user(operator)
+                .with_detail(json!({ "domain": updated.domain })),
+        );
+        Ok(updated)
+    }
+
+    async fn run_due_purges(&self) -> Result<usize, AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let due = SysTenant::find()
+            .filter(SysTenantColumn::Status.eq(TenantStatus::PurgeScheduled))
+            .filter(SysTenantColumn::PurgeScheduledAt.lte(Local::now().naive_local()))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+
+        let mut purged = 0;
+        for tenant in due {
+            match self.purge_tenant(&tenant).await {
+                Ok(()) => purged += 1,
+                Err(e) => project_error!("Failed to purge tenant '{}': {:?}", tenant.domain, e),
+            }
+        }
+        Ok(purged)
+    }
+
+    async fn restore_tenant_pools(&self) -> Result<(), AppError> {
+        let db = db_helper::get_db_connection().await?;
+        let tenants = SysTenant::find()
+            .filter(SysTenantColumn::Isolation.eq(TenantIsolation::Schema))
+            .all(db.as_ref())
+            .await
+            .map_err(AppError::from)?;
+        for tenant in tenants {
+            if let Err(e) = Self::connect_schema(&tenant.domain).await {
+                project_error!(
+                    "Failed to connect schema of tenant '{}': {:?}",
+                    tenant.domain,
+                    e
+                );
+            }
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl TenantStatusChecker for SysTenantService {
+    async fn suspension(&self, domain: &str) -> Result<Option<String>, AppError> {
+        tenant_helper::suspension(domain).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_admin_role_code() {
+        assert_eq!(
+            admin_role_code("ROLE_ADMIN", "acme-cn"),
+            "ROLE_ADMIN_ACME_CN"
+        );
+    }
+}
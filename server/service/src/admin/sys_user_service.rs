@@ -1,45 +1,126 @@
+use std::{collections::HashMap, sync::Arc};
+
 use async_trait::async_trait;
-use chrono::Local;
+use axum_casbin::casbin::MgmtApi;
+use chrono::{Local, NaiveDateTime};
 use sea_orm::{
-    ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel, PaginatorTrait,
-    QueryFilter, Set,
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, Condition, EntityTrait, IntoActiveModel,
+    JoinType, PaginatorTrait, QueryFilter, QuerySelect, RelationTrait, Set,
 };
-use server_core::web::{error::AppError, page::PaginatedData};
+use serde_json::{json, Value};
+use server_config::ComplianceConfig;
+use server_core::web::{auth::User, error::AppError, page::PageResult};
+use server_global::global;
 use server_model::admin::{
     entities::{
-        prelude::SysUser,
+        prelude::{SysLoginLog, SysOrganization, SysRole, SysUser, SysUserRole},
+        sea_orm_active_enums::Status,
+        sys_login_log::Column as SysLoginLogColumn,
+        sys_organization::Column as SysOrganizationColumn,
+        sys_role::{Column as SysRoleColumn, Relation as SysRoleRelation},
         sys_user::{
             ActiveModel as SysUserActiveModel, Column as SysUserColumn, Model as SysUserModel,
         },
+        sys_user_role::{Column as SysUserRoleColumn, Relation as SysUserRoleRelation},
     },
     input::{CreateUserInput, UpdateUserInput, UserPageRequest},
-    output::UserWithoutPassword,
+    output::{
+        DataScopeOutput, EffectivePermission, EffectivePermissionsOutput, UserWithoutPassword,
+    },
 };
 use server_utils::SecureUtil;
+use tokio::sync::RwLock;
+use tracing::instrument;
 use ulid::Ulid;
 
-use super::sys_user_error::UserError;
-use crate::helper::db_helper;
+use super::{
+    sys_auth_service::{SysAuthService, TAuthService},
+    sys_user_error::UserError,
+};
+use crate::helper::{
+    audit_helper::{record_audit, AuditEntry},
+    cache_helper::{self, namespace},
+    data_scope_helper::{self, ResolvedScope},
+    db_helper::{self, ReadOnlyConnection},
+    notification_helper::{self, category, Notification},
+    organization_helper,
+    redis_helper::{self, RedisSource},
+};
 
 #[async_trait]
 pub trait TUserService {
-    async fn find_all(&self) -> Result<Vec<UserWithoutPassword>, AppError>;
+    async fn find_all(&self, operator: &User) -> Result<Vec<UserWithoutPassword>, AppError>;
     async fn find_paginated_users(
         &self,
         params: UserPageRequest,
-    ) -> Result<PaginatedData<UserWithoutPassword>, AppError>;
+        operator: &User,
+    ) -> Result<PageResult<UserWithoutPassword>, AppError>;
 
-    async fn create_user(&self, input: CreateUserInput) -> Result<UserWithoutPassword, AppError>;
-    async fn get_user(&self, id: &str) -> Result<UserWithoutPassword, AppError>;
-    async fn update_user(&self, input: UpdateUserInput) -> Result<UserWithoutPassword, AppError>;
-    async fn delete_user(&self, id: &str) -> Result<(), AppError>;
+    async fn create_user(
+        &self,
+        input: CreateUserInput,
+        operator: &User,
+    ) -> Result<UserWithoutPassword, AppError>;
+    async fn get_user(&self, id: &str, operator: &User) -> Result<UserWithoutPassword, AppError>;
+    async fn update_user(
+        &self,
+        input: UpdateUserInput,
+        operator: &User,
+    ) -> Result<UserWithoutPassword, AppError>;
+    async fn delete_user(&self, id: &str, operator: &User) -> Result<(), AppError>;
+
+    /// 计算指定用户的有效权限、菜单和数据范围，不签发令牌
+    async fn get_effective_permissions(
+        &self,
+        id: &str,
+        operator: &User,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<EffectivePermissionsOutput, AppError>;
+
+    /// 按长期未登录账号策略通知或禁用账号，由定时任务调用
+    async fn disable_inactive_users(&self) -> Result<(), AppError>;
 }
 
 #[derive(Clone)]
 pub struct SysUserService;
 
+const INACTIVE_WARNED_KEY_PREFIX: &str = "soybean:inactive_account:warned";
+
+/// 用户列表支持通过 `include` 嵌入的关联
+const USER_INCLUDES: &[&str] = &["organization", "roles"];
+
+/// 长期未登录账号的处理动作
+#[derive(Debug, PartialEq, Eq)]
+enum InactiveAction {
+    /// 即将达到上限，提前通知
+    Warn { days_left: i64 },
+    /// 已达到上限，禁用账号
+    Disable,
+}
+
+/// 根据最近活跃时间判断账号需要的处理，未到通知期时返回 `None`
+fn evaluate_inactivity(
+    last_active: NaiveDateTime,
+    now: NaiveDateTime,
+    inactive_days: u32,
+    warning_days: u32,
+) -> Option<InactiveAction> {
+    let idle_days = (now - last_active).num_days();
+    let limit = i64::from(inactive_days);
+    if idle_days >= limit {
+        Some(InactiveAction::Disable)
+    } else if idle_days >= limit - i64::from(warning_days) {
+        Some(InactiveAction::Warn {
+            days_left: limit - idle_days,
+        })
+    } else {
+        None
+    }
+}
+
 impl SysUserService {
     async fn check_username_unique(&self, username: &str) -> Result<(), AppError> {
+        // 用户名全局唯一，需看到其他租户的用户，不经过行级安全连接
         let db = db_helper::get_db_connection().await?;
         let existing_user = SysUser::find()
             .filter(SysUserColumn::Username.eq(username))
@@ -54,21 +135,175 @@ impl SysUserService {
     }
 
     async fn get_user_by_id(&self, id: String) -> Result<SysUserModel, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         SysUser::find_by_id(id)
-            .one(db.as_ref())
+            .one(&db)
             .await
             .map_err(AppError::from)?
             .ok_or_else(|| UserError::UserNotFound.into())
     }
+
+    async fn get_role_codes(&self, user_id: &str) -> Result<Vec<String>, AppError> {
+        let db = db_helper::get_rls_connection().await?;
+        SysRole::find()
+            .select_only()
+            .column(SysRoleColumn::Code)
+            .join(JoinType::InnerJoin, SysRoleRelation::SysUserRole.def())
+            .filter(SysUserRoleColumn::UserId.eq(user_id))
+            .into_tuple::<String>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)
+    }
+
+    /// 向用户本人和上级发送禁用预告，同一轮通知期内只发送一次
+    async fn warn_inactive_user(
+        &self,
+        user: &SysUserModel,
+        recipients: Vec<String>,
+        days_left: i64,
+        warning_days: u32,
+    ) {
+        if recipients.is_empty() {
+            return;
+        }
+        let key = format!("{}:{}", INACTIVE_WARNED_KEY_PREFIX, user.id);
+        let first = redis_helper::query::<Option<String>>(
+            RedisSource::Primary,
+            redis::cmd("SET")
+                .arg(&key)
+                .arg(1)
+                .arg("NX")
+                .arg("EX")
+                .arg(u64::from(warning_days) * 86400),
+        )
+        .await;
+        match first {
+            Ok(Some(_)) => {},
+            Ok(None) => return,
+            Err(e) => {
+                tracing::warn!(
+                    user = %user.username,
+                    error = %e,
+                    "Failed to mark inactive account warning"
+                );
+                return;
+            },
+        }
+
+        let subject = format!(
+            "账号 {} 将在 {} 天后因长期未登录被禁用",
+            user.username, days_left
+        );
+        let content = format!(
+            "账号 {}（{}）已长期未登录，将在 {} 天后被自动禁用。如需继续使用，请在此之前登录一次。",
+            user.username, user.nick_name, days_left
+        );
+        for recipient in recipients {
+            let notification = Notification::new(
+                recipient,
+                category::ACCOUNT,
+                subject.clone(),
+                content.clone(),
+            );
+            if let Err(e) = notification_helper::notify(notification).await {
+                tracing::warn!(
+                    user = %user.username,
+                    error = %e,
+                    "Failed to send inactive account warning"
+                );
+            }
+        }
+    }
+
+    /// 校验目标用户是否在操作人的数据范围内
+    fn check_scope(&self, scope: &ResolvedScope, user: &SysUserModel) -> Result<(), AppError> {
+        if scope.allows(&user.id, user.organization_id.as_deref()) {
+            Ok(())
+        } else {
+            Err(UserError::OutOfDataScope.into())
+        }
+    }
+
+    /// 批量加载一页用户的角色，用户 ID 到角色列表
+    async fn load_user_roles(
+        &self,
+        db: &ReadOnlyConnection,
+        user_ids: Vec<String>,
+    ) -> Result<HashMap<String, Vec<Value>>, AppError> {
+        let rows: Vec<(String, String, String, String)> = SysUserRole::find()
+            .select_only()
+            .column(SysUserRoleColumn::UserId)
+            .column(SysRoleColumn::Id)
+            .column(SysRoleColumn::Code)
+            .column(SysRoleColumn::Name)
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+            .filter(SysUserRoleColumn::UserId.is_in(user_ids))
+            .into_tuple()
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+
+        let mut roles: HashMap<String, Vec<Value>> = HashMap::new();
+        for (user_id, id, code, name) in rows {
+            roles
+                .entry(user_id)
+                .or_default()
+                .push(json!({ "id": id, "code": code, "name": name }));
+        }
+        Ok(roles)
+    }
+
+    /// 批量加载一页用户所属的组织，组织 ID 到组织
+    async fn load_organizations(
+        &self,
+        db: &ReadOnlyConnection,
+        organization_ids: Vec<String>,
+    ) -> Result<HashMap<String, Value>, AppError> {
+        let organizations = SysOrganization::find()
+            .filter(SysOrganizationColumn::Id.is_in(organization_ids))
+            .all(db)
+            .await
+            .map_err(AppError::from)?;
+
+        Ok(organizations
+            .into_iter()
+            .map(|organization| {
+                let value = json!({
+                    "id": organization.id,
+                    "code": organization.code,
+                    "name": organization.name,
+                });
+                (organization.id, value)
+            })
+            .collect())
+    }
+
+    /// 校验操作人能否把用户放入指定组织
+    fn check_organization(
+        &self,
+        scope: &ResolvedScope,
+        organization_id: Option<&str>,
+    ) -> Result<(), AppError> {
+        if scope.allows_organization(organization_id) {
+            Ok(())
+        } else {
+            Err(UserError::OutOfDataScope.into())
+        }
+    }
 }
 
 #[async_trait]
 impl TUserService for SysUserService {
-    async fn find_all(&self) -> Result<Vec<UserWithoutPassword>, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysUser::find()
-            .all(db.as_ref())
+    async fn find_all(&self, operator: &User) -> Result<Vec<UserWithoutPassword>, AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        let db = db_helper::get_rls_connection().await?;
+        let mut query = SysUser::find();
+        if let Some(condition) = scope.user_condition() {
+            query = query.filter(condition);
+        }
+        query
+            .all(&db)
             .await
             .map(|users| users.into_iter().map(UserWithoutPassword::from).collect())
             .map_err(AppError::from)
@@ -77,23 +312,26 @@ impl TUserService for SysUserService {
     async fn find_paginated_users(
         &self,
         params: UserPageRequest,
-    ) -> Result<PaginatedData<UserWithoutPassword>, AppError> {
-        let db = db_helper::get_db_connection().await?;
+        operator: &User,
+    ) -> Result<PageResult<UserWithoutPassword>, AppError> {
+        let relations = params.include.relations(USER_INCLUDES)?;
+        let scope = data_scope_helper::resolve(operator).await?;
+        let db = db_helper::get_read_connection().await?;
         let mut query = SysUser::find();
 
+        if let Some(condition) = scope.user_condition() {
+            query = query.filter(condition);
+        }
+
         if let Some(ref keywords) = params.keywords {
             let condition = Condition::any().add(SysUserColumn::Username.contains(keywords));
             query = query.filter(condition);
         }
 
-        let total = query
-            .clone()
-            .count(db.as_ref())
-            .await
-            .map_err(AppError::from)?;
+        let total = query.clone().count(&db).await.map_err(AppError::from)?;
 
-        let paginator = query.paginate(db.as_ref(), params.page_details.size);
-        let records = paginator
+        let paginator = query.paginate(&db, params.page_details.size);
+        let records: Vec<UserWithoutPassword> = paginator
             .fetch_page(params.page_details.current - 1)
             .await
             .map_err(AppError::from)?
@@ -101,18 +339,42 @@ impl TUserService for SysUserService {
             .map(UserWithoutPassword::from)
             .collect();
 
-        Ok(PaginatedData {
-            current: params.page_details.current,
-            size: params.page_details.size,
-            total,
-            records,
-        })
+        // 关联数据按整页批量加载，每种关联只查询一次
+        let user_ids: Vec<String> = records.iter().map(|user| user.id.clone()).collect();
+        let organization_ids: Vec<String> = records
+            .iter()
+            .filter_map(|user| user.organization_id.clone())
+            .collect();
+        let mut page = PageResult::new(&params.page_details, total, records);
+        for relation in relations {
+            page = match relation.as_str() {
+                "roles" => {
+                    let roles = self.load_user_roles(&db, user_ids.clone()).await?;
+                    page.with_included("roles", roles)
+                },
+                "organization" => {
+                    let organizations = self
+                        .load_organizations(&db, organization_ids.clone())
+                        .await?;
+                    page.with_included("organization", organizations)
+                },
+                _ => page,
+            };
+        }
+
+        Ok(page)
     }
 
-    async fn create_user(&self, input: CreateUserInput) -> Result<UserWithoutPassword, AppError> {
+    async fn create_user(
+        &self,
+        input: CreateUserInput,
+        operator: &User,
+    ) -> Result<UserWithoutPassword, AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        self.check_organization(&scope, input.organization_id.as_deref())?;
         self.check_username_unique(&input.username).await?;
 
-        let db = db_helper::get_db_connection().await?;
+        let db = db_helper::get_rls_connection().await?;
         let user = SysUserActiveModel {
             id: Set(Ulid::new().to_string()),
             domain: Set(input.domain),
@@ -124,27 +386,43 @@ impl TUserService for SysUserService {
             email: Set(input.email),
             phone_number: Set(input.phone_number),
             status: Set(input.status),
-            created_at: Set(Local::now().naive_local()),
-            created_by: Set("TODO".to_string()),
+            organization_id: Set(input.organization_id),
             ..Default::default()
         };
 
-        let user_model = user.insert(db.as_ref()).await.map_err(AppError::from)?;
+        let user_model = user.insert(&db).await.map_err(AppError::from)?;
         Ok(UserWithoutPassword::from(user_model))
     }
 
-    async fn get_user(&self, id: &str) -> Result<UserWithoutPassword, AppError> {
-        let db = db_helper::get_db_connection().await?;
-        SysUser::find_by_id(id)
-            .one(db.as_ref())
-            .await
-            .map_err(AppError::from)?
-            .map(UserWithoutPassword::from)
-            .ok_or_else(|| UserError::UserNotFound.into())
+    async fn get_user(&self, id: &str, operator: &User) -> Result<UserWithoutPassword, AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        let user = cache_helper::find_by_id(namespace::USER, id, || async {
+            let db = db_helper::get_rls_connection().await?;
+            SysUser::find_by_id(id)
+                .one(&db)
+                .await
+                .map_err(AppError::from)
+        })
+        .await?
+        .ok_or(UserError::UserNotFound)?;
+
+        self.check_scope(&scope, &user)?;
+        Ok(UserWithoutPassword::from(user))
     }
 
-    async fn update_user(&self, input: UpdateUserInput) -> Result<UserWithoutPassword, AppError> {
-        let mut user = self.get_user_by_id(input.id).await?.into_active_model();
+    async fn update_user(
+        &self,
+        input: UpdateUserInput,
+        operator: &User,
+    ) -> Result<UserWithoutPassword, AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        let user = self.get_user_by_id(input.id).await?;
+        // 原组织和目标组织都须在范围内，避免把用户移出或移入他人管理的组织
+        self.check_scope(&scope, &user)?;
+        if input.user.organization_id != user.organization_id {
+            self.check_organization(&scope, input.user.organization_id.as_deref())?;
+        }
+        let mut user = user.into_active_model();
 
         if input.user.username != *user.username.as_ref() {
             self.check_username_unique(&input.user.username).await?;
@@ -158,17 +436,22 @@ impl TUserService for SysUserService {
         user.email = Set(input.user.email);
         user.phone_number = Set(input.user.phone_number);
         user.status = Set(input.user.status);
+        user.organization_id = Set(input.user.organization_id);
 
-        let db = db_helper::get_db_connection().await?;
-        let updated_user = user.update(db.as_ref()).await.map_err(AppError::from)?;
+        let db = db_helper::get_rls_connection().await?;
+        let updated_user = user.update(&db).await.map_err(AppError::from)?;
         Ok(UserWithoutPassword::from(updated_user))
     }
 
-    async fn delete_user(&self, id: &str) -> Result<(), AppError> {
-        let db = db_helper::get_db_connection().await?;
+    async fn delete_user(&self, id: &str, operator: &User) -> Result<(), AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        let user = self.get_user_by_id(id.to_string()).await?;
+        self.check_scope(&scope, &user)?;
+
+        let db = db_helper::get_rls_connection().await?;
 
         let result = SysUser::delete_by_id(id)
-            .exec(db.as_ref())
+            .exec(&db)
             .await
             .map_err(AppError::from)?;
 
@@ -178,4 +461,195 @@ impl TUserService for SysUserService {
 
         Ok(())
     }
+    async fn get_effective_permissions(
+        &self,
+        id: &str,
+        operator: &User,
+        enforcer: Arc<RwLock<impl MgmtApi + Send + Sync>>,
+    ) -> Result<EffectivePermissionsOutput, AppError> {
+        let scope = data_scope_helper::resolve(operator).await?;
+        let user = self.get_user_by_id(id.to_string()).await?;
+        self.check_scope(&scope, &user)?;
+
+        let roles = self.get_role_codes(&user.id).await?;
+
+        // 策略格式为 [角色, 域, 路径, 方法]，与 Casbin 鉴权时的匹配方式一致
+        let mut permissions = {
+            let enforcer = enforcer.read().await;
+            roles
+                .iter()
+                .flat_map(|role| {
+                    enforcer.get_filtered_policy(0, vec![role.clone(), user.domain.clone()])
+                })
+                .filter_map(|policy| match policy.as_slice() {
+                    [role, _, path, method, ..] => Some(EffectivePermission {
+                        role: role.clone(),
+                        path: path.clone(),
+                        method: method.clone(),
+                    }),
+                    _ => None,
+                })
+                .collect::<Vec<_>>()
+        };
+        permissions.sort();
+
+        let routes = SysAuthService.get_user_routes(&roles, &user.domain).await?;
+
+        let data_scope = match data_scope_helper::resolve_for(&user.id, &roles).await? {
+            ResolvedScope::All => DataScopeOutput {
+                unrestricted: true,
+                organization_ids: vec![],
+            },
+            ResolvedScope::Limited { organizations, .. } => {
+                let mut organization_ids = organizations.into_iter().collect::<Vec<_>>();
+                organization_ids.sort();
+                DataScopeOutput {
+                    unrestricted: false,
+                    organization_ids,
+                }
+            },
+        };
+
+        record_audit(
+            AuditEntry::new("用户管理", "查看用户有效权限")
+                .with_user(operator)
+                .with_detail(json!({ "userId": user.id, "username": user.username })),
+        );
+
+        Ok(EffectivePermissionsOutput {
+            user: UserWithoutPassword::from(user),
+            roles,
+            permissions,
+            routes,
+            data_scope,
+        })
+    }
+
+    #[instrument(skip(self))]
+    async fn disable_inactive_users(&self) -> Result<(), AppError> {
+        let config = global::get_config::<ComplianceConfig>()
+            .await
+            .map(|config| config.inactive_account.clone())
+            .unwrap_or_default();
+        if !config.enabled {
+            return Ok(());
+        }
+        let db = db_helper::get_rls_connection().await?;
+
+        let users = SysUser::find().all(&db).await.map_err(AppError::from)?;
+
+        let mut user_roles: HashMap<String, Vec<String>> = HashMap::new();
+        for (user_id, code) in SysUserRole::find()
+            .select_only()
+            .column(SysUserRoleColumn::UserId)
+            .column(SysRoleColumn::Code)
+            .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+            .into_tuple::<(String, String)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+        {
+            user_roles.entry(user_id).or_default().push(code);
+        }
+
+        let last_logins: HashMap<String, Option<NaiveDateTime>> = SysLoginLog::find()
+            .select_only()
+            .column(SysLoginLogColumn::UserId)
+            .expr(Expr::col(SysLoginLogColumn::LoginTime).max())
+            .group_by(SysLoginLogColumn::UserId)
+            .into_tuple::<(String, Option<NaiveDateTime>)>()
+            .all(&db)
+            .await
+            .map_err(AppError::from)?
+            .into_iter()
+            .collect();
+
+        let parents = organization_helper::load_parents().await?;
+        let managers = organization_helper::role_holders(&config.manager_role).await?;
+
+        let now = Local::now().naive_local();
+        for user in users
+            .iter()
+            .filter(|user| !user.built_in && user.status == Status::Enabled)
+        {
+            let roles = user_roles.get(&user.id).cloned().unwrap_or_default();
+            let Some(inactive_days) = config.inactive_days_for(&user.username, &roles) else {
+                continue;
+            };
+            // 从未登录过的账号从创建时间开始计算
+            let last_active = last_logins
+                .get(&user.id)
+                .copied()
+                .flatten()
+                .unwrap_or(user.created_at);
+
+            match evaluate_inactivity(last_active, now, inactive_days, config.warning_days) {
+                None => {},
+                Some(InactiveAction::Warn { days_left }) => {
+                    let manager = organization_helper::nearest_holder(
+                        &user.id,
+                        user.organization_id.as_deref(),
+                        &parents,
+                        &managers,
+                    );
+                    let recipients = std::iter::once(user.id.clone())
+                        .chain(manager.filter(|manager| manager != &user.id))
+                        .collect::<Vec<_>>();
+                    self.warn_inactive_user(user, recipients, days_left, config.warning_days)
+                        .await;
+                },
+                Some(InactiveAction::Disable) => {
+                    let mut active = user.clone().into_active_model();
+                    active.status = Set(Status::Disabled);
+                    active.updated_at = Set(Some(now));
+                    active.updated_by = Set(Some("system".to_string()));
+                    active.update(&db).await.map_err(AppError::from)?;
+
+                    tracing::info!(
+                        target: "metrics",
+                        event = "inactive_account_disabled",
+                        username = %user.username
+                    );
+                    record_audit(
+                        AuditEntry::new("用户管理", "禁用长期未登录账号").with_detail(json!({
+                            "userId": user.id,
+                            "username": user.username,
+                            "lastActiveAt": last_active,
+                            "inactiveDays": inactive_days,
+                        })),
+                    );
+                },
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+
+    #[test]
+    fn test_evaluate_inactivity() {
+        let now = Local::now().naive_local();
+        let days_ago = |days| now - Duration::days(days);
+
+        assert_eq!(evaluate_inactivity(days_ago(10), now, 90, 7), None);
+        assert_eq!(
+            evaluate_inactivity(days_ago(83), now, 90, 7),
+            Some(InactiveAction::Warn { days_left: 7 })
+        );
+        assert_eq!(
+            evaluate_inactivity(days_ago(89), now, 90, 7),
+            Some(InactiveAction::Warn { days_left: 1 })
+        );
+        assert_eq!(
+            evaluate_inactivity(days_ago(90), now, 90, 7),
+            Some(InactiveAction::Disable)
+        );
+        // 不提前通知时到期才处理
+        assert_eq!(evaluate_inactivity(days_ago(89), now, 90, 0), None);
+    }
 }
@@ -0,0 +1,336 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use chrono::{Local, NaiveDateTime};
+use serde::Serialize;
+use serde_json::{json, Value};
+use server_config::AlertConfig;
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::error::AppError;
+use server_global::global;
+use server_model::admin::entities::{
+    sea_orm_active_enums::{AlertChannel, AlertSignalKind},
+    sys_alert_rule::Model as SysAlertRuleModel,
+};
+
+/// 超过该数量的计数窗口时清理已过期的窗口，避免随机用户名的登录失败撑大内存
+const MAX_TRACKED_WINDOWS: usize = 10_000;
+
+static TRACKER: LazyLock<Mutex<AlertTracker>> =
+    LazyLock::new(|| Mutex::new(AlertTracker::default()));
+
+/// 安全信号，由业务代码通过事件总线发送，告警监听器按规则计数
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SecuritySignal {
+    pub kind: AlertSignalKind,
+    /// 计数对象，如登录失败的账号、被授权的角色、导出数据的用户
+    pub subject: String,
+    /// 触发信号的操作人
+    pub actor: Option<String>,
+    pub domain: Option<String>,
+    pub detail: Option<Value>,
+    pub occurred_at: NaiveDateTime,
+}
+
+impl SecuritySignal {
+    pub fn new(kind: AlertSignalKind, subject: impl Into<String>) -> Self {
+        Self {
+            kind,
+            subject: subject.into(),
+            actor: None,
+            domain: None,
+            detail: None,
+            occurred_at: Local::now().naive_local(),
+        }
+    }
+
+    pub fn with_actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+
+    pub fn with_detail(mut self, detail: Value) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+}
+
+/// 发送安全信号，由告警监听器异步处理
+pub fn emit(signal: SecuritySignal) {
+    global::send_dyn_event(SystemEvent::SecuritySignalEvent.as_ref(), Box::new(signal));
+}
+
+#[derive(Default)]
+struct AlertWindow {
+    hits: VecDeque<Instant>,
+    last_fired: Option<Instant>,
+}
+
+impl AlertWindow {
+    fn is_idle(&self, now: Instant, retention: Duration) -> bool {
+        self.hits.is_empty()
+            && self
+                .last_fired
+                .is_none_or(|fired| now.duration_since(fired) >= retention)
+    }
+}
+
+/// 按（规则，对象）维护的滑动窗口计数
+///
+/// 计数只保存在当前实例内存中，多实例部署时各实例分别计数
+#[derive(Default)]
+struct AlertTracker {
+    windows: HashMap<(String, String), AlertWindow>,
+}
+
+impl AlertTracker {
+    /// 记录一次信号，达到阈值且不在冷却期内时返回窗口内的信号数
+    fn observe(&mut self, rule: &SysAlertRuleModel, subject: &str, now: Instant) -> Option<usize> {
+        let window_size = Duration::from_secs(rule.window_seconds.max(1) as u64);
+        let cooldown = Duration::from_secs(rule.cooldown_seconds.max(0) as u64);
+
+        if self.windows.len() >= MAX_TRACKED_WINDOWS {
+            let retention = window_size.max(cooldown);
+            self.windows.retain(|_, window| {
+                window
+                    .hits
+                    .retain(|hit| now.duration_since(*hit) < window_size);
+                !window.is_idle(now, retention)
+            });
+        }
+
+        let window = self
+            .windows
+            .entry((rule.id.clone(), subject.to_string()))
+            .or_default();
+        window
+            .hits
+            .retain(|hit| now.duration_since(*hit) < window_size);
+        window.hits.push_back(now);
+
+        let count = window.hits.len();
+        if count < rule.threshold.max(1) as usize {
+            return None;
+        }
+        if window
+            .last_fired
+            .is_some_and(|fired| now.duration_since(fired) < cooldown)
+        {
+            return None;
+        }
+
+        // 触发后清空计数，冷却结束后需重新累计到阈值才会再次告警
+        window.last_fired = Some(now);
+        window.hits.clear();
+        Some(count)
+    }
+
+    fn forget_rule(&mut self, rule_id: &str) {
+        self.windows.retain(|(id, _), _| id != rule_id);
+    }
+}
+
+/// 记录一次信号，返回是否需要触发告警及窗口内的信号数
+pub fn observe(rule: &SysAlertRuleModel, subject: &str) -> Option<usize> {
+    TRACKER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .observe(rule, subject, Instant::now())
+}
+
+/// 规则修改或删除后清除计数和冷却状态
+pub fn forget_rule(rule_id: &str) {
+    TRACKER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .forget_rule(rule_id);
+}
+
+fn alert_payload(rule: &SysAlertRuleModel, signal: &SecuritySignal, count: usize) -> Value {
+    json!({
+        "rule": rule.name,
+        "ruleId": rule.id,
+        "signalKind": rule.signal_kind,
+        "subject": signal.subject,
+        "actor": signal.actor,
+        "domain": signal.domain,
+        "count": count,
+        "windowSeconds": rule.window_seconds,
+        "detail": signal.detail,
+        "triggeredAt": Local::now().naive_local(),
+    })
+}
+
+fn alert_subject(rule: &SysAlertRuleModel, signal: &SecuritySignal, count: usize) -> String {
+    format!(
+        "[安全告警] {}: {} 在 {} 秒内出现 {} 次",
+        rule.name, signal.subject, rule.window_seconds, count
+    )
+}
+
+/// 按规则配置的渠道发送告警
+pub async fn deliver(
+    rule: &SysAlertRuleModel,
+    signal: &SecuritySignal,
+    count: usize,
+) -> Result<(), AppError> {
+    let config = global::get_config::<AlertConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    let payload = alert_payload(rule, signal, count);
+
+    tracing::warn!(
+        target: "metrics",
+        event = "security_alert_triggered",
+        rule = %rule.name,
+        subject = %signal.subject,
+        count = count,
+    );
+    if rule.channel == AlertChannel::Log {
+        return Ok(());
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(config.timeout))
+        .build()
+        .map_err(delivery_error)?;
+
+    match rule.channel {
+        AlertChannel::Log => Ok(()),
+        AlertChannel::Webhook => {
+            let target = rule.target.as_deref().unwrap_or_default();
+            post_json(client.post(target), &payload).await
+        },
+        AlertChannel::Email => {
+            let to = rule
+                .target
+                .as_deref()
+                .unwrap_or_default()
+                .split(',')
+                .map(str::trim)
+                .filter(|to| !to.is_empty())
+                .map(ToString::to_string)
+                .collect::<Vec<_>>();
+            send_mail(
+                &to,
+                &alert_subject(rule, signal, count),
+                &serde_json::to_string_pretty(&payload).unwrap_or_default(),
+            )
+            .await
+        },
+    }
+}
+
+/// 通过配置的邮件网关发送邮件，未配置网关时只写入日志
+pub async fn send_mail(to: &[String], subject: &str, content: &str) -> Result<(), AppError> {
+    let config = global::get_config::<AlertConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    let Some(gateway) = config.mail_gateway.as_deref() else {
+        tracing::warn!(subject, "Mail gateway is not configured, mail only logged");
+        return Ok(());
+    };
+
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_secs(config.timeout))
+        .build()
+        .map_err(delivery_error)?;
+    let mut request = client.post(gateway);
+    if let Some(authorization) = &config.mail_authorization {
+        request = request.header(reqwest::header::AUTHORIZATION, authorization);
+    }
+    let mail = json!({
+        "to": to,
+        "subject": subject,
+        "content": content,
+    });
+    post_json(request, &mail).await
+}
+
+fn delivery_error(e: reqwest::Error) -> AppError {
+    AppError::dependency(format!("Failed to send alert: {}", e))
+}
+
+async fn post_json(request: reqwest::RequestBuilder, body: &Value) -> Result<(), AppError> {
+    request
+        .json(body)
+        .send()
+        .await
+        .and_then(reqwest::Response::error_for_status)
+        .map(|_| ())
+        .map_err(delivery_error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(threshold: i32, window_seconds: i32, cooldown_seconds: i32) -> SysAlertRuleModel {
+        SysAlertRuleModel {
+            id: "rule".to_string(),
+            name: "login".to_string(),
+            signal_kind: AlertSignalKind::LoginFailed,
+            threshold,
+            window_seconds,
+            cooldown_seconds,
+            channel: AlertChannel::Log,
+            target: None,
+            enabled: true,
+            last_triggered_at: None,
+            created_at: Local::now().naive_local(),
+            created_by: "test".to_string(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn test_observe_threshold_window_and_cooldown() {
+        let mut tracker = AlertTracker::default();
+        let rule = rule(3, 60, 300);
+        let start = Instant::now();
+
+        assert_eq!(tracker.observe(&rule, "admin", start), None);
+        assert_eq!(tracker.observe(&rule, "other", start), None);
+        assert_eq!(tracker.observe(&rule, "admin", start), None);
+        assert_eq!(tracker.observe(&rule, "admin", start), Some(3));
+
+        // 冷却期内再次达到阈值也不告警
+        for _ in 0..3 {
+            assert_eq!(
+                tracker.observe(&rule, "admin", start + Duration::from_secs(10)),
+                None
+            );
+        }
+
+        // 超出窗口的信号不计入
+        let later = start + Duration::from_secs(400);
+        assert_eq!(tracker.observe(&rule, "admin", later), None);
+        assert_eq!(
+            tracker.observe(&rule, "admin", later + Duration::from_secs(61)),
+            None
+        );
+        assert_eq!(
+            tracker.observe(&rule, "admin", later + Duration::from_secs(62)),
+            None
+        );
+        assert_eq!(
+            tracker.observe(&rule, "admin", later + Duration::from_secs(63)),
+            Some(3)
+        );
+
+        tracker.forget_rule("rule");
+        assert!(tracker.windows.is_empty());
+    }
+}
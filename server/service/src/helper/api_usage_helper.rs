@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use chrono::{Duration, Local, NaiveDateTime, Timelike};
+use server_core::web::{error::AppError, usage::ApiUsageEvent};
+
+use crate::helper::redis_helper::{self, RedisSource};
+
+const KEY_PREFIX: &str = "soybean:api_usage";
+
+/// Redis 中小时计数的保留时间（秒），超过后仍未汇总的计数丢弃
+const BUCKET_TTL_SECS: u64 = 2 * 24 * 3600;
+
+/// 汇总时回看的小时数，与保留时间一致
+const ROLLUP_LOOKBACK_HOURS: i64 = 48;
+
+/// 小时结束后等待的时间，避免遗漏仍在写入的计数
+const ROLLUP_GRACE: Duration = Duration::minutes(1);
+
+/// 小时内单个调用方的计数
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UsageCount {
+    pub label: String,
+    pub requests: i64,
+    pub errors: i64,
+}
+
+/// 调用方类型和标识
+pub type UsageKey = (String, String);
+
+/// 所在小时的开始时间
+pub fn bucket_start(time: NaiveDateTime) -> NaiveDateTime {
+    time.date().and_hms_opt(time.hour(), 0, 0).unwrap_or(time)
+}
+
+fn bucket_key(bucket: NaiveDateTime) -> String {
+    format!("{}:{}", KEY_PREFIX, bucket.format("%Y%m%d%H"))
+}
+
+/// 解析小时计数哈希，字段为 `r|{kind}|{subject}`（请求数）、`e|…`（错误数）和 `l|…`（名称）
+fn parse_bucket(fields: HashMap<String, String>) -> HashMap<UsageKey, UsageCount> {
+    let mut counts: HashMap<UsageKey, UsageCount> = HashMap::new();
+    for (field, value) in fields {
+        let mut parts = field.splitn(3, '|');
+        let (Some(metric), Some(kind), Some(subject)) = (parts.next(), parts.next(), parts.next())
+        else {
+            continue;
+        };
+        let count = counts
+            .entry((kind.to_string(), subject.to_string()))
+            .or_default();
+        match metric {
+            "r" => count.requests = value.parse().unwrap_or_default(),
+            "e" => count.errors = value.parse().unwrap_or_default(),
+            "l" => count.label = value,
+            _ => {},
+        }
+    }
+    counts.retain(|_, count| count.requests > 0);
+    counts
+}
+
+/// 累加一次调用到当前小时的计数
+pub async fn record(event: &ApiUsageEvent) -> Result<(), AppError> {
+    let key = bucket_key(bucket_start(Local::now().naive_local()));
+    let field = format!("{}|{}", event.kind.as_str(), event.subject);
+
+    let mut pipe = redis::pipe();
+    pipe.cmd("HINCRBY")
+        .arg(&key)
+        .arg(format!("r|{}", field))
+        .arg(1)
+        .ignore();
+    if event.error {
+        pipe.cmd("HINCRBY")
+            .arg(&key)
+            .arg(format!("e|{}", field))
+            .arg(1)
+            .ignore();
+    }
+    pipe.cmd("HSET")
+        .arg(&key)
+        .arg(format!("l|{}", field))
+        .arg(&event.label)
+        .ignore()
+        .cmd("EXPIRE")
+        .arg(&key)
+        .arg(BUCKET_TTL_SECS)
+        .ignore();
+    redis_helper::query_pipeline::<()>(RedisSource::Primary, &pipe).await
+}
+
+/// 当前小时的实时计数，供配额校验等需要实时数据的场景使用
+pub async fn current_usage() -> Result<HashMap<UsageKey, UsageCount>, AppError> {
+    let key = bucket_key(bucket_start(Local::now().naive_local()));
+    let fields: HashMap<String, String> =
+        redis_helper::query(RedisSource::Primary, redis::cmd("HGETALL").arg(&key)).await?;
+    Ok(parse_bucket(fields))
+}
+
+/// 等待汇总的已结束小时
+pub fn completed_buckets(now: NaiveDateTime) -> Vec<NaiveDateTime> {
+    let current = bucket_start(now);
+    (1..=ROLLUP_LOOKBACK_HOURS)
+        .map(|hours| current - Duration::hours(hours))
+        .filter(|bucket| *bucket + Duration::hours(1) + ROLLUP_GRACE <= now)
+        .collect()
+}
+
+/// 取出一个小时的计数并从 Redis 删除
+pub async fn take_bucket(bucket: NaiveDateTime) -> Result<HashMap<UsageKey, UsageCount>, AppError> {
+    let key = bucket_key(bucket);
+    let (fields, _): (HashMap<String, String>, i64) = redis_helper::query_pipeline(
+        RedisSource::Primary,
+        redis::pipe()
+            .atomic()
+            .cmd("HGETALL")
+            .arg(&key)
+            .cmd("DEL")
+            .arg(&key),
+    )
+    .await?;
+    Ok(parse_bucket(fields))
+}
+
+/// 写库失败时把取出的计数加回 Redis，下次汇总时重试
+pub async fn restore_bucket(
+    bucket: NaiveDateTime,
+    counts: &HashMap<UsageKey, UsageCount>,
+) -> Result<(), AppError> {
+    let key = bucket_key(bucket);
+    let mut pipe = redis::pipe();
+    for ((kind, subject), count) in counts {
+        let field = format!("{}|{}", kind, subject);
+        pipe.cmd("HINCRBY")
+            .arg(&key)
+            .arg(format!("r|{}", field))
+            .arg(count.requests)
+            .ignore()
+            .cmd("HINCRBY")
+            .arg(&key)
+            .arg(format!("e|{}", field))
+            .arg(count.errors)
+            .ignore()
+            .cmd("HSET")
+            .arg(&key)
+            .arg(format!("l|{}", field))
+            .arg(&count.label)
+            .ignore();
+    }
+    pipe.cmd("EXPIRE").arg(&key).arg(BUCKET_TTL_SECS).ignore();
+    redis_helper::query_pipeline::<()>(RedisSource::Primary, &pipe).await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_parse_bucket() {
+        let fields = HashMap::from([
+            ("r|user|01J".to_string(), "10".to_string()),
+            ("e|user|01J".to_string(), "2".to_string()),
+            ("l|user|01J".to_string(), "admin".to_string()),
+            ("r|api_key|ak|with|pipes".to_string(), "3".to_string()),
+            ("l|api_key|orphan".to_string(), "no requests".to_string()),
+            ("broken".to_string(), "1".to_string()),
+        ]);
+        let counts = parse_bucket(fields);
+
+        assert_eq!(counts.len(), 2);
+        assert_eq!(
+            counts[&("user".to_string(), "01J".to_string())],
+            UsageCount {
+                label: "admin".to_string(),
+                requests: 10,
+                errors: 2,
+            }
+        );
+        assert_eq!(
+            counts[&("api_key".to_string(), "ak|with|pipes".to_string())].requests,
+            3
+        );
+    }
+
+    #[test]
+    fn test_completed_buckets() {
+        let at = |hour, minute| {
+            NaiveDate::from_ymd_opt(2026, 10, 15)
+                .unwrap()
+                .and_hms_opt(hour, minute, 30)
+                .unwrap()
+        };
+
+        assert_eq!(
+            bucket_start(at(9, 41)).time(),
+            chrono::NaiveTime::from_hms_opt(9, 0, 0).unwrap()
+        );
+
+        // 上一小时刚结束，还在等待期内
+        let buckets = completed_buckets(at(10, 0));
+        assert_eq!(buckets.len(), ROLLUP_LOOKBACK_HOURS as usize - 1);
+        assert_eq!(buckets[0], bucket_start(at(8, 0)));
+
+        let buckets = completed_buckets(at(10, 5));
+        assert_eq!(buckets[0], bucket_start(at(9, 0)));
+    }
+}
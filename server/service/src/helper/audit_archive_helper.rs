@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use aws_sdk_s3::{
+    primitives::{ByteStream, DateTime as S3DateTime},
+    types::{ChecksumAlgorithm, ObjectLockMode as S3ObjectLockMode},
+    Client as S3Client,
+};
+use chrono::{Datelike, Local, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use server_config::{AuditArchiveConfig, ObjectLockMode};
+use server_core::web::error::AppError;
+
+use crate::helper::s3_helper::{self, S3Source};
+
+/// 审计日志归档对象的存储
+pub struct ArchiveStore {
+    client: Arc<S3Client>,
+    config: AuditArchiveConfig,
+}
+
+impl ArchiveStore {
+    pub async fn resolve(config: AuditArchiveConfig) -> Result<Self, AppError> {
+        let source = match &config.s3_instance {
+            Some(name) => S3Source::Named(name.clone()),
+            None => S3Source::Primary,
+        };
+        let client = s3_helper::get_client(source).await?;
+        Ok(Self { client, config })
+    }
+
+    pub fn bucket(&self) -> &str {
+        &self.config.bucket
+    }
+
+    /// 分区归档对象的键：`<prefix>/<domain>/<YYYY-MM>/<id>.jsonl`
+    pub fn object_key(&self, domain: &str, period_start: NaiveDateTime, id: &str) -> String {
+        format!(
+            "{}/{}/{}/{}.jsonl",
+            self.config.prefix.trim_end_matches('/'),
+            partition_key(domain),
+            period_start.format("%Y-%m"),
+            id
+        )
+    }
+
+    /// 写入归档对象并设置 Object Lock 保留期，返回保留截止时间
+    ///
+    /// 存储桶未开启 Object Lock 时写入失败
+    pub async fn put(&self, key: &str, data: Vec<u8>) -> Result<NaiveDateTime, AppError> {
+        let mode = match self.config.object_lock_mode {
+            ObjectLockMode::Compliance => S3ObjectLockMode::Compliance,
+            ObjectLockMode::Governance => S3ObjectLockMode::Governance,
+        };
+        let retain_until = Utc::now() + chrono::Duration::days(self.config.retention_days as i64);
+
+        self.client
+            .put_object()
+            .bucket(&self.config.bucket)
+            .key(key)
+            .content_type("application/x-ndjson")
+            .object_lock_mode(mode)
+            .object_lock_retain_until_date(S3DateTime::from_secs(retain_until.timestamp()))
+            // Object Lock 要求请求携带内容校验和
+            .checksum_algorithm(ChecksumAlgorithm::Sha256)
+            .body(ByteStream::from(data))
+            .send()
+            .await
+            .map_err(s3_helper::s3_error)?;
+        Ok(retain_until.with_timezone(&Local).naive_local())
+    }
+
+    pub async fn get(&self, bucket: &str, key: &str) -> Result<Vec<u8>, AppError> {
+        let output = self
+            .client
+            .get_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(s3_helper::s3_error)?;
+        let data = output
+            .body
+            .collect()
+            .await
+            .map_err(|e| AppError::dependency(e.to_string()))?;
+        Ok(data.into_bytes().to_vec())
+    }
+}
+
+/// 时间所在自然月的起止时间
+pub fn month_bounds(time: NaiveDateTime) -> (NaiveDateTime, NaiveDateTime) {
+    let start = NaiveDate::from_ymd_opt(time.year(), time.month(), 1)
+        .unwrap_or(time.date())
+        .and_time(NaiveTime::MIN);
+    let end = start
+        .checked_add_months(Months::new(1))
+        .unwrap_or(NaiveDateTime::MAX);
+    (start, end)
+}
+
+/// 保留在数据库中的最早月份的月初，在此之前结束的分区已关闭
+pub fn hot_cutoff(now: NaiveDateTime, hot_months: u32) -> NaiveDateTime {
+    let (month_start, _) = month_bounds(now);
+    month_start
+        .checked_sub_months(Months::new(hot_months.saturating_sub(1)))
+        .unwrap_or(NaiveDateTime::MIN)
+}
+
+/// 对象键中的域，系统事件的域为空
+fn partition_key(domain: &str) -> String {
+    if domain.is_empty() {
+        return "_system".to_string();
+    }
+    domain
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn time(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S").unwrap()
+    }
+
+    #[test]
+    fn test_partition_periods() {
+        assert_eq!(
+            month_bounds(time("2026-12-15 08:30:00")),
+            (time("2026-12-01 00:00:00"), time("2027-01-01 00:00:00"))
+        );
+        assert_eq!(
+            hot_cutoff(time("2026-10-15 08:30:00"), 6),
+            time("2026-05-01 00:00:00")
+        );
+        assert_eq!(
+            hot_cutoff(time("2026-10-15 08:30:00"), 0),
+            time("2026-10-01 00:00:00")
+        );
+        assert_eq!(partition_key(""), "_system");
+        assert_eq!(partition_key("built-in/a b"), "built-in_a_b");
+    }
+}
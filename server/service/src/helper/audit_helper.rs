@@ -0,0 +1,80 @@
+use chrono::Local;
+use serde_json::Value;
+use server_constant::definition::consts::SystemEvent;
+use server_core::web::auth::User;
+use server_global::global::{self, OperationLogContext};
+
+/// 业务审计记录
+///
+/// 用于记录不经过 HTTP 操作日志中间件、但需要留痕的管理动作，
+/// 最终与操作日志一起写入 `sys_operation_log`
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    user_id: Option<String>,
+    username: Option<String>,
+    domain: Option<String>,
+    request_id: String,
+    module_name: String,
+    description: String,
+    detail: Option<Value>,
+}
+
+impl AuditEntry {
+    pub fn new(module_name: &str, description: impl Into<String>) -> Self {
+        Self {
+            user_id: None,
+            username: None,
+            domain: None,
+            request_id: "system".to_string(),
+            module_name: module_name.to_string(),
+            description: description.into(),
+            detail: None,
+        }
+    }
+
+    pub fn with_user(mut self, user: &User) -> Self {
+        self.user_id = Some(user.user_id());
+        self.username = Some(user.username());
+        self.domain = Some(user.domain());
+        self
+    }
+
+    pub fn with_request_id(mut self, request_id: impl Into<String>) -> Self {
+        self.request_id = request_id.into();
+        self
+    }
+
+    pub fn with_detail(mut self, detail: Value) -> Self {
+        self.detail = Some(detail);
+        self
+    }
+}
+
+/// 发送审计事件，由操作日志监听器异步落库
+pub fn record_audit(entry: AuditEntry) {
+    let now = Local::now().naive_local();
+    let context = OperationLogContext {
+        user_id: entry.user_id,
+        username: entry.username,
+        domain: entry.domain,
+        module_name: entry.module_name.clone(),
+        description: entry.description,
+        request_id: entry.request_id,
+        method: "AUDIT".to_string(),
+        url: entry.module_name,
+        ip: String::new(),
+        user_agent: None,
+        params: None,
+        body: entry.detail,
+        response: None,
+        start_time: now,
+        end_time: now,
+        duration: 0,
+        created_at: now,
+    };
+
+    global::send_dyn_event(
+        SystemEvent::AuditOperationLoggedEvent.as_ref(),
+        Box::new(context),
+    );
+}
@@ -0,0 +1,136 @@
+use std::future::Future;
+
+use server_config::CacheConfig;
+use server_core::web::{error::AppError, rls};
+use server_global::global;
+
+use crate::{
+    helper::redis_helper::{self, RedisSource},
+    project_error,
+};
+
+/// 负缓存命名空间，与按 ID 查询的实体对应
+pub mod namespace {
+    pub const DOMAIN: &str = "domain";
+    pub const FILE: &str = "file";
+    pub const MENU: &str = "menu";
+    pub const ROLE: &str = "role";
+    pub const USER: &str = "user";
+}
+
+async fn cache_config() -> CacheConfig {
+    global::get_config::<CacheConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default()
+}
+
+fn redis_source(config: &CacheConfig) -> RedisSource {
+    config
+        .redis_instance
+        .clone()
+        .map_or(RedisSource::Primary, RedisSource::Named)
+}
+
+/// 启用行级安全时同一 ID 在不同租户下的可见性不同，键中带上当前租户
+fn not_found_key(config: &CacheConfig, tenant: Option<&str>, namespace: &str, id: &str) -> String {
+    match tenant {
+        Some(tenant) => format!("{}:nf:{}:{}:{}", config.key_prefix, tenant, namespace, id),
+        None => format!("{}:nf:{}:{}", config.key_prefix, namespace, id),
+    }
+}
+
+fn current_tenant() -> Option<String> {
+    rls::current_context().map(|context| context.tenant_id)
+}
+
+/// 按 ID 查询，记录不存在时缓存“未找到”结果
+///
+/// 有效期内的重复查询直接返回 `None` 而不调用 `load`。
+/// 缓存读写失败只记录日志，不影响查询本身
+pub async fn find_by_id<T, F, Fut>(
+    namespace: &str,
+    id: &str,
+    load: F,
+) -> Result<Option<T>, AppError>
+where
+    F: FnOnce() -> Fut,
+    Fut: Future<Output = Result<Option<T>, AppError>>,
+{
+    let config = cache_config().await;
+    let ttl = config.negative_ttl_for(namespace);
+    let source = redis_source(&config);
+    if ttl == 0 || !redis_helper::is_available(source.clone()).await {
+        return load().await;
+    }
+
+    let key = not_found_key(&config, current_tenant().as_deref(), namespace, id);
+    match redis_helper::query::<bool>(source.clone(), redis::cmd("EXISTS").arg(&key)).await {
+        Ok(true) => {
+            tracing::info!(target: "metrics", event = "negative_cache_hit", namespace);
+            return Ok(None);
+        },
+        Ok(false) => {},
+        Err(e) => project_error!("Failed to read negative cache '{}': {}", key, e),
+    }
+
+    let result = load().await?;
+    if result.is_none() {
+        let set = redis::cmd("SET")
+            .arg(&key)
+            .arg(1)
+            .arg("EX")
+            .arg(ttl)
+            .clone();
+        if let Err(e) = redis_helper::query::<()>(source, &set).await {
+            project_error!("Failed to write negative cache '{}': {}", key, e);
+        }
+    }
+    Ok(result)
+}
+
+/// 清除 ID 的“未找到”标记，在新建记录后调用，避免新记录在标记有效期内不可见
+pub async fn forget_not_found(namespace: &str, id: &str) {
+    let config = cache_config().await;
+    let source = redis_source(&config);
+    if config.negative_ttl_for(namespace) == 0 || !redis_helper::is_available(source.clone()).await
+    {
+        return;
+    }
+
+    let key = not_found_key(&config, current_tenant().as_deref(), namespace, id);
+    if let Err(e) = redis_helper::query::<()>(source, redis::cmd("DEL").arg(&key)).await {
+        project_error!("Failed to clear negative cache '{}': {}", key, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_negative_ttl_override() {
+        let mut config = CacheConfig::default();
+        config
+            .negative_namespaces
+            .insert(namespace::FILE.to_string(), 0);
+
+        assert_eq!(config.negative_ttl_for(namespace::USER), 30);
+        assert_eq!(config.negative_ttl_for(namespace::FILE), 0);
+        assert_eq!(
+            not_found_key(&config, None, namespace::USER, "42"),
+            "soybean:cache:nf:user:42"
+        );
+    }
+
+    #[test]
+    fn test_not_found_key_scoped_by_tenant() {
+        let config = CacheConfig::default();
+
+        let tenant_a = not_found_key(&config, Some("tenant-a"), namespace::USER, "42");
+        let tenant_b = not_found_key(&config, Some("tenant-b"), namespace::USER, "42");
+
+        assert_eq!(tenant_a, "soybean:cache:nf:tenant-a:user:42");
+        assert_ne!(tenant_a, tenant_b);
+    }
+}
@@ -0,0 +1,195 @@
+use std::collections::{HashMap, HashSet};
+
+use sea_orm::{ColumnTrait, Condition, EntityTrait, QueryFilter, QuerySelect};
+use server_core::web::{auth::User, error::AppError};
+use server_model::admin::entities::{
+    prelude::{SysOrganization, SysRole, SysUser},
+    sea_orm_active_enums::{DataScope, Status},
+    sys_organization::Column as SysOrganizationColumn,
+    sys_role::Column as SysRoleColumn,
+    sys_user::Column as SysUserColumn,
+};
+
+use crate::helper::db_helper;
+
+/// 当前用户可管理的数据范围
+///
+/// 接口权限由 Casbin 控制，数据范围决定在有权限的接口中能看到和操作哪些记录。
+/// 用户拥有多个角色时取并集，任一角色不限制则不限制。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ResolvedScope {
+    All,
+    /// 可管理的组织，以及用户本人
+    Limited {
+        organizations: HashSet<String>,
+        user_id: String,
+    },
+}
+
+impl ResolvedScope {
+    /// 是否可以管理指定用户，`organization_id` 为该用户所属组织
+    pub fn allows(&self, user_id: &str, organization_id: Option<&str>) -> bool {
+        match self {
+            ResolvedScope::All => true,
+            ResolvedScope::Limited {
+                organizations,
+                user_id: own_id,
+            } => {
+                own_id == user_id || organization_id.is_some_and(|org| organizations.contains(org))
+            },
+        }
+    }
+
+    /// 是否可以把用户放入指定组织
+    pub fn allows_organization(&self, organization_id: Option<&str>) -> bool {
+        match self {
+            ResolvedScope::All => true,
+            ResolvedScope::Limited { organizations, .. } => {
+                organization_id.is_some_and(|org| organizations.contains(org))
+            },
+        }
+    }
+
+    /// 用户查询的过滤条件，不限制时返回 `None`
+    pub fn user_condition(&self) -> Option<Condition> {
+        match self {
+            ResolvedScope::All => None,
+            ResolvedScope::Limited {
+                organizations,
+                user_id,
+            } => Some(
+                Condition::any()
+                    .add(SysUserColumn::OrganizationId.is_in(organizations.iter().cloned()))
+                    .add(SysUserColumn::Id.eq(user_id.as_str())),
+            ),
+        }
+    }
+}
+
+/// 计算数据范围覆盖的组织
+///
+/// `scopes` 为各角色的范围和根组织，根组织未设置时使用用户所属组织；
+/// `organizations` 为全部组织的（ID，上级 ID）。
+fn expand(
+    scopes: &[(DataScope, Option<String>)],
+    user_organization: Option<&str>,
+    organizations: &[(String, String)],
+) -> HashSet<String> {
+    let mut children: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (id, pid) in organizations {
+        children.entry(pid.as_str()).or_default().push(id.as_str());
+    }
+
+    let mut result = HashSet::new();
+    for (scope, root) in scopes {
+        let Some(root) = root.as_deref().or(user_organization) else {
+            continue;
+        };
+        match scope {
+            DataScope::Org => {
+                result.insert(root.to_string());
+            },
+            DataScope::OrgSubtree => {
+                let mut pending = vec![root];
+                while let Some(id) = pending.pop() {
+                    // 组织数据存在环时避免死循环
+                    if result.insert(id.to_string()) {
+                        pending.extend(children.get(id).into_iter().flatten());
+                    }
+                }
+            },
+            DataScope::All | DataScope::SelfOnly => {},
+        }
+    }
+    result
+}
+
+/// 解析当前用户的数据范围
+pub async fn resolve(user: &User) -> Result<ResolvedScope, AppError> {
+    resolve_for(&user.user_id(), &user.subject()).await
+}
+
+/// 按用户 ID 和角色编码解析数据范围
+pub async fn resolve_for(user_id: &str, role_codes: &[String]) -> Result<ResolvedScope, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    let scopes = SysRole::find()
+        .select_only()
+        .column(SysRoleColumn::DataScope)
+        .column(SysRoleColumn::ScopeOrganizationId)
+        .filter(SysRoleColumn::Code.is_in(role_codes.to_vec()))
+        .filter(SysRoleColumn::Status.eq(Status::Enabled))
+        .into_tuple::<(DataScope, Option<String>)>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)?;
+
+    if scopes.iter().any(|(scope, _)| *scope == DataScope::All) {
+        return Ok(ResolvedScope::All);
+    }
+
+    let user_organization = SysUser::find_by_id(user_id)
+        .select_only()
+        .column(SysUserColumn::OrganizationId)
+        .into_tuple::<Option<String>>()
+        .one(&db)
+        .await
+        .map_err(AppError::from)?
+        .flatten();
+    let organizations = SysOrganization::find()
+        .select_only()
+        .column(SysOrganizationColumn::Id)
+        .column(SysOrganizationColumn::Pid)
+        .into_tuple::<(String, String)>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(ResolvedScope::Limited {
+        organizations: expand(&scopes, user_organization.as_deref(), &organizations),
+        user_id: user_id.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_expand_scopes() {
+        let organizations = [
+            ("hq".to_string(), "0".to_string()),
+            ("rd".to_string(), "hq".to_string()),
+            ("rd-web".to_string(), "rd".to_string()),
+            ("sales".to_string(), "hq".to_string()),
+        ]
+        .to_vec();
+
+        let subtree = expand(&[(DataScope::OrgSubtree, None)], Some("rd"), &organizations);
+        assert_eq!(
+            subtree,
+            HashSet::from(["rd".to_string(), "rd-web".to_string()])
+        );
+
+        let own_org = expand(&[(DataScope::Org, None)], Some("rd"), &organizations);
+        assert_eq!(own_org, HashSet::from(["rd".to_string()]));
+
+        let fixed_root = expand(
+            &[(DataScope::OrgSubtree, Some("sales".to_string()))],
+            Some("rd"),
+            &organizations,
+        );
+        assert_eq!(fixed_root, HashSet::from(["sales".to_string()]));
+
+        assert!(expand(&[(DataScope::SelfOnly, None)], Some("rd"), &organizations).is_empty());
+        assert!(expand(&[(DataScope::OrgSubtree, None)], None, &organizations).is_empty());
+
+        let scope = ResolvedScope::Limited {
+            organizations: subtree,
+            user_id: "me".to_string(),
+        };
+        assert!(scope.allows("other", Some("rd-web")));
+        assert!(scope.allows("me", None));
+        assert!(!scope.allows("other", Some("sales")));
+        assert!(!scope.allows_organization(None));
+    }
+}
@@ -1,23 +1,504 @@
-use std::sync::Arc;
+use std::{
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
 
-use sea_orm::{ConnAcquireErr, DatabaseConnection, DbErr};
-use server_core::web::error::AppError;
-use server_global::global::{GLOBAL_DB_POOL, GLOBAL_PRIMARY_DB};
+use async_trait::async_trait;
+use ring::rand::{SecureRandom, SystemRandom};
+use sea_orm::{
+    ConnAcquireErr, ConnectOptions, ConnectionTrait, Database, DatabaseConnection,
+    DatabaseTransaction, DbBackend, DbErr, ExecResult, QueryResult, Statement, TransactionTrait,
+};
+use server_config::{DatabaseConfig, DatabasesInstancesConfig, OptionalConfigs, ReplicaStrategy};
+use server_core::web::{
+    error::AppError,
+    recorder::attach_db_recorder,
+    rls::{self, RlsContext, RLS_TENANT_SETTING, RLS_USER_SETTING},
+};
+pub use server_global::global::PRIMARY_DB_NAME;
+use server_global::global::{self, GLOBAL_DB_POOL, GLOBAL_DB_REPLICAS, GLOBAL_PRIMARY_DB};
+
+use crate::{project_error, project_info};
+
+/// 旧连接池等待在途请求释放的最长时间
+const DRAIN_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// 轮询选择只读副本的游标，各数据库共用
+static REPLICA_CURSOR: AtomicUsize = AtomicUsize::new(0);
 
 pub async fn get_db_connection() -> Result<Arc<DatabaseConnection>, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Database).await?;
     let db = GLOBAL_PRIMARY_DB.read().await;
     db.as_ref()
         .cloned()
         .ok_or_else(|| AppError::from(DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)))
 }
 
-/// 获取命名数据库连接
-#[allow(dead_code)]
+/// 获取主库的读连接，用于列表、统计等读多写少的查询
+///
+/// 配置了只读副本时按 `replica_strategy` 选择副本，否则使用主库；执行前拒绝写语句，
+/// 并与 `get_rls_connection` 一样设置行级安全会话变量。
+/// 副本存在复制延迟，写入后需要立即读到结果的场景应使用 `get_db_connection`
+pub async fn get_read_connection() -> Result<ReadOnlyConnection, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Database).await?;
+    match replica_connection(PRIMARY_DB_NAME).await {
+        Some(db) => Ok(ReadOnlyConnection(RlsConnection(db))),
+        None => get_db_connection()
+            .await
+            .map(|db| ReadOnlyConnection(RlsConnection(db))),
+    }
+}
+
+/// 获取带行级安全上下文的主库连接
+///
+/// 请求经过行级安全中间件时，每条语句在单独的事务中执行，事务开始时以
+/// `set_config(..., true)` 设置当前用户和租户，事务结束即失效，不会残留到连接池中的其他请求；
+/// 没有上下文或非 PostgreSQL 时直接执行
+pub async fn get_rls_connection() -> Result<RlsConnection, AppError> {
+    get_db_connection().await.map(RlsConnection)
+}
+
+/// 获取命名数据库连接，只读实例需通过 `get_read_only_connection` 获取
 pub async fn get_named_connection(name: &str) -> Result<Arc<DatabaseConnection>, AppError> {
+    if is_read_only_instance(name).await {
+        return Err(AppError::dependency(format!(
+            "Database '{}' is read-only, use get_read_only_connection",
+            name
+        )));
+    }
+    pool_connection(name).await
+}
+
+/// 获取只读连接，执行前拒绝写语句
+///
+/// 可用于任意命名连接池；配置为 `read_only` 的实例只能通过此方法获取，
+/// PostgreSQL 实例的会话同时以只读事务执行，两道限制任一生效即可阻止写入。
+/// 实例配置了只读副本时优先使用副本
+pub async fn get_read_only_connection(name: &str) -> Result<ReadOnlyConnection, AppError> {
+    if let Some(db) = replica_connection(name).await {
+        return Ok(ReadOnlyConnection(RlsConnection(db)));
+    }
+    pool_connection(name)
+        .await
+        .map(|db| ReadOnlyConnection(RlsConnection(db)))
+}
+
+/// 按数据库配置的负载均衡策略选择一个只读副本，没有可用副本时返回 `None`
+async fn replica_connection(name: &str) -> Option<Arc<DatabaseConnection>> {
+    let replicas = GLOBAL_DB_REPLICAS.read().await.get(name).cloned()?;
+    let index = match replica_strategy(name).await {
+        ReplicaStrategy::RoundRobin => REPLICA_CURSOR.fetch_add(1, Ordering::Relaxed),
+        ReplicaStrategy::Random => random_index(),
+    };
+    replicas.get(index % replicas.len()).cloned()
+}
+
+async fn replica_strategy(name: &str) -> ReplicaStrategy {
+    if name == PRIMARY_DB_NAME {
+        return global::get_config::<DatabaseConfig>()
+            .await
+            .map(|config| config.replica_strategy)
+            .unwrap_or_default();
+    }
+    global::get_config::<OptionalConfigs<DatabasesInstancesConfig>>()
+        .await
+        .and_then(|instances| {
+            instances
+                .configs
+                .iter()
+                .flatten()
+                .find(|instance| instance.name == name)
+                .map(|instance| instance.database.replica_strategy)
+        })
+        .unwrap_or_default()
+}
+
+fn random_index() -> usize {
+    let mut bytes = [0u8; 8];
+    match SystemRandom::new().fill(&mut bytes) {
+        Ok(()) => u64::from_le_bytes(bytes) as usize,
+        Err(_) => REPLICA_CURSOR.fetch_add(1, Ordering::Relaxed),
+    }
+}
+
+async fn is_read_only_instance(name: &str) -> bool {
+    global::get_config::<OptionalConfigs<DatabasesInstancesConfig>>()
+        .await
+        .is_some_and(|instances| {
+            instances
+                .configs
+                .iter()
+                .flatten()
+                .any(|instance| instance.name == name && instance.database.read_only)
+        })
+}
+
+async fn pool_connection(name: &str) -> Result<Arc<DatabaseConnection>, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Database).await?;
     let pools = GLOBAL_DB_POOL.read().await;
-    let db = pools.get(name).ok_or_else(|| AppError {
-        code: 500,
-        message: format!("Database pool '{}' not found", name),
-    })?;
+    let db = pools
+        .get(name)
+        .ok_or_else(|| AppError::dependency(format!("Database pool '{}' not found", name)))?;
     Ok(db.clone())
 }
+
+/// 只读数据库连接，执行前检查语句，非只读语句返回 `DbErr::Custom`
+///
+/// 与 `RlsConnection` 一样带上行级安全上下文，读副本的查询同样受 RLS 策略约束
+#[derive(Clone)]
+pub struct ReadOnlyConnection(RlsConnection);
+
+impl ReadOnlyConnection {
+    fn check(sql: &str) -> Result<(), DbErr> {
+        if is_read_only_statement(sql) {
+            Ok(())
+        } else {
+            Err(DbErr::Custom(format!(
+                "Write statement rejected on read-only connection: {}",
+                sql
+            )))
+        }
+    }
+}
+
+#[async_trait]
+impl ConnectionTrait for ReadOnlyConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        Self::check(&stmt.sql)?;
+        self.0.execute(stmt).await
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        Self::check(sql)?;
+        self.0.execute_unprepared(sql).await
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        Self::check(&stmt.sql)?;
+        self.0.query_one(stmt).await
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        Self::check(&stmt.sql)?;
+        self.0.query_all(stmt).await
+    }
+
+    fn support_returning(&self) -> bool {
+        self.0.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.0.is_mock_connection()
+    }
+}
+
+/// 执行前设置行级安全会话变量的数据库连接
+#[derive(Clone)]
+pub struct RlsConnection(Arc<DatabaseConnection>);
+
+impl RlsConnection {
+    /// 开启事务并设置会话变量，事务内的语句都受 RLS 策略约束
+    pub async fn begin(&self) -> Result<DatabaseTransaction, DbErr> {
+        let txn = self.0.begin().await?;
+        if let Some(context) = self.context() {
+            apply_rls_context(&txn, &context).await?;
+        }
+        Ok(txn)
+    }
+
+    fn context(&self) -> Option<RlsContext> {
+        rls::current_context().filter(|_| self.0.get_database_backend() == DbBackend::Postgres)
+    }
+
+    /// 有上下文时为单条语句开启设置了会话变量的事务
+    async fn scoped(&self) -> Result<Option<DatabaseTransaction>, DbErr> {
+        let Some(context) = self.context() else {
+            return Ok(None);
+        };
+        let txn = self.0.begin().await?;
+        apply_rls_context(&txn, &context).await?;
+        Ok(Some(txn))
+    }
+}
+
+async fn apply_rls_context(txn: &DatabaseTransaction, context: &RlsContext) -> Result<(), DbErr> {
+    txn.execute(Statement::from_sql_and_values(
+        DbBackend::Postgres,
+        "SELECT set_config($1, $2, true), set_config($3, $4, true)",
+        [
+            RLS_USER_SETTING.into(),
+            context.user_id.clone().into(),
+            RLS_TENANT_SETTING.into(),
+            context.tenant_id.clone().into(),
+        ],
+    ))
+    .await?;
+    Ok(())
+}
+
+#[async_trait]
+impl ConnectionTrait for RlsConnection {
+    fn get_database_backend(&self) -> DbBackend {
+        self.0.get_database_backend()
+    }
+
+    async fn execute(&self, stmt: Statement) -> Result<ExecResult, DbErr> {
+        match self.scoped().await? {
+            Some(txn) => {
+                let result = txn.execute(stmt).await?;
+                txn.commit().await?;
+                Ok(result)
+            },
+            None => self.0.execute(stmt).await,
+        }
+    }
+
+    async fn execute_unprepared(&self, sql: &str) -> Result<ExecResult, DbErr> {
+        match self.scoped().await? {
+            Some(txn) => {
+                let result = txn.execute_unprepared(sql).await?;
+                txn.commit().await?;
+                Ok(result)
+            },
+            None => self.0.execute_unprepared(sql).await,
+        }
+    }
+
+    async fn query_one(&self, stmt: Statement) -> Result<Option<QueryResult>, DbErr> {
+        match self.scoped().await? {
+            Some(txn) => {
+                let result = txn.query_one(stmt).await?;
+                txn.commit().await?;
+                Ok(result)
+            },
+            None => self.0.query_one(stmt).await,
+        }
+    }
+
+    async fn query_all(&self, stmt: Statement) -> Result<Vec<QueryResult>, DbErr> {
+        match self.scoped().await? {
+            Some(txn) => {
+                let result = txn.query_all(stmt).await?;
+                txn.commit().await?;
+                Ok(result)
+            },
+            None => self.0.query_all(stmt).await,
+        }
+    }
+
+    fn support_returning(&self) -> bool {
+        self.0.support_returning()
+    }
+
+    fn is_mock_connection(&self) -> bool {
+        self.0.is_mock_connection()
+    }
+}
+
+/// 只读语句允许的首个关键字
+const READ_ONLY_KEYWORDS: &[&str] = &["SELECT", "WITH", "SHOW", "EXPLAIN", "VALUES", "TABLE"];
+
+/// 出现在语句任意位置即视为写入的关键字，如 `WITH ... DELETE`、`SELECT ... FOR UPDATE`
+const WRITE_KEYWORDS: &[&str] = &[
+    "INSERT", "UPDATE", "DELETE", "MERGE", "TRUNCATE", "DROP", "ALTER", "CREATE", "GRANT",
+    "REVOKE", "COPY", "CALL", "LOCK", "INTO",
+];
+
+/// 是否为只读语句：以查询关键字开头，且字符串和带引号标识符之外不含写入关键字
+///
+/// 只允许单条语句，`;` 之后还有内容时视为非只读
+fn is_read_only_statement(sql: &str) -> bool {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut chars = sql.chars().peekable();
+    let mut terminated = false;
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' {
+            if terminated {
+                return false;
+            }
+            word.push(c.to_ascii_uppercase());
+            continue;
+        }
+        if !word.is_empty() {
+            words.push(std::mem::take(&mut word));
+        }
+        match c {
+            '\'' | '"' | '`' => {
+                if terminated {
+                    return false;
+                }
+                // 字符串字面量或带引号标识符，成对引号为转义
+                while let Some(inner) = chars.next() {
+                    if inner == c {
+                        if chars.peek() == Some(&c) {
+                            chars.next();
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            },
+            ';' => terminated = true,
+            c if terminated && !c.is_whitespace() => return false,
+            _ => {},
+        }
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+        .first()
+        .is_some_and(|first| READ_ONLY_KEYWORDS.contains(&first.as_str()))
+        && !words
+            .iter()
+            .any(|word| WRITE_KEYWORDS.contains(&word.as_str()))
+}
+
+fn build_connect_options(db_config: &DatabaseConfig, url: String) -> ConnectOptions {
+    let mut opt = ConnectOptions::new(url);
+    opt.max_connections(db_config.max_connections)
+        .min_connections(db_config.min_connections)
+        .connect_timeout(Duration::from_secs(db_config.connect_timeout))
+        .idle_timeout(Duration::from_secs(db_config.idle_timeout))
+        .sqlx_logging(false);
+    if let Some(search_path) = db_config.schema_search_path() {
+        opt.set_schema_search_path(search_path);
+    }
+    if let Some(key) = db_config.sqlcipher_key() {
+        opt.sqlcipher_key(key.expose_secret().to_string());
+    }
+    opt
+}
+
+/// 按新配置重建连接池并替换现有连接池
+///
+/// sqlx 连接池创建后无法调整容量，因此先建立新连接池再原子替换，
+/// 旧连接池在在途请求全部释放后关闭（最长等待 `DRAIN_TIMEOUT`）。只读副本与主库共用连接池参数，
+/// 一并重建，任一连接失败时保留现有连接池
+pub async fn rebuild_connection(name: &str, db_config: &DatabaseConfig) -> Result<(), AppError> {
+    let db = Arc::new(connect(name, db_config, db_config.connect_url()).await?);
+    let mut replicas = Vec::new();
+    for (index, url) in db_config.replica_connect_urls().into_iter().enumerate() {
+        let replica_name = format!("{}:replica{}", name, index);
+        replicas.push(Arc::new(connect(&replica_name, db_config, url).await?));
+    }
+
+    let previous = if name == PRIMARY_DB_NAME {
+        GLOBAL_PRIMARY_DB.write().await.replace(db)
+    } else {
+        GLOBAL_DB_POOL.write().await.insert(name.to_string(), db)
+    };
+    let previous_replicas = if replicas.is_empty() {
+        GLOBAL_DB_REPLICAS.write().await.remove(name)
+    } else {
+        GLOBAL_DB_REPLICAS
+            .write()
+            .await
+            .insert(name.to_string(), replicas)
+    };
+
+    if let Some(previous) = previous {
+        drain_connection(name.to_string(), previous);
+    }
+    for (index, replica) in previous_replicas.into_iter().flatten().enumerate() {
+        drain_connection(format!("{}:replica{}", name, index), replica);
+    }
+    Ok(())
+}
+
+async fn connect(
+    name: &str,
+    db_config: &DatabaseConfig,
+    url: String,
+) -> Result<DatabaseConnection, AppError> {
+    let mut db = Database::connect(build_connect_options(db_config, url))
+        .await
+        .map_err(AppError::from)?;
+    attach_db_recorder(
+        &mut db,
+        name,
+        db_config.effective_statement_cache_capacity(),
+    );
+    Ok(db)
+}
+
+/// 移除命名连接池及其只读副本，在途请求释放后关闭
+pub async fn remove_connection(name: &str) {
+    if let Some(previous) = GLOBAL_DB_POOL.write().await.remove(name) {
+        drain_connection(name.to_string(), previous);
+    }
+    let replicas = GLOBAL_DB_REPLICAS.write().await.remove(name);
+    for (index, replica) in replicas.into_iter().flatten().enumerate() {
+        drain_connection(format!("{}:replica{}", name, index), replica);
+    }
+}
+
+fn drain_connection(name: String, mut db: Arc<DatabaseConnection>) {
+    tokio::spawn(async move {
+        let deadline = tokio::time::Instant::now() + DRAIN_TIMEOUT;
+        loop {
+            match Arc::try_unwrap(db) {
+                Ok(conn) => {
+                    if let Err(e) = conn.close().await {
+                        project_error!("Failed to close drained pool '{}': {}", name, e);
+                    } else {
+                        project_info!("Drained previous pool for database '{}'", name);
+                    }
+                    return;
+                },
+                Err(shared) if tokio::time::Instant::now() >= deadline => {
+                    // 仍被持有时交由最后一个引用释放
+                    project_info!(
+                        "Previous pool for database '{}' still in use after drain timeout",
+                        name
+                    );
+                    drop(shared);
+                    return;
+                },
+                Err(shared) => {
+                    db = shared;
+                    tokio::time::sleep(Duration::from_secs(1)).await;
+                },
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_read_only_statement() {
+        assert!(is_read_only_statement(
+            "SELECT \"id\", \"update_time\" FROM \"sys_user\" WHERE \"name\" = 'DROP TABLE x'"
+        ));
+        assert!(is_read_only_statement(
+            "WITH t AS (SELECT 1) SELECT * FROM t;"
+        ));
+        assert!(is_read_only_statement("EXPLAIN SELECT 1"));
+
+        assert!(!is_read_only_statement("INSERT INTO t VALUES (1)"));
+        assert!(!is_read_only_statement(
+            "WITH d AS (DELETE FROM t RETURNING id) SELECT * FROM d"
+        ));
+        assert!(!is_read_only_statement("SELECT * FROM t FOR UPDATE"));
+        assert!(!is_read_only_statement("SELECT * INTO backup FROM t"));
+        assert!(!is_read_only_statement("SELECT 1; DROP TABLE t"));
+        assert!(!is_read_only_statement(
+            "SET default_transaction_read_only = off"
+        ));
+    }
+}
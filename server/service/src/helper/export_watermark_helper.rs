@@ -0,0 +1,119 @@
+use chrono::Local;
+use serde_json::json;
+use server_config::{ComplianceConfig, WatermarkPolicy};
+use server_core::web::auth::User;
+use server_global::global;
+use ulid::Ulid;
+
+use crate::helper::audit_helper::{record_audit, AuditEntry};
+
+/// 返回水印编号的响应头
+pub const WATERMARK_HEADER: &str = "x-export-watermark";
+
+/// 一次导出的水印
+#[derive(Debug, Clone)]
+pub struct ExportWatermark {
+    /// 水印编号，审计日志中按该编号追溯导出请求
+    pub id: String,
+    /// 水印文字
+    pub text: String,
+    embed: bool,
+}
+
+impl ExportWatermark {
+    /// 需要写入文件内容的水印文字，策略关闭内容水印时为 `None`
+    pub fn embedded_text(&self) -> Option<&str> {
+        self.embed.then_some(self.text.as_str())
+    }
+
+    /// 在 CSV 内容前加上水印注释行
+    pub fn prepend_csv(&self, csv: String) -> String {
+        match self.embedded_text() {
+            Some(text) => format!("# {}\n{}", text, csv),
+            None => csv,
+        }
+    }
+}
+
+/// 按导出类型的策略生成水印并写入审计日志，策略未启用时返回 `None`
+pub async fn issue(export_type: &str, operator: &User) -> Option<ExportWatermark> {
+    let config = global::get_config::<ComplianceConfig>().await?;
+    let policy = config.export_watermark.policy(export_type);
+    if !policy.enabled {
+        return None;
+    }
+
+    let watermark = render(policy, Ulid::new().to_string(), operator);
+    record_audit(
+        AuditEntry::new("export", format!("Issued {} export watermark", export_type))
+            .with_user(operator)
+            .with_detail(json!({
+                "exportType": export_type,
+                "watermarkId": watermark.id,
+                "watermark": watermark.text,
+            })),
+    );
+    Some(watermark)
+}
+
+fn render(policy: &WatermarkPolicy, id: String, operator: &User) -> ExportWatermark {
+    let text = policy
+        .template
+        .replace("{export_id}", &id)
+        .replace("{user_id}", &operator.user_id())
+        .replace("{username}", &operator.username())
+        .replace("{domain}", &operator.domain())
+        .replace(
+            "{timestamp}",
+            &Local::now().format("%Y-%m-%d %H:%M:%S").to_string(),
+        );
+    // 水印写在 CSV 注释行中，去掉换行等控制字符避免破坏文件结构
+    let text = text
+        .chars()
+        .map(|c| if c.is_control() { ' ' } else { c })
+        .collect();
+
+    ExportWatermark {
+        id,
+        text,
+        embed: policy.embed,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use server_core::web::auth::Claims;
+
+    use super::*;
+
+    #[test]
+    fn test_render_watermark() {
+        let operator = User::from(Claims::new(
+            "u1".to_string(),
+            "admin".to_string(),
+            "alice\nbob".to_string(),
+            Vec::new(),
+            "built-in".to_string(),
+            None,
+        ));
+        let policy = WatermarkPolicy {
+            template: "{username}|{user_id}@{domain}|{export_id}".to_string(),
+            ..WatermarkPolicy::default()
+        };
+
+        let watermark = render(&policy, "EXP1".to_string(), &operator);
+        assert_eq!(watermark.text, "alice bob|u1@built-in|EXP1");
+        assert_eq!(
+            watermark.prepend_csv("a,b\n".to_string()),
+            "# alice bob|u1@built-in|EXP1\na,b\n"
+        );
+
+        let policy = WatermarkPolicy {
+            embed: false,
+            ..policy
+        };
+        let watermark = render(&policy, "EXP2".to_string(), &operator);
+        assert_eq!(watermark.embedded_text(), None);
+        assert_eq!(watermark.prepend_csv("a,b\n".to_string()), "a,b\n");
+    }
+}
@@ -0,0 +1,121 @@
+use std::time::Duration;
+
+use server_config::{LoginThrottleConfig, SecurityConfig};
+use server_global::global;
+
+use crate::{
+    helper::redis_helper::{self, RedisSource},
+    project_error,
+};
+
+async fn throttle_config() -> LoginThrottleConfig {
+    global::get_config::<SecurityConfig>()
+        .await
+        .map(|config| config.login_throttle.clone())
+        .unwrap_or_default()
+}
+
+fn redis_source(config: &LoginThrottleConfig) -> RedisSource {
+    config
+        .redis_instance
+        .clone()
+        .map_or(RedisSource::Primary, RedisSource::Named)
+}
+
+fn account_key(config: &LoginThrottleConfig, domain: &str, identifier: &str) -> String {
+    format!("{}:account:{}:{}", config.key_prefix, domain, identifier)
+}
+
+fn ip_key(config: &LoginThrottleConfig, ip: &str) -> String {
+    format!("{}:ip:{}", config.key_prefix, ip)
+}
+
+/// 是否对本次登录节流，未启用、白名单 IP 或 Redis 不可用时不节流
+async fn active_config(ip: Option<&str>) -> Option<LoginThrottleConfig> {
+    let config = throttle_config().await;
+    if !config.enabled || ip.is_some_and(|ip| config.is_allowlisted(ip)) {
+        return None;
+    }
+    redis_helper::is_available(redis_source(&config))
+        .await
+        .then_some(config)
+}
+
+/// 登录前调用，按账号和 IP 中较多的失败次数延迟
+///
+/// 计数读取失败只记录日志，不阻止登录
+pub async fn delay_login(domain: &str, identifier: &str, ip: &str) {
+    let Some(config) = active_config(Some(ip)).await else {
+        return;
+    };
+
+    // 两个键在集群模式下可能位于不同槽位，使用管道而非 MGET
+    let mut pipeline = redis::pipe();
+    pipeline
+        .cmd("GET")
+        .arg(account_key(&config, domain, identifier))
+        .cmd("GET")
+        .arg(ip_key(&config, ip));
+    let failures =
+        match redis_helper::query_pipeline::<Vec<Option<u32>>>(redis_source(&config), &pipeline)
+            .await
+        {
+            Ok(counts) => counts.into_iter().flatten().max().unwrap_or_default(),
+            Err(e) => {
+                project_error!("Failed to read login throttle counters: {}", e);
+                return;
+            },
+        };
+
+    let delay_ms = config.delay_ms(failures);
+    if delay_ms > 0 {
+        tracing::info!(
+            target: "metrics",
+            event = "login_throttled",
+            domain,
+            ip,
+            failures,
+            delay_ms,
+        );
+        tokio::time::sleep(Duration::from_millis(delay_ms)).await;
+    }
+}
+
+/// 登录失败后累加账号和 IP 的失败次数，有效期从最近一次失败开始计算
+pub async fn record_login_failure(domain: &str, identifier: &str, ip: &str) {
+    let Some(config) = active_config(Some(ip)).await else {
+        return;
+    };
+
+    let mut pipeline = redis::pipe();
+    for key in [
+        account_key(&config, domain, identifier),
+        ip_key(&config, ip),
+    ] {
+        pipeline
+            .cmd("INCR")
+            .arg(&key)
+            .ignore()
+            .cmd("EXPIRE")
+            .arg(&key)
+            .arg(config.window)
+            .ignore();
+    }
+    if let Err(e) = redis_helper::query_pipeline::<()>(redis_source(&config), &pipeline).await {
+        project_error!("Failed to record login failure: {}", e);
+    }
+}
+
+/// 登录成功后清除账号的失败次数，IP 计数按有效期自然过期
+pub async fn reset_login_failures(domain: &str, identifier: &str) {
+    let Some(config) = active_config(None).await else {
+        return;
+    };
+
+    let key = account_key(&config, domain, identifier);
+    if let Err(e) =
+        redis_helper::query::<()>(redis_source(&config), redis::cmd("DEL").arg(&key)).await
+    {
+        project_error!("Failed to reset login failures '{}': {}", key, e);
+    }
+}
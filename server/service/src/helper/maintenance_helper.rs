@@ -0,0 +1,307 @@
+use std::{
+    sync::{LazyLock, Mutex},
+    time::{Duration as StdDuration, Instant},
+};
+
+use chrono::{Datelike, Duration, Local, NaiveDateTime, Timelike};
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    prelude::SysMaintenanceWindow,
+    sea_orm_active_enums::MaintenanceScheduleKind,
+    sys_maintenance_window::{
+        Column as SysMaintenanceWindowColumn, Model as SysMaintenanceWindowModel,
+    },
+};
+
+use crate::{helper::db_helper, project_error};
+
+/// 启用窗口的缓存时间，告警和后台任务频繁检查，不必每次查库
+const CACHE_TTL: StdDuration = StdDuration::from_secs(30);
+
+/// 缓存的加载时间和内容
+type Cache = Mutex<Option<(Instant, Vec<SysMaintenanceWindowModel>)>>;
+
+static CACHE: LazyLock<Cache> = LazyLock::new(|| Mutex::new(None));
+
+/// 解析后的 cron 表达式（分 时 日 月 周），每个字段以位图表示允许的取值
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CronSchedule {
+    minutes: u64,
+    hours: u64,
+    days: u64,
+    months: u64,
+    weekdays: u64,
+    /// 日和周都有限定时满足其一即可，与标准 cron 一致
+    day_or_weekday: bool,
+}
+
+impl CronSchedule {
+    pub fn parse(expr: &str) -> Option<Self> {
+        let fields = expr.split_whitespace().collect::<Vec<_>>();
+        let [minute, hour, day, month, weekday] = fields.as_slice() else {
+            return None;
+        };
+
+        // 7 和 0 都表示周日
+        let mut weekdays = parse_field(weekday, 0, 7)?;
+        if weekdays & (1 << 7) != 0 {
+            weekdays = (weekdays | 1) & !(1 << 7);
+        }
+
+        Some(Self {
+            minutes: parse_field(minute, 0, 59)?,
+            hours: parse_field(hour, 0, 23)?,
+            days: parse_field(day, 1, 31)?,
+            months: parse_field(month, 1, 12)?,
+            weekdays,
+            day_or_weekday: !day.starts_with('*') && !weekday.starts_with('*'),
+        })
+    }
+
+    pub fn matches(&self, time: NaiveDateTime) -> bool {
+        let has = |mask: u64, value: u32| mask & (1 << value) != 0;
+        let day = has(self.days, time.day());
+        let weekday = has(self.weekdays, time.weekday().num_days_from_sunday());
+        let day_matches = if self.day_or_weekday {
+            day || weekday
+        } else {
+            day && weekday
+        };
+        day_matches
+            && has(self.minutes, time.minute())
+            && has(self.hours, time.hour())
+            && has(self.months, time.month())
+    }
+}
+
+/// 解析单个字段，支持 `*`、`a`、`a-b`、`*/n`、`a/n`、`a-b/n` 及逗号分隔的组合
+fn parse_field(field: &str, min: u32, max: u32) -> Option<u64> {
+    let mut mask = 0u64;
+    for part in field.split(',') {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, step.parse::<u32>().ok().filter(|step| *step > 0)?),
+            None => (part, 1),
+        };
+        let (start, end) = if range == "*" {
+            (min, max)
+        } else if let Some((start, end)) = range.split_once('-') {
+            (start.parse().ok()?, end.parse().ok()?)
+        } else {
+            let value = range.parse().ok()?;
+            (value, if step > 1 { max } else { value })
+        };
+        if start < min || end > max || start > end {
+            return None;
+        }
+        for value in (start..=end).step_by(step as usize) {
+            mask |= 1 << value;
+        }
+    }
+    Some(mask)
+}
+
+fn truncate_to_minute(time: NaiveDateTime) -> NaiveDateTime {
+    time.with_second(0)
+        .and_then(|time| time.with_nanosecond(0))
+        .unwrap_or(time)
+}
+
+/// 周期窗口的开始时间是否在 `start_at`、`end_at` 限定的生效区间内
+fn within_bounds(window: &SysMaintenanceWindowModel, start: NaiveDateTime) -> bool {
+    window.start_at.is_none_or(|start_at| start >= start_at)
+        && window.end_at.is_none_or(|end_at| start < end_at)
+}
+
+/// `now` 所处的一次窗口，不在窗口内时返回 `None`
+pub fn occurrence_at(
+    window: &SysMaintenanceWindowModel,
+    now: NaiveDateTime,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    match window.schedule_kind {
+        MaintenanceScheduleKind::Range => {
+            let (start, end) = (window.start_at?, window.end_at?);
+            (start <= now && now < end).then_some((start, end))
+        },
+        MaintenanceScheduleKind::Cron => {
+            let schedule = CronSchedule::parse(window.cron.as_deref()?)?;
+            let duration = Duration::minutes(i64::from(window.duration_minutes?.max(1)));
+            let minute = truncate_to_minute(now);
+            // 从当前分钟往前找持续时间内最近一次开始
+            (0..duration.num_minutes())
+                .map(|offset| minute - Duration::minutes(offset))
+                .take_while(|start| window.start_at.is_none_or(|start_at| *start >= start_at))
+                .find(|start| within_bounds(window, *start) && schedule.matches(*start))
+                .map(|start| (start, start + duration))
+        },
+    }
+}
+
+/// `now` 之后 `lookahead` 内最近一次开始的窗口
+pub fn next_occurrence(
+    window: &SysMaintenanceWindowModel,
+    now: NaiveDateTime,
+    lookahead: Duration,
+) -> Option<(NaiveDateTime, NaiveDateTime)> {
+    match window.schedule_kind {
+        MaintenanceScheduleKind::Range => {
+            let (start, end) = (window.start_at?, window.end_at?);
+            (start > now && start <= now + lookahead).then_some((start, end))
+        },
+        MaintenanceScheduleKind::Cron => {
+            let schedule = CronSchedule::parse(window.cron.as_deref()?)?;
+            let duration = Duration::minutes(i64::from(window.duration_minutes?.max(1)));
+            let minute = truncate_to_minute(now);
+            (1..=lookahead.num_minutes())
+                .map(|offset| minute + Duration::minutes(offset))
+                .take_while(|start| window.end_at.is_none_or(|end_at| *start < end_at))
+                .find(|start| within_bounds(window, *start) && schedule.matches(*start))
+                .map(|start| (start, start + duration))
+        },
+    }
+}
+
+/// 读取启用的维护窗口，带进程内缓存
+pub async fn enabled_windows() -> Result<Vec<SysMaintenanceWindowModel>, AppError> {
+    {
+        let cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((loaded_at, windows)) = cache.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return Ok(windows.clone());
+            }
+        }
+    }
+
+    let db = db_helper::get_db_connection().await?;
+    let windows = SysMaintenanceWindow::find()
+        .filter(SysMaintenanceWindowColumn::Enabled.eq(true))
+        .all(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), windows.clone()));
+    Ok(windows)
+}
+
+/// 维护窗口修改后清除缓存
+pub fn invalidate() {
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 是否有满足条件的窗口正在进行，读取失败时视为没有
+async fn any_active(effect: fn(&SysMaintenanceWindowModel) -> bool) -> bool {
+    match enabled_windows().await {
+        Ok(windows) => {
+            let now = Local::now().naive_local();
+            windows
+                .iter()
+                .filter(|window| effect(window))
+                .any(|window| occurrence_at(window, now).is_some())
+        },
+        Err(e) => {
+            project_error!("Failed to load maintenance windows: {:?}", e);
+            false
+        },
+    }
+}
+
+/// 当前是否处于抑制安全告警的维护窗口
+pub async fn alerts_suppressed() -> bool {
+    any_active(|window| window.suppress_alerts).await
+}
+
+/// 当前是否处于暂停后台任务的维护窗口
+pub async fn jobs_paused() -> bool {
+    any_active(|window| window.pause_jobs).await
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    fn at(day: u32, hour: u32, minute: u32) -> NaiveDateTime {
+        // 2026-10-04 是周日
+        NaiveDate::from_ymd_opt(2026, 10, day)
+            .unwrap()
+            .and_hms_opt(hour, minute, 0)
+            .unwrap()
+    }
+
+    fn cron_window(cron: &str, duration_minutes: i32) -> SysMaintenanceWindowModel {
+        SysMaintenanceWindowModel {
+            id: "window".to_string(),
+            name: "weekly".to_string(),
+            message: None,
+            schedule_kind: MaintenanceScheduleKind::Cron,
+            cron: Some(cron.to_string()),
+            duration_minutes: Some(duration_minutes),
+            start_at: None,
+            end_at: None,
+            suppress_alerts: true,
+            pause_jobs: true,
+            show_banner: true,
+            enabled: true,
+            created_at: at(1, 0, 0),
+            created_by: "test".to_string(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn test_cron_parse_and_match() {
+        assert!(CronSchedule::parse("0 2 * *").is_none());
+        assert!(CronSchedule::parse("60 * * * *").is_none());
+        assert!(CronSchedule::parse("*/0 * * * *").is_none());
+
+        let schedule = CronSchedule::parse("*/15 2-4 * * 0,6").unwrap();
+        assert!(schedule.matches(at(4, 2, 45)));
+        assert!(!schedule.matches(at(4, 2, 50)));
+        assert!(!schedule.matches(at(5, 2, 45)));
+
+        // 7 表示周日
+        assert!(CronSchedule::parse("0 0 * * 7")
+            .unwrap()
+            .matches(at(4, 0, 0)));
+
+        // 日和周同时限定时满足其一即可
+        let schedule = CronSchedule::parse("0 0 1 * 0").unwrap();
+        assert!(schedule.matches(at(1, 0, 0)));
+        assert!(schedule.matches(at(4, 0, 0)));
+        assert!(!schedule.matches(at(5, 0, 0)));
+    }
+
+    #[test]
+    fn test_occurrences() {
+        // 每周日 23:30 开始，持续 2 小时，跨越零点
+        let window = cron_window("30 23 * * 0", 120);
+        assert_eq!(
+            occurrence_at(&window, at(5, 1, 10)),
+            Some((at(4, 23, 30), at(5, 1, 30)))
+        );
+        assert_eq!(occurrence_at(&window, at(5, 1, 30)), None);
+        assert_eq!(
+            next_occurrence(&window, at(4, 12, 0), Duration::hours(24)),
+            Some((at(4, 23, 30), at(5, 1, 30)))
+        );
+        assert_eq!(
+            next_occurrence(&window, at(5, 12, 0), Duration::hours(24)),
+            None
+        );
+
+        let mut window = window;
+        window.start_at = Some(at(5, 0, 0));
+        assert_eq!(occurrence_at(&window, at(5, 1, 10)), None);
+
+        let mut range = cron_window("", 0);
+        range.schedule_kind = MaintenanceScheduleKind::Range;
+        range.start_at = Some(at(10, 8, 0));
+        range.end_at = Some(at(10, 9, 0));
+        assert!(occurrence_at(&range, at(10, 8, 0)).is_some());
+        assert!(occurrence_at(&range, at(10, 9, 0)).is_none());
+        assert!(next_occurrence(&range, at(10, 7, 0), Duration::hours(1)).is_some());
+        assert!(next_occurrence(&range, at(10, 6, 0), Duration::hours(1)).is_none());
+    }
+}
@@ -1,3 +1,22 @@
+pub mod alert_helper;
+pub mod api_usage_helper;
+pub mod audit_archive_helper;
+pub mod audit_helper;
+pub mod cache_helper;
+pub mod data_scope_helper;
 pub mod db_helper;
+pub mod export_watermark_helper;
+pub mod login_throttle_helper;
+pub mod maintenance_helper;
+pub mod module_helper;
 pub mod mongo_helper;
+pub mod notification_helper;
+pub mod organization_helper;
+pub mod passkey_helper;
 pub mod redis_helper;
+pub mod s3_helper;
+pub mod share_attempt_helper;
+pub mod siem_helper;
+pub mod tenant_helper;
+pub mod tree_helper;
+pub mod virus_scan_helper;
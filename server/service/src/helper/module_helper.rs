@@ -0,0 +1,176 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    prelude::SysParam,
+    sys_param::{Column as SysParamColumn, Model as SysParamModel},
+};
+
+use crate::{helper::db_helper, project_error};
+
+/// 模块开关参数的缓存时间，每个请求都要检查，不必每次查库；其他实例最迟在缓存过期后生效
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// 模块开关参数的前缀，完整的键为 `module.<code>.enabled`
+const PARAM_PREFIX: &str = "module.";
+
+/// 缓存的加载时间和内容
+type Cache = Mutex<Option<(Instant, HashMap<String, SysParamModel>)>>;
+
+static CACHE: LazyLock<Cache> = LazyLock::new(|| Mutex::new(None));
+
+/// 可在运行时停用的功能模块
+#[derive(Debug)]
+pub struct ModuleDefinition {
+    pub code: &'static str,
+    pub name: &'static str,
+    /// 停用后返回 404 的路由前缀
+    pub route_prefixes: &'static [&'static str],
+}
+
+/// 可停用的模块，用户、角色、菜单等基础模块不在其中
+pub const MODULES: &[ModuleDefinition] = &[
+    ModuleDefinition {
+        code: "access_review",
+        name: "权限复核",
+        route_prefixes: &["/access-review"],
+    },
+    ModuleDefinition {
+        code: "api_usage",
+        name: "调用统计",
+        route_prefixes: &["/api-usage"],
+    },
+    ModuleDefinition {
+        code: "consistency",
+        name: "数据一致性检查",
+        route_prefixes: &["/consistency"],
+    },
+    ModuleDefinition {
+        code: "metering",
+        name: "计量报表",
+        route_prefixes: &["/metering"],
+    },
+    ModuleDefinition {
+        code: "notification",
+        name: "站内通知",
+        route_prefixes: &["/notification"],
+    },
+    ModuleDefinition {
+        code: "telemetry",
+        name: "使用情况上报",
+        route_prefixes: &["/telemetry"],
+    },
+];
+
+/// 模块开关对应的系统参数键
+pub fn param_key(code: &str) -> String {
+    format!("{}{}.enabled", PARAM_PREFIX, code)
+}
+
+pub fn find_module(code: &str) -> Option<&'static ModuleDefinition> {
+    MODULES.iter().find(|module| module.code == code)
+}
+
+/// 路径所属的模块，按路径段匹配前缀，`/notification` 不匹配 `/notifications`
+pub fn module_for_path(path: &str) -> Option<&'static ModuleDefinition> {
+    MODULES.iter().find(|module| {
+        module.route_prefixes.iter().any(|prefix| {
+            path.strip_prefix(prefix)
+                .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+        })
+    })
+}
+
+/// 全部模块开关参数，按键索引
+pub async fn module_params() -> Result<HashMap<String, SysParamModel>, AppError> {
+    {
+        let cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((loaded_at, params)) = cache.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return Ok(params.clone());
+            }
+        }
+    }
+
+    let db = db_helper::get_db_connection().await?;
+    let params: HashMap<String, SysParamModel> = SysParam::find()
+        .filter(SysParamColumn::Key.starts_with(PARAM_PREFIX))
+        .all(db.as_ref())
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .map(|param| (param.key.clone(), param))
+        .collect();
+
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), params.clone()));
+    Ok(params)
+}
+
+/// 模块开关修改后清除本实例的缓存
+pub fn invalidate() {
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 参数值是否表示启用，未设置参数的模块默认启用
+pub fn is_enabled_value(value: Option<&str>) -> bool {
+    !value.is_some_and(|value| value.trim().eq_ignore_ascii_case("false"))
+}
+
+/// 路径所属的模块已停用时返回模块编码
+pub async fn disabled_module_for_path(path: &str) -> Result<Option<&'static str>, AppError> {
+    let Some(module) = module_for_path(path) else {
+        return Ok(None);
+    };
+    let params = module_params().await?;
+    let value = params
+        .get(&param_key(module.code))
+        .map(|param| param.value.as_str());
+    Ok((!is_enabled_value(value)).then_some(module.code))
+}
+
+/// 模块是否启用，后台任务据此暂停；读取失败时视为启用
+pub async fn module_enabled(code: &str) -> bool {
+    match module_params().await {
+        Ok(params) => is_enabled_value(
+            params
+                .get(&param_key(code))
+                .map(|param| param.value.as_str()),
+        ),
+        Err(e) => {
+            project_error!("Failed to load module switches: {:?}", e);
+            true
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module_for_path() {
+        assert_eq!(
+            module_for_path("/notification/preference").map(|module| module.code),
+            Some("notification")
+        );
+        assert_eq!(
+            module_for_path("/metering").map(|module| module.code),
+            Some("metering")
+        );
+        assert!(module_for_path("/notifications").is_none());
+        assert!(module_for_path("/user/list").is_none());
+    }
+
+    #[test]
+    fn test_is_enabled_value() {
+        assert!(is_enabled_value(None));
+        assert!(is_enabled_value(Some("true")));
+        assert!(!is_enabled_value(Some("false")));
+        assert!(!is_enabled_value(Some(" FALSE ")));
+    }
+}
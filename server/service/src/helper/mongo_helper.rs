@@ -16,14 +16,13 @@ pub enum MongoSource {
 
 /// 获取主 MongoDB 客户端
 pub async fn get_primary_client() -> Result<Client, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Mongo).await?;
     let client = GLOBAL_PRIMARY_MONGO
         .read()
         .await
         .clone()
-        .ok_or_else(|| AppError {
-            code: 500,
-            message: "Primary MongoDB not initialized".to_string(),
-        })?;
+        .ok_or_else(|| AppError::dependency("Primary MongoDB not initialized"))?;
     Ok(client.as_ref().clone())
 }
 
@@ -40,11 +39,12 @@ pub async fn get_primary_collection<T: Send + Sync>(
 
 /// 获取命名 MongoDB 客户端
 pub async fn get_named_client(name: &str) -> Result<Client, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Mongo).await?;
     let pools = GLOBAL_MONGO_POOL.read().await;
-    let client = pools.get(name).ok_or_else(|| AppError {
-        code: 500,
-        message: format!("MongoDB pool '{}' not found", name),
-    })?;
+    let client = pools
+        .get(name)
+        .ok_or_else(|| AppError::dependency(format!("MongoDB pool '{}' not found", name)))?;
     Ok(client.as_ref().clone())
 }
 
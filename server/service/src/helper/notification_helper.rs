@@ -0,0 +1,438 @@
+use std::collections::BTreeMap;
+
+use chrono::{Local, NaiveTime};
+use sea_orm::{
+    sea_query::Expr, ActiveModelTrait, ColumnTrait, EntityTrait, QueryFilter, QueryOrder,
+    QuerySelect, Set,
+};
+use server_config::NotificationConfig;
+use server_core::web::error::AppError;
+use server_global::global;
+use server_model::admin::{
+    entities::{
+        prelude::{SysNotification, SysNotificationPreference, SysUser},
+        sea_orm_active_enums::{NotificationEmailStatus, NotificationPriority},
+        sys_notification::{
+            ActiveModel as SysNotificationActiveModel, Column as SysNotificationColumn,
+            Model as SysNotificationModel,
+        },
+        sys_user::Column as SysUserColumn,
+    },
+    output::NotificationPreferenceOutput,
+};
+use ulid::Ulid;
+
+use crate::{
+    helper::{alert_helper, db_helper},
+    project_error,
+};
+
+/// 通知分类，用户可以按分类静音
+pub mod category {
+    pub const ACCESS_REVIEW: &str = "access_review";
+    pub const ACCOUNT: &str = "account";
+    pub const CONSISTENCY: &str = "consistency";
+    pub const SECURITY: &str = "security";
+}
+
+/// 发给单个用户的通知
+#[derive(Debug, Clone)]
+pub struct Notification {
+    user_id: String,
+    category: String,
+    priority: NotificationPriority,
+    title: String,
+    content: String,
+}
+
+impl Notification {
+    pub fn new(
+        user_id: impl Into<String>,
+        category: &str,
+        title: impl Into<String>,
+        content: impl Into<String>,
+    ) -> Self {
+        Self {
+            user_id: user_id.into(),
+            category: category.to_string(),
+            priority: NotificationPriority::Normal,
+            title: title.into(),
+            content: content.into(),
+        }
+    }
+
+    pub fn with_priority(mut self, priority: NotificationPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+}
+
+/// 邮件的投递方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EmailDelivery {
+    Skip,
+    Now,
+    Digest,
+}
+
+/// 按用户偏好决定的投递方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DeliveryPlan {
+    in_app: bool,
+    email: EmailDelivery,
+}
+
+/// 当前时间是否在免打扰时段内，开始晚于结束时表示跨越零点
+fn in_quiet_hours(preference: &NotificationPreferenceOutput, now: NaiveTime) -> bool {
+    match (preference.quiet_start, preference.quiet_end) {
+        (Some(start), Some(end)) if start <= end => now >= start && now < end,
+        (Some(start), Some(end)) => now >= start || now < end,
+        _ => false,
+    }
+}
+
+/// 计算通知的投递方式，不需要投递时返回 `None`
+///
+/// 高优先级通知忽略静音分类、摘要和免打扰时段；低优先级通知在开启摘要时汇总；
+/// 免打扰时段内的其他通知推迟到时段结束后的摘要邮件
+fn plan_delivery(
+    preference: &NotificationPreferenceOutput,
+    has_email: bool,
+    category: &str,
+    priority: NotificationPriority,
+    now: NaiveTime,
+) -> Option<DeliveryPlan> {
+    let urgent = priority == NotificationPriority::High;
+    if !urgent
+        && preference
+            .muted_categories
+            .iter()
+            .any(|muted| muted == category)
+    {
+        return None;
+    }
+
+    let email = if !preference.email_enabled || !has_email {
+        EmailDelivery::Skip
+    } else if urgent {
+        EmailDelivery::Now
+    } else if (priority == NotificationPriority::Low && preference.digest_enabled)
+        || in_quiet_hours(preference, now)
+    {
+        EmailDelivery::Digest
+    } else {
+        EmailDelivery::Now
+    };
+
+    let plan = DeliveryPlan {
+        in_app: preference.in_app_enabled,
+        email,
+    };
+    (plan.in_app || plan.email != EmailDelivery::Skip).then_some(plan)
+}
+
+/// 读取用户的通知偏好，未设置时返回默认值
+pub async fn load_preference(user_id: &str) -> Result<NotificationPreferenceOutput, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    SysNotificationPreference::find_by_id(user_id)
+        .one(&db)
+        .await
+        .map_err(AppError::from)
+        .map(|preference| preference.map(Into::into).unwrap_or_default())
+}
+
+async fn user_email(user_id: &str) -> Result<Option<String>, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    SysUser::find_by_id(user_id)
+        .select_only()
+        .column(SysUserColumn::Email)
+        .into_tuple::<Option<String>>()
+        .one(&db)
+        .await
+        .map_err(AppError::from)
+        .map(|email| email.flatten().filter(|email| !email.trim().is_empty()))
+}
+
+/// 按用户偏好投递通知
+///
+/// 通知写入 `sys_notification`，站内信从该表读取，等待摘要的通知由 [`send_digests`] 汇总发送
+pub async fn notify(notification: Notification) -> Result<(), AppError> {
+    let preference = load_preference(&notification.user_id).await?;
+    let email = user_email(&notification.user_id).await?;
+    let now = Local::now().naive_local();
+
+    let Some(plan) = plan_delivery(
+        &preference,
+        email.is_some(),
+        &notification.category,
+        notification.priority,
+        now.time(),
+    ) else {
+        tracing::info!(
+            target: "metrics",
+            event = "notification_suppressed",
+            category = %notification.category,
+        );
+        return Ok(());
+    };
+
+    let (email_status, emailed_at) = match (plan.email, email) {
+        (EmailDelivery::Now, Some(email)) => {
+            match alert_helper::send_mail(&[email], &notification.title, &notification.content)
+                .await
+            {
+                Ok(()) => (NotificationEmailStatus::Sent, Some(now)),
+                Err(e) => {
+                    project_error!(
+                        "Failed to email notification to {}: {}",
+                        notification.user_id,
+                        e.message()
+                    );
+                    (NotificationEmailStatus::Failed, None)
+                },
+            }
+        },
+        (EmailDelivery::Digest, Some(_)) => (NotificationEmailStatus::Pending, None),
+        _ => (NotificationEmailStatus::Skipped, None),
+    };
+
+    let db = db_helper::get_rls_connection().await?;
+    SysNotificationActiveModel {
+        id: Set(Ulid::new().to_string()),
+        user_id: Set(notification.user_id),
+        category: Set(notification.category),
+        priority: Set(notification.priority),
+        title: Set(notification.title),
+        content: Set(notification.content),
+        in_app: Set(plan.in_app),
+        email_status: Set(email_status),
+        created_at: Set(now),
+        read_at: Set(None),
+        emailed_at: Set(emailed_at),
+    }
+    .insert(&db)
+    .await
+    .map_err(AppError::from)?;
+    Ok(())
+}
+
+/// 为每个有待汇总通知的用户发送一封摘要邮件，返回发送的邮件数
+///
+/// 处于免打扰时段的用户留到下一次
+pub async fn send_digests() -> Result<usize, AppError> {
+    let config = global::get_config::<NotificationConfig>()
+        .await
+        .map(|config| (*config).clone())
+        .unwrap_or_default();
+    let db = db_helper::get_rls_connection().await?;
+
+    let user_ids = SysNotification::find()
+        .select_only()
+        .column(SysNotificationColumn::UserId)
+        .distinct()
+        .filter(SysNotificationColumn::EmailStatus.eq(NotificationEmailStatus::Pending))
+        .into_tuple::<String>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)?;
+
+    let now = Local::now().naive_local();
+    let mut sent = 0;
+    for user_id in user_ids {
+        let preference = load_preference(&user_id).await?;
+        if in_quiet_hours(&preference, now.time()) {
+            continue;
+        }
+
+        let notifications = SysNotification::find()
+            .filter(SysNotificationColumn::UserId.eq(&user_id))
+            .filter(SysNotificationColumn::EmailStatus.eq(NotificationEmailStatus::Pending))
+            .order_by_asc(SysNotificationColumn::CreatedAt)
+            .limit(config.digest_max_items)
+            .all(&db)
+            .await
+            .map_err(AppError::from)?;
+
+        let status = match user_email(&user_id).await? {
+            Some(email) if preference.email_enabled => {
+                let (subject, content) = digest_mail(&notifications);
+                match alert_helper::send_mail(&[email], &subject, &content).await {
+                    Ok(()) => {
+                        sent += 1;
+                        NotificationEmailStatus::Sent
+                    },
+                    Err(e) => {
+                        project_error!("Failed to send digest to {}: {}", user_id, e.message());
+                        NotificationEmailStatus::Failed
+                    },
+                }
+            },
+            // 汇总前关闭了邮件或删除了邮箱
+            _ => NotificationEmailStatus::Skipped,
+        };
+
+        SysNotification::update_many()
+            .col_expr(
+                SysNotificationColumn::EmailStatus,
+                Expr::value(status.clone()),
+            )
+            .col_expr(
+                SysNotificationColumn::EmailedAt,
+                Expr::value((status == NotificationEmailStatus::Sent).then_some(now)),
+            )
+            .filter(
+                SysNotificationColumn::Id.is_in(
+                    notifications
+                        .iter()
+                        .map(|notification| notification.id.clone()),
+                ),
+            )
+            .exec(&db)
+            .await
+            .map_err(AppError::from)?;
+    }
+
+    if sent > 0 {
+        tracing::info!(target: "metrics", event = "notification_digest_sent", count = sent);
+    }
+    Ok(sent)
+}
+
+/// 摘要邮件的标题和正文，按分类分组
+fn digest_mail(notifications: &[SysNotificationModel]) -> (String, String) {
+    let mut groups: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for notification in notifications {
+        groups
+            .entry(notification.category.as_str())
+            .or_default()
+            .push(notification.title.as_str());
+    }
+
+    let subject = format!("您有 {} 条新通知", notifications.len());
+    let content = groups
+        .into_iter()
+        .map(|(category, titles)| {
+            let lines = titles
+                .iter()
+                .map(|title| format!("- {}", title))
+                .collect::<Vec<_>>()
+                .join("\n");
+            format!("[{}]\n{}", category, lines)
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n");
+    (subject, content)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(hour: u32) -> NaiveTime {
+        NaiveTime::from_hms_opt(hour, 0, 0).unwrap()
+    }
+
+    #[test]
+    fn test_plan_delivery() {
+        let mut preference = NotificationPreferenceOutput::default();
+        let now = Some(DeliveryPlan {
+            in_app: true,
+            email: EmailDelivery::Now,
+        });
+        let digest = Some(DeliveryPlan {
+            in_app: true,
+            email: EmailDelivery::Digest,
+        });
+
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::Low,
+                at(12)
+            ),
+            now
+        );
+
+        preference.digest_enabled = true;
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::Low,
+                at(12)
+            ),
+            digest
+        );
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::Normal,
+                at(12)
+            ),
+            now
+        );
+
+        // 跨越零点的免打扰时段
+        preference.quiet_start = Some(at(22));
+        preference.quiet_end = Some(at(7));
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::Normal,
+                at(23)
+            ),
+            digest
+        );
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::High,
+                at(23)
+            ),
+            now
+        );
+
+        preference.muted_categories = vec!["account".to_string()];
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::Normal,
+                at(12)
+            ),
+            None
+        );
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                true,
+                "account",
+                NotificationPriority::High,
+                at(12)
+            ),
+            now
+        );
+
+        preference.in_app_enabled = false;
+        assert_eq!(
+            plan_delivery(
+                &preference,
+                false,
+                "other",
+                NotificationPriority::Normal,
+                at(12)
+            ),
+            None
+        );
+    }
+}
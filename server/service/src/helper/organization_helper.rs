@@ -0,0 +1,105 @@
+use std::collections::HashMap;
+
+use sea_orm::{
+    ColumnTrait, EntityTrait, JoinType, QueryFilter, QueryOrder, QuerySelect, RelationTrait,
+};
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    prelude::{SysOrganization, SysUser},
+    sys_organization::Column as SysOrganizationColumn,
+    sys_role::Column as SysRoleColumn,
+    sys_user::{Column as SysUserColumn, Relation as SysUserRelation},
+    sys_user_role::Relation as SysUserRoleRelation,
+};
+
+use crate::helper::db_helper;
+
+/// 全部组织的上级关系（组织 ID -> 上级 ID）
+pub async fn load_parents() -> Result<HashMap<String, String>, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    SysOrganization::find()
+        .select_only()
+        .column(SysOrganizationColumn::Id)
+        .column(SysOrganizationColumn::Pid)
+        .into_tuple::<(String, String)>()
+        .all(&db)
+        .await
+        .map(|rows| rows.into_iter().collect())
+        .map_err(AppError::from)
+}
+
+/// 按组织分组的角色持有人（组织 ID -> 用户 ID），同一组织内按用户名排序
+pub async fn role_holders(role_code: &str) -> Result<HashMap<String, Vec<String>>, AppError> {
+    let db = db_helper::get_rls_connection().await?;
+    let rows = SysUser::find()
+        .select_only()
+        .column(SysUserColumn::OrganizationId)
+        .column(SysUserColumn::Id)
+        .join(JoinType::InnerJoin, SysUserRelation::SysUserRole.def())
+        .join(JoinType::InnerJoin, SysUserRoleRelation::SysRole.def())
+        .filter(SysRoleColumn::Code.eq(role_code))
+        .filter(SysUserColumn::OrganizationId.is_not_null())
+        .order_by_asc(SysUserColumn::Username)
+        .into_tuple::<(String, String)>()
+        .all(&db)
+        .await
+        .map_err(AppError::from)?;
+
+    let mut holders: HashMap<String, Vec<String>> = HashMap::new();
+    for (organization_id, user_id) in rows {
+        holders.entry(organization_id).or_default().push(user_id);
+    }
+    Ok(holders)
+}
+
+/// 从用户所属组织开始逐级向上，返回第一个不是用户本人的持有人
+pub fn nearest_holder(
+    user_id: &str,
+    organization_id: Option<&str>,
+    parents: &HashMap<String, String>,
+    holders: &HashMap<String, Vec<String>>,
+) -> Option<String> {
+    let mut current = organization_id;
+    // 组织数据存在环时最多遍历组织总数次
+    for _ in 0..=parents.len() {
+        let organization = current?;
+        if let Some(holder) = holders
+            .get(organization)
+            .and_then(|ids| ids.iter().find(|id| id.as_str() != user_id))
+        {
+            return Some(holder.clone());
+        }
+        current = parents.get(organization).map(String::as_str);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nearest_holder_walks_up_organizations() {
+        let parents = HashMap::from([
+            ("rd-web".to_string(), "rd".to_string()),
+            ("rd".to_string(), "hq".to_string()),
+            ("hq".to_string(), "0".to_string()),
+        ]);
+        let holders = HashMap::from([
+            ("rd".to_string(), vec!["rd-lead".to_string()]),
+            ("hq".to_string(), vec!["ceo".to_string()]),
+        ]);
+
+        assert_eq!(
+            nearest_holder("dev", Some("rd-web"), &parents, &holders),
+            Some("rd-lead".to_string())
+        );
+        // 持有人本人交给上级组织
+        assert_eq!(
+            nearest_holder("rd-lead", Some("rd"), &parents, &holders),
+            Some("ceo".to_string())
+        );
+        assert_eq!(nearest_holder("ceo", Some("hq"), &parents, &holders), None);
+        assert_eq!(nearest_holder("dev", None, &parents, &holders), None);
+    }
+}
@@ -0,0 +1,244 @@
+use chrono::Local;
+use sea_orm::{
+    ActiveModelTrait, ColumnTrait, EntityTrait, PaginatorTrait, QueryFilter, QueryOrder, Set,
+};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use server_config::{PasskeyConfig, SecurityConfig};
+use server_core::web::error::AppError;
+use server_global::global;
+use server_model::admin::entities::{
+    prelude::SysUserPasskey,
+    sys_user_passkey::{
+        ActiveModel as SysUserPasskeyActiveModel, Column as SysUserPasskeyColumn,
+        Model as SysUserPasskeyModel,
+    },
+};
+use ulid::Ulid;
+use webauthn_rs::prelude::{
+    CredentialID, Passkey, PasskeyAuthentication, PasskeyRegistration, PublicKeyCredential, Url,
+    Uuid, Webauthn, WebauthnBuilder, WebauthnError,
+};
+
+use crate::{
+    admin::sys_passkey_error::PasskeyError,
+    helper::{
+        db_helper,
+        redis_helper::{self, RedisSource},
+    },
+    project_error,
+};
+
+const KEY_PREFIX: &str = "soybean:passkey";
+
+/// 挑战类型，决定 Redis 键的命名空间
+#[derive(Debug, Clone, Copy)]
+pub enum ChallengeKind {
+    Registration,
+    Login,
+    /// 密码验证通过、等待通行密钥二次验证的登录票据
+    Ticket,
+}
+
+impl ChallengeKind {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChallengeKind::Registration => "registration",
+            ChallengeKind::Login => "login",
+            ChallengeKind::Ticket => "ticket",
+        }
+    }
+}
+
+/// 注册挑战状态
+#[derive(Serialize, Deserialize)]
+pub struct RegistrationState {
+    pub user_id: String,
+    pub device_name: String,
+    pub registration: PasskeyRegistration,
+}
+
+/// 登录挑战状态
+#[derive(Serialize, Deserialize)]
+pub struct LoginState {
+    pub user_id: String,
+    pub domain: String,
+    /// 是否为密码登录后的二次验证
+    pub second_factor: bool,
+    pub authentication: PasskeyAuthentication,
+}
+
+/// 二次验证票据
+#[derive(Serialize, Deserialize)]
+pub struct SecondFactorTicket {
+    pub user_id: String,
+    pub domain: String,
+}
+
+pub async fn passkey_config() -> PasskeyConfig {
+    global::get_config::<SecurityConfig>()
+        .await
+        .map(|config| config.passkey.clone())
+        .unwrap_or_default()
+}
+
+/// 启用通行密钥时返回配置，否则返回错误
+pub async fn enabled_config() -> Result<PasskeyConfig, AppError> {
+    let config = passkey_config().await;
+    if config.enabled {
+        Ok(config)
+    } else {
+        Err(PasskeyError::NotEnabled.into())
+    }
+}
+
+pub fn webauthn(config: &PasskeyConfig) -> Result<Webauthn, AppError> {
+    let origin = Url::parse(&config.rp_origin)
+        .map_err(|e| AppError::internal(format!("Invalid passkey rp_origin: {}", e)))?;
+    WebauthnBuilder::new(&config.rp_id, &origin)
+        .map(|builder| builder.rp_name(&config.rp_name))
+        .and_then(WebauthnBuilder::build)
+        .map_err(|e| AppError::internal(format!("Invalid passkey configuration: {}", e)))
+}
+
+/// 浏览器校验失败或凭证格式错误，记录原因后统一返回验证失败
+pub fn verification_error(e: WebauthnError) -> AppError {
+    tracing::warn!("Passkey verification failed: {}", e);
+    PasskeyError::VerificationFailed.into()
+}
+
+/// 由用户 ID 派生稳定的 WebAuthn 用户句柄，避免向认证器暴露内部 ID
+pub fn user_handle(user_id: &str) -> Uuid {
+    let digest = ring::digest::digest(&ring::digest::SHA256, user_id.as_bytes());
+    let mut bytes = [0u8; 16];
+    bytes.copy_from_slice(&digest.as_ref()[..16]);
+    Uuid::from_bytes(bytes)
+}
+
+pub fn credential_key(id: &CredentialID) -> String {
+    hex::encode(id)
+}
+
+fn challenge_key(kind: ChallengeKind, challenge_id: &str) -> String {
+    format!("{}:{}:{}", KEY_PREFIX, kind.as_str(), challenge_id)
+}
+
+/// 保存挑战状态，返回挑战 ID
+pub async fn save_challenge<T: Serialize>(
+    config: &PasskeyConfig,
+    kind: ChallengeKind,
+    state: &T,
+) -> Result<String, AppError> {
+    let challenge_id = Ulid::new().to_string();
+    let value = serde_json::to_string(state)
+        .map_err(|e| AppError::internal(format!("Failed to serialize passkey challenge: {}", e)))?;
+    redis_helper::query::<()>(
+        RedisSource::Primary,
+        redis::cmd("SET")
+            .arg(challenge_key(kind, &challenge_id))
+            .arg(value)
+            .arg("EX")
+            .arg(config.challenge_ttl),
+    )
+    .await?;
+    Ok(challenge_id)
+}
+
+/// 取出并删除挑战状态，每个挑战只能使用一次
+pub async fn take_challenge<T: DeserializeOwned>(
+    kind: ChallengeKind,
+    challenge_id: &str,
+) -> Result<T, AppError> {
+    let value = redis_helper::query::<Option<String>>(
+        RedisSource::Primary,
+        redis::cmd("GETDEL").arg(challenge_key(kind, challenge_id)),
+    )
+    .await?
+    .ok_or(PasskeyError::ChallengeExpired)?;
+    serde_json::from_str(&value).map_err(|e| {
+        project_error!("Failed to deserialize passkey challenge: {}", e);
+        PasskeyError::ChallengeExpired.into()
+    })
+}
+
+/// 用户已注册的通行密钥，无法解析的凭证记录日志后跳过
+pub async fn find_passkeys(user_id: &str) -> Result<Vec<(SysUserPasskeyModel, Passkey)>, AppError> {
+    let db = db_helper::get_db_connection().await?;
+    let records = SysUserPasskey::find()
+        .filter(SysUserPasskeyColumn::UserId.eq(user_id))
+        .order_by_asc(SysUserPasskeyColumn::CreatedAt)
+        .all(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+
+    Ok(records
+        .into_iter()
+        .filter_map(
+            |record| match serde_json::from_str::<Passkey>(&record.credential) {
+                Ok(passkey) => Some((record, passkey)),
+                Err(e) => {
+                    project_error!("Invalid passkey credential '{}': {}", record.id, e);
+                    None
+                },
+            },
+        )
+        .collect())
+}
+
+/// 校验登录凭证，成功后更新凭证的签名计数和最近使用时间
+pub async fn complete_authentication(
+    config: &PasskeyConfig,
+    state: &LoginState,
+    credential: Value,
+) -> Result<(), AppError> {
+    let credential = serde_json::from_value::<PublicKeyCredential>(credential)
+        .map_err(|_| AppError::from(PasskeyError::VerificationFailed))?;
+    let result = webauthn(config)?
+        .finish_passkey_authentication(&credential, &state.authentication)
+        .map_err(verification_error)?;
+
+    let db = db_helper::get_db_connection().await?;
+    let record = SysUserPasskey::find()
+        .filter(SysUserPasskeyColumn::CredentialId.eq(credential_key(result.cred_id())))
+        .filter(SysUserPasskeyColumn::UserId.eq(&state.user_id))
+        .one(db.as_ref())
+        .await
+        .map_err(AppError::from)?
+        .ok_or(PasskeyError::VerificationFailed)?;
+
+    let mut passkey = serde_json::from_str::<Passkey>(&record.credential)
+        .map_err(|_| AppError::from(PasskeyError::VerificationFailed))?;
+    passkey.update_credential(&result);
+    let mut record: SysUserPasskeyActiveModel = record.into();
+    record.credential = Set(serde_json::to_string(&passkey)
+        .map_err(|e| AppError::internal(format!("Failed to serialize passkey: {}", e)))?);
+    record.last_used_at = Set(Some(Local::now().naive_local()));
+    record.update(db.as_ref()).await.map_err(AppError::from)?;
+    Ok(())
+}
+
+/// 密码登录后是否还需要通行密钥二次验证
+pub async fn requires_second_factor(user_id: &str) -> Result<bool, AppError> {
+    let config = passkey_config().await;
+    if !config.enabled || !config.second_factor {
+        return Ok(false);
+    }
+    let db = db_helper::get_db_connection().await?;
+    let count = SysUserPasskey::find()
+        .filter(SysUserPasskeyColumn::UserId.eq(user_id))
+        .count(db.as_ref())
+        .await
+        .map_err(AppError::from)?;
+    Ok(count > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_user_handle_is_stable() {
+        assert_eq!(user_handle("01J8Z3"), user_handle("01J8Z3"));
+        assert_ne!(user_handle("01J8Z3"), user_handle("01J8Z4"));
+    }
+}
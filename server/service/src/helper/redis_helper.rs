@@ -1,7 +1,18 @@
 #![allow(dead_code)]
-use redis::{aio::MultiplexedConnection, cluster_async::ClusterConnection, ErrorKind, RedisError};
+use std::{
+    collections::HashMap,
+    future::Future,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use redis::{
+    aio::ConnectionManager, cluster_async::ClusterConnection, Cmd, ErrorKind, FromRedisValue,
+    Pipeline, RedisError, RedisResult, ScriptInvocation,
+};
+use server_config::{OptionalConfigs, RedisInstancesConfig};
 use server_core::web::error::AppError;
-use server_global::global::{RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL};
+use server_global::global::{get_config, RedisConnection, GLOBAL_PRIMARY_REDIS, GLOBAL_REDIS_POOL};
 
 /// Redis连接来源
 #[derive(Debug, Clone)]
@@ -13,7 +24,9 @@ pub enum RedisSource {
 }
 
 /// 获取Redis连接
-pub async fn get_redis_connection(source: RedisSource) -> Result<MultiplexedConnection, AppError> {
+pub async fn get_redis_connection(source: RedisSource) -> Result<ConnectionManager, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Redis).await?;
     match source {
         RedisSource::Primary => {
             let redis = GLOBAL_PRIMARY_REDIS.read().await.clone().ok_or_else(|| {
@@ -23,9 +36,7 @@ pub async fn get_redis_connection(source: RedisSource) -> Result<MultiplexedConn
                 )))
             })?;
             match redis {
-                RedisConnection::Single(client) => {
-                    Ok(client.get_multiplexed_async_connection().await?)
-                },
+                RedisConnection::Single(client) => Ok(client.get_connection().await?),
                 RedisConnection::Cluster(_) => Err(AppError::from(RedisError::from((
                     ErrorKind::IoError,
                     "Primary Redis is not Single mode",
@@ -41,9 +52,7 @@ pub async fn get_redis_connection(source: RedisSource) -> Result<MultiplexedConn
                 )))
             })?;
             match redis {
-                RedisConnection::Single(client) => {
-                    Ok(client.get_multiplexed_async_connection().await?)
-                },
+                RedisConnection::Single(client) => Ok(client.get_connection().await?),
                 RedisConnection::Cluster(_) => Err(AppError::from(RedisError::from((
                     ErrorKind::IoError,
                     "Named Redis is not Single mode",
@@ -57,6 +66,8 @@ pub async fn get_redis_connection(source: RedisSource) -> Result<MultiplexedConn
 pub async fn get_redis_cluster_connection(
     source: RedisSource,
 ) -> Result<ClusterConnection, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Redis).await?;
     match source {
         RedisSource::Primary => {
             let redis = GLOBAL_PRIMARY_REDIS.read().await.clone().ok_or_else(|| {
@@ -70,7 +81,7 @@ pub async fn get_redis_cluster_connection(
                     ErrorKind::IoError,
                     "Primary Redis is not Cluster mode",
                 )))),
-                RedisConnection::Cluster(client) => Ok(client.get_async_connection().await?),
+                RedisConnection::Cluster(client) => Ok(client.get_connection().await?),
             }
         },
         RedisSource::Named(name) => {
@@ -86,8 +97,215 @@ pub async fn get_redis_cluster_connection(
                     ErrorKind::IoError,
                     "Named Redis is not Cluster mode",
                 )))),
-                RedisConnection::Cluster(client) => Ok(client.get_async_connection().await?),
+                RedisConnection::Cluster(client) => Ok(client.get_connection().await?),
             }
         },
     }
 }
+
+async fn resolve_connection(source: &RedisSource) -> Result<RedisConnection, AppError> {
+    let redis = match source {
+        RedisSource::Primary => GLOBAL_PRIMARY_REDIS.read().await.clone(),
+        RedisSource::Named(name) => GLOBAL_REDIS_POOL.read().await.get(name).cloned(),
+    };
+    redis.ok_or_else(|| {
+        AppError::from(RedisError::from((
+            ErrorKind::IoError,
+            "Redis not initialized",
+        )))
+    })
+}
+
+/// 判断 Redis 是否已初始化
+pub async fn is_available(source: RedisSource) -> bool {
+    resolve_connection(&source).await.is_ok()
+}
+
+macro_rules! with_connection {
+    ($redis:expr, $conn:ident => $body:expr) => {
+        match $redis {
+            RedisConnection::Single(client) => {
+                let mut $conn = client.get_connection().await?;
+                $body
+            },
+            RedisConnection::Cluster(client) => {
+                let mut $conn = client.get_connection().await?;
+                $body
+            },
+        }
+    };
+}
+
+/// 连续连接失败达到该次数后熔断
+const CIRCUIT_FAILURE_THRESHOLD: u32 = 5;
+/// 熔断持续时间，到期后放行请求探测实例是否恢复
+const CIRCUIT_OPEN_DURATION: Duration = Duration::from_secs(30);
+
+#[derive(Default)]
+struct CircuitState {
+    failures: u32,
+    open_until: Option<Instant>,
+}
+
+/// 按实例名称记录的熔断状态，仅配置了备用实例的实例参与熔断
+static CIRCUITS: LazyLock<Mutex<HashMap<String, CircuitState>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+fn circuit_open(name: &str) -> bool {
+    CIRCUITS
+        .lock()
+        .unwrap()
+        .get(name)
+        .and_then(|state| state.open_until)
+        .is_some_and(|open_until| Instant::now() < open_until)
+}
+
+fn record_success(name: &str) {
+    let mut circuits = CIRCUITS.lock().unwrap();
+    if let Some(state) = circuits.remove(name) {
+        if state.open_until.is_some() {
+            tracing::info!(target: "metrics", event = "redis_circuit_closed", instance = name);
+        }
+    }
+}
+
+/// 记录一次连接失败，返回熔断是否处于打开状态
+fn record_failure(name: &str) -> bool {
+    let mut circuits = CIRCUITS.lock().unwrap();
+    let state = circuits.entry(name.to_string()).or_default();
+    state.failures += 1;
+    if state.failures >= CIRCUIT_FAILURE_THRESHOLD {
+        // 半开状态下探测失败同样重新计时
+        state.open_until = Some(Instant::now() + CIRCUIT_OPEN_DURATION);
+        tracing::info!(
+            target: "metrics",
+            event = "redis_circuit_opened",
+            instance = name,
+            failures = state.failures,
+        );
+        return true;
+    }
+    false
+}
+
+/// 连接类错误计入熔断，命令本身的错误（如类型不匹配、脚本错误）不计入
+fn is_connection_error(e: &RedisError) -> bool {
+    e.is_io_error() || e.is_connection_refusal() || e.is_connection_dropped() || e.is_timeout()
+}
+
+async fn fallback_for(name: &str) -> Option<String> {
+    get_config::<OptionalConfigs<RedisInstancesConfig>>()
+        .await?
+        .configs
+        .as_ref()?
+        .iter()
+        .find(|instance| instance.name == name)?
+        .fallback
+        .clone()
+}
+
+/// 在指定实例上执行操作，实例熔断时转到配置的备用实例
+///
+/// 备用实例只转移一次，不会继续转到备用实例的备用实例
+async fn execute<T, F, Fut>(source: RedisSource, op: F) -> Result<T, AppError>
+where
+    F: Fn(RedisConnection) -> Fut,
+    Fut: Future<Output = RedisResult<T>>,
+{
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::Redis).await?;
+
+    let fallback = match &source {
+        RedisSource::Named(name) => fallback_for(name).await.map(|fallback| (name, fallback)),
+        RedisSource::Primary => None,
+    };
+    let Some((name, fallback)) = fallback else {
+        return op(resolve_connection(&source).await?)
+            .await
+            .map_err(AppError::from);
+    };
+
+    if !circuit_open(name) {
+        let result = op(resolve_connection(&source).await?).await;
+        match &result {
+            Err(e) if is_connection_error(e) => {
+                if !record_failure(name) {
+                    return result.map_err(AppError::from);
+                }
+            },
+            _ => {
+                record_success(name);
+                return result.map_err(AppError::from);
+            },
+        }
+    }
+
+    tracing::info!(
+        target: "metrics",
+        event = "redis_fallback_used",
+        instance = name.as_str(),
+        fallback = fallback.as_str(),
+    );
+    op(resolve_connection(&RedisSource::Named(fallback)).await?)
+        .await
+        .map_err(AppError::from)
+}
+
+/// 执行命令，自动区分单机与集群模式
+pub async fn query<T: FromRedisValue>(source: RedisSource, cmd: &Cmd) -> Result<T, AppError> {
+    execute(source, |redis| async move {
+        with_connection!(redis, conn => cmd.query_async(&mut conn).await)
+    })
+    .await
+}
+
+/// 执行管道命令，自动区分单机与集群模式
+pub async fn query_pipeline<T: FromRedisValue>(
+    source: RedisSource,
+    pipeline: &Pipeline,
+) -> Result<T, AppError> {
+    execute(source, |redis| async move {
+        with_connection!(redis, conn => pipeline.query_async(&mut conn).await)
+    })
+    .await
+}
+
+/// 执行 Lua 脚本，自动区分单机与集群模式
+pub async fn invoke_script<T: FromRedisValue>(
+    source: RedisSource,
+    invocation: &ScriptInvocation<'_>,
+) -> Result<T, AppError> {
+    execute(source, |redis| async move {
+        with_connection!(redis, conn => invocation.invoke_async(&mut conn).await)
+    })
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_circuit_opens_after_threshold() {
+        let name = "test_circuit_threshold";
+        for _ in 1..CIRCUIT_FAILURE_THRESHOLD {
+            assert!(!record_failure(name));
+        }
+        assert!(!circuit_open(name));
+
+        assert!(record_failure(name));
+        assert!(circuit_open(name));
+
+        record_success(name);
+        assert!(!circuit_open(name));
+        assert!(!record_failure(name));
+    }
+
+    #[test]
+    fn test_command_errors_do_not_count() {
+        let command_error = RedisError::from((ErrorKind::TypeError, "wrong type"));
+        assert!(!is_connection_error(&command_error));
+        let io_error = RedisError::from(std::io::Error::from(std::io::ErrorKind::ConnectionReset));
+        assert!(is_connection_error(&io_error));
+    }
+}
@@ -0,0 +1,144 @@
+#![allow(dead_code)]
+use std::{sync::Arc, time::Duration};
+
+use aws_sdk_s3::{
+    config::http::HttpResponse,
+    error::{DisplayErrorContext, SdkError},
+    presigning::PresigningConfig,
+    Client as S3Client,
+};
+use server_config::{OptionalConfigs, S3Config, S3InstancesConfig};
+use server_core::web::error::AppError;
+use server_global::global::{get_config, GLOBAL_PRIMARY_S3, GLOBAL_S3_POOL};
+
+/// S3 客户端来源
+#[derive(Debug, Clone)]
+pub enum S3Source {
+    /// 主 S3 客户端
+    Primary,
+    /// 命名的 S3 客户端
+    Named(String),
+}
+
+/// 获取主 S3 客户端
+pub async fn get_primary_client() -> Result<Arc<S3Client>, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::S3).await?;
+    GLOBAL_PRIMARY_S3
+        .read()
+        .await
+        .clone()
+        .ok_or_else(|| AppError::dependency("Primary S3 client not initialized"))
+}
+
+/// 获取命名 S3 客户端
+pub async fn get_named_client(name: &str) -> Result<Arc<S3Client>, AppError> {
+    #[cfg(feature = "chaos")]
+    server_core::web::chaos::inject_fault(server_core::web::chaos::FaultTarget::S3).await?;
+    let pools = GLOBAL_S3_POOL.read().await;
+    pools
+        .get(name)
+        .cloned()
+        .ok_or_else(|| AppError::dependency(format!("S3 client '{}' not found", name)))
+}
+
+/// 获取 S3 客户端
+pub async fn get_client(source: S3Source) -> Result<Arc<S3Client>, AppError> {
+    match source {
+        S3Source::Primary => get_primary_client().await,
+        S3Source::Named(name) => get_named_client(&name).await,
+    }
+}
+
+/// 获取 S3 客户端对应的实例配置，用于读取分片上传等按实例调优的参数
+pub async fn get_instance_config(source: &S3Source) -> Result<S3Config, AppError> {
+    let config = match source {
+        S3Source::Primary => get_config::<S3Config>()
+            .await
+            .map(|config| (*config).clone()),
+        S3Source::Named(name) => get_config::<OptionalConfigs<S3InstancesConfig>>()
+            .await
+            .and_then(|instances| {
+                instances
+                    .configs
+                    .as_ref()?
+                    .iter()
+                    .find(|instance| &instance.name == name)
+                    .map(|instance| instance.s3.clone())
+            }),
+    };
+
+    config.ok_or_else(|| AppError::internal(format!("S3 config for {:?} not found", source)))
+}
+
+/// 生成对象的预签名下载地址，有效期取实例配置的 `presign_expiry`
+///
+/// `content_disposition` 和 `content_type` 覆盖下载时的响应头，用于还原原始文件名
+pub async fn presign_get_object(
+    client: &S3Client,
+    config: &S3Config,
+    bucket: &str,
+    key: &str,
+    content_disposition: Option<String>,
+    content_type: Option<String>,
+) -> Result<String, AppError> {
+    let presigning = PresigningConfig::expires_in(Duration::from_secs(config.presign_expiry()))
+        .map_err(|e| AppError::internal(e.to_string()))?;
+    let request = client
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .set_response_content_disposition(content_disposition)
+        .set_response_content_type(content_type)
+        .presigned(presigning)
+        .await
+        .map_err(s3_error)?;
+    Ok(request.uri().to_string())
+}
+
+/// S3 请求错误转换为应用错误，对象不存在为 `NotFound`，其余视为依赖不可用
+pub fn s3_error<E>(err: SdkError<E, HttpResponse>) -> AppError
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    let message = DisplayErrorContext(&err).to_string();
+    match &err {
+        SdkError::ServiceError(context) if context.raw().status().as_u16() == 404 => {
+            AppError::not_found(message)
+        },
+        _ => AppError::dependency(message),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use aws_sdk_s3::{
+        operation::head_object::HeadObjectError, primitives::SdkBody, types::error::NotFound,
+    };
+    use server_core::web::error::ErrorCategory;
+
+    use super::*;
+
+    fn service_error(status: u16) -> SdkError<HeadObjectError, HttpResponse> {
+        SdkError::service_error(
+            HeadObjectError::NotFound(NotFound::builder().build()),
+            HttpResponse::new(status.try_into().unwrap(), SdkBody::empty()),
+        )
+    }
+
+    #[test]
+    fn test_s3_error() {
+        assert_eq!(
+            s3_error(service_error(404)).category(),
+            ErrorCategory::NotFound
+        );
+        assert_eq!(
+            s3_error(service_error(503)).category(),
+            ErrorCategory::Dependency
+        );
+
+        let err: SdkError<HeadObjectError, HttpResponse> =
+            SdkError::timeout_error("connect timeout");
+        assert_eq!(s3_error(err).category(), ErrorCategory::Dependency);
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use server_config::ShareLinkConfig;
+
+/// 超过该数量的记录时清理已过期的记录，避免大量来源地址撑大内存
+const MAX_TRACKED_ATTEMPTS: usize = 10_000;
+
+static LIMITER: LazyLock<Mutex<AttemptLimiter>> =
+    LazyLock::new(|| Mutex::new(AttemptLimiter::default()));
+
+/// 连续失败次数及最近一次失败的时间
+struct Attempts {
+    failures: u32,
+    last_failure: Instant,
+}
+
+/// 分享密码尝试次数限制，按分享链接和来源计数
+///
+/// 计数保存在进程内，不依赖 Redis 和登录节流配置；多实例部署时每个实例单独计数
+#[derive(Default)]
+struct AttemptLimiter {
+    attempts: HashMap<String, Attempts>,
+}
+
+impl AttemptLimiter {
+    /// 是否已锁定，锁定时长从最近一次失败起算
+    fn is_locked(&self, key: &str, max_attempts: u32, lockout: Duration, now: Instant) -> bool {
+        self.attempts.get(key).is_some_and(|attempts| {
+            attempts.failures >= max_attempts && now.duration_since(attempts.last_failure) < lockout
+        })
+    }
+
+    fn record_failure(&mut self, key: &str, lockout: Duration, now: Instant) {
+        if self.attempts.len() >= MAX_TRACKED_ATTEMPTS {
+            self.attempts
+                .retain(|_, attempts| now.duration_since(attempts.last_failure) < lockout);
+        }
+        let attempts = self.attempts.entry(key.to_string()).or_insert(Attempts {
+            failures: 0,
+            last_failure: now,
+        });
+        // 锁定期过后重新计数
+        if now.duration_since(attempts.last_failure) >= lockout {
+            attempts.failures = 0;
+        }
+        attempts.failures += 1;
+        attempts.last_failure = now;
+    }
+
+    fn reset(&mut self, key: &str) {
+        self.attempts.remove(key);
+    }
+}
+
+fn attempt_key(share_id: &str, client: &str) -> String {
+    format!("{}:{}", share_id, client)
+}
+
+/// 来源对分享链接的密码尝试是否已被锁定
+pub fn is_locked(config: &ShareLinkConfig, share_id: &str, client: &str) -> bool {
+    LIMITER.lock().unwrap_or_else(|e| e.into_inner()).is_locked(
+        &attempt_key(share_id, client),
+        config.max_password_attempts,
+        Duration::from_secs(config.password_lockout),
+        Instant::now(),
+    )
+}
+
+/// 记录一次密码错误
+pub fn record_failure(config: &ShareLinkConfig, share_id: &str, client: &str) {
+    LIMITER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .record_failure(
+            &attempt_key(share_id, client),
+            Duration::from_secs(config.password_lockout),
+            Instant::now(),
+        );
+}
+
+/// 密码正确后清除失败次数
+pub fn reset(share_id: &str, client: &str) {
+    LIMITER
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .reset(&attempt_key(share_id, client));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locks_out_after_max_attempts() {
+        let mut limiter = AttemptLimiter::default();
+        let lockout = Duration::from_secs(900);
+        let start = Instant::now();
+        let key = attempt_key("share", "10.0.0.1");
+
+        for _ in 0..4 {
+            limiter.record_failure(&key, lockout, start);
+            assert!(!limiter.is_locked(&key, 5, lockout, start));
+        }
+        limiter.record_failure(&key, lockout, start);
+        assert!(limiter.is_locked(&key, 5, lockout, start));
+
+        // 其他来源和其他分享链接不受影响
+        assert!(!limiter.is_locked(&attempt_key("share", "10.0.0.2"), 5, lockout, start));
+        assert!(!limiter.is_locked(&attempt_key("other", "10.0.0.1"), 5, lockout, start));
+
+        // 锁定期过后解除，再次输错重新计数
+        let later = start + lockout;
+        assert!(!limiter.is_locked(&key, 5, lockout, later));
+        limiter.record_failure(&key, lockout, later);
+        assert!(!limiter.is_locked(&key, 5, lockout, later));
+
+        limiter.reset(&key);
+        assert!(!limiter.is_locked(&key, 1, lockout, later));
+    }
+}
@@ -0,0 +1,429 @@
+use std::{
+    collections::VecDeque,
+    sync::{Mutex, OnceLock},
+    time::Duration,
+};
+
+use chrono::{DateTime, Local};
+use serde::Serialize;
+use serde_json::Value;
+use server_config::{SiemConfig, SiemFormat, SiemTransport};
+use server_global::global::OperationLogContext;
+use tokio::{
+    io::AsyncWriteExt,
+    net::{TcpStream, UdpSocket},
+};
+
+use crate::project_error;
+
+const PRODUCT_VENDOR: &str = "Soybean";
+const PRODUCT_NAME: &str = "soybean-admin";
+/// syslog facility 13（log audit）
+const SYSLOG_FACILITY: u8 = 13;
+/// 单次发送的超时时间
+const SEND_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 事件分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SiemCategory {
+    /// 操作日志与业务审计
+    Audit,
+    /// 登录认证
+    Auth,
+}
+
+impl SiemCategory {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SiemCategory::Audit => "audit",
+            SiemCategory::Auth => "auth",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SiemOutcome {
+    Success,
+    Failure,
+}
+
+impl SiemOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            SiemOutcome::Success => "success",
+            SiemOutcome::Failure => "failure",
+        }
+    }
+}
+
+/// 导出到 SIEM 的事件
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SiemEvent {
+    pub timestamp: DateTime<Local>,
+    pub category: SiemCategory,
+    pub action: String,
+    pub outcome: SiemOutcome,
+    pub user_id: Option<String>,
+    pub username: Option<String>,
+    pub domain: Option<String>,
+    pub source_ip: Option<String>,
+    pub request_id: Option<String>,
+    pub detail: Option<Value>,
+}
+
+impl SiemEvent {
+    pub fn new(category: SiemCategory, action: impl Into<String>, outcome: SiemOutcome) -> Self {
+        Self {
+            timestamp: Local::now(),
+            category,
+            action: action.into(),
+            outcome,
+            user_id: None,
+            username: None,
+            domain: None,
+            source_ip: None,
+            request_id: None,
+            detail: None,
+        }
+    }
+
+    /// 由操作日志构造，业务审计记录附带审计详情，HTTP 操作日志不附带请求体
+    pub fn from_operation_log(context: &OperationLogContext) -> Self {
+        let is_audit = context.method == "AUDIT";
+        let action = if is_audit {
+            format!("{}: {}", context.module_name, context.description)
+        } else {
+            format!("{} {}", context.method, context.url)
+        };
+        let failed = context
+            .response
+            .as_ref()
+            .and_then(|response| response.get("success"))
+            .and_then(Value::as_bool)
+            == Some(false);
+
+        Self {
+            timestamp: context
+                .created_at
+                .and_local_timezone(Local)
+                .single()
+                .unwrap_or_else(Local::now),
+            user_id: context.user_id.clone(),
+            username: context.username.clone(),
+            domain: context.domain.clone(),
+            source_ip: (!context.ip.is_empty()).then(|| context.ip.clone()),
+            request_id: Some(context.request_id.clone()),
+            detail: if is_audit {
+                context.body.clone()
+            } else {
+                Some(serde_json::json!({ "durationMs": context.duration }))
+            },
+            ..Self::new(
+                SiemCategory::Audit,
+                action,
+                if failed {
+                    SiemOutcome::Failure
+                } else {
+                    SiemOutcome::Success
+                },
+            )
+        }
+    }
+
+    /// 转换为单行 JSON
+    fn to_json_line(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+
+    /// 转换为 CEF 格式
+    fn to_cef(&self) -> String {
+        let severity = match self.outcome {
+            SiemOutcome::Success => 3,
+            SiemOutcome::Failure => 6,
+        };
+        let mut extensions = vec![
+            ("rt", self.timestamp.timestamp_millis().to_string()),
+            ("cat", self.category.as_str().to_string()),
+            ("outcome", self.outcome.as_str().to_string()),
+        ];
+        let optional = [
+            ("suid", &self.user_id),
+            ("suser", &self.username),
+            ("cs1", &self.domain),
+            ("src", &self.source_ip),
+            ("externalId", &self.request_id),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                extensions.push((key, value.clone()));
+            }
+        }
+        if self.domain.is_some() {
+            extensions.push(("cs1Label", "domain".to_string()));
+        }
+        if let Some(detail) = &self.detail {
+            extensions.push(("msg", detail.to_string()));
+        }
+
+        let extensions: Vec<String> = extensions
+            .into_iter()
+            .map(|(key, value)| format!("{}={}", key, escape_cef_extension(&value)))
+            .collect();
+        format!(
+            "CEF:0|{}|{}|{}|{}-{}|{}|{}|{}",
+            PRODUCT_VENDOR,
+            PRODUCT_NAME,
+            env!("CARGO_PKG_VERSION"),
+            self.category.as_str(),
+            self.outcome.as_str(),
+            escape_cef_header(&self.action),
+            severity,
+            extensions.join(" ")
+        )
+    }
+
+    fn format(&self, format: SiemFormat) -> String {
+        match format {
+            SiemFormat::Json => self.to_json_line(),
+            SiemFormat::Cef => self.to_cef(),
+        }
+    }
+
+    /// 封装为 RFC 5424 syslog 报文
+    fn to_syslog(&self, format: SiemFormat, hostname: &str) -> String {
+        let severity = match self.outcome {
+            SiemOutcome::Success => 6,
+            SiemOutcome::Failure => 4,
+        };
+        format!(
+            "<{}>1 {} {} {} - {} - {}",
+            SYSLOG_FACILITY * 8 + severity,
+            self.timestamp.to_rfc3339(),
+            hostname,
+            PRODUCT_NAME,
+            self.category.as_str(),
+            self.format(format)
+        )
+    }
+}
+
+fn escape_cef_header(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('|', "\\|")
+        .replace(['\r', '\n'], " ")
+}
+
+fn escape_cef_extension(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('=', "\\=")
+        .replace('\r', "\\r")
+        .replace('\n', "\\n")
+}
+
+struct SiemExporter {
+    config: SiemConfig,
+    buffer: Mutex<VecDeque<SiemEvent>>,
+    client: reqwest::Client,
+    hostname: String,
+}
+
+static EXPORTER: OnceLock<SiemExporter> = OnceLock::new();
+
+/// 初始化导出器，未启用或重复初始化时返回 `false`
+pub fn init_siem_exporter(config: SiemConfig) -> bool {
+    if !config.enabled {
+        return false;
+    }
+    let client = match reqwest::Client::builder().timeout(SEND_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            project_error!("Failed to build SIEM HTTP client: {}", e);
+            return false;
+        },
+    };
+    EXPORTER
+        .set(SiemExporter {
+            buffer: Mutex::new(VecDeque::with_capacity(config.batch_size)),
+            config,
+            client,
+            hostname: std::env::var("HOSTNAME").unwrap_or_else(|_| "-".to_string()),
+        })
+        .is_ok()
+}
+
+/// 写入导出缓冲区，未启用导出时忽略
+pub fn export(event: SiemEvent) {
+    let Some(exporter) = EXPORTER.get() else {
+        return;
+    };
+    let mut buffer = exporter.buffer.lock().unwrap();
+    if buffer.len() >= exporter.config.buffer_capacity {
+        buffer.pop_front();
+        tracing::info!(target: "metrics", event = "siem_events_dropped", count = 1);
+    }
+    buffer.push_back(event);
+}
+
+/// 按批次发送缓冲区中的事件，某批重试耗尽后放回缓冲区并结束本轮发送
+pub async fn flush_siem_events() {
+    let Some(exporter) = EXPORTER.get() else {
+        return;
+    };
+
+    loop {
+        let batch: Vec<SiemEvent> = {
+            let mut buffer = exporter.buffer.lock().unwrap();
+            let size = buffer.len().min(exporter.config.batch_size);
+            buffer.drain(..size).collect()
+        };
+        if batch.is_empty() {
+            return;
+        }
+
+        match exporter.send_with_retry(&batch).await {
+            Ok(()) => {
+                tracing::info!(target: "metrics", event = "siem_events_exported", count = batch.len());
+            },
+            Err(e) => {
+                project_error!("Failed to export {} SIEM events: {}", batch.len(), e);
+                tracing::info!(target: "metrics", event = "siem_export_failed", count = batch.len());
+                exporter.requeue(batch);
+                return;
+            },
+        }
+    }
+}
+
+impl SiemExporter {
+    /// 将发送失败的批次放回缓冲区头部，缓冲区已满时丢弃其中较早的事件
+    fn requeue(&self, batch: Vec<SiemEvent>) {
+        let mut buffer = self.buffer.lock().unwrap();
+        let total = batch.len();
+        let mut requeued = 0;
+        for event in batch.into_iter().rev() {
+            if buffer.len() >= self.config.buffer_capacity {
+                break;
+            }
+            buffer.push_front(event);
+            requeued += 1;
+        }
+        if requeued < total {
+            tracing::info!(
+                target: "metrics",
+                event = "siem_events_dropped",
+                count = total - requeued,
+            );
+        }
+    }
+
+    async fn send_with_retry(&self, batch: &[SiemEvent]) -> Result<(), String> {
+        let mut delay = Duration::from_secs(1);
+        let mut attempt = 0;
+        loop {
+            match self.send(batch).await {
+                Ok(()) => return Ok(()),
+                Err(e) if attempt >= self.config.max_retries => return Err(e),
+                Err(_) => {
+                    attempt += 1;
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                },
+            }
+        }
+    }
+
+    async fn send(&self, batch: &[SiemEvent]) -> Result<(), String> {
+        let config = &self.config;
+        match config.transport {
+            SiemTransport::Http => {
+                let body: Vec<String> = batch
+                    .iter()
+                    .map(|event| event.format(config.format))
+                    .collect();
+                let content_type = match config.format {
+                    SiemFormat::Json => "application/x-ndjson",
+                    SiemFormat::Cef => "text/plain",
+                };
+                let mut request = self
+                    .client
+                    .post(&config.endpoint)
+                    .header(reqwest::header::CONTENT_TYPE, content_type)
+                    .body(body.join("\n"));
+                if let Some(authorization) = &config.authorization {
+                    request = request.header(reqwest::header::AUTHORIZATION, authorization);
+                }
+                request
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map(|_| ())
+                    .map_err(|e| e.to_string())
+            },
+            SiemTransport::SyslogUdp => {
+                let socket = UdpSocket::bind("0.0.0.0:0")
+                    .await
+                    .map_err(|e| e.to_string())?;
+                for event in batch {
+                    let frame = event.to_syslog(config.format, &self.hostname);
+                    socket
+                        .send_to(frame.as_bytes(), &config.endpoint)
+                        .await
+                        .map_err(|e| e.to_string())?;
+                }
+                Ok(())
+            },
+            SiemTransport::SyslogTcp => {
+                let send = async {
+                    let mut stream = TcpStream::connect(&config.endpoint).await?;
+                    for event in batch {
+                        let frame = event.to_syslog(config.format, &self.hostname);
+                        stream.write_all(frame.as_bytes()).await?;
+                        stream.write_all(b"\n").await?;
+                    }
+                    stream.flush().await
+                };
+                tokio::time::timeout(SEND_TIMEOUT, send)
+                    .await
+                    .map_err(|_| "syslog send timed out".to_string())?
+                    .map_err(|e| e.to_string())
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn login_failure() -> SiemEvent {
+        SiemEvent {
+            username: Some("admin".to_string()),
+            source_ip: Some("10.0.0.1".to_string()),
+            detail: Some(serde_json::json!({ "reason": "a=b" })),
+            ..SiemEvent::new(SiemCategory::Auth, "login|password", SiemOutcome::Failure)
+        }
+    }
+
+    #[test]
+    fn test_cef_escaping() {
+        let cef = login_failure().to_cef();
+        assert!(cef.starts_with("CEF:0|Soybean|soybean-admin|"));
+        assert!(cef.contains("|auth-failure|login\\|password|6|"));
+        assert!(cef.contains("suser=admin src=10.0.0.1"));
+        assert!(cef.contains(r#"msg={"reason":"a\=b"}"#));
+    }
+
+    #[test]
+    fn test_syslog_frame() {
+        let frame = login_failure().to_syslog(SiemFormat::Json, "host-1");
+        // facility 13 * 8 + warning 4
+        assert!(frame.starts_with("<108>1 "));
+        assert!(frame.contains(" host-1 soybean-admin - auth - {"));
+        assert!(frame.contains(r#""outcome":"failure""#));
+    }
+}
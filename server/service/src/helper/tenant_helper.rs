@@ -0,0 +1,130 @@
+use std::{
+    collections::HashMap,
+    sync::{LazyLock, Mutex},
+    time::{Duration, Instant},
+};
+
+use sea_orm::{ColumnTrait, EntityTrait, QueryFilter};
+use server_config::append_pg_option;
+use server_core::web::error::AppError;
+use server_model::admin::entities::{
+    prelude::SysTenant, sea_orm_active_enums::TenantStatus, sys_tenant::Column as SysTenantColumn,
+};
+
+use crate::{admin::sys_tenant_error::TenantError, helper::db_helper};
+
+/// 停用租户的缓存时间，每个请求都会检查，不必每次查库
+const CACHE_TTL: Duration = Duration::from_secs(30);
+
+/// 未填写停用说明时展示的默认说明
+const DEFAULT_SUSPEND_MESSAGE: &str = "该租户已停用，请联系服务提供方";
+
+/// 缓存的加载时间和内容
+type Cache = Mutex<Option<(Instant, HashMap<String, String>)>>;
+
+static CACHE: LazyLock<Cache> = LazyLock::new(|| Mutex::new(None));
+
+/// 停用和等待删除的租户及展示说明，带进程内缓存
+async fn inactive_tenants() -> Result<HashMap<String, String>, AppError> {
+    {
+        let cache = CACHE.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some((loaded_at, tenants)) = cache.as_ref() {
+            if loaded_at.elapsed() < CACHE_TTL {
+                return Ok(tenants.clone());
+            }
+        }
+    }
+
+    let db = db_helper::get_db_connection().await?;
+    let tenants: HashMap<String, String> = SysTenant::find()
+        .filter(SysTenantColumn::Status.ne(TenantStatus::Active))
+        .all(db.as_ref())
+        .await
+        .map_err(AppError::from)?
+        .into_iter()
+        .map(|tenant| {
+            let message = tenant
+                .suspend_reason
+                .filter(|reason| !reason.trim().is_empty())
+                .unwrap_or_else(|| DEFAULT_SUSPEND_MESSAGE.to_string());
+            (tenant.domain, message)
+        })
+        .collect();
+
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = Some((Instant::now(), tenants.clone()));
+    Ok(tenants)
+}
+
+/// 租户状态修改后清除缓存
+pub fn invalidate() {
+    *CACHE.lock().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// 租户停用时返回展示说明
+pub async fn suspension(domain: &str) -> Result<Option<String>, AppError> {
+    Ok(inactive_tenants().await?.remove(domain))
+}
+
+/// 登录时校验租户状态，停用的租户不再签发令牌
+pub async fn ensure_active(domain: &str) -> Result<(), AppError> {
+    match suspension(domain).await? {
+        Some(message) => Err(TenantError::TenantSuspended(message).into()),
+        None => Ok(()),
+    }
+}
+
+/// 租户编码只能包含小写字母、数字、`-` 和 `_`，且以字母开头，可安全用于 schema 名
+pub fn is_valid_code(code: &str) -> bool {
+    code.starts_with(|c: char| c.is_ascii_lowercase())
+        && code
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// 独立 schema 的名称
+pub fn schema_name(code: &str) -> String {
+    format!("tenant_{}", code.replace('-', "_"))
+}
+
+/// 租户数据库在连接池管理中使用的名称
+pub fn pool_name(code: &str) -> String {
+    format!("tenant:{}", code)
+}
+
+/// 在主库连接串上指定 `search_path`，使连接默认使用租户 schema
+pub fn schema_url(url: &str, schema: &str) -> String {
+    let mut url = url.to_string();
+    append_pg_option(&mut url, &format!("search_path={}", schema));
+    url
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tenant_naming() {
+        assert!(is_valid_code("acme-cn_01"));
+        assert!(!is_valid_code("1acme"));
+        assert!(!is_valid_code("Acme"));
+        assert!(!is_valid_code("acme\";drop"));
+
+        assert_eq!(schema_name("acme-cn"), "tenant_acme_cn");
+        assert_eq!(
+            schema_url("postgres://u:p@db:5432/app", "tenant_acme"),
+            "postgres://u:p@db:5432/app?options=-c%20search_path%3Dtenant_acme"
+        );
+        assert_eq!(
+            schema_url("postgres://u:p@db/app?sslmode=require", "tenant_acme"),
+            "postgres://u:p@db/app?sslmode=require&options=-c%20search_path%3Dtenant_acme"
+        );
+        // 主库连接串已有启动参数时合并到同一个 options 中
+        assert_eq!(
+            schema_url(
+                "postgres://u:p@db/app?options=-c%20work_mem%3D64MB",
+                "tenant_acme"
+            ),
+            "postgres://u:p@db/app?options=-c%20work_mem%3D64MB%20-c%20search_path%3Dtenant_acme"
+        );
+    }
+}
@@ -0,0 +1,343 @@
+use std::collections::{HashMap, HashSet};
+
+use chrono::Local;
+use sea_orm::{
+    sea_query::Expr, ColumnTrait, ConnectionTrait, DatabaseConnection, EntityTrait, QueryFilter,
+    QuerySelect, TransactionTrait, Value,
+};
+use server_core::web::error::AppError;
+use server_model::{
+    admin::{
+        entities::{sys_menu, sys_organization},
+        output::TreeNode,
+    },
+    audit::current_actor,
+};
+
+use crate::admin::sys_tree_error::TreeError;
+
+/// 根节点的上级 ID
+pub const ROOT_PID: &str = "0";
+
+/// 未指定深度时子树的最大层数，防止数据中存在环时无限展开
+pub const MAX_TREE_DEPTH: u32 = 32;
+
+/// 以 `pid` 列保存上级 ID 的树形实体
+///
+/// 树以邻接表保存，节点只记录直接上级，子树整体移动时只需改写被移动节点的 `pid`
+pub trait TreeEntity: EntityTrait {
+    fn id_column() -> Self::Column;
+    fn pid_column() -> Self::Column;
+    fn updated_at_column() -> Self::Column;
+    fn updated_by_column() -> Self::Column;
+    fn node_id(model: &Self::Model) -> String;
+    fn node_pid(model: &Self::Model) -> String;
+    /// 把字符串形式的 ID 转换为 ID 列的值，格式不合法时返回 `None`
+    fn id_value(id: &str) -> Option<Value>;
+}
+
+impl TreeEntity for sys_menu::Entity {
+    fn id_column() -> Self::Column {
+        sys_menu::Column::Id
+    }
+
+    fn pid_column() -> Self::Column {
+        sys_menu::Column::Pid
+    }
+
+    fn updated_at_column() -> Self::Column {
+        sys_menu::Column::UpdatedAt
+    }
+
+    fn updated_by_column() -> Self::Column {
+        sys_menu::Column::UpdatedBy
+    }
+
+    fn node_id(model: &Self::Model) -> String {
+        model.id.to_string()
+    }
+
+    fn node_pid(model: &Self::Model) -> String {
+        model.pid.clone()
+    }
+
+    fn id_value(id: &str) -> Option<Value> {
+        id.parse::<i32>().ok().map(Value::from)
+    }
+}
+
+impl TreeEntity for sys_organization::Entity {
+    fn id_column() -> Self::Column {
+        sys_organization::Column::Id
+    }
+
+    fn pid_column() -> Self::Column {
+        sys_organization::Column::Pid
+    }
+
+    fn updated_at_column() -> Self::Column {
+        sys_organization::Column::UpdatedAt
+    }
+
+    fn updated_by_column() -> Self::Column {
+        sys_organization::Column::UpdatedBy
+    }
+
+    fn node_id(model: &Self::Model) -> String {
+        model.id.clone()
+    }
+
+    fn node_pid(model: &Self::Model) -> String {
+        model.pid.clone()
+    }
+
+    fn id_value(id: &str) -> Option<Value> {
+        Some(Value::from(id))
+    }
+}
+
+async fn find_node<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+) -> Result<Option<E::Model>, AppError> {
+    let Some(value) = E::id_value(id) else {
+        return Ok(None);
+    };
+    E::find()
+        .filter(E::id_column().eq(value))
+        .one(db)
+        .await
+        .map_err(AppError::from)
+}
+
+/// 上级 ID 属于 `pids` 的全部节点
+async fn find_by_pids<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    pids: &[String],
+) -> Result<Vec<E::Model>, AppError> {
+    if pids.is_empty() {
+        return Ok(Vec::new());
+    }
+    E::find()
+        .filter(E::pid_column().is_in(pids.iter().cloned()))
+        .all(db)
+        .await
+        .map_err(AppError::from)
+}
+
+/// `ids` 中有下级节点的 ID
+async fn ids_with_children<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    ids: &[String],
+) -> Result<HashSet<String>, AppError> {
+    if ids.is_empty() {
+        return Ok(HashSet::new());
+    }
+    E::find()
+        .select_only()
+        .column(E::pid_column())
+        .distinct()
+        .filter(E::pid_column().is_in(ids.iter().cloned()))
+        .into_tuple::<String>()
+        .all(db)
+        .await
+        .map(|pids| pids.into_iter().collect())
+        .map_err(AppError::from)
+}
+
+/// 懒加载直接下级节点，`pid` 为 `0` 时返回根节点
+pub async fn find_children<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    pid: &str,
+) -> Result<Vec<TreeNode<E::Model>>, AppError> {
+    let children = find_by_pids::<E, _>(db, &[pid.to_string()]).await?;
+    let ids: Vec<String> = children.iter().map(E::node_id).collect();
+    let with_children = ids_with_children::<E, _>(db, &ids).await?;
+
+    Ok(children
+        .into_iter()
+        .map(|node| TreeNode {
+            has_children: with_children.contains(&E::node_id(&node)),
+            node,
+            children: None,
+        })
+        .collect())
+}
+
+/// 加载以 `id` 为根、最多 `depth` 层下级的子树
+///
+/// 逐层按 `pid IN (...)` 查询，不依赖递归 CTE；超出深度的节点只标记是否有下级
+pub async fn find_subtree<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+    depth: Option<u32>,
+) -> Result<TreeNode<E::Model>, AppError> {
+    let root = find_node::<E, _>(db, id)
+        .await?
+        .ok_or(TreeError::NodeNotFound)?;
+    let depth = depth.unwrap_or(MAX_TREE_DEPTH).clamp(1, MAX_TREE_DEPTH);
+
+    let mut visited = HashSet::from([id.to_string()]);
+    let mut levels: Vec<Vec<E::Model>> = Vec::new();
+    let mut frontier = vec![id.to_string()];
+    for _ in 0..depth {
+        let nodes: Vec<E::Model> = find_by_pids::<E, _>(db, &frontier)
+            .await?
+            .into_iter()
+            .filter(|node| visited.insert(E::node_id(node)))
+            .collect();
+        if nodes.is_empty() {
+            break;
+        }
+        frontier = nodes.iter().map(E::node_id).collect();
+        levels.push(nodes);
+    }
+    let with_children = ids_with_children::<E, _>(db, &frontier).await?;
+
+    Ok(assemble::<E>(root, levels, &with_children))
+}
+
+/// 自底向上把逐层查询的结果组装为树，最深一层按 `deepest_with_children` 标记是否有下级
+fn assemble<E: TreeEntity>(
+    root: E::Model,
+    levels: Vec<Vec<E::Model>>,
+    deepest_with_children: &HashSet<String>,
+) -> TreeNode<E::Model> {
+    let mut below: HashMap<String, Vec<TreeNode<E::Model>>> = HashMap::new();
+    let deepest = levels.len();
+    for (level, nodes) in levels.into_iter().enumerate().rev() {
+        let mut current: HashMap<String, Vec<TreeNode<E::Model>>> = HashMap::new();
+        for node in nodes {
+            let id = E::node_id(&node);
+            let children = below.remove(&id);
+            let has_children = match &children {
+                Some(_) => true,
+                None => level + 1 == deepest && deepest_with_children.contains(&id),
+            };
+            current
+                .entry(E::node_pid(&node))
+                .or_default()
+                .push(TreeNode {
+                    node,
+                    has_children,
+                    children,
+                });
+        }
+        below = current;
+    }
+
+    let children = below.remove(&E::node_id(&root));
+    TreeNode {
+        node: root,
+        has_children: children.is_some(),
+        children,
+    }
+}
+
+/// `id` 的全部下级节点 ID，不含 `id` 本身
+async fn descendant_ids<E: TreeEntity, C: ConnectionTrait>(
+    db: &C,
+    id: &str,
+) -> Result<HashSet<String>, AppError> {
+    let mut descendants = HashSet::new();
+    let mut frontier = vec![id.to_string()];
+    while !frontier.is_empty() {
+        frontier = find_by_pids::<E, _>(db, &frontier)
+            .await?
+            .iter()
+            .map(E::node_id)
+            .filter(|child| child != id && descendants.insert(child.clone()))
+            .collect();
+    }
+    Ok(descendants)
+}
+
+/// 把 `id` 连同全部下级节点移动到 `pid` 下，`pid` 为 `0` 时移动到根
+///
+/// 在同一事务内校验目标上级存在且不在被移动的子树中，再改写节点的 `pid`
+pub async fn move_subtree<E: TreeEntity>(
+    db: &DatabaseConnection,
+    id: &str,
+    pid: &str,
+) -> Result<(), AppError> {
+    let id_value = E::id_value(id).ok_or(TreeError::NodeNotFound)?;
+    let txn = db.begin().await.map_err(AppError::from)?;
+
+    let node = find_node::<E, _>(&txn, id)
+        .await?
+        .ok_or(TreeError::NodeNotFound)?;
+    if E::node_pid(&node) == pid {
+        return Ok(());
+    }
+    if pid != ROOT_PID {
+        if pid == id || descendant_ids::<E, _>(&txn, id).await?.contains(pid) {
+            return Err(TreeError::CyclicMove.into());
+        }
+        find_node::<E, _>(&txn, pid)
+            .await?
+            .ok_or(TreeError::ParentNotFound)?;
+    }
+
+    // 批量更新不经过实体保存钩子，审计列需自行设置
+    E::update_many()
+        .col_expr(E::pid_column(), Expr::value(pid))
+        .col_expr(
+            E::updated_at_column(),
+            Expr::value(Local::now().naive_local()),
+        )
+        .col_expr(E::updated_by_column(), Expr::value(current_actor()))
+        .filter(E::id_column().eq(id_value))
+        .exec(&txn)
+        .await
+        .map_err(AppError::from)?;
+
+    txn.commit().await.map_err(AppError::from)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDateTime;
+    use server_model::admin::entities::{sea_orm_active_enums::Status, sys_organization::Model};
+
+    use super::*;
+
+    fn organization(id: &str, pid: &str) -> Model {
+        Model {
+            id: id.to_string(),
+            code: id.to_string(),
+            name: id.to_string(),
+            description: None,
+            pid: pid.to_string(),
+            status: Status::Enabled,
+            created_at: NaiveDateTime::default(),
+            created_by: "system".to_string(),
+            updated_at: None,
+            updated_by: None,
+        }
+    }
+
+    #[test]
+    fn test_assemble_subtree() {
+        let levels = vec![
+            vec![organization("a", "root"), organization("b", "root")],
+            vec![organization("a1", "a")],
+        ];
+        let deepest_with_children = HashSet::from(["a1".to_string()]);
+        let tree = assemble::<sys_organization::Entity>(
+            organization("root", ROOT_PID),
+            levels,
+            &deepest_with_children,
+        );
+
+        assert!(tree.has_children);
+        let children = tree.children.unwrap();
+        assert_eq!(children.len(), 2);
+        let a = children.iter().find(|node| node.node.id == "a").unwrap();
+        let a1 = &a.children.as_ref().unwrap()[0];
+        assert!(a1.has_children);
+        assert!(a1.children.is_none());
+        let b = children.iter().find(|node| node.node.id == "b").unwrap();
+        assert!(!b.has_children);
+    }
+}
@@ -0,0 +1,96 @@
+use std::time::Duration;
+
+use server_config::ScannerConfig;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time::timeout,
+};
+
+/// 单次发送给 clamd 的数据块大小
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// 扫描结果
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ScanVerdict {
+    /// 未发现威胁
+    Clean,
+    /// 命中病毒特征，附带特征名称
+    Infected(String),
+}
+
+/// 使用 clamd 的 INSTREAM 命令扫描内容
+///
+/// 协议：发送 `zINSTREAM\0`，随后按 `<4 字节大端长度><数据>` 分块发送，
+/// 以长度为 0 的块结束，clamd 返回 `stream: OK` 或 `stream: <签名> FOUND`
+pub async fn scan_with_clamd(config: &ScannerConfig, data: &[u8]) -> Result<ScanVerdict, String> {
+    timeout(
+        Duration::from_secs(config.timeout),
+        instream(&config.clamd_address, data),
+    )
+    .await
+    .map_err(|_| format!("clamd scan timed out after {}s", config.timeout))?
+}
+
+async fn instream(address: &str, data: &[u8]) -> Result<ScanVerdict, String> {
+    let mut stream = TcpStream::connect(address)
+        .await
+        .map_err(|e| format!("Failed to connect to clamd at {}: {}", address, e))?;
+
+    stream
+        .write_all(b"zINSTREAM\0")
+        .await
+        .map_err(|e| e.to_string())?;
+
+    for chunk in data.chunks(CHUNK_SIZE) {
+        stream
+            .write_all(&(chunk.len() as u32).to_be_bytes())
+            .await
+            .map_err(|e| e.to_string())?;
+        stream.write_all(chunk).await.map_err(|e| e.to_string())?;
+    }
+    stream
+        .write_all(&0u32.to_be_bytes())
+        .await
+        .map_err(|e| e.to_string())?;
+    stream.flush().await.map_err(|e| e.to_string())?;
+
+    let mut response = Vec::new();
+    stream
+        .read_to_end(&mut response)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    parse_clamd_response(&String::from_utf8_lossy(&response))
+}
+
+fn parse_clamd_response(response: &str) -> Result<ScanVerdict, String> {
+    let response = response.trim_end_matches(['\0', '\n']).trim();
+    let result = response
+        .strip_prefix("stream:")
+        .map(str::trim)
+        .unwrap_or(response);
+
+    if result == "OK" {
+        Ok(ScanVerdict::Clean)
+    } else if let Some(signature) = result.strip_suffix("FOUND") {
+        Ok(ScanVerdict::Infected(signature.trim().to_string()))
+    } else {
+        Err(format!("Unexpected clamd response: {}", response))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_clamd_response() {
+        assert_eq!(parse_clamd_response("stream: OK\0"), Ok(ScanVerdict::Clean));
+        assert_eq!(
+            parse_clamd_response("stream: Eicar-Test-Signature FOUND\0"),
+            Ok(ScanVerdict::Infected("Eicar-Test-Signature".to_string()))
+        );
+        assert!(parse_clamd_response("INSTREAM size limit exceeded. ERROR\0").is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use std::io::{self, Read, Write};
+
+use crc32fast::Hasher;
+use miniz_oxide::deflate::core::{
+    compress_to_output, create_comp_flags_from_zip_params, CompressorOxide, TDEFLFlush, TDEFLStatus,
+};
+
+/// 压缩级别，取值 0-10，6 是速度和压缩率的常用折中
+const LEVEL: i32 = 6;
+
+/// 每次读取的字节数
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// gzip 头：魔数、压缩方法（deflate）、标志位、修改时间（未记录）、额外标志、操作系统（未知）
+const HEADER: [u8; 10] = [0x1F, 0x8B, 0x08, 0x00, 0, 0, 0, 0, 0x00, 0xFF];
+
+pub struct GzipUtil;
+
+impl GzipUtil {
+    /// 将输入流式压缩为单成员的 gzip 格式（RFC 1952），可以直接用 `gunzip` 解压
+    ///
+    /// miniz_oxide 只输出裸 deflate 流，gzip 头和 CRC32/长度尾部在这里补上。返回原始数据的长度
+    pub fn compress<R: Read, W: Write>(mut reader: R, mut writer: W) -> io::Result<u64> {
+        // 负的窗口位数表示不写 zlib 头，输出裸 deflate 流
+        let mut compressor = CompressorOxide::new(create_comp_flags_from_zip_params(LEVEL, -15, 0));
+        let mut hasher = Hasher::new();
+        let mut total = 0u64;
+        let mut buffer = vec![0u8; CHUNK_SIZE];
+
+        writer.write_all(&HEADER)?;
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hasher.update(&buffer[..read]);
+            total += read as u64;
+            deflate(
+                &mut compressor,
+                &buffer[..read],
+                TDEFLFlush::None,
+                &mut writer,
+            )?;
+        }
+        deflate(&mut compressor, &[], TDEFLFlush::Finish, &mut writer)?;
+
+        writer.write_all(&hasher.finalize().to_le_bytes())?;
+        // ISIZE 为原始长度对 2^32 取模
+        writer.write_all(&(total as u32).to_le_bytes())?;
+        writer.flush()?;
+        Ok(total)
+    }
+}
+
+fn deflate<W: Write>(
+    compressor: &mut CompressorOxide,
+    input: &[u8],
+    flush: TDEFLFlush,
+    writer: &mut W,
+) -> io::Result<()> {
+    let mut write_error = None;
+    let (status, _) = compress_to_output(compressor, input, flush, |output| {
+        match writer.write_all(output) {
+            Ok(()) => true,
+            Err(e) => {
+                write_error = Some(e);
+                false
+            },
+        }
+    });
+    if let Some(e) = write_error {
+        return Err(e);
+    }
+    match status {
+        TDEFLStatus::Okay | TDEFLStatus::Done => Ok(()),
+        status => Err(io::Error::other(format!("deflate failed: {:?}", status))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use miniz_oxide::inflate::decompress_to_vec;
+
+    use super::*;
+
+    #[test]
+    fn test_compress() {
+        // 超过一个读取块，覆盖多次压缩调用
+        let data = b"127.0.0.1 - - \"GET / HTTP/1.1\" 200 12\n".repeat(4096);
+        let mut output = Vec::new();
+        let total = GzipUtil::compress(data.as_slice(), &mut output).unwrap();
+
+        assert_eq!(total, data.len() as u64);
+        assert_eq!(&output[..10], &HEADER);
+        assert!(output.len() < data.len());
+
+        let trailer = &output[output.len() - 8..];
+        assert_eq!(&trailer[..4], &crc32fast::hash(&data).to_le_bytes());
+        assert_eq!(&trailer[4..], &(data.len() as u32).to_le_bytes());
+        assert_eq!(
+            decompress_to_vec(&output[10..output.len() - 8]).unwrap(),
+            data
+        );
+    }
+}
@@ -1,5 +1,9 @@
+mod gzip_util;
 mod secure_util;
 mod tree_util;
+mod zip_util;
 
+pub use gzip_util::*;
 pub use secure_util::*;
 pub use tree_util::*;
+pub use zip_util::*;
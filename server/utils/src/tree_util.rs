@@ -30,6 +30,14 @@ impl TreeBuilder {
     ///
     /// # 示例
     /// ```rust
+    /// # use server_utils::TreeBuilder;
+    /// # struct Node {
+    /// #     id: i32,
+    /// #     parent_id: Option<i32>,
+    /// #     sequence: i32,
+    /// #     children: Vec<Node>,
+    /// # }
+    /// # let nodes: Vec<Node> = Vec::new();
     /// let tree = TreeBuilder::build(
     ///     nodes,
     ///     |node| node.id,                            // ID获取器
@@ -112,6 +120,14 @@ impl TreeBuilder {
     ///
     /// # 示例
     /// ```rust
+    /// # use server_utils::TreeBuilder;
+    /// # struct Node {
+    /// #     id: i32,
+    /// #     parent_id: Option<i32>,
+    /// #     sequence: i32,
+    /// #     children: Vec<Node>,
+    /// # }
+    /// # let nodes: Vec<Node> = Vec::new();
     /// let tree = TreeBuilder::build_fast(
     ///     nodes,
     ///     |node| node.id,
@@ -177,7 +193,7 @@ impl TreeBuilder {
     /// 递归构建树结构
     #[inline]
     fn attach_children<T, Id, F1, F2>(
-        nodes: &mut Vec<T>,
+        nodes: &mut [T],
         child_map: &mut HashMap<Id, Vec<T>>,
         id_fn: &F1,
         set_children_fn: &mut F2,
@@ -0,0 +1,143 @@
+use std::io;
+
+use chrono::{Datelike, NaiveDateTime, Timelike};
+
+/// ZIP 中文件名使用 UTF-8 编码的标志位
+const UTF8_FLAG: u16 = 1 << 11;
+const VERSION: u16 = 20;
+
+/// MS-DOS 格式的修改时间和日期，只能表示 1980 年以后的时间
+fn dos_datetime(time: NaiveDateTime) -> (u16, u16) {
+    let dos_time = ((time.hour() << 11) | (time.minute() << 5) | (time.second() / 2)) as u16;
+    let dos_date =
+        (((time.year().max(1980) - 1980) as u32) << 9) | (time.month() << 5) | time.day();
+    (dos_time, dos_date as u16)
+}
+
+pub struct ZipUtil;
+
+impl ZipUtil {
+    /// 将文件以不压缩（stored）方式打包为 ZIP，适用于体积较小的文本文件
+    ///
+    /// 不支持 ZIP64：文件数超过 65535、文件名超过 65535 字节，或单个文件、整个压缩包超过 4GiB 时返回错误
+    pub fn store(entries: &[(&str, &[u8])], modified: NaiveDateTime) -> io::Result<Vec<u8>> {
+        let entry_count = to_u16(entries.len(), "entry count")?;
+        let (dos_time, dos_date) = dos_datetime(modified);
+        let mut archive = Vec::new();
+        let mut central = Vec::new();
+
+        for (name, data) in entries {
+            let offset = to_u32(archive.len(), "archive size")?;
+            let crc = crc32fast::hash(data);
+            let size = to_u32(data.len(), "file size")?;
+            let name_len = to_u16(name.len(), "file name length")?;
+
+            // 本地文件头
+            archive.extend_from_slice(&0x0403_4B50u32.to_le_bytes());
+            archive.extend_from_slice(&VERSION.to_le_bytes());
+            archive.extend_from_slice(&UTF8_FLAG.to_le_bytes());
+            archive.extend_from_slice(&0u16.to_le_bytes());
+            archive.extend_from_slice(&dos_time.to_le_bytes());
+            archive.extend_from_slice(&dos_date.to_le_bytes());
+            archive.extend_from_slice(&crc.to_le_bytes());
+            archive.extend_from_slice(&size.to_le_bytes());
+            archive.extend_from_slice(&size.to_le_bytes());
+            archive.extend_from_slice(&name_len.to_le_bytes());
+            archive.extend_from_slice(&0u16.to_le_bytes());
+            archive.extend_from_slice(name.as_bytes());
+            archive.extend_from_slice(data);
+
+            // 中央目录记录
+            central.extend_from_slice(&0x0201_4B50u32.to_le_bytes());
+            central.extend_from_slice(&VERSION.to_le_bytes());
+            central.extend_from_slice(&VERSION.to_le_bytes());
+            central.extend_from_slice(&UTF8_FLAG.to_le_bytes());
+            central.extend_from_slice(&0u16.to_le_bytes());
+            central.extend_from_slice(&dos_time.to_le_bytes());
+            central.extend_from_slice(&dos_date.to_le_bytes());
+            central.extend_from_slice(&crc.to_le_bytes());
+            central.extend_from_slice(&size.to_le_bytes());
+            central.extend_from_slice(&size.to_le_bytes());
+            central.extend_from_slice(&name_len.to_le_bytes());
+            // 扩展字段、注释、磁盘号、内部属性长度均为 0
+            central.extend_from_slice(&[0u8; 8]);
+            central.extend_from_slice(&0u32.to_le_bytes());
+            central.extend_from_slice(&offset.to_le_bytes());
+            central.extend_from_slice(name.as_bytes());
+        }
+
+        let central_offset = to_u32(archive.len(), "archive size")?;
+        let central_size = to_u32(central.len(), "central directory size")?;
+        archive.extend_from_slice(&central);
+
+        // 中央目录结束记录
+        archive.extend_from_slice(&0x0605_4B50u32.to_le_bytes());
+        archive.extend_from_slice(&[0u8; 4]);
+        archive.extend_from_slice(&entry_count.to_le_bytes());
+        archive.extend_from_slice(&entry_count.to_le_bytes());
+        archive.extend_from_slice(&central_size.to_le_bytes());
+        archive.extend_from_slice(&central_offset.to_le_bytes());
+        archive.extend_from_slice(&0u16.to_le_bytes());
+        Ok(archive)
+    }
+}
+
+fn to_u16(value: usize, field: &str) -> io::Result<u16> {
+    u16::try_from(value).map_err(|_| overflow(field))
+}
+
+fn to_u32(value: usize, field: &str) -> io::Result<u32> {
+    u32::try_from(value).map_err(|_| overflow(field))
+}
+
+fn overflow(field: &str) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidInput,
+        format!("{} exceeds the ZIP limit (ZIP64 is not supported)", field),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::NaiveDate;
+
+    use super::*;
+
+    #[test]
+    fn test_store() {
+        let modified = NaiveDate::from_ymd_opt(2024, 5, 17)
+            .unwrap()
+            .and_hms_opt(13, 45, 30)
+            .unwrap();
+        let archive = ZipUtil::store(
+            &[
+                ("a.txt", b"hello".as_slice()),
+                ("b/c.json", b"{}".as_slice()),
+            ],
+            modified,
+        )
+        .unwrap();
+
+        assert_eq!(&archive[..4], &[0x50, 0x4B, 0x03, 0x04]);
+        assert_eq!(&archive[30..35], b"a.txt");
+        assert_eq!(&archive[35..40], b"hello");
+
+        let end = archive.len() - 22;
+        assert_eq!(&archive[end..end + 4], &[0x50, 0x4B, 0x05, 0x06]);
+        let entries = u16::from_le_bytes([archive[end + 10], archive[end + 11]]);
+        assert_eq!(entries, 2);
+        let central_offset =
+            u32::from_le_bytes(archive[end + 16..end + 20].try_into().unwrap()) as usize;
+        assert_eq!(
+            &archive[central_offset..central_offset + 4],
+            &[0x50, 0x4B, 0x01, 0x02]
+        );
+
+        let dos_time = u16::from_le_bytes([archive[10], archive[11]]);
+        assert_eq!(dos_time, (13 << 11) | (45 << 5) | 15);
+
+        let long_name = "a".repeat(u16::MAX as usize + 1);
+        let err = ZipUtil::store(&[(long_name.as_str(), b"".as_slice())], modified).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
@@ -1,5 +1,4 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rand;
 use xdb::searcher::{
     get_block_by_size, get_full_cache, get_vector_index_cache, search_by_ip, searcher_init,
 };